@@ -0,0 +1,60 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+
+from materialize.mzcompose.service import Service, ServiceConfig
+
+
+class MongoDb(Service):
+    DEFAULT_VERSION = "7.0"
+    DEFAULT_REPLICA_SET = "rs0"
+
+    def __init__(
+        self,
+        name: str = "mongodb",
+        version: str = DEFAULT_VERSION,
+        port: int = 27017,
+        replica_set: str = DEFAULT_REPLICA_SET,
+        volumes_extra: list[str] = [],
+    ) -> None:
+        # Change streams require a replica set, so run a single member one. The
+        # healthcheck initiates it on first boot and is healthy once the member
+        # has become primary.
+        initiate = (
+            f"try {{ rs.status().ok }} catch (e) {{ "
+            f"rs.initiate({{_id: '{replica_set}', members: [{{_id: 0, host: '{name}:{port}'}}]}}).ok }}; "
+            f"if (!db.hello().isWritablePrimary) {{ quit(1) }}"
+        )
+        config: ServiceConfig = {
+            "image": f"mongo:{version}",
+            "ports": [port],
+            "command": [
+                "--replSet",
+                replica_set,
+                "--bind_ip_all",
+                "--port",
+                str(port),
+            ],
+            "healthcheck": {
+                "test": [
+                    "CMD",
+                    "mongosh",
+                    "--quiet",
+                    "--port",
+                    str(port),
+                    "--eval",
+                    initiate,
+                ],
+                "interval": "1s",
+                "start_period": "60s",
+            },
+            "volumes": volumes_extra,
+        }
+
+        super().__init__(name=name, config=config)
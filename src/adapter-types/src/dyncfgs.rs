@@ -323,6 +323,15 @@ pub const CATALOG_INFO_METRICS_RECONCILE_INTERVAL: Config<Duration> = Config::ne
     "How frequently to reconcile the catalog `*_info` metrics with the catalog. A zero duration disables reconciliation.",
 );
 
+/// How frequently source tables with `SCHEMA CHANGE POLICY = 'add_columns'` are
+/// compared against their upstream table. A zero duration disables the checks.
+pub const SOURCE_TABLE_SCHEMA_CHANGE_CHECK_INTERVAL: Config<Duration> = Config::new(
+    "source_table_schema_change_check_interval",
+    Duration::from_secs(30),
+    "How frequently to check the upstream tables of source tables with a schema change policy \
+    for added columns. A zero duration disables the checks.",
+);
+
 /// Server-side `statement_timeout` to set on Postgres/CRDB connections used by
 /// the Postgres/CRDB timestamp oracle. A zero value leaves the statement
 /// timeout unset.
@@ -462,6 +471,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&ARRANGEMENT_SIZE_HISTORY_COLLECTION_INTERVAL)
         .add(&ARRANGEMENT_SIZE_HISTORY_RETENTION_PERIOD)
         .add(&CATALOG_INFO_METRICS_RECONCILE_INTERVAL)
+        .add(&SOURCE_TABLE_SCHEMA_CHANGE_CHECK_INTERVAL)
         .add(&PG_TIMESTAMP_ORACLE_STATEMENT_TIMEOUT)
         .add(&ENABLE_SCOPED_SYSTEM_PARAMETERS)
 }
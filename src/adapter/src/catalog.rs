@@ -266,6 +266,7 @@ pub struct ConnCatalog<'a> {
     portals: Option<&'a BTreeMap<String, Portal>>,
     notices_tx: UnboundedSender<AdapterNotice>,
     restrict_to_user_objects: bool,
+    rbac_checks_enabled: bool,
 }
 
 impl ConnCatalog<'_> {
@@ -1309,6 +1310,22 @@ impl Catalog {
             .resolve_builtin_table_update(self.state.pack_storage_usage_update(event, diff))
     }
 
+    pub fn pack_snapshot_storage_usage_update(
+        &self,
+        id: CatalogItemId,
+        size_bytes: u64,
+        collection_timestamp: EpochMillis,
+        diff: Diff,
+    ) -> BuiltinTableUpdate {
+        self.state
+            .resolve_builtin_table_update(self.state.pack_snapshot_storage_usage_update(
+                id,
+                size_bytes,
+                collection_timestamp,
+                diff,
+            ))
+    }
+
     pub fn system_config(&self) -> &SystemVars {
         self.state.system_config()
     }
@@ -1799,6 +1816,10 @@ impl SessionCatalog for ConnCatalog<'_> {
         self.restrict_to_user_objects
     }
 
+    fn rbac_checks_enabled(&self) -> bool {
+        self.rbac_checks_enabled
+    }
+
     fn get_prepared_statement_desc(&self, name: &str) -> Option<&StatementDesc> {
         self.prepared_statements
             .as_ref()
@@ -17,27 +17,29 @@ use mz_catalog::SYSTEM_CONN_ID;
 use mz_catalog::builtin::{
     BuiltinTable, MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AWS_CONNECTIONS,
     MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES, MZ_CLUSTER_REPLICA_SIZE_INTERNAL,
-    MZ_CLUSTER_REPLICA_SIZES, MZ_COLUMNS, MZ_EGRESS_IPS, MZ_FUNCTIONS,
+    MZ_CLUSTER_REPLICA_SIZES, MZ_COLUMN_PRIVILEGES, MZ_COLUMNS, MZ_EGRESS_IPS, MZ_FUNCTIONS,
     MZ_HISTORY_RETENTION_STRATEGIES, MZ_ICEBERG_SINKS, MZ_INDEX_COLUMNS, MZ_KAFKA_CONNECTIONS,
     MZ_KAFKA_SINKS, MZ_KAFKA_SOURCE_TABLES, MZ_LICENSE_KEYS, MZ_LIST_TYPES, MZ_MAP_TYPES,
     MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES, MZ_MYSQL_SOURCE_TABLES, MZ_OBJECT_DEPENDENCIES,
     MZ_OBJECT_GLOBAL_IDS, MZ_OPERATORS, MZ_POSTGRES_SOURCE_TABLES, MZ_PSEUDO_TYPES,
-    MZ_REPLACEMENTS, MZ_ROLE_AUTH, MZ_SESSIONS, MZ_SINKS, MZ_SOURCE_REFERENCES,
-    MZ_SQL_SERVER_SOURCE_TABLES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
-    MZ_SUBSCRIPTIONS, MZ_TABLES, MZ_TYPE_PG_METADATA, MZ_TYPES, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
+    MZ_REPLACEMENTS, MZ_ROLE_AUTH, MZ_SESSIONS, MZ_SINKS, MZ_SNAPSHOT_STORAGE_USAGE, MZ_SNAPSHOTS,
+    MZ_SOURCE_REFERENCES, MZ_SQL_SERVER_SOURCE_TABLES, MZ_SSH_TUNNEL_CONNECTIONS,
+    MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_TABLES, MZ_TYPE_PG_METADATA, MZ_TYPES,
+    MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::config::AwsPrincipalContext;
 use mz_catalog::durable::SourceReferences;
 use mz_catalog::memory::error::Error;
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, Connection, DataSourceDesc, Func, Index, MaterializedView, Sink,
-    Table, TableDataSource, Type, View,
+    CatalogEntry, CatalogItem, Connection, DataSourceDesc, Func, Index, MaterializedView,
+    Notification, Sink, Table, TableDataSource, Type, View,
 };
 use mz_expr::MirScalarExpr;
 use mz_license_keys::ValidatedLicenseKey;
 use mz_orchestrator::{CpuLimit, DiskLimit, MemoryLimit};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
+use mz_ore::now::EpochMillis;
 use mz_persist_client::batch::ProtoBatch;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::interval::Interval;
@@ -46,13 +48,14 @@ use mz_repr::adt::mz_acl_item::PrivilegeMap;
 use mz_repr::refresh_schedule::RefreshEvery;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    CatalogItemId, Datum, Diff, GlobalId, ReprColumnType, Row, RowPacker, SqlScalarType, Timestamp,
+    CatalogItemId, ColumnName, Datum, Diff, GlobalId, ReprColumnType, Row, RowPacker,
+    SqlScalarType, Timestamp,
 };
 use mz_sql::ast::{CreateIndexStatement, Statement, UnresolvedItemName};
-use mz_sql::catalog::{CatalogType, TypeCategory};
+use mz_sql::catalog::{CatalogItem as SqlCatalogItem, CatalogType, TypeCategory};
 use mz_sql::func::FuncImplCatalogDetails;
 use mz_sql::names::SchemaSpecifier;
-use mz_sql::plan::{ConnectionDetails, SshKey};
+use mz_sql::plan::{ColumnPrivileges, ConnectionDetails, SshKey};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_storage_client::client::TableData;
 use mz_storage_types::connections::KafkaConnection;
@@ -126,6 +129,22 @@ impl CatalogState {
         BuiltinTableUpdate::row(&*MZ_OBJECT_DEPENDENCIES, row, diff)
     }
 
+    fn pack_column_privileges_update(
+        &self,
+        id: CatalogItemId,
+        column_name: &ColumnName,
+        privileges: &PrivilegeMap,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        let privileges_row = self.pack_privilege_array_row(privileges);
+        let row = Row::pack_slice(&[
+            Datum::String(&id.to_string()),
+            Datum::String(column_name.as_str()),
+            privileges_row.unpack_first(),
+        ]);
+        BuiltinTableUpdate::row(&*MZ_COLUMN_PRIVILEGES, row, diff)
+    }
+
     pub(super) fn pack_role_auth_update(
         &self,
         id: RoleId,
@@ -241,9 +260,12 @@ impl CatalogState {
                                         diff,
                                     )
                                 }
-                                // Load generator sources don't have any special
-                                // updates.
-                                "load-generator" => vec![],
+                                // Load generator, MongoDB, S3, Iceberg, Kinesis, NATS
+                                // and MQTT sources don't have any special updates.
+                                "load-generator" | "mongodb" | "s3" | "iceberg" | "kinesis"
+                                | "nats" | "mqtt" => {
+                                    vec![]
+                                }
                                 "kafka" => {
                                     mz_ore::soft_assert_eq_no_log!(external_reference.len(), 1);
                                     let topic = external_reference[0].as_str();
@@ -271,6 +293,10 @@ impl CatalogState {
                     });
                 }
 
+                if let TableDataSource::Snapshot { from, as_of } = &table.data_source {
+                    updates.extend(self.pack_snapshot_update(id, from, *as_of, diff));
+                }
+
                 updates
             }
             CatalogItem::Source(source) => {
@@ -358,6 +384,15 @@ impl CatalogState {
             CatalogItem::Sink(sink) => {
                 self.pack_sink_update(id, oid, schema_id, name, owner_id, sink, diff)
             }
+            CatalogItem::Notification(notification) => self.pack_notification_update(
+                id,
+                oid,
+                schema_id,
+                name,
+                owner_id,
+                notification,
+                diff,
+            ),
             CatalogItem::Type(ty) => {
                 self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
             }
@@ -378,6 +413,13 @@ impl CatalogState {
             }
         }
 
+        if entry.is_relation() {
+            let column_privileges = ColumnPrivileges::from_create_sql(entry.create_sql());
+            for (column_name, privileges) in column_privileges.iter() {
+                updates.push(self.pack_column_privileges_update(id, column_name, privileges, diff));
+            }
+        }
+
         // Always report the latest for an objects columns.
         if let Some(desc) = entry.relation_desc_latest() {
             let defaults = match entry.item() {
@@ -624,6 +666,25 @@ impl CatalogState {
         )]
     }
 
+    fn pack_snapshot_update(
+        &self,
+        id: CatalogItemId,
+        from: &GlobalId,
+        as_of: Timestamp,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let materialized_view_id = self.get_entry_by_global_id(from).id();
+        vec![BuiltinTableUpdate::row(
+            &*MZ_SNAPSHOTS,
+            Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::String(&materialized_view_id.to_string()),
+                Datum::MzTimestamp(as_of),
+            ]),
+            diff,
+        )]
+    }
+
     fn pack_connection_update(
         &self,
         id: CatalogItemId,
@@ -669,6 +730,9 @@ impl CatalogState {
             | ConnectionDetails::Postgres(_)
             | ConnectionDetails::MySql(_)
             | ConnectionDetails::SqlServer(_)
+            | ConnectionDetails::MongoDb(_)
+            | ConnectionDetails::Nats(_)
+            | ConnectionDetails::Mqtt(_)
             | ConnectionDetails::IcebergCatalog(_) => (),
         };
         updates
@@ -973,6 +1037,9 @@ impl CatalogState {
                     diff,
                 ));
             }
+            StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Http(_)
+            | StorageSinkConnection::S3(_) => {}
         };
 
         let create_stmt = mz_sql::parse::parse(&sink.create_sql)
@@ -1017,6 +1084,46 @@ impl CatalogState {
         updates
     }
 
+    /// Notifications are listed in `mz_sinks`, as they are dropped and owned
+    /// like sinks, with the type `notification`.
+    fn pack_notification_update(
+        &self,
+        id: CatalogItemId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        notification: &Notification,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let create_stmt = mz_sql::parse::parse(&notification.create_sql)
+            .unwrap_or_else(|_| panic!("create_sql cannot be invalid: {}", notification.create_sql))
+            .into_element()
+            .ast;
+
+        vec![BuiltinTableUpdate::row(
+            &*MZ_SINKS,
+            Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                Datum::String("notification"),
+                Datum::Null,
+                Datum::Null,
+                Datum::Null,
+                Datum::Null,
+                Datum::Null,
+                Datum::Null,
+                Datum::String(&notification.cluster_id.to_string()),
+                Datum::String(&owner_id.to_string()),
+                Datum::String(&notification.create_sql),
+                Datum::String(&create_stmt.to_ast_string_redacted()),
+            ]),
+            diff,
+        )]
+    }
+
     fn pack_index_update(
         &self,
         id: CatalogItemId,
@@ -1326,6 +1433,26 @@ impl CatalogState {
         BuiltinTableUpdate::row(id, row, diff)
     }
 
+    pub fn pack_snapshot_storage_usage_update(
+        &self,
+        id: CatalogItemId,
+        size_bytes: u64,
+        collection_timestamp: EpochMillis,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        let table = &MZ_SNAPSHOT_STORAGE_USAGE;
+        let row = Row::pack_slice(&[
+            Datum::String(&id.to_string()),
+            Datum::UInt64(size_bytes),
+            Datum::TimestampTz(
+                mz_ore::now::to_datetime(collection_timestamp)
+                    .try_into()
+                    .expect("must fit"),
+            ),
+        ]);
+        BuiltinTableUpdate::row(table, row, diff)
+    }
+
     pub fn pack_egress_ip_update(
         &self,
         ip: &IpNet,
@@ -553,7 +553,11 @@ impl CatalogState {
                         )
                         | Statement::CreateTable(ast::CreateTableStatement { name, .. })
                         | Statement::CreateType(ast::CreateTypeStatement { name, .. })
-                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. }) => {
+                        | Statement::CreateSecret(ast::CreateSecretStatement { name, .. })
+                        | Statement::CreateNotification(ast::CreateNotificationStatement {
+                            name,
+                            ..
+                        }) => {
                             let [db_component, schema_component, item_component] = &name.0[..]
                             else {
                                 let name =
@@ -436,8 +436,14 @@ fn rewrite_sources_to_tables(
                     }
                 });
             }
-            CreateSourceConnection::Kafka { .. } | CreateSourceConnection::LoadGenerator { .. } => {
-            }
+            CreateSourceConnection::Kafka { .. }
+            | CreateSourceConnection::Kinesis { .. }
+            | CreateSourceConnection::Nats { .. }
+            | CreateSourceConnection::Mqtt { .. }
+            | CreateSourceConnection::MongoDb { .. }
+            | CreateSourceConnection::S3 { .. }
+            | CreateSourceConnection::Iceberg { .. }
+            | CreateSourceConnection::LoadGenerator { .. } => {}
         }
 
         // Then, figure out the new statements for the progress and source.
@@ -446,6 +452,9 @@ fn rewrite_sources_to_tables(
                 connection @ (CreateSourceConnection::Postgres { .. }
                 | CreateSourceConnection::MySql { .. }
                 | CreateSourceConnection::SqlServer { .. }
+                | CreateSourceConnection::MongoDb { .. }
+                | CreateSourceConnection::S3 { .. }
+                | CreateSourceConnection::Iceberg { .. }
                 | CreateSourceConnection::LoadGenerator {
                     generator:
                         LoadGenerator::Tpch | LoadGenerator::Auction | LoadGenerator::Marketing,
@@ -483,6 +492,7 @@ fn rewrite_sources_to_tables(
                                 limit: None,
                                 offset: None,
                             },
+                            with_options: vec![],
                         },
                     });
 
@@ -510,6 +520,13 @@ fn rewrite_sources_to_tables(
                         dummy_source_stmt,
                     )
                 }
+                CreateSourceConnection::Kinesis { .. }
+                | CreateSourceConnection::Nats { .. }
+                | CreateSourceConnection::Mqtt { .. } => {
+                    // These sources only ever output to tables, so they never have a progress
+                    // subsource and are skipped above.
+                    anyhow::bail!("source {name} unexpectedly uses the legacy source syntax")
+                }
                 CreateSourceConnection::Kafka {
                     options,
                     connection,
@@ -537,13 +554,25 @@ fn rewrite_sources_to_tables(
                             details.into_proto().encode_to_vec(),
                         )))),
                     }];
-                    // The external reference for a kafka source is the just the topic name
+                    // The external reference for a kafka source is the just the topic name, or the
+                    // first listed topic or pattern for sources over more than one topic.
                     let topic_option = options
                         .iter()
-                        .find(|o| matches!(o.name, KafkaSourceConfigOptionName::Topic))
+                        .find(|o| {
+                            matches!(
+                                o.name,
+                                KafkaSourceConfigOptionName::Topic
+                                    | KafkaSourceConfigOptionName::Topics
+                                    | KafkaSourceConfigOptionName::TopicPattern
+                            )
+                        })
                         .expect("kafka sources must have a topic");
                     let topic = match &topic_option.value {
                         Some(WithOptionValue::Value(Value::String(topic))) => topic,
+                        Some(WithOptionValue::Sequence(topics)) => match topics.first() {
+                            Some(WithOptionValue::Value(Value::String(topic))) => topic,
+                            _ => unreachable!("topics must be strings"),
+                        },
                         _ => unreachable!("topic must be a string"),
                     };
                     let external_reference = UnresolvedItemName::qualified(&[Ident::new(topic)?]);
@@ -626,9 +626,13 @@ impl Catalog {
     /// Note that this must be done before creating/rendering collections
     /// because the storage controller might not be aware of new system
     /// collections created between versions.
+    ///
+    /// Unless `read_only` is set, this also reclaims the storage collections
+    /// of temporary items that were alive when the previous process exited.
     async fn initialize_storage_state(
         &mut self,
         storage_collections: &Arc<dyn StorageCollections + Send + Sync>,
+        read_only: bool,
     ) -> Result<(), mz_catalog::durable::CatalogError> {
         let collections = self
             .entries()
@@ -656,6 +660,33 @@ impl Catalog {
             Some(id) => assert_eq!(*id, shard_id),
         }
 
+        // Temporary items are not durable, so the storage collections of the
+        // temporary items that were alive when the previous process exited are
+        // only known from the records made when creating them. Drop their
+        // metadata and finalize their shards.
+        if !read_only {
+            let temporary_ids = txn.get_temporary_collections();
+            if !temporary_ids.is_empty() {
+                info!(
+                    ?temporary_ids,
+                    "dropping storage collections of temporary items"
+                );
+                for id in &temporary_ids {
+                    txn.remove_temporary_collection(*id)?;
+                }
+                let dropped = txn.delete_collection_metadata(temporary_ids);
+                let referenced_shards: BTreeSet<_> =
+                    txn.get_collection_metadata().into_values().collect();
+                let dropped_shards = dropped
+                    .into_iter()
+                    .map(|(_, shard_id)| shard_id)
+                    .filter(|shard_id| !referenced_shards.contains(shard_id))
+                    .collect();
+                txn.insert_unfinalized_shards(dropped_shards)
+                    .map_err(mz_catalog::durable::DurableCatalogError::from)?;
+            }
+        }
+
         storage_collections
             .initialize_state(&mut txn, collections)
             .await
@@ -711,7 +742,7 @@ impl Catalog {
             mz_controller::Controller::new(config, envd_epoch, read_only, &read_only_tx).await
         };
 
-        self.initialize_storage_state(&controller.storage_collections)
+        self.initialize_storage_state(&controller.storage_collections, read_only)
             .await?;
 
         info!(
@@ -32,8 +32,8 @@ use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
     Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
-    TableDataSource, Type, View,
+    NetworkPolicy, Notification, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences,
+    Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaLocation, ReplicaAllocation, ReplicaLocation, UnmanagedReplicaLocation,
@@ -74,9 +74,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateNotificationPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -365,6 +365,7 @@ impl CatalogState {
             portals: Some(session.portals()),
             notices_tx: session.retain_notice_transmitter(),
             restrict_to_user_objects: session.vars().restrict_to_user_objects(),
+            rbac_checks_enabled: rbac::is_rbac_enforced_for_session(self.system_config(), session),
         }
     }
 
@@ -389,6 +390,7 @@ impl CatalogState {
             portals: None,
             notices_tx,
             restrict_to_user_objects: false,
+            rbac_checks_enabled: false,
         }
     }
 
@@ -452,8 +454,9 @@ impl CatalogState {
                         }
                     }
                 }
-                CatalogItem::Sink(sink) => {
-                    let from_item_id = self.get_entry_by_global_id(&sink.from).id();
+                CatalogItem::Sink(Sink { from, .. })
+                | CatalogItem::Notification(Notification { from, .. }) => {
+                    let from_item_id = self.get_entry_by_global_id(from).id();
                     if seen.insert(from_item_id) {
                         queue.push_back(from_item_id);
                     }
@@ -1250,6 +1253,9 @@ impl CatalogState {
                                 ));
                             }
                         },
+                        mz_sql::plan::TableDataSource::Snapshot { from, as_of } => {
+                            TableDataSource::Snapshot { from, as_of }
+                        }
                     },
                 })
             }
@@ -1474,6 +1480,7 @@ impl CatalogState {
                     raw_expr,
                     locally_optimized_expr: optimized_expr,
                     desc,
+                    conn_id: None,
                     resolved_ids,
                     dependencies,
                     replacement_target: materialized_view.replacement_target,
@@ -1520,6 +1527,18 @@ impl CatalogState {
                 cluster_id: in_cluster,
                 commit_interval: sink.commit_interval,
             }),
+            Plan::CreateNotification(CreateNotificationPlan {
+                notification,
+                in_cluster,
+                ..
+            }) => CatalogItem::Notification(Notification {
+                create_sql: notification.create_sql,
+                global_id,
+                from: notification.from,
+                desc: notification.desc,
+                resolved_ids,
+                cluster_id: in_cluster,
+            }),
             Plan::CreateType(CreateTypePlan { typ, .. }) => {
                 // Even if we don't need the `RelationDesc` here, error out
                 // early and eagerly, as a kind of soft assertion that we _can_
@@ -2707,7 +2726,8 @@ impl CatalogState {
                         }
                         // Regular tables handle compaction directly in
                         // catalog_implications, not through this function.
-                        TableDataSource::TableWrites { .. } => {}
+                        // Snapshots never compact past their pinned time.
+                        TableDataSource::TableWrites { .. } | TableDataSource::Snapshot { .. } => {}
                         TableDataSource::DataSource {
                             desc:
                                 DataSourceDesc::Ingestion { .. }
@@ -93,6 +93,7 @@ impl Catalog {
                         }
                         CatalogItem::View(_)
                         | CatalogItem::Sink(_)
+                        | CatalogItem::Notification(_)
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
                         | CatalogItem::Secret(_)
@@ -230,6 +231,7 @@ impl Catalog {
                         ));
                     }
                     CatalogItem::Sink(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
@@ -39,7 +39,7 @@ use mz_catalog::memory::error::{AmbiguousRename, Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterConfig, ClusterVariant, DataSourceDesc, DefaultPrivileges,
     ReconfigurationState, ReconfigurationStatus, ReconfigurationTarget, SourceReferences,
-    StateDiff, StateUpdate, StateUpdateKind, TemporaryItem,
+    StateDiff, StateUpdate, StateUpdateKind, TableDataSource, TemporaryItem,
 };
 use mz_controller::clusters::{ManagedReplicaLocation, ReplicaConfig, ReplicaLocation};
 use mz_controller_types::{ClusterId, ReplicaId};
@@ -129,6 +129,9 @@ pub enum Op {
         name: ColumnName,
         typ: SqlColumnType,
         sql: RawDataType,
+        /// For tables that read from a source, the `DETAILS` option of the table once the column
+        /// has been added.
+        source_export_details: Option<String>,
     },
     AlterMaterializedViewApplyReplacement {
         id: CatalogItemId,
@@ -864,6 +867,7 @@ impl Catalog {
                     | CatalogItem::Source(_)
                     | CatalogItem::Log(_)
                     | CatalogItem::Sink(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
@@ -1253,19 +1257,32 @@ impl Catalog {
                 name,
                 typ,
                 sql,
+                source_export_details,
             } => {
                 let column_name = name.to_string();
                 let column_type = typ.to_string();
                 let nullable = typ.nullable;
                 let mut new_entry = state.get_entry(&id).clone();
+                // Source tables are only altered to follow their upstream table, which requires
+                // the description of the upstream table to change along with them.
+                if let CatalogItem::Table(table) = &new_entry.item
+                    && matches!(table.data_source, TableDataSource::DataSource { .. })
+                    && source_export_details.is_none()
+                {
+                    return Err(AdapterError::Unsupported(
+                        "adding columns to tables that read from sources",
+                    ));
+                }
                 let version = new_entry.item.add_column(name, typ, sql)?;
+                if let Some(details) = source_export_details {
+                    new_entry.item.set_source_export_details(details)?;
+                }
                 // All versions of a table share the same shard, so it shouldn't matter what
                 // GlobalId we use here.
                 let shard_id = state
                     .storage_metadata()
                     .get_collection_shard(new_entry.latest_global_id())?;
 
-                // TODO(alter_table): Support adding columns to sources.
                 let CatalogItem::Table(table) = &mut new_entry.item else {
                     return Err(AdapterError::Unsupported("adding columns to non-Table"));
                 };
@@ -1655,7 +1672,14 @@ impl Catalog {
                     CatalogItem::Table(table) => {
                         let gids: Vec<_> = table.global_ids().collect();
                         assert_eq!(gids.len(), 1);
-                        storage_collections_to_create.extend(gids);
+                        // Snapshots read the shard of the materialized view they
+                        // were taken from, rather than owning a shard of their own.
+                        if let TableDataSource::Snapshot { from, .. } = &table.data_source {
+                            let shard_id = state.storage_metadata().get_collection_shard(*from)?;
+                            storage_collections_to_register.insert(gids[0], shard_id);
+                        } else {
+                            storage_collections_to_create.extend(gids);
+                        }
                     }
                     CatalogItem::Source(source) => {
                         storage_collections_to_create.insert(source.global_id());
@@ -1676,6 +1700,7 @@ impl Catalog {
                     }
                     CatalogItem::Log(_)
                     | CatalogItem::View(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
@@ -1683,6 +1708,15 @@ impl Catalog {
                     | CatalogItem::Connection(_) => (),
                 }
 
+                // Temporary items are not durable, so record their storage
+                // collections for the next process to drop them if this one
+                // exits before the session that created them does.
+                if item.is_temporary() && item.is_storage_collection() {
+                    for gid in item.global_ids() {
+                        tx.insert_temporary_collection(gid)?;
+                    }
+                }
+
                 let system_user = session.map_or(false, |s| s.user().is_system_user());
                 if !system_user {
                     if let Some(id @ ClusterId::System(_)) = item.cluster_id() {
@@ -1839,6 +1873,14 @@ impl Catalog {
                                 external_type: s.sink_type().to_string(),
                             })
                         }
+                        CatalogItem::Notification(n) => {
+                            EventDetails::CreateSourceSinkV4(mz_audit_log::CreateSourceSinkV4 {
+                                id: id.to_string(),
+                                cluster_id: Some(n.cluster_id.to_string()),
+                                name,
+                                external_type: "notification".to_string(),
+                            })
+                        }
                         CatalogItem::Index(i) => {
                             EventDetails::CreateIndexV1(mz_audit_log::CreateIndexV1 {
                                 id: id.to_string(),
@@ -2010,6 +2052,11 @@ impl Catalog {
 
                     if entry.item().is_storage_collection() {
                         storage_collections_to_drop.extend(entry.global_ids());
+                        if entry.item().is_temporary() {
+                            for gid in entry.global_ids() {
+                                tx.remove_temporary_collection(gid)?;
+                            }
+                        }
                     }
 
                     if state.source_references.contains_key(&item_id) {
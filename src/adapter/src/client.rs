@@ -19,7 +19,7 @@ use anyhow::bail;
 use chrono::{DateTime, Utc};
 use derivative::Derivative;
 use futures::{Stream, StreamExt};
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
 use mz_auth::password::Password;
 use mz_auth::{Authenticated, AuthenticatorKind};
@@ -62,7 +62,8 @@ use crate::coord::{Coordinator, ExecuteContextGuard};
 use crate::error::AdapterError;
 use crate::metrics::{self, Metrics};
 use crate::session::{
-    EndTransactionAction, PreparedStatement, Session, SessionConfig, StateRevision, TransactionId,
+    EndTransactionAction, Notification, PreparedStatement, Session, SessionConfig, StateRevision,
+    TransactionId,
 };
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::telemetry::{self, EventDetails, SegmentClientExt, StatementFailureType};
@@ -242,6 +243,7 @@ impl Client {
         let client_ip = session.client_ip();
         let application_name = session.application_name().into();
         let notice_tx = session.retain_notice_transmitter();
+        let notification_tx = session.retain_notification_transmitter();
 
         let (tx, rx) = oneshot::channel();
 
@@ -266,6 +268,7 @@ impl Client {
             client_ip: client_ip.copied(),
             application_name,
             notice_tx,
+            notification_tx,
         });
 
         // When startup fails, no need to call terminate (handle_startup does this). Delay creating
@@ -1455,6 +1458,30 @@ impl SessionClient {
         self.timeouts.recv().await
     }
 
+    /// Awaits the next timeout or, outside of a transaction, the next
+    /// notification for a channel the session is listening on. Timeouts take
+    /// precedence over notifications.
+    ///
+    /// # Cancel safety
+    ///
+    /// This method is cancel safe, for the same reasons as
+    /// [`SessionClient::recv_timeout`].
+    pub async fn recv_timeout_or_notification(&mut self) -> Either<TimeoutType, Notification> {
+        let session = self.session.as_mut().expect("SessionClient invariant");
+        // Like PostgreSQL, hold notifications back until the current
+        // transaction, if any, ends.
+        let deliver_notifications = session.transaction().inner().is_none();
+        tokio::select! {
+            biased;
+
+            Some(timeout) = self.timeouts.recv() => Either::Left(timeout),
+            notification = session.recv_notification(), if deliver_notifications => {
+                Either::Right(notification)
+            }
+            else => std::future::pending().await,
+        }
+    }
+
     /// Attempt to sequence a peek from the session task.
     ///
     /// Returns `Ok(Some(response))` if we handled the peek, or `Ok(None)` to fall back to the
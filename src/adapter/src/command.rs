@@ -53,7 +53,7 @@ use crate::coord::peek::{PeekDataflowPlan, PeekResponseUnary};
 use crate::coord::timestamp_selection::TimestampDetermination;
 use crate::coord::{ExecuteContextExtra, ExecuteContextGuard};
 use crate::error::AdapterError;
-use crate::session::{EndTransactionAction, RowBatchStream, Session};
+use crate::session::{EndTransactionAction, Notification, RowBatchStream, Session};
 use crate::statement_logging::{
     FrontendStatementLoggingEvent, StatementEndedExecutionReason, StatementExecutionStrategy,
     StatementLoggingFrontend,
@@ -99,6 +99,7 @@ pub enum Command {
         uuid: Uuid,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::Sender<Notification>,
     },
 
     AuthenticatePassword {
@@ -613,6 +614,8 @@ pub enum ExecuteResponse {
     CreatedIndex,
     /// The requested introspection subscribe was created.
     CreatedIntrospectionSubscribe,
+    /// The requested notification was created.
+    CreatedNotification,
     /// The requested secret was created.
     CreatedSecret,
     /// The requested sink was created.
@@ -631,6 +634,8 @@ pub enum ExecuteResponse {
     CreatedType,
     /// The requested network policy was created.
     CreatedNetworkPolicy,
+    /// The requested row level security policy was created.
+    CreatedPolicy,
     /// The requested prepared statement was removed.
     Deallocate { all: bool },
     /// The requested cursor was declared.
@@ -645,6 +650,8 @@ pub enum ExecuteResponse {
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested row level security policy was dropped.
+    DroppedPolicy,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -663,6 +670,8 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The session started listening on the requested channel.
+    Listen,
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -694,6 +703,8 @@ pub enum ExecuteResponse {
     },
     /// A new transaction was started.
     StartedTransaction,
+    /// The session stopped listening on the requested channels.
+    Unlisten,
     /// Updates to the requested source or view will be streamed to the
     /// contained receiver.
     Subscribing {
@@ -789,6 +800,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedIndex => Ok(ExecuteResponse::CreatedIndex),
             ExecuteResponseKind::CreatedSecret => Ok(ExecuteResponse::CreatedSecret),
             ExecuteResponseKind::CreatedSink => Ok(ExecuteResponse::CreatedSink),
+            ExecuteResponseKind::CreatedNotification => Ok(ExecuteResponse::CreatedNotification),
             ExecuteResponseKind::CreatedSource => Ok(ExecuteResponse::CreatedSource),
             ExecuteResponseKind::CreatedTable => Ok(ExecuteResponse::CreatedTable),
             ExecuteResponseKind::CreatedView => Ok(ExecuteResponse::CreatedView),
@@ -797,6 +809,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
                 Ok(ExecuteResponse::CreatedMaterializedView)
             }
             ExecuteResponseKind::CreatedNetworkPolicy => Ok(ExecuteResponse::CreatedNetworkPolicy),
+            ExecuteResponseKind::CreatedPolicy => Ok(ExecuteResponse::CreatedPolicy),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
@@ -805,11 +818,13 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedPolicy => Ok(ExecuteResponse::DroppedPolicy),
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listen => Ok(ExecuteResponse::Listen),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -820,6 +835,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Unlisten => Ok(ExecuteResponse::Unlisten),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsStreaming => Err(()),
@@ -853,6 +869,7 @@ impl ExecuteResponse {
             CreatedIndex { .. } => Some("CREATE INDEX".into()),
             CreatedSecret { .. } => Some("CREATE SECRET".into()),
             CreatedSink { .. } => Some("CREATE SINK".into()),
+            CreatedNotification => Some("CREATE NOTIFICATION".into()),
             CreatedSource { .. } => Some("CREATE SOURCE".into()),
             CreatedTable { .. } => Some("CREATE TABLE".into()),
             CreatedView { .. } => Some("CREATE VIEW".into()),
@@ -860,6 +877,7 @@ impl ExecuteResponse {
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
             CreatedType => Some("CREATE TYPE".into()),
             CreatedNetworkPolicy => Some("CREATE NETWORKPOLICY".into()),
+            CreatedPolicy => Some("CREATE POLICY".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
@@ -867,6 +885,7 @@ impl ExecuteResponse {
             DiscardedAll => Some("DISCARD ALL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedPolicy => Some("DROP POLICY".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -881,6 +900,7 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listen => Some("LISTEN".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -893,6 +913,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Unlisten => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
             CreatedIntrospectionSubscribe => Some("CREATE INTROSPECTION SUBSCRIBE".into()),
@@ -925,7 +946,8 @@ impl ExecuteResponse {
             | AlterSink
             | AlterTableAddColumn
             | AlterMaterializedViewApplyReplacement
-            | AlterNetworkPolicy => &[AlteredObject],
+            | AlterNetworkPolicy
+            | AlterRowLevelSecurity => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
             AlterSetCluster => &[AlteredObject],
             AlterRole => &[AlteredRole],
@@ -946,6 +968,7 @@ impl ExecuteResponse {
             CreateSource | CreateSources => &[CreatedSource],
             CreateSecret => &[CreatedSecret],
             CreateSink => &[CreatedSink],
+            CreateNotification => &[CreatedNotification],
             CreateTable => &[CreatedTable],
             CreateView => &[CreatedView],
             CreateMaterializedView => &[CreatedMaterializedView],
@@ -953,11 +976,13 @@ impl ExecuteResponse {
             CreateType => &[CreatedType],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
+            CreatePolicy => &[CreatedPolicy],
             Declare => &[DeclaredCursor],
             DiscardTemp => &[DiscardedTemp],
             DiscardAll => &[DiscardedAll],
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
+            DropPolicy => &[DroppedPolicy],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
             | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
@@ -976,6 +1001,7 @@ impl ExecuteResponse {
             GrantPrivileges => &[GrantedPrivilege],
             GrantRole => &[GrantedRole],
             Insert => &[Inserted, SendingRowsImmediate],
+            PlanKind::Listen => &[ExecuteResponseKind::Listen],
             PlanKind::Prepare => &[ExecuteResponseKind::Prepare],
             PlanKind::Raise => &[ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => &[ExecuteResponseKind::ReassignOwned],
@@ -986,6 +1012,7 @@ impl ExecuteResponse {
             }
             PlanKind::Subscribe => &[Subscribing, ExecuteResponseKind::CopyTo],
             StartTransaction => &[StartedTransaction],
+            PlanKind::Unlisten => &[ExecuteResponseKind::Unlisten],
             SideEffectingFunc => &[SendingRowsStreaming, SendingRowsImmediate],
             ValidateConnection => &[ExecuteResponseKind::ValidatedConnection],
         }
@@ -131,9 +131,7 @@ use mz_ore::task::{JoinHandle, spawn};
 use mz_ore::thread::JoinHandleExt;
 use mz_ore::tracing::{OpenTelemetryContext, TracingHandle};
 use mz_ore::url::SensitiveUrl;
-use mz_ore::{
-    assert_none, instrument, soft_assert_eq_or_log, soft_assert_or_log, soft_panic_or_log, stack,
-};
+use mz_ore::{assert_none, instrument, soft_assert_or_log, soft_panic_or_log, stack};
 use mz_persist_client::PersistClient;
 use mz_persist_client::batch::ProtoBatch;
 use mz_persist_client::usage::{ShardsUsageReferenced, StorageUsageClient};
@@ -163,6 +161,7 @@ use mz_storage_types::connections::Connection as StorageConnection;
 use mz_storage_types::connections::ConnectionContext;
 use mz_storage_types::connections::inline::{IntoInlineConnection, ReferencedConnection};
 use mz_storage_types::read_holds::ReadHold;
+use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sinks::{S3SinkFormat, StorageSinkDesc};
 use mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC;
 use mz_storage_types::sources::{IngestionDescription, SourceExport, Timeline};
@@ -196,6 +195,7 @@ use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
+use crate::coord::notifications::NotificationSubscribe;
 use crate::coord::peek::PendingPeek;
 use crate::coord::statement_logging::StatementLogging;
 use crate::coord::timeline::{TimelineContext, TimelineState};
@@ -207,7 +207,7 @@ use crate::explain::optimizer_trace::{DispatchGuard, OptimizerTrace};
 use crate::metrics::Metrics;
 use crate::optimize::dataflows::{ComputeInstanceSnapshot, DataflowBuilder};
 use crate::optimize::{self, Optimize, OptimizerConfig};
-use crate::session::{EndTransactionAction, Session};
+use crate::session::{EndTransactionAction, Notification, Session};
 use crate::statement_logging::{
     StatementEndedExecutionReason, StatementLifecycleEvent, StatementLoggingId,
 };
@@ -239,7 +239,10 @@ mod indexes;
 mod info_metrics;
 mod introspection;
 mod message_handler;
+mod notifications;
 mod privatelink_status;
+mod snapshots;
+mod source_table_schemas;
 mod sql;
 mod validity;
 
@@ -355,11 +358,14 @@ pub enum Message {
     },
     StorageUsageSchedule,
     StorageUsageFetch,
-    StorageUsageUpdate(ShardsUsageReferenced),
+    StorageUsageUpdate(ShardsUsageReferenced, BTreeMap<CatalogItemId, u64>),
     StorageUsagePrune(Vec<BuiltinTableUpdate>),
     ArrangementSizesSchedule,
     ArrangementSizesSnapshot,
     ArrangementSizesPrune(Vec<BuiltinTableUpdate>),
+    SourceTableSchemaSchedule,
+    SourceTableSchemaCheck,
+    SourceTableSchemaChanges(Vec<(CatalogItemId, Vec<mz_sql::pure::SourceTableAddedColumn>)>),
     /// Performs any cleanup and logging actions necessary for
     /// finalizing a statement execution.
     RetireExecute {
@@ -506,11 +512,14 @@ impl Message {
             Message::StagedBatches { .. } => "staged_batches",
             Message::StorageUsageSchedule => "storage_usage_schedule",
             Message::StorageUsageFetch => "storage_usage_fetch",
-            Message::StorageUsageUpdate(_) => "storage_usage_update",
+            Message::StorageUsageUpdate(..) => "storage_usage_update",
             Message::StorageUsagePrune(_) => "storage_usage_prune",
             Message::ArrangementSizesSchedule => "arrangement_sizes_schedule",
             Message::ArrangementSizesSnapshot => "arrangement_sizes_snapshot",
             Message::ArrangementSizesPrune(_) => "arrangement_sizes_prune",
+            Message::SourceTableSchemaSchedule => "source_table_schema_schedule",
+            Message::SourceTableSchemaCheck => "source_table_schema_check",
+            Message::SourceTableSchemaChanges(_) => "source_table_schema_changes",
             Message::RetireExecute { .. } => "retire_execute",
             Message::ExecuteSingleStatementTransaction { .. } => {
                 "execute_single_statement_transaction"
@@ -1302,6 +1311,13 @@ pub struct ConnMeta {
     #[serde(skip)]
     notice_tx: mpsc::UnboundedSender<AdapterNotice>,
 
+    /// Channel on which to send asynchronous notifications to a session.
+    #[serde(skip)]
+    notification_tx: mpsc::Sender<Notification>,
+
+    /// Channels on which the session is listening for notifications.
+    listen_channels: BTreeSet<String>,
+
     /// The role that initiated the database context. Fixed for the duration of the connection.
     /// WARNING: This role reference is not updated when the role is dropped.
     /// Consumers should not assume that this role exist.
@@ -2022,6 +2038,8 @@ pub struct Coordinator {
     connection_cancel_watches: BTreeMap<ConnectionId, (watch::Sender<bool>, watch::Receiver<bool>)>,
     /// Active introspection subscribes.
     introspection_subscribes: BTreeMap<GlobalId, IntrospectionSubscribe>,
+    /// Subscribes maintaining notification sinks, keyed by the subscribe ID.
+    notification_subscribes: BTreeMap<GlobalId, NotificationSubscribe>,
 
     /// Locks that grant access to a specific object, populated lazily as objects are written to.
     write_locks: BTreeMap<CatalogItemId, Arc<tokio::sync::Mutex<()>>>,
@@ -2066,6 +2084,8 @@ pub struct Coordinator {
     storage_usage_client: StorageUsageClient,
     /// The interval at which to collect storage usage information.
     storage_usage_collection_interval: Duration,
+    /// The most recently collected size and collection time of every snapshot.
+    snapshot_storage_usage: BTreeMap<CatalogItemId, (u64, EpochMillis)>,
 
     /// Segment analytics client.
     #[derivative(Debug = "ignore")]
@@ -2440,6 +2460,10 @@ impl Coordinator {
 
         let mut policies_to_set: BTreeMap<CompactionWindow, CollectionIdBundle> =
             Default::default();
+        // Snapshots are pinned to a fixed time, rather than following a
+        // compaction window.
+        let mut snapshot_policies_to_set: Vec<(CatalogItemId, ReadPolicy)> = Vec::new();
+        let mut snapshots_to_pin = Vec::new();
 
         let enable_worker_core_affinity =
             self.catalog().system_config().enable_worker_core_affinity();
@@ -2583,11 +2607,19 @@ impl Coordinator {
                         .insert(source.global_id());
                 }
                 CatalogItem::Table(table) => {
-                    policies_to_set
-                        .entry(policy.expect("tables have a compaction window"))
-                        .or_insert_with(Default::default)
-                        .storage_ids
-                        .extend(table.global_ids());
+                    if let TableDataSource::Snapshot { as_of, .. } = &table.data_source {
+                        snapshot_policies_to_set.push((
+                            entry.id(),
+                            ReadPolicy::ValidFrom(Antichain::from_elem(*as_of)),
+                        ));
+                        snapshots_to_pin.push((table.global_id_writes(), *as_of));
+                    } else {
+                        policies_to_set
+                            .entry(policy.expect("tables have a compaction window"))
+                            .or_insert_with(Default::default)
+                            .storage_ids
+                            .extend(table.global_ids());
+                    }
                 }
                 CatalogItem::Index(idx) => {
                     let policy_entry = policies_to_set
@@ -2710,7 +2742,10 @@ impl Coordinator {
                     }
                 }
                 // Nothing to do for these cases
-                CatalogItem::Log(_)
+                // Notifications are installed by the coordinator once the
+                // compute controller is ready, see `bootstrap_notification_subscribes`.
+                CatalogItem::Notification(_)
+                | CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_) => {}
@@ -2749,6 +2784,18 @@ impl Coordinator {
         for (cw, policies) in policies_to_set {
             self.initialize_read_policies(&policies, cw).await;
         }
+        self.update_storage_read_policies(snapshot_policies_to_set);
+        // Pin the snapshots again, in case the process crashed between
+        // creating a snapshot and pinning it.
+        for (global_id, as_of) in snapshots_to_pin {
+            let metadata = self
+                .controller
+                .storage
+                .collection_metadata(global_id)
+                .expect("snapshot collection exists");
+            self.pin_snapshot(global_id, metadata.data_shard, as_of)
+                .await;
+        }
 
         // Expose mapping from T-shirt sizes to actual sizes
         builtin_table_updates.extend(
@@ -2942,6 +2989,9 @@ impl Coordinator {
         // Initialize unified introspection.
         self.bootstrap_introspection_subscribes().await;
 
+        // Install the subscribes that maintain notification sinks.
+        self.bootstrap_notification_subscribes().await;
+
         info!(
             "startup: coordinator init: bootstrap: migrate builtin tables in read-only mode complete in {:?}",
             final_steps_start.elapsed()
@@ -3213,22 +3263,50 @@ impl Coordinator {
                             desc: data_source_desc,
                             timeline,
                         } => {
-                            // TODO(alter_table): Support versioning tables that read from sources.
-                            soft_assert_eq_or_log!(table.collections.len(), 1);
-                            let collection_descs =
-                                table.collection_descs().map(|(gid, _version, desc)| {
-                                    (
-                                        gid,
+                            // Like tables with writes, the latest version owns the shard and
+                            // is the only one fed by the source. Earlier versions, left behind
+                            // by columns being added upstream, remain readable at their own
+                            // description.
+                            let versions: BTreeMap<_, _> = table
+                                .collection_descs()
+                                .map(|(gid, version, desc)| (version, (gid, desc)))
+                                .collect();
+                            let collection_descs = versions.iter().map(|(version, (gid, desc))| {
+                                let next_version = version.bump();
+                                match versions.get(&next_version) {
+                                    Some((next_gid, _desc)) => {
+                                        let collection_desc = CollectionDescription {
+                                            desc: desc.clone(),
+                                            data_source: DataSource::Other,
+                                            since: None,
+                                            timeline: Some(timeline.clone()),
+                                            primary: Some(*next_gid),
+                                        };
+                                        (*gid, collection_desc)
+                                    }
+                                    None => (
+                                        *gid,
                                         source_desc(
                                             entry.latest_global_id(),
                                             data_source_desc,
-                                            &desc,
+                                            desc,
                                             timeline,
                                         ),
-                                    )
-                                });
+                                    ),
+                                }
+                            });
                             collections.extend(collection_descs);
                         }
+                        TableDataSource::Snapshot { from, as_of } => {
+                            let collection_desc = CollectionDescription {
+                                desc: table.desc.latest(),
+                                data_source: DataSource::Other,
+                                since: Some(Antichain::from_elem(*as_of)),
+                                timeline: Some(table.timeline()),
+                                primary: Some(*from),
+                            };
+                            collections.push((table.global_id_writes(), collection_desc));
+                        }
                     };
                 }
                 CatalogItem::MaterializedView(mv) => {
@@ -3290,6 +3368,7 @@ impl Coordinator {
                 }
                 CatalogItem::Log(_)
                 | CatalogItem::View(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Index(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
@@ -3690,6 +3769,7 @@ impl Coordinator {
                 | CatalogItem::Log(_)
                 | CatalogItem::View(_)
                 | CatalogItem::Sink(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
@@ -3722,6 +3802,7 @@ impl Coordinator {
                 | CatalogItem::Log(_)
                 | CatalogItem::View(_)
                 | CatalogItem::Sink(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
@@ -3833,6 +3914,7 @@ impl Coordinator {
 
             self.schedule_storage_usage_collection().await;
             self.schedule_arrangement_sizes_collection().await;
+            self.schedule_source_table_schema_check();
             self.spawn_privatelink_vpc_endpoints_watch_task();
             self.spawn_statement_logging_task();
             self.spawn_catalog_info_metrics_task();
@@ -5045,6 +5127,7 @@ pub fn serve(
                     active_copies: BTreeMap::new(),
                     connection_cancel_watches: BTreeMap::new(),
                     introspection_subscribes: BTreeMap::new(),
+                    notification_subscribes: BTreeMap::new(),
                     write_locks: BTreeMap::new(),
                     deferred_write_ops: BTreeMap::new(),
                     pending_writes: Vec::new(),
@@ -5054,6 +5137,7 @@ pub fn serve(
                     cloud_resource_controller,
                     storage_usage_client,
                     storage_usage_collection_interval,
+                    snapshot_storage_usage: BTreeMap::new(),
                     segment_client,
                     metrics,
                     catalog_info_metrics_registry: metrics_registry.clone(),
@@ -982,6 +982,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
         | Plan::CreateSink(_)
+        | Plan::CreateNotification(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
         | Plan::CreateMaterializedView(_)
@@ -1020,6 +1021,9 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterSetCluster(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
+        | Plan::AlterRowLevelSecurity(_)
+        | Plan::CreatePolicy(_)
+        | Plan::DropPolicy(_)
         | Plan::AlterSourceTimestampInterval(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
@@ -1038,6 +1042,8 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::Prepare(_)
         | Plan::Execute(_)
         | Plan::Deallocate(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
         | Plan::Raise(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
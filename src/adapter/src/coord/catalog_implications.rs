@@ -35,7 +35,7 @@ use itertools::Itertools;
 use mz_adapter_types::compaction::CompactionWindow;
 use mz_catalog::memory::objects::{
     CatalogItem, Cluster, ClusterReplica, Connection, DataSourceDesc, Index, MaterializedView,
-    Secret, Sink, Source, StateDiff, Table, TableDataSource, View,
+    Notification, Secret, Sink, Source, StateDiff, Table, TableDataSource, View,
 };
 use mz_cloud_resources::VpcEndpointConfig;
 use mz_compute_client::logging::LogVariant;
@@ -53,10 +53,12 @@ use mz_sql::plan::ConnectionDetails;
 use mz_storage_client::controller::{CollectionDescription, DataSource};
 use mz_storage_types::connections::PostgresConnection;
 use mz_storage_types::connections::inline::{InlinedConnection, IntoInlineConnection};
+use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sinks::StorageSinkConnection;
 use mz_storage_types::sources::{
     GenericSourceConnection, SourceDesc, SourceExport, SourceExportDataConfig,
 };
+use timely::progress::Antichain;
 use tracing::{Instrument, info_span, warn};
 
 use crate::active_compute_sink::ActiveComputeSinkRetireReason;
@@ -236,6 +238,7 @@ impl Coordinator {
         let mut sources_to_drop = vec![];
         let mut replication_slots_to_drop: Vec<(PostgresConnection, String)> = vec![];
         let mut storage_sink_gids_to_drop = vec![];
+        let mut notification_gids_to_drop = vec![];
         let mut indexes_to_drop = vec![];
         let mut compute_sinks_to_drop = vec![];
         let mut view_gids_to_drop = vec![];
@@ -256,7 +259,10 @@ impl Coordinator {
         let mut table_collections_to_create = BTreeMap::new();
         let mut source_collections_to_create = BTreeMap::new();
         let mut storage_policies_to_initialize = BTreeMap::new();
+        let mut storage_read_policies_to_set = Vec::new();
         let mut execution_timestamps_to_set = BTreeSet::new();
+        let mut snapshots_to_pin = vec![];
+        let mut snapshots_to_release = vec![];
         let mut vpc_endpoints_to_create: Vec<(CatalogItemId, VpcEndpointConfig)> = vec![];
 
         // Sources that shouldn't be dropped, even if we saw a `Dropped` event.
@@ -285,10 +291,14 @@ impl Coordinator {
 
             match implication {
                 CatalogImplication::Table(CatalogImplicationKind::Added(table)) => {
+                    if let TableDataSource::Snapshot { as_of, .. } = &table.data_source {
+                        snapshots_to_pin.push((table.global_id_writes(), *as_of));
+                    }
                     self.handle_create_table(
                         &ctx,
                         &mut table_collections_to_create,
                         &mut storage_policies_to_initialize,
+                        &mut storage_read_policies_to_set,
                         &mut execution_timestamps_to_set,
                         catalog_id,
                         table.clone(),
@@ -304,9 +314,22 @@ impl Coordinator {
                 }
 
                 CatalogImplication::Table(CatalogImplicationKind::Dropped(table, full_name)) => {
+                    // Snapshots are not written to, so the storage controller
+                    // drops them like any other read-only collection.
+                    let is_snapshot = matches!(table.data_source, TableDataSource::Snapshot { .. });
                     let global_ids = table.global_ids();
                     for global_id in global_ids {
-                        tables_to_drop.insert((catalog_id, global_id));
+                        if is_snapshot {
+                            let metadata = self
+                                .controller
+                                .storage
+                                .collection_metadata(global_id)
+                                .expect("snapshot collection exists");
+                            snapshots_to_release.push((global_id, metadata.data_shard));
+                            sources_to_drop.push((catalog_id, global_id));
+                        } else {
+                            tables_to_drop.insert((catalog_id, global_id));
+                        }
                         dropped_item_names.insert(global_id, full_name.clone());
                     }
                 }
@@ -412,6 +435,23 @@ impl Coordinator {
                     storage_sink_gids_to_drop.push(sink.global_id());
                     dropped_item_names.insert(sink.global_id(), full_name);
                 }
+                CatalogImplication::Notification(CatalogImplicationKind::Added(_notification)) => {
+                    // No action needed: the subscribe maintaining the
+                    // notification is installed when sequencing its creation.
+                }
+                CatalogImplication::Notification(CatalogImplicationKind::Altered {
+                    prev: _prev_notification,
+                    new: _new_notification,
+                }) => {
+                    // No action needed: renaming a notification renames its
+                    // channel, which is looked up on every delivery.
+                }
+                CatalogImplication::Notification(CatalogImplicationKind::Dropped(
+                    notification,
+                    _full_name,
+                )) => {
+                    notification_gids_to_drop.push(notification.global_id());
+                }
                 CatalogImplication::Index(CatalogImplicationKind::Added(index)) => {
                     tracing::debug!(?index, "not handling AddIndex in here yet");
                 }
@@ -586,6 +626,7 @@ impl Coordinator {
                 CatalogImplication::Table(CatalogImplicationKind::None)
                 | CatalogImplication::Source(CatalogImplicationKind::None)
                 | CatalogImplication::Sink(CatalogImplicationKind::None)
+                | CatalogImplication::Notification(CatalogImplicationKind::None)
                 | CatalogImplication::Index(CatalogImplicationKind::None)
                 | CatalogImplication::MaterializedView(CatalogImplicationKind::None)
                 | CatalogImplication::View(CatalogImplicationKind::None)
@@ -744,6 +785,18 @@ impl Coordinator {
         // policy that might make the since advance.
         self.initialize_storage_collections(storage_policies_to_initialize)
             .await?;
+        if !storage_read_policies_to_set.is_empty() {
+            self.update_storage_read_policies(storage_read_policies_to_set);
+        }
+        for (global_id, as_of) in snapshots_to_pin {
+            let metadata = self
+                .controller
+                .storage
+                .collection_metadata(global_id)
+                .expect("snapshot collection was just created");
+            self.pin_snapshot(global_id, metadata.data_shard, as_of)
+                .await;
+        }
 
         // Create VPC endpoints for AWS PrivateLink connections
         if !vpc_endpoints_to_create.is_empty() {
@@ -954,10 +1007,18 @@ impl Coordinator {
                 self.drop_sources(sources_to_drop);
             }
 
+            for (global_id, shard_id) in snapshots_to_release {
+                self.release_snapshot(global_id, shard_id).await;
+            }
+
             if !storage_sink_gids_to_drop.is_empty() {
                 self.drop_storage_sinks(storage_sink_gids_to_drop);
             }
 
+            if !notification_gids_to_drop.is_empty() {
+                self.drop_notification_subscribes(notification_gids_to_drop);
+            }
+
             if !active_compute_sinks_to_drop.is_empty() {
                 let retire_notify = self
                     .retire_compute_sinks(active_compute_sinks_to_drop)
@@ -1183,6 +1244,7 @@ impl Coordinator {
         ctx: &Option<&mut ExecuteContext>,
         storage_collections_to_create: &mut BTreeMap<GlobalId, CollectionDescription>,
         storage_policies_to_initialize: &mut BTreeMap<CompactionWindow, BTreeSet<GlobalId>>,
+        storage_read_policies_to_set: &mut Vec<(CatalogItemId, ReadPolicy)>,
         execution_timestamps_to_set: &mut BTreeSet<StatementLoggingId>,
         table_id: CatalogItemId,
         table: Table,
@@ -1317,6 +1379,30 @@ impl Coordinator {
                     _ => unreachable!("CREATE TABLE data source got {:?}", data_source_desc),
                 }
             }
+            TableDataSource::Snapshot { from, as_of } => {
+                // Snapshots are read-only collections backed by the shard of
+                // the materialized view they were taken from. The read policy
+                // keeps the collection of the snapshot readable at its `AS OF`
+                // while it is installed; the shard itself is pinned by the
+                // critical since handle of the snapshot.
+                let collection_desc = CollectionDescription {
+                    desc: table.desc.latest(),
+                    data_source: DataSource::Other,
+                    since: Some(Antichain::from_elem(*as_of)),
+                    timeline: Some(table.timeline()),
+                    primary: Some(*from),
+                };
+
+                let global_id = table
+                    .global_ids()
+                    .expect_element(|| "snapshots cannot have multiple versions");
+
+                storage_collections_to_create.insert(global_id, collection_desc);
+                storage_read_policies_to_set.push((
+                    table_id,
+                    ReadPolicy::ValidFrom(Antichain::from_elem(*as_of)),
+                ));
+            }
         }
 
         Ok(())
@@ -1372,17 +1458,39 @@ impl Coordinator {
             .unwrap_or_terminate("unable to advance catalog upper");
 
         // Alter the table description, creating a "new" collection.
-        self.controller
-            .storage
-            .alter_table_desc(
-                existing_gid,
-                new_gid,
-                new_desc,
-                expected_version,
-                register_ts,
-            )
-            .await
-            .expect("failed to alter desc of table");
+        match &new_table.data_source {
+            TableDataSource::DataSource {
+                desc: DataSourceDesc::IngestionExport { details, .. },
+                ..
+            } => {
+                // The ingestion takes over writing to the shard with the new
+                // description.
+                self.controller
+                    .storage
+                    .alter_ingestion_export_desc(
+                        existing_gid,
+                        new_gid,
+                        new_desc,
+                        details.clone(),
+                        expected_version,
+                    )
+                    .await
+                    .expect("failed to alter desc of source table");
+            }
+            _ => {
+                self.controller
+                    .storage
+                    .alter_table_desc(
+                        existing_gid,
+                        new_gid,
+                        new_desc,
+                        expected_version,
+                        register_ts,
+                    )
+                    .await
+                    .expect("failed to alter desc of table");
+            }
+        }
 
         // Initialize the ReadPolicy which ensures we have the correct read holds.
         let compaction_window = new_table
@@ -1607,6 +1715,7 @@ impl Coordinator {
                     CatalogItem::Log(_)
                     | CatalogItem::View(_)
                     | CatalogItem::MaterializedView(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
@@ -1670,6 +1779,7 @@ enum CatalogImplication {
     Table(CatalogImplicationKind<Table>),
     Source(CatalogImplicationKind<(Source, Option<GenericSourceConnection>)>),
     Sink(CatalogImplicationKind<Sink>),
+    Notification(CatalogImplicationKind<Notification>),
     Index(CatalogImplicationKind<Index>),
     MaterializedView(CatalogImplicationKind<MaterializedView>),
     View(CatalogImplicationKind<View>),
@@ -1808,6 +1918,13 @@ impl CatalogImplication {
                 CatalogItem::Sink(sink) => {
                     self.absorb_sink(sink, Some(parsed_full_name), catalog_update.diff);
                 }
+                CatalogItem::Notification(notification) => {
+                    self.absorb_notification(
+                        notification,
+                        Some(parsed_full_name),
+                        catalog_update.diff,
+                    );
+                }
                 CatalogItem::Index(index) => {
                     self.absorb_index(index, Some(parsed_full_name), catalog_update.diff);
                 }
@@ -1847,6 +1964,13 @@ impl CatalogImplication {
                 CatalogItem::Sink(sink) => {
                     self.absorb_sink(sink, Some(parsed_full_name), catalog_update.diff);
                 }
+                CatalogItem::Notification(notification) => {
+                    self.absorb_notification(
+                        notification,
+                        Some(parsed_full_name),
+                        catalog_update.diff,
+                    );
+                }
                 CatalogItem::Index(index) => {
                     self.absorb_index(index, Some(parsed_full_name), catalog_update.diff);
                 }
@@ -1906,6 +2030,7 @@ impl CatalogImplication {
         (Source, Option<GenericSourceConnection>)
     );
     impl_absorb_method!(absorb_sink, Sink, Sink);
+    impl_absorb_method!(absorb_notification, Notification, Notification);
     impl_absorb_method!(absorb_index, Index, Index);
     impl_absorb_method!(absorb_materialized_view, MaterializedView, MaterializedView);
     impl_absorb_method!(absorb_view, View, View);
@@ -87,6 +87,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateSources(_)
         | Plan::CreateSecret(_)
         | Plan::CreateSink(_)
+        | Plan::CreateNotification(_)
         | Plan::CreateTable(_)
         | Plan::CreateView(_)
         | Plan::CreateMaterializedView(_)
@@ -136,6 +137,9 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterSetCluster(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
+        | Plan::AlterRowLevelSecurity(_)
+        | Plan::CreatePolicy(_)
+        | Plan::DropPolicy(_)
         | Plan::AlterSourceTimestampInterval(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
@@ -155,6 +159,8 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::Prepare(_)
         | Plan::Execute(_)
         | Plan::Deallocate(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
         | Plan::Raise(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
@@ -79,7 +79,7 @@ use crate::coord::{
 };
 use crate::error::{AdapterError, AuthenticationError};
 use crate::notice::AdapterNotice;
-use crate::session::{Session, TransactionOps, TransactionStatus};
+use crate::session::{Notification, Session, TransactionOps, TransactionStatus};
 use crate::statement_logging::{StatementEndedExecutionReason, WatchSetCreation};
 use crate::util::{ClientTransmitter, ResultExt};
 use crate::webhook::{
@@ -129,6 +129,7 @@ impl Coordinator {
                     client_ip,
                     application_name,
                     notice_tx,
+                    notification_tx,
                 } => {
                     // Note: We purposefully do not use a ClientTransmitter here because startup
                     // handles errors and cleanup of sessions itself.
@@ -141,6 +142,7 @@ impl Coordinator {
                         client_ip,
                         application_name,
                         notice_tx,
+                        notification_tx,
                     )
                     .await;
                 }
@@ -820,6 +822,7 @@ impl Coordinator {
         client_ip: Option<IpAddr>,
         application_name: String,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
+        notification_tx: mpsc::Sender<Notification>,
     ) {
         // Early return if successful, otherwise cleanup any possible state.
         match self
@@ -835,6 +838,8 @@ impl Coordinator {
                 let conn = ConnMeta {
                     secret_key,
                     notice_tx,
+                    notification_tx,
+                    listen_channels: BTreeSet::new(),
                     drop_sinks: BTreeSet::new(),
                     pending_cluster_alters: BTreeSet::new(),
                     connected_at: self.now(),
@@ -1301,6 +1306,8 @@ impl Coordinator {
                     | Statement::Deallocate(_)
                     | Statement::Declare(_)
                     | Statement::Discard(_)
+                    | Statement::Listen(_)
+                    | Statement::Unlisten(_)
                     | Statement::Execute(_)
                     | Statement::ExplainPlan(_)
                     | Statement::ExplainPushdown(_)
@@ -1360,6 +1367,7 @@ impl Coordinator {
                     | Statement::AlterSetCluster(_)
                     | Statement::AlterOwner(_)
                     | Statement::AlterRetainHistory(_)
+                    | Statement::AlterRowLevelSecurity(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
@@ -1379,15 +1387,19 @@ impl Coordinator {
                     | Statement::CreateSchema(_)
                     | Statement::CreateSecret(_)
                     | Statement::CreateSink(_)
+                    | Statement::CreateSnapshot(_)
+                    | Statement::CreateNotification(_)
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::CreateNetworkPolicy(_)
+                    | Statement::CreatePolicy(_)
                     | Statement::Delete(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropPolicy(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
                     | Statement::Insert(_)
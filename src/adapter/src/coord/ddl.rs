@@ -1184,6 +1184,9 @@ impl Coordinator {
                             | ConnectionDetails::Ssh { .. }
                             | ConnectionDetails::Aws(_)
                             | ConnectionDetails::Gcp(_)
+                            | ConnectionDetails::MongoDb(_)
+                            | ConnectionDetails::Nats(_)
+                            | ConnectionDetails::Mqtt(_)
                             | ConnectionDetails::IcebergCatalog(_) => {}
                         },
                         CatalogItem::Table(_) => {
@@ -1192,7 +1195,9 @@ impl Coordinator {
                         CatalogItem::Source(source) => {
                             new_sources += source.user_controllable_persist_shard_count()
                         }
-                        CatalogItem::Sink(_) => new_sinks += 1,
+                        // Notifications count towards the sink limit, as each
+                        // is maintained by a dataflow.
+                        CatalogItem::Sink(_) | CatalogItem::Notification(_) => new_sinks += 1,
                         CatalogItem::MaterializedView(_) => {
                             new_materialized_views += 1;
                         }
@@ -1271,7 +1276,9 @@ impl Coordinator {
                                         new_sources -=
                                             source.user_controllable_persist_shard_count()
                                     }
-                                    CatalogItem::Sink(_) => new_sinks -= 1,
+                                    CatalogItem::Sink(_) | CatalogItem::Notification(_) => {
+                                        new_sinks -= 1
+                                    }
                                     CatalogItem::MaterializedView(_) => {
                                         new_materialized_views -= 1;
                                     }
@@ -1306,6 +1313,7 @@ impl Coordinator {
                     CatalogItem::Connection(_)
                     | CatalogItem::Table(_)
                     | CatalogItem::Sink(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::MaterializedView(_)
                     | CatalogItem::Secret(_)
                     | CatalogItem::Log(_)
@@ -1363,6 +1371,9 @@ impl Coordinator {
                 | ConnectionDetails::Ssh { .. }
                 | ConnectionDetails::Aws(_)
                 | ConnectionDetails::Gcp(_)
+                | ConnectionDetails::MongoDb(_)
+                | ConnectionDetails::Nats(_)
+                | ConnectionDetails::Mqtt(_)
                 | ConnectionDetails::IcebergCatalog(_) => {}
             }
         }
@@ -69,6 +69,7 @@ impl DataflowBuilder<'_> {
                         panic!("log source {id} is missing index");
                     }
                     CatalogItem::Sink(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
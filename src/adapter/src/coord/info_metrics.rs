@@ -440,6 +440,7 @@ mod tests {
                     .finish(),
                 partition_by: None,
                 topic: "topic".to_string(),
+                topic_by: None,
                 topic_options: Default::default(),
                 compression_type: KafkaSinkCompressionType::None,
                 progress_group_id: KafkaIdStyle::Legacy,
@@ -27,14 +27,16 @@ use mz_ore::option::OptionExt;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::{soft_assert_or_log, task};
 use mz_persist_client::usage::ShardsUsageReferenced;
-use mz_repr::{Datum, Diff, Row};
+use mz_repr::{CatalogItemId, Datum, Diff, Row};
 use mz_sql::ast::Statement;
+use mz_sql::catalog::CatalogItem as SqlCatalogItem;
 use mz_sql::names::ResolvedIds;
 use mz_sql::pure::PurifiedStatement;
 use mz_storage_client::controller::IntrospectionType;
 use opentelemetry::trace::TraceContextExt;
 use rand::{Rng, SeedableRng, rngs};
 use serde_json::json;
+use timely::progress::Antichain;
 use tracing::{Instrument, Level, event, info_span, warn};
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
@@ -128,8 +130,10 @@ impl Coordinator {
             Message::StorageUsageFetch => {
                 self.storage_usage_fetch().boxed_local().await;
             }
-            Message::StorageUsageUpdate(sizes) => {
-                self.storage_usage_update(sizes).boxed_local().await;
+            Message::StorageUsageUpdate(sizes, snapshot_sizes) => {
+                self.storage_usage_update(sizes, snapshot_sizes)
+                    .boxed_local()
+                    .await;
             }
             Message::StorageUsagePrune(expired) => {
                 self.storage_usage_prune(expired).boxed_local().await;
@@ -145,6 +149,17 @@ impl Coordinator {
             Message::ArrangementSizesPrune(expired) => {
                 self.arrangement_sizes_prune(expired).boxed_local().await;
             }
+            Message::SourceTableSchemaSchedule => {
+                self.schedule_source_table_schema_check();
+            }
+            Message::SourceTableSchemaCheck => {
+                self.check_source_table_schemas();
+            }
+            Message::SourceTableSchemaChanges(changes) => {
+                self.apply_source_table_schema_changes(changes)
+                    .boxed_local()
+                    .await;
+            }
             Message::RetireExecute {
                 otel_ctx,
                 data,
@@ -252,6 +267,34 @@ impl Coordinator {
             .map(|(_id, m)| m.data_shard)
             .collect();
 
+        // Snapshots share the shard of the materialized view they were taken
+        // from. What they cost is the history they retain in that shard: the
+        // updates between their `AS OF` and the since the view would otherwise
+        // be compacted to.
+        let snapshots: Vec<_> = self
+            .catalog()
+            .user_tables()
+            .filter_map(|entry| {
+                let (from, as_of) = entry.snapshot_details()?;
+                let metadata = self.controller.storage.collection_metadata(from).ok()?;
+                let frontiers = self
+                    .controller
+                    .storage_collections
+                    .collection_frontiers(from)
+                    .ok()?;
+                let upper = frontiers
+                    .implied_capability
+                    .iter()
+                    .map(|time| u64::from(*time));
+                Some((
+                    entry.id(),
+                    metadata.data_shard,
+                    Antichain::from_elem(u64::from(as_of)),
+                    Antichain::from_iter(upper),
+                ))
+            })
+            .collect();
+
         let collection_metric = self.metrics.storage_usage_collection_time_seconds.clone();
 
         // Spawn an asynchronous task to compute the storage usage, which
@@ -259,18 +302,28 @@ impl Coordinator {
         task::spawn(|| "storage_usage_fetch", async move {
             let collection_metric_timer = collection_metric.start_timer();
             let shard_sizes = client.shards_usage_referenced(live_shards).await;
+            let mut snapshot_sizes = BTreeMap::new();
+            for (id, shard_id, lower, upper) in snapshots {
+                let size = client.shard_usage_between(shard_id, &lower, &upper).await;
+                snapshot_sizes.insert(id, size);
+            }
             collection_metric_timer.observe_duration();
 
             // It is not an error for shard sizes to become ready after
             // `internal_cmd_rx` is dropped.
-            if let Err(e) = internal_cmd_tx.send(Message::StorageUsageUpdate(shard_sizes)) {
+            let msg = Message::StorageUsageUpdate(shard_sizes, snapshot_sizes);
+            if let Err(e) = internal_cmd_tx.send(msg) {
                 warn!("internal_cmd_rx dropped before we could send: {:?}", e);
             }
         });
     }
 
     #[mz_ore::instrument(level = "debug")]
-    async fn storage_usage_update(&mut self, shards_usage: ShardsUsageReferenced) {
+    async fn storage_usage_update(
+        &mut self,
+        shards_usage: ShardsUsageReferenced,
+        snapshot_sizes: BTreeMap<CatalogItemId, u64>,
+    ) {
         // Similar to audit events, use the oracle ts so this is guaranteed to
         // increase. This is intentionally the timestamp of when collection
         // finished, not when it started, so that we don't write data with a
@@ -294,7 +347,7 @@ impl Coordinator {
             }
         };
 
-        let updates: Vec<_> = shards_usage
+        let mut updates: Vec<_> = shards_usage
             .by_shard
             .into_iter()
             .map(|(shard_id, shard_usage)| {
@@ -308,6 +361,31 @@ impl Coordinator {
             })
             .collect();
 
+        // Only the most recent size of each snapshot is kept, so replace the
+        // sizes of the previous collection.
+        let snapshot_sizes: BTreeMap<_, _> = snapshot_sizes
+            .into_iter()
+            .map(|(id, size_bytes)| (id, (size_bytes, collection_timestamp)))
+            .collect();
+        let previous_snapshot_sizes =
+            std::mem::replace(&mut self.snapshot_storage_usage, snapshot_sizes);
+        for (id, (size_bytes, timestamp)) in previous_snapshot_sizes {
+            updates.push(self.catalog().pack_snapshot_storage_usage_update(
+                id,
+                size_bytes,
+                timestamp,
+                Diff::MINUS_ONE,
+            ));
+        }
+        for (id, (size_bytes, timestamp)) in &self.snapshot_storage_usage {
+            updates.push(self.catalog().pack_snapshot_storage_usage_update(
+                *id,
+                *size_bytes,
+                *timestamp,
+                Diff::ONE,
+            ));
+        }
+
         let (table_updates, _) = self.builtin_table_update().execute(updates).await;
 
         let internal_cmd_tx = self.internal_cmd_tx.clone();
@@ -696,6 +774,9 @@ impl Coordinator {
                 } else if self.introspection_subscribes.contains_key(&sink_id) {
                     self.handle_introspection_subscribe_batch(sink_id, response)
                         .await;
+                } else if self.notification_subscribes.contains_key(&sink_id) {
+                    self.handle_notification_subscribe_batch(sink_id, response)
+                        .await;
                 } else {
                     // Cancellation may cause us to receive responses for subscribes no longer
                     // tracked, so we quietly ignore them.
@@ -0,0 +1,412 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for notifications and `LISTEN`/`UNLISTEN`.
+//!
+//! A notification, created with `CREATE NOTIFICATION`, delivers the changes to a
+//! relation as asynchronous notifications to all sessions that have issued
+//! `LISTEN` on the notification's channel. The channel is the unqualified name of
+//! the notification, which planning keeps unique among all notifications.
+//!
+//! # Lifecycle of Notification Subscribes
+//!
+//! * When a notification is created, and for every notification during
+//!   coordinator bootstrapping, the coordinator calls `install_notification_subscribe`
+//!   to install a subscribe that reports the changes to the relation.
+//! * The coordinator calls `handle_notification_subscribe_batch` for each response it
+//!   receives from a notification subscribe, to forward the received updates to the
+//!   listening sessions. A subscribe that fails is reinstalled.
+//! * When a notification is dropped, the coordinator calls
+//!   `drop_notification_subscribes` to drop the subscribe maintaining it.
+//!
+//! Notifications are not durable: changes that happen while the coordinator is
+//! down, while the subscribe is being reinstalled, or while no session is
+//! listening, are not delivered. Each session buffers at most
+//! [`MAX_PENDING_NOTIFICATIONS`] notifications, and drops further ones until it
+//! catches up.
+
+use mz_adapter_types::connection::ConnectionId;
+use mz_compute_client::protocol::response::SubscribeBatch;
+use mz_controller_types::ClusterId;
+use mz_interchange::encode::column_names_and_types;
+use mz_interchange::json::encode_datums_as_json;
+use mz_ore::collections::CollectionExt;
+use mz_ore::soft_panic_or_log;
+use mz_repr::adt::mz_acl_item::AclMode;
+use mz_repr::optimize::OverrideFrom;
+use mz_repr::{ColumnName, GlobalId, SqlColumnType};
+use mz_sql::ast::Statement;
+use mz_sql::plan::{
+    ListenPlan, Params, Plan, SubscribePlan, UnlistenPlan, row_level_security_restricts_role,
+};
+use mz_sql_parser::ast::display::AstDisplay;
+use serde_json::json;
+use timely::progress::Antichain;
+use tokio::sync::mpsc::error::TrySendError;
+use tracing::{info, warn};
+
+use crate::coord::Coordinator;
+use crate::coord::timestamp_selection::TimestampProvider;
+use crate::optimize::Optimize;
+use crate::session::{MAX_PENDING_NOTIFICATIONS, Notification, Session};
+use crate::{AdapterError, ExecuteResponse, optimize};
+
+/// State tracked about an installed notification subscribe.
+#[derive(Debug)]
+pub(super) struct NotificationSubscribe {
+    /// The ID of the notification maintained by this subscribe.
+    notification_id: GlobalId,
+    /// The ID of the cluster the subscribe is installed on.
+    cluster_id: ClusterId,
+    /// The names and types of the columns of the relation.
+    columns: Vec<(ColumnName, SqlColumnType)>,
+}
+
+impl Coordinator {
+    /// Installs the subscribes maintaining all existing notifications.
+    ///
+    /// Meant to be invoked during coordinator bootstrapping.
+    pub(super) async fn bootstrap_notification_subscribes(&mut self) {
+        let notification_ids: Vec<_> = self
+            .catalog()
+            .entries()
+            .filter_map(|entry| entry.notification())
+            .map(|notification| notification.global_id())
+            .collect();
+
+        for notification_id in notification_ids {
+            self.install_notification_subscribe(notification_id).await;
+        }
+    }
+
+    /// Installs a subscribe maintaining the given notification.
+    ///
+    /// The subscribe only reports changes that happen after its installation.
+    /// Failures are logged rather than returned, as the notification has
+    /// already been committed to the catalog.
+    pub(super) async fn install_notification_subscribe(&mut self, notification_id: GlobalId) {
+        if let Err(error) = self
+            .try_install_notification_subscribe(notification_id)
+            .await
+        {
+            warn!(%notification_id, %error, "failed to install notification subscribe");
+        }
+    }
+
+    async fn try_install_notification_subscribe(
+        &mut self,
+        notification_id: GlobalId,
+    ) -> Result<(), AdapterError> {
+        let entry = self.catalog().get_entry_by_global_id(&notification_id);
+        let notification = entry.notification().expect("must be a notification");
+        let cluster_id = notification.cluster_id;
+        let columns = column_names_and_types(notification.desc.clone());
+        let plan = self.notification_subscribe_plan(&notification.create_sql)?;
+
+        let (_, subscribe_id) = self.allocate_transient_id();
+        let (_, view_id) = self.allocate_transient_id();
+        info!(%notification_id, %subscribe_id, "installing notification subscribe");
+
+        let compute_instance = self
+            .instance_snapshot(cluster_id)
+            .map_err(|_| AdapterError::ConcurrentClusterDrop)?;
+        let vars = self.catalog().system_config();
+        let overrides = self.catalog.get_cluster(cluster_id).config.features();
+        let optimizer_config = optimize::OptimizerConfig::from(vars)
+            .override_from(&overrides)
+            .override_from(&self.cluster_scoped_optimizer_overrides(cluster_id));
+
+        let mut optimizer = optimize::subscribe::Optimizer::new(
+            self.owned_catalog(),
+            compute_instance,
+            view_id,
+            subscribe_id,
+            plan.with_snapshot,
+            None,
+            format!("notification-subscribe-{subscribe_id}"),
+            optimizer_config,
+            self.optimizer_metrics(),
+        );
+
+        // MIR ⇒ MIR optimization (global)
+        let global_mir_plan = optimizer.catch_unwind_optimize(plan)?;
+
+        // Timestamp selection. Start at the most recent readable time, so that
+        // the subscribe does not report changes that happened before it was
+        // installed.
+        let id_bundle = global_mir_plan.id_bundle(cluster_id);
+        let read_holds = self.acquire_read_holds(&id_bundle);
+        let mut as_of = read_holds.least_valid_read();
+        as_of.extend(self.greatest_available_read(&id_bundle));
+        let as_of = as_of
+            .into_iter()
+            .max()
+            .map(Antichain::from_elem)
+            .unwrap_or_default();
+        let global_mir_plan = global_mir_plan.resolve(as_of);
+
+        // MIR ⇒ LIR lowering and LIR ⇒ LIR optimization (global)
+        let global_lir_plan = optimizer.catch_unwind_optimize(global_mir_plan)?;
+        let (df_desc, _df_meta) = global_lir_plan.unapply();
+
+        self.notification_subscribes.insert(
+            subscribe_id,
+            NotificationSubscribe {
+                notification_id,
+                cluster_id,
+                columns,
+            },
+        );
+        self.ship_dataflow(df_desc, cluster_id, None).await;
+        drop(read_holds);
+
+        Ok(())
+    }
+
+    /// Derives the `SUBSCRIBE` maintaining a notification from its
+    /// `CREATE NOTIFICATION` statement.
+    fn notification_subscribe_plan(&self, create_sql: &str) -> Result<SubscribePlan, AdapterError> {
+        let stmt = mz_sql::parse::parse(create_sql)?.into_element().ast;
+        let Statement::CreateNotification(stmt) = stmt else {
+            return Err(AdapterError::Internal(format!(
+                "create SQL of notification is not a CREATE NOTIFICATION statement: {create_sql}"
+            )));
+        };
+        let selection = match &stmt.selection {
+            Some(selection) => format!(" WHERE {}", selection.to_ast_string_stable()),
+            None => String::new(),
+        };
+        let sql = format!(
+            "SUBSCRIBE (SELECT * FROM {}{selection}) WITH (SNAPSHOT = false)",
+            stmt.on.to_ast_string_stable(),
+        );
+
+        let catalog = self.catalog().for_system_session();
+        let parsed = mz_sql::parse::parse(&sql)?.into_element();
+        let (stmt, resolved_ids) = mz_sql::names::resolve(&catalog, parsed.ast)?;
+        let (plan, _sql_impl_ids) =
+            mz_sql::plan::plan(None, &catalog, stmt, &Params::empty(), &resolved_ids)?;
+        match plan {
+            Plan::Subscribe(plan) => Ok(plan),
+            _ => Err(AdapterError::Internal(format!(
+                "unexpected plan for notification subscribe: {plan:?}"
+            ))),
+        }
+    }
+
+    /// Drops the subscribes maintaining the given notifications.
+    pub(super) fn drop_notification_subscribes(&mut self, notification_ids: Vec<GlobalId>) {
+        let to_drop: Vec<_> = self
+            .notification_subscribes
+            .iter()
+            .filter(|(_, s)| notification_ids.contains(&s.notification_id))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in to_drop {
+            self.drop_notification_subscribe(id);
+        }
+    }
+
+    /// Drops the given notification subscribe, returning the ID of the
+    /// notification it maintained.
+    fn drop_notification_subscribe(&mut self, id: GlobalId) -> Option<GlobalId> {
+        let Some(subscribe) = self.notification_subscribes.remove(&id) else {
+            soft_panic_or_log!("attempt to drop unknown notification subscribe (id={id})");
+            return None;
+        };
+        let notification_id = subscribe.notification_id;
+        info!(%id, %notification_id, "dropping notification subscribe");
+        if let Err(error) = self
+            .controller
+            .compute
+            .drop_collections(subscribe.cluster_id, vec![id])
+        {
+            soft_panic_or_log!(
+                "error dropping compute collection for notification subscribe: {error} \
+                 (id={id}, cluster_id={})",
+                subscribe.cluster_id,
+            );
+        }
+        Some(notification_id)
+    }
+
+    /// Processes a batch returned by a notification subscribe, by delivering
+    /// each received update to the sessions listening on the notification's
+    /// channel, or by reinstalling the subscribe if it failed.
+    pub(super) async fn handle_notification_subscribe_batch(
+        &mut self,
+        id: GlobalId,
+        batch: SubscribeBatch,
+    ) {
+        let Some(subscribe) = self.notification_subscribes.get(&id) else {
+            soft_panic_or_log!("updates for unknown notification subscribe (id={id})");
+            return;
+        };
+        let notification_id = subscribe.notification_id;
+
+        let updates = match batch.updates {
+            Ok(updates) if updates.is_empty() => return,
+            Ok(updates) => updates,
+            Err(error) => {
+                // A failed subscribe does not report any further changes, so
+                // replace it. The changes until the new subscribe is installed
+                // are not delivered.
+                warn!(%id, %notification_id, %error, "reinstalling failed notification subscribe");
+                if let Some(notification_id) = self.drop_notification_subscribe(id) {
+                    self.install_notification_subscribe(notification_id).await;
+                }
+                return;
+            }
+        };
+
+        let entry = self.catalog().get_entry_by_global_id(&notification_id);
+        let channel = entry.name().item.clone();
+        let listeners = self.notification_listeners(&channel, notification_id);
+        if listeners.is_empty() {
+            return;
+        }
+
+        // Deliver the updates in timestamp order, as in a `SUBSCRIBE`.
+        let mut payloads = Vec::new();
+        for collection in &updates {
+            for (row, time, diff) in collection.iter() {
+                let payload = json!({
+                    "diff": diff.into_inner(),
+                    "row": encode_datums_as_json(row.iter(), &subscribe.columns),
+                });
+                payloads.push((*time, payload.to_string()));
+            }
+        }
+        payloads.sort_by_key(|(time, _)| *time);
+
+        let mut dropped = 0;
+        for conn_id in listeners {
+            let conn = &self.active_conns[&conn_id];
+            for (_, payload) in &payloads {
+                let notification = Notification {
+                    channel: channel.clone(),
+                    payload: payload.clone(),
+                };
+                match conn.notification_tx.try_send(notification) {
+                    Ok(()) => {}
+                    // The session does not keep up with the notifications, or
+                    // does not consume them at all, like sessions served over
+                    // HTTP. Drop the notification rather than buffering it
+                    // without bound.
+                    Err(TrySendError::Full(_)) => dropped += 1,
+                    // The session terminated concurrently, so there is nobody
+                    // left to notify.
+                    Err(TrySendError::Closed(_)) => break,
+                }
+            }
+        }
+        if dropped > 0 {
+            warn!(
+                %notification_id,
+                dropped,
+                "dropped notifications for sessions with {MAX_PENDING_NOTIFICATIONS} pending \
+                 notifications",
+            );
+        }
+    }
+
+    /// Returns the connections listening on `channel` that may receive the
+    /// notifications raised by the given notification.
+    ///
+    /// Receiving notifications requires `SELECT` privileges on the relation
+    /// the notification was created on. Privileges on some of the
+    /// relation's columns do not suffice, as notifications contain every
+    /// column. Notifications are planned on behalf of the system, so they are
+    /// also withheld from roles that are subject to the row level security
+    /// policies of the relation.
+    fn notification_listeners(
+        &self,
+        channel: &str,
+        notification_id: GlobalId,
+    ) -> Vec<ConnectionId> {
+        let catalog = self.catalog();
+        let notification = catalog
+            .get_entry_by_global_id(&notification_id)
+            .notification()
+            .expect("must be a notification");
+        let from = catalog.get_entry_by_global_id(&notification.from);
+        let rbac_enabled = catalog.system_config().enable_rbac_checks();
+        let conn_catalog = catalog.for_system_session();
+
+        self.active_conns
+            .iter()
+            .filter(|(_, conn)| conn.listen_channels.contains(channel))
+            .filter(|(_, conn)| {
+                if !rbac_enabled || conn.user().is_superuser() {
+                    return true;
+                }
+                let role_membership = catalog
+                    .state()
+                    .collect_role_membership(conn.authenticated_role_id());
+                let privileges = role_membership
+                    .iter()
+                    .flat_map(|role_id| from.privileges().get_acl_items_for_grantee(role_id))
+                    .fold(AclMode::empty(), |accum, item| accum.union(item.acl_mode));
+                privileges.contains(AclMode::SELECT)
+            })
+            .filter(|(_, conn)| {
+                let role_id = conn.authenticated_role_id();
+                match row_level_security_restricts_role(&conn_catalog, from, role_id) {
+                    Ok(restricted) => !restricted,
+                    Err(error) => {
+                        warn!(%notification_id, %error, "could not determine row level security");
+                        false
+                    }
+                }
+            })
+            .map(|(conn_id, _)| conn_id.clone())
+            .collect()
+    }
+
+    /// Starts listening on the requested channel.
+    pub(super) fn sequence_listen(
+        &mut self,
+        session: &Session,
+        plan: ListenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let conn = self
+            .active_conns
+            .get_mut(session.conn_id())
+            .expect("connection must exist");
+        conn.listen_channels.insert(plan.channel);
+        Ok(ExecuteResponse::Listen)
+    }
+
+    /// Stops listening on the requested channel, or on all channels.
+    pub(super) fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        plan: UnlistenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        match plan.channel {
+            Some(channel) => {
+                let conn = self
+                    .active_conns
+                    .get_mut(session.conn_id())
+                    .expect("connection must exist");
+                conn.listen_channels.remove(&channel);
+            }
+            None => self.unlisten_all(session.conn_id()),
+        }
+        Ok(ExecuteResponse::Unlisten)
+    }
+
+    /// Stops listening on all channels for the given connection.
+    pub(super) fn unlisten_all(&mut self, conn_id: &ConnectionId) {
+        if let Some(conn) = self.active_conns.get_mut(conn_id) {
+            conn.listen_channels.clear();
+        }
+    }
+}
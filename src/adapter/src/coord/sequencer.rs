@@ -282,6 +282,10 @@ impl Coordinator {
                 Plan::CreateSink(plan) => {
                     self.sequence_create_sink(ctx, plan, resolved_ids).await;
                 }
+                Plan::CreateNotification(plan) => {
+                    self.sequence_create_notification(ctx, plan, resolved_ids)
+                        .await;
+                }
                 Plan::CreateView(plan) => {
                     self.sequence_create_view(ctx, plan, resolved_ids).await;
                 }
@@ -485,6 +489,18 @@ impl Coordinator {
                     let result = self.sequence_alter_retain_history(&mut ctx, plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterRowLevelSecurity(plan) => {
+                    let result = self.sequence_alter_row_level_security(&mut ctx, plan).await;
+                    ctx.retire(result);
+                }
+                Plan::CreatePolicy(plan) => {
+                    let result = self.sequence_create_policy(&mut ctx, plan).await;
+                    ctx.retire(result);
+                }
+                Plan::DropPolicy(plan) => {
+                    let result = self.sequence_drop_policy(&mut ctx, plan).await;
+                    ctx.retire(result);
+                }
                 Plan::AlterSourceTimestampInterval(plan) => {
                     let result = self
                         .sequence_alter_source_timestamp_interval(&mut ctx, plan)
@@ -554,6 +570,7 @@ impl Coordinator {
                         let (_, retire_notify) = self.clear_transaction(ctx.session_mut()).await;
                         ctx.delay_response_until(retire_notify);
                         self.drop_temp_items(ctx.session().conn_id()).await;
+                        self.unlisten_all(ctx.session().conn_id());
                         ctx.session_mut().reset();
                         Ok(ExecuteResponse::DiscardedAll)
                     } else {
@@ -655,6 +672,14 @@ impl Coordinator {
                         ctx.retire(Ok(ExecuteResponse::Deallocate { all: true }));
                     }
                 },
+                Plan::Listen(plan) => {
+                    let res = self.sequence_listen(ctx.session(), plan);
+                    ctx.retire(res);
+                }
+                Plan::Unlisten(plan) => {
+                    let res = self.sequence_unlisten(ctx.session(), plan);
+                    ctx.retire(res);
+                }
                 Plan::Raise(RaisePlan { severity }) => {
                     ctx.session()
                         .add_notice(AdapterNotice::UserRequested { severity });
@@ -23,7 +23,8 @@ use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::{ENABLE_PASSWORD_AUTH, READ_THEN_WRITE_MAX_DEPENDENCIES};
 use mz_catalog::memory::error::ErrorKind;
 use mz_catalog::memory::objects::{
-    CatalogItem, Connection, DataSourceDesc, Sink, Source, Table, TableDataSource, Type,
+    CatalogEntry, CatalogItem, Connection, DataSourceDesc, Notification, Sink, Source, Table,
+    TableDataSource, Type,
 };
 use mz_expr::{
     CollectionPlan, Eval, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
@@ -31,17 +32,18 @@ use mz_expr::{
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::future::OreFutureExt;
+use mz_ore::str::StrExt;
 use mz_ore::task::{self, JoinHandle, spawn};
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::{assert_none, instrument};
 use mz_repr::adt::jsonb::Jsonb;
-use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::explain::json::json_string;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
-    CatalogItemId, Datum, Diff, GlobalId, RelationVersion, RelationVersionSelector, Row, RowArena,
-    RowIterator, Timestamp,
+    CatalogItemId, ColumnName, Datum, Diff, GlobalId, RelationVersion, RelationVersionSelector,
+    Row, RowArena, RowIterator, Timestamp,
 };
 use mz_secrets::SecretsReader;
 use mz_sql::ast::{
@@ -60,8 +62,8 @@ use mz_sql::names::{
     SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    AlterMaterializedViewApplyReplacementPlan, ConnectionDetails, NetworkPolicyRule,
-    StatementContext,
+    AlterMaterializedViewApplyReplacementPlan, ColumnPrivileges, ConnectionDetails,
+    NetworkPolicyRule, StatementContext,
 };
 use mz_sql::pure::{PurifiedSourceExport, generate_subsource_statements};
 use mz_storage_types::sinks::StorageSinkDesc;
@@ -172,6 +174,29 @@ where
     }
 }
 
+/// Returns the operation that replaces the privileges on the columns of `entry` with
+/// `privileges`, or `None` if they are unchanged.
+fn update_column_privileges_op(
+    entry: &CatalogEntry,
+    privileges: &ColumnPrivileges,
+) -> Result<Option<catalog::Op>, AdapterError> {
+    if *privileges == ColumnPrivileges::from_create_sql(entry.create_sql()) {
+        return Ok(None);
+    }
+    let mut to_item = entry.item().clone();
+    to_item.update_column_privileges(privileges).map_err(|()| {
+        AdapterError::Internal(format!(
+            "{} does not support privileges on columns",
+            entry.id()
+        ))
+    })?;
+    Ok(Some(catalog::Op::UpdateItem {
+        id: entry.id(),
+        name: entry.name().clone(),
+        to_item,
+    }))
+}
+
 struct DropOps {
     ops: Vec<catalog::Op>,
     dropped_active_db: bool,
@@ -541,7 +566,10 @@ impl Coordinator {
             .with_options
             .iter()
             .filter_map(|opt| match opt.name {
-                CreateSourceOptionName::TimestampInterval => None,
+                CreateSourceOptionName::TimestampInterval
+                | CreateSourceOptionName::ColumnPrivileges
+                | CreateSourceOptionName::RowLevelSecurity
+                | CreateSourceOptionName::RowLevelSecurityPolicies => None,
                 CreateSourceOptionName::RetainHistory => Some(CreateSubsourceOption {
                     name: CreateSubsourceOptionName::RetainHistory,
                     value: opt.value.clone(),
@@ -1047,6 +1075,33 @@ impl Coordinator {
                     unreachable!("CREATE TABLE data source got {:?}", o)
                 }
             },
+            plan::TableDataSource::Snapshot { from, as_of } => {
+                TableDataSource::Snapshot { from, as_of }
+            }
+        };
+
+        // A snapshot can only be pinned at a time that the materialized view
+        // can still be read at. Hold back the materialized view's since until
+        // the snapshot's own read policy is installed.
+        let _snapshot_read_hold = if let TableDataSource::Snapshot { from, as_of } = &data_source {
+            let read_hold = self
+                .controller
+                .storage_collections
+                .acquire_read_holds(vec![*from])
+                .map_err(AdapterError::concurrent_dependency_drop_from_collection_missing)?
+                .into_element();
+            if !read_hold.since().less_equal(as_of) {
+                return Err(AdapterError::ImpossibleTimestampConstraints {
+                    constraints: format!(
+                        "Timestamp ({as_of}) is not valid for all inputs: \
+                         [{from}: since {:?}]",
+                        read_hold.since().elements(),
+                    ),
+                });
+            }
+            Some(read_hold)
+        } else {
+            None
         };
 
         let is_webhook = if let TableDataSource::DataSource {
@@ -1174,6 +1229,66 @@ impl Coordinator {
         ctx.retire(Ok(ExecuteResponse::CreatedSink))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_create_notification(
+        &mut self,
+        ctx: ExecuteContext,
+        plan: plan::CreateNotificationPlan,
+        resolved_ids: ResolvedIds,
+    ) {
+        let plan::CreateNotificationPlan {
+            name,
+            notification,
+            if_not_exists,
+            in_cluster,
+        } = plan;
+
+        let (item_id, global_id) = return_if_err!(self.allocate_user_id().await, ctx);
+
+        let catalog_notification = Notification {
+            create_sql: notification.create_sql,
+            global_id,
+            from: notification.from,
+            desc: notification.desc,
+            resolved_ids,
+            cluster_id: in_cluster,
+        };
+
+        let ops = vec![catalog::Op::CreateItem {
+            id: item_id,
+            name: name.clone(),
+            item: CatalogItem::Notification(catalog_notification),
+            owner_id: *ctx.session().current_role_id(),
+        }];
+
+        let result = self.catalog_transact(Some(ctx.session()), ops).await;
+
+        match result {
+            Ok(()) => {}
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                ctx.session()
+                    .add_notice(AdapterNotice::ObjectAlreadyExists {
+                        name: name.item,
+                        ty: "notification",
+                    });
+                ctx.retire(Ok(ExecuteResponse::CreatedNotification));
+                return;
+            }
+            Err(e) => {
+                ctx.retire(Err(e));
+                return;
+            }
+        };
+
+        // Notifications are maintained by the coordinator rather than by the
+        // storage layer.
+        self.install_notification_subscribe(global_id).await;
+
+        ctx.retire(Ok(ExecuteResponse::CreatedNotification))
+    }
+
     /// Validates that a view definition does not contain any expressions that may lead to
     /// ambiguous column references to system tables. For example `NATURAL JOIN` or `SELECT *`.
     ///
@@ -1354,13 +1469,21 @@ impl Coordinator {
             dropped_roles: &BTreeMap<RoleId, &str>,
             dependent_objects: &mut BTreeMap<String, Vec<String>>,
             object_id: &SystemObjectId,
+            column: Option<&ColumnName>,
             catalog: &ConnCatalog,
         ) {
+            let describe = || {
+                let object_description =
+                    ErrorMessageObjectDescription::from_sys_id(object_id, catalog);
+                match column {
+                    Some(column) => format!("column {} of {object_description}", column.quoted()),
+                    None => object_description.to_string(),
+                }
+            };
             for privilege in privileges.all_values() {
                 if let Some(role_name) = dropped_roles.get(&privilege.grantee) {
                     let grantor_name = catalog.get_role(&privilege.grantor).name();
-                    let object_description =
-                        ErrorMessageObjectDescription::from_sys_id(object_id, catalog);
+                    let object_description = describe();
                     dependent_objects
                         .entry(role_name.to_string())
                         .or_default()
@@ -1370,8 +1493,7 @@ impl Coordinator {
                 }
                 if let Some(role_name) = dropped_roles.get(&privilege.grantor) {
                     let grantee_name = catalog.get_role(&privilege.grantee).name();
-                    let object_description =
-                        ErrorMessageObjectDescription::from_sys_id(object_id, catalog);
+                    let object_description = describe();
                     dependent_objects
                         .entry(role_name.to_string())
                         .or_default()
@@ -1398,8 +1520,20 @@ impl Coordinator {
                 dropped_roles,
                 &mut dependent_objects,
                 &id,
+                None,
                 &catalog,
             );
+            for (column, privileges) in ColumnPrivileges::from_create_sql(entry.create_sql()).iter()
+            {
+                privilege_check(
+                    privileges,
+                    dropped_roles,
+                    &mut dependent_objects,
+                    &id,
+                    Some(column),
+                    &catalog,
+                );
+            }
         }
         for database in self.catalog.databases() {
             let database_id = SystemObjectId::Object(database.id().into());
@@ -1416,6 +1550,7 @@ impl Coordinator {
                 dropped_roles,
                 &mut dependent_objects,
                 &database_id,
+                None,
                 &catalog,
             );
             for schema in database.schemas_by_id.values() {
@@ -1435,6 +1570,7 @@ impl Coordinator {
                     dropped_roles,
                     &mut dependent_objects,
                     &schema_id,
+                    None,
                     &catalog,
                 );
             }
@@ -1454,6 +1590,7 @@ impl Coordinator {
                 dropped_roles,
                 &mut dependent_objects,
                 &cluster_id,
+                None,
                 &catalog,
             );
             for replica in cluster.replicas() {
@@ -1474,6 +1611,7 @@ impl Coordinator {
             dropped_roles,
             &mut dependent_objects,
             &SystemObjectId::System,
+            None,
             &catalog,
         );
         for (default_privilege_object, default_privilege_acl_items) in
@@ -1522,15 +1660,16 @@ impl Coordinator {
 
         // Make sure this stays in sync with the beginning of `rbac::check_plan`.
         let session_catalog = self.catalog().for_session(session);
+        let mut role_membership = None;
         if rbac::is_rbac_enabled_for_session(session_catalog.system_vars(), session)
             && !session.is_superuser()
         {
             // Obtain all roles that the current session is a member of.
-            let role_membership =
-                session_catalog.collect_role_membership(session.current_role_id());
+            let membership = role_membership
+                .insert(session_catalog.collect_role_membership(session.current_role_id()));
             let invalid_revokes: BTreeSet<_> = privilege_revokes
                 .extract_if(.., |(_, privilege)| {
-                    !role_membership.contains(&privilege.grantor)
+                    !membership.contains(&privilege.grantor)
                 })
                 .map(|(object_id, _)| object_id)
                 .collect();
@@ -1541,6 +1680,33 @@ impl Coordinator {
             }
         }
 
+        // Privileges on columns are stored with the relation's definition, so revoking them
+        // rewrites the relation. They are always granted by the relation's owner.
+        let mut column_privilege_revoke_ops = Vec::new();
+        for entry in self.catalog().entries() {
+            if plan.drop_ids.contains(&ObjectId::Item(entry.id())) {
+                continue;
+            }
+            let mut column_privileges = ColumnPrivileges::from_create_sql(entry.create_sql());
+            for role_id in &plan.role_ids {
+                column_privileges.revoke_all_columns(role_id, AclMode::all());
+            }
+            let Some(op) = update_column_privileges_op(entry, &column_privileges)? else {
+                continue;
+            };
+            if let Some(membership) = &role_membership
+                && !membership.contains(entry.owner_id())
+            {
+                let object_description = ErrorMessageObjectDescription::from_sys_id(
+                    &SystemObjectId::Object(entry.id().into()),
+                    &session_catalog,
+                );
+                session.add_notice(AdapterNotice::CannotRevoke { object_description });
+                continue;
+            }
+            column_privilege_revoke_ops.push(op);
+        }
+
         // Group revokes by target so each object is rewritten once, not once per privilege.
         let mut privilege_revokes_by_target: BTreeMap<SystemObjectId, Vec<MzAclItem>> =
             BTreeMap::new();
@@ -1572,7 +1738,9 @@ impl Coordinator {
             dropped_in_use_indexes,
         } = self.sequence_drop_common(session, plan.drop_ids)?;
 
-        let ops = privilege_revoke_ops
+        let ops = column_privilege_revoke_ops
+            .into_iter()
+            .chain(privilege_revoke_ops)
             .chain(default_privilege_revoke_ops)
             .chain(drop_ops.into_iter())
             .collect();
@@ -3169,6 +3337,75 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(plan.object_type))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_row_level_security(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterRowLevelSecurityPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.update_row_level_security(
+            ctx,
+            plan.id,
+            plan::RowLevelSecurityAction::SetEnabled(plan.enabled),
+        )
+        .await?;
+        Ok(ExecuteResponse::AlteredObject(plan.object_type))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_policy(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::CreatePolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.update_row_level_security(
+            ctx,
+            plan.id,
+            plan::RowLevelSecurityAction::CreatePolicy(plan.policy),
+        )
+        .await?;
+        Ok(ExecuteResponse::CreatedPolicy)
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_drop_policy(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::DropPolicyPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // The planner has already reported a missing policy for `IF EXISTS`.
+        if let Some(name) = plan.name {
+            self.update_row_level_security(
+                ctx,
+                plan.id,
+                plan::RowLevelSecurityAction::DropPolicy(name),
+            )
+            .await?;
+        }
+        Ok(ExecuteResponse::DroppedPolicy)
+    }
+
+    /// Rewrites the row level security options in the `CREATE` statement of the item `id`.
+    async fn update_row_level_security(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        id: CatalogItemId,
+        action: plan::RowLevelSecurityAction,
+    ) -> Result<(), AdapterError> {
+        let entry = self.catalog().get_entry(&id);
+        let mut to_item = entry.item().clone();
+        to_item.update_row_level_security(&action).map_err(|()| {
+            AdapterError::Internal(format!("{id} does not support row level security"))
+        })?;
+        let ops = vec![catalog::Op::UpdateItem {
+            id,
+            name: entry.name().clone(),
+            to_item,
+        }];
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_source_timestamp_interval(
         &mut self,
@@ -4404,6 +4641,7 @@ impl Coordinator {
             target_id,
             grantor,
             acl_from_all,
+            columns,
         } in update_privileges
         {
             let actual_object_type = catalog.get_system_object_type(&target_id);
@@ -4430,6 +4668,50 @@ impl Coordinator {
                     .ensure_not_reserved_object(object_id, session.conn_id())?;
             }
 
+            // Privileges on columns are stored with the relation's definition rather than in its
+            // privileges. Revoking `SELECT` on a relation also revokes it on the relation's
+            // columns, as in PostgreSQL.
+            let revokes_columns = matches!(variant, UpdatePrivilegeVariant::Revoke)
+                && acl_mode.contains(AclMode::SELECT);
+            if let SystemObjectId::Object(ObjectId::Item(id)) = &target_id
+                && (!columns.is_empty() || revokes_columns)
+            {
+                let entry = self.catalog().get_entry(id);
+                let mut column_privileges = ColumnPrivileges::from_create_sql(entry.create_sql());
+                for grantee in &grantees {
+                    self.catalog().ensure_not_system_role(grantee)?;
+                    self.catalog().ensure_not_predefined_role(grantee)?;
+                    match variant {
+                        UpdatePrivilegeVariant::Grant => {
+                            for column in &columns {
+                                column_privileges.grant(
+                                    column.clone(),
+                                    MzAclItem {
+                                        grantee: *grantee,
+                                        grantor,
+                                        acl_mode,
+                                    },
+                                );
+                            }
+                        }
+                        UpdatePrivilegeVariant::Revoke if columns.is_empty() => {
+                            column_privileges.revoke_all_columns(grantee, AclMode::SELECT);
+                        }
+                        UpdatePrivilegeVariant::Revoke => {
+                            for column in &columns {
+                                column_privileges.revoke(column, grantee, acl_mode);
+                            }
+                        }
+                    }
+                }
+                if let Some(op) = update_column_privileges_op(entry, &column_privileges)? {
+                    ops.push(op);
+                }
+                if !columns.is_empty() {
+                    continue;
+                }
+            }
+
             let privileges = self
                 .catalog()
                 .get_privileges(&target_id, session.conn_id())
@@ -4778,6 +5060,7 @@ impl Coordinator {
             name: column_name,
             typ: column_type,
             sql: raw_sql_type,
+            source_export_details: None,
         }];
 
         self.catalog_transact_with_context(None, Some(ctx), ops)
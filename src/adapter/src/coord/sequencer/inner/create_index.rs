@@ -444,6 +444,7 @@ impl Coordinator {
                             keys,
                             cluster_id,
                             compaction_window,
+                            temporary,
                         },
                     if_not_exists,
                 },
@@ -466,7 +467,11 @@ impl Coordinator {
                 global_id,
                 keys: keys.into(),
                 on,
-                conn_id: None,
+                conn_id: if temporary {
+                    Some(ctx.session().conn_id().clone())
+                } else {
+                    None
+                },
                 resolved_ids,
                 cluster_id,
                 is_retained_metrics_object: false,
@@ -583,6 +583,7 @@ impl Coordinator {
                             non_null_assertions,
                             compaction_window,
                             refresh_schedule,
+                            temporary,
                             ..
                         },
                     drop_ids,
@@ -682,6 +683,11 @@ impl Coordinator {
                     raw_expr: raw_expr.into(),
                     locally_optimized_expr: local_mir_plan.expr().into(),
                     desc,
+                    conn_id: if temporary {
+                        Some(ctx.session().conn_id().clone())
+                    } else {
+                        None
+                    },
                     collections,
                     resolved_ids,
                     dependencies,
@@ -0,0 +1,103 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Durable pins of the history that snapshots read.
+//!
+//! A snapshot reads the shard of the materialized view it was taken from at a
+//! fixed timestamp. The read holds of the storage controller keep that
+//! timestamp readable while the coordinator runs, but they only exist in
+//! memory. Each snapshot therefore also registers a persist critical since
+//! handle on the shard of the view, which persist keeps across restarts and
+//! which compacts the shard no further than the `AS OF` of the snapshot. The
+//! handle is released when the snapshot is dropped.
+
+use mz_persist_client::Diagnostics;
+use mz_persist_client::critical::{CriticalReaderId, Opaque};
+use mz_persist_types::ShardId;
+use mz_repr::{GlobalId, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::sources::SourceData;
+use timely::progress::Antichain;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::coord::Coordinator;
+
+/// The high bits of the [`CriticalReaderId`] of every snapshot. The low bits
+/// are the ID of the snapshot.
+const SNAPSHOT_READER_ID_PREFIX: u64 = 0x736e_6170_7368_6f74;
+
+/// Returns the ID of the critical since handle of the snapshot `id`, which is
+/// derived from `id` so that it can be found again after a restart.
+fn snapshot_reader_id(id: GlobalId) -> CriticalReaderId {
+    let GlobalId::User(id) = id else {
+        panic!("snapshots have user IDs, got {id}");
+    };
+    format!("c{}", Uuid::from_u64_pair(SNAPSHOT_READER_ID_PREFIX, id))
+        .parse()
+        .expect("valid critical reader ID")
+}
+
+impl Coordinator {
+    /// Holds back the since of `shard_id`, the shard of the materialized view
+    /// that the snapshot `id` was taken from, at `as_of`, the timestamp of the
+    /// snapshot.
+    ///
+    /// Pinning a snapshot that is already pinned is a no-op.
+    pub(crate) async fn pin_snapshot(&self, id: GlobalId, shard_id: ShardId, as_of: Timestamp) {
+        self.downgrade_snapshot_since(id, shard_id, Antichain::from_elem(as_of))
+            .await;
+    }
+
+    /// Releases the hold that the dropped snapshot `id` had on `shard_id`.
+    pub(crate) async fn release_snapshot(&self, id: GlobalId, shard_id: ShardId) {
+        self.downgrade_snapshot_since(id, shard_id, Antichain::new())
+            .await;
+    }
+
+    /// Downgrades the critical since handle of the snapshot `id` on `shard_id`
+    /// to `since`. This is awaited rather than spawned, so that the pin and
+    /// the release of a snapshot can't be reordered.
+    async fn downgrade_snapshot_since(
+        &self,
+        id: GlobalId,
+        shard_id: ShardId,
+        since: Antichain<Timestamp>,
+    ) {
+        // Persist state can only be written to by the leader.
+        if self.controller.read_only() {
+            return;
+        }
+
+        let mut handle = self
+            .persist_client
+            .open_critical_since::<SourceData, (), Timestamp, StorageDiff>(
+                shard_id,
+                snapshot_reader_id(id),
+                Opaque::encode(&i64::MIN),
+                Diagnostics {
+                    shard_name: id.to_string(),
+                    handle_purpose: format!("snapshot {id}"),
+                },
+            )
+            .await
+            .expect("invalid persist usage");
+        // A handle that is opened for the first time starts at the since
+        // of the shard, which the read holds of the snapshot keep at or
+        // before `as_of`. Downgrading to a since the handle is already
+        // beyond leaves it where it is.
+        let opaque = handle.opaque().clone();
+        if let Err(actual) = handle
+            .compare_and_downgrade_since(&opaque, (&opaque, &since))
+            .await
+        {
+            warn!(%id, %shard_id, ?actual, "concurrent update of snapshot since handle");
+        }
+    }
+}
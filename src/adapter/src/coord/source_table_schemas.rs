@@ -0,0 +1,185 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Propagation of columns added to upstream tables into source tables.
+//!
+//! Tables created with `SCHEMA CHANGE POLICY = 'add_columns'` are periodically
+//! compared against their upstream table. Columns that were added upstream are
+//! added to the table the same way `ALTER TABLE ... ADD COLUMN` adds columns to
+//! regular tables: each column creates a new version of the table, and with it a
+//! new collection that continues writing to the same shard. The ingestion stalls
+//! on the change until the new version has been installed, so that no update
+//! is ever ingested without the new column.
+
+use std::time::Duration;
+
+use mz_adapter_types::dyncfgs::SOURCE_TABLE_SCHEMA_CHANGE_CHECK_INTERVAL;
+use mz_catalog::memory::objects::{CatalogItem, DataSourceDesc, TableDataSource};
+use mz_ore::collections::CollectionExt;
+use mz_ore::task;
+use mz_repr::CatalogItemId;
+use mz_sql::pure::SourceTableAddedColumn;
+use mz_sql_parser::ast::Statement;
+use mz_storage_types::sources::{SchemaChangePolicy, SourceExportDetails};
+use tracing::{info, warn};
+
+use crate::catalog;
+use crate::coord::{Coordinator, Message};
+
+/// The re-poll cadence used while checks are disabled (a zero
+/// [SOURCE_TABLE_SCHEMA_CHANGE_CHECK_INTERVAL]), so they can be re-enabled at
+/// runtime.
+const FALLBACK_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+impl Coordinator {
+    /// Schedules the next check of the upstream tables of source tables.
+    pub(crate) fn schedule_source_table_schema_check(&self) {
+        let interval =
+            SOURCE_TABLE_SCHEMA_CHANGE_CHECK_INTERVAL.get(self.catalog().system_config().dyncfgs());
+        let (sleep_for, msg) = if interval.is_zero() {
+            (FALLBACK_CHECK_INTERVAL, Message::SourceTableSchemaSchedule)
+        } else {
+            (interval, Message::SourceTableSchemaCheck)
+        };
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| "source_table_schema_check_schedule", async move {
+            tokio::time::sleep(sleep_for).await;
+            // Send is best-effort: if the coordinator is shutting down, drop.
+            let _ = internal_cmd_tx.send(msg);
+        });
+    }
+
+    /// Compares the source tables that follow the schema of their upstream
+    /// table against their upstream table, off the main coordinator thread.
+    pub(crate) fn check_source_table_schemas(&self) {
+        // Only the leader alters the catalog.
+        if self.controller.read_only() {
+            self.schedule_source_table_schema_check();
+            return;
+        }
+
+        let catalog = self.owned_catalog();
+        let tables: Vec<_> = catalog
+            .user_tables()
+            .filter_map(|entry| {
+                let CatalogItem::Table(table) = entry.item() else {
+                    return None;
+                };
+                let TableDataSource::DataSource {
+                    desc: DataSourceDesc::IngestionExport { details, .. },
+                    ..
+                } = &table.data_source
+                else {
+                    return None;
+                };
+                let policy = match details {
+                    SourceExportDetails::Postgres(details) => details.schema_change_policy,
+                    SourceExportDetails::MySql(details) => details.schema_change_policy,
+                    _ => SchemaChangePolicy::None,
+                };
+                (policy == SchemaChangePolicy::AddColumns)
+                    .then(|| (entry.id(), table.create_sql.clone()))
+            })
+            .collect();
+
+        if tables.is_empty() {
+            self.schedule_source_table_schema_check();
+            return;
+        }
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        let storage_configuration = self.controller.storage.config().clone();
+        task::spawn(|| "source_table_schema_check", async move {
+            let conn_catalog = catalog.for_system_session();
+            let mut changes = Vec::new();
+            for (id, create_sql) in tables {
+                let Some(create_sql) = create_sql else {
+                    continue;
+                };
+                let stmt = mz_sql::parse::parse(&create_sql)
+                    .expect("create_sql cannot be invalid")
+                    .into_element()
+                    .ast;
+                let stmt = match mz_sql::names::resolve(&conn_catalog, stmt) {
+                    Ok((stmt, _)) => stmt,
+                    Err(e) => {
+                        warn!(%id, "unable to resolve source table: {e}");
+                        continue;
+                    }
+                };
+                let Statement::CreateTableFromSource(stmt) = stmt else {
+                    continue;
+                };
+                match mz_sql::pure::purify_source_table_added_columns(
+                    catalog.for_system_session(),
+                    stmt,
+                    &storage_configuration,
+                )
+                .await
+                {
+                    Ok(columns) if columns.is_empty() => {}
+                    Ok(columns) => changes.push((id, columns)),
+                    Err(e) => warn!(%id, "unable to check upstream schema of source table: {e}"),
+                }
+            }
+            // Send is best-effort: if the coordinator is shutting down, drop.
+            let _ = internal_cmd_tx.send(Message::SourceTableSchemaChanges(changes));
+        });
+    }
+
+    /// Adds the columns that were added upstream to their source tables, then
+    /// schedules the next check.
+    pub(crate) async fn apply_source_table_schema_changes(
+        &mut self,
+        changes: Vec<(CatalogItemId, Vec<SourceTableAddedColumn>)>,
+    ) {
+        for (id, columns) in changes {
+            for column in columns {
+                // The table might have been dropped while we were checking it.
+                let Some(entry) = self.catalog().try_get_entry(&id) else {
+                    break;
+                };
+                let Some(desc) = entry.relation_desc_latest() else {
+                    break;
+                };
+                if desc.get_by_name(&column.name).is_some() {
+                    continue;
+                }
+
+                let (_, new_global_id) = match self.allocate_user_id().await {
+                    Ok(ids) => ids,
+                    Err(e) => {
+                        warn!(%id, "unable to allocate id for source table column: {e}");
+                        break;
+                    }
+                };
+                let name = column.name.clone();
+                // Each column gets a version, and transaction, of its own.
+                let ops = vec![catalog::Op::AlterAddColumn {
+                    id,
+                    new_global_id,
+                    name: column.name,
+                    typ: column.typ,
+                    sql: column.raw_sql_type,
+                    source_export_details: Some(column.details),
+                }];
+                match self.catalog_transact(None, ops).await {
+                    Ok(()) => info!(%id, "added upstream column {name} to source table"),
+                    Err(e) => {
+                        warn!(%id, "unable to add upstream column {name} to source table: {e}");
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.schedule_source_table_schema_check();
+    }
+}
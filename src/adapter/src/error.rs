@@ -880,6 +880,9 @@ impl AdapterError {
             AdapterError::PlanError(PlanError::ColumnAlreadyExists { .. }) => {
                 SqlState::DUPLICATE_COLUMN
             }
+            AdapterError::PlanError(PlanError::ColumnPermissionDenied { .. }) => {
+                SqlState::INSUFFICIENT_PRIVILEGE
+            }
             AdapterError::PlanError(PlanError::UnknownParameter(_)) => {
                 SqlState::UNDEFINED_PARAMETER
             }
@@ -201,6 +201,7 @@ impl AdapterNotice {
             AdapterNotice::NonApplicablePrivilegeTypes { .. } => Severity::Notice,
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
+                PlanNotice::PolicyDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => Severity::Notice,
@@ -310,6 +311,7 @@ impl AdapterNotice {
             AdapterNotice::NonApplicablePrivilegeTypes { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
+                PlanNotice::PolicyDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => {
@@ -368,6 +368,7 @@ impl<'a> DataflowBuilder<'a> {
                         dataflow.import_source(*id, log.variant.desc().typ().clone(), monotonic);
                     }
                     CatalogItem::Sink(_)
+                    | CatalogItem::Notification(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
@@ -501,7 +502,9 @@ impl<'a> DataflowBuilder<'a> {
             match self.catalog.get_entry(&id).item() {
                 CatalogItem::Source(source) => Ok(self.monotonic_source(&source.data_source)),
                 CatalogItem::Table(table) => match &table.data_source {
-                    TableDataSource::TableWrites { .. } => Ok(false),
+                    TableDataSource::TableWrites { .. } | TableDataSource::Snapshot { .. } => {
+                        Ok(false)
+                    }
                     TableDataSource::DataSource { desc, timeline: _ } => {
                         Ok(self.monotonic_source(desc))
                     }
@@ -552,6 +555,7 @@ impl<'a> DataflowBuilder<'a> {
                 | CatalogItem::Log(_)
                 | CatalogItem::MaterializedView(_)
                 | CatalogItem::Sink(_)
+                | CatalogItem::Notification(_)
                 | CatalogItem::Func(_) => Ok(false),
             }
         })?;
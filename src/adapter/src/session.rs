@@ -67,6 +67,10 @@ use mz_catalog::durable::Snapshot;
 
 const DUMMY_CONNECTION_ID: ConnectionId = ConnectionId::Static(0);
 
+/// The maximum number of notifications that can be pending delivery to a
+/// session. Further notifications are dropped until the session catches up.
+pub const MAX_PENDING_NOTIFICATIONS: usize = 1024;
+
 /// A session holds per-connection state.
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -100,6 +104,8 @@ pub struct Session {
     vars: SessionVars,
     notices_tx: mpsc::UnboundedSender<AdapterNotice>,
     notices_rx: mpsc::UnboundedReceiver<AdapterNotice>,
+    notifications_tx: mpsc::Sender<Notification>,
+    notifications_rx: mpsc::Receiver<Notification>,
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
@@ -213,6 +219,16 @@ pub struct SessionConfig {
     pub groups: Option<Vec<String>>,
 }
 
+/// An asynchronous notification delivered to a session that has issued
+/// `LISTEN` on the notification's channel.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    /// The channel on which the notification was raised.
+    pub channel: String,
+    /// The payload of the notification.
+    pub payload: String,
+}
+
 impl Session {
     /// Creates a new session for the specified connection ID.
     pub(crate) fn new(
@@ -321,6 +337,7 @@ impl Session {
         metrics: SessionMetrics,
     ) -> Session {
         let (notices_tx, notices_rx) = mpsc::unbounded_channel();
+        let (notifications_tx, notifications_rx) = mpsc::channel(MAX_PENDING_NOTIFICATIONS);
         let default_cluster = INTERNAL_USER_NAME_TO_DEFAULT_CLUSTER.get(&user);
         let user = User {
             name: user,
@@ -349,6 +366,8 @@ impl Session {
             vars,
             notices_tx,
             notices_rx,
+            notifications_tx,
+            notifications_rx,
             next_transaction_id: 0,
             secret_key: rand::random(),
             external_metadata_rx,
@@ -554,6 +573,25 @@ impl Session {
         notices
     }
 
+    /// Returns a channel on which to send asynchronous notifications to the
+    /// session. The channel holds at most [`MAX_PENDING_NOTIFICATIONS`]
+    /// notifications, as sessions that are not served over pgwire never
+    /// receive them.
+    pub fn retain_notification_transmitter(&self) -> mpsc::Sender<Notification> {
+        self.notifications_tx.clone()
+    }
+
+    /// Awaits a notification for a channel the session is listening on.
+    ///
+    /// This method is cancel safe.
+    pub async fn recv_notification(&mut self) -> Notification {
+        // This method is cancel safe because recv is cancel safe.
+        self.notifications_rx
+            .recv()
+            .await
+            .expect("Session also holds a sender, so recv won't ever return None")
+    }
+
     /// Returns Some if the notice should be reported, otherwise None.
     fn notice_filter(&self, notice: AdapterNotice) -> Option<AdapterNotice> {
         // Filter out low threshold severity.
@@ -232,6 +232,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedIntrospectionSubscribe
             | ExecuteResponse::CreatedSecret
             | ExecuteResponse::CreatedSink
+            | ExecuteResponse::CreatedNotification
             | ExecuteResponse::CreatedSource
             | ExecuteResponse::CreatedTable
             | ExecuteResponse::CreatedView
@@ -239,6 +240,7 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedMaterializedView
             | ExecuteResponse::CreatedType
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPolicy
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::DeclaredCursor
             | ExecuteResponse::Deleted(_)
@@ -246,10 +248,13 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPolicy
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(_)
+            | ExecuteResponse::Listen
+            | ExecuteResponse::Unlisten
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
@@ -164,6 +164,8 @@ pub fn analyze_audited_statement(
         StatementKind::CreateSchema => Some((StatementAction::Create, ObjectType::Schema)),
         StatementKind::CreateSecret => Some((StatementAction::Create, ObjectType::Secret)),
         StatementKind::CreateSink => Some((StatementAction::Create, ObjectType::Sink)),
+        StatementKind::CreateSnapshot => Some((StatementAction::Create, ObjectType::Table)),
+        StatementKind::CreateNotification => Some((StatementAction::Create, ObjectType::Sink)),
         StatementKind::CreateSource => Some((StatementAction::Create, ObjectType::Source)),
         StatementKind::CreateTable => Some((StatementAction::Create, ObjectType::Table)),
         StatementKind::CreateView => Some((StatementAction::Create, ObjectType::View)),
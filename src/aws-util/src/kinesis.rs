@@ -0,0 +1,44 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use aws_sdk_kinesis::error::SdkError;
+use aws_sdk_kinesis::operation::list_shards::ListShardsError;
+use aws_sdk_kinesis::types::Shard;
+use aws_types::sdk_config::SdkConfig;
+
+pub use aws_sdk_kinesis::Client;
+
+/// Creates a new client from an [SDK config](aws_types::sdk_config::SdkConfig)
+/// with Materialize-specific customizations.
+pub fn new_client(sdk_config: &SdkConfig) -> Client {
+    Client::new(sdk_config)
+}
+
+/// Lists all shards of `stream`, including the closed shards that are still
+/// within the retention period of the stream.
+pub async fn list_shards(
+    client: &Client,
+    stream: &str,
+) -> Result<Vec<Shard>, SdkError<ListShardsError>> {
+    let mut shards = vec![];
+    let mut next_token = None;
+    loop {
+        // The stream name must not be specified along with a token.
+        let request = match next_token {
+            Some(token) => client.list_shards().next_token(token),
+            None => client.list_shards().stream_name(stream),
+        };
+        let response = request.send().await?;
+        shards.extend(response.shards.unwrap_or_default());
+        next_token = response.next_token;
+        if next_token.is_none() {
+            return Ok(shards);
+        }
+    }
+}
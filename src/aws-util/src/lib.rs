@@ -20,6 +20,8 @@ use hyper_0_14::client::connect::dns::Name;
 use hyper_tls::HttpsConnector;
 use tower_service::Service;
 
+#[cfg(feature = "kinesis")]
+pub mod kinesis;
 #[cfg(feature = "s3")]
 pub mod s3;
 #[cfg(feature = "s3")]
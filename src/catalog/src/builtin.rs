@@ -1141,6 +1141,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         // mz_indexes is generated dynamically below with inlined builtin VALUES.
         Builtin::Table(&MZ_INDEX_COLUMNS),
         Builtin::Table(&MZ_TABLES),
+        Builtin::Table(&MZ_SNAPSHOTS),
         // mz_sources is generated dynamically below with inlined builtin VALUES.
         Builtin::Table(&MZ_SOURCE_REFERENCES),
         Builtin::MaterializedView(&MZ_POSTGRES_SOURCES),
@@ -1183,6 +1184,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::MaterializedView(&MZ_PENDING_CLUSTER_REPLICAS),
         Builtin::MaterializedView(&MZ_AUDIT_EVENTS),
         Builtin::Table(&MZ_STORAGE_USAGE_BY_SHARD),
+        Builtin::Table(&MZ_SNAPSHOT_STORAGE_USAGE),
         Builtin::Table(&MZ_EGRESS_IPS),
         Builtin::Table(&MZ_AWS_PRIVATELINK_CONNECTIONS),
         Builtin::Table(&MZ_AWS_CONNECTIONS),
@@ -1210,6 +1212,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::View(&MZ_OBJECT_HISTORY),
         Builtin::View(&MZ_OBJECT_LIFETIMES),
         Builtin::Table(&MZ_OBJECT_GLOBAL_IDS),
+        Builtin::Table(&MZ_COLUMN_PRIVILEGES),
         Builtin::View(&MZ_ARRANGEMENT_SHARING_PER_WORKER),
         Builtin::View(&MZ_ARRANGEMENT_SHARING),
         Builtin::View(&MZ_ARRANGEMENT_SIZES_PER_WORKER),
@@ -1333,6 +1336,7 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::View(&INFORMATION_SCHEMA_TABLES),
         Builtin::View(&INFORMATION_SCHEMA_TABLE_CONSTRAINTS),
         Builtin::View(&INFORMATION_SCHEMA_TABLE_PRIVILEGES),
+        Builtin::View(&INFORMATION_SCHEMA_COLUMN_PRIVILEGES),
         Builtin::View(&INFORMATION_SCHEMA_ROLE_TABLE_GRANTS),
         Builtin::View(&INFORMATION_SCHEMA_TRIGGERS),
         Builtin::View(&INFORMATION_SCHEMA_VIEWS),
@@ -390,6 +390,84 @@ WHERE
     }
 });
 
+pub static INFORMATION_SCHEMA_COLUMN_PRIVILEGES: LazyLock<BuiltinView> = LazyLock::new(|| {
+    BuiltinView {
+        name: "column_privileges",
+        schema: INFORMATION_SCHEMA,
+        oid: oid::VIEW_COLUMN_PRIVILEGES_OID,
+        desc: RelationDesc::builder()
+            .with_column("grantor", SqlScalarType::String.nullable(false))
+            .with_column("grantee", SqlScalarType::String.nullable(true))
+            .with_column("table_catalog", SqlScalarType::String.nullable(true))
+            .with_column("table_schema", SqlScalarType::String.nullable(false))
+            .with_column("table_name", SqlScalarType::String.nullable(false))
+            .with_column("column_name", SqlScalarType::String.nullable(false))
+            .with_column("privilege_type", SqlScalarType::String.nullable(true))
+            .with_column("is_grantable", SqlScalarType::String.nullable(false))
+            .finish(),
+        column_comments: BTreeMap::new(),
+        sql: "
+SELECT
+    grantor,
+    grantee,
+    table_catalog,
+    table_schema,
+    table_name,
+    column_name,
+    privilege_type,
+    is_grantable
+FROM
+    (SELECT
+        grantor.name AS grantor,
+        CASE mz_internal.mz_aclitem_grantee(privileges)
+            WHEN 'p' THEN 'PUBLIC'
+            ELSE grantee.name
+        END AS grantee,
+        table_catalog,
+        table_schema,
+        table_name,
+        column_name,
+        unnest(mz_internal.mz_format_privileges(mz_internal.mz_aclitem_privileges(privileges))) AS privilege_type,
+        -- ADMIN OPTION isn't implemented.
+        'NO' AS is_grantable
+    FROM
+        (SELECT
+            unnest(column_privileges.privileges) AS privileges,
+            column_privileges.column_name,
+            CASE
+                WHEN schemas.database_id IS NULL THEN current_database()
+                ELSE databases.name
+            END AS table_catalog,
+            schemas.name AS table_schema,
+            relations.name AS table_name
+        FROM
+            -- As in PostgreSQL, privileges on a relation apply to each of its columns.
+            (SELECT id, column_name, privileges FROM mz_internal.mz_column_privileges
+            UNION ALL
+            SELECT relations.id, columns.name, relations.privileges
+            FROM mz_catalog.mz_relations AS relations
+            JOIN mz_catalog.mz_columns AS columns ON relations.id = columns.id) AS column_privileges
+        JOIN mz_catalog.mz_relations AS relations ON column_privileges.id = relations.id
+        JOIN mz_catalog.mz_schemas AS schemas ON relations.schema_id = schemas.id
+        LEFT JOIN mz_catalog.mz_databases AS databases ON schemas.database_id = databases.id
+        WHERE schemas.database_id IS NULL OR databases.name = current_database())
+    JOIN mz_catalog.mz_roles AS grantor ON mz_internal.mz_aclitem_grantor(privileges) = grantor.id
+    LEFT JOIN mz_catalog.mz_roles AS grantee ON mz_internal.mz_aclitem_grantee(privileges) = grantee.id)
+WHERE
+    privilege_type IN ('SELECT', 'INSERT', 'UPDATE')
+    -- WHERE clause is not guaranteed to short-circuit and 'PUBLIC' will cause an error when passed
+    -- to pg_has_role. Therefore we need to use a CASE statement.
+    AND CASE
+        WHEN grantee = 'PUBLIC' THEN true
+        ELSE mz_catalog.mz_is_superuser()
+            OR pg_has_role(current_role, grantee, 'USAGE')
+            OR pg_has_role(current_role, grantor, 'USAGE')
+    END",
+        access: vec![PUBLIC_SELECT],
+        ontology: None,
+    }
+});
+
 pub static INFORMATION_SCHEMA_TRIGGERS: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView {
     name: "triggers",
     schema: INFORMATION_SCHEMA,
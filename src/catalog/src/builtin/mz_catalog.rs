@@ -1112,6 +1112,61 @@ WHERE
     }
 });
 
+pub static MZ_SNAPSHOTS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_snapshots",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_SNAPSHOTS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column(
+            "materialized_view_id",
+            SqlScalarType::String.nullable(false),
+        )
+        .with_column("as_of", SqlScalarType::MzTimestamp.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        (
+            "id",
+            "The ID of the snapshot. Corresponds to `mz_tables.id`.",
+        ),
+        (
+            "materialized_view_id",
+            "The ID of the materialized view the snapshot was taken from. Corresponds to `mz_materialized_views.id`.",
+        ),
+        ("as_of", "The timestamp at which the snapshot is pinned."),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+    ontology: Some(Ontology {
+        entity_name: "snapshot",
+        description: "A table pinned to the contents of a materialized view at a fixed timestamp",
+        links: &const {
+            [
+                OntologyLink {
+                    name: "details_of",
+                    target: "table",
+                    properties: LinkProperties::fk("id", "id", Cardinality::OneToOne),
+                },
+                OntologyLink {
+                    name: "snapshot_of",
+                    target: "mv",
+                    properties: LinkProperties::fk(
+                        "materialized_view_id",
+                        "id",
+                        Cardinality::ManyToOne,
+                    ),
+                },
+            ]
+        },
+        column_semantic_types: &[
+            ("id", SemanticType::CatalogItemId),
+            ("materialized_view_id", SemanticType::CatalogItemId),
+            ("as_of", SemanticType::MzTimestamp),
+        ],
+    }),
+});
+
 pub static MZ_SSH_TUNNEL_CONNECTIONS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     name: "mz_ssh_tunnel_connections",
     schema: MZ_CATALOG_SCHEMA,
@@ -2982,6 +3037,13 @@ SELECT
 FROM
     mz_internal.mz_storage_shards
     JOIN mz_internal.mz_storage_usage_by_shard USING (shard_id)
+-- Snapshots read the shard of their materialized view, which is already
+-- accounted for under the ID of the view.
+WHERE object_id NOT IN (
+    SELECT global_id
+    FROM mz_internal.mz_object_global_ids
+    JOIN mz_catalog.mz_snapshots USING (id)
+)
 GROUP BY object_id, collection_timestamp",
     access: vec![PUBLIC_SELECT],
     ontology: Some(Ontology {
@@ -3032,6 +3094,15 @@ most_recent_collection_timestamp_by_shard AS (
     SELECT shard_id, max(collection_timestamp) AS collection_timestamp
     FROM recent_storage_usage_by_shard
     GROUP BY shard_id
+),
+
+-- Snapshots read the shard of their materialized view, which is already
+-- accounted for under the ID of the view. They are instead charged for the
+-- history they retain in that shard.
+snapshot_global_ids AS (
+    SELECT global_id
+    FROM mz_internal.mz_object_global_ids
+    JOIN mz_catalog.mz_snapshots USING (id)
 )
 
 SELECT
@@ -3044,7 +3115,18 @@ FROM
     LEFT JOIN recent_storage_usage_by_shard
         ON mz_storage_shards.shard_id = recent_storage_usage_by_shard.shard_id
         AND most_recent_collection_timestamp_by_shard.collection_timestamp = recent_storage_usage_by_shard.collection_timestamp
-GROUP BY object_id",
+WHERE object_id NOT IN (SELECT global_id FROM snapshot_global_ids)
+GROUP BY object_id
+
+UNION ALL
+
+SELECT
+    mz_snapshots.id AS object_id,
+    mz_snapshot_storage_usage.size_bytes
+FROM
+    mz_catalog.mz_snapshots
+    LEFT JOIN mz_internal.mz_snapshot_storage_usage
+        ON mz_snapshots.id = mz_snapshot_storage_usage.id",
     access: vec![PUBLIC_SELECT],
     ontology: Some(Ontology {
         entity_name: "recent_storage",
@@ -2466,6 +2466,49 @@ pub static MZ_STORAGE_USAGE_BY_SHARD: LazyLock<BuiltinTable> = LazyLock::new(||
     }),
 });
 
+pub static MZ_SNAPSHOT_STORAGE_USAGE: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_snapshot_storage_usage",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_SNAPSHOT_STORAGE_USAGE_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("size_bytes", SqlScalarType::UInt64.nullable(false))
+        .with_column(
+            "collection_timestamp",
+            SqlScalarType::TimestampTz { precision: None }.nullable(false),
+        )
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        (
+            "id",
+            "The ID of the snapshot. Corresponds to `mz_snapshots.id`.",
+        ),
+        (
+            "size_bytes",
+            "The number of storage bytes of history that the snapshot retains in the shard of its materialized view.",
+        ),
+        (
+            "collection_timestamp",
+            "The time at which the size was collected.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+    ontology: Some(Ontology {
+        entity_name: "snapshot_storage_usage",
+        description: "Storage retained by snapshots",
+        links: &const { [] },
+        column_semantic_types: &const {
+            [
+                ("id", SemanticType::CatalogItemId),
+                ("size_bytes", SemanticType::ByteCount),
+                ("collection_timestamp", SemanticType::WallclockTimestamp),
+            ]
+        },
+    }),
+});
+
 pub static MZ_AWS_CONNECTIONS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     name: "mz_aws_connections",
     schema: MZ_INTERNAL_SCHEMA,
@@ -3849,6 +3892,43 @@ pub static MZ_OBJECT_GLOBAL_IDS: LazyLock<BuiltinTable> = LazyLock::new(|| Built
     }),
 });
 
+pub static MZ_COLUMN_PRIVILEGES: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_column_privileges",
+    schema: MZ_INTERNAL_SCHEMA,
+    oid: oid::TABLE_MZ_COLUMN_PRIVILEGES_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("column_name", SqlScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            SqlScalarType::Array(Box::new(SqlScalarType::MzAclItem)).nullable(false),
+        )
+        .with_key(vec![0, 1])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        (
+            "id",
+            "The ID of the relation. Corresponds to `mz_relations.id`.",
+        ),
+        ("column_name", "The name of the column."),
+        ("privileges", "The privileges belonging to the column."),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+    ontology: Some(Ontology {
+        entity_name: "column_privilege",
+        description: "Privileges granted on individual columns of a relation",
+        links: &const {
+            [OntologyLink {
+                name: "privileges_of",
+                target: "relation",
+                properties: LinkProperties::fk("id", "id", Cardinality::ManyToOne),
+            }]
+        },
+        column_semantic_types: &[("id", SemanticType::CatalogItemId)],
+    }),
+});
+
 // TODO (SangJunBak): Remove once mz_object_history is released and used in the Console https://github.com/MaterializeInc/console/issues/3342
 pub static MZ_OBJECT_LIFETIMES: LazyLock<BuiltinView> = LazyLock::new(|| BuiltinView {
     name: "mz_object_lifetimes",
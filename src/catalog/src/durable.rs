@@ -73,6 +73,7 @@ pub(crate) const CATALOG_CONTENT_VERSION_KEY: &str = "catalog_content_version";
 pub const BUILTIN_MIGRATION_SHARD_KEY: &str = "builtin_migration_shard";
 pub const EXPRESSION_CACHE_SHARD_KEY: &str = "expression_cache_shard";
 pub const MOCK_AUTHENTICATION_NONCE_KEY: &str = "mock_authentication_nonce";
+pub(crate) const TEMPORARY_COLLECTION_KEY_PREFIX: &str = "temporary_collection.";
 
 #[derive(Clone, Debug)]
 pub struct BootstrapArgs {
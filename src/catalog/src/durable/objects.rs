@@ -1516,9 +1516,11 @@ pub fn item_type(create_sql: &str) -> CatalogItemType {
     };
 
     match next_token {
-        Some("TABLE") => CatalogItemType::Table,
+        // Snapshots are read-only tables.
+        Some("TABLE") | Some("SNAPSHOT") => CatalogItemType::Table,
         Some("SOURCE") | Some("SUBSOURCE") => CatalogItemType::Source,
-        Some("SINK") => CatalogItemType::Sink,
+        // Notifications are sinks that deliver to listening sessions.
+        Some("SINK") | Some("NOTIFICATION") => CatalogItemType::Sink,
         Some("VIEW") => CatalogItemType::View,
         Some("MATERIALIZED") => {
             assert_eq!(tokens.next(), Some("VIEW"));
@@ -67,8 +67,8 @@ use crate::durable::{
     DATABASE_ID_ALLOC_KEY, DefaultPrivilege, DurableCatalogError, DurableCatalogState,
     EXPRESSION_CACHE_SHARD_KEY, MOCK_AUTHENTICATION_NONCE_KEY, NetworkPolicy, OID_ALLOC_KEY,
     SCHEMA_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_ITEM_ALLOC_KEY,
-    SYSTEM_REPLICA_ID_ALLOC_KEY, Snapshot, SystemConfiguration, USER_ITEM_ALLOC_KEY,
-    USER_NETWORK_POLICY_ID_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
+    SYSTEM_REPLICA_ID_ALLOC_KEY, Snapshot, SystemConfiguration, TEMPORARY_COLLECTION_KEY_PREFIX,
+    USER_ITEM_ALLOC_KEY, USER_NETWORK_POLICY_ID_ALLOC_KEY, USER_ROLE_ID_ALLOC_KEY,
 };
 use crate::memory::objects::{StateDiff, StateUpdate, StateUpdateKind};
 
@@ -2010,6 +2010,34 @@ impl<'a> Transaction<'a> {
         )
     }
 
+    /// Records that the storage collection `id` belongs to a temporary item.
+    ///
+    /// Temporary items are not durable, so this record is what identifies
+    /// their storage collections if the process exits before dropping them.
+    pub fn insert_temporary_collection(&mut self, id: GlobalId) -> Result<(), CatalogError> {
+        self.set_setting(
+            format!("{TEMPORARY_COLLECTION_KEY_PREFIX}{id}"),
+            Some(String::new()),
+        )
+    }
+
+    /// Removes the record of the temporary storage collection `id`, if any.
+    pub fn remove_temporary_collection(&mut self, id: GlobalId) -> Result<(), CatalogError> {
+        self.set_setting(format!("{TEMPORARY_COLLECTION_KEY_PREFIX}{id}"), None)
+    }
+
+    /// Returns the storage collections recorded as belonging to temporary
+    /// items.
+    pub fn get_temporary_collections(&self) -> BTreeSet<GlobalId> {
+        let mut ids = BTreeSet::new();
+        self.settings.for_values(|key, _| {
+            if let Some(id) = key.name.strip_prefix(TEMPORARY_COLLECTION_KEY_PREFIX) {
+                ids.insert(id.parse().expect("valid GlobalId"));
+            }
+        });
+        ids
+    }
+
     /// Updates the catalog `with_0dt_deployment_max_wait` "config" value to
     /// match the `with_0dt_deployment_max_wait` "system var" value.
     ///
@@ -38,7 +38,8 @@ use mz_repr::{
 };
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{
-    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, Expr, Raw, RawDataType, Statement,
+    ColumnDef, ColumnOption, ColumnOptionDef, ColumnVersioned, CreateTableFromSourceStatement,
+    Expr, Raw, RawDataType, Statement, TableFromSourceColumns, TableFromSourceOptionName,
     UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_sql::catalog::{
@@ -52,10 +53,11 @@ use mz_sql::names::{
     QualifiedSchemaName, ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier,
 };
 use mz_sql::plan::{
-    AutoScalingStrategy, ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
-    ConnectionDetails, CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant,
-    CreateSourcePlan, HirRelationExpr, NetworkPolicyRule, OnTimeoutAction, PlanError,
-    WebhookBodyFormat, WebhookHeaders, WebhookValidation,
+    AutoScalingStrategy, ClusterSchedule, ColumnPrivileges, ComputeReplicaConfig,
+    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
+    CreateClusterPlan, CreateClusterVariant, CreateSourcePlan, HirRelationExpr, NetworkPolicyRule,
+    OnTimeoutAction, PlanError, RowLevelSecurityAction, WebhookBodyFormat, WebhookHeaders,
+    WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -762,6 +764,10 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.writable_table_details()
     }
 
+    fn snapshot_details(&self) -> Option<(GlobalId, Timestamp)> {
+        self.entry.snapshot_details()
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         self.entry.replacement_target()
     }
@@ -807,6 +813,10 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.is_progress_source()
     }
 
+    fn is_notification(&self) -> bool {
+        self.entry.is_notification()
+    }
+
     fn progress_id(&self) -> Option<CatalogItemId> {
         self.entry.progress_id()
     }
@@ -846,6 +856,7 @@ pub enum CatalogItem {
     View(View),
     MaterializedView(MaterializedView),
     Sink(Sink),
+    Notification(Notification),
     Index(Index),
     Type(Type),
     Func(Func),
@@ -902,6 +913,9 @@ impl Table {
             // (including system tables), so they are realtime.
             TableDataSource::TableWrites { .. } => Timeline::EpochMilliseconds,
             TableDataSource::DataSource { timeline, .. } => timeline.clone(),
+            // Snapshots are pinned views of materialized views, which are
+            // always in the realtime timeline.
+            TableDataSource::Snapshot { .. } => Timeline::EpochMilliseconds,
         }
     }
 
@@ -957,6 +971,11 @@ pub enum TableDataSource {
         desc: DataSourceDesc,
         timeline: Timeline,
     },
+
+    /// The table is a read-only view of the identified materialized view's
+    /// contents as of `as_of`. It shares its persist shard with the
+    /// materialized view, and holds back that shard's since to `as_of`.
+    Snapshot { from: GlobalId, as_of: Timestamp },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -1255,7 +1274,10 @@ impl Source {
                     // for users.
                     GenericSourceConnection::Postgres(_)
                     | GenericSourceConnection::MySql(_)
-                    | GenericSourceConnection::SqlServer(_) => 0,
+                    | GenericSourceConnection::SqlServer(_)
+                    | GenericSourceConnection::MongoDb(_)
+                    | GenericSourceConnection::S3(_)
+                    | GenericSourceConnection::Iceberg(_) => 0,
                     GenericSourceConnection::LoadGenerator(lg) => match lg.load_generator {
                         // Load generators that output data in their primary shard
                         LoadGenerator::Clock
@@ -1266,7 +1288,10 @@ impl Source {
                         | LoadGenerator::Marketing
                         | LoadGenerator::Tpch { .. } => 0,
                     },
-                    GenericSourceConnection::Kafka(_) => 1,
+                    GenericSourceConnection::Kafka(_)
+                    | GenericSourceConnection::Kinesis(_)
+                    | GenericSourceConnection::Nats(_)
+                    | GenericSourceConnection::Mqtt(_) => 1,
                 }
             }
             //  DataSourceDesc::IngestionExport represents a subsource, which
@@ -1344,7 +1369,10 @@ impl Sink {
     pub fn combined_format(&self) -> Option<Cow<'_, str>> {
         match &self.connection {
             StorageSinkConnection::Kafka(connection) => Some(connection.format.get_format_name()),
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Http(_)
+            | StorageSinkConnection::S3(_) => None,
         }
     }
 
@@ -1360,7 +1388,10 @@ impl Sink {
                 let value_format = connection.format.value_format.get_format_name();
                 Some((key_format, value_format))
             }
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::Http(_)
+            | StorageSinkConnection::S3(_) => None,
         }
     }
 
@@ -1374,6 +1405,29 @@ impl Sink {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    /// Parse-able SQL that defines this notification.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this notification from outside the catalog.
+    pub global_id: GlobalId,
+    /// Collection whose changes are delivered as notifications.
+    pub from: GlobalId,
+    /// Columns of the collection the notification was created on.
+    pub desc: RelationDesc,
+    /// Other catalog objects this notification references.
+    pub resolved_ids: ResolvedIds,
+    /// Cluster the subscribe maintaining this notification runs on.
+    pub cluster_id: ClusterId,
+}
+
+impl Notification {
+    /// The single [`GlobalId`] that this notification can be referenced by.
+    pub fn global_id(&self) -> GlobalId {
+        self.global_id
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct View {
     /// Parse-able SQL that defines this view.
@@ -1414,6 +1468,8 @@ pub struct MaterializedView {
     pub locally_optimized_expr: Arc<OptimizedMirRelationExpr>,
     /// [`VersionedRelationDesc`] of this materialized view, derived from the `create_sql`.
     pub desc: VersionedRelationDesc,
+    /// If created in the `TEMPORARY` schema, the [`ConnectionId`] for that session.
+    pub conn_id: Option<ConnectionId>,
     /// Other catalog items that this materialized view references, determined at name resolution.
     pub resolved_ids: ResolvedIds,
     /// All of the catalog objects that are referenced by this view.
@@ -1512,6 +1568,7 @@ impl MaterializedView {
         let rpl_stmt = parse(&replacement.create_sql);
         let new_stmt = mz_sql::ast::CreateMaterializedViewStatement {
             if_exists: old_stmt.if_exists,
+            temporary: old_stmt.temporary,
             name: old_stmt.name,
             columns: rpl_stmt.columns,
             replacement_for: None,
@@ -1542,6 +1599,7 @@ impl MaterializedView {
             raw_expr: replacement.raw_expr,
             locally_optimized_expr: replacement.locally_optimized_expr,
             desc: replacement.desc,
+            conn_id: self.conn_id.take(),
             resolved_ids,
             dependencies,
             replacement_target: None,
@@ -1724,7 +1782,8 @@ impl CatalogItem {
             CatalogItem::Table(_) => CatalogItemType::Table,
             CatalogItem::Source(_) => CatalogItemType::Source,
             CatalogItem::Log(_) => CatalogItemType::Source,
-            CatalogItem::Sink(_) => CatalogItemType::Sink,
+            // Notifications are dropped, owned and audited like sinks.
+            CatalogItem::Sink(_) | CatalogItem::Notification(_) => CatalogItemType::Sink,
             CatalogItem::View(_) => CatalogItemType::View,
             CatalogItem::MaterializedView(_) => CatalogItemType::MaterializedView,
             CatalogItem::Index(_) => CatalogItemType::Index,
@@ -1741,6 +1800,7 @@ impl CatalogItem {
             CatalogItem::Source(source) => source.global_id,
             CatalogItem::Log(log) => log.global_id,
             CatalogItem::Sink(sink) => sink.global_id,
+            CatalogItem::Notification(notification) => notification.global_id,
             CatalogItem::View(view) => view.global_id,
             CatalogItem::MaterializedView(mv) => {
                 return itertools::Either::Left(mv.collections.values().copied());
@@ -1765,6 +1825,7 @@ impl CatalogItem {
             CatalogItem::Source(source) => source.global_id,
             CatalogItem::Log(log) => log.global_id,
             CatalogItem::Sink(sink) => sink.global_id,
+            CatalogItem::Notification(notification) => notification.global_id,
             CatalogItem::View(view) => view.global_id,
             CatalogItem::MaterializedView(mv) => mv.global_id_writes(),
             CatalogItem::Index(index) => index.global_id,
@@ -1837,6 +1898,7 @@ impl CatalogItem {
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Sink(_) => true,
             CatalogItem::Log(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::View(_)
             | CatalogItem::Index(_)
             | CatalogItem::Type(_)
@@ -1866,6 +1928,7 @@ impl CatalogItem {
             CatalogItem::Func(_)
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Secret(_)
             | CatalogItem::Connection(_)
             | CatalogItem::Type(_) => None,
@@ -1927,6 +1990,7 @@ impl CatalogItem {
             CatalogItem::Func(_) => &*EMPTY,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
+            CatalogItem::Notification(notification) => &notification.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
             CatalogItem::Log(_) => &*EMPTY,
             CatalogItem::Table(table) => &table.resolved_ids,
@@ -1951,6 +2015,7 @@ impl CatalogItem {
             CatalogItem::Func(_) => {}
             CatalogItem::Index(_) => {}
             CatalogItem::Sink(_) => {}
+            CatalogItem::Notification(_) => {}
             CatalogItem::Source(_) => {}
             CatalogItem::Log(_) => {}
             CatalogItem::Table(_) => {}
@@ -1972,10 +2037,11 @@ impl CatalogItem {
             CatalogItem::View(view) => view.conn_id.as_ref(),
             CatalogItem::Index(index) => index.conn_id.as_ref(),
             CatalogItem::Table(table) => table.conn_id.as_ref(),
+            CatalogItem::MaterializedView(mv) => mv.conn_id.as_ref(),
             CatalogItem::Log(_)
             | CatalogItem::Source(_)
             | CatalogItem::Sink(_)
-            | CatalogItem::MaterializedView(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Secret(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
@@ -1990,10 +2056,11 @@ impl CatalogItem {
             CatalogItem::View(view) => view.conn_id = conn_id,
             CatalogItem::Index(index) => index.conn_id = conn_id,
             CatalogItem::Table(table) => table.conn_id = conn_id,
+            CatalogItem::MaterializedView(mv) => mv.conn_id = conn_id,
             CatalogItem::Log(_)
             | CatalogItem::Source(_)
             | CatalogItem::Sink(_)
-            | CatalogItem::MaterializedView(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Secret(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
@@ -2017,6 +2084,7 @@ impl CatalogItem {
             CatalogItem::Log(_)
             | CatalogItem::Source(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
             | CatalogItem::Type(_)
@@ -2072,6 +2140,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Sink(i))
             }
+            CatalogItem::Notification(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Notification(i))
+            }
             CatalogItem::View(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -2145,6 +2218,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Sink(i))
             }
+            CatalogItem::Notification(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Notification(i))
+            }
             CatalogItem::View(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -2207,6 +2285,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql);
                 CatalogItem::Sink(i)
             }
+            CatalogItem::Notification(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Notification(i)
+            }
             CatalogItem::View(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql);
@@ -2357,6 +2440,139 @@ impl CatalogItem {
         }
     }
 
+    /// Updates the row level security configuration of an item. Returns an error if this item
+    /// does not support row level security.
+    pub fn update_row_level_security(&mut self, action: &RowLevelSecurityAction) -> Result<(), ()> {
+        let update = |ast: &mut Statement<Raw>| {
+            // Each statement type has unique option types. This macro handles them commonly.
+            macro_rules! update_row_level_security {
+                ( $options:expr, $opt:ident, $name:ident ) => {{
+                    let options = $options;
+                    match action {
+                        RowLevelSecurityAction::SetEnabled(enabled) => {
+                            options
+                                .retain(|o| o.name != mz_sql_parser::ast::$name::RowLevelSecurity);
+                            if *enabled {
+                                options.push(mz_sql_parser::ast::$opt {
+                                    name: mz_sql_parser::ast::$name::RowLevelSecurity,
+                                    value: Some(WithOptionValue::Value(Value::Boolean(true))),
+                                });
+                            }
+                        }
+                        RowLevelSecurityAction::CreatePolicy(policy) => {
+                            let existing = options.iter_mut().find_map(|o| match o {
+                                mz_sql_parser::ast::$opt {
+                                    name: mz_sql_parser::ast::$name::RowLevelSecurityPolicies,
+                                    value: Some(WithOptionValue::RowLevelSecurityPolicies(policies)),
+                                } => Some(policies),
+                                _ => None,
+                            });
+                            match existing {
+                                Some(policies) => policies.push(policy.clone()),
+                                None => options.push(mz_sql_parser::ast::$opt {
+                                    name: mz_sql_parser::ast::$name::RowLevelSecurityPolicies,
+                                    value: Some(WithOptionValue::RowLevelSecurityPolicies(vec![
+                                        policy.clone(),
+                                    ])),
+                                }),
+                            }
+                        }
+                        RowLevelSecurityAction::DropPolicy(name) => {
+                            options.retain_mut(|o| match o {
+                                mz_sql_parser::ast::$opt {
+                                    name: mz_sql_parser::ast::$name::RowLevelSecurityPolicies,
+                                    value: Some(WithOptionValue::RowLevelSecurityPolicies(policies)),
+                                } => {
+                                    policies.retain(|p| &p.name != name);
+                                    !policies.is_empty()
+                                }
+                                _ => true,
+                            });
+                        }
+                    }
+                }};
+            }
+            match ast {
+                Statement::CreateTable(stmt) => {
+                    update_row_level_security!(&mut stmt.with_options, TableOption, TableOptionName)
+                }
+                Statement::CreateTableFromSource(stmt) => update_row_level_security!(
+                    &mut stmt.with_options,
+                    TableFromSourceOption,
+                    TableFromSourceOptionName
+                ),
+                Statement::CreateSource(stmt) => update_row_level_security!(
+                    &mut stmt.with_options,
+                    CreateSourceOption,
+                    CreateSourceOptionName
+                ),
+                Statement::CreateView(stmt) => update_row_level_security!(
+                    &mut stmt.definition.with_options,
+                    ViewOption,
+                    ViewOptionName
+                ),
+                Statement::CreateMaterializedView(stmt) => update_row_level_security!(
+                    &mut stmt.with_options,
+                    MaterializedViewOption,
+                    MaterializedViewOptionName
+                ),
+                _ => return Err(()),
+            }
+            Ok(())
+        };
+
+        self.update_sql(update)
+    }
+
+    /// Replaces the privileges on the columns of an item. Returns an error if this item does not
+    /// support privileges on columns.
+    pub fn update_column_privileges(&mut self, privileges: &ColumnPrivileges) -> Result<(), ()> {
+        let update = |ast: &mut Statement<Raw>| {
+            // Each statement type has unique option types. This macro handles them commonly.
+            macro_rules! update_column_privileges {
+                ( $options:expr, $opt:ident, $name:ident ) => {{
+                    let options = $options;
+                    options.retain(|o| o.name != mz_sql_parser::ast::$name::ColumnPrivileges);
+                    if !privileges.is_empty() {
+                        options.push(mz_sql_parser::ast::$opt {
+                            name: mz_sql_parser::ast::$name::ColumnPrivileges,
+                            value: Some(WithOptionValue::ColumnPrivileges(privileges.to_ast())),
+                        });
+                    }
+                }};
+            }
+            match ast {
+                Statement::CreateTable(stmt) => {
+                    update_column_privileges!(&mut stmt.with_options, TableOption, TableOptionName)
+                }
+                Statement::CreateTableFromSource(stmt) => update_column_privileges!(
+                    &mut stmt.with_options,
+                    TableFromSourceOption,
+                    TableFromSourceOptionName
+                ),
+                Statement::CreateSource(stmt) => update_column_privileges!(
+                    &mut stmt.with_options,
+                    CreateSourceOption,
+                    CreateSourceOptionName
+                ),
+                Statement::CreateView(stmt) => update_column_privileges!(
+                    &mut stmt.definition.with_options,
+                    ViewOption,
+                    ViewOptionName
+                ),
+                Statement::CreateMaterializedView(stmt) => update_column_privileges!(
+                    &mut stmt.with_options,
+                    MaterializedViewOption,
+                    MaterializedViewOptionName
+                ),
+                _ => return Err(()),
+            }
+            Ok(())
+        };
+
+        self.update_sql(update)
+    }
+
     pub fn add_column(
         &mut self,
         name: ColumnName,
@@ -2371,24 +2587,31 @@ impl CatalogItem {
         };
         let next_version = table.desc.add_column(name.clone(), typ);
 
+        let version = ColumnOptionDef {
+            name: None,
+            option: ColumnOption::Versioned {
+                action: ColumnVersioned::Added,
+                version: next_version.into(),
+            },
+        };
+        let column = ColumnDef {
+            name: name.into(),
+            data_type: sql,
+            collation: None,
+            options: vec![version],
+        };
         let update = |mut ast: &mut Statement<Raw>| match &mut ast {
             Statement::CreateTable(stmt) => {
-                let version = ColumnOptionDef {
-                    name: None,
-                    option: ColumnOption::Versioned {
-                        action: ColumnVersioned::Added,
-                        version: next_version.into(),
-                    },
-                };
-                let column = ColumnDef {
-                    name: name.into(),
-                    data_type: sql,
-                    collation: None,
-                    options: vec![version],
-                };
                 stmt.columns.push(column);
                 Ok(())
             }
+            Statement::CreateTableFromSource(CreateTableFromSourceStatement {
+                columns: TableFromSourceColumns::Defined(columns),
+                ..
+            }) => {
+                columns.push(column);
+                Ok(())
+            }
             _ => Err(()),
         };
 
@@ -2397,6 +2620,27 @@ impl CatalogItem {
         Ok(next_version)
     }
 
+    /// Replaces the `DETAILS` option of a `CREATE TABLE .. FROM SOURCE` statement, which describes
+    /// the upstream table the source table reads from.
+    pub fn set_source_export_details(&mut self, details: String) -> Result<(), PlanError> {
+        let update = |ast: &mut Statement<Raw>| match ast {
+            Statement::CreateTableFromSource(stmt) => {
+                let option = stmt
+                    .with_options
+                    .iter_mut()
+                    .find(|o| o.name == TableFromSourceOptionName::Details)
+                    .ok_or(())?;
+                option.value = Some(WithOptionValue::Value(Value::String(details)));
+                Ok(())
+            }
+            _ => Err(()),
+        };
+
+        self.update_sql(update).map_err(|()| {
+            PlanError::Unstructured("expected CREATE TABLE .. FROM SOURCE statement".to_string())
+        })
+    }
+
     /// Updates the create_sql field of this item. Returns an error if this is a builtin item,
     /// otherwise returns f's result.
     pub fn update_sql<F, T>(&mut self, f: F) -> Result<T, ()>
@@ -2408,6 +2652,7 @@ impl CatalogItem {
             | CatalogItem::Type(Type { create_sql, .. })
             | CatalogItem::Source(Source { create_sql, .. }) => create_sql.as_mut(),
             CatalogItem::Sink(Sink { create_sql, .. })
+            | CatalogItem::Notification(Notification { create_sql, .. })
             | CatalogItem::View(View { create_sql, .. })
             | CatalogItem::MaterializedView(MaterializedView { create_sql, .. })
             | CatalogItem::Index(Index { create_sql, .. })
@@ -2444,6 +2689,7 @@ impl CatalogItem {
             | CatalogItem::View(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2465,6 +2711,7 @@ impl CatalogItem {
             CatalogItem::Table(_)
             | CatalogItem::Log(_)
             | CatalogItem::View(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2489,6 +2736,7 @@ impl CatalogItem {
                 | DataSourceDesc::Catalog => None,
             },
             CatalogItem::Sink(sink) => Some(sink.cluster_id),
+            CatalogItem::Notification(notification) => Some(notification.cluster_id),
             CatalogItem::Table(_)
             | CatalogItem::Log(_)
             | CatalogItem::View(_)
@@ -2510,6 +2758,7 @@ impl CatalogItem {
             CatalogItem::Log(_)
             | CatalogItem::View(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2531,6 +2780,7 @@ impl CatalogItem {
             CatalogItem::Log(_)
             | CatalogItem::View(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2555,6 +2805,7 @@ impl CatalogItem {
             CatalogItem::Log(_)
             | CatalogItem::View(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2575,6 +2826,7 @@ impl CatalogItem {
             | CatalogItem::View(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
@@ -2619,6 +2871,11 @@ impl CatalogItem {
                 (index.create_sql.clone(), index.global_id, BTreeMap::new())
             }
             CatalogItem::Sink(sink) => (sink.create_sql.clone(), sink.global_id, BTreeMap::new()),
+            CatalogItem::Notification(notification) => (
+                notification.create_sql.clone(),
+                notification.global_id,
+                BTreeMap::new(),
+            ),
             CatalogItem::Type(typ) => {
                 let create_sql = typ
                     .create_sql
@@ -2671,6 +2928,11 @@ impl CatalogItem {
             }
             CatalogItem::Index(index) => (index.create_sql, index.global_id, BTreeMap::new()),
             CatalogItem::Sink(sink) => (sink.create_sql, sink.global_id, BTreeMap::new()),
+            CatalogItem::Notification(notification) => (
+                notification.create_sql,
+                notification.global_id,
+                BTreeMap::new(),
+            ),
             CatalogItem::Type(typ) => {
                 let create_sql = typ.create_sql.expect("builtin types cannot be serialized");
                 (create_sql, typ.global_id, BTreeMap::new())
@@ -2693,6 +2955,7 @@ impl CatalogItem {
             CatalogItem::Log(log) => return Some(log.global_id),
             CatalogItem::View(view) => return Some(view.global_id),
             CatalogItem::Sink(sink) => return Some(sink.global_id),
+            CatalogItem::Notification(notification) => return Some(notification.global_id),
             CatalogItem::Index(index) => return Some(index.global_id),
             CatalogItem::Type(ty) => return Some(ty.global_id),
             CatalogItem::Func(func) => return Some(func.global_id),
@@ -2768,6 +3031,15 @@ impl CatalogEntry {
         }
     }
 
+    /// Returns the inner [`Notification`] if this entry is a notification, else
+    /// `None`.
+    pub fn notification(&self) -> Option<&Notification> {
+        match self.item() {
+            CatalogItem::Notification(notification) => Some(notification),
+            _ => None,
+        }
+    }
+
     /// Returns the inner [`Secret`] if this entry is a secret, else `None`.
     pub fn secret(&self) -> Option<&Secret> {
         match self.item() {
@@ -2898,6 +3170,7 @@ impl CatalogEntry {
             | CatalogItem::View(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Sink(_)
+            | CatalogItem::Notification(_)
             | CatalogItem::Index(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
@@ -2911,6 +3184,11 @@ impl CatalogEntry {
         matches!(self.item(), CatalogItem::Sink(_))
     }
 
+    /// Reports whether this catalog entry is a notification.
+    pub fn is_notification(&self) -> bool {
+        matches!(self.item(), CatalogItem::Notification(_))
+    }
+
     /// Reports whether this catalog entry is a materialized view.
     pub fn is_materialized_view(&self) -> bool {
         matches!(self.item(), CatalogItem::MaterializedView(_))
@@ -3994,6 +4272,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
                 create_sql.as_deref().unwrap_or("<builtin>")
             }
             CatalogItem::Sink(Sink { create_sql, .. }) => create_sql,
+            CatalogItem::Notification(Notification { create_sql, .. }) => create_sql,
             CatalogItem::View(View { create_sql, .. }) => create_sql,
             CatalogItem::MaterializedView(MaterializedView { create_sql, .. }) => create_sql,
             CatalogItem::Index(Index { create_sql, .. }) => create_sql,
@@ -4031,6 +4310,18 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn snapshot_details(&self) -> Option<(GlobalId, Timestamp)> {
+        if let CatalogItem::Table(Table {
+            data_source: TableDataSource::Snapshot { from, as_of },
+            ..
+        }) = self.item()
+        {
+            Some((*from, *as_of))
+        } else {
+            None
+        }
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         if let CatalogItem::MaterializedView(mv) = self.item() {
             mv.replacement_target
@@ -4084,6 +4375,10 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.is_progress_source()
     }
 
+    fn is_notification(&self) -> bool {
+        self.is_notification()
+    }
+
     fn progress_id(&self) -> Option<CatalogItemId> {
         self.progress_id()
     }
@@ -1531,21 +1531,26 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedSecret { .. }
         | ExecuteResponse::CreatedSource { .. }
         | ExecuteResponse::CreatedSink { .. }
+        | ExecuteResponse::CreatedNotification
         | ExecuteResponse::CreatedView { .. }
         | ExecuteResponse::CreatedViews { .. }
         | ExecuteResponse::CreatedMaterializedView { .. }
         | ExecuteResponse::CreatedType
         | ExecuteResponse::CreatedNetworkPolicy
+        | ExecuteResponse::CreatedPolicy
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedPolicy
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
+        | ExecuteResponse::Listen
+        | ExecuteResponse::Unlisten
         | ExecuteResponse::Copied(_)
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
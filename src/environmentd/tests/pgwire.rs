@@ -737,7 +737,9 @@ fn pg_test_inner(path: &Path, mz_flags: bool) {
             server.enable_feature_flags(&[
                 "enable_create_table_from_source",
                 "enable_load_generator_datums",
+                "enable_notifications",
                 "enable_raise_statement",
+                "enable_row_level_security",
                 "unsafe_enable_unorchestrated_cluster_replicas",
                 "unsafe_enable_unsafe_functions",
             ]);
@@ -905,6 +907,11 @@ fn test_pgtest_mz_notice() {
     pg_test_inner(Path::new("../../test/pgtest-mz/notice.pt"), true);
 }
 
+#[mz_ore::test]
+fn test_pgtest_mz_notifications() {
+    pg_test_inner(Path::new("../../test/pgtest-mz/notifications.pt"), true);
+}
+
 #[mz_ore::test]
 fn test_pgtest_mz_numeric_binary_overflow() {
     pg_test_inner(
@@ -411,7 +411,7 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
             Format::Protobuf(_) => "protobuf",
             Format::Regex(_) => "regex",
             Format::Csv { .. } => "csv",
-            Format::Json { .. } => "json",
+            Format::Json { .. } | Format::JsonCsr { .. } => "json",
             Format::Text => "text",
         }
     }
@@ -466,6 +466,14 @@ fn parse_catalog_create_sql<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
                     Postgres { connection, .. } => ("postgres", Some(connection)),
                     MySql { connection, .. } => ("mysql", Some(connection)),
                     SqlServer { connection, .. } => ("sql-server", Some(connection)),
+                    MongoDb { connection, .. } => ("mongodb", Some(connection)),
+                    S3 { connection, .. } => ("s3", Some(connection)),
+                    Kinesis { connection, .. } => ("kinesis", Some(connection)),
+                    Nats { connection, .. } => ("nats", Some(connection)),
+                    Mqtt { connection, .. } => ("mqtt", Some(connection)),
+                    Iceberg {
+                        catalog_connection, ..
+                    } => ("iceberg", Some(catalog_connection)),
                     LoadGenerator { .. } => ("load-generator", None),
                 };
                 info.insert("source_type", json!(source_type));
@@ -636,7 +644,8 @@ fn parse_postgres_source_details<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
 ///
 /// - jsonb `{"topic": <text>, "group_id_prefix": <text | null>, "connection_id": <text>}`
 ///   for `CREATE SOURCE ... FROM KAFKA CONNECTION ... (TOPIC = ..., [GROUP ID PREFIX = ...])`
-///   statements.
+///   statements. For sources over `TOPICS` or a `TOPIC PATTERN`, `topic` is the first listed
+///   topic or the pattern.
 /// - jsonb `null` for any other statement.
 ///
 /// Errors if the statement fails to parse, is a kafka source without a
@@ -679,10 +688,17 @@ fn parse_kafka_source_details<'a>(a: &'a str) -> Result<Jsonb, EvalError> {
         for opt in options {
             let string_value = match opt.value {
                 Some(WithOptionValue::Value(Value::String(s))) => Some(s),
+                // The first of the listed `TOPICS`.
+                Some(WithOptionValue::Sequence(values)) => match values.into_iter().next() {
+                    Some(WithOptionValue::Value(Value::String(s))) => Some(s),
+                    _ => None,
+                },
                 _ => None,
             };
             match opt.name {
-                KafkaSourceConfigOptionName::Topic => topic = string_value,
+                KafkaSourceConfigOptionName::Topic
+                | KafkaSourceConfigOptionName::Topics
+                | KafkaSourceConfigOptionName::TopicPattern => topic = string_value,
                 KafkaSourceConfigOptionName::GroupIdPrefix => group_id_prefix = string_value,
                 _ => {}
             }
@@ -856,6 +872,30 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn kafka_topic_list_and_pattern() {
+        for (topics, topic) in [
+            ("TOPICS ('a', 'b')", "a"),
+            ("TOPIC PATTERN 'events-.*'", "events-.*"),
+        ] {
+            let sql = format!(
+                "CREATE SOURCE \"materialize\".\"public\".\"k_src\" \
+                 IN CLUSTER [u42] \
+                 FROM KAFKA CONNECTION [u11 AS \"materialize\".\"public\".\"k_conn\"] \
+                 ({topics}) FORMAT TEXT"
+            );
+            let out = super::parse_kafka_source_details(&sql).expect("ok");
+            assert_eq!(
+                as_serde(out),
+                json!({
+                    "topic": topic,
+                    "group_id_prefix": null,
+                    "connection_id": "u11",
+                }),
+            );
+        }
+    }
+
     #[mz_ore::test]
     fn kafka_non_kafka_source_returns_null_jsonb() {
         let sql = "CREATE SOURCE \"materialize\".\"public\".\"lg\" \
@@ -11,7 +11,7 @@ use std::hint::black_box;
 use byteorder::{NetworkEndian, WriteBytesExt};
 use criterion::{Criterion, Throughput};
 use mz_avro::types::Value as AvroValue;
-use mz_interchange::avro::{Decoder, WriterSchemaProvider, parse_schema};
+use mz_interchange::avro::{AvroDecodeOptions, Decoder, WriterSchemaProvider, parse_schema};
 use mz_ore::cast::CastFrom;
 use mz_repr::adt::date::Date;
 use tokio::runtime::Runtime;
@@ -398,6 +398,7 @@ pub fn bench_avro(c: &mut Criterion) {
         schema_str,
         &[],
         WriterSchemaProvider::None,
+        AvroDecodeOptions::default(),
         "avro_bench".to_string(),
     )
     .unwrap();
@@ -10,7 +10,7 @@
 use std::hint::black_box;
 
 use criterion::{Criterion, Throughput};
-use mz_interchange::protobuf::{DecodedDescriptors, Decoder};
+use mz_interchange::protobuf::{DEFAULT_RECURSION_LIMIT, DecodedDescriptors, Decoder};
 use mz_ore::cast::CastFrom;
 use prost::Message;
 
@@ -70,6 +70,7 @@ pub fn bench_protobuf(c: &mut Criterion) {
         DecodedDescriptors::from_bytes(
             &include_bytes!(concat!(env!("OUT_DIR"), "/file_descriptor_set.pb"))[..],
             ".benchmark.Record".to_string(),
+            DEFAULT_RECURSION_LIMIT,
         )
         .unwrap(),
         false,
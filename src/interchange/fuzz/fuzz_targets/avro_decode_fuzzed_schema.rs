@@ -72,7 +72,7 @@ use std::sync::OnceLock;
 
 use libfuzzer_sys::arbitrary::{self, Unstructured};
 use libfuzzer_sys::fuzz_target;
-use mz_interchange::avro::{Decoder, WriterSchemaProvider};
+use mz_interchange::avro::{AvroDecodeOptions, Decoder, WriterSchemaProvider};
 use mz_repr::{Datum, Row};
 
 fn rt() -> &'static tokio::runtime::Runtime {
@@ -703,7 +703,13 @@ fn run_roundtrip(u: &mut Unstructured) -> arbitrary::Result<()> {
     }
     schema.push_str("]}");
 
-    let Ok(mut decoder) = Decoder::new(&schema, &[], WriterSchemaProvider::None, "fuzz".into()) else {
+    let Ok(mut decoder) = Decoder::new(
+        &schema,
+        &[],
+        WriterSchemaProvider::None,
+        AvroDecodeOptions::default(),
+        "fuzz".into(),
+    ) else {
         // A record of plain scalars always validates. If not, nothing to check.
         return Ok(());
     };
@@ -757,7 +763,13 @@ fn run(mut u: Unstructured) -> arbitrary::Result<()> {
 
     // No CSR client and confluent_wire_format = false, so decode is
     // self-contained (no network) over the generated reader schema.
-    let Ok(mut decoder) = Decoder::new(&schema, &[], WriterSchemaProvider::None, "fuzz".into()) else {
+    let Ok(mut decoder) = Decoder::new(
+        &schema,
+        &[],
+        WriterSchemaProvider::None,
+        AvroDecodeOptions::default(),
+        "fuzz".into(),
+    ) else {
         return Ok(());
     };
 
@@ -14,8 +14,9 @@
 //! untrusted-input surface, and a fixed descriptor never exercises them with
 //! anything but one well-formed schema. The interesting paths are the ones a
 //! single descriptor can't reach: recursion detection (a message field
-//! referencing its own or a mutually-referencing message must be rejected, not
-//! stack-overflow), the full scalar `Kind` mapping, enums, and repeated fields.
+//! referencing its own or a mutually-referencing message must fall back to
+//! `jsonb` beyond the recursion limit, not stack-overflow), the full scalar
+//! `Kind` mapping, enums, and repeated fields.
 //!
 //! Critically, we don't feed *random bytes* as the body: prost reads a field
 //! tag then skips/short-circuits on most random bytes, so a random body rarely
@@ -33,9 +34,9 @@
 //!
 //! Beyond the well-formed-schema happy path, we drive the descriptor-validation
 //! surface harder. Some schemas grow a `map<string,string>` field (a repeated
-//! field of a `map_entry`-tagged message): `derive_column_type` is supposed to
-//! *reject* maps (`field.is_map()`), so this exercises that rejection cleanly
-//! rather than only ever feeding it map-free input. Field numbers are
+//! field of a `map_entry`-tagged message), which `derive_column_type` describes
+//! as a Materialize `map`, so map decoding is exercised rather than only ever
+//! feeding it map-free input. Field numbers are
 //! fuzzer-chosen in a small range, so gaps, out-of-order, and duplicate numbers
 //! all occur. Duplicates are an invalid descriptor `DescriptorPool::decode`
 //! must reject without panicking. Packable repeated scalars are sometimes
@@ -521,6 +522,7 @@ fn run(mut u: Unstructured) -> arbitrary::Result<()> {
     // message (where columns are derived directly) and not only when nested.
     let root = usize::from(u.int_in_range(0u8..=u8::try_from(msgs.len() - 1).unwrap())?);
     let root_name = format!("fuzz.M{root}");
+    let recursion_limit = u32::from(u.int_in_range(0u8..=2)?);
 
     // Body for the root message: usually a valid encoding (so prost decodes
     // through to the Row conversion), but a quarter of the time the raw remaining
@@ -547,17 +549,20 @@ fn run(mut u: Unstructured) -> arbitrary::Result<()> {
     };
 
     for confluent_wire_format in [false, true] {
-        let Ok(descriptors) = DecodedDescriptors::from_bytes(&fds, root_name.clone()) else {
+        let Ok(descriptors) =
+            DecodedDescriptors::from_bytes(&fds, root_name.clone(), recursion_limit)
+        else {
             return Ok(());
         };
         let Ok(mut decoder) = Decoder::new(descriptors, confluent_wire_format) else {
             return Ok(());
         };
-        // The Confluent variant strips a 5-byte schema-registry header (magic
-        // byte 0 + 4-byte schema id) before the body. Prepend one so its body
+        // The Confluent variant strips a schema-registry header (magic byte 0,
+        // a 4-byte schema id and the message indexes, where a single 0 byte
+        // selects the first message) before the body. Prepend one so its body
         // decodes just as deeply as the raw variant.
         let payload = if confluent_wire_format {
-            let mut p = vec![0u8; 5];
+            let mut p = vec![0u8; 6];
             p.extend_from_slice(&body);
             p
         } else {
@@ -19,7 +19,8 @@ pub use crate::avro::encode::{
     encode_debezium_transaction_unchecked, get_debezium_transaction_schema,
 };
 pub use crate::avro::schema::{
-    AvroSchemaResolver, WriterSchemaKey, WriterSchemaProvider, parse_schema, schema_to_relationdesc,
+    AvroDecodeOptions, AvroSchemaResolver, WriterSchemaKey, WriterSchemaProvider, parse_schema,
+    schema_to_relationdesc,
 };
 
 fn is_null(schema: &SchemaPieceOrNamed) -> bool {
@@ -46,7 +47,8 @@ mod tests {
             "fields": []
         }"#;
 
-        let desc = schema_to_relationdesc(parse_schema(schema, &[])?)?;
+        let desc =
+            schema_to_relationdesc(parse_schema(schema, &[])?, &AvroDecodeOptions::default())?;
         assert_eq!(desc.arity(), 0, "empty record produced rows");
 
         Ok(())
@@ -63,7 +65,8 @@ mod tests {
             ]
         }"#;
 
-        let desc = schema_to_relationdesc(parse_schema(schema, &[])?)?;
+        let desc =
+            schema_to_relationdesc(parse_schema(schema, &[])?, &AvroDecodeOptions::default())?;
         let expected_desc = RelationDesc::builder()
             .with_column("f1", SqlScalarType::Int32.nullable(false))
             .with_column("f2", SqlScalarType::String.nullable(false))
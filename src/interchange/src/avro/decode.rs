@@ -11,6 +11,7 @@ use std::collections::BTreeMap;
 
 use anyhow::{Context, Error};
 use mz_avro::error::{DecodeError, Error as AvroError};
+use mz_avro::types::Value;
 use mz_avro::{
     AvroArrayAccess, AvroDecode, AvroDeserializer, AvroMapAccess, AvroRead, AvroRecordAccess,
     GeneralDeserializer, ValueDecoder, ValueOrReader, give_value,
@@ -20,18 +21,20 @@ use mz_repr::adt::date::Date;
 use mz_repr::adt::jsonb::JsonbPacker;
 use mz_repr::adt::numeric;
 use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::strconv;
 use mz_repr::{Datum, Row, RowPacker};
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 use tracing::trace;
 use uuid::Uuid;
 
-use crate::avro::AvroSchemaResolver;
+use crate::avro::{AvroDecodeOptions, AvroSchemaResolver};
 
 /// Manages decoding of Avro-encoded bytes.
 #[derive(Debug)]
 pub struct Decoder {
     csr_avro: AvroSchemaResolver,
+    options: AvroDecodeOptions,
     debug_name: String,
     buf1: Vec<u8>,
     row_buf: Row,
@@ -40,9 +43,10 @@ pub struct Decoder {
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_err;
+    use mz_repr::adt::jsonb::JsonbPacker;
     use mz_repr::{Datum, Row};
 
-    use crate::avro::{Decoder, WriterSchemaProvider};
+    use crate::avro::{AvroDecodeOptions, Decoder, WriterSchemaProvider};
 
     #[mz_ore::test(tokio::test)]
     async fn test_error_followed_by_success() {
@@ -51,8 +55,14 @@ mod tests {
 "name": "test",
 "fields": [{"name": "f1", "type": "int"}, {"name": "f2", "type": "int"}]
 }"#;
-        let mut decoder =
-            Decoder::new(schema, &[], WriterSchemaProvider::None, "Test".to_string()).unwrap();
+        let mut decoder = Decoder::new(
+            schema,
+            &[],
+            WriterSchemaProvider::None,
+            AvroDecodeOptions::default(),
+            "Test".to_string(),
+        )
+        .unwrap();
         // This is not a valid Avro blob for the given schema
         let mut bad_bytes: &[u8] = &[0];
         assert_err!(decoder.decode(&mut bad_bytes).await.unwrap());
@@ -64,6 +74,83 @@ mod tests {
             Row::pack([Datum::Int32(0), Datum::Int32(0)])
         );
     }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_max_depth() {
+        let schema = r#"{
+"type": "record",
+"name": "node",
+"fields": [{"name": "v", "type": "int"}, {"name": "next", "type": ["null", "node"]}]
+}"#;
+        let options = AvroDecodeOptions {
+            max_depth: Some(1),
+            union_as_record: false,
+        };
+        let mut decoder = Decoder::new(
+            schema,
+            &[],
+            WriterSchemaProvider::None,
+            options,
+            "Test".to_string(),
+        )
+        .unwrap();
+        // {"v": 1, "next": {"v": 2, "next": {"v": 3, "next": null}}}
+        let mut bytes: &[u8] = &[2, 2, 4, 2, 6, 0];
+        let mut expected = Row::default();
+        let mut packer = expected.packer();
+        packer.push(Datum::Int32(1));
+        packer.push_list_with(|packer| {
+            packer.push(Datum::Int32(2));
+            JsonbPacker::new(packer)
+                .pack_serde_json(serde_json::json!({"v": 3, "next": null}))
+                .unwrap();
+        });
+        assert_eq!(decoder.decode(&mut bytes).await.unwrap().unwrap(), expected);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    async fn test_union_as_record() {
+        let schema = r#"{
+"type": "record",
+"name": "test",
+"fields": [{"name": "u", "type": ["null", "int", "string"]}]
+}"#;
+        let options = AvroDecodeOptions {
+            max_depth: None,
+            union_as_record: true,
+        };
+        let mut decoder = Decoder::new(
+            schema,
+            &[],
+            WriterSchemaProvider::None,
+            options,
+            "Test".to_string(),
+        )
+        .unwrap();
+        let mut string_bytes: &[u8] = &[4, 2, b'a'];
+        let mut expected = Row::default();
+        expected
+            .packer()
+            .push_list([Datum::Int32(2), Datum::Null, Datum::String("a")]);
+        assert_eq!(
+            decoder.decode(&mut string_bytes).await.unwrap().unwrap(),
+            expected
+        );
+        let mut int_bytes: &[u8] = &[2, 10];
+        let mut expected = Row::default();
+        expected
+            .packer()
+            .push_list([Datum::Int32(1), Datum::Int32(5), Datum::Null]);
+        assert_eq!(
+            decoder.decode(&mut int_bytes).await.unwrap().unwrap(),
+            expected
+        );
+        let mut null_bytes: &[u8] = &[0];
+        assert_eq!(
+            decoder.decode(&mut null_bytes).await.unwrap().unwrap(),
+            Row::pack([Datum::Null])
+        );
+    }
 }
 
 impl Decoder {
@@ -76,10 +163,14 @@ impl Decoder {
     /// The `reader_reference_schemas` parameter provides schemas for types that
     /// are referenced by the reader schema but defined in separate schemas.
     /// These should be provided in dependency order (dependencies first).
+    ///
+    /// The `options` must match the options that the relation of the decoded
+    /// rows was derived with.
     pub fn new(
         reader_schema: &str,
         reader_reference_schemas: &[String],
         writer_schemas: crate::avro::WriterSchemaProvider,
+        options: AvroDecodeOptions,
         debug_name: String,
     ) -> anyhow::Result<Decoder> {
         let csr_avro =
@@ -87,6 +178,7 @@ impl Decoder {
 
         Ok(Decoder {
             csr_avro,
+            options,
             debug_name,
             buf1: vec![],
             row_buf: Row::default(),
@@ -110,6 +202,8 @@ impl Decoder {
             packer: &mut packer,
             buf: &mut self.buf1,
             is_top: true,
+            options: self.options,
+            depth: 1,
         };
         let dsr = GeneralDeserializer {
             schema: resolved_schema.top_node(),
@@ -141,6 +235,18 @@ pub struct AvroFlatDecoder<'a, 'row> {
     pub packer: &'a mut RowPacker<'row>,
     pub buf: &'a mut Vec<u8>,
     pub is_top: bool,
+    pub options: AvroDecodeOptions,
+    /// The depth of the decoded value, as defined by [`AvroDecodeOptions::max_depth`].
+    pub depth: u32,
+}
+
+impl<'a, 'row> AvroFlatDecoder<'a, 'row> {
+    /// Packs a value that is nested too deeply to be decoded into SQL types as `jsonb`.
+    fn pack_json(self, value: serde_json::Value) -> Result<(), AvroError> {
+        JsonbPacker::new(self.packer)
+            .pack_serde_json(value)
+            .map_err(|e| AvroError::Decode(DecodeError::Custom(e.to_string())))
+    }
 }
 
 impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
@@ -150,6 +256,24 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
         self,
         a: &mut A,
     ) -> Result<Self::Out, AvroError> {
+        if !self.is_top && self.options.exceeds_max_depth(self.depth) {
+            let mut fields = serde_json::Map::new();
+            while let Some((name, _idx, f)) = a.next_field()? {
+                fields.insert(
+                    name.to_owned(),
+                    avro_to_json(&f.decode_field(ValueDecoder)?),
+                );
+            }
+            return self.pack_json(serde_json::Value::Object(fields));
+        }
+        let options = self.options;
+        // The fields of the top-level record are the top-level columns, which
+        // are at the same depth as the record.
+        let depth = if self.is_top {
+            self.depth
+        } else {
+            self.depth + 1
+        };
         let mut str_buf = std::mem::take(self.buf);
         let mut pack_record = |rp: &mut RowPacker| -> Result<(), AvroError> {
             let mut expected = 0;
@@ -170,6 +294,8 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                         packer: rp,
                         buf: &mut str_buf,
                         is_top: false,
+                        options,
+                        depth,
                     })?;
                 } else {
                     let val = f.decode_field(ValueDecoder)?;
@@ -184,6 +310,8 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: rp,
                     buf: &mut str_buf,
                     is_top: false,
+                    options,
+                    depth,
                 };
                 give_value(dec, &val)?;
             }
@@ -206,6 +334,45 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
         deserializer: D,
         reader: &'b mut R,
     ) -> Result<Self::Out, AvroError> {
+        let n_non_null = n_variants - usize::from(null_variant.is_some());
+        if self.options.union_as_record && n_non_null > 1 {
+            if null_variant == Some(idx) {
+                self.packer.push(Datum::Null);
+            } else if self.options.exceeds_max_depth(self.depth) {
+                let value = deserializer.deserialize(reader, ValueDecoder)?;
+                self.pack_json(avro_to_json(&value))?;
+            } else {
+                // The 1-based index of the variant among the non-null variants.
+                let variant = idx + 1 - usize::from(null_variant.is_some_and(|null| null < idx));
+                let variant = i32::try_from(variant).map_err(|_| {
+                    DecodeError::Custom(format!("union variant {variant} out of range"))
+                })?;
+                let options = self.options;
+                let depth = self.depth + 1;
+                let mut str_buf = std::mem::take(self.buf);
+                self.packer.push_list_with(|rp| -> Result<(), AvroError> {
+                    rp.push(Datum::Int32(variant));
+                    let mut deserializer = Some(deserializer);
+                    for i in (0..n_variants).filter(|i| null_variant != Some(*i)) {
+                        if i == idx {
+                            let dec = AvroFlatDecoder {
+                                packer: rp,
+                                buf: &mut str_buf,
+                                is_top: false,
+                                options,
+                                depth,
+                            };
+                            deserializer.take().unwrap().deserialize(reader, dec)?;
+                        } else {
+                            rp.push(Datum::Null);
+                        }
+                    }
+                    Ok(())
+                })?;
+                *self.buf = str_buf;
+            }
+            return Ok(());
+        }
         if null_variant == Some(idx) {
             for _ in 0..n_variants - 1 {
                 self.packer.push(Datum::Null)
@@ -217,6 +384,8 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: self.packer,
                     buf: self.buf,
                     is_top: false,
+                    options: self.options,
+                    depth: self.depth,
                 };
                 if null_variant != Some(i) {
                     if i == idx {
@@ -406,7 +575,16 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
     }
     #[inline]
     fn array<A: AvroArrayAccess>(mut self, a: &mut A) -> Result<Self::Out, AvroError> {
+        if self.options.exceeds_max_depth(self.depth) {
+            let mut elements = vec![];
+            while let Some(value) = a.decode_next(ValueDecoder)? {
+                elements.push(avro_to_json(&value));
+            }
+            return self.pack_json(serde_json::Value::Array(elements));
+        }
         self.is_top = false;
+        let options = self.options;
+        let depth = self.depth + 1;
         let mut str_buf = std::mem::take(self.buf);
         self.packer.push_list_with(|rp| -> Result<(), AvroError> {
             loop {
@@ -414,6 +592,8 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                     packer: rp,
                     buf: &mut str_buf,
                     is_top: false,
+                    options,
+                    depth,
                 };
                 if a.decode_next(next)?.is_none() {
                     break;
@@ -431,6 +611,15 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
         while let Some((name, f)) = a.next_entry()? {
             map.insert(name, f.decode_field(ValueDecoder)?);
         }
+        if self.options.exceeds_max_depth(self.depth) {
+            let entries = map
+                .into_iter()
+                .map(|(key, val)| (key, avro_to_json(&val)))
+                .collect();
+            return self.pack_json(serde_json::Value::Object(entries));
+        }
+        let options = self.options;
+        let depth = self.depth + 1;
         self.packer
             .push_dict_with(|packer| -> Result<(), AvroError> {
                 for (key, val) in map {
@@ -440,6 +629,8 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
                             packer,
                             buf: &mut vec![],
                             is_top: false,
+                            options,
+                            depth,
                         },
                         &val,
                     )?;
@@ -451,6 +642,63 @@ impl<'a, 'row> AvroDecode for AvroFlatDecoder<'a, 'row> {
     }
 }
 
+/// Converts an Avro value that is nested too deeply to be decoded into SQL types
+/// to JSON.
+///
+/// Records and maps become objects, arrays become arrays, and unions become
+/// their inhabited variant. Scalars become the JSON value that is closest to
+/// the SQL value they would otherwise be decoded as, with numeric, temporal,
+/// `bytes` and `uuid` values formatted as strings.
+fn avro_to_json(value: &Value) -> serde_json::Value {
+    use serde_json::Value as Json;
+
+    match value {
+        Value::Null => Json::Null,
+        Value::Boolean(b) => Json::Bool(*b),
+        Value::Int(i) => Json::from(*i),
+        Value::Long(i) => Json::from(*i),
+        Value::Float(f) => Json::from(f64::from(*f)),
+        Value::Double(f) => Json::from(*f),
+        Value::Date(days) => match Date::from_unix_epoch(*days) {
+            Ok(date) => Json::String(date.to_string()),
+            Err(_) => Json::from(*days),
+        },
+        Value::Timestamp(ts) => Json::String(ts.to_string()),
+        Value::Decimal(decimal) => {
+            let mut unscaled = decimal.unscaled.clone();
+            let numeric = u8::try_from(decimal.scale).ok().and_then(|scale| {
+                numeric::twos_complement_be_to_numeric(&mut unscaled, scale).ok()
+            });
+            match numeric {
+                Some(n) => Json::String(n.to_string()),
+                None => Json::Null,
+            }
+        }
+        Value::Bytes(bytes) | Value::Fixed(_, bytes) => {
+            let mut s = String::new();
+            strconv::format_bytes(&mut s, bytes);
+            Json::String(s)
+        }
+        Value::String(s) | Value::Enum(_, s) => Json::String(s.clone()),
+        Value::Union { inner, .. } => avro_to_json(inner),
+        Value::Array(values) => Json::Array(values.iter().map(avro_to_json).collect()),
+        Value::Map(entries) => Json::Object(
+            entries
+                .iter()
+                .map(|(key, value)| (key.clone(), avro_to_json(value)))
+                .collect(),
+        ),
+        Value::Record(fields) => Json::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), avro_to_json(value)))
+                .collect(),
+        ),
+        Value::Json(json) => json.clone(),
+        Value::Uuid(uuid) => Json::String(uuid.to_string()),
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DiffPair<T> {
     pub before: Option<T>,
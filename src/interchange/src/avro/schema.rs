@@ -34,6 +34,16 @@
 //! there is nothing we can do, because we expect to be able to turn it into exactly one
 //! SQL type, not a series of them. Thus, in these cases, we just bail. For example, it's
 //! not possible to ingest an array or map whose element type is an Essential Union.
+//!
+//! With [`AvroDecodeOptions::union_as_record`], Essential Unions are instead converted to
+//! a single record with a `variant` field, holding the 1-based index of the inhabited
+//! non-null variant, followed by a nullable field for each non-null variant. This
+//! representation can appear anywhere, including in arrays and maps.
+//!
+//! Named types that refer to themselves cannot be represented in the SQL type system, so
+//! recursive schemas are rejected, unless [`AvroDecodeOptions::max_depth`] is set. Records,
+//! arrays, maps and Essential Unions nested deeper than that are converted to `jsonb`,
+//! which bounds the expansion of recursive types.
 
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
@@ -70,19 +80,50 @@ pub fn parse_schema(schema: &str, references: &[String]) -> anyhow::Result<Schem
     Ok(Schema::parse_with_references(&schema, &parsed_refs)?)
 }
 
+/// Options that control how Avro schemas are converted to relations, and how Avro
+/// values are decoded into rows of those relations.
+///
+/// See the module documentation for details.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AvroDecodeOptions {
+    /// The maximum depth at which records, arrays, maps and Essential Unions are decoded
+    /// into SQL records, lists and maps. The top-level columns are at depth 1, and the
+    /// fields and elements of a value are one level deeper than the value. Deeper values
+    /// are decoded as `jsonb`. If `None`, nothing is decoded as `jsonb` and recursive
+    /// schemas are rejected.
+    pub max_depth: Option<u32>,
+    /// Whether to decode Essential Unions as a single record, rather than as a column
+    /// per variant.
+    pub union_as_record: bool,
+}
+
+impl AvroDecodeOptions {
+    /// Whether a record, array, map or Essential Union at `depth` is decoded as `jsonb`.
+    pub(crate) fn exceeds_max_depth(&self, depth: u32) -> bool {
+        self.max_depth.is_some_and(|max_depth| depth > max_depth)
+    }
+}
+
 /// Converts an Apache Avro schema into a list of column names and types.
 // TODO(petrosagg): find a way to make this a TryFrom impl somewhere
-pub fn schema_to_relationdesc(schema: Schema) -> Result<RelationDesc, anyhow::Error> {
+pub fn schema_to_relationdesc(
+    schema: Schema,
+    options: &AvroDecodeOptions,
+) -> Result<RelationDesc, anyhow::Error> {
     // TODO(petrosagg): call directly into validate_schema_2 and do the Record flattening once
     // we're in RelationDesc land
     Ok(RelationDesc::from_names_and_types(validate_schema_1(
         schema.top_node(),
+        options,
     )?))
 }
 
 /// Convert an Avro schema to a series of columns and names, flattening the top-level record,
 /// if the top node is indeed a record.
-fn validate_schema_1(schema: SchemaNode) -> anyhow::Result<Vec<(ColumnName, SqlColumnType)>> {
+fn validate_schema_1(
+    schema: SchemaNode,
+    options: &AvroDecodeOptions,
+) -> anyhow::Result<Vec<(ColumnName, SqlColumnType)>> {
     let mut columns = vec![];
     let mut seen_avro_nodes = Default::default();
     match schema.inner {
@@ -90,13 +131,21 @@ fn validate_schema_1(schema: SchemaNode) -> anyhow::Result<Vec<(ColumnName, SqlC
             for f in fields {
                 columns.extend(get_named_columns(
                     &mut seen_avro_nodes,
+                    options,
                     schema.step(&f.schema),
                     Some(&f.name),
+                    1,
                 )?);
             }
         }
         _ => {
-            columns.extend(get_named_columns(&mut seen_avro_nodes, schema, None)?);
+            columns.extend(get_named_columns(
+                &mut seen_avro_nodes,
+                options,
+                schema,
+                None,
+                1,
+            )?);
         }
     }
     Ok(columns)
@@ -106,8 +155,10 @@ fn validate_schema_1(schema: SchemaNode) -> anyhow::Result<Vec<(ColumnName, SqlC
 /// See module comments for details.
 fn get_union_columns<'a>(
     seen_avro_nodes: &mut BTreeSet<usize>,
+    options: &AvroDecodeOptions,
     schema: SchemaNode<'a>,
     base_name: Option<&str>,
+    depth: u32,
 ) -> anyhow::Result<Vec<(ColumnName, SqlColumnType)>> {
     let us = match schema.inner {
         SchemaPiece::Union(us) => us,
@@ -115,11 +166,35 @@ fn get_union_columns<'a>(
     };
     let mut columns = vec![];
     let vs = us.variants();
+    let n_non_null = vs.iter().filter(|v| !is_null(v)).count();
     if vs.is_empty() || (vs.len() == 1 && is_null(&vs[0])) {
         bail!(anyhow!("Empty or null-only unions are not supported"));
+    } else if options.union_as_record && n_non_null > 1 {
+        let name = base_name.unwrap_or(UNKNOWN_COLUMN_NAME);
+        let scalar_type = if options.exceeds_max_depth(depth) {
+            SqlScalarType::Jsonb
+        } else {
+            let mut fields = vec![("variant".into(), SqlScalarType::Int32.nullable(false))];
+            for v in vs.iter().filter(|v| !is_null(v)) {
+                with_recursion_guard(seen_avro_nodes, options, schema.root, v, |seen| {
+                    let name = match v.get_piece_and_name(schema.root) {
+                        (_, Some(full_name)) => full_name.base_name().to_owned(),
+                        (piece, None) => unnamed_type_name(piece).to_owned(),
+                    };
+                    let ty = validate_schema_2(seen, options, schema.step(v), depth + 1)?;
+                    fields.push((name.into(), ty.nullable(true)));
+                    Ok(())
+                })?;
+            }
+            SqlScalarType::Record {
+                fields: fields.into(),
+                custom_id: None,
+            }
+        };
+        columns.push((name.into(), scalar_type.nullable(vs.len() > n_non_null)));
     } else {
         for (i, v) in vs.iter().filter(|v| !is_null(v)).enumerate() {
-            with_recursion_guard(seen_avro_nodes, schema.root, v, |seen| {
+            with_recursion_guard(seen_avro_nodes, options, schema.root, v, |seen| {
                 let node = schema.step(v);
                 if let SchemaPiece::Union(_) = node.inner {
                     unreachable!("Internal error: directly nested avro union!");
@@ -153,7 +228,7 @@ fn get_union_columns<'a>(
                 // If there is more than one variant in the union,
                 // the column's output type is nullable, as this
                 // column will be null whenever it is uninhabited.
-                let ty = validate_schema_2(seen, node)?;
+                let ty = validate_schema_2(seen, options, node, depth)?;
                 columns.push((name.into(), ty.nullable(vs.len() > 1)));
                 Ok(())
             })?;
@@ -164,13 +239,15 @@ fn get_union_columns<'a>(
 
 fn get_named_columns<'a>(
     seen_avro_nodes: &mut BTreeSet<usize>,
+    options: &AvroDecodeOptions,
     schema: SchemaNode<'a>,
     base_name: Option<&str>,
+    depth: u32,
 ) -> anyhow::Result<Vec<(ColumnName, SqlColumnType)>> {
     if let SchemaPiece::Union(_) = schema.inner {
-        get_union_columns(seen_avro_nodes, schema, base_name)
+        get_union_columns(seen_avro_nodes, options, schema, base_name, depth)
     } else {
-        let scalar_type = validate_schema_2(seen_avro_nodes, schema)?;
+        let scalar_type = validate_schema_2(seen_avro_nodes, options, schema, depth)?;
         Ok(vec![(
             // TODO(benesch): we should do better than this when there's no base
             // name, e.g., invent a name based on the type.
@@ -185,11 +262,18 @@ fn get_named_columns<'a>(
 /// (because it is an Essential Union in the sense described in the module docs).
 fn validate_schema_2(
     seen_avro_nodes: &mut BTreeSet<usize>,
+    options: &AvroDecodeOptions,
     schema: SchemaNode,
+    depth: u32,
 ) -> anyhow::Result<SqlScalarType> {
     Ok(match schema.inner {
+        SchemaPiece::Record { .. } | SchemaPiece::Array(_) | SchemaPiece::Map(_)
+            if options.exceeds_max_depth(depth) =>
+        {
+            SqlScalarType::Jsonb
+        }
         SchemaPiece::Union(_) => {
-            let columns = get_union_columns(seen_avro_nodes, schema, None)?;
+            let columns = get_union_columns(seen_avro_nodes, options, schema, None, depth)?;
             if columns.len() != 1 {
                 bail!("Union of more than one non-null type not valid here");
             }
@@ -236,11 +320,13 @@ fn validate_schema_2(
         SchemaPiece::Record { fields, .. } => {
             let mut columns = vec![];
             for f in fields {
-                with_recursion_guard(seen_avro_nodes, schema.root, &f.schema, |seen| {
+                with_recursion_guard(seen_avro_nodes, options, schema.root, &f.schema, |seen| {
                     columns.extend(get_named_columns(
                         seen,
+                        options,
                         schema.step(&f.schema),
                         Some(&f.name),
+                        depth + 1,
                     )?);
                     Ok(())
                 })?;
@@ -250,22 +336,40 @@ fn validate_schema_2(
                 custom_id: None,
             }
         }
-        SchemaPiece::Array(inner) => {
-            with_recursion_guard(seen_avro_nodes, schema.root, inner.as_ref(), |seen| {
+        SchemaPiece::Array(inner) => with_recursion_guard(
+            seen_avro_nodes,
+            options,
+            schema.root,
+            inner.as_ref(),
+            |seen| {
                 Ok(SqlScalarType::List {
-                    element_type: Box::new(validate_schema_2(seen, schema.step(inner))?),
+                    element_type: Box::new(validate_schema_2(
+                        seen,
+                        options,
+                        schema.step(inner),
+                        depth + 1,
+                    )?),
                     custom_id: None,
                 })
-            })?
-        }
-        SchemaPiece::Map(inner) => {
-            with_recursion_guard(seen_avro_nodes, schema.root, inner.as_ref(), |seen| {
+            },
+        )?,
+        SchemaPiece::Map(inner) => with_recursion_guard(
+            seen_avro_nodes,
+            options,
+            schema.root,
+            inner.as_ref(),
+            |seen| {
                 Ok(SqlScalarType::Map {
-                    value_type: Box::new(validate_schema_2(seen, schema.step(inner))?),
+                    value_type: Box::new(validate_schema_2(
+                        seen,
+                        options,
+                        schema.step(inner),
+                        depth + 1,
+                    )?),
                     custom_id: None,
                 })
-            })?
-        }
+            },
+        )?,
         _ => bail!("Unsupported type in schema: {:?}", schema.inner),
     })
 }
@@ -273,8 +377,12 @@ fn validate_schema_2(
 /// Runs `f` with `node` marked as on the current resolution path, bailing if it's
 /// already on the path (a cycle). The mark is cleared on exit so sibling reuse of a
 /// named type isn't flagged.
+///
+/// Cycles are allowed if `options` sets a maximum depth, as every cycle passes through a
+/// record, which is decoded as `jsonb` once it is nested deeply enough.
 fn with_recursion_guard<T>(
     seen: &mut BTreeSet<usize>,
+    options: &AvroDecodeOptions,
     root: &Schema,
     node: &SchemaPieceOrNamed,
     f: impl FnOnce(&mut BTreeSet<usize>) -> anyhow::Result<T>,
@@ -283,8 +391,10 @@ fn with_recursion_guard<T>(
         SchemaPieceOrNamed::Named(idx) => Some(*idx),
         SchemaPieceOrNamed::Piece(_) => None,
     };
+    let mut inserted = false;
     if let Some(named_idx) = named_idx {
-        if !seen.insert(named_idx) {
+        inserted = seen.insert(named_idx);
+        if !inserted && options.max_depth.is_none() {
             bail!(
                 "Recursive types are not supported: {}",
                 node.get_human_name(root)
@@ -292,12 +402,34 @@ fn with_recursion_guard<T>(
         }
     }
     let result = f(seen);
-    if let Some(named_idx) = named_idx {
+    if let (Some(named_idx), true) = (named_idx, inserted) {
         seen.remove(&named_idx);
     }
     result
 }
 
+/// The name of the field that holds an unnamed variant of a union decoded as a record.
+fn unnamed_type_name(piece: &SchemaPiece) -> &'static str {
+    match piece {
+        SchemaPiece::Null => "null",
+        SchemaPiece::Boolean => "boolean",
+        SchemaPiece::Int => "int",
+        SchemaPiece::Long => "long",
+        SchemaPiece::Float => "float",
+        SchemaPiece::Double => "double",
+        SchemaPiece::Date => "date",
+        SchemaPiece::TimestampMilli | SchemaPiece::TimestampMicro => "timestamp",
+        SchemaPiece::Decimal { .. } => "decimal",
+        SchemaPiece::Bytes => "bytes",
+        SchemaPiece::String => "string",
+        SchemaPiece::Json => "json",
+        SchemaPiece::Uuid => "uuid",
+        SchemaPiece::Array(_) => "array",
+        SchemaPiece::Map(_) => "map",
+        _ => UNKNOWN_COLUMN_NAME,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,8 +439,11 @@ mod tests {
     /// a named reference: record fields (directly or via a union), arrays, and
     /// maps. Each should be rejected rather than recursed into forever.
     fn assert_recursive(schema: &str) {
-        let err = schema_to_relationdesc(parse_schema(schema, &[]).expect("schema should parse"))
-            .expect_err("recursive schema should be rejected");
+        let err = schema_to_relationdesc(
+            parse_schema(schema, &[]).expect("schema should parse"),
+            &AvroDecodeOptions::default(),
+        )
+        .expect_err("recursive schema should be rejected");
         assert!(
             err.to_string()
                 .contains("Recursive types are not supported"),
@@ -355,11 +490,104 @@ mod tests {
                 {"name": "b", "type": "inner"}
             ]
         }"#;
-        let desc = schema_to_relationdesc(parse_schema(schema, &[]).expect("schema should parse"))
-            .expect("diamond reuse of a named type should be allowed");
+        let desc = schema_to_relationdesc(
+            parse_schema(schema, &[]).expect("schema should parse"),
+            &AvroDecodeOptions::default(),
+        )
+        .expect("diamond reuse of a named type should be allowed");
         assert_eq!(desc.arity(), 2);
     }
 
+    /// With a maximum depth, recursive types are expanded until they are nested
+    /// too deeply, and are `jsonb` from then on.
+    #[mz_ore::test]
+    fn recursive_record_with_max_depth() {
+        let schema = r#"{"type":"record","name":"a","fields":[
+            {"name":"v","type":"int"},
+            {"name":"next","type":["null","a"]},
+            {"name":"children","type":{"type":"array","items":"a"}}
+        ]}"#;
+        let options = AvroDecodeOptions {
+            max_depth: Some(1),
+            union_as_record: false,
+        };
+        let desc = schema_to_relationdesc(parse_schema(schema, &[]).unwrap(), &options).unwrap();
+        let a = SqlScalarType::Record {
+            fields: [
+                ("v".into(), SqlScalarType::Int32.nullable(false)),
+                ("next".into(), SqlScalarType::Jsonb.nullable(true)),
+                ("children".into(), SqlScalarType::Jsonb.nullable(false)),
+            ]
+            .into(),
+            custom_id: None,
+        };
+        let expected = RelationDesc::builder()
+            .with_column("v", SqlScalarType::Int32.nullable(false))
+            .with_column("next", a.clone().nullable(true))
+            .with_column(
+                "children",
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Jsonb),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .finish();
+        assert_eq!(desc, expected);
+    }
+
+    /// Essential unions become a single record, even where they could not be
+    /// flattened into columns.
+    #[mz_ore::test]
+    fn union_as_record() {
+        let schema = r#"{"type":"record","name":"r","fields":[
+            {"name":"u","type":["null","int",{"type":"record","name":"n.s","fields":[]}]},
+            {"name":"a","type":{"type":"array","items":["int","string"]}}
+        ]}"#;
+        let options = AvroDecodeOptions {
+            max_depth: None,
+            union_as_record: true,
+        };
+        let desc = schema_to_relationdesc(parse_schema(schema, &[]).unwrap(), &options).unwrap();
+        let u = SqlScalarType::Record {
+            fields: [
+                ("variant".into(), SqlScalarType::Int32.nullable(false)),
+                ("int".into(), SqlScalarType::Int32.nullable(true)),
+                (
+                    "s".into(),
+                    SqlScalarType::Record {
+                        fields: [].into(),
+                        custom_id: None,
+                    }
+                    .nullable(true),
+                ),
+            ]
+            .into(),
+            custom_id: None,
+        };
+        let a = SqlScalarType::Record {
+            fields: [
+                ("variant".into(), SqlScalarType::Int32.nullable(false)),
+                ("int".into(), SqlScalarType::Int32.nullable(true)),
+                ("string".into(), SqlScalarType::String.nullable(true)),
+            ]
+            .into(),
+            custom_id: None,
+        };
+        let expected = RelationDesc::builder()
+            .with_column("u", u.nullable(true))
+            .with_column(
+                "a",
+                SqlScalarType::List {
+                    element_type: Box::new(a),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .finish();
+        assert_eq!(desc, expected);
+    }
+
     #[mz_ore::test]
     fn registry_name_from_schema_arn_commercial() {
         assert_eq!(
@@ -11,7 +11,7 @@ use std::path::PathBuf;
 use std::process;
 
 use anyhow::Context;
-use mz_interchange::avro::Decoder;
+use mz_interchange::avro::{AvroDecodeOptions, Decoder};
 use mz_interchange::confluent;
 use mz_ore::cli;
 use mz_ore::cli::CliConfig;
@@ -56,6 +56,7 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         &schema,
         &[],
         mz_interchange::avro::WriterSchemaProvider::None,
+        AvroDecodeOptions::default(),
         debug_name,
     )
     .context("creating decoder")?;
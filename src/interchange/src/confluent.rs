@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use anyhow::{Result, bail};
+use anyhow::{Result, anyhow, bail};
 use byteorder::{BigEndian, ByteOrder};
 
 /// Extracts the schema_id placed in front of the serialized message by the confluent stack
@@ -52,20 +52,118 @@ pub fn extract_avro_header(buf: &[u8]) -> Result<(i32, &[u8])> {
     extract_schema_id(buf, "avro")
 }
 
-pub fn extract_protobuf_header(buf: &[u8]) -> Result<(i32, &[u8])> {
-    let (schema_id, buf) = extract_schema_id(buf, "protobuf")?;
-
-    match buf.get(0) {
-        Some(0) => Ok((schema_id, &buf[1..])),
-        Some(message_id) => bail!(
-            "unsupported Confluent-style protobuf message descriptor id: \
-                expected 0, but found: {}",
-            message_id
-        ),
-        None => bail!(
-            "Confluent-style protobuf datum is too few bytes: expected a message id after magic \
-            and schema id, got a buffer of length {}",
-            buf.len()
-        ),
+pub fn extract_json_header(buf: &[u8]) -> Result<(i32, &[u8])> {
+    extract_schema_id(buf, "json")
+}
+
+/// Extracts the schema ID and the message indexes placed in front of a Confluent-style protobuf
+/// datum, returning the schema ID and the rest of the buffer. The message indexes are written to
+/// `message_indexes`.
+pub fn extract_protobuf_header<'buf>(
+    buf: &'buf [u8],
+    message_indexes: &mut Vec<i64>,
+) -> Result<(i32, &'buf [u8])> {
+    let (schema_id, mut buf) = extract_schema_id(buf, "protobuf")?;
+
+    // The schema ID is followed by the indexes of the message within the schema
+    // (e.g. `[1, 0]` for the first message nested in the second top-level
+    // message), as a zigzag-encoded varint count followed by that many
+    // zigzag-encoded varints. The common case `[0]` is abbreviated to a single
+    // zero byte.
+    if buf.is_empty() {
+        bail!(
+            "Confluent-style protobuf datum is too few bytes: expected message indexes after \
+            magic and schema id, got a buffer of length 0"
+        );
+    }
+    message_indexes.clear();
+    let count = decode_zigzag(&mut buf)?;
+    if count < 0 {
+        bail!(
+            "invalid Confluent-style protobuf message index count: {}",
+            count
+        );
+    }
+    if count == 0 {
+        message_indexes.push(0);
+    }
+    for _ in 0..count {
+        message_indexes.push(decode_zigzag(&mut buf)?);
+    }
+    Ok((schema_id, buf))
+}
+
+/// Writes the Confluent-style encoding of protobuf `message_indexes` to `out`.
+pub fn encode_protobuf_message_indexes(message_indexes: &[i64], out: &mut Vec<u8>) {
+    if message_indexes == [0] {
+        out.push(0);
+        return;
+    }
+    let count = i64::try_from(message_indexes.len()).expect("message nesting fits in i64");
+    for n in std::iter::once(count).chain(message_indexes.iter().copied()) {
+        prost::encoding::encode_varint(zigzag(n), out);
+    }
+}
+
+/// Zigzag-encodes `n`.
+fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)).cast_unsigned()
+}
+
+/// Decodes a zigzag-encoded varint from the front of `buf`.
+fn decode_zigzag(buf: &mut &[u8]) -> Result<i64> {
+    let n = prost::encoding::decode_varint(buf)
+        .map_err(|e| anyhow!("decoding Confluent-style protobuf message indexes: {}", e))?;
+    let magnitude = i64::try_from(n >> 1).expect("shifted u64 fits in i64");
+    Ok(if n & 1 == 0 {
+        magnitude
+    } else {
+        -magnitude - 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn protobuf_header() {
+        let header = [0, 0, 0, 0, 42];
+        let mut indexes = vec![];
+
+        // The abbreviated `[0]` message indexes.
+        let buf = [&header[..], &[0, 8]].concat();
+        assert_eq!(
+            extract_protobuf_header(&buf, &mut indexes).unwrap(),
+            (42, &[8][..])
+        );
+        assert_eq!(indexes, [0]);
+
+        // The message indexes `[1, 0]`.
+        let buf = [&header[..], &[4, 2, 0, 8]].concat();
+        assert_eq!(
+            extract_protobuf_header(&buf, &mut indexes).unwrap(),
+            (42, &[8][..])
+        );
+        assert_eq!(indexes, [1, 0]);
+
+        assert!(extract_protobuf_header(&header, &mut indexes).is_err());
+        assert!(extract_protobuf_header(&[&header[..], &[1]].concat(), &mut indexes).is_err());
+        assert!(extract_protobuf_header(&[&header[..], &[4, 2]].concat(), &mut indexes).is_err());
+    }
+
+    #[mz_ore::test]
+    fn protobuf_message_indexes_roundtrip() {
+        let mut indexes = vec![];
+        for expected in [vec![0], vec![1], vec![1, 0], vec![0, 70, 3]] {
+            let mut buf = vec![0, 0, 0, 0, 42];
+            encode_protobuf_message_indexes(&expected, &mut buf);
+            buf.push(8);
+            assert_eq!(
+                extract_protobuf_header(&buf, &mut indexes).unwrap(),
+                (42, &[8][..])
+            );
+            assert_eq!(indexes, expected);
+        }
     }
 }
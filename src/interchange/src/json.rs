@@ -28,6 +28,9 @@ const MICROS_PER_MILLIS: u32 = 1_000;
 // Manages encoding of JSON-encoded bytes
 pub struct JsonEncoder {
     columns: Vec<(ColumnName, SqlColumnType)>,
+    /// The ID of the JSON Schema in the Confluent Schema Registry to frame
+    /// each document with, if any.
+    schema_id: Option<i32>,
 }
 
 impl JsonEncoder {
@@ -36,14 +39,38 @@ impl JsonEncoder {
         if debezium {
             columns = envelopes::dbz_envelope(columns);
         };
-        JsonEncoder { columns }
+        JsonEncoder {
+            columns,
+            schema_id: None,
+        }
+    }
+
+    /// Constructs an encoder that frames each document with the Confluent
+    /// wire format header for the JSON Schema with ID `schema_id`.
+    ///
+    /// The schema is expected to be built by
+    /// [`build_row_schema_json`](crate::json_schema::build_row_schema_json).
+    pub fn new_confluent(desc: RelationDesc, debezium: bool, schema_id: i32) -> Self {
+        JsonEncoder {
+            schema_id: Some(schema_id),
+            ..Self::new(desc, debezium)
+        }
     }
 }
 
 impl Encode for JsonEncoder {
     fn encode_unchecked(&self, row: mz_repr::Row) -> Vec<u8> {
         let value = encode_datums_as_json(row.iter(), self.columns.as_ref());
-        value.to_string().into_bytes()
+        match self.schema_id {
+            None => value.to_string().into_bytes(),
+            Some(schema_id) => {
+                // A magic byte (0) followed by the big-endian 32-bit schema ID.
+                let mut buf = vec![0];
+                buf.extend_from_slice(&schema_id.to_be_bytes());
+                serde_json::to_writer(&mut buf, &value).expect("writing to vec cannot fail");
+                buf
+            }
+        }
     }
 }
 
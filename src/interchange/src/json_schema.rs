@@ -0,0 +1,646 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Translations between JSON Schema and Materialize types.
+//!
+//! Sources derive typed columns from the JSON Schema that is registered for a
+//! topic, and sinks describe the JSON they write with a generated JSON Schema.
+//!
+//! Only the parts of JSON Schema that describe the shape of a document are
+//! interpreted. A property of an object becomes a column, or a field of a
+//! record, and is nullable unless the object requires it and the property
+//! doesn't admit `null`. Any schema that can't be represented by a single
+//! Materialize type, like a union of several types, a recursive reference or
+//! an object without properties, is decoded as `jsonb`.
+
+use std::collections::BTreeSet;
+
+use anyhow::{Context, anyhow, bail};
+use mz_ore::str::StrExt;
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::{
+    ColumnName, Datum, RelationDesc, Row, RowPacker, SqlColumnType, SqlScalarType, strconv,
+};
+use serde_json::{Map, Value, json};
+
+use crate::encode::column_names_and_types;
+use crate::envelopes;
+
+/// The name of the column that holds a document whose schema isn't an object
+/// with properties.
+const DATA_COLUMN_NAME: &str = "data";
+
+/// The JSON Schema dialect of the schemas that sinks publish.
+const SCHEMA_DIALECT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// The shape of a value that is described by a JSON Schema.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Bool,
+    Int,
+    Number,
+    String,
+    Date,
+    TimestampTz,
+    Uuid,
+    List { items: Box<Node>, nullable: bool },
+    Record(Vec<Field>),
+    Jsonb,
+}
+
+/// A property of an object.
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    name: String,
+    node: Node,
+    nullable: bool,
+}
+
+impl Node {
+    fn scalar_type(&self) -> SqlScalarType {
+        match self {
+            Node::Bool => SqlScalarType::Bool,
+            Node::Int => SqlScalarType::Int64,
+            Node::Number => SqlScalarType::Float64,
+            Node::String => SqlScalarType::String,
+            Node::Date => SqlScalarType::Date,
+            Node::TimestampTz => SqlScalarType::TimestampTz { precision: None },
+            Node::Uuid => SqlScalarType::Uuid,
+            Node::List { items, .. } => SqlScalarType::List {
+                element_type: Box::new(items.scalar_type()),
+                custom_id: None,
+            },
+            Node::Record(fields) => SqlScalarType::Record {
+                fields: fields
+                    .iter()
+                    .map(|field| {
+                        let ty = field.node.scalar_type().nullable(field.nullable);
+                        (ColumnName::from(field.name.as_str()), ty)
+                    })
+                    .collect(),
+                custom_id: None,
+            },
+            Node::Jsonb => SqlScalarType::Jsonb,
+        }
+    }
+}
+
+/// A decoded description of the values that a JSON Schema admits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedSchema {
+    /// The properties of the root object, or a single `data` property if the
+    /// root of the schema doesn't describe an object with properties.
+    fields: Vec<Field>,
+    /// Whether the root of the schema is flattened into columns.
+    flattened: bool,
+}
+
+impl DecodedSchema {
+    /// Parses a JSON Schema.
+    ///
+    /// References to definitions in the same schema are resolved. References
+    /// to other schemas are not supported.
+    pub fn parse(schema: &str) -> Result<Self, anyhow::Error> {
+        let root: Value = serde_json::from_str(schema).context("parsing JSON Schema")?;
+        let mut visiting = BTreeSet::new();
+        let (node, nullable) = derive_node(&root, &root, &mut visiting)?;
+        match node {
+            Node::Record(fields) if !nullable => Ok(DecodedSchema {
+                fields,
+                flattened: true,
+            }),
+            node => Ok(DecodedSchema {
+                fields: vec![Field {
+                    name: DATA_COLUMN_NAME.into(),
+                    node,
+                    nullable,
+                }],
+                flattened: false,
+            }),
+        }
+    }
+
+    /// Describes the columns of the rows that a [`Decoder`] constructed from
+    /// this schema produces.
+    pub fn desc(&self) -> RelationDesc {
+        self.fields
+            .iter()
+            .fold(RelationDesc::builder(), |desc, field| {
+                desc.with_column(
+                    field.name.as_str(),
+                    field.node.scalar_type().nullable(field.nullable),
+                )
+            })
+            .finish()
+    }
+}
+
+/// Derives the shape of the values that `schema` admits, and whether it admits
+/// `null`.
+///
+/// `visiting` holds the references that are being resolved, which detects
+/// recursive references.
+fn derive_node(
+    root: &Value,
+    schema: &Value,
+    visiting: &mut BTreeSet<String>,
+) -> Result<(Node, bool), anyhow::Error> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok((Node::Jsonb, true)),
+        Value::Bool(false) => bail!("JSON Schema `false` does not admit any values"),
+        Value::Object(schema) => schema,
+        _ => bail!("invalid JSON Schema: {}", schema),
+    };
+
+    if let Some(reference) = schema.get("$ref") {
+        let reference = reference
+            .as_str()
+            .ok_or_else(|| anyhow!("invalid JSON Schema reference: {}", reference))?;
+        if visiting.contains(reference) {
+            // A recursive type has no finite representation as a record.
+            return Ok((Node::Jsonb, true));
+        }
+        let target = resolve_reference(root, reference)?;
+        visiting.insert(reference.to_owned());
+        let result = derive_node(root, target, visiting);
+        visiting.remove(reference);
+        return result;
+    }
+
+    for keyword in ["oneOf", "anyOf"] {
+        if let Some(variants) = schema.get(keyword) {
+            let variants = variants
+                .as_array()
+                .ok_or_else(|| anyhow!("invalid JSON Schema: {} must be an array", keyword))?;
+            let (nulls, others): (Vec<_>, Vec<_>) =
+                variants.iter().partition(|variant| is_null_schema(variant));
+            return match others.as_slice() {
+                [other] => {
+                    let (node, nullable) = derive_node(root, other, visiting)?;
+                    Ok((node, nullable || !nulls.is_empty()))
+                }
+                _ => Ok((Node::Jsonb, true)),
+            };
+        }
+    }
+
+    let (types, nullable) = match schema.get("type") {
+        None => return Ok((Node::Jsonb, true)),
+        Some(Value::String(ty)) => (vec![ty.as_str()], ty == "null"),
+        Some(Value::Array(types)) => {
+            let types = types
+                .iter()
+                .map(|ty| {
+                    ty.as_str()
+                        .ok_or_else(|| anyhow!("invalid JSON Schema type: {}", ty))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let nullable = types.contains(&"null");
+            (types, nullable)
+        }
+        Some(ty) => bail!("invalid JSON Schema type: {}", ty),
+    };
+    let types: Vec<_> = types.into_iter().filter(|ty| *ty != "null").collect();
+
+    let node = match types.as_slice() {
+        [] => return Ok((Node::Jsonb, true)),
+        ["boolean"] => Node::Bool,
+        ["integer"] => Node::Int,
+        ["number"] => Node::Number,
+        ["string"] => match schema.get("format").and_then(Value::as_str) {
+            Some("date") => Node::Date,
+            Some("date-time") => Node::TimestampTz,
+            Some("uuid") => Node::Uuid,
+            _ => Node::String,
+        },
+        ["array"] => match schema.get("items") {
+            Some(items @ (Value::Object(_) | Value::Bool(true))) => {
+                let (items, nullable) = derive_node(root, items, visiting)?;
+                Node::List {
+                    items: Box::new(items),
+                    nullable,
+                }
+            }
+            // Tuples have no representation as a list.
+            _ => Node::Jsonb,
+        },
+        ["object"] => match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) if !properties.is_empty() => {
+                let required: BTreeSet<_> = schema
+                    .get("required")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+                let mut fields = Vec::with_capacity(properties.len());
+                for (name, property) in properties {
+                    let (node, nullable) = derive_node(root, property, visiting)
+                        .with_context(|| format!("deriving type of property {}", name.quoted()))?;
+                    fields.push(Field {
+                        name: name.clone(),
+                        node,
+                        nullable: nullable || !required.contains(name.as_str()),
+                    });
+                }
+                Node::Record(fields)
+            }
+            _ => Node::Jsonb,
+        },
+        [ty] => bail!("unsupported JSON Schema type {}", ty.quoted()),
+        // A value that can have several types has no representation other
+        // than `jsonb`.
+        _ => Node::Jsonb,
+    };
+    Ok((node, nullable))
+}
+
+/// Reports whether a schema only admits `null`.
+fn is_null_schema(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+/// Resolves a JSON Pointer reference, like `#/definitions/Address`, against
+/// the root of a schema.
+fn resolve_reference<'a>(root: &'a Value, reference: &str) -> Result<&'a Value, anyhow::Error> {
+    let pointer = reference.strip_prefix('#').ok_or_else(|| {
+        anyhow!(
+            "references to other JSON Schemas are not supported: {}",
+            reference.quoted()
+        )
+    })?;
+    root.pointer(pointer).ok_or_else(|| {
+        anyhow!(
+            "JSON Schema reference {} does not resolve",
+            reference.quoted()
+        )
+    })
+}
+
+/// Decodes JSON documents into rows with the columns of a [`DecodedSchema`].
+#[derive(Debug)]
+pub struct Decoder {
+    schema: DecodedSchema,
+    row: Row,
+    confluent_wire_format: bool,
+}
+
+impl Decoder {
+    /// Constructs a decoder for the documents that a JSON Schema describes.
+    pub fn new(schema: DecodedSchema, confluent_wire_format: bool) -> Self {
+        Decoder {
+            schema,
+            row: Row::default(),
+            confluent_wire_format,
+        }
+    }
+
+    /// Decodes a JSON document into a [`Row`].
+    pub fn decode(&mut self, mut bytes: &[u8]) -> Result<Row, anyhow::Error> {
+        if self.confluent_wire_format {
+            // Like Protobuf, documents are decoded with the schema of the
+            // source rather than the schema they were written with. Documents
+            // that don't match the schema of the source are decoding errors.
+            let (_schema_id, adjusted_bytes) = crate::confluent::extract_json_header(bytes)?;
+            bytes = adjusted_bytes;
+        }
+        let value: Value = serde_json::from_slice(bytes).context("parsing JSON")?;
+        let mut packer = self.row.packer();
+        if self.schema.flattened {
+            let Value::Object(object) = value else {
+                return Err(unexpected("object", &value));
+            };
+            pack_fields(&mut packer, &self.schema.fields, object)?;
+        } else {
+            let field = &self.schema.fields[0];
+            pack_value(&mut packer, &field.node, field.nullable, value)?;
+        }
+        Ok(self.row.clone())
+    }
+}
+
+fn pack_fields(
+    packer: &mut RowPacker,
+    fields: &[Field],
+    mut object: Map<String, Value>,
+) -> Result<(), anyhow::Error> {
+    for field in fields {
+        let value = object.remove(&field.name).unwrap_or(Value::Null);
+        pack_value(packer, &field.node, field.nullable, value)
+            .with_context(|| format!("decoding property {}", field.name.quoted()))?;
+    }
+    Ok(())
+}
+
+fn pack_value(
+    packer: &mut RowPacker,
+    node: &Node,
+    nullable: bool,
+    value: Value,
+) -> Result<(), anyhow::Error> {
+    if value.is_null() {
+        if !nullable {
+            bail!("unexpected null value");
+        }
+        packer.push(Datum::Null);
+        return Ok(());
+    }
+    match node {
+        Node::Bool => {
+            let b = value
+                .as_bool()
+                .ok_or_else(|| unexpected("boolean", &value))?;
+            packer.push(Datum::from(b));
+        }
+        Node::Int => {
+            let i = value
+                .as_i64()
+                .ok_or_else(|| unexpected("integer", &value))?;
+            packer.push(Datum::Int64(i));
+        }
+        Node::Number => {
+            let f = value.as_f64().ok_or_else(|| unexpected("number", &value))?;
+            packer.push(Datum::Float64(f.into()));
+        }
+        Node::String => {
+            let s = value.as_str().ok_or_else(|| unexpected("string", &value))?;
+            packer.push(Datum::String(s));
+        }
+        Node::Date => {
+            let s = value.as_str().ok_or_else(|| unexpected("string", &value))?;
+            packer.push(Datum::Date(strconv::parse_date(s)?));
+        }
+        Node::TimestampTz => {
+            let s = value.as_str().ok_or_else(|| unexpected("string", &value))?;
+            packer.push(Datum::TimestampTz(strconv::parse_timestamptz(s)?));
+        }
+        Node::Uuid => {
+            let s = value.as_str().ok_or_else(|| unexpected("string", &value))?;
+            packer.push(Datum::Uuid(strconv::parse_uuid(s)?));
+        }
+        Node::List { items, nullable } => {
+            let Value::Array(values) = value else {
+                return Err(unexpected("array", &value));
+            };
+            packer.push_list_with(|packer| {
+                for value in values {
+                    pack_value(packer, items, *nullable, value)?;
+                }
+                Ok::<_, anyhow::Error>(())
+            })?;
+        }
+        Node::Record(fields) => {
+            let Value::Object(object) = value else {
+                return Err(unexpected("object", &value));
+            };
+            packer.push_list_with(|packer| pack_fields(packer, fields, object))?;
+        }
+        Node::Jsonb => JsonbPacker::new(packer).pack_serde_json(value)?,
+    }
+    Ok(())
+}
+
+fn unexpected(expected: &str, value: &Value) -> anyhow::Error {
+    anyhow!("expected {}, found {}", expected, value_type(value))
+}
+
+/// Describes the type of a JSON value for error messages.
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Builds the JSON Schema of the documents that a
+/// [`JsonEncoder`](crate::json::JsonEncoder) writes for rows of `desc`.
+pub fn build_row_schema_json(desc: RelationDesc, debezium: bool) -> Value {
+    let mut columns = column_names_and_types(desc);
+    if debezium {
+        columns = envelopes::dbz_envelope(columns);
+    }
+    let mut schema = object_schema(&columns);
+    schema
+        .as_object_mut()
+        .expect("object schemas are JSON objects")
+        .insert("$schema".into(), json!(SCHEMA_DIALECT));
+    schema
+}
+
+fn object_schema(fields: &[(ColumnName, SqlColumnType)]) -> Value {
+    let properties: Map<_, _> = fields
+        .iter()
+        .map(|(name, ty)| (name.to_string(), column_schema(ty)))
+        .collect();
+    // The encoder writes every column, so every property is required.
+    let required: Vec<_> = fields.iter().map(|(name, _ty)| name.to_string()).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn column_schema(ty: &SqlColumnType) -> Value {
+    let schema = match &ty.scalar_type {
+        SqlScalarType::Bool => json!({ "type": "boolean" }),
+        SqlScalarType::PgLegacyChar
+        | SqlScalarType::Int16
+        | SqlScalarType::Int32
+        | SqlScalarType::Int64
+        | SqlScalarType::UInt16
+        | SqlScalarType::UInt32
+        | SqlScalarType::UInt64
+        | SqlScalarType::Oid
+        | SqlScalarType::RegClass
+        | SqlScalarType::RegProc
+        | SqlScalarType::RegType => json!({ "type": "integer" }),
+        SqlScalarType::Float32 | SqlScalarType::Float64 => json!({ "type": "number" }),
+        SqlScalarType::Date => json!({ "type": "string", "format": "date" }),
+        SqlScalarType::Uuid => json!({ "type": "string", "format": "uuid" }),
+        // Bytes are encoded as an array of their values.
+        SqlScalarType::Bytes => json!({
+            "type": "array",
+            "items": { "type": "integer" },
+        }),
+        // Numerics are encoded as strings to preserve their precision, and
+        // timestamps are encoded as milliseconds since the epoch with a
+        // fractional part.
+        SqlScalarType::AclItem
+        | SqlScalarType::MzAclItem
+        | SqlScalarType::Numeric { .. }
+        | SqlScalarType::Time
+        | SqlScalarType::Timestamp { .. }
+        | SqlScalarType::TimestampTz { .. }
+        | SqlScalarType::Interval
+        | SqlScalarType::String
+        | SqlScalarType::Char { .. }
+        | SqlScalarType::VarChar { .. }
+        | SqlScalarType::PgLegacyName
+        | SqlScalarType::MzTimestamp
+        | SqlScalarType::Range { .. } => json!({ "type": "string" }),
+        SqlScalarType::Jsonb => json!({}),
+        // Arrays are encoded as nested arrays, one level per dimension, so
+        // the type of their items depends on the number of dimensions.
+        SqlScalarType::Array(_) | SqlScalarType::Int2Vector => json!({ "type": "array" }),
+        SqlScalarType::List { element_type, .. } => json!({
+            "type": "array",
+            "items": column_schema(&(**element_type).clone().nullable(true)),
+        }),
+        SqlScalarType::Map { value_type, .. } => json!({
+            "type": "object",
+            "additionalProperties": column_schema(&(**value_type).clone().nullable(true)),
+        }),
+        SqlScalarType::Record { fields, .. } => object_schema(fields),
+    };
+    if ty.nullable {
+        json!({ "oneOf": [{ "type": "null" }, schema] })
+    } else {
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, RelationDesc, Row, SqlScalarType};
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_decode_properties() {
+        let schema = DecodedSchema::parse(
+            r##"{
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer" },
+                    "name": { "type": ["string", "null"] },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "address": { "$ref": "#/definitions/Address" },
+                    "extra": {}
+                },
+                "required": ["id", "name", "tags"],
+                "definitions": {
+                    "Address": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } },
+                        "required": ["city"]
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        let desc = schema.desc();
+        let types: Vec<_> = desc
+            .iter()
+            .map(|(name, ty)| (name.as_str(), ty.clone()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("id", SqlScalarType::Int64.nullable(false)),
+                ("name", SqlScalarType::String.nullable(true)),
+                (
+                    "tags",
+                    SqlScalarType::List {
+                        element_type: Box::new(SqlScalarType::String),
+                        custom_id: None,
+                    }
+                    .nullable(false)
+                ),
+                (
+                    "address",
+                    SqlScalarType::Record {
+                        fields: [("city".into(), SqlScalarType::String.nullable(false))].into(),
+                        custom_id: None,
+                    }
+                    .nullable(true)
+                ),
+                ("extra", SqlScalarType::Jsonb.nullable(true)),
+            ]
+        );
+
+        let mut decoder = Decoder::new(schema, true);
+        let document = r#"{"id": 1, "name": null, "tags": ["a"], "address": {"city": "x"}}"#;
+        let row = decoder
+            .decode(&[b"\0\0\0\0\x01", document.as_bytes()].concat())
+            .unwrap();
+        let mut expected = Row::default();
+        let mut packer = expected.packer();
+        packer.push(Datum::Int64(1));
+        packer.push(Datum::Null);
+        packer.push_list([Datum::String("a")]);
+        packer.push_list([Datum::String("x")]);
+        packer.push(Datum::Null);
+        assert_eq!(row, expected);
+
+        // A required property must be present.
+        let err = decoder
+            .decode(b"\0\0\0\0\x01{\"name\": \"n\", \"tags\": []}")
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("decoding property \"id\""));
+    }
+
+    #[mz_ore::test]
+    fn test_recursive_reference() {
+        let schema = DecodedSchema::parse(
+            r##"{
+                "type": "object",
+                "properties": { "tree": { "$ref": "#/definitions/Tree" } },
+                "definitions": {
+                    "Tree": {
+                        "type": "object",
+                        "properties": {
+                            "children": { "type": "array", "items": { "$ref": "#/definitions/Tree" } }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        let (_name, ty) = schema.desc().into_iter().next().unwrap();
+        let SqlScalarType::Record { fields, .. } = ty.scalar_type else {
+            panic!("expected a record, found {ty:?}");
+        };
+        assert_eq!(
+            fields[0].1.scalar_type,
+            SqlScalarType::List {
+                element_type: Box::new(SqlScalarType::Jsonb),
+                custom_id: None,
+            }
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_row_schema_round_trips() {
+        let desc = RelationDesc::builder()
+            .with_column("a", SqlScalarType::Int32.nullable(false))
+            .with_column("b", SqlScalarType::String.nullable(true))
+            .with_column("c", SqlScalarType::Date.nullable(false))
+            .finish();
+        let schema = build_row_schema_json(desc.clone(), false).to_string();
+        let decoded = DecodedSchema::parse(&schema).unwrap();
+        let types: Vec<_> = decoded
+            .desc()
+            .iter()
+            .map(|(name, ty)| (name.as_str().to_owned(), ty.clone()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("a".to_owned(), SqlScalarType::Int64.nullable(false)),
+                ("b".to_owned(), SqlScalarType::String.nullable(true)),
+                ("c".to_owned(), SqlScalarType::Date.nullable(false)),
+            ]
+        );
+    }
+}
@@ -17,5 +17,6 @@ pub mod encode;
 pub mod envelopes;
 pub mod glue;
 pub mod json;
+pub mod json_schema;
 pub mod protobuf;
 pub mod text_binary;
@@ -7,16 +7,33 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::{Context, anyhow, bail};
+use bytes::Bytes;
+use itertools::Itertools;
 use mz_ore::str::StrExt;
-use mz_repr::{ColumnName, Datum, Row, RowPacker, SqlColumnType, SqlScalarType};
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::{ColumnName, Datum, RelationDesc, Row, RowPacker, SqlColumnType, SqlScalarType};
+use prost::Message;
+use prost_reflect::prost_types::field_descriptor_proto::{Label, Type};
+use prost_reflect::prost_types::{
+    DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, MessageOptions,
+    OneofDescriptorProto,
+};
 use prost_reflect::{
-    Cardinality, DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor,
-    ReflectMessage, Value,
+    Cardinality, DescriptorPool, DynamicMessage, FieldDescriptor, FileDescriptor, Kind, MapKey,
+    MessageDescriptor, ReflectMessage, SerializeOptions, Value,
 };
 
+use crate::encode::{Encode, TypedDatum, column_names_and_types};
+use crate::envelopes::dbz_envelope;
+use crate::json::{JsonNumberPolicy, ToJson};
+
+/// The default number of times a recursive message may be nested within itself before it
+/// is decoded as `jsonb`.
+pub const DEFAULT_RECURSION_LIMIT: u32 = 0;
+
 /// A decoded description of the schema of a Protobuf message.
 #[derive(Debug, PartialEq)]
 pub struct DecodedDescriptors {
@@ -28,7 +45,14 @@ pub struct DecodedDescriptors {
 impl DecodedDescriptors {
     /// Builds a `DecodedDescriptors` from an encoded `FileDescriptorSet` and
     /// the fully qualified name of a message inside that file descriptor set.
-    pub fn from_bytes(bytes: &[u8], message_name: String) -> Result<Self, anyhow::Error> {
+    ///
+    /// A recursive message that is nested within itself more than
+    /// `recursion_limit` times is described as `jsonb` rather than as a record.
+    pub fn from_bytes(
+        bytes: &[u8],
+        message_name: String,
+        recursion_limit: u32,
+    ) -> Result<Self, anyhow::Error> {
         let fds = DescriptorPool::decode(bytes).context("decoding file descriptor set")?;
         let message_descriptor = fds.get_message_by_name(&message_name).ok_or_else(|| {
             anyhow!(
@@ -36,8 +60,11 @@ impl DecodedDescriptors {
                 message_name.quoted(),
             )
         })?;
-        let mut seen_messages = BTreeSet::new();
-        seen_messages.insert(message_descriptor.name().to_owned());
+        let mut seen_messages = SeenMessages {
+            depths: BTreeMap::new(),
+            recursion_limit,
+        };
+        seen_messages.enter(&message_descriptor);
         let mut columns = vec![];
         for field in message_descriptor.fields() {
             let name = ColumnName::from(field.name());
@@ -67,6 +94,7 @@ pub struct Decoder {
     descriptors: DecodedDescriptors,
     row: Row,
     confluent_wire_format: bool,
+    message_indexes: Vec<i64>,
 }
 
 impl Decoder {
@@ -79,6 +107,7 @@ impl Decoder {
             descriptors,
             row: Row::default(),
             confluent_wire_format,
+            message_indexes: vec![],
         })
     }
 
@@ -101,22 +130,141 @@ impl Decoder {
             // allocations).
             //
             // [0]: https://developers.google.com/protocol-buffers/docs/overview
-            let (_schema_id, adjusted_bytes) = crate::confluent::extract_protobuf_header(bytes)?;
+            let (_schema_id, adjusted_bytes) =
+                crate::confluent::extract_protobuf_header(bytes, &mut self.message_indexes)?;
             bytes = adjusted_bytes;
+
+            // The message indexes name the message that the datum was written as within the file
+            // that declares it. Messages of other types, which can share a topic with the one we
+            // decode, are not self-describing enough to be told apart once decoding starts.
+            let expected = &self.descriptors.message_descriptor;
+            let message = resolve_message_indexes(&expected.parent_file(), &self.message_indexes);
+            match message {
+                Some(message) if message == *expected => (),
+                Some(message) => bail!(
+                    "Confluent-style protobuf message indexes {:?} name message {}, expected \
+                    message {}",
+                    self.message_indexes,
+                    message.full_name().quoted(),
+                    expected.full_name().quoted(),
+                ),
+                None => bail!(
+                    "Confluent-style protobuf message indexes {:?} do not name a message, \
+                    expected message {}",
+                    self.message_indexes,
+                    expected.full_name().quoted(),
+                ),
+            }
         }
         let message = DynamicMessage::decode(self.descriptors.message_descriptor.clone(), bytes)?;
         let mut packer = self.row.packer();
-        pack_message(&mut packer, &message)?;
+        pack_message(&mut packer, &message, &self.descriptors.columns)?;
         Ok(Some(self.row.clone()))
     }
 }
 
+/// Returns the Confluent-style message indexes of `message`, i.e. the position of each message on
+/// the path from the top-level message of its file to `message` among its siblings.
+pub fn message_indexes(message: &MessageDescriptor) -> Vec<i64> {
+    let mut indexes = vec![];
+    let mut message = message.clone();
+    loop {
+        let parent = message.parent_message();
+        let position = match &parent {
+            Some(parent) => parent.child_messages().position(|m| m == message),
+            None => message.parent_file().messages().position(|m| m == message),
+        };
+        let position = position.expect("message is a child of its parent");
+        indexes.push(i64::try_from(position).expect("message count fits in i64"));
+        match parent {
+            Some(parent) => message = parent,
+            None => break,
+        }
+    }
+    indexes.reverse();
+    indexes
+}
+
+/// Resolves Confluent-style message `indexes` to the message they name within `file`, if any.
+fn resolve_message_indexes(file: &FileDescriptor, indexes: &[i64]) -> Option<MessageDescriptor> {
+    let (first, rest) = indexes.split_first()?;
+    let mut message = file.messages().nth(usize::try_from(*first).ok()?)?;
+    for index in rest {
+        message = message
+            .child_messages()
+            .nth(usize::try_from(*index).ok()?)?;
+    }
+    Some(message)
+}
+
+/// The messages on the path from the top-level message to the message whose
+/// type is being derived.
+struct SeenMessages {
+    /// The number of times each message appears on the path, by full name.
+    depths: BTreeMap<String, u32>,
+    recursion_limit: u32,
+}
+
+impl SeenMessages {
+    /// Enters `message`, returning `false` if it is nested within itself more
+    /// often than the recursion limit allows.
+    fn enter(&mut self, message: &MessageDescriptor) -> bool {
+        let depth = self
+            .depths
+            .entry(message.full_name().to_owned())
+            .or_default();
+        if *depth > self.recursion_limit {
+            return false;
+        }
+        *depth += 1;
+        true
+    }
+
+    fn exit(&mut self, message: &MessageDescriptor) {
+        let depth = self
+            .depths
+            .get_mut(message.full_name())
+            .expect("exited message was entered");
+        *depth -= 1;
+    }
+}
+
 fn derive_column_type(
-    seen_messages: &mut BTreeSet<String>,
+    seen_messages: &mut SeenMessages,
     field: &FieldDescriptor,
 ) -> Result<SqlColumnType, anyhow::Error> {
     if field.is_map() {
-        bail!("Protobuf map fields are not supported");
+        let entry = field
+            .kind()
+            .as_message()
+            .cloned()
+            .ok_or_else(|| anyhow!("map field {} has no entry message", field.name()))?;
+        let key_field = entry.map_entry_key_field();
+        let value_field = entry.map_entry_value_field();
+        let value_type = derive_column_type(seen_messages, &value_field)?;
+        // Materialize maps only admit string keys, so maps with other keys are
+        // described as a list of key/value records.
+        let scalar_type = match key_field.kind() {
+            Kind::String => SqlScalarType::Map {
+                value_type: Box::new(value_type.scalar_type),
+                custom_id: None,
+            },
+            kind => SqlScalarType::List {
+                element_type: Box::new(SqlScalarType::Record {
+                    fields: [
+                        (
+                            ColumnName::from("key"),
+                            derive_inner_type(seen_messages, kind)?,
+                        ),
+                        (ColumnName::from("value"), value_type),
+                    ]
+                    .into(),
+                    custom_id: None,
+                }),
+                custom_id: None,
+            },
+        };
+        return Ok(scalar_type.nullable(false));
     }
 
     let ty = derive_inner_type(seen_messages, field.kind())?;
@@ -134,7 +282,7 @@ fn derive_column_type(
 }
 
 fn derive_inner_type(
-    seen_messages: &mut BTreeSet<String>,
+    seen_messages: &mut SeenMessages,
     ty: Kind,
 ) -> Result<SqlColumnType, anyhow::Error> {
     match ty {
@@ -149,17 +297,16 @@ fn derive_inner_type(
         Kind::Bytes => Ok(SqlScalarType::Bytes.nullable(false)),
         Kind::Enum(_) => Ok(SqlScalarType::String.nullable(false)),
         Kind::Message(m) => {
-            if seen_messages.contains(m.name()) {
-                bail!("Recursive types are not supported: {}", m.name());
+            if !seen_messages.enter(&m) {
+                return Ok(SqlScalarType::Jsonb.nullable(true));
             }
-            seen_messages.insert(m.name().to_owned());
             let mut fields = Vec::with_capacity(m.fields().len());
             for field in m.fields() {
                 let column_name = ColumnName::from(field.name());
                 let column_type = derive_column_type(seen_messages, &field)?;
                 fields.push((column_name, column_type))
             }
-            seen_messages.remove(m.name());
+            seen_messages.exit(&m);
             let ty = SqlScalarType::Record {
                 fields: fields.into(),
                 custom_id: None,
@@ -169,8 +316,12 @@ fn derive_inner_type(
     }
 }
 
-fn pack_message(packer: &mut RowPacker, message: &DynamicMessage) -> Result<(), anyhow::Error> {
-    for field_desc in message.descriptor().fields() {
+fn pack_message(
+    packer: &mut RowPacker,
+    message: &DynamicMessage,
+    columns: &[(ColumnName, SqlColumnType)],
+) -> Result<(), anyhow::Error> {
+    for (field_desc, (_name, ty)) in message.descriptor().fields().zip(columns) {
         if !message.has_field(&field_desc) {
             if field_desc.cardinality() == Cardinality::Required {
                 bail!(
@@ -178,13 +329,16 @@ fn pack_message(packer: &mut RowPacker, message: &DynamicMessage) -> Result<(),
                     field_desc.name()
                 );
             }
-            if field_desc.kind().as_message().is_some() && !field_desc.is_list() {
+            if field_desc.kind().as_message().is_some()
+                && !field_desc.is_list()
+                && !field_desc.is_map()
+            {
                 packer.push(Datum::Null);
                 continue;
             }
         }
         let value = message.get_field(&field_desc);
-        pack_value(packer, &field_desc, &*value)?;
+        pack_value(packer, &field_desc, &*value, &ty.scalar_type)?;
     }
     Ok(())
 }
@@ -193,6 +347,7 @@ fn pack_value(
     packer: &mut RowPacker,
     field_desc: &FieldDescriptor,
     value: &Value,
+    ty: &SqlScalarType,
 ) -> Result<(), anyhow::Error> {
     match value {
         Value::Bool(false) => packer.push(Datum::False),
@@ -222,19 +377,659 @@ fn pack_value(
             })?;
             packer.push(Datum::String(value.name()));
         }
-        Value::Message(m) => packer.push_list_with(|packer| pack_message(packer, m))?,
+        Value::Message(m) => match ty {
+            SqlScalarType::Record { fields, .. } => {
+                packer.push_list_with(|packer| pack_message(packer, m, fields))?
+            }
+            // Recursive messages beyond the recursion limit are decoded as JSON.
+            SqlScalarType::Jsonb => {
+                let options = SerializeOptions::new()
+                    .use_proto_field_name(true)
+                    .stringify_64_bit_integers(false)
+                    .skip_default_fields(false);
+                let json = m.serialize_with_options(serde_json::value::Serializer, &options)?;
+                JsonbPacker::new(packer).pack_serde_json(json)?;
+            }
+            _ => bail!(
+                "internal error: decoding protobuf: field {} has unexpected type {:?}",
+                field_desc.name(),
+                ty
+            ),
+        },
         Value::List(values) => {
+            let element_type = ty.unwrap_list_element_type();
             packer.push_list_with(|packer| {
                 for value in values {
-                    pack_value(packer, field_desc, value)?;
+                    pack_value(packer, field_desc, value, element_type)?;
                 }
                 Ok::<_, anyhow::Error>(())
             })?;
         }
-        Value::Map(_) => bail!(
-            "internal error: unexpected value while decoding protobuf message: {:?}",
-            value
-        ),
+        Value::Map(entries) => {
+            let entry = field_desc.kind();
+            let entry = entry.as_message().ok_or_else(|| {
+                anyhow!(
+                    "internal error: decoding protobuf: map field {} missing entry descriptor",
+                    field_desc.name()
+                )
+            })?;
+            let value_field = entry.map_entry_value_field();
+            // Map entries are unordered on the wire, but Materialize maps and
+            // lists must be packed in a deterministic order.
+            let mut entries: Vec<_> = entries.iter().collect();
+            entries.sort_by(|(k1, _), (k2, _)| map_key_datum(k1).cmp(&map_key_datum(k2)));
+            match ty {
+                SqlScalarType::Map { value_type, .. } => {
+                    packer.push_dict_with(|packer| {
+                        for (key, value) in entries {
+                            packer.push(map_key_datum(key));
+                            pack_value(packer, &value_field, value, value_type)?;
+                        }
+                        Ok::<_, anyhow::Error>(())
+                    })?;
+                }
+                SqlScalarType::List { element_type, .. } => {
+                    let SqlScalarType::Record { fields, .. } = &**element_type else {
+                        bail!(
+                            "internal error: decoding protobuf: map field {} has unexpected type {:?}",
+                            field_desc.name(),
+                            ty
+                        );
+                    };
+                    let value_type = &fields[1].1.scalar_type;
+                    packer.push_list_with(|packer| {
+                        for (key, value) in entries {
+                            packer.push_list_with(|packer| {
+                                packer.push(map_key_datum(key));
+                                pack_value(packer, &value_field, value, value_type)
+                            })?;
+                        }
+                        Ok::<_, anyhow::Error>(())
+                    })?;
+                }
+                _ => bail!(
+                    "internal error: decoding protobuf: map field {} has unexpected type {:?}",
+                    field_desc.name(),
+                    ty
+                ),
+            }
+        }
     }
     Ok(())
 }
+
+fn map_key_datum(key: &MapKey) -> Datum<'_> {
+    match key {
+        MapKey::Bool(false) => Datum::False,
+        MapKey::Bool(true) => Datum::True,
+        MapKey::I32(i) => Datum::Int32(*i),
+        MapKey::I64(i) => Datum::Int64(*i),
+        MapKey::U32(i) => Datum::UInt32(*i),
+        MapKey::U64(i) => Datum::UInt64(*i),
+        MapKey::String(s) => Datum::String(s),
+    }
+}
+
+/// The name of the message that describes the rows written by an [`Encoder`].
+const ROW_MESSAGE_NAME: &str = "Row";
+
+/// The Protobuf schema of the messages that an [`Encoder`] writes for the rows
+/// of a relation.
+///
+/// Columns of types without a Protobuf equivalent are written as strings, in
+/// the format of the JSON encoding of the type. `NULL` columns are omitted from
+/// the message, and `NULL` elements of lists and `NULL` values of maps are
+/// written as the default value of their type.
+struct RowSchema {
+    columns: Vec<(ColumnName, SqlColumnType)>,
+    message: MessageDescriptor,
+}
+
+impl RowSchema {
+    fn new(desc: RelationDesc, debezium: bool) -> Result<Self, anyhow::Error> {
+        let mut columns = column_names_and_types(desc);
+        if debezium {
+            columns = dbz_envelope(columns);
+        }
+        let mut builder = SchemaBuilder::default();
+        builder.add_message(ROW_MESSAGE_NAME.into(), &columns);
+        let file = FileDescriptorProto {
+            name: Some("row.proto".into()),
+            message_type: builder.messages,
+            syntax: Some("proto3".into()),
+            ..Default::default()
+        };
+        let pool = DescriptorPool::from_file_descriptor_set(FileDescriptorSet { file: vec![file] })
+            .context("building protobuf schema")?;
+        let message = pool
+            .get_message_by_name(ROW_MESSAGE_NAME)
+            .expect("row message was added");
+        Ok(RowSchema { columns, message })
+    }
+
+    /// Renders the schema as the source of a `.proto` file.
+    fn to_proto(&self) -> String {
+        fn kind_name(kind: Kind) -> String {
+            match kind {
+                Kind::Bool => "bool".into(),
+                Kind::Int32 => "int32".into(),
+                Kind::Int64 => "int64".into(),
+                Kind::Uint32 => "uint32".into(),
+                Kind::Uint64 => "uint64".into(),
+                Kind::Float => "float".into(),
+                Kind::Double => "double".into(),
+                Kind::String => "string".into(),
+                Kind::Bytes => "bytes".into(),
+                Kind::Message(m) => m.name().into(),
+                kind => unreachable!("generated schemas do not use {kind:?}"),
+            }
+        }
+
+        let mut proto = String::from("syntax = \"proto3\";\n");
+        for message in self.message.parent_file().messages() {
+            proto.push_str(&format!("\nmessage {} {{\n", message.name()));
+            for field in message.fields() {
+                let (label, ty) = match field.kind() {
+                    Kind::Message(entry) if field.is_map() => {
+                        let key = kind_name(entry.map_entry_key_field().kind());
+                        let value = kind_name(entry.map_entry_value_field().kind());
+                        ("", format!("map<{key}, {value}>"))
+                    }
+                    kind if field.is_list() => ("repeated ", kind_name(kind)),
+                    kind if field.field_descriptor_proto().proto3_optional() => {
+                        ("optional ", kind_name(kind))
+                    }
+                    kind => ("", kind_name(kind)),
+                };
+                proto.push_str(&format!(
+                    "  {label}{ty} {} = {};\n",
+                    field.name(),
+                    field.number()
+                ));
+            }
+            proto.push_str("}\n");
+        }
+        proto
+    }
+}
+
+/// Builds the messages of a [`RowSchema`].
+#[derive(Default)]
+struct SchemaBuilder {
+    messages: Vec<DescriptorProto>,
+    /// The name of the message that describes each record type, so that
+    /// identical record types share a message.
+    records: BTreeMap<Box<[(ColumnName, SqlColumnType)]>, String>,
+}
+
+impl SchemaBuilder {
+    /// Adds a message named `name` with a field for each of `columns`.
+    fn add_message(&mut self, name: String, columns: &[(ColumnName, SqlColumnType)]) {
+        // Reserve the message's position, so that messages appear in the order
+        // they are first referenced and the row message is the first message.
+        let index = self.messages.len();
+        self.messages.push(DescriptorProto::default());
+
+        let mut message = DescriptorProto {
+            name: Some(name.clone()),
+            ..Default::default()
+        };
+        let mut field_names = BTreeSet::new();
+        for (i, (column_name, ty)) in columns.iter().enumerate() {
+            let mut field_name = proto_identifier(column_name.as_str());
+            let stem_len = field_name.len();
+            let mut suffix = 1;
+            while !field_names.insert(field_name.clone()) {
+                field_name.truncate(stem_len);
+                field_name.push_str(&format!("_{suffix}"));
+                suffix += 1;
+            }
+            let number = i32::try_from(i + 1).expect("too many columns for a protobuf message");
+            let field = self.field(&name, &mut message, field_name, number, ty);
+            message.field.push(field);
+        }
+        self.messages[index] = message;
+    }
+
+    /// Describes a field of `message` for a column of type `ty`.
+    fn field(
+        &mut self,
+        message_name: &str,
+        message: &mut DescriptorProto,
+        name: String,
+        number: i32,
+        ty: &SqlColumnType,
+    ) -> FieldDescriptorProto {
+        let mut field = FieldDescriptorProto {
+            number: Some(number),
+            label: Some(Label::Optional.into()),
+            ..Default::default()
+        };
+        match &ty.scalar_type {
+            SqlScalarType::List { element_type, .. } if !is_collection(element_type) => {
+                let (ty, type_name) = self.element_type(element_type);
+                field.label = Some(Label::Repeated.into());
+                field.r#type = Some(ty.into());
+                field.type_name = type_name;
+            }
+            SqlScalarType::Map { value_type, .. } if !is_collection(value_type) => {
+                let (value_ty, value_type_name) = self.element_type(value_type);
+                let entry_name = map_entry_name(&name);
+                message.nested_type.push(DescriptorProto {
+                    name: Some(entry_name.clone()),
+                    field: vec![
+                        FieldDescriptorProto {
+                            name: Some("key".into()),
+                            number: Some(1),
+                            label: Some(Label::Optional.into()),
+                            r#type: Some(Type::String.into()),
+                            ..Default::default()
+                        },
+                        FieldDescriptorProto {
+                            name: Some("value".into()),
+                            number: Some(2),
+                            label: Some(Label::Optional.into()),
+                            r#type: Some(value_ty.into()),
+                            type_name: value_type_name,
+                            ..Default::default()
+                        },
+                    ],
+                    options: Some(MessageOptions {
+                        map_entry: Some(true),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                });
+                field.label = Some(Label::Repeated.into());
+                field.r#type = Some(Type::Message.into());
+                field.type_name = Some(format!(".{message_name}.{entry_name}"));
+            }
+            scalar_type => {
+                let (field_ty, type_name) = self.element_type(scalar_type);
+                // Nullable scalars have explicit presence, so that `NULL` can be
+                // told apart from the default value. Proto3 represents this with
+                // a synthetic oneof.
+                if ty.nullable && field_ty != Type::Message {
+                    field.proto3_optional = Some(true);
+                    field.oneof_index =
+                        Some(i32::try_from(message.oneof_decl.len()).expect("too many oneofs"));
+                    message.oneof_decl.push(OneofDescriptorProto {
+                        name: Some(format!("_{name}")),
+                        ..Default::default()
+                    });
+                }
+                field.r#type = Some(field_ty.into());
+                field.type_name = type_name;
+            }
+        }
+        field.name = Some(name);
+        field
+    }
+
+    /// Describes a singular value of type `ty`, returning the Protobuf type and,
+    /// for messages, the fully qualified name of the message.
+    fn element_type(&mut self, ty: &SqlScalarType) -> (Type, Option<String>) {
+        match ty {
+            SqlScalarType::Bool => (Type::Bool, None),
+            SqlScalarType::Int16 | SqlScalarType::Int32 => (Type::Int32, None),
+            SqlScalarType::Int64 => (Type::Int64, None),
+            SqlScalarType::UInt16 | SqlScalarType::UInt32 => (Type::Uint32, None),
+            SqlScalarType::UInt64 => (Type::Uint64, None),
+            SqlScalarType::Float32 => (Type::Float, None),
+            SqlScalarType::Float64 => (Type::Double, None),
+            SqlScalarType::Bytes => (Type::Bytes, None),
+            SqlScalarType::Record { fields, .. } => {
+                let name = match self.records.get(fields) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = format!("Record{}", self.records.len() + 1);
+                        self.records.insert(fields.clone(), name.clone());
+                        self.add_message(name.clone(), fields);
+                        name
+                    }
+                };
+                (Type::Message, Some(format!(".{name}")))
+            }
+            // Strings, and types without a Protobuf equivalent.
+            _ => (Type::String, None),
+        }
+    }
+}
+
+/// Whether a value of type `ty` would be described by a repeated field.
+fn is_collection(ty: &SqlScalarType) -> bool {
+    matches!(
+        ty,
+        SqlScalarType::List { .. } | SqlScalarType::Map { .. } | SqlScalarType::Array(_)
+    )
+}
+
+/// Converts a column name into a valid Protobuf identifier.
+fn proto_identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !ident.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+/// The name that `protoc` gives the entry message of a map field.
+fn map_entry_name(field_name: &str) -> String {
+    let mut name = String::with_capacity(field_name.len() + 5);
+    let mut upper = true;
+    for c in field_name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            name.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name.push_str("Entry");
+    name
+}
+
+/// Builds the `.proto` source of the schema of the messages that an [`Encoder`]
+/// writes for the rows of `desc`.
+pub fn build_row_schema_proto(desc: RelationDesc, debezium: bool) -> Result<String, anyhow::Error> {
+    Ok(RowSchema::new(desc, debezium)?.to_proto())
+}
+
+/// Encodes rows as Protobuf messages in the Confluent wire format.
+///
+/// The schema of the messages is built by [`build_row_schema_proto`].
+#[derive(Debug)]
+pub struct Encoder {
+    columns: Vec<(ColumnName, SqlColumnType)>,
+    message: MessageDescriptor,
+    schema_id: i32,
+}
+
+impl Encoder {
+    /// Constructs an encoder that frames each message with the Confluent wire
+    /// format header for the schema with ID `schema_id`.
+    pub fn new(desc: RelationDesc, debezium: bool, schema_id: i32) -> Result<Self, anyhow::Error> {
+        let RowSchema { columns, message } = RowSchema::new(desc, debezium)?;
+        Ok(Encoder {
+            columns,
+            message,
+            schema_id,
+        })
+    }
+}
+
+impl Encode for Encoder {
+    fn encode_unchecked(&self, row: Row) -> Vec<u8> {
+        let message = encode_message(&self.message, &self.columns, row.iter());
+        // A magic byte (0), the big-endian 32-bit schema ID, and the message
+        // indexes `[0]`, abbreviated to a single zero byte, that select the row
+        // message.
+        let mut buf = vec![0];
+        buf.extend_from_slice(&self.schema_id.to_be_bytes());
+        buf.push(0);
+        message
+            .encode(&mut buf)
+            .expect("encoding to a vec cannot fail");
+        buf
+    }
+}
+
+fn encode_message<'a, I>(
+    descriptor: &MessageDescriptor,
+    columns: &[(ColumnName, SqlColumnType)],
+    datums: I,
+) -> DynamicMessage
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut message = DynamicMessage::new(descriptor.clone());
+    for ((field, (_name, ty)), datum) in descriptor.fields().zip_eq(columns).zip_eq(datums) {
+        if datum.is_null() {
+            continue;
+        }
+        let kind = field.kind();
+        let value = if field.is_map() {
+            let entry = kind.as_message().expect("map fields have an entry message");
+            let value_kind = entry.map_entry_value_field().kind();
+            let value_type = ty.scalar_type.unwrap_map_value_type();
+            let entries = datum
+                .unwrap_map()
+                .iter()
+                .map(|(key, value)| {
+                    let value = encode_value(value, value_type, &value_kind);
+                    (MapKey::String(key.into()), value)
+                })
+                .collect();
+            Value::Map(entries)
+        } else if field.is_list() {
+            let element_type = ty.scalar_type.unwrap_list_element_type();
+            let elements = datum
+                .unwrap_list()
+                .iter()
+                .map(|element| encode_value(element, element_type, &kind))
+                .collect();
+            Value::List(elements)
+        } else {
+            encode_value(datum, &ty.scalar_type, &kind)
+        };
+        message.set_field(&field, value);
+    }
+    message
+}
+
+/// Encodes a singular value of type `ty` as a value of kind `kind`.
+fn encode_value(datum: Datum, ty: &SqlScalarType, kind: &Kind) -> Value {
+    if datum.is_null() {
+        return Value::default_value(kind);
+    }
+    match ty {
+        SqlScalarType::Bool => Value::Bool(datum.unwrap_bool()),
+        SqlScalarType::Int16 => Value::I32(i32::from(datum.unwrap_int16())),
+        SqlScalarType::Int32 => Value::I32(datum.unwrap_int32()),
+        SqlScalarType::Int64 => Value::I64(datum.unwrap_int64()),
+        SqlScalarType::UInt16 => Value::U32(u32::from(datum.unwrap_uint16())),
+        SqlScalarType::UInt32 => Value::U32(datum.unwrap_uint32()),
+        SqlScalarType::UInt64 => Value::U64(datum.unwrap_uint64()),
+        SqlScalarType::Float32 => Value::F32(datum.unwrap_float32()),
+        SqlScalarType::Float64 => Value::F64(datum.unwrap_float64()),
+        SqlScalarType::Bytes => Value::Bytes(Bytes::copy_from_slice(datum.unwrap_bytes())),
+        SqlScalarType::Record { fields, .. } => {
+            let descriptor = kind.as_message().expect("records are encoded as messages");
+            Value::Message(encode_message(
+                descriptor,
+                fields,
+                datum.unwrap_list().iter(),
+            ))
+        }
+        ty => {
+            let ty = ty.clone().nullable(false);
+            let text = match TypedDatum::new(datum, &ty).json(&JsonNumberPolicy::KeepAsNumber) {
+                serde_json::Value::String(s) => s,
+                value => value.to_string(),
+            };
+            Value::String(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use mz_repr::adt::jsonb::Jsonb;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_encode_row() {
+        let point = SqlScalarType::Record {
+            fields: [
+                ("x".into(), SqlScalarType::Int32.nullable(false)),
+                ("y".into(), SqlScalarType::Int32.nullable(false)),
+            ]
+            .into(),
+            custom_id: None,
+        };
+        let desc = RelationDesc::builder()
+            .with_column("id", SqlScalarType::Int64.nullable(false))
+            .with_column("name", SqlScalarType::String.nullable(true))
+            .with_column(
+                "tags",
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::String),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .with_column(
+                "counts",
+                SqlScalarType::Map {
+                    value_type: Box::new(SqlScalarType::Int32),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .with_column("start point", point.clone().nullable(true))
+            .with_column("end", point.nullable(true))
+            .with_column("extra", SqlScalarType::Jsonb.nullable(true))
+            .finish();
+
+        let schema = build_row_schema_proto(desc.clone(), false).unwrap();
+        assert_eq!(
+            schema,
+            r#"syntax = "proto3";
+
+message Row {
+  int64 id = 1;
+  optional string name = 2;
+  repeated string tags = 3;
+  map<string, int32> counts = 4;
+  Record1 start_point = 5;
+  Record1 end = 6;
+  optional string extra = 7;
+}
+
+message Record1 {
+  int32 x = 1;
+  int32 y = 2;
+}
+"#
+        );
+
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.push(Datum::Int64(1));
+        packer.push(Datum::Null);
+        packer.push_list([Datum::String("a"), Datum::String("b")]);
+        packer.push_dict([("k", Datum::Int32(2))]);
+        packer.push_list([Datum::Int32(3), Datum::Int32(4)]);
+        packer.push(Datum::Null);
+        let extra = Jsonb::from_str(r#"{"a": [1, 2]}"#).unwrap();
+        packer.push(extra.as_ref().into_datum());
+
+        let encoder = Encoder::new(desc, false, 7).unwrap();
+        let bytes = encoder.encode_unchecked(row);
+        assert_eq!(bytes[..6], [0, 0, 0, 0, 7, 0]);
+
+        let message = DynamicMessage::decode(encoder.message.clone(), &bytes[6..]).unwrap();
+        let field = |name: &str| message.get_field_by_name(name).unwrap().into_owned();
+        assert_eq!(field("id"), Value::I64(1));
+        assert!(!message.has_field_by_name("name"));
+        assert_eq!(
+            field("tags"),
+            Value::List(vec![Value::String("a".into()), Value::String("b".into())])
+        );
+        assert_eq!(
+            field("counts"),
+            Value::Map([(MapKey::String("k".into()), Value::I32(2))].into())
+        );
+        let start = field("start_point");
+        let start = start.as_message().unwrap();
+        assert_eq!(*start.get_field_by_name("x").unwrap(), Value::I32(3));
+        assert_eq!(*start.get_field_by_name("y").unwrap(), Value::I32(4));
+        assert!(!message.has_field_by_name("end"));
+        assert_eq!(field("extra"), Value::String(r#"{"a":[1,2]}"#.into()));
+    }
+
+    #[mz_ore::test]
+    fn test_decode_confluent_message_indexes() {
+        // Two message types that are written to the same topic, the second of which has a
+        // nested message.
+        let message = |name: &str, nested_type| DescriptorProto {
+            name: Some(name.into()),
+            field: vec![FieldDescriptorProto {
+                name: Some("a".into()),
+                number: Some(1),
+                label: Some(Label::Optional.into()),
+                r#type: Some(Type::Int64.into()),
+                ..Default::default()
+            }],
+            nested_type,
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            name: Some("topic.proto".into()),
+            package: Some("test".into()),
+            message_type: vec![
+                message("Value", vec![]),
+                message("Other", vec![message("Nested", vec![])]),
+            ],
+            syntax: Some("proto3".into()),
+            ..Default::default()
+        };
+        let file_descriptor_set = FileDescriptorSet { file: vec![file] }.encode_to_vec();
+        let decoder = |message_name: &str| {
+            let descriptors = DecodedDescriptors::from_bytes(
+                &file_descriptor_set,
+                message_name.into(),
+                DEFAULT_RECURSION_LIMIT,
+            )
+            .unwrap();
+            assert_eq!(
+                message_indexes(&descriptors.message_descriptor),
+                match message_name {
+                    ".test.Value" => vec![0],
+                    ".test.Other" => vec![1],
+                    _ => vec![1, 0],
+                }
+            );
+            Decoder::new(descriptors, true).unwrap()
+        };
+        let datum = |message_indexes: &[i64]| {
+            let mut buf = vec![0, 0, 0, 0, 1];
+            crate::confluent::encode_protobuf_message_indexes(message_indexes, &mut buf);
+            // Field 1 set to 7.
+            buf.extend([8, 7]);
+            buf
+        };
+        let mut expected = Row::default();
+        expected.packer().push(Datum::Int64(7));
+
+        let mut value = decoder(".test.Value");
+        assert_eq!(value.decode(&datum(&[0])).unwrap(), Some(expected.clone()));
+        let err = value.decode(&datum(&[1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Confluent-style protobuf message indexes [1] name message "test.Other", expected message "test.Value""#
+        );
+        let err = value.decode(&datum(&[2])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Confluent-style protobuf message indexes [2] do not name a message, expected message "test.Value""#
+        );
+
+        let mut other = decoder(".test.Other");
+        assert_eq!(other.decode(&datum(&[1])).unwrap(), Some(expected.clone()));
+        assert!(other.decode(&datum(&[0])).is_err());
+        assert!(other.decode(&datum(&[1, 0])).is_err());
+
+        let mut nested = decoder(".test.Other.Nested");
+        assert_eq!(nested.decode(&datum(&[1, 0])).unwrap(), Some(expected));
+        assert!(nested.decode(&datum(&[1])).is_err());
+    }
+}
@@ -860,6 +860,44 @@ pub fn get_partitions<C: ClientContext>(
     Ok(partition_ids)
 }
 
+/// Retrieve the partitions of every topic whose name satisfies `matches` using the given `client`.
+///
+/// Topics that are being deleted or that have no partitions yet are omitted.
+pub fn get_matching_partitions<C: ClientContext>(
+    client: &Client<C>,
+    matches: impl Fn(&str) -> bool,
+    timeout: Duration,
+) -> Result<BTreeMap<String, Vec<PartitionId>>, GetPartitionsError> {
+    let meta = client.fetch_metadata(None, timeout)?;
+
+    let mut topics = BTreeMap::new();
+    for meta_topic in meta.topics() {
+        if !matches(meta_topic.name()) {
+            continue;
+        }
+        match meta_topic.error().map(RDKafkaErrorCode::from) {
+            Some(RDKafkaErrorCode::UnknownTopic | RDKafkaErrorCode::UnknownTopicOrPartition) => {
+                continue;
+            }
+            Some(code) => Err(anyhow!(code))?,
+            None => (),
+        }
+
+        let mut partition_ids = Vec::with_capacity(meta_topic.partitions().len());
+        for partition_meta in meta_topic.partitions() {
+            if let Some(code) = partition_meta.error() {
+                Err(anyhow!(RDKafkaErrorCode::from(code)))?;
+            }
+            partition_ids.push(partition_meta.id());
+        }
+        if !partition_ids.is_empty() {
+            topics.insert(meta_topic.name().to_string(), partition_ids);
+        }
+    }
+
+    Ok(topics)
+}
+
 /// Default to true as they have no downsides <https://github.com/confluentinc/librdkafka/issues/283>.
 pub const DEFAULT_KEEPALIVE: bool = true;
 /// The `rdkafka` default.
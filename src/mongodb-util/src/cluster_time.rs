@@ -0,0 +1,204 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The cluster time of a MongoDB deployment, which orders its writes.
+//!
+//! Every write to a replica set or sharded cluster is assigned a cluster time,
+//! a `(seconds, increment)` pair that is unique per oplog entry. All the
+//! writes of a multi-document transaction share the cluster time of the
+//! transaction's commit, which makes the cluster time a suitable timestamp for
+//! sources: ingesting the changes of a cluster time together preserves the
+//! atomicity of transactions.
+//!
+//! Change stream events carry their cluster time, and so do the resume tokens
+//! that identify positions in a change stream.
+
+use std::fmt;
+
+#[cfg(any(test, feature = "proptest"))]
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+/// A MongoDB cluster time.
+///
+/// Note: The derived impl of [`PartialOrd`] and [`Ord`] relies on the field
+/// ordering so do not change it.
+#[derive(
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub struct ClusterTime {
+    /// Seconds since the Unix epoch.
+    pub time: u32,
+    /// The ordinal of the write within its second.
+    pub increment: u32,
+}
+
+impl ClusterTime {
+    /// Returns the cluster time as a single integer, which sorts the same way.
+    pub fn as_u64(&self) -> u64 {
+        (u64::from(self.time) << 32) | u64::from(self.increment)
+    }
+
+    /// The inverse of [`ClusterTime::as_u64`].
+    pub fn from_u64(value: u64) -> Self {
+        ClusterTime {
+            time: u32::try_from(value >> 32).expect("shifted out the low bits"),
+            increment: u32::try_from(value & u64::from(u32::MAX)).expect("masked the high bits"),
+        }
+    }
+
+    /// Increment this [`ClusterTime`].
+    ///
+    /// The returned [`ClusterTime`] may not exist upstream yet, but it's
+    /// guaranteed to sort greater than `self`.
+    pub fn increment(self) -> ClusterTime {
+        let value = self.as_u64().checked_add(1);
+        ClusterTime::from_u64(value.unwrap_or_else(|| panic!("overflowed ClusterTime, {self:?}")))
+    }
+
+    /// Returns the cluster time of the change stream position that `token`
+    /// identifies.
+    ///
+    /// Resume tokens are opaque, but their `_data` field is a hex encoded
+    /// key string whose first element is the cluster time of the position,
+    /// encoded as a type byte of `0x82` followed by the big endian time and
+    /// increment.
+    pub fn from_resume_token(token: &mongodb::change_stream::event::ResumeToken) -> Option<Self> {
+        let token = bson::to_document(token).ok()?;
+        let data = token.get_str("_data").ok()?;
+        Self::from_resume_token_data(data)
+    }
+
+    fn from_resume_token_data(data: &str) -> Option<Self> {
+        const TIMESTAMP_TYPE: u8 = 0x82;
+
+        let bytes = hex::decode(data.get(..18)?).ok()?;
+        let (ty, rest) = bytes.split_first()?;
+        if *ty != TIMESTAMP_TYPE {
+            return None;
+        }
+        let value = u64::from_be_bytes(rest.try_into().ok()?);
+        Some(ClusterTime::from_u64(value))
+    }
+}
+
+impl From<bson::Timestamp> for ClusterTime {
+    fn from(ts: bson::Timestamp) -> Self {
+        ClusterTime {
+            time: ts.time,
+            increment: ts.increment,
+        }
+    }
+}
+
+impl From<ClusterTime> for bson::Timestamp {
+    fn from(ts: ClusterTime) -> Self {
+        bson::Timestamp {
+            time: ts.time,
+            increment: ts.increment,
+        }
+    }
+}
+
+impl fmt::Display for ClusterTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.time, self.increment)
+    }
+}
+
+impl columnation::Columnation for ClusterTime {
+    type InnerRegion = columnation::CopyRegion<ClusterTime>;
+}
+
+impl timely::progress::Timestamp for ClusterTime {
+    // No need to describe complex summaries.
+    type Summary = ();
+
+    fn minimum() -> Self {
+        ClusterTime::default()
+    }
+}
+
+impl timely::progress::PathSummary<ClusterTime> for () {
+    fn results_in(&self, src: &ClusterTime) -> Option<ClusterTime> {
+        Some(*src)
+    }
+
+    fn followed_by(&self, _other: &Self) -> Option<Self> {
+        Some(())
+    }
+}
+
+impl timely::progress::timestamp::Refines<()> for ClusterTime {
+    fn to_inner(_other: ()) -> Self {
+        use timely::progress::Timestamp;
+        Self::minimum()
+    }
+    fn to_outer(self) -> () {}
+
+    fn summarize(_path: <Self as timely::progress::Timestamp>::Summary) -> () {}
+}
+
+impl timely::order::PartialOrder for ClusterTime {
+    fn less_equal(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    fn less_than(&self, other: &Self) -> bool {
+        self < other
+    }
+}
+impl timely::order::TotalOrder for ClusterTime {}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_resume_token_cluster_time() {
+        // A resume token of an insert event, as returned by MongoDB 7.0.
+        let data = "8265F1A2B3000000042B042C0100296E5A1004A1B2C3D4E5F60718293A4B5C6D7E8F9046645F6964006465F1A2B3C4D5E6F708090A0B0004";
+        let time = ClusterTime::from_resume_token_data(data).unwrap();
+        assert_eq!(
+            time,
+            ClusterTime {
+                time: 0x65F1A2B3,
+                increment: 4,
+            }
+        );
+
+        assert_eq!(ClusterTime::from_resume_token_data("82"), None);
+        assert_eq!(
+            ClusterTime::from_resume_token_data("0065F1A2B300000004"),
+            None
+        );
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn proptest_cluster_time_u64_roundtrip(a: ClusterTime, b: ClusterTime) {
+            prop_assert_eq!(ClusterTime::from_u64(a.as_u64()), a);
+            prop_assert_eq!(a.cmp(&b), a.as_u64().cmp(&b.as_u64()));
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Decoding of MongoDB documents into rows.
+
+use bson::{Bson, Document};
+use mz_repr::Row;
+use mz_repr::adt::jsonb::JsonbPacker;
+
+/// Packs `document` into `row` as two `jsonb` datums: the `_id` of the
+/// document, which is its key, and the document itself.
+///
+/// Documents are represented in relaxed Extended JSON, which represents the
+/// BSON types that JSON lacks as objects, e.g. `{"$oid": "..."}`.
+pub fn pack_document(row: &mut Row, document: Document) -> Result<(), DecodeError> {
+    let id = document.get("_id").cloned().ok_or(DecodeError::MissingId)?;
+
+    let mut packer = row.packer();
+    JsonbPacker::new(&mut packer)
+        .pack_serde_json(id.into_relaxed_extjson())
+        .map_err(|e| DecodeError::Json(e.to_string()))?;
+    JsonbPacker::new(&mut packer)
+        .pack_serde_json(Bson::Document(document).into_relaxed_extjson())
+        .map_err(|e| DecodeError::Json(e.to_string()))?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecodeError {
+    #[error("document has no _id field")]
+    MissingId,
+    #[error("unable to represent document as jsonb: {0}")]
+    Json(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use bson::doc;
+    use bson::oid::ObjectId;
+    use mz_repr::adt::jsonb::JsonbRef;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_pack_document() {
+        let oid = ObjectId::parse_str("65f1a2b3c4d5e6f708090a0b").unwrap();
+        let document = doc! {
+            "_id": oid,
+            "name": "widget",
+            "count": 3_i64,
+            "tags": ["a", "b"],
+        };
+        let mut row = Row::default();
+        pack_document(&mut row, document).unwrap();
+
+        let datums: Vec<_> = row
+            .iter()
+            .map(|d| JsonbRef::from_datum(d).to_string())
+            .collect();
+        assert_eq!(
+            datums,
+            vec![
+                r#"{"$oid":"65f1a2b3c4d5e6f708090a0b"}"#.to_string(),
+                r#"{"_id":{"$oid":"65f1a2b3c4d5e6f708090a0b"},"count":3,"name":"widget","tags":["a","b"]}"#
+                    .to_string(),
+            ]
+        );
+
+        let mut row = Row::default();
+        assert_eq!(
+            pack_document(&mut row, doc! { "name": "widget" }),
+            Err(DecodeError::MissingId)
+        );
+    }
+}
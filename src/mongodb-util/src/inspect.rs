@@ -0,0 +1,134 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Queries that inspect the state and configuration of a MongoDB deployment.
+
+use bson::{Document, doc};
+use futures::TryStreamExt;
+use mongodb::results::CollectionType;
+use mongodb::{Client, Database};
+
+use crate::{ClusterTime, MongoDbError};
+
+/// The oldest server version whose change streams report the pre-images of
+/// updates and deletes.
+const MIN_SERVER_VERSION: (u64, u64) = (6, 0);
+
+/// Runs the `hello` command, which describes the member of the deployment
+/// that the client is connected to.
+pub async fn hello(client: &Client) -> Result<Document, MongoDbError> {
+    Ok(client
+        .database("admin")
+        .run_command(doc! { "hello": 1 })
+        .await?)
+}
+
+/// Ensures that the deployment supports change streams, i.e. that it's a
+/// replica set or a sharded cluster, and that it's recent enough.
+pub async fn ensure_change_stream_support(client: &Client) -> Result<(), MongoDbError> {
+    let hello = hello(client).await?;
+    let is_replica_set = hello.contains_key("setName");
+    let is_sharded = hello.get_str("msg").ok() == Some("isdbgrid");
+    if !is_replica_set && !is_sharded {
+        return Err(MongoDbError::InvalidSystemSetting {
+            setting: "deployment".into(),
+            expected: "replica set or sharded cluster".into(),
+            actual: "standalone".into(),
+        });
+    }
+
+    let build_info = client
+        .database("admin")
+        .run_command(doc! { "buildInfo": 1 })
+        .await?;
+    let version = build_info.get_str("version")?;
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    let major_minor = (parts.next().flatten(), parts.next().flatten());
+    match major_minor {
+        (Some(major), Some(minor)) if (major, minor) >= MIN_SERVER_VERSION => Ok(()),
+        _ => Err(MongoDbError::InvalidSystemSetting {
+            setting: "version".into(),
+            expected: format!(">= {}.{}", MIN_SERVER_VERSION.0, MIN_SERVER_VERSION.1),
+            actual: version.into(),
+        }),
+    }
+}
+
+/// Returns the latest cluster time the deployment has assigned to a write.
+///
+/// Every write whose effects are visible by the time this returns has a
+/// cluster time less than or equal to the returned one.
+pub async fn get_current_cluster_time(client: &Client) -> Result<ClusterTime, MongoDbError> {
+    let reply = client
+        .database("admin")
+        .run_command(doc! { "ping": 1 })
+        .await?;
+    let time = match reply.get_timestamp("operationTime") {
+        Ok(time) => time,
+        Err(_) => reply
+            .get_document("$clusterTime")?
+            .get_timestamp("clusterTime")?,
+    };
+    Ok(time.into())
+}
+
+/// A collection of a database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionInfo {
+    /// The name of the collection.
+    pub name: String,
+    /// Whether change streams report the pre- and post-images of the changes
+    /// to the collection.
+    pub pre_and_post_images: bool,
+}
+
+/// Returns the collections of `db`, omitting views and system collections.
+pub async fn list_collections(db: &Database) -> Result<Vec<CollectionInfo>, MongoDbError> {
+    let specs: Vec<_> = db.list_collections().await?.try_collect().await?;
+    let mut collections: Vec<_> = specs
+        .into_iter()
+        .filter(|spec| {
+            matches!(spec.collection_type, CollectionType::Collection)
+                && !spec.name.starts_with("system.")
+        })
+        .map(|spec| CollectionInfo {
+            pre_and_post_images: spec
+                .options
+                .change_stream_pre_and_post_images
+                .is_some_and(|images| images.enabled),
+            name: spec.name,
+        })
+        .collect();
+    collections.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(collections)
+}
+
+/// Ensures that change streams report the pre- and post-images of the changes
+/// to the named collections of `db`, which is what allows updates and deletes
+/// to be retracted.
+pub async fn ensure_pre_and_post_images(
+    db: &Database,
+    collections: &[&str],
+) -> Result<(), MongoDbError> {
+    let infos = list_collections(db).await?;
+    let missing: Vec<_> = collections
+        .iter()
+        .filter(|name| {
+            !infos
+                .iter()
+                .any(|info| info.name == **name && info.pre_and_post_images)
+        })
+        .map(|name| name.to_string())
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(MongoDbError::MissingPreAndPostImages(missing))
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! MongoDB utility library.
+
+use std::io::Write;
+use std::time::Duration;
+
+use mongodb::Client;
+use mongodb::options::{
+    ClientOptions, Credential, ReadPreference, SelectionCriteria, ServerAddress, Tls, TlsOptions,
+};
+
+pub mod cluster_time;
+pub mod decoding;
+pub mod inspect;
+pub mod snapshot;
+
+pub use cluster_time::ClusterTime;
+pub use decoding::pack_document;
+
+/// The default timeout for establishing a connection to the deployment.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How to secure the connection to the deployment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsMode {
+    /// Don't use TLS.
+    Disabled,
+    /// Use TLS, but don't verify the server's certificate.
+    Required,
+    /// Use TLS, and verify the server's certificate and host name.
+    VerifyIdentity,
+}
+
+/// Configuration for a connection to a MongoDB deployment.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The host names of the deployment's members that are used to discover
+    /// the rest of the deployment.
+    pub hosts: Vec<(String, u16)>,
+    /// The user to authenticate as, if any.
+    pub user: Option<String>,
+    /// The password to authenticate with.
+    pub password: Option<String>,
+    /// The database the user is defined in. Defaults to `admin`.
+    pub auth_source: Option<String>,
+    /// How to secure the connection.
+    pub tls_mode: TlsMode,
+    /// An optional root certificate in PEM format to verify the server's
+    /// certificate with.
+    pub tls_root_cert: Option<String>,
+    /// The timeout for establishing a connection.
+    pub connect_timeout: Duration,
+}
+
+impl Config {
+    /// Returns a [`Client`] for the deployment, after verifying that the
+    /// deployment can be reached with the configured credentials.
+    ///
+    /// Every operation of the returned client is sent to the primary, so that
+    /// cursors opened by one command can be continued by the next.
+    pub async fn connect(&self) -> Result<Client, MongoDbError> {
+        let mut options = ClientOptions::default();
+        options.hosts = self
+            .hosts
+            .iter()
+            .map(|(host, port)| ServerAddress::Tcp {
+                host: host.clone(),
+                port: Some(*port),
+            })
+            .collect();
+        options.app_name = Some("materialize".into());
+        options.connect_timeout = Some(self.connect_timeout);
+        options.server_selection_timeout = Some(self.connect_timeout);
+        options.selection_criteria =
+            Some(SelectionCriteria::ReadPreference(ReadPreference::Primary));
+        if let Some(user) = &self.user {
+            options.credential = Some(
+                Credential::builder()
+                    .username(user.clone())
+                    .password(self.password.clone())
+                    .source(self.auth_source.clone())
+                    .build(),
+            );
+        }
+
+        // The driver only reads root certificates from files, which it does
+        // when the client is constructed.
+        let mut root_cert_file = None;
+        options.tls = match self.tls_mode {
+            TlsMode::Disabled => Some(Tls::Disabled),
+            TlsMode::Required => Some(Tls::Enabled(
+                TlsOptions::builder()
+                    .allow_invalid_certificates(true)
+                    .build(),
+            )),
+            TlsMode::VerifyIdentity => {
+                let ca_file_path = match &self.tls_root_cert {
+                    Some(cert) => {
+                        let mut file = tempfile::NamedTempFile::new()
+                            .map_err(|e| MongoDbError::InvalidClientConfig(e.to_string()))?;
+                        file.write_all(cert.as_bytes())
+                            .map_err(|e| MongoDbError::InvalidClientConfig(e.to_string()))?;
+                        let path = file.path().to_path_buf();
+                        root_cert_file = Some(file);
+                        Some(path)
+                    }
+                    None => None,
+                };
+                Some(Tls::Enabled(
+                    TlsOptions::builder().ca_file_path(ca_file_path).build(),
+                ))
+            }
+        };
+
+        let client = Client::with_options(options)?;
+        drop(root_cert_file);
+        // Constructing a client doesn't connect to the deployment, so do that
+        // now to surface any connection or authentication errors.
+        inspect::hello(&client).await?;
+        Ok(client)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MongoDbError {
+    #[error("error creating mongodb client with config: {0}")]
+    InvalidClientConfig(String),
+    #[error(
+        "invalid mongodb deployment setting '{setting}'. Expected '{expected}'. Got '{actual}'."
+    )]
+    InvalidSystemSetting {
+        setting: String,
+        expected: String,
+        actual: String,
+    },
+    #[error(
+        "collections {} do not have changeStreamPreAndPostImages enabled",
+        quoted_list(.0)
+    )]
+    MissingPreAndPostImages(Vec<String>),
+    #[error("unexpected reply from mongodb: {0}")]
+    UnexpectedReply(String),
+    /// A MongoDB driver error.
+    #[error(transparent)]
+    MongoDb(#[from] mongodb::error::Error),
+    /// Any other error we bail on.
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+fn quoted_list(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("'{name}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl From<bson::document::ValueAccessError> for MongoDbError {
+    fn from(e: bson::document::ValueAccessError) -> Self {
+        MongoDbError::UnexpectedReply(e.to_string())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Consistent snapshots of collections.
+//!
+//! A snapshot reads a collection as of a [`ClusterTime`], using the `snapshot`
+//! read concern. The driver doesn't expose the time at which its snapshot
+//! sessions read, so the snapshot issues the `find` and `getMore` commands
+//! itself.
+
+use bson::{Bson, Document, doc};
+use futures::Stream;
+use mongodb::Database;
+
+use crate::{ClusterTime, MongoDbError};
+
+/// The number of documents to request per round trip.
+const BATCH_SIZE: i32 = 1000;
+
+/// Returns the documents of `collection` as of `at`.
+///
+/// The deployment only retains the history required to serve the snapshot for
+/// `minSnapshotHistoryWindowInSeconds`, so `at` should be recent and the
+/// stream should be consumed promptly.
+pub fn snapshot(
+    db: Database,
+    collection: String,
+    at: ClusterTime,
+) -> impl Stream<Item = Result<Document, MongoDbError>> {
+    async_stream::try_stream! {
+        let reply = db
+            .run_command(doc! {
+                "find": &collection,
+                "batchSize": BATCH_SIZE,
+                "readConcern": {
+                    "level": "snapshot",
+                    "atClusterTime": bson::Timestamp::from(at),
+                },
+            })
+            .await?;
+        let (mut cursor_id, mut batch) = parse_cursor(reply, "firstBatch")?;
+        loop {
+            for document in batch {
+                yield document;
+            }
+            if cursor_id == 0 {
+                break;
+            }
+            let reply = db
+                .run_command(doc! {
+                    "getMore": cursor_id,
+                    "collection": &collection,
+                    "batchSize": BATCH_SIZE,
+                })
+                .await?;
+            (cursor_id, batch) = parse_cursor(reply, "nextBatch")?;
+        }
+    }
+}
+
+/// Returns the id of the cursor in the reply to a `find` or `getMore` command,
+/// and the batch of documents it contains.
+fn parse_cursor(reply: Document, batch_field: &str) -> Result<(i64, Vec<Document>), MongoDbError> {
+    let cursor = reply.get_document("cursor")?;
+    let id = cursor.get_i64("id")?;
+    let batch = cursor
+        .get_array(batch_field)?
+        .iter()
+        .map(|document| match document {
+            Bson::Document(document) => Ok(document.clone()),
+            other => Err(MongoDbError::UnexpectedReply(format!(
+                "expected document in cursor batch, got {other}"
+            ))),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((id, batch))
+}
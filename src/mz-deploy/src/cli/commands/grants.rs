@@ -247,6 +247,8 @@ pub fn desired_grants(
             PrivilegeSpecification::Privileges(privs) => {
                 privs.iter().map(|p| p.to_string()).collect()
             }
+            // Privileges on columns are not privileges on the object.
+            PrivilegeSpecification::Columns(_) => continue,
         };
         for role in &grant.roles {
             let role_name = role.as_str().to_lowercase();
@@ -801,6 +801,7 @@ fn create_target_view_sql(stmt: &Statement, fqn: &FullyQualifiedName) -> Result<
                 name: mv.name,
                 columns: mv.columns,
                 query: mv.query,
+                with_options: vec![],
             },
         },
         other => {
@@ -770,7 +770,9 @@ pub(crate) async fn validate_sink_connections_exist_impl(
     for obj in planned_project.iter_objects() {
         if let Statement::CreateSink(ref stmt) = obj.typed_object.stmt {
             let connection_ids = match &stmt.connection {
-                CreateSinkConnection::Kafka { connection, .. } => {
+                CreateSinkConnection::Kafka { connection, .. }
+                | CreateSinkConnection::Postgres { connection, .. }
+                | CreateSinkConnection::S3 { connection, .. } => {
                     vec![ObjectId::from_raw_item_name(
                         connection,
                         obj.id.expect_database(),
@@ -796,6 +798,7 @@ pub(crate) async fn validate_sink_connections_exist_impl(
                     }
                     ids
                 }
+                CreateSinkConnection::Http { .. } => vec![],
             };
 
             for conn_id in connection_ids {
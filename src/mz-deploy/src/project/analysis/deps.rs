@@ -475,9 +475,18 @@ fn extract_source_connection_dep(
 ) {
     match connection {
         CreateSourceConnection::Kafka { connection, .. }
+        | CreateSourceConnection::Kinesis { connection, .. }
+        | CreateSourceConnection::Nats { connection, .. }
+        | CreateSourceConnection::Mqtt { connection, .. }
         | CreateSourceConnection::Postgres { connection, .. }
         | CreateSourceConnection::SqlServer { connection, .. }
-        | CreateSourceConnection::MySql { connection, .. } => {
+        | CreateSourceConnection::MySql { connection, .. }
+        | CreateSourceConnection::MongoDb { connection, .. }
+        | CreateSourceConnection::S3 { connection, .. }
+        | CreateSourceConnection::Iceberg {
+            catalog_connection: connection,
+            ..
+        } => {
             deps.insert(ObjectId::from_raw_item_name(
                 connection,
                 default_database,
@@ -36,6 +36,7 @@ use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, GlobalId, RelationDesc, RelationVersion, RelationVersionSelector, SqlScalarType,
+    Timestamp,
 };
 use mz_secrets::InMemorySecretsController;
 use mz_sql::ast::Expr;
@@ -418,6 +419,10 @@ impl CatalogItem for LocalItem {
         false
     }
 
+    fn is_notification(&self) -> bool {
+        false
+    }
+
     fn progress_id(&self) -> Option<CatalogItemId> {
         None
     }
@@ -430,6 +435,10 @@ impl CatalogItem for LocalItem {
         None
     }
 
+    fn snapshot_details(&self) -> Option<(GlobalId, Timestamp)> {
+        None
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         None
     }
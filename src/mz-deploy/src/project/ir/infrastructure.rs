@@ -102,6 +102,9 @@ fn connection_type_name(ct: &CreateConnectionType) -> &'static str {
         CreateConnectionType::Csr => "Confluent Schema Registry",
         CreateConnectionType::GlueSchemaRegistry => "Glue Schema Registry",
         CreateConnectionType::SqlServer => "SQL Server",
+        CreateConnectionType::MongoDb => "MongoDB",
+        CreateConnectionType::Nats => "NATS",
+        CreateConnectionType::Mqtt => "MQTT",
         CreateConnectionType::IcebergCatalog => "Iceberg Catalog",
     }
 }
@@ -199,6 +202,30 @@ fn extract_source(stmt: &mz_sql_parser::ast::CreateSourceStatement<Raw>) -> Infr
             Some(raw_item_name_to_string(connection)),
             options_to_properties!(options),
         ),
+        CreateSourceConnection::Kinesis {
+            connection,
+            options,
+        } => (
+            "Kinesis".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
+        CreateSourceConnection::Nats {
+            connection,
+            options,
+        } => (
+            "NATS".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
+        CreateSourceConnection::Mqtt {
+            connection,
+            options,
+        } => (
+            "MQTT".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
         CreateSourceConnection::MySql {
             connection,
             options,
@@ -215,6 +242,30 @@ fn extract_source(stmt: &mz_sql_parser::ast::CreateSourceStatement<Raw>) -> Infr
             Some(raw_item_name_to_string(connection)),
             options_to_properties!(options),
         ),
+        CreateSourceConnection::MongoDb {
+            connection,
+            options,
+        } => (
+            "MongoDB".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
+        CreateSourceConnection::S3 {
+            connection,
+            options,
+        } => (
+            "S3".to_string(),
+            Some(raw_item_name_to_string(connection)),
+            options_to_properties!(options),
+        ),
+        CreateSourceConnection::Iceberg {
+            catalog_connection,
+            options,
+        } => (
+            "Iceberg".to_string(),
+            Some(raw_item_name_to_string(catalog_connection)),
+            options_to_properties!(options),
+        ),
         CreateSourceConnection::LoadGenerator { generator, options } => (
             format!("Load Generator ({})", generator),
             None,
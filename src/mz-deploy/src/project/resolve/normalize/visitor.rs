@@ -136,8 +136,8 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
 
     /// Normalize connection references in CREATE SINK statements.
     ///
-    /// Handles both Kafka and Iceberg sink types, ensuring their connection
-    /// references are normalized.
+    /// Handles Kafka, Iceberg and Postgres sink types, ensuring their
+    /// connection references are normalized.
     pub fn normalize_sink_connection(&self, connection: &mut CreateSinkConnection<Raw>) {
         match connection {
             CreateSinkConnection::Kafka { connection, .. } => {
@@ -153,6 +153,19 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
                     self.normalize_raw_item_name(aws_connection);
                 }
             }
+            CreateSinkConnection::Postgres { connection, .. }
+            | CreateSinkConnection::S3 { connection, .. } => {
+                self.normalize_raw_item_name(connection);
+            }
+            // HTTP sinks have no connection, but their options can reference
+            // secrets.
+            CreateSinkConnection::Http { options, .. } => {
+                for option in options {
+                    if let Some(ref mut value) = option.value {
+                        self.normalize_with_option_value(value);
+                    }
+                }
+            }
         }
     }
 
@@ -163,9 +176,18 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
     pub fn normalize_source_connection(&self, connection: &mut CreateSourceConnection<Raw>) {
         match connection {
             CreateSourceConnection::Kafka { connection, .. }
+            | CreateSourceConnection::Kinesis { connection, .. }
+            | CreateSourceConnection::Nats { connection, .. }
+            | CreateSourceConnection::Mqtt { connection, .. }
             | CreateSourceConnection::Postgres { connection, .. }
             | CreateSourceConnection::SqlServer { connection, .. }
-            | CreateSourceConnection::MySql { connection, .. } => {
+            | CreateSourceConnection::MySql { connection, .. }
+            | CreateSourceConnection::MongoDb { connection, .. }
+            | CreateSourceConnection::S3 { connection, .. }
+            | CreateSourceConnection::Iceberg {
+                catalog_connection: connection,
+                ..
+            } => {
                 self.normalize_raw_item_name(connection);
             }
             CreateSourceConnection::LoadGenerator { .. } => {}
@@ -205,6 +227,11 @@ impl<T: NameTransformer> NormalizingVisitor<T> {
                     self.normalize_with_option_value(item);
                 }
             }
+            WithOptionValue::Map(entries) => {
+                for item in entries.values_mut() {
+                    self.normalize_with_option_value(item);
+                }
+            }
             _ => {}
         }
     }
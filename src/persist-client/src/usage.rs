@@ -16,6 +16,8 @@ use std::time::Instant;
 use futures::stream::{FuturesUnordered, StreamExt};
 use mz_ore::cast::CastFrom;
 use mz_persist::location::Blob;
+use timely::PartialOrder;
+use timely::progress::Antichain;
 use tokio::sync::Semaphore;
 use tracing::{error, info};
 
@@ -274,6 +276,44 @@ impl StorageUsageClient {
         ShardsUsageReferenced { by_shard }
     }
 
+    /// Computes the byte size of the batches in the current state of a shard that hold updates
+    /// at times in `[lower, upper)`, or zero if that interval is empty or the shard does not exist.
+    ///
+    /// This is the history that a reader retains by holding back the since of the shard to `lower`
+    /// while the shard would otherwise be compacted up to `upper`. Batches that straddle either
+    /// bound are counted in full.
+    pub async fn shard_usage_between(
+        &self,
+        shard_id: ShardId,
+        lower: &Antichain<u64>,
+        upper: &Antichain<u64>,
+    ) -> u64 {
+        if !PartialOrder::less_than(lower, upper) {
+            return 0;
+        }
+        let versions = self.state_versions.fetch_all_live_diffs(&shard_id).await;
+        if versions.is_empty() {
+            return 0;
+        }
+        let state = self
+            .state_versions
+            .fetch_current_state::<u64>(&shard_id, versions)
+            .await
+            .check_ts_codec(&shard_id)
+            .expect("ts should be a u64 in all prod shards");
+        let bytes = state
+            .collections
+            .trace
+            .batches()
+            .filter(|batch| {
+                !PartialOrder::less_equal(batch.desc.upper(), lower)
+                    && !PartialOrder::less_equal(upper, batch.desc.lower())
+            })
+            .map(|batch| batch.encoded_size_bytes())
+            .sum();
+        u64::cast_from(bytes)
+    }
+
     /// Computes [ShardUsageAudit] for a single shard.
     ///
     /// Performs a full scan of [Blob] and [mz_persist::location::Consensus] to compute a full audit
@@ -721,7 +761,6 @@ mod tests {
     use mz_dyncfg::ConfigUpdates;
     use mz_persist::location::SeqNo;
     use semver::Version;
-    use timely::progress::Antichain;
 
     use crate::ShardId;
     use crate::batch::{
@@ -844,6 +883,57 @@ mod tests {
         );
     }
 
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn usage_between(dyncfgs: ConfigUpdates) {
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 3, 1),
+            (("3".to_owned(), "three".to_owned()), 5, 1),
+        ];
+
+        let shard_id = ShardId::new();
+        let mut client = new_test_client(&dyncfgs).await;
+        // Keep one batch per append.
+        client.cfg.compaction_enabled = false;
+        let (mut write, _) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write.expect_append(&data[..1], vec![0], vec![2]).await;
+        write.expect_append(&data[1..2], vec![2], vec![4]).await;
+        write.expect_append(&data[2..], vec![4], vec![6]).await;
+
+        let usage = StorageUsageClient::open(client);
+        let between = |lower: u64, upper: u64| {
+            let usage = usage.clone();
+            async move {
+                usage
+                    .shard_usage_between(
+                        shard_id,
+                        &Antichain::from_elem(lower),
+                        &Antichain::from_elem(upper),
+                    )
+                    .await
+            }
+        };
+
+        let total = between(0, 6).await;
+        let middle = between(2, 4).await;
+        assert!(middle > 0);
+        assert!(middle < total);
+        assert_eq!(between(0, 2).await + middle + between(4, 6).await, total);
+        // Batches that straddle a bound are counted in full.
+        assert_eq!(between(3, 4).await, middle);
+        assert_eq!(between(6, 10).await, 0);
+        assert_eq!(between(2, 2).await, 0);
+        assert_eq!(
+            usage
+                .shard_usage_between(ShardId::new(), &Antichain::from_elem(0), &Antichain::new())
+                .await,
+            0
+        );
+    }
+
     /// This is just a sanity check for the overall flow of computing ShardUsage.
     /// The edge cases are exercised in separate tests.
     #[mz_persist_proc::test(tokio::test)]
@@ -817,3 +817,7 @@ pub const MV_MZ_CLUSTER_AUTO_SCALING_STRATEGIES_OID: u32 = 17103;
 pub const INDEX_MZ_CLUSTER_AUTO_SCALING_STRATEGIES_IND_OID: u32 = 17104;
 pub const FUNC_PARSE_POSTGRES_SOURCE_DETAILS_OID: u32 = 17105;
 pub const FUNC_PARSE_KAFKA_SOURCE_DETAILS_OID: u32 = 17106;
+pub const TABLE_MZ_SNAPSHOTS_OID: u32 = 17107;
+pub const TABLE_MZ_COLUMN_PRIVILEGES_OID: u32 = 17108;
+pub const VIEW_COLUMN_PRIVILEGES_OID: u32 = 17109;
+pub const TABLE_MZ_SNAPSHOT_STORAGE_USAGE_OID: u32 = 17110;
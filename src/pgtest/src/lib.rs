@@ -21,7 +21,8 @@
 //! Additional messages are accumulated and returned as well.
 //!
 //! The first time a `conn=name` argument is specified, `cluster=name` can also
-//! be specified to set the sessions cluster on initial connection.
+//! be specified to set the sessions cluster on initial connection, and
+//! `user=name` to connect as a different user than the default one.
 //!
 //! During debugging, set the environment variable `PGTEST_VERBOSE=1` to see
 //! messages sent and received.
@@ -128,7 +129,9 @@ impl PgConn {
         };
 
         conn.stream.set_read_timeout(Some(timeout))?;
-        options.insert(0, ("user", user));
+        if !options.iter().any(|(name, _)| *name == "user") {
+            options.insert(0, ("user", user));
+        }
         options.insert(0, ("welcome_message", "off"));
         conn.send(|buf| frontend::startup_message(options, buf).unwrap())?;
         match conn.recv()?.1 {
@@ -259,6 +262,13 @@ impl PgConn {
                                 .unwrap(),
                         })?,
                     ),
+                    Message::NotificationResponse(body) => (
+                        "NotificationResponse",
+                        serde_json::to_string(&NotificationResponse {
+                            channel: body.channel()?.to_string(),
+                            payload: body.message()?.to_string(),
+                        })?,
+                    ),
                     Message::CopyOutResponse(body) => (
                         "CopyOut",
                         serde_json::to_string(&CopyOut {
@@ -463,6 +473,12 @@ pub struct ErrorResponse {
     pub fields: Vec<ErrorField>,
 }
 
+#[derive(Serialize)]
+pub struct NotificationResponse {
+    pub channel: String,
+    pub payload: String,
+}
+
 #[derive(Serialize)]
 pub struct ErrorField {
     pub typ: char,
@@ -507,6 +523,11 @@ pub fn run_test(tf: &mut datadriven::TestFile, addr: String, user: String, timeo
             let cluster = cluster.into_first();
             options.push(("cluster", cluster.as_str()));
         }
+        let user = args.remove("user");
+        if let Some(user) = &user {
+            let user = user.into_first();
+            options.push(("user", user.as_str()));
+        }
         match tc.directive.as_str() {
             "send" => {
                 for line in lines {
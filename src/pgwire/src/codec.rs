@@ -297,6 +297,7 @@ impl Codec {
             BackendMessage::CopyOutResponse { .. } => b'H',
             BackendMessage::CopyData(_) => b'd',
             BackendMessage::CopyDone => b'c',
+            BackendMessage::NotificationResponse { .. } => b'A',
         };
         dst.put_u8(byte);
 
@@ -442,6 +443,15 @@ impl Codec {
                 dst.put_u32(conn_id);
                 dst.put_u32(secret_key);
             }
+            BackendMessage::NotificationResponse {
+                pid,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(pid);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::ParameterDescription(params) => {
                 if params.len() > usize::try_from(i16::MAX).expect("i16::MAX is positive") {
                     return Err(io::Error::new(
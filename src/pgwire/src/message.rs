@@ -53,6 +53,11 @@ pub enum BackendMessage {
     },
     CopyData(Vec<u8>),
     CopyDone,
+    NotificationResponse {
+        pid: u32,
+        channel: String,
+        payload: String,
+    },
 }
 
 impl From<ErrorResponse> for BackendMessage {
@@ -19,11 +19,11 @@ use base64::prelude::*;
 use byteorder::{ByteOrder, NetworkEndian};
 use csv_core::ReadRecordResult;
 use futures::future::{BoxFuture, FutureExt, pending};
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use mz_adapter::client::{RecordFirstRowStream, redact_sql_for_logging};
 use mz_adapter::session::{
-    EndTransactionAction, InProgressRows, LifecycleTimestamps, PortalRefMut, PortalState, Session,
-    SessionConfig, TransactionStatus,
+    EndTransactionAction, InProgressRows, LifecycleTimestamps, Notification, PortalRefMut,
+    PortalState, Session, SessionConfig, TransactionStatus,
 };
 use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use mz_adapter::{
@@ -905,8 +905,25 @@ where
         let message = select! {
             biased;
 
-            // `recv_timeout()` is cancel-safe as per it's docs.
-            Some(timeout) = self.adapter_client.recv_timeout() => {
+            // `recv_timeout_or_notification()` is cancel-safe as per it's docs.
+            event = self.adapter_client.recv_timeout_or_notification() => {
+                let timeout = match event {
+                    Either::Left(timeout) => timeout,
+                    Either::Right(Notification { channel, payload }) => {
+                        // Notifications are raised by the system rather than
+                        // by another backend, so report the process ID that
+                        // the session itself advertised in `BackendKeyData`.
+                        let pid = self.adapter_client.session().conn_id().unhandled();
+                        self.send(BackendMessage::NotificationResponse {
+                            pid,
+                            channel,
+                            payload,
+                        })
+                        .await?;
+                        self.conn.flush().await?;
+                        return Ok(State::Ready);
+                    }
+                };
                 let err: AdapterError = timeout.into();
                 let conn_id = self.adapter_client.session().conn_id();
                 tracing::warn!("session timed out, conn_id {}", conn_id);
@@ -2400,12 +2417,14 @@ where
             | ExecuteResponse::CreatedSchema { .. }
             | ExecuteResponse::CreatedSecret { .. }
             | ExecuteResponse::CreatedSink { .. }
+            | ExecuteResponse::CreatedNotification
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPolicy
             | ExecuteResponse::Comment
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
@@ -2413,9 +2432,12 @@ where
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPolicy
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
+            | ExecuteResponse::Listen
+            | ExecuteResponse::Unlisten
             | ExecuteResponse::Copied(..)
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
@@ -311,9 +311,51 @@ const BIN_OPS: &[&str] = &[
 /// (which must never panic on any input), and occasionally a valid-but-unusual
 /// statement the structured grammar wouldn't assemble.
 const NOISE: &[&str] = &[
-    "(", ")", "[", "]", "{", "}", ",", ";", ".", "::", ":", "*", "@", "?", "!", "\\", "\"", "'",
-    "->", "->>", "#>>", "||", "<>", "=>", "%", "~", "&", "|", "$1", "$$", "''", "\"\"", "/*", "*/",
-    "--", "  ", "\t", "\n", "1e999", "0x1", "-0", ".", "e", "E'\\x41'", "U&'\\0041'",
+    "(",
+    ")",
+    "[",
+    "]",
+    "{",
+    "}",
+    ",",
+    ";",
+    ".",
+    "::",
+    ":",
+    "*",
+    "@",
+    "?",
+    "!",
+    "\\",
+    "\"",
+    "'",
+    "->",
+    "->>",
+    "#>>",
+    "||",
+    "<>",
+    "=>",
+    "%",
+    "~",
+    "&",
+    "|",
+    "$1",
+    "$$",
+    "''",
+    "\"\"",
+    "/*",
+    "*/",
+    "--",
+    "  ",
+    "\t",
+    "\n",
+    "1e999",
+    "0x1",
+    "-0",
+    ".",
+    "e",
+    "E'\\x41'",
+    "U&'\\0041'",
 ];
 
 // The parser's AST source, embedded so the fuzzed connector option space stays
@@ -1379,7 +1421,10 @@ impl<'a, 'u> Gen<'a, 'u> {
                     self.ident_list(1, 3);
                     if self.chance(1, 2) {
                         self.out.push_str(" WITH");
-                        self.config_option_list(option_names("AlterSourceAddSubsourceOptionName"), true);
+                        self.config_option_list(
+                            option_names("AlterSourceAddSubsourceOptionName"),
+                            true,
+                        );
                     }
                 } else {
                     self.out.push_str("VALIDATE CONNECTION ");
@@ -1448,8 +1493,9 @@ impl<'a, 'u> Gen<'a, 'u> {
     }
 
     /// `CREATE SOURCE` over every connector kind (load generator, Kafka,
-    /// Postgres, MySQL, SQL Server), each with its full config-option space, and
-    /// the source-level `WITH (…)` options.
+    /// Postgres, MySQL, SQL Server, MongoDB, S3, Iceberg, Kinesis, NATS,
+    /// MQTT), each with its full config-option space, and the source-level
+    /// `WITH (…)` options.
     fn create_source(&mut self) {
         self.out.push_str("CREATE SOURCE ");
         if self.chance(1, 6) {
@@ -1460,7 +1506,7 @@ impl<'a, 'u> Gen<'a, 'u> {
             self.out.push_str(" IN CLUSTER ");
             self.ident();
         }
-        match self.pick(5) {
+        match self.pick(11) {
             0 => {
                 // Load generator, self-contained, needs no connection. Option
                 // and generator-kind validity is a planning concern, any pairing
@@ -1503,12 +1549,73 @@ impl<'a, 'u> Gen<'a, 'u> {
                 self.config_option_list(option_names("MySqlConfigOptionName"), false);
                 self.for_tables();
             }
-            _ => {
+            4 => {
                 self.out.push_str(" FROM SQL SERVER CONNECTION ");
                 self.qualified_name();
                 self.config_option_list(option_names("SqlServerConfigOptionName"), false);
                 self.for_tables();
             }
+            5 => {
+                // MongoDB, whose collections are only ingested via
+                // `CREATE TABLE … FROM SOURCE`.
+                self.out.push_str(" FROM MONGODB CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("MongoDbConfigOptionName"), false);
+            }
+            6 => {
+                self.out.push_str(" FROM S3 CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("S3ConfigOptionName"), false);
+            }
+            7 => {
+                self.out.push_str(" FROM ICEBERG CATALOG CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("IcebergConfigOptionName"), false);
+            }
+            8 => {
+                // Kinesis, which takes the same FORMAT / INCLUDE / ENVELOPE
+                // clauses as Kafka.
+                self.out.push_str(" FROM KINESIS CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("KinesisConfigOptionName"), false);
+                if self.chance(2, 3) {
+                    self.out.push(' ');
+                    self.format_clause();
+                }
+                self.include_metadata();
+                if self.chance(1, 2) {
+                    self.out.push_str(" ENVELOPE ");
+                    self.one_of(&["NONE", "UPSERT", "DEBEZIUM"]);
+                }
+            }
+            9 => {
+                self.out.push_str(" FROM NATS CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("NatsConfigOptionName"), false);
+                if self.chance(2, 3) {
+                    self.out.push(' ');
+                    self.format_clause();
+                }
+                self.include_metadata();
+                if self.chance(1, 2) {
+                    self.out.push_str(" ENVELOPE ");
+                    self.one_of(&["NONE", "UPSERT", "DEBEZIUM"]);
+                }
+            }
+            _ => {
+                self.out.push_str(" FROM MQTT CONNECTION ");
+                self.qualified_name();
+                self.config_option_list(option_names("MqttConfigOptionName"), false);
+                if self.chance(2, 3) {
+                    self.out.push(' ');
+                    self.format_clause();
+                }
+                self.include_metadata();
+                if self.chance(1, 2) {
+                    self.out.push_str(" ENVELOPE ");
+                    self.one_of(&["NONE", "UPSERT", "DEBEZIUM"]);
+                }
+            }
         }
         if self.chance(1, 3) {
             self.out.push_str(" WITH");
@@ -1570,6 +1677,9 @@ impl<'a, 'u> Gen<'a, 'u> {
             "POSTGRES",
             "MYSQL",
             "SQL SERVER",
+            "MONGODB",
+            "NATS",
+            "MQTT",
             "AWS",
             "SSH TUNNEL",
         ]);
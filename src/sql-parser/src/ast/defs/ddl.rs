@@ -36,6 +36,14 @@ pub enum MaterializedViewOptionName {
     RetainHistory,
     /// The `REFRESH [=] ...` option.
     Refresh,
+    /// The `COLUMN PRIVILEGES = (...)` option, maintained by `GRANT` and
+    /// `REVOKE` on columns.
+    ColumnPrivileges,
+    /// The `ROW LEVEL SECURITY [=] <bool>` option.
+    RowLevelSecurity,
+    /// The `ROW LEVEL SECURITY POLICIES = (...)` option, maintained by
+    /// `CREATE POLICY` and `DROP POLICY`.
+    RowLevelSecurityPolicies,
 }
 
 impl AstDisplay for MaterializedViewOptionName {
@@ -45,6 +53,11 @@ impl AstDisplay for MaterializedViewOptionName {
             MaterializedViewOptionName::PartitionBy => f.write_str("PARTITION BY"),
             MaterializedViewOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
             MaterializedViewOptionName::Refresh => f.write_str("REFRESH"),
+            MaterializedViewOptionName::ColumnPrivileges => f.write_str("COLUMN PRIVILEGES"),
+            MaterializedViewOptionName::RowLevelSecurity => f.write_str("ROW LEVEL SECURITY"),
+            MaterializedViewOptionName::RowLevelSecurityPolicies => {
+                f.write_str("ROW LEVEL SECURITY POLICIES")
+            }
         }
     }
 }
@@ -59,7 +72,10 @@ impl WithOptionName for MaterializedViewOptionName {
         match self {
             MaterializedViewOptionName::AssertNotNull
             | MaterializedViewOptionName::RetainHistory
-            | MaterializedViewOptionName::Refresh => false,
+            | MaterializedViewOptionName::Refresh
+            | MaterializedViewOptionName::ColumnPrivileges
+            | MaterializedViewOptionName::RowLevelSecurity
+            | MaterializedViewOptionName::RowLevelSecurityPolicies => false,
             // The value is an arbitrary user expression/literal that may embed
             // sensitive data, so redact it (mirrors `KafkaSinkConfigOptionName`).
             MaterializedViewOptionName::PartitionBy => true,
@@ -287,10 +303,15 @@ impl_display_t!(ProtobufSchema);
 pub enum CsrConfigOptionName<T: AstInfo> {
     AvroKeyFullname,
     AvroValueFullname,
+    AvroMaxDepth,
+    AvroUnionAsRecord,
     NullDefaults,
     AvroDocOn(AvroDocOn<T>),
     KeyCompatibilityLevel,
     ValueCompatibilityLevel,
+    KeyMessage,
+    ValueMessage,
+    RecursionLimit,
 }
 
 impl<T: AstInfo> WithOptionName for CsrConfigOptionName<T> {
@@ -303,10 +324,15 @@ impl<T: AstInfo> WithOptionName for CsrConfigOptionName<T> {
         match self {
             Self::AvroKeyFullname
             | Self::AvroValueFullname
+            | Self::AvroMaxDepth
+            | Self::AvroUnionAsRecord
             | Self::NullDefaults
             | Self::AvroDocOn(_)
             | Self::KeyCompatibilityLevel
-            | Self::ValueCompatibilityLevel => false,
+            | Self::ValueCompatibilityLevel
+            | Self::KeyMessage
+            | Self::ValueMessage
+            | Self::RecursionLimit => false,
         }
     }
 }
@@ -355,12 +381,17 @@ impl<T: AstInfo> AstDisplay for CsrConfigOptionName<T> {
         match self {
             CsrConfigOptionName::AvroKeyFullname => f.write_str("AVRO KEY FULLNAME"),
             CsrConfigOptionName::AvroValueFullname => f.write_str("AVRO VALUE FULLNAME"),
+            CsrConfigOptionName::AvroMaxDepth => f.write_str("AVRO MAX DEPTH"),
+            CsrConfigOptionName::AvroUnionAsRecord => f.write_str("AVRO UNION AS RECORD"),
             CsrConfigOptionName::NullDefaults => f.write_str("NULL DEFAULTS"),
             CsrConfigOptionName::AvroDocOn(doc_on) => f.write_node(doc_on),
             CsrConfigOptionName::KeyCompatibilityLevel => f.write_str("KEY COMPATIBILITY LEVEL"),
             CsrConfigOptionName::ValueCompatibilityLevel => {
                 f.write_str("VALUE COMPATIBILITY LEVEL")
             }
+            CsrConfigOptionName::KeyMessage => f.write_str("KEY MESSAGE"),
+            CsrConfigOptionName::ValueMessage => f.write_str("VALUE MESSAGE"),
+            CsrConfigOptionName::RecursionLimit => f.write_str("RECURSION LIMIT"),
         }
     }
 }
@@ -446,6 +477,25 @@ impl<T: AstInfo> AstDisplay for CsrConnectionProtobuf<T> {
 }
 impl_display_t!(CsrConnectionProtobuf);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CsrConnectionJson<T: AstInfo> {
+    pub connection: CsrConnection<T>,
+    pub seed: Option<CsrSeedJson>,
+}
+
+impl<T: AstInfo> AstDisplay for CsrConnectionJson<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("USING CONFLUENT SCHEMA REGISTRY ");
+        f.write_node(&self.connection);
+
+        if let Some(seed) = &self.seed {
+            f.write_str(" ");
+            f.write_node(seed);
+        }
+    }
+}
+impl_display_t!(CsrConnectionJson);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CsrSeedAvro {
     pub key_schema: Option<String>,
@@ -562,6 +612,28 @@ impl AstDisplay for CsrSeedProtobufSchema {
 }
 impl_display!(CsrSeedProtobufSchema);
 
+/// The JSON Schemas registered for the key and value of a topic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CsrSeedJson {
+    pub key_schema: Option<String>,
+    pub value_schema: String,
+}
+
+impl AstDisplay for CsrSeedJson {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("SEED");
+        if let Some(key_schema) = &self.key_schema {
+            f.write_str(" KEY SCHEMA '");
+            f.write_node(&display::escape_single_quote_string(key_schema));
+            f.write_str("'");
+        }
+        f.write_str(" VALUE SCHEMA '");
+        f.write_node(&display::escape_single_quote_string(&self.value_schema));
+        f.write_str("'");
+    }
+}
+impl_display!(CsrSeedJson);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum FormatSpecifier<T: AstInfo> {
     /// `CREATE SOURCE/SINK .. FORMAT`
@@ -601,6 +673,10 @@ pub enum Format<T: AstInfo> {
     Json {
         array: bool,
     },
+    /// `JSON USING CONFLUENT SCHEMA REGISTRY ...`
+    JsonCsr {
+        csr_connection: CsrConnectionJson<T>,
+    },
     Text,
 }
 
@@ -642,6 +718,9 @@ pub enum SourceIncludeMetadata {
     Partition {
         alias: Option<Ident>,
     },
+    Topic {
+        alias: Option<Ident>,
+    },
     Offset {
         alias: Option<Ident>,
     },
@@ -653,6 +732,18 @@ pub enum SourceIncludeMetadata {
         alias: Ident,
         use_bytes: bool,
     },
+    CommitTimestamp {
+        alias: Option<Ident>,
+    },
+    Lsn {
+        alias: Option<Ident>,
+    },
+    Gtid {
+        alias: Option<Ident>,
+    },
+    TransactionId {
+        alias: Option<Ident>,
+    },
 }
 
 impl AstDisplay for SourceIncludeMetadata {
@@ -677,6 +768,26 @@ impl AstDisplay for SourceIncludeMetadata {
                 f.write_str("PARTITION");
                 print_alias(f, alias);
             }
+            SourceIncludeMetadata::Topic { alias } => {
+                f.write_str("TOPIC");
+                print_alias(f, alias);
+            }
+            SourceIncludeMetadata::CommitTimestamp { alias } => {
+                f.write_str("COMMIT TIMESTAMP");
+                print_alias(f, alias);
+            }
+            SourceIncludeMetadata::Lsn { alias } => {
+                f.write_str("LSN");
+                print_alias(f, alias);
+            }
+            SourceIncludeMetadata::Gtid { alias } => {
+                f.write_str("GTID");
+                print_alias(f, alias);
+            }
+            SourceIncludeMetadata::TransactionId { alias } => {
+                f.write_str("TRANSACTION ID");
+                print_alias(f, alias);
+            }
             SourceIncludeMetadata::Offset { alias } => {
                 f.write_str("OFFSET");
                 print_alias(f, alias);
@@ -882,6 +993,10 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                     f.write_str(" ARRAY");
                 }
             }
+            Self::JsonCsr { csr_connection } => {
+                f.write_str("JSON ");
+                f.write_node(csr_connection);
+            }
             Self::Text => f.write_str("TEXT"),
         }
     }
@@ -1065,6 +1180,9 @@ pub enum CreateConnectionType {
     Ssh,
     SqlServer,
     MySql,
+    MongoDb,
+    Nats,
+    Mqtt,
     IcebergCatalog,
 }
 
@@ -1081,6 +1199,9 @@ impl CreateConnectionType {
             Self::Ssh => "ssh-tunnel",
             Self::MySql => "mysql",
             Self::SqlServer => "sql-server",
+            Self::MongoDb => "mongodb",
+            Self::Nats => "nats",
+            Self::Mqtt => "mqtt",
             Self::IcebergCatalog => "iceberg-catalog",
         }
     }
@@ -1119,6 +1240,15 @@ impl AstDisplay for CreateConnectionType {
             Self::MySql => {
                 f.write_str("MYSQL");
             }
+            Self::MongoDb => {
+                f.write_str("MONGODB");
+            }
+            Self::Nats => {
+                f.write_str("NATS");
+            }
+            Self::Mqtt => {
+                f.write_str("MQTT");
+            }
             Self::IcebergCatalog => {
                 f.write_str("ICEBERG CATALOG");
             }
@@ -1167,6 +1297,8 @@ impl_display_t!(CreateConnectionOption);
 pub enum KafkaSourceConfigOptionName {
     GroupIdPrefix,
     Topic,
+    Topics,
+    TopicPattern,
     TopicMetadataRefreshInterval,
     StartTimestamp,
     StartOffset,
@@ -1177,6 +1309,8 @@ impl AstDisplay for KafkaSourceConfigOptionName {
         f.write_str(match self {
             KafkaSourceConfigOptionName::GroupIdPrefix => "GROUP ID PREFIX",
             KafkaSourceConfigOptionName::Topic => "TOPIC",
+            KafkaSourceConfigOptionName::Topics => "TOPICS",
+            KafkaSourceConfigOptionName::TopicPattern => "TOPIC PATTERN",
             KafkaSourceConfigOptionName::TopicMetadataRefreshInterval => {
                 "TOPIC METADATA REFRESH INTERVAL"
             }
@@ -1197,6 +1331,8 @@ impl WithOptionName for KafkaSourceConfigOptionName {
         match self {
             KafkaSourceConfigOptionName::GroupIdPrefix
             | KafkaSourceConfigOptionName::Topic
+            | KafkaSourceConfigOptionName::Topics
+            | KafkaSourceConfigOptionName::TopicPattern
             | KafkaSourceConfigOptionName::TopicMetadataRefreshInterval
             | KafkaSourceConfigOptionName::StartOffset
             | KafkaSourceConfigOptionName::StartTimestamp => false,
@@ -1218,6 +1354,7 @@ pub enum KafkaSinkConfigOptionName {
     PartitionBy,
     ProgressGroupIdPrefix,
     Topic,
+    TopicBy,
     TransactionalIdPrefix,
     LegacyIds,
     TopicConfig,
@@ -1233,6 +1370,7 @@ impl AstDisplay for KafkaSinkConfigOptionName {
             KafkaSinkConfigOptionName::PartitionBy => "PARTITION BY",
             KafkaSinkConfigOptionName::ProgressGroupIdPrefix => "PROGRESS GROUP ID PREFIX",
             KafkaSinkConfigOptionName::Topic => "TOPIC",
+            KafkaSinkConfigOptionName::TopicBy => "TOPIC BY",
             KafkaSinkConfigOptionName::TransactionalIdPrefix => "TRANSACTIONAL ID PREFIX",
             KafkaSinkConfigOptionName::LegacyIds => "LEGACY IDS",
             KafkaSinkConfigOptionName::TopicConfig => "TOPIC CONFIG",
@@ -1263,7 +1401,7 @@ impl WithOptionName for KafkaSinkConfigOptionName {
             | KafkaSinkConfigOptionName::TopicConfig
             | KafkaSinkConfigOptionName::TopicPartitionCount
             | KafkaSinkConfigOptionName::TopicReplicationFactor => false,
-            KafkaSinkConfigOptionName::PartitionBy => true,
+            KafkaSinkConfigOptionName::PartitionBy | KafkaSinkConfigOptionName::TopicBy => true,
         }
     }
 }
@@ -1278,14 +1416,20 @@ impl_display_t!(KafkaSinkConfigOption);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IcebergSinkConfigOptionName {
+    CompactionInterval,
     Namespace,
+    PartitionBy,
+    SnapshotRetention,
     Table,
 }
 
 impl AstDisplay for IcebergSinkConfigOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
+            IcebergSinkConfigOptionName::CompactionInterval => "COMPACTION INTERVAL",
             IcebergSinkConfigOptionName::Namespace => "NAMESPACE",
+            IcebergSinkConfigOptionName::PartitionBy => "PARTITION BY",
+            IcebergSinkConfigOptionName::SnapshotRetention => "SNAPSHOT RETENTION",
             IcebergSinkConfigOptionName::Table => "TABLE",
         })
     }
@@ -1300,7 +1444,11 @@ impl WithOptionName for IcebergSinkConfigOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            IcebergSinkConfigOptionName::Namespace | IcebergSinkConfigOptionName::Table => false,
+            IcebergSinkConfigOptionName::CompactionInterval
+            | IcebergSinkConfigOptionName::Namespace
+            | IcebergSinkConfigOptionName::PartitionBy
+            | IcebergSinkConfigOptionName::SnapshotRetention
+            | IcebergSinkConfigOptionName::Table => false,
         }
     }
 }
@@ -1313,6 +1461,198 @@ pub struct IcebergSinkConfigOption<T: AstInfo> {
 impl_display_for_with_option!(IcebergSinkConfigOption);
 impl_display_t!(IcebergSinkConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostgresSinkConfigOptionName {
+    Schema,
+    Table,
+}
+
+impl AstDisplay for PostgresSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PostgresSinkConfigOptionName::Schema => "SCHEMA",
+            PostgresSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(PostgresSinkConfigOptionName);
+
+impl WithOptionName for PostgresSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            PostgresSinkConfigOptionName::Schema | PostgresSinkConfigOptionName::Table => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostgresSinkConfigOption<T: AstInfo> {
+    pub name: PostgresSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(PostgresSinkConfigOption);
+impl_display_t!(PostgresSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpSinkConfigOptionName {
+    Url,
+    Headers,
+    SigningSecret,
+    SigningHeader,
+    BodyFormat,
+    BatchSize,
+}
+
+impl AstDisplay for HttpSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            HttpSinkConfigOptionName::Url => "URL",
+            HttpSinkConfigOptionName::Headers => "HEADERS",
+            HttpSinkConfigOptionName::SigningSecret => "SIGNING SECRET",
+            HttpSinkConfigOptionName::SigningHeader => "SIGNING HEADER",
+            HttpSinkConfigOptionName::BodyFormat => "BODY FORMAT",
+            HttpSinkConfigOptionName::BatchSize => "BATCH SIZE",
+        })
+    }
+}
+impl_display!(HttpSinkConfigOptionName);
+
+impl WithOptionName for HttpSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            // URLs can embed credentials and headers can carry literal tokens.
+            HttpSinkConfigOptionName::Url | HttpSinkConfigOptionName::Headers => true,
+            HttpSinkConfigOptionName::SigningSecret
+            | HttpSinkConfigOptionName::SigningHeader
+            | HttpSinkConfigOptionName::BodyFormat
+            | HttpSinkConfigOptionName::BatchSize => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpSinkConfigOption<T: AstInfo> {
+    pub name: HttpSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(HttpSinkConfigOption);
+impl_display_t!(HttpSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum S3SinkConfigOptionName {
+    Uri,
+    Format,
+    MaxFileSize,
+    RollInterval,
+}
+
+impl AstDisplay for S3SinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            S3SinkConfigOptionName::Uri => "URI",
+            S3SinkConfigOptionName::Format => "FORMAT",
+            S3SinkConfigOptionName::MaxFileSize => "MAX FILE SIZE",
+            S3SinkConfigOptionName::RollInterval => "ROLL INTERVAL",
+        })
+    }
+}
+impl_display!(S3SinkConfigOptionName);
+
+impl WithOptionName for S3SinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            S3SinkConfigOptionName::Uri
+            | S3SinkConfigOptionName::Format
+            | S3SinkConfigOptionName::MaxFileSize
+            | S3SinkConfigOptionName::RollInterval => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct S3SinkConfigOption<T: AstInfo> {
+    pub name: S3SinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(S3SinkConfigOption);
+impl_display_t!(S3SinkConfigOption);
+
+/// A transform that derives the partition value of an Iceberg table from a
+/// column.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergPartitionTransform {
+    Identity,
+    /// Hashes the value into the given number of buckets.
+    Bucket(u64),
+    /// Truncates the value to the given width.
+    Truncate(u64),
+    Year,
+    Month,
+    Day,
+    Hour,
+}
+
+impl IcebergPartitionTransform {
+    /// The name of the transform, as written in a `PARTITION BY` option.
+    pub fn name(&self) -> &'static str {
+        match self {
+            IcebergPartitionTransform::Identity => "identity",
+            IcebergPartitionTransform::Bucket(_) => "bucket",
+            IcebergPartitionTransform::Truncate(_) => "truncate",
+            IcebergPartitionTransform::Year => "year",
+            IcebergPartitionTransform::Month => "month",
+            IcebergPartitionTransform::Day => "day",
+            IcebergPartitionTransform::Hour => "hour",
+        }
+    }
+}
+
+/// A field of the `PARTITION BY` option of an Iceberg sink, e.g. `day(ts)`
+/// or `bucket(16, id)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IcebergPartitionField {
+    pub transform: IcebergPartitionTransform,
+    pub column: Ident,
+}
+
+impl AstDisplay for IcebergPartitionField {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match &self.transform {
+            IcebergPartitionTransform::Identity => f.write_node(&self.column),
+            IcebergPartitionTransform::Bucket(n) | IcebergPartitionTransform::Truncate(n) => {
+                f.write_str(self.transform.name());
+                f.write_str("(");
+                f.write_str(n);
+                f.write_str(", ");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+            transform => {
+                f.write_str(transform.name());
+                f.write_str("(");
+                f.write_node(&self.column);
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display!(IcebergPartitionField);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PgConfigOptionName {
     /// Hex encoded string of binary serialization of
@@ -1487,6 +1827,267 @@ pub struct SqlServerConfigOption<T: AstInfo> {
 impl_display_for_with_option!(SqlServerConfigOption);
 impl_display_t!(SqlServerConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MongoDbConfigOptionName {
+    /// The database whose collections the source ingests.
+    Database,
+}
+
+impl AstDisplay for MongoDbConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MongoDbConfigOptionName::Database => "DATABASE",
+        })
+    }
+}
+impl_display!(MongoDbConfigOptionName);
+
+impl WithOptionName for MongoDbConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            MongoDbConfigOptionName::Database => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `{FROM|INTO} CONNECTION ...` statement.
+pub struct MongoDbConfigOption<T: AstInfo> {
+    pub name: MongoDbConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(MongoDbConfigOption);
+impl_display_t!(MongoDbConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum S3ConfigOptionName {
+    /// The `s3://bucket/prefix` URI of the objects the source lists.
+    Uri,
+    /// A glob pattern that object keys must match to be ingested.
+    Pattern,
+    /// The format of the objects, either `CSV` or `PARQUET`.
+    Format,
+    /// Whether CSV objects start with a header row.
+    Header,
+    /// The delimiter of CSV objects.
+    Delimiter,
+    /// The quote character of CSV objects.
+    Quote,
+    /// The escape character of CSV objects.
+    Escape,
+    /// The string that represents `NULL` in CSV objects.
+    Null,
+}
+
+impl AstDisplay for S3ConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            S3ConfigOptionName::Uri => "URI",
+            S3ConfigOptionName::Pattern => "PATTERN",
+            S3ConfigOptionName::Format => "FORMAT",
+            S3ConfigOptionName::Header => "HEADER",
+            S3ConfigOptionName::Delimiter => "DELIMITER",
+            S3ConfigOptionName::Quote => "QUOTE",
+            S3ConfigOptionName::Escape => "ESCAPE",
+            S3ConfigOptionName::Null => "NULL",
+        })
+    }
+}
+impl_display!(S3ConfigOptionName);
+
+impl WithOptionName for S3ConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            S3ConfigOptionName::Uri
+            | S3ConfigOptionName::Pattern
+            | S3ConfigOptionName::Format
+            | S3ConfigOptionName::Header
+            | S3ConfigOptionName::Delimiter
+            | S3ConfigOptionName::Quote
+            | S3ConfigOptionName::Escape
+            | S3ConfigOptionName::Null => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM S3 CONNECTION ...` statement.
+pub struct S3ConfigOption<T: AstInfo> {
+    pub name: S3ConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(S3ConfigOption);
+impl_display_t!(S3ConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KinesisConfigOptionName {
+    /// The name of the Kinesis data stream the source reads.
+    Stream,
+}
+
+impl AstDisplay for KinesisConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            KinesisConfigOptionName::Stream => "STREAM",
+        })
+    }
+}
+impl_display!(KinesisConfigOptionName);
+
+impl WithOptionName for KinesisConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            KinesisConfigOptionName::Stream => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM KINESIS CONNECTION ...` statement.
+pub struct KinesisConfigOption<T: AstInfo> {
+    pub name: KinesisConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(KinesisConfigOption);
+impl_display_t!(KinesisConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NatsConfigOptionName {
+    /// The name of the JetStream stream the source reads.
+    Stream,
+    /// The name of the durable consumer the source reads the stream with.
+    Consumer,
+    /// The subject filter that selects the messages of the stream to read.
+    Subject,
+}
+
+impl AstDisplay for NatsConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            NatsConfigOptionName::Stream => "STREAM",
+            NatsConfigOptionName::Consumer => "CONSUMER",
+            NatsConfigOptionName::Subject => "SUBJECT",
+        })
+    }
+}
+impl_display!(NatsConfigOptionName);
+
+impl WithOptionName for NatsConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            NatsConfigOptionName::Stream
+            | NatsConfigOptionName::Consumer
+            | NatsConfigOptionName::Subject => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM NATS CONNECTION ...` statement.
+pub struct NatsConfigOption<T: AstInfo> {
+    pub name: NatsConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(NatsConfigOption);
+impl_display_t!(NatsConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MqttConfigOptionName {
+    /// The topic filter the source subscribes to.
+    Topic,
+    /// The shared subscription group the source subscribes in, if any.
+    SharedGroup,
+}
+
+impl AstDisplay for MqttConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MqttConfigOptionName::Topic => "TOPIC",
+            MqttConfigOptionName::SharedGroup => "SHARED GROUP",
+        })
+    }
+}
+impl_display!(MqttConfigOptionName);
+
+impl WithOptionName for MqttConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            MqttConfigOptionName::Topic | MqttConfigOptionName::SharedGroup => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM MQTT CONNECTION ...` statement.
+pub struct MqttConfigOption<T: AstInfo> {
+    pub name: MqttConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(MqttConfigOption);
+impl_display_t!(MqttConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IcebergConfigOptionName {
+    /// The namespace whose tables the source can ingest.
+    Namespace,
+}
+
+impl AstDisplay for IcebergConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            IcebergConfigOptionName::Namespace => "NAMESPACE",
+        })
+    }
+}
+impl_display!(IcebergConfigOptionName);
+
+impl WithOptionName for IcebergConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            IcebergConfigOptionName::Namespace => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM ICEBERG CATALOG CONNECTION ...` statement.
+pub struct IcebergConfigOption<T: AstInfo> {
+    pub name: IcebergConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(IcebergConfigOption);
+impl_display_t!(IcebergConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceConnection<T: AstInfo> {
     Kafka {
@@ -1505,6 +2106,30 @@ pub enum CreateSourceConnection<T: AstInfo> {
         connection: T::ItemName,
         options: Vec<MySqlConfigOption<T>>,
     },
+    MongoDb {
+        connection: T::ItemName,
+        options: Vec<MongoDbConfigOption<T>>,
+    },
+    S3 {
+        connection: T::ItemName,
+        options: Vec<S3ConfigOption<T>>,
+    },
+    Kinesis {
+        connection: T::ItemName,
+        options: Vec<KinesisConfigOption<T>>,
+    },
+    Nats {
+        connection: T::ItemName,
+        options: Vec<NatsConfigOption<T>>,
+    },
+    Mqtt {
+        connection: T::ItemName,
+        options: Vec<MqttConfigOption<T>>,
+    },
+    Iceberg {
+        catalog_connection: T::ItemName,
+        options: Vec<IcebergConfigOption<T>>,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
@@ -1562,6 +2187,78 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::MongoDb {
+                connection,
+                options,
+            } => {
+                f.write_str("MONGODB CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::S3 {
+                connection,
+                options,
+            } => {
+                f.write_str("S3 CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::Kinesis {
+                connection,
+                options,
+            } => {
+                f.write_str("KINESIS CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::Nats {
+                connection,
+                options,
+            } => {
+                f.write_str("NATS CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::Mqtt {
+                connection,
+                options,
+            } => {
+                f.write_str("MQTT CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSourceConnection::Iceberg {
+                catalog_connection,
+                options,
+            } => {
+                f.write_str("ICEBERG CATALOG CONNECTION ");
+                f.write_node(catalog_connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1706,6 +2403,19 @@ pub enum CreateSinkConnection<T: AstInfo> {
         key: Option<SinkKey>,
         options: Vec<IcebergSinkConfigOption<T>>,
     },
+    Postgres {
+        connection: T::ItemName,
+        options: Vec<PostgresSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    Http {
+        options: Vec<HttpSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    S3 {
+        connection: T::ItemName,
+        options: Vec<S3SinkConfigOption<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1755,6 +2465,44 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::Http { options, key } => {
+                f.write_str("HTTP (");
+                f.write_node(&display::comma_separated(options));
+                f.write_str(")");
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::S3 {
+                connection,
+                options,
+            } => {
+                f.write_str("S3 CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
         }
     }
 }
@@ -1880,6 +2628,9 @@ impl_display!(KeyConstraint);
 pub enum CreateSourceOptionName {
     TimestampInterval,
     RetainHistory,
+    ColumnPrivileges,
+    RowLevelSecurity,
+    RowLevelSecurityPolicies,
 }
 
 impl AstDisplay for CreateSourceOptionName {
@@ -1887,6 +2638,9 @@ impl AstDisplay for CreateSourceOptionName {
         f.write_str(match self {
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
             CreateSourceOptionName::RetainHistory => "RETAIN HISTORY",
+            CreateSourceOptionName::ColumnPrivileges => "COLUMN PRIVILEGES",
+            CreateSourceOptionName::RowLevelSecurity => "ROW LEVEL SECURITY",
+            CreateSourceOptionName::RowLevelSecurityPolicies => "ROW LEVEL SECURITY POLICIES",
         })
     }
 }
@@ -1900,9 +2654,11 @@ impl WithOptionName for CreateSourceOptionName {
     /// on the conservative side and return `true`.
     fn redact_value(&self) -> bool {
         match self {
-            CreateSourceOptionName::TimestampInterval | CreateSourceOptionName::RetainHistory => {
-                false
-            }
+            CreateSourceOptionName::TimestampInterval
+            | CreateSourceOptionName::RetainHistory
+            | CreateSourceOptionName::ColumnPrivileges
+            | CreateSourceOptionName::RowLevelSecurity
+            | CreateSourceOptionName::RowLevelSecurityPolicies => false,
         }
     }
 }
@@ -64,11 +64,15 @@ pub enum Statement<T: AstInfo> {
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
     CreateNetworkPolicy(CreateNetworkPolicyStatement<T>),
+    CreateSnapshot(CreateSnapshotStatement<T>),
+    CreateNotification(CreateNotificationStatement<T>),
+    CreatePolicy(CreatePolicyStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterRetainHistory(AlterRetainHistoryStatement<T>),
+    AlterRowLevelSecurity(AlterRowLevelSecurityStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
@@ -85,6 +89,7 @@ pub enum Statement<T: AstInfo> {
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropPolicy(DropPolicyStatement<T>),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -106,6 +111,8 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     ExecuteUnitTest(ExecuteUnitTestStatement<T>),
     Deallocate(DeallocateStatement),
+    Listen(ListenStatement),
+    Unlisten(UnlistenStatement),
     Raise(RaiseStatement),
     GrantRole(GrantRoleStatement<T>),
     RevokeRole(RevokeRoleStatement<T>),
@@ -143,11 +150,15 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::CreateNetworkPolicy(stmt) => f.write_node(stmt),
+            Statement::CreateSnapshot(stmt) => f.write_node(stmt),
+            Statement::CreateNotification(stmt) => f.write_node(stmt),
+            Statement::CreatePolicy(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
             Statement::AlterNetworkPolicy(stmt) => f.write_node(stmt),
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterRetainHistory(stmt) => f.write_node(stmt),
+            Statement::AlterRowLevelSecurity(stmt) => f.write_node(stmt),
             Statement::AlterObjectSwap(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
@@ -164,6 +175,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropPolicy(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -185,6 +197,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::ExecuteUnitTest(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
             Statement::GrantRole(stmt) => f.write_node(stmt),
             Statement::RevokeRole(stmt) => f.write_node(stmt),
@@ -250,9 +264,13 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
         StatementKind::CreateNetworkPolicy => "create_network_policy",
+        StatementKind::CreateSnapshot => "create_snapshot",
+        StatementKind::CreateNotification => "create_notification",
+        StatementKind::CreatePolicy => "create_policy",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterRetainHistory => "alter_retain_history",
+        StatementKind::AlterRowLevelSecurity => "alter_row_level_security",
         StatementKind::AlterObjectSwap => "alter_object_swap",
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterNetworkPolicy => "alter_network_policy",
@@ -273,6 +291,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropPolicy => "drop_policy",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -294,6 +313,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Execute => "execute",
         StatementKind::ExecuteUnitTest => "execute_unit_test",
         StatementKind::Deallocate => "deallocate",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
         StatementKind::Raise => "raise",
         StatementKind::GrantRole => "grant_role",
         StatementKind::RevokeRole => "revoke_role",
@@ -1437,6 +1458,7 @@ pub struct ViewDefinition<T: AstInfo> {
     /// View name
     pub name: UnresolvedItemName,
     pub columns: Vec<Ident>,
+    pub with_options: Vec<ViewOption<T>>,
     pub query: Query<T>,
 }
 
@@ -1450,12 +1472,143 @@ impl<T: AstInfo> AstDisplay for ViewDefinition<T> {
             f.write_str(")");
         }
 
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+
         f.write_str(" AS ");
         f.write_node(&self.query);
     }
 }
 impl_display_t!(ViewDefinition);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ViewOptionName {
+    /// The `COLUMN PRIVILEGES = (...)` option, maintained by `GRANT` and
+    /// `REVOKE` on columns.
+    ColumnPrivileges,
+    /// The `ROW LEVEL SECURITY [=] <bool>` option.
+    RowLevelSecurity,
+    /// The `ROW LEVEL SECURITY POLICIES = (...)` option, maintained by
+    /// `CREATE POLICY` and `DROP POLICY`.
+    RowLevelSecurityPolicies,
+}
+
+impl AstDisplay for ViewOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            ViewOptionName::ColumnPrivileges => "COLUMN PRIVILEGES",
+            ViewOptionName::RowLevelSecurity => "ROW LEVEL SECURITY",
+            ViewOptionName::RowLevelSecurityPolicies => "ROW LEVEL SECURITY POLICIES",
+        })
+    }
+}
+impl_display!(ViewOptionName);
+
+impl WithOptionName for ViewOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            ViewOptionName::ColumnPrivileges
+            | ViewOptionName::RowLevelSecurity
+            | ViewOptionName::RowLevelSecurityPolicies => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViewOption<T: AstInfo> {
+    pub name: ViewOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(ViewOption);
+
+/// A row level security policy, as stored in the `ROW LEVEL SECURITY POLICIES`
+/// option of the relation it applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RowLevelSecurityPolicy<T: AstInfo> {
+    pub name: Ident,
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for RowLevelSecurityPolicy<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(RowLevelSecurityPolicy);
+
+/// A privilege on a column, as stored in the `COLUMN PRIVILEGES` option of the
+/// relation the column belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ColumnPrivilege {
+    pub column: Ident,
+    /// The privilege in the text format of an `mz_aclitem`, which refers to
+    /// roles by ID.
+    pub acl_item: String,
+}
+
+impl AstDisplay for ColumnPrivilege {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.column);
+        f.write_str(" = '");
+        f.write_node(&display::escape_single_quote_string(&self.acl_item));
+        f.write_str("'");
+    }
+}
+impl_display!(ColumnPrivilege);
+
+/// `CREATE POLICY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePolicyStatement<T: AstInfo> {
+    pub name: Ident,
+    pub on: T::ItemName,
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE POLICY ");
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreatePolicyStatement);
+
+/// `DROP POLICY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropPolicyStatement<T: AstInfo> {
+    pub if_exists: bool,
+    pub name: Ident,
+    pub on: T::ItemName,
+}
+
+impl<T: AstInfo> AstDisplay for DropPolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP POLICY ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+    }
+}
+impl_display_t!(DropPolicyStatement);
+
 /// `CREATE VIEW`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateViewStatement<T: AstInfo> {
@@ -1490,6 +1643,7 @@ impl_display_t!(CreateViewStatement);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateMaterializedViewStatement<T: AstInfo> {
     pub if_exists: IfExistsBehavior,
+    pub temporary: bool,
     pub name: UnresolvedItemName,
     pub columns: Vec<Ident>,
     pub replacement_for: Option<T::ItemName>,
@@ -1506,6 +1660,9 @@ impl<T: AstInfo> AstDisplay for CreateMaterializedViewStatement<T> {
         if self.if_exists == IfExistsBehavior::Replace {
             f.write_str(" OR REPLACE");
         }
+        if self.temporary {
+            f.write_str(" TEMPORARY");
+        }
         if self.replacement_for.is_some() {
             f.write_str(" REPLACEMENT");
         }
@@ -1648,6 +1805,14 @@ pub enum TableOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// The `COLUMN PRIVILEGES = (...)` option, maintained by `GRANT` and
+    /// `REVOKE` on columns.
+    ColumnPrivileges,
+    /// The `ROW LEVEL SECURITY [=] <bool>` option.
+    RowLevelSecurity,
+    /// The `ROW LEVEL SECURITY POLICIES = (...)` option, maintained by
+    /// `CREATE POLICY` and `DROP POLICY`.
+    RowLevelSecurityPolicies,
     /// A special option to test that we do redact values.
     RedactedTest,
 }
@@ -1661,6 +1826,15 @@ impl AstDisplay for TableOptionName {
             TableOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            TableOptionName::ColumnPrivileges => {
+                f.write_str("COLUMN PRIVILEGES");
+            }
+            TableOptionName::RowLevelSecurity => {
+                f.write_str("ROW LEVEL SECURITY");
+            }
+            TableOptionName::RowLevelSecurityPolicies => {
+                f.write_str("ROW LEVEL SECURITY POLICIES");
+            }
             TableOptionName::RedactedTest => {
                 f.write_str("REDACTED");
             }
@@ -1679,7 +1853,10 @@ impl WithOptionName for TableOptionName {
             // The value is an arbitrary user expression/literal that may embed
             // sensitive data, so redact it (mirrors `KafkaSinkConfigOptionName`).
             TableOptionName::PartitionBy => true,
-            TableOptionName::RetainHistory => false,
+            TableOptionName::RetainHistory
+            | TableOptionName::ColumnPrivileges
+            | TableOptionName::RowLevelSecurity
+            | TableOptionName::RowLevelSecurityPolicies => false,
             TableOptionName::RedactedTest => true,
         }
     }
@@ -1706,6 +1883,18 @@ pub enum TableFromSourceOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// The `COLUMN PRIVILEGES = (...)` option, maintained by `GRANT` and
+    /// `REVOKE` on columns.
+    ColumnPrivileges,
+    /// The `ROW LEVEL SECURITY [=] <bool>` option.
+    RowLevelSecurity,
+    /// The `ROW LEVEL SECURITY POLICIES = (...)` option, maintained by
+    /// `CREATE POLICY` and `DROP POLICY`.
+    RowLevelSecurityPolicies,
+    /// The `RETRACT ON TRUNCATE [=] <bool>` option.
+    RetractOnTruncate,
+    /// The `SCHEMA CHANGE POLICY [=] <string>` option.
+    SchemaChangePolicy,
 }
 
 impl AstDisplay for TableFromSourceOptionName {
@@ -1716,6 +1905,11 @@ impl AstDisplay for TableFromSourceOptionName {
             TableFromSourceOptionName::Details => "DETAILS",
             TableFromSourceOptionName::PartitionBy => "PARTITION BY",
             TableFromSourceOptionName::RetainHistory => "RETAIN HISTORY",
+            TableFromSourceOptionName::ColumnPrivileges => "COLUMN PRIVILEGES",
+            TableFromSourceOptionName::RowLevelSecurity => "ROW LEVEL SECURITY",
+            TableFromSourceOptionName::RowLevelSecurityPolicies => "ROW LEVEL SECURITY POLICIES",
+            TableFromSourceOptionName::RetractOnTruncate => "RETRACT ON TRUNCATE",
+            TableFromSourceOptionName::SchemaChangePolicy => "SCHEMA CHANGE POLICY",
         })
     }
 }
@@ -1732,7 +1926,12 @@ impl WithOptionName for TableFromSourceOptionName {
             TableFromSourceOptionName::Details
             | TableFromSourceOptionName::TextColumns
             | TableFromSourceOptionName::ExcludeColumns
-            | TableFromSourceOptionName::RetainHistory => false,
+            | TableFromSourceOptionName::RetainHistory
+            | TableFromSourceOptionName::ColumnPrivileges
+            | TableFromSourceOptionName::RowLevelSecurity
+            | TableFromSourceOptionName::RowLevelSecurityPolicies
+            | TableFromSourceOptionName::RetractOnTruncate
+            | TableFromSourceOptionName::SchemaChangePolicy => false,
             // The value is an arbitrary user expression/literal that may embed
             // sensitive data, so redact it (mirrors `KafkaSinkConfigOptionName`).
             TableFromSourceOptionName::PartitionBy => true,
@@ -1862,11 +2061,15 @@ pub struct CreateIndexStatement<T: AstInfo> {
     pub key_parts: Option<Vec<Expr<T>>>,
     pub with_options: Vec<IndexOption<T>>,
     pub if_not_exists: bool,
+    pub temporary: bool,
 }
 
 impl<T: AstInfo> AstDisplay for CreateIndexStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("CREATE ");
+        if self.temporary {
+            f.write_str("TEMPORARY ");
+        }
         if self.key_parts.is_none() {
             f.write_str("DEFAULT ");
         }
@@ -2202,6 +2405,68 @@ impl<T: AstInfo> AstDisplay for CreateSecretStatement<T> {
 }
 impl_display_t!(CreateSecretStatement);
 
+/// `CREATE SNAPSHOT ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateSnapshotStatement<T: AstInfo> {
+    /// Name of the created snapshot.
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    /// The materialized view whose contents the snapshot pins.
+    pub from: T::ItemName,
+    /// The time at which the contents of `from` are pinned.
+    pub as_of: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateSnapshotStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SNAPSHOT ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" FROM ");
+        f.write_node(&self.from);
+        f.write_str(" AS OF ");
+        f.write_node(&self.as_of);
+    }
+}
+impl_display_t!(CreateSnapshotStatement);
+
+/// `CREATE NOTIFICATION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateNotificationStatement<T: AstInfo> {
+    /// Name of the created notification. Its unqualified name is the channel
+    /// on which notifications are delivered.
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    pub in_cluster: Option<T::ClusterName>,
+    /// The relation whose changes raise notifications.
+    pub on: T::ItemName,
+    /// Only changes to rows matching this predicate raise notifications.
+    pub selection: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateNotificationStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE NOTIFICATION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        if let Some(cluster) = &self.in_cluster {
+            f.write_str(" IN CLUSTER ");
+            f.write_node(cluster);
+        }
+        f.write_str(" ON ");
+        f.write_node(&self.on);
+        if let Some(selection) = &self.selection {
+            f.write_str(" WHERE ");
+            f.write_node(selection);
+        }
+    }
+}
+impl_display_t!(CreateNotificationStatement);
+
 /// `CREATE TYPE ..`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateTypeStatement<T: AstInfo> {
@@ -2824,6 +3089,34 @@ impl<T: AstInfo> AstDisplay for AlterRetainHistoryStatement<T> {
 }
 impl_display_t!(AlterRetainHistoryStatement);
 
+/// `ALTER <OBJECT> ... { ENABLE | DISABLE } ROW LEVEL SECURITY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterRowLevelSecurityStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedObjectName,
+    pub enabled: bool,
+}
+
+impl AstDisplay for AlterRowLevelSecurityStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        if self.enabled {
+            f.write_str(" ENABLE");
+        } else {
+            f.write_str(" DISABLE");
+        }
+        f.write_str(" ROW LEVEL SECURITY");
+    }
+}
+impl_display!(AlterRowLevelSecurityStatement);
+
 /// `ALTER <OBJECT> SWAP ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterObjectSwapStatement {
@@ -3326,6 +3619,38 @@ impl AstDisplay for DiscardTarget {
 }
 impl_display!(DiscardTarget);
 
+/// `LISTEN`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement {
+    pub channel: Ident,
+}
+
+impl AstDisplay for ListenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.channel);
+    }
+}
+impl_display!(ListenStatement);
+
+/// `UNLISTEN`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` for `UNLISTEN *`.
+    pub channel: Option<Ident>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.channel {
+            Some(channel) => f.write_node(channel),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
 /// `DROP`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DropObjectsStatement {
@@ -4454,6 +4779,9 @@ pub enum WithOptionValue<T: AstInfo> {
     ClusterAutoScalingStrategyOptionValue(ClusterAutoScalingStrategyOptionValue),
     ClusterAlterStrategy(ClusterAlterOptionValue<T>),
     NetworkPolicyRules(Vec<NetworkPolicyRuleDefinition<T>>),
+    RowLevelSecurityPolicies(Vec<RowLevelSecurityPolicy<T>>),
+    ColumnPrivileges(Vec<ColumnPrivilege>),
+    IcebergPartitionBy(Vec<IcebergPartitionField>),
 }
 
 impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
@@ -4467,7 +4795,8 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 | WithOptionValue::Map(_)
                 | WithOptionValue::RetainHistoryFor(_)
                 | WithOptionValue::Refresh(_)
-                | WithOptionValue::Expr(_) => {
+                | WithOptionValue::Expr(_)
+                | WithOptionValue::RowLevelSecurityPolicies(_) => {
                     // These are redact-aware.
                 }
                 WithOptionValue::ConnectionKafkaBroker(_) => {
@@ -4490,7 +4819,9 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 | WithOptionValue::ClusterScheduleOptionValue(_)
                 | WithOptionValue::ClusterAutoScalingStrategyOptionValue(_)
                 | WithOptionValue::ClusterAlterStrategy(_)
-                | WithOptionValue::NetworkPolicyRules(_) => {
+                | WithOptionValue::NetworkPolicyRules(_)
+                | WithOptionValue::ColumnPrivileges(_)
+                | WithOptionValue::IcebergPartitionBy(_) => {
                     // These do not need redaction.
                 }
             }
@@ -4535,6 +4866,21 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
                 f.write_node(&display::comma_separated(rules));
                 f.write_str(")");
             }
+            WithOptionValue::RowLevelSecurityPolicies(policies) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(policies));
+                f.write_str(")");
+            }
+            WithOptionValue::ColumnPrivileges(privileges) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(privileges));
+                f.write_str(")");
+            }
+            WithOptionValue::IcebergPartitionBy(fields) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(fields));
+                f.write_str(")");
+            }
             WithOptionValue::ConnectionAwsPrivatelink(aws_privatelink) => {
                 f.write_node(aws_privatelink);
             }
@@ -5521,6 +5867,8 @@ impl_display!(Privilege);
 pub enum PrivilegeSpecification {
     All,
     Privileges(Vec<Privilege>),
+    /// Privileges on specific columns, e.g. `SELECT (a, b)`.
+    Columns(Vec<ColumnPrivilegeSpecification>),
 }
 
 impl AstDisplay for PrivilegeSpecification {
@@ -5530,11 +5878,30 @@ impl AstDisplay for PrivilegeSpecification {
             PrivilegeSpecification::Privileges(privileges) => {
                 f.write_node(&display::comma_separated(privileges))
             }
+            PrivilegeSpecification::Columns(privileges) => {
+                f.write_node(&display::comma_separated(privileges))
+            }
         }
     }
 }
 impl_display!(PrivilegeSpecification);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnPrivilegeSpecification {
+    pub privilege: Privilege,
+    pub columns: Vec<Ident>,
+}
+
+impl AstDisplay for ColumnPrivilegeSpecification {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.privilege);
+        f.write_str(" (");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(")");
+    }
+}
+impl_display!(ColumnPrivilegeSpecification);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GrantTargetSpecification<T: AstInfo> {
     Object {
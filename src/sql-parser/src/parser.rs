@@ -505,6 +505,12 @@ impl<'a> Parser<'a> {
                 Token::Keyword(DEALLOCATE) => Ok(self
                     .parse_deallocate()
                     .map_parser_err(StatementKind::Deallocate)?),
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
                 Token::Keyword(RAISE) => {
                     Ok(self.parse_raise().map_parser_err(StatementKind::Raise)?)
                 }
@@ -2103,6 +2109,12 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(SECRET) {
             self.parse_create_secret()
                 .map_parser_err(StatementKind::CreateSecret)
+        } else if self.peek_keyword(SNAPSHOT) {
+            self.parse_create_snapshot()
+                .map_parser_err(StatementKind::CreateSnapshot)
+        } else if self.peek_keyword(NOTIFICATION) {
+            self.parse_create_notification()
+                .map_parser_err(StatementKind::CreateNotification)
         } else if self.peek_keyword(CONNECTION) {
             self.parse_create_connection()
                 .map_parser_err(StatementKind::CreateConnection)
@@ -2122,6 +2134,9 @@ impl<'a> Parser<'a> {
         } else if self.peek_keywords(&[NETWORK, POLICY]) {
             self.parse_create_network_policy()
                 .map_parser_err(StatementKind::CreateNetworkPolicy)
+        } else if self.peek_keyword(POLICY) {
+            self.parse_create_policy()
+                .map_parser_err(StatementKind::CreatePolicy)
         } else {
             let index = self.index;
 
@@ -2133,13 +2148,26 @@ impl<'a> Parser<'a> {
                 self.index = index;
                 self.parse_create_view()
                     .map_parser_err(StatementKind::CreateView)
+            } else if parsed_temporary && self.peek_keywords(&[MATERIALIZED, VIEW]) {
+                self.index = index;
+                self.parse_create_materialized_view()
+                    .map_parser_err(StatementKind::CreateMaterializedView)
+            } else if parsed_temporary
+                && !parsed_or_replace
+                && (self.peek_keyword(INDEX) || self.peek_keywords(&[DEFAULT, INDEX]))
+            {
+                self.index = index;
+                self.parse_create_index()
+                    .map_parser_err(StatementKind::CreateIndex)
             } else {
                 let expected_msg = match (parsed_or_replace, parsed_temporary) {
-                    (true, true) => "VIEW after CREATE OR REPLACE TEMPORARY",
+                    (true, true) => "VIEW, or MATERIALIZED VIEW after CREATE OR REPLACE TEMPORARY",
                     (true, false) => {
                         "[TEMPORARY] VIEW, or MATERIALIZED VIEW after CREATE OR REPLACE"
                     }
-                    (false, true) => "TABLE, or VIEW after CREATE TEMPORARY",
+                    (false, true) => {
+                        "TABLE, VIEW, INDEX, or MATERIALIZED VIEW after CREATE TEMPORARY"
+                    }
                     (false, false) => {
                         "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, [TEMPORARY] TABLE, \
                         SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW \
@@ -2203,8 +2231,13 @@ impl<'a> Parser<'a> {
             };
             Format::Csv { columns, delimiter }
         } else if self.parse_keyword(JSON) {
-            let array = self.parse_keyword(ARRAY);
-            Format::Json { array }
+            if self.parse_keywords(&[USING, CONFLUENT, SCHEMA, REGISTRY]) {
+                let csr_connection = self.parse_csr_connection_json()?;
+                Format::JsonCsr { csr_connection }
+            } else {
+                let array = self.parse_keyword(ARRAY);
+                Format::Json { array }
+            }
         } else if self.parse_keyword(TEXT) {
             Format::Text
         } else if self.parse_keyword(BYTES) {
@@ -2368,21 +2401,31 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_csr_config_option(&mut self) -> Result<CsrConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[AVRO, NULL, KEY, VALUE, DOC])? {
-            AVRO => {
-                let name = match self.expect_one_of_keywords(&[KEY, VALUE])? {
-                    KEY => CsrConfigOptionName::AvroKeyFullname,
-                    VALUE => CsrConfigOptionName::AvroValueFullname,
-                    _ => unreachable!(),
-                };
-                self.expect_keyword(FULLNAME)?;
-                name
-            }
+        let name = match self.expect_one_of_keywords(&[AVRO, NULL, KEY, VALUE, DOC, RECURSION])? {
+            AVRO => match self.expect_one_of_keywords(&[KEY, VALUE, MAX, UNION])? {
+                KEY => {
+                    self.expect_keyword(FULLNAME)?;
+                    CsrConfigOptionName::AvroKeyFullname
+                }
+                VALUE => {
+                    self.expect_keyword(FULLNAME)?;
+                    CsrConfigOptionName::AvroValueFullname
+                }
+                MAX => {
+                    self.expect_keyword(DEPTH)?;
+                    CsrConfigOptionName::AvroMaxDepth
+                }
+                UNION => {
+                    self.expect_keywords(&[AS, RECORD])?;
+                    CsrConfigOptionName::AvroUnionAsRecord
+                }
+                _ => unreachable!(),
+            },
             NULL => {
                 self.expect_keyword(DEFAULTS)?;
                 CsrConfigOptionName::NullDefaults
             }
-            KEY => match self.expect_one_of_keywords(&[DOC, COMPATIBILITY])? {
+            KEY => match self.expect_one_of_keywords(&[DOC, COMPATIBILITY, MESSAGE])? {
                 DOC => {
                     self.expect_keyword(ON)?;
                     let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
@@ -2395,9 +2438,10 @@ impl<'a> Parser<'a> {
                     self.expect_keyword(LEVEL)?;
                     CsrConfigOptionName::KeyCompatibilityLevel
                 }
+                MESSAGE => CsrConfigOptionName::KeyMessage,
                 _ => unreachable!(),
             },
-            VALUE => match self.expect_one_of_keywords(&[DOC, COMPATIBILITY])? {
+            VALUE => match self.expect_one_of_keywords(&[DOC, COMPATIBILITY, MESSAGE])? {
                 DOC => {
                     self.expect_keyword(ON)?;
                     let doc_on_identifier = self.parse_avro_doc_on_option_name()?;
@@ -2410,6 +2454,7 @@ impl<'a> Parser<'a> {
                     self.expect_keyword(LEVEL)?;
                     CsrConfigOptionName::ValueCompatibilityLevel
                 }
+                MESSAGE => CsrConfigOptionName::ValueMessage,
                 _ => unreachable!(),
             },
             DOC => {
@@ -2420,6 +2465,10 @@ impl<'a> Parser<'a> {
                     for_schema: DocOnSchema::All,
                 })
             }
+            RECURSION => {
+                self.expect_keyword(LIMIT)?;
+                CsrConfigOptionName::RecursionLimit
+            }
             _ => unreachable!(),
         };
         Ok(CsrConfigOption {
@@ -2550,6 +2599,29 @@ impl<'a> Parser<'a> {
         Ok(CsrConnectionProtobuf { connection, seed })
     }
 
+    fn parse_csr_connection_json(&mut self) -> Result<CsrConnectionJson<Raw>, ParserError> {
+        let connection = self.parse_csr_connection_reference()?;
+
+        let seed = if self.parse_keyword(SEED) {
+            let key_schema = if self.parse_keyword(KEY) {
+                self.expect_keyword(SCHEMA)?;
+                Some(self.parse_literal_string()?)
+            } else {
+                None
+            };
+            self.expect_keywords(&[VALUE, SCHEMA])?;
+            let value_schema = self.parse_literal_string()?;
+            Some(CsrSeedJson {
+                key_schema,
+                value_schema,
+            })
+        } else {
+            None
+        };
+
+        Ok(CsrConnectionJson { connection, seed })
+    }
+
     fn parse_source_error_policy_option(&mut self) -> Result<SourceErrorPolicy, ParserError> {
         match self.expect_one_of_keywords(&[INLINE])? {
             INLINE => Ok(SourceErrorPolicy::Inline {
@@ -2636,7 +2708,7 @@ impl<'a> Parser<'a> {
             _ => unreachable!(),
         };
         let connection_type = match self.expect_one_of_keywords(&[
-            AWS, GCP, KAFKA, CONFLUENT, POSTGRES, SSH, SQL, MYSQL, ICEBERG,
+            AWS, GCP, KAFKA, CONFLUENT, POSTGRES, SSH, SQL, MYSQL, MONGODB, NATS, MQTT, ICEBERG,
         ])? {
             AWS => {
                 if self.parse_keyword(PRIVATELINK) {
@@ -2664,6 +2736,9 @@ impl<'a> Parser<'a> {
                 CreateConnectionType::SqlServer
             }
             MYSQL => CreateConnectionType::MySql,
+            MONGODB => CreateConnectionType::MongoDb,
+            NATS => CreateConnectionType::Nats,
+            MQTT => CreateConnectionType::Mqtt,
             ICEBERG => {
                 self.expect_keyword(CATALOG)?;
                 CreateConnectionType::IcebergCatalog
@@ -2847,7 +2922,7 @@ impl<'a> Parser<'a> {
     fn parse_kafka_source_config_option(
         &mut self,
     ) -> Result<KafkaSourceConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[GROUP, START, TOPIC])? {
+        let name = match self.expect_one_of_keywords(&[GROUP, START, TOPIC, TOPICS])? {
             GROUP => {
                 self.expect_keywords(&[ID, PREFIX])?;
                 KafkaSourceConfigOptionName::GroupIdPrefix
@@ -2861,10 +2936,13 @@ impl<'a> Parser<'a> {
                 if self.parse_keyword(METADATA) {
                     self.expect_keywords(&[REFRESH, INTERVAL])?;
                     KafkaSourceConfigOptionName::TopicMetadataRefreshInterval
+                } else if self.parse_keyword(PATTERN) {
+                    KafkaSourceConfigOptionName::TopicPattern
                 } else {
                     KafkaSourceConfigOptionName::Topic
                 }
             }
+            TOPICS => KafkaSourceConfigOptionName::Topics,
             _ => unreachable!(),
         };
         Ok(KafkaSourceConfigOption {
@@ -2876,8 +2954,25 @@ impl<'a> Parser<'a> {
     fn parse_iceberg_sink_config_option(
         &mut self,
     ) -> Result<IcebergSinkConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[NAMESPACE, TABLE])? {
+        let name = match self
+            .expect_one_of_keywords(&[COMPACTION, NAMESPACE, PARTITION, SNAPSHOT, TABLE])?
+        {
+            COMPACTION => {
+                self.expect_keyword(INTERVAL)?;
+                IcebergSinkConfigOptionName::CompactionInterval
+            }
             NAMESPACE => IcebergSinkConfigOptionName::Namespace,
+            PARTITION => {
+                self.expect_keyword(BY)?;
+                return Ok(IcebergSinkConfigOption {
+                    name: IcebergSinkConfigOptionName::PartitionBy,
+                    value: self.parse_iceberg_partition_by()?,
+                });
+            }
+            SNAPSHOT => {
+                self.expect_keyword(RETENTION)?;
+                IcebergSinkConfigOptionName::SnapshotRetention
+            }
             TABLE => IcebergSinkConfigOptionName::Table,
             _ => unreachable!(),
         };
@@ -2887,6 +2982,108 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses the value of an Iceberg `PARTITION BY` option, e.g.
+    /// `(region, day(ts), bucket(16, id))`.
+    fn parse_iceberg_partition_by(&mut self) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        self.expect_token(&Token::LParen)?;
+        let fields = self.parse_comma_separated(Parser::parse_iceberg_partition_field)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(WithOptionValue::IcebergPartitionBy(fields)))
+    }
+
+    fn parse_iceberg_partition_field(&mut self) -> Result<IcebergPartitionField, ParserError> {
+        let pos = self.peek_pos();
+        let name = self.parse_identifier()?;
+        if !self.consume_token(&Token::LParen) {
+            return Ok(IcebergPartitionField {
+                transform: IcebergPartitionTransform::Identity,
+                column: name,
+            });
+        }
+        let transform = match name.as_str() {
+            "identity" => IcebergPartitionTransform::Identity,
+            "bucket" | "truncate" => {
+                let n = self.parse_literal_uint()?;
+                self.expect_token(&Token::Comma)?;
+                if name.as_str() == "bucket" {
+                    IcebergPartitionTransform::Bucket(n)
+                } else {
+                    IcebergPartitionTransform::Truncate(n)
+                }
+            }
+            "year" => IcebergPartitionTransform::Year,
+            "month" => IcebergPartitionTransform::Month,
+            "day" => IcebergPartitionTransform::Day,
+            "hour" => IcebergPartitionTransform::Hour,
+            _ => {
+                return parser_err!(self, pos, "unknown Iceberg partition transform: {}", name);
+            }
+        };
+        let column = self.parse_identifier()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(IcebergPartitionField { transform, column })
+    }
+
+    fn parse_postgres_sink_config_option(
+        &mut self,
+    ) -> Result<PostgresSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[SCHEMA, TABLE])? {
+            SCHEMA => PostgresSinkConfigOptionName::Schema,
+            TABLE => PostgresSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(PostgresSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_http_sink_config_option(&mut self) -> Result<HttpSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[URL, HEADERS, SIGNING, BODY, BATCH])? {
+            URL => HttpSinkConfigOptionName::Url,
+            HEADERS => HttpSinkConfigOptionName::Headers,
+            SIGNING => match self.expect_one_of_keywords(&[SECRET, HEADER])? {
+                SECRET => HttpSinkConfigOptionName::SigningSecret,
+                HEADER => HttpSinkConfigOptionName::SigningHeader,
+                _ => unreachable!(),
+            },
+            BODY => {
+                self.expect_keyword(FORMAT)?;
+                HttpSinkConfigOptionName::BodyFormat
+            }
+            BATCH => {
+                self.expect_keyword(SIZE)?;
+                HttpSinkConfigOptionName::BatchSize
+            }
+            _ => unreachable!(),
+        };
+        Ok(HttpSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_s3_sink_config_option(&mut self) -> Result<S3SinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[URI, FORMAT, MAX, ROLL])? {
+            URI => S3SinkConfigOptionName::Uri,
+            FORMAT => S3SinkConfigOptionName::Format,
+            MAX => {
+                self.expect_keywords(&[FILE, SIZE])?;
+                S3SinkConfigOptionName::MaxFileSize
+            }
+            ROLL => {
+                self.expect_keyword(INTERVAL)?;
+                S3SinkConfigOptionName::RollInterval
+            }
+            _ => unreachable!(),
+        };
+        Ok(S3SinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_kafka_sink_config_option(
         &mut self,
     ) -> Result<KafkaSinkConfigOption<Raw>, ParserError> {
@@ -2915,8 +3112,15 @@ impl<'a> Parser<'a> {
                 KafkaSinkConfigOptionName::ProgressGroupIdPrefix
             }
             TOPIC => {
-                match self.parse_one_of_keywords(&[METADATA, PARTITION, REPLICATION, CONFIG]) {
+                match self.parse_one_of_keywords(&[BY, METADATA, PARTITION, REPLICATION, CONFIG]) {
                     None => KafkaSinkConfigOptionName::Topic,
+                    Some(BY) => {
+                        let _ = self.consume_token(&Token::Eq);
+                        return Ok(KafkaSinkConfigOption {
+                            name: KafkaSinkConfigOptionName::TopicBy,
+                            value: Some(WithOptionValue::Expr(self.parse_expr()?)),
+                        });
+                    }
                     Some(METADATA) => {
                         self.expect_keywords(&[REFRESH, INTERVAL])?;
                         KafkaSinkConfigOptionName::TopicMetadataRefreshInterval
@@ -3346,7 +3550,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[TIMESTAMP, RETAIN])? {
+        let name = match self.expect_one_of_keywords(&[TIMESTAMP, RETAIN, COLUMN, ROW])? {
             TIMESTAMP => {
                 self.expect_keyword(INTERVAL)?;
                 CreateSourceOptionName::TimestampInterval
@@ -3355,6 +3559,17 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(HISTORY)?;
                 CreateSourceOptionName::RetainHistory
             }
+            COLUMN => {
+                self.expect_keyword(PRIVILEGES)?;
+                CreateSourceOptionName::ColumnPrivileges
+            }
+            ROW => {
+                if self.parse_row_level_security_option_name()? {
+                    CreateSourceOptionName::RowLevelSecurityPolicies
+                } else {
+                    CreateSourceOptionName::RowLevelSecurity
+                }
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -3370,6 +3585,18 @@ impl<'a> Parser<'a> {
                 value: self.parse_option_retain_history()?,
             });
         }
+        if name == CreateSourceOptionName::RowLevelSecurityPolicies {
+            return Ok(CreateSourceOption {
+                name,
+                value: self.parse_row_level_security_policies()?,
+            });
+        }
+        if name == CreateSourceOptionName::ColumnPrivileges {
+            return Ok(CreateSourceOption {
+                name,
+                value: self.parse_column_privileges()?,
+            });
+        }
         Ok(CreateSourceOption {
             name,
             value: self.parse_optional_option_value()?,
@@ -3518,7 +3745,9 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_create_iceberg_sink(
+    /// Parses the remainder of a sink that takes a `MODE` but no `FORMAT` or
+    /// `ENVELOPE`.
+    fn parse_create_mode_sink(
         &mut self,
         name: Option<UnresolvedItemName>,
         in_cluster: Option<RawClusterName>,
@@ -3554,6 +3783,43 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses the remainder of a sink that takes an `ENVELOPE` but no `FORMAT`.
+    fn parse_create_envelope_sink(
+        &mut self,
+        name: Option<UnresolvedItemName>,
+        in_cluster: Option<RawClusterName>,
+        from: RawItemName,
+        if_not_exists: bool,
+        connection: CreateSinkConnection<Raw>,
+    ) -> Result<CreateSinkStatement<Raw>, ParserError> {
+        let envelope = if self.parse_keyword(ENVELOPE) {
+            Some(self.parse_sink_envelope()?)
+        } else {
+            None
+        };
+
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_create_sink_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        Ok(CreateSinkStatement {
+            name,
+            in_cluster,
+            from,
+            connection,
+            format: None,
+            envelope,
+            mode: None,
+            if_not_exists,
+            with_options,
+        })
+    }
+
     fn parse_create_kafka_sink(
         &mut self,
         name: Option<UnresolvedItemName>,
@@ -3633,8 +3899,11 @@ impl<'a> Parser<'a> {
             conn @ CreateSinkConnection::Kafka { .. } => {
                 self.parse_create_kafka_sink(name, in_cluster, from, if_not_exists, conn)
             }
-            conn @ CreateSinkConnection::Iceberg { .. } => {
-                self.parse_create_iceberg_sink(name, in_cluster, from, if_not_exists, conn)
+            conn @ (CreateSinkConnection::Iceberg { .. } | CreateSinkConnection::S3 { .. }) => {
+                self.parse_create_mode_sink(name, in_cluster, from, if_not_exists, conn)
+            }
+            conn @ (CreateSinkConnection::Postgres { .. } | CreateSinkConnection::Http { .. }) => {
+                self.parse_create_envelope_sink(name, in_cluster, from, if_not_exists, conn)
             }
         }?;
 
@@ -3670,7 +3939,9 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, SQL, MYSQL, LOAD])? {
+        match self.expect_one_of_keywords(&[
+            KAFKA, KINESIS, NATS, MQTT, POSTGRES, SQL, MYSQL, MONGODB, S3, ICEBERG, LOAD,
+        ])? {
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -3724,6 +3995,113 @@ impl<'a> Parser<'a> {
                     options,
                 })
             }
+            MONGODB => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_mongodb_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::MongoDb {
+                    connection,
+                    options,
+                })
+            }
+            S3 => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_s3_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::S3 {
+                    connection,
+                    options,
+                })
+            }
+            KINESIS => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_kinesis_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::Kinesis {
+                    connection,
+                    options,
+                })
+            }
+            NATS => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_nats_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::Nats {
+                    connection,
+                    options,
+                })
+            }
+            MQTT => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_mqtt_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::Mqtt {
+                    connection,
+                    options,
+                })
+            }
+            ICEBERG => {
+                self.expect_keywords(&[CATALOG, CONNECTION])?;
+                let catalog_connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options =
+                        self.parse_comma_separated(Parser::parse_iceberg_connection_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::Iceberg {
+                    catalog_connection,
+                    options,
+                })
+            }
             KAFKA => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -3925,13 +4303,91 @@ impl<'a> Parser<'a> {
                         )
                     });
 
-                Ok(SqlServerConfigOption {
-                    name: SqlServerConfigOptionName::ExcludeColumns,
-                    value,
-                })
+                Ok(SqlServerConfigOption {
+                    name: SqlServerConfigOptionName::ExcludeColumns,
+                    value,
+                })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_mongodb_connection_option(&mut self) -> Result<MongoDbConfigOption<Raw>, ParserError> {
+        self.expect_keyword(DATABASE)?;
+        Ok(MongoDbConfigOption {
+            name: MongoDbConfigOptionName::Database,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_s3_connection_option(&mut self) -> Result<S3ConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[
+            URI, PATTERN, FORMAT, HEADER, DELIMITER, QUOTE, ESCAPE, NULL,
+        ])? {
+            URI => S3ConfigOptionName::Uri,
+            PATTERN => S3ConfigOptionName::Pattern,
+            FORMAT => S3ConfigOptionName::Format,
+            HEADER => S3ConfigOptionName::Header,
+            DELIMITER => S3ConfigOptionName::Delimiter,
+            QUOTE => S3ConfigOptionName::Quote,
+            ESCAPE => S3ConfigOptionName::Escape,
+            NULL => S3ConfigOptionName::Null,
+            _ => unreachable!(),
+        };
+        Ok(S3ConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_kinesis_connection_option(&mut self) -> Result<KinesisConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[STREAM])? {
+            STREAM => KinesisConfigOptionName::Stream,
+            _ => unreachable!(),
+        };
+        Ok(KinesisConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_nats_connection_option(&mut self) -> Result<NatsConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[STREAM, CONSUMER, SUBJECT])? {
+            STREAM => NatsConfigOptionName::Stream,
+            CONSUMER => NatsConfigOptionName::Consumer,
+            SUBJECT => NatsConfigOptionName::Subject,
+            _ => unreachable!(),
+        };
+        Ok(NatsConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_mqtt_connection_option(&mut self) -> Result<MqttConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[TOPIC, SHARED])? {
+            TOPIC => MqttConfigOptionName::Topic,
+            SHARED => {
+                self.expect_keyword(GROUP)?;
+                MqttConfigOptionName::SharedGroup
             }
             _ => unreachable!(),
-        }
+        };
+        Ok(MqttConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_iceberg_connection_option(&mut self) -> Result<IcebergConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[NAMESPACE])? {
+            NAMESPACE => IcebergConfigOptionName::Namespace,
+            _ => unreachable!(),
+        };
+        Ok(IcebergConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
     }
 
     fn parse_load_generator_option(&mut self) -> Result<LoadGeneratorOption<Raw>, ParserError> {
@@ -4089,13 +4545,91 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_postgres_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_postgres_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key,
+        })
+    }
+
+    fn parse_create_http_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(Parser::parse_http_sink_config_option)?;
+        self.expect_token(&Token::RParen)?;
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::Http { options, key })
+    }
+
+    fn parse_create_s3_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_s3_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        Ok(CreateSinkConnection::S3 {
+            connection,
+            options,
+        })
+    }
+
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, ICEBERG])? {
+        match self.expect_one_of_keywords(&[KAFKA, ICEBERG, POSTGRES, HTTP, S3])? {
             KAFKA => self.parse_create_kafka_sink_connection(),
             ICEBERG => {
                 self.expect_keyword(CATALOG)?;
                 self.parse_create_iceberg_sink_connection()
             }
+            POSTGRES => self.parse_create_postgres_sink_connection(),
+            HTTP => self.parse_create_http_sink_connection(),
+            S3 => self.parse_create_s3_sink_connection(),
             _ => unreachable!(),
         }
     }
@@ -4125,17 +4659,46 @@ impl<'a> Parser<'a> {
         // ANSI SQL and Postgres support RECURSIVE here, but we don't.
         let name = self.parse_item_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
-        // Postgres supports WITH options here, but we don't.
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_view_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
         self.expect_keyword(AS)?;
         let query = self.parse_query()?;
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
         Ok(ViewDefinition {
             name,
             columns,
+            with_options,
             query,
         })
     }
 
+    fn parse_view_option(&mut self) -> Result<ViewOption<Raw>, ParserError> {
+        if self.expect_one_of_keywords(&[COLUMN, ROW])? == COLUMN {
+            self.expect_keyword(PRIVILEGES)?;
+            return Ok(ViewOption {
+                name: ViewOptionName::ColumnPrivileges,
+                value: self.parse_column_privileges()?,
+            });
+        }
+        if self.parse_row_level_security_option_name()? {
+            Ok(ViewOption {
+                name: ViewOptionName::RowLevelSecurityPolicies,
+                value: self.parse_row_level_security_policies()?,
+            })
+        } else {
+            Ok(ViewOption {
+                name: ViewOptionName::RowLevelSecurity,
+                value: self.parse_optional_option_value()?,
+            })
+        }
+    }
+
     fn parse_create_materialized_view(&mut self) -> Result<Statement<Raw>, ParserError> {
         let mut if_exists = if self.parse_keyword(OR) {
             self.expect_keyword(REPLACE)?;
@@ -4143,7 +4706,9 @@ impl<'a> Parser<'a> {
         } else {
             IfExistsBehavior::Error
         };
-        let replacement = self.parse_keyword(REPLACEMENT);
+        let temporary = self.parse_one_of_keywords(&[TEMP, TEMPORARY]).is_some();
+        // Temporary materialized views cannot be replacements.
+        let replacement = !temporary && self.parse_keyword(REPLACEMENT);
         self.expect_keywords(&[MATERIALIZED, VIEW])?;
         if if_exists == IfExistsBehavior::Error && self.parse_if_not_exists()? {
             if_exists = IfExistsBehavior::Skip;
@@ -4188,6 +4753,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::CreateMaterializedView(
             CreateMaterializedViewStatement {
                 if_exists,
+                temporary,
                 name,
                 columns,
                 replacement_for,
@@ -4203,7 +4769,8 @@ impl<'a> Parser<'a> {
     fn parse_materialized_view_option_name(
         &mut self,
     ) -> Result<MaterializedViewOptionName, ParserError> {
-        let option = self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH])?;
+        let option =
+            self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH, COLUMN, ROW])?;
         let name = match option {
             ASSERT => {
                 self.expect_keywords(&[NOT, NULL])?;
@@ -4218,6 +4785,17 @@ impl<'a> Parser<'a> {
                 MaterializedViewOptionName::RetainHistory
             }
             REFRESH => MaterializedViewOptionName::Refresh,
+            COLUMN => {
+                self.expect_keyword(PRIVILEGES)?;
+                MaterializedViewOptionName::ColumnPrivileges
+            }
+            ROW => {
+                if self.parse_row_level_security_option_name()? {
+                    MaterializedViewOptionName::RowLevelSecurityPolicies
+                } else {
+                    MaterializedViewOptionName::RowLevelSecurity
+                }
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4232,6 +4810,10 @@ impl<'a> Parser<'a> {
             MaterializedViewOptionName::Refresh => {
                 Some(self.parse_materialized_view_refresh_option_value()?)
             }
+            MaterializedViewOptionName::RowLevelSecurityPolicies => {
+                self.parse_row_level_security_policies()?
+            }
+            MaterializedViewOptionName::ColumnPrivileges => self.parse_column_privileges()?,
             _ => self.parse_optional_option_value()?,
         };
         Ok(MaterializedViewOption { name, value })
@@ -4288,6 +4870,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_index(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let temporary = self.parse_one_of_keywords(&[TEMP, TEMPORARY]).is_some();
         let default_index = self.parse_keyword(DEFAULT);
         self.expect_keyword(INDEX)?;
 
@@ -4346,6 +4929,7 @@ impl<'a> Parser<'a> {
             key_parts,
             with_options,
             if_not_exists,
+            temporary,
         }))
     }
 
@@ -4355,7 +4939,7 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(REDACTED) {
             return Ok(TableOptionName::RedactedTest);
         }
-        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN])? {
+        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN, COLUMN, ROW])? {
             PARTITION => {
                 self.expect_keyword(BY)?;
                 TableOptionName::PartitionBy
@@ -4364,6 +4948,17 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(HISTORY)?;
                 TableOptionName::RetainHistory
             }
+            COLUMN => {
+                self.expect_keyword(PRIVILEGES)?;
+                TableOptionName::ColumnPrivileges
+            }
+            ROW => {
+                if self.parse_row_level_security_option_name()? {
+                    TableOptionName::RowLevelSecurityPolicies
+                } else {
+                    TableOptionName::RowLevelSecurity
+                }
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4374,6 +4969,9 @@ impl<'a> Parser<'a> {
         let value = match name {
             TableOptionName::PartitionBy => self.parse_optional_option_value(),
             TableOptionName::RetainHistory => self.parse_option_retain_history(),
+            TableOptionName::ColumnPrivileges => self.parse_column_privileges(),
+            TableOptionName::RowLevelSecurity => self.parse_optional_option_value(),
+            TableOptionName::RowLevelSecurityPolicies => self.parse_row_level_security_policies(),
             TableOptionName::RedactedTest => self.parse_optional_option_value(),
         }?;
         Ok(TableOption { name, value })
@@ -4500,6 +5098,112 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_snapshot(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(SNAPSHOT)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_keyword(FROM)?;
+        let from = self.parse_raw_name()?;
+        self.expect_keywords(&[AS, OF])?;
+        let as_of = self.parse_expr()?;
+        Ok(Statement::CreateSnapshot(CreateSnapshotStatement {
+            name,
+            if_not_exists,
+            from,
+            as_of,
+        }))
+    }
+
+    fn parse_create_notification(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(NOTIFICATION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        let in_cluster = self.parse_optional_in_cluster()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_raw_name()?;
+        let selection = if self.parse_keyword(WHERE) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(Statement::CreateNotification(CreateNotificationStatement {
+            name,
+            if_not_exists,
+            in_cluster,
+            on,
+            selection,
+        }))
+    }
+
+    fn parse_create_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(POLICY)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_raw_name()?;
+        let using = self.parse_row_level_security_policy_using()?;
+        Ok(Statement::CreatePolicy(CreatePolicyStatement {
+            name,
+            on,
+            using,
+        }))
+    }
+
+    fn parse_row_level_security_policy_using(&mut self) -> Result<Expr<Raw>, ParserError> {
+        self.expect_keyword(USING)?;
+        self.expect_token(&Token::LParen)?;
+        let using = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(using)
+    }
+
+    /// Parses the value of a `ROW LEVEL SECURITY POLICIES` option, e.g.
+    /// `(p1 USING (a = 1), p2 USING (b = 2))`.
+    fn parse_row_level_security_policies(
+        &mut self,
+    ) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        self.expect_token(&Token::LParen)?;
+        let policies = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let policies = self.parse_comma_separated(|parser| {
+                let name = parser.parse_identifier()?;
+                let using = parser.parse_row_level_security_policy_using()?;
+                Ok(RowLevelSecurityPolicy { name, using })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            policies
+        };
+        Ok(Some(WithOptionValue::RowLevelSecurityPolicies(policies)))
+    }
+
+    /// Parses the name of a `ROW LEVEL SECURITY [POLICIES]` option, after the
+    /// leading `ROW` keyword. Returns whether the name refers to the policies.
+    fn parse_row_level_security_option_name(&mut self) -> Result<bool, ParserError> {
+        self.expect_keywords(&[LEVEL, SECURITY])?;
+        Ok(self.parse_keyword(POLICIES))
+    }
+
+    /// Parses the value of a `COLUMN PRIVILEGES` option, e.g.
+    /// `(a = 'u2=r/u1', b = 'p=r/u1')`.
+    fn parse_column_privileges(&mut self) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        self.expect_token(&Token::LParen)?;
+        let privileges = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let privileges = self.parse_comma_separated(|parser| {
+                let column = parser.parse_identifier()?;
+                parser.expect_token(&Token::Eq)?;
+                let acl_item = parser.parse_literal_string()?;
+                Ok(ColumnPrivilege { column, acl_item })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            privileges
+        };
+        Ok(Some(WithOptionValue::ColumnPrivileges(privileges)))
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
@@ -4906,9 +5610,19 @@ impl<'a> Parser<'a> {
     fn parse_source_include_metadata(&mut self) -> Result<Vec<SourceIncludeMetadata>, ParserError> {
         if self.parse_keyword(INCLUDE) {
             self.parse_comma_separated(|parser| {
-                let metadata = match parser
-                    .expect_one_of_keywords(&[KEY, TIMESTAMP, PARTITION, OFFSET, HEADERS, HEADER])?
-                {
+                let metadata = match parser.expect_one_of_keywords(&[
+                    KEY,
+                    TIMESTAMP,
+                    PARTITION,
+                    TOPIC,
+                    OFFSET,
+                    HEADERS,
+                    HEADER,
+                    COMMIT,
+                    LSN,
+                    GTID,
+                    TRANSACTION,
+                ])? {
                     KEY => SourceIncludeMetadata::Key {
                         alias: parser.parse_alias()?,
                     },
@@ -4918,6 +5632,9 @@ impl<'a> Parser<'a> {
                     PARTITION => SourceIncludeMetadata::Partition {
                         alias: parser.parse_alias()?,
                     },
+                    TOPIC => SourceIncludeMetadata::Topic {
+                        alias: parser.parse_alias()?,
+                    },
                     OFFSET => SourceIncludeMetadata::Offset {
                         alias: parser.parse_alias()?,
                     },
@@ -4935,6 +5652,24 @@ impl<'a> Parser<'a> {
                             use_bytes,
                         }
                     }
+                    COMMIT => {
+                        parser.expect_keyword(TIMESTAMP)?;
+                        SourceIncludeMetadata::CommitTimestamp {
+                            alias: parser.parse_alias()?,
+                        }
+                    }
+                    LSN => SourceIncludeMetadata::Lsn {
+                        alias: parser.parse_alias()?,
+                    },
+                    GTID => SourceIncludeMetadata::Gtid {
+                        alias: parser.parse_alias()?,
+                    },
+                    TRANSACTION => {
+                        parser.expect_keyword(ID)?;
+                        SourceIncludeMetadata::TransactionId {
+                            alias: parser.parse_alias()?,
+                        }
+                    }
                     _ => unreachable!("only explicitly allowed items can be parsed"),
                 };
                 Ok(metadata)
@@ -4959,12 +5694,27 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(POLICY) {
+            self.parse_drop_policy()
+                .map_parser_err(StatementKind::DropPolicy)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
         }
     }
 
+    fn parse_drop_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let on = self.parse_raw_name()?;
+        Ok(Statement::DropPolicy(DropPolicyStatement {
+            if_exists,
+            name,
+            on,
+        }))
+    }
+
     fn parse_drop_objects(&mut self) -> Result<Statement<Raw>, ParserError> {
         let object_type = self.expect_object_type()?;
         let if_exists = self.parse_if_exists()?;
@@ -5294,9 +6044,9 @@ impl<'a> Parser<'a> {
     fn parse_table_from_source_option(
         &mut self,
     ) -> Result<TableFromSourceOption<Raw>, ParserError> {
-        let option = match self
-            .expect_one_of_keywords(&[TEXT, EXCLUDE, IGNORE, DETAILS, PARTITION, RETAIN])?
-        {
+        let option = match self.expect_one_of_keywords(&[
+            TEXT, EXCLUDE, IGNORE, DETAILS, PARTITION, RETAIN, COLUMN, ROW, RETRACT, SCHEMA,
+        ])? {
             ref keyword @ (TEXT | EXCLUDE) => {
                 self.expect_keyword(COLUMNS)?;
 
@@ -5354,6 +6104,40 @@ impl<'a> Parser<'a> {
                     value: self.parse_option_retain_history()?,
                 }
             }
+            COLUMN => {
+                self.expect_keyword(PRIVILEGES)?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::ColumnPrivileges,
+                    value: self.parse_column_privileges()?,
+                }
+            }
+            ROW => {
+                if self.parse_row_level_security_option_name()? {
+                    TableFromSourceOption {
+                        name: TableFromSourceOptionName::RowLevelSecurityPolicies,
+                        value: self.parse_row_level_security_policies()?,
+                    }
+                } else {
+                    TableFromSourceOption {
+                        name: TableFromSourceOptionName::RowLevelSecurity,
+                        value: self.parse_optional_option_value()?,
+                    }
+                }
+            }
+            RETRACT => {
+                self.expect_keywords(&[ON, TRUNCATE])?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::RetractOnTruncate,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
+            SCHEMA => {
+                self.expect_keywords(&[CHANGE, POLICY])?;
+                TableFromSourceOption {
+                    name: TableFromSourceOptionName::SchemaChangePolicy,
+                    value: self.parse_optional_option_value()?,
+                }
+            }
             _ => unreachable!(),
         };
         Ok(option)
@@ -5984,7 +6768,9 @@ impl<'a> Parser<'a> {
 
         Ok(
             match self
-                .expect_one_of_keywords(&[ADD, DROP, RESET, SET, RENAME, OWNER, REFRESH])
+                .expect_one_of_keywords(&[
+                    ADD, DROP, RESET, SET, RENAME, OWNER, REFRESH, ENABLE, DISABLE,
+                ])
                 .map_no_statement_parser_err()?
             {
                 ADD => {
@@ -6110,6 +6896,16 @@ impl<'a> Parser<'a> {
                         new_owner,
                     })
                 }
+                action @ (ENABLE | DISABLE) => {
+                    return self
+                        .parse_alter_row_level_security(
+                            ObjectType::Source,
+                            if_exists,
+                            source_name,
+                            action == ENABLE,
+                        )
+                        .map_parser_err(StatementKind::AlterRowLevelSecurity);
+                }
                 REFRESH => {
                     self.expect_keyword(REFERENCES)
                         .map_parser_err(StatementKind::AlterSource)?;
@@ -6124,6 +6920,24 @@ impl<'a> Parser<'a> {
         )
     }
 
+    fn parse_alter_row_level_security(
+        &mut self,
+        object_type: ObjectType,
+        if_exists: bool,
+        name: UnresolvedItemName,
+        enabled: bool,
+    ) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[ROW, LEVEL, SECURITY])?;
+        Ok(Statement::AlterRowLevelSecurity(
+            AlterRowLevelSecurityStatement {
+                object_type,
+                if_exists,
+                name: UnresolvedObjectName::Item(name),
+                enabled,
+            },
+        ))
+    }
+
     fn parse_alter_source_add_subsource_option(
         &mut self,
     ) -> Result<AlterSourceAddSubsourceOption<Raw>, ParserError> {
@@ -6566,7 +7380,7 @@ impl<'a> Parser<'a> {
             GrantTargetAllSpecification::All
         };
         let is_grant = self.expect_one_of_keywords(&[GRANT, REVOKE])? == GRANT;
-        let privileges = self.parse_privilege_specification().ok_or_else(|| {
+        let privileges = self.parse_privilege_specification()?.ok_or_else(|| {
             self.expected::<_, PrivilegeSpecification>(
                 self.peek_pos(),
                 "ALL or INSERT or SELECT or UPDATE or DELETE or USAGE or CREATE",
@@ -6614,9 +7428,9 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let keywords: &[_] = match object_type {
-            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD],
-            ObjectType::MaterializedView => &[SET, RENAME, OWNER, RESET, APPLY],
-            ObjectType::View => &[SET, RENAME, OWNER, RESET],
+            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD, ENABLE, DISABLE],
+            ObjectType::MaterializedView => &[SET, RENAME, OWNER, RESET, APPLY, ENABLE, DISABLE],
+            ObjectType::View => &[SET, RENAME, OWNER, RESET, ENABLE, DISABLE],
             ObjectType::Source
             | ObjectType::Sink
             | ObjectType::Index
@@ -6723,6 +7537,9 @@ impl<'a> Parser<'a> {
                     },
                 ))
             }
+            ENABLE | DISABLE => self
+                .parse_alter_row_level_security(object_type, if_exists, name, action == ENABLE)
+                .map_parser_err(StatementKind::AlterRowLevelSecurity),
             APPLY => {
                 assert_eq!(
                     object_type,
@@ -9628,6 +10445,24 @@ impl<'a> Parser<'a> {
         Ok(Statement::Deallocate(DeallocateStatement { name }))
     }
 
+    /// Parse a `LISTEN` statement, assuming that the `LISTEN` token has
+    /// already been consumed.
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        Ok(Statement::Listen(ListenStatement { channel }))
+    }
+
+    /// Parse an `UNLISTEN` statement, assuming that the `UNLISTEN` token has
+    /// already been consumed.
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { channel }))
+    }
+
     /// Parse a `FETCH` statement, assuming that the `FETCH` token
     /// has already been consumed.
     fn parse_fetch(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -9683,7 +10518,10 @@ impl<'a> Parser<'a> {
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::GrantPrivileges)?
+        {
             Some(privileges) => self
                 .parse_grant_privilege(privileges)
                 .map_parser_err(StatementKind::GrantPrivileges),
@@ -9725,7 +10563,10 @@ impl<'a> Parser<'a> {
     /// Parse a `REVOKE` statement, assuming that the `REVOKE` token
     /// has already been consumed.
     fn parse_revoke(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::RevokePrivileges)?
+        {
             Some(privileges) => self
                 .parse_revoke_privilege(privileges)
                 .map_parser_err(StatementKind::RevokePrivileges),
@@ -10241,25 +11082,39 @@ impl<'a> Parser<'a> {
         )
     }
 
-    /// Parse one or more privileges separated by a ','.
-    fn parse_privilege_specification(&mut self) -> Option<PrivilegeSpecification> {
+    /// Parse one or more privileges separated by a ','. Each privilege may be
+    /// followed by the parenthesized list of columns that it applies to.
+    fn parse_privilege_specification(
+        &mut self,
+    ) -> Result<Option<PrivilegeSpecification>, ParserError> {
         if self.parse_keyword(ALL) {
             let _ = self.parse_keyword(PRIVILEGES);
-            return Some(PrivilegeSpecification::All);
+            return Ok(Some(PrivilegeSpecification::All));
         }
 
         let mut privileges = Vec::new();
+        let mut column_privileges = Vec::new();
         while let Some(privilege) = self.parse_privilege() {
-            privileges.push(privilege);
+            if self.peek_token() == Some(Token::LParen) {
+                let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                column_privileges.push(ColumnPrivilegeSpecification { privilege, columns });
+            } else {
+                privileges.push(privilege);
+            }
             if !self.consume_token(&Token::Comma) {
                 break;
             }
         }
 
-        if privileges.is_empty() {
-            None
-        } else {
-            Some(PrivilegeSpecification::Privileges(privileges))
+        match (privileges.is_empty(), column_privileges.is_empty()) {
+            (true, true) => Ok(None),
+            (false, true) => Ok(Some(PrivilegeSpecification::Privileges(privileges))),
+            (true, false) => Ok(Some(PrivilegeSpecification::Columns(column_privileges))),
+            (false, false) => parser_err!(
+                self,
+                self.peek_prev_pos(),
+                "cannot mix privileges on columns with privileges on whole objects"
+            ),
         }
     }
 
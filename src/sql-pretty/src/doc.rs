@@ -875,6 +875,13 @@ impl Pretty {
                 ")",
             ));
         }
+        if !v.with_options.is_empty() {
+            docs.push(bracket(
+                "WITH (",
+                comma_separate(|wo| self.doc_display_pass(wo), &v.with_options),
+                ")",
+            ));
+        }
         docs.push(nest_title("AS", self.doc_query(&v.query)));
         RcDoc::intersperse(docs, Doc::line()).group()
     }
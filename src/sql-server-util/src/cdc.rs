@@ -98,6 +98,8 @@ pub struct CdcStream<'a, M: SqlServerCdcMetrics> {
     /// we'll wait this duration for SQL Server to report an [`Lsn`] and thus indicate CDC is
     /// ready to go.
     max_lsn_wait: Duration,
+    /// Whether changes should include the commit time and ID of their transaction.
+    transaction_metadata: bool,
     /// Metrics.
     metrics: M,
 }
@@ -113,6 +115,7 @@ impl<'a, M: SqlServerCdcMetrics> CdcStream<'a, M> {
             capture_instances,
             poll_interval: Duration::from_secs(1),
             max_lsn_wait: Duration::from_secs(10),
+            transaction_metadata: false,
             metrics,
         }
     }
@@ -150,6 +153,15 @@ impl<'a, M: SqlServerCdcMetrics> CdcStream<'a, M> {
         self
     }
 
+    /// Whether each change should include the commit time and ID of its transaction, see
+    /// [`crate::inspect::get_changes_asc`].
+    ///
+    /// Default is false.
+    pub fn transaction_metadata(mut self, enabled: bool) -> Self {
+        self.transaction_metadata = enabled;
+        self
+    }
+
     /// Takes a snapshot of the upstream table that the specified `table` represents.
     pub async fn snapshot<'b>(
         &'b mut self,
@@ -306,6 +318,7 @@ impl<'a, M: SqlServerCdcMetrics> CdcStream<'a, M> {
                                 *instance_lsn,
                                 db_max_lsn,
                                 RowFilterOption::AllUpdateOld,
+                                self.transaction_metadata,
                             )
                             // TODO(sql_server3): Make this chunk size configurable.
                             .ready_chunks(64);
@@ -230,20 +230,41 @@ fn parse_lsn(result: &[tiberius::Row]) -> Result<Lsn, SqlServerError> {
     }
 }
 
+/// Name of the column [`get_changes_asc`] returns a change's commit time in, if requested.
+pub const COMMIT_TIME_COLUMN: &str = "__$mz_commit_time";
+/// Name of the column [`get_changes_asc`] returns a change's transaction ID in, if requested.
+pub const TRANSACTION_ID_COLUMN: &str = "__$mz_tran_id";
+
 /// Queries the specified capture instance and returns all changes from
 /// `[start_lsn, end_lsn)`, ordered by `start_lsn` in an ascending fashion.
+///
+/// If `transaction_metadata` is set, each change additionally includes the commit time and ID of
+/// its transaction from `cdc.lsn_time_mapping`, in the [`COMMIT_TIME_COLUMN`] and
+/// [`TRANSACTION_ID_COLUMN`] columns.
 pub fn get_changes_asc(
     client: &mut Client,
     capture_instance: &str,
     start_lsn: Lsn,
     end_lsn: Lsn,
     filter: RowFilterOption,
+    transaction_metadata: bool,
 ) -> impl Stream<Item = Result<tiberius::Row, SqlServerError>> + Send {
     const START_LSN_COLUMN: &str = "__$start_lsn";
-    let query = format!(
-        "SELECT * FROM cdc.{function}(@P1, @P2, N'{filter}') ORDER BY {START_LSN_COLUMN} ASC;",
-        function = quote_identifier(&format!("fn_cdc_get_all_changes_{capture_instance}"))
-    );
+    let function = quote_identifier(&format!("fn_cdc_get_all_changes_{capture_instance}"));
+    let query = if transaction_metadata {
+        format!(
+            "SELECT c.*, m.tran_end_time AS {commit_time}, m.tran_id AS {tran_id} \
+            FROM cdc.{function}(@P1, @P2, N'{filter}') AS c \
+            LEFT JOIN cdc.lsn_time_mapping AS m ON m.start_lsn = c.{START_LSN_COLUMN} \
+            ORDER BY c.{START_LSN_COLUMN} ASC;",
+            commit_time = quote_identifier(COMMIT_TIME_COLUMN),
+            tran_id = quote_identifier(TRANSACTION_ID_COLUMN),
+        )
+    } else {
+        format!(
+            "SELECT * FROM cdc.{function}(@P1, @P2, N'{filter}') ORDER BY {START_LSN_COLUMN} ASC;"
+        )
+    };
     client.query_streaming(
         query,
         &[
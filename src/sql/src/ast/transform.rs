@@ -19,9 +19,10 @@ use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
     AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawDataType, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    CreateNotificationStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSnapshotStatement, CreateSourceStatement, CreateSubsourceStatement, CreateTableStatement,
+    CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query, Raw, RawDataType,
+    RawItemName, Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -47,7 +48,9 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTableFromSource(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
-        | stmt @ Statement::CreateSecret(_) => {
+        | stmt @ Statement::CreateSecret(_)
+        | stmt @ Statement::CreateSnapshot(_)
+        | stmt @ Statement::CreateNotification(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
                 cur_schema,
@@ -173,6 +176,8 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         | Statement::CreateTable(CreateTableStatement { name, .. })
         | Statement::CreateTableFromSource(CreateTableFromSourceStatement { name, .. })
         | Statement::CreateSecret(CreateSecretStatement { name, .. })
+        | Statement::CreateSnapshot(CreateSnapshotStatement { name, .. })
+        | Statement::CreateNotification(CreateNotificationStatement { name, .. })
         | Statement::CreateConnection(CreateConnectionStatement { name, .. })
         | Statement::CreateWebhookSource(CreateWebhookSourceStatement { name, .. }) => {
             // The last name in an ItemName is the item name. The item name
@@ -224,6 +229,12 @@ pub fn create_stmt_rename_refs(
         Statement::CreateTableFromSource(CreateTableFromSourceStatement { source, .. }) => {
             maybe_update_item_name(source.name_mut());
         }
+        Statement::CreateSnapshot(CreateSnapshotStatement { from, .. }) => {
+            maybe_update_item_name(from.name_mut());
+        }
+        Statement::CreateNotification(CreateNotificationStatement { on, .. }) => {
+            maybe_update_item_name(on.name_mut());
+        }
         Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { query, .. },
             ..
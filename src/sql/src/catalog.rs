@@ -35,6 +35,7 @@ use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, ColumnName, GlobalId, RelationDesc, RelationVersion, RelationVersionSelector,
+    Timestamp,
 };
 use mz_sql_parser::ast::{Expr, QualifiedReplica, UnresolvedItemName};
 use mz_storage_types::connections::inline::{ConnectionResolver, ReferencedConnection};
@@ -361,6 +362,15 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
         false
     }
 
+    /// Returns true if the session's privileges are checked, i.e. if RBAC is
+    /// enabled for the session and the session is not a superuser.
+    ///
+    /// Defaults to false so that non-session catalog implementations are
+    /// unaffected.
+    fn rbac_checks_enabled(&self) -> bool {
+        false
+    }
+
     /// Returns system vars
     fn system_vars(&self) -> &SystemVars;
 
@@ -880,6 +890,9 @@ pub trait CatalogItem {
     /// Reports whether this catalog item is a progress source.
     fn is_progress_source(&self) -> bool;
 
+    /// Reports whether this catalog item is a notification.
+    fn is_notification(&self) -> bool;
+
     /// If this catalog item is a source, it return the IDs of its progress collection.
     fn progress_id(&self) -> Option<CatalogItemId>;
 
@@ -891,6 +904,10 @@ pub trait CatalogItem {
     /// catalog item is a table that accepts writes.
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]>;
 
+    /// Returns the materialized view and the pinned timestamp associated with
+    /// the catalog item, if the catalog item is a snapshot.
+    fn snapshot_details(&self) -> Option<(GlobalId, Timestamp)>;
+
     /// The item this catalog item replaces, if any.
     fn replacement_target(&self) -> Option<CatalogItemId>;
 
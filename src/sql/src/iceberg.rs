@@ -7,12 +7,238 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use crate::names::Aug;
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use iceberg::spec::{PrimitiveType, Schema, Type};
+use mz_ore::str::StrExt;
+use mz_repr::adt::numeric::NumericMaxScale;
+use mz_repr::{ColumnName, RelationDesc, SqlScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
-use mz_sql_parser::ast::{IcebergSinkConfigOption, IcebergSinkConfigOptionName};
+use mz_sql_parser::ast::{
+    IcebergPartitionField, IcebergPartitionTransform, IcebergSinkConfigOption,
+    IcebergSinkConfigOptionName,
+};
+use mz_storage_types::sinks as storage;
+
+use crate::names::Aug;
+use crate::normalize;
+use crate::plan::{PlanError, StatementContext};
+use crate::pure::error::IcebergSourcePurificationError;
 
 generate_extracted_config!(
     IcebergSinkConfigOption,
     (Table, String),
-    (Namespace, String)
+    (Namespace, String),
+    (PartitionBy, Vec<IcebergPartitionField>, Default(vec![])),
+    (CompactionInterval, Duration),
+    (SnapshotRetention, Duration)
 );
+
+/// Plans the `PARTITION BY` option of an Iceberg sink against the
+/// description of the sinked relation.
+///
+/// Each field must name a column of `desc` whose type the transform accepts,
+/// and no column may be partitioned by the same transform twice.
+pub(crate) fn plan_iceberg_partition_by(
+    scx: &StatementContext,
+    fields: Vec<IcebergPartitionField>,
+    desc: &RelationDesc,
+) -> Result<Vec<storage::IcebergPartitionField>, PlanError> {
+    let mut seen = BTreeSet::new();
+    let mut planned = Vec::with_capacity(fields.len());
+    for field in fields {
+        let display = field.to_ast_string_simple();
+        let kind = field.transform.name();
+        let name = normalize::column_name(field.column);
+        let Some((_, typ)) = desc.get_by_name(&name) else {
+            sql_bail!("PARTITION BY column {} does not exist", name.quoted());
+        };
+        let count = |n: u64| match u32::try_from(n) {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(sql_err!(
+                "invalid partition transform {}: width must be between 1 and {}",
+                display,
+                u32::MAX
+            )),
+        };
+        let transform = match field.transform {
+            IcebergPartitionTransform::Identity => storage::IcebergPartitionTransform::Identity,
+            IcebergPartitionTransform::Bucket(n) => {
+                storage::IcebergPartitionTransform::Bucket(count(n)?)
+            }
+            IcebergPartitionTransform::Truncate(n) => {
+                storage::IcebergPartitionTransform::Truncate(count(n)?)
+            }
+            IcebergPartitionTransform::Year => storage::IcebergPartitionTransform::Year,
+            IcebergPartitionTransform::Month => storage::IcebergPartitionTransform::Month,
+            IcebergPartitionTransform::Day => storage::IcebergPartitionTransform::Day,
+            IcebergPartitionTransform::Hour => storage::IcebergPartitionTransform::Hour,
+        };
+        if !transform_accepts(transform, &typ.scalar_type) {
+            sql_bail!(
+                "invalid partition transform {}: column {} has unsupported type {}",
+                display,
+                name.quoted(),
+                scx.humanize_sql_scalar_type(&typ.scalar_type, false)
+            );
+        }
+        // Iceberg names partition fields after the column and transform, so
+        // repeating a pair would produce clashing partition fields.
+        if !seen.insert((name.clone(), kind)) {
+            sql_bail!("PARTITION BY contains {} more than once", display);
+        }
+        planned.push(storage::IcebergPartitionField {
+            column: name.to_string(),
+            transform,
+        });
+    }
+    Ok(planned)
+}
+
+/// Reports whether the Iceberg `transform` can be applied to a column of
+/// `scalar_type`, after the sink's mapping of Materialize types to Iceberg
+/// types.
+fn transform_accepts(
+    transform: storage::IcebergPartitionTransform,
+    scalar_type: &SqlScalarType,
+) -> bool {
+    use storage::IcebergPartitionTransform::*;
+    let is_numeric = matches!(
+        scalar_type,
+        SqlScalarType::Int16
+            | SqlScalarType::Int32
+            | SqlScalarType::Int64
+            | SqlScalarType::UInt16
+            | SqlScalarType::UInt32
+            | SqlScalarType::UInt64
+            | SqlScalarType::MzTimestamp
+            | SqlScalarType::Numeric { .. }
+    );
+    let is_string = matches!(
+        scalar_type,
+        SqlScalarType::String | SqlScalarType::Char { .. } | SqlScalarType::VarChar { .. }
+    );
+    let is_timestamp = matches!(
+        scalar_type,
+        SqlScalarType::Timestamp { .. } | SqlScalarType::TimestampTz { .. }
+    );
+    match transform {
+        Identity => !matches!(
+            scalar_type,
+            SqlScalarType::List { .. }
+                | SqlScalarType::Array(_)
+                | SqlScalarType::Map { .. }
+                | SqlScalarType::Record { .. }
+                | SqlScalarType::Range { .. }
+                | SqlScalarType::Int2Vector
+        ),
+        Bucket(_) => {
+            is_numeric
+                || is_string
+                || is_timestamp
+                || matches!(
+                    scalar_type,
+                    SqlScalarType::Date
+                        | SqlScalarType::Time
+                        | SqlScalarType::Uuid
+                        | SqlScalarType::Bytes
+                )
+        }
+        Truncate(_) => is_numeric || is_string || matches!(scalar_type, SqlScalarType::Bytes),
+        Year | Month | Day => is_timestamp || matches!(scalar_type, SqlScalarType::Date),
+        Hour => is_timestamp,
+    }
+}
+
+/// Converts the schema of an upstream Iceberg table into the [`RelationDesc`]
+/// of a source table that ingests it.
+///
+/// Columns are nullable unless the Iceberg field is `required`, and the
+/// table's identifier fields, if any, become the key of the description.
+pub(crate) fn iceberg_schema_to_relation_desc(
+    schema: &Schema,
+) -> Result<RelationDesc, IcebergSourcePurificationError> {
+    let fields = schema.as_struct().fields();
+    let mut builder = RelationDesc::builder();
+    for field in fields {
+        let ty = iceberg_type_to_scalar_type(&field.name, &field.field_type)?;
+        builder = builder.with_column(field.name.as_str(), ty.nullable(!field.required));
+    }
+    let key: Vec<usize> = schema
+        .identifier_field_ids()
+        .filter_map(|id| fields.iter().position(|field| field.id == id))
+        .collect();
+    if !key.is_empty() {
+        builder = builder.with_key(key);
+    }
+    Ok(builder.finish())
+}
+
+fn iceberg_type_to_scalar_type(
+    column: &str,
+    ty: &Type,
+) -> Result<SqlScalarType, IcebergSourcePurificationError> {
+    let unsupported = || IcebergSourcePurificationError::UnsupportedType {
+        column: column.to_string(),
+        ty: ty.to_string(),
+    };
+    let scalar_type = match ty {
+        Type::Primitive(primitive) => match primitive {
+            PrimitiveType::Boolean => SqlScalarType::Bool,
+            PrimitiveType::Int => SqlScalarType::Int32,
+            PrimitiveType::Long => SqlScalarType::Int64,
+            PrimitiveType::Float => SqlScalarType::Float32,
+            PrimitiveType::Double => SqlScalarType::Float64,
+            PrimitiveType::Decimal { scale, .. } => SqlScalarType::Numeric {
+                max_scale: Some(
+                    NumericMaxScale::try_from(i64::from(*scale)).map_err(|_| unsupported())?,
+                ),
+            },
+            PrimitiveType::Date => SqlScalarType::Date,
+            PrimitiveType::Time => SqlScalarType::Time,
+            PrimitiveType::Timestamp | PrimitiveType::TimestampNs => {
+                SqlScalarType::Timestamp { precision: None }
+            }
+            PrimitiveType::Timestamptz => SqlScalarType::TimestampTz { precision: None },
+            PrimitiveType::TimestamptzNs => return Err(unsupported()),
+            PrimitiveType::String => SqlScalarType::String,
+            PrimitiveType::Uuid => SqlScalarType::Uuid,
+            PrimitiveType::Fixed(_) | PrimitiveType::Binary => SqlScalarType::Bytes,
+        },
+        Type::List(list) => SqlScalarType::List {
+            element_type: Box::new(iceberg_type_to_scalar_type(
+                column,
+                &list.element_field.field_type,
+            )?),
+            custom_id: None,
+        },
+        Type::Struct(fields) => SqlScalarType::Record {
+            fields: fields
+                .fields()
+                .iter()
+                .map(|field| {
+                    let ty = iceberg_type_to_scalar_type(column, &field.field_type)?;
+                    Ok((
+                        ColumnName::from(field.name.as_str()),
+                        ty.nullable(!field.required),
+                    ))
+                })
+                .collect::<Result<_, IcebergSourcePurificationError>>()?,
+            custom_id: None,
+        },
+        Type::Map(map) => {
+            if *map.key_field.field_type != Type::Primitive(PrimitiveType::String) {
+                return Err(unsupported());
+            }
+            SqlScalarType::Map {
+                value_type: Box::new(iceberg_type_to_scalar_type(
+                    column,
+                    &map.value_field.field_type,
+                )?),
+                custom_id: None,
+            }
+        }
+    };
+    Ok(scalar_type)
+}
@@ -20,6 +20,7 @@ use mz_sql_parser::ast::{
     KafkaSourceConfigOptionName,
 };
 use mz_storage_types::sinks::KafkaSinkCompressionType;
+use mz_storage_types::sources::kafka::{KafkaTopicKeys, KafkaTopicSet};
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{Offset, TopicPartitionList};
 use tokio::time::Duration;
@@ -35,6 +36,8 @@ generate_extracted_config!(
     KafkaSourceConfigOption,
     (GroupIdPrefix, String),
     (Topic, String),
+    (Topics, Vec<String>),
+    (TopicPattern, String),
     (
         TopicMetadataRefreshInterval,
         Duration,
@@ -56,6 +59,7 @@ generate_extracted_config!(
     (TransactionalIdPrefix, String),
     (LegacyIds, bool),
     (Topic, String),
+    (TopicBy, Expr<Aug>),
     (TopicConfig, BTreeMap<String, String>),
     (
         TopicMetadataRefreshInterval,
@@ -267,3 +271,54 @@ where
     })
     .await
 }
+
+/// Validates that `topic_set` names at least one existing topic, and that the source can tell the
+/// progress of its topics apart, and returns the topic whose schema the source is planned against:
+/// the first listed topic, or the first matching topic in name order.
+pub async fn first_topic_in_set<C>(
+    consumer: Arc<BaseConsumer<C>>,
+    topic_set: &KafkaTopicSet,
+    fetch_metadata_timeout: Duration,
+) -> Result<String, PlanError>
+where
+    C: ConsumerContext + 'static,
+{
+    match topic_set {
+        KafkaTopicSet::List(topics) => {
+            let mut topic_keys = KafkaTopicKeys::default();
+            for topic in topics {
+                ensure_topic_exists(Arc::clone(&consumer), topic, fetch_metadata_timeout).await?;
+                topic_keys.insert(topic).map_err(|e| sql_err!("{e}"))?;
+            }
+            topics
+                .first()
+                .cloned()
+                .ok_or_else(|| sql_err!("TOPICS must list at least one topic"))
+        }
+        KafkaTopicSet::Pattern(pattern) => {
+            let matches = topic_set
+                .matcher()
+                .map_err(|e| sql_err!("invalid TOPIC PATTERN: {e}"))?;
+            task::spawn_blocking(|| format!("kafka_match_topics:{pattern}"), {
+                let pattern = pattern.clone();
+                move || {
+                    let topics = mz_kafka_util::client::get_matching_partitions(
+                        consumer.as_ref().client(),
+                        matches,
+                        fetch_metadata_timeout,
+                    )
+                    .map_err(|e| sql_err!("{}", e))?;
+                    let mut topic_keys = KafkaTopicKeys::default();
+                    for topic in topics.keys() {
+                        topic_keys.insert(topic).map_err(|e| sql_err!("{e}"))?;
+                    }
+                    match topics.into_keys().next() {
+                        Some(topic) => Ok(topic),
+                        None => sql_bail!("no topics match TOPIC PATTERN '{}'", pattern),
+                    }
+                }
+            })
+            .await
+        }
+    }
+}
@@ -2078,6 +2078,14 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                     .map(|r| self.fold_network_policy_rule_definition(r))
                     .collect(),
             ),
+            RowLevelSecurityPolicies(policies) => RowLevelSecurityPolicies(
+                policies
+                    .into_iter()
+                    .map(|p| self.fold_row_level_security_policy(p))
+                    .collect(),
+            ),
+            ColumnPrivileges(privileges) => ColumnPrivileges(privileges),
+            IcebergPartitionBy(fields) => IcebergPartitionBy(fields),
         }
     }
 
@@ -22,7 +22,8 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
+    CreateNotificationStatement, CreateSecretStatement, CreateSinkStatement,
+    CreateSnapshotStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableFromSourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior,
     MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns, UnresolvedItemName,
@@ -383,6 +384,7 @@ pub fn create_statement(
                     name,
                     query,
                     columns: _,
+                    with_options: _,
                 },
         }) => {
             *name = if *temporary {
@@ -402,6 +404,7 @@ pub fn create_statement(
 
         Statement::CreateMaterializedView(CreateMaterializedViewStatement {
             if_exists,
+            temporary,
             name,
             columns: _,
             replacement_for: _,
@@ -411,7 +414,11 @@ pub fn create_statement(
             with_options: _,
             as_of: _,
         }) => {
-            *name = allocate_name(name)?;
+            *name = if *temporary {
+                allocate_temporary_name(name)?
+            } else {
+                allocate_name(name)?
+            };
             {
                 let mut normalizer = QueryNormalizer::new();
                 normalizer.visit_query_mut(query);
@@ -429,6 +436,7 @@ pub fn create_statement(
             key_parts,
             with_options: _,
             if_not_exists,
+            temporary: _,
         }) => {
             let mut normalizer = QueryNormalizer::new();
             if let Some(key_parts) = key_parts {
@@ -458,6 +466,32 @@ pub fn create_statement(
             *name = allocate_name(name)?;
             *if_not_exists = false;
         }
+        Statement::CreateSnapshot(CreateSnapshotStatement {
+            name,
+            if_not_exists,
+            from: _,
+            as_of: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
+        Statement::CreateNotification(CreateNotificationStatement {
+            name,
+            if_not_exists,
+            in_cluster: _,
+            on: _,
+            selection,
+        }) => {
+            *name = allocate_name(name)?;
+            if let Some(selection) = selection {
+                let mut normalizer = QueryNormalizer::new();
+                normalizer.visit_expr_mut(selection);
+                if let Some(err) = normalizer.err {
+                    return Err(err);
+                }
+            }
+            *if_not_exists = false;
+        }
         Statement::CreateConnection(CreateConnectionStatement {
             name,
             connection_type: _,
@@ -52,8 +52,9 @@ use mz_repr::{
 };
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ClusterAlterOptionValue, ConnectionOptionName, CreateSinkOption,
-    CreateSinkOptionName, QualifiedReplica, RawDataType, SelectStatement,
-    TransactionIsolationLevel, TransactionMode, UnresolvedItemName, Value, WithOptionValue,
+    CreateSinkOptionName, Ident, QualifiedReplica, RawDataType, RowLevelSecurityPolicy,
+    SelectStatement, TransactionIsolationLevel, TransactionMode, UnresolvedItemName, Value,
+    WithOptionValue,
 };
 use mz_ssh_util::keys::SshKeyPair;
 use mz_storage_types::connections::aws::AwsConnection;
@@ -61,8 +62,8 @@ use mz_storage_types::connections::gcp::{GcpConnection, GcpServiceAccountKeyToke
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::{
     AwsPrivatelinkConnection, CsrConnection, GlueSchemaRegistryConnection,
-    IcebergCatalogConnection, KafkaConnection, MySqlConnection, PostgresConnection,
-    SqlServerConnectionDetails, SshConnection,
+    IcebergCatalogConnection, KafkaConnection, MongoDbConnection, MqttConnection, MySqlConnection,
+    NatsConnection, PostgresConnection, SqlServerConnectionDetails, SshConnection,
 };
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::sinks::{S3SinkFormat, SinkEnvelope, StorageSinkConnection};
@@ -85,6 +86,7 @@ use crate::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaSpecifier, SystemObjectId,
 };
 
+pub(crate) mod column_privileges;
 pub(crate) mod error;
 pub(crate) mod explain;
 pub(crate) mod hir;
@@ -93,6 +95,7 @@ pub(crate) mod lowering;
 pub(crate) mod notice;
 pub(crate) mod plan_utils;
 pub(crate) mod query;
+pub(crate) mod row_level_security;
 pub(crate) mod scope;
 pub(crate) mod side_effecting_func;
 pub(crate) mod statement;
@@ -104,6 +107,7 @@ pub(crate) mod with_options;
 use crate::plan;
 use crate::plan::statement::ddl::ClusterAlterUntilReadyOptionExtracted;
 use crate::plan::with_options::OptionalDuration;
+pub use column_privileges::ColumnPrivileges;
 pub use error::PlanError;
 pub use explain::normalize_subqueries;
 pub use hir::{
@@ -113,6 +117,7 @@ pub use hir::{
 pub use lowering::Config as HirToMirConfig;
 pub use notice::PlanNotice;
 pub use query::{ExprContext, QueryContext, QueryLifetime};
+pub use row_level_security::restricts_role as row_level_security_restricts_role;
 pub use scope::Scope;
 pub use side_effecting_func::SideEffectingFunc;
 pub use statement::ddl::{
@@ -142,10 +147,12 @@ pub enum Plan {
     CreateSources(Vec<CreateSourcePlanBundle>),
     CreateSecret(CreateSecretPlan),
     CreateSink(CreateSinkPlan),
+    CreateNotification(CreateNotificationPlan),
     CreateTable(CreateTablePlan),
     CreateView(CreateViewPlan),
     CreateMaterializedView(CreateMaterializedViewPlan),
     CreateNetworkPolicy(CreateNetworkPolicyPlan),
+    CreatePolicy(CreatePolicyPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
     Comment(CommentPlan),
@@ -153,6 +160,7 @@ pub enum Plan {
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropPolicy(DropPolicyPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -195,6 +203,7 @@ pub enum Plan {
     AlterTableAddColumn(AlterTablePlan),
     AlterMaterializedViewApplyReplacement(AlterMaterializedViewApplyReplacementPlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
+    AlterRowLevelSecurity(AlterRowLevelSecurityPlan),
     Declare(DeclarePlan),
     Fetch(FetchPlan),
     Close(ClosePlan),
@@ -202,6 +211,8 @@ pub enum Plan {
     Prepare(PreparePlan),
     Execute(ExecutePlan),
     Deallocate(DeallocatePlan),
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
     Raise(RaisePlan),
     GrantRole(GrantRolePlan),
     RevokeRole(RevokeRolePlan),
@@ -238,6 +249,9 @@ impl Plan {
             ],
             StatementKind::AlterRole => &[PlanKind::AlterRole],
             StatementKind::AlterNetworkPolicy => &[PlanKind::AlterNetworkPolicy],
+            StatementKind::AlterRowLevelSecurity => {
+                &[PlanKind::AlterNoop, PlanKind::AlterRowLevelSecurity]
+            }
             StatementKind::AlterSecret => &[PlanKind::AlterNoop, PlanKind::AlterSecret],
             StatementKind::AlterSetCluster => &[PlanKind::AlterNoop, PlanKind::AlterSetCluster],
             StatementKind::AlterSink => &[PlanKind::AlterNoop, PlanKind::AlterSink],
@@ -275,11 +289,14 @@ impl Plan {
             StatementKind::CreateDatabase => &[PlanKind::CreateDatabase],
             StatementKind::CreateIndex => &[PlanKind::CreateIndex],
             StatementKind::CreateNetworkPolicy => &[PlanKind::CreateNetworkPolicy],
+            StatementKind::CreatePolicy => &[PlanKind::CreatePolicy],
             StatementKind::CreateMaterializedView => &[PlanKind::CreateMaterializedView],
             StatementKind::CreateRole => &[PlanKind::CreateRole],
             StatementKind::CreateSchema => &[PlanKind::CreateSchema],
             StatementKind::CreateSecret => &[PlanKind::CreateSecret],
             StatementKind::CreateSink => &[PlanKind::CreateSink],
+            StatementKind::CreateSnapshot => &[PlanKind::CreateTable],
+            StatementKind::CreateNotification => &[PlanKind::CreateNotification],
             StatementKind::CreateSource | StatementKind::CreateSubsource => {
                 &[PlanKind::CreateSource]
             }
@@ -294,6 +311,7 @@ impl Plan {
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
+            StatementKind::DropPolicy => &[PlanKind::DropPolicy],
             StatementKind::Execute => &[PlanKind::Execute],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
@@ -305,6 +323,7 @@ impl Plan {
             StatementKind::GrantPrivileges => &[PlanKind::GrantPrivileges],
             StatementKind::GrantRole => &[PlanKind::GrantRole],
             StatementKind::Insert => &[PlanKind::Insert],
+            StatementKind::Listen => &[PlanKind::Listen],
             StatementKind::Prepare => &[PlanKind::Prepare],
             StatementKind::Raise => &[PlanKind::Raise],
             StatementKind::ReassignOwned => &[PlanKind::ReassignOwned],
@@ -325,6 +344,7 @@ impl Plan {
             ],
             StatementKind::StartTransaction => &[PlanKind::StartTransaction],
             StatementKind::Subscribe => &[PlanKind::Subscribe],
+            StatementKind::Unlisten => &[PlanKind::Unlisten],
             StatementKind::Update => &[PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => &[PlanKind::ValidateConnection],
             StatementKind::AlterRetainHistory => &[PlanKind::AlterRetainHistory],
@@ -345,12 +365,14 @@ impl Plan {
             Plan::CreateSources(_) => "create source",
             Plan::CreateSecret(_) => "create secret",
             Plan::CreateSink(_) => "create sink",
+            Plan::CreateNotification(_) => "create notification",
             Plan::CreateTable(_) => "create table",
             Plan::CreateView(_) => "create view",
             Plan::CreateMaterializedView(_) => "create materialized view",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
             Plan::CreateNetworkPolicy(_) => "create network policy",
+            Plan::CreatePolicy(_) => "create policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -373,6 +395,7 @@ impl Plan {
                 ObjectType::NetworkPolicy => "drop network policy",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropPolicy(_) => "drop policy",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -429,6 +452,7 @@ impl Plan {
             Plan::AlterSystemResetAll(_) => "alter system",
             Plan::AlterRole(_) => "alter role",
             Plan::AlterNetworkPolicy(_) => "alter network policy",
+            Plan::AlterRowLevelSecurity(_) => "alter row level security",
             Plan::AlterOwner(plan) => match plan.object_type {
                 ObjectType::Table => "alter table owner",
                 ObjectType::View => "alter view owner",
@@ -462,6 +486,8 @@ impl Plan {
             Plan::Prepare(_) => "prepare",
             Plan::Execute(_) => "execute",
             Plan::Deallocate(_) => "deallocate",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
             Plan::Raise(_) => "raise",
             Plan::GrantRole(_) => "grant role",
             Plan::RevokeRole(_) => "revoke role",
@@ -749,6 +775,14 @@ pub struct CreateSinkPlan {
     pub in_cluster: ClusterId,
 }
 
+#[derive(Debug)]
+pub struct CreateNotificationPlan {
+    pub name: QualifiedItemName,
+    pub notification: Notification,
+    pub if_not_exists: bool,
+    pub in_cluster: ClusterId,
+}
+
 #[derive(Debug)]
 pub struct CreateTablePlan {
     pub name: QualifiedItemName,
@@ -797,6 +831,38 @@ pub struct AlterNetworkPolicyPlan {
     pub rules: Vec<NetworkPolicyRule>,
 }
 
+/// Adds a row level security policy to a relation.
+#[derive(Debug, Clone)]
+pub struct CreatePolicyPlan {
+    pub id: CatalogItemId,
+    pub policy: RowLevelSecurityPolicy<Raw>,
+}
+
+/// Removes a row level security policy from a relation.
+#[derive(Debug, Clone)]
+pub struct DropPolicyPlan {
+    pub id: CatalogItemId,
+    /// The name of the policy, or `None` if the policy does not exist and the
+    /// statement specified `IF EXISTS`.
+    pub name: Option<Ident>,
+}
+
+/// Enables or disables row level security on a relation.
+#[derive(Debug, Clone)]
+pub struct AlterRowLevelSecurityPlan {
+    pub id: CatalogItemId,
+    pub object_type: ObjectType,
+    pub enabled: bool,
+}
+
+/// A change to the row level security configuration of a relation.
+#[derive(Debug, Clone)]
+pub enum RowLevelSecurityAction {
+    SetEnabled(bool),
+    CreatePolicy(RowLevelSecurityPolicy<Raw>),
+    DropPolicy(Ident),
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateIndexPlan {
     pub name: QualifiedItemName,
@@ -1450,6 +1516,18 @@ pub struct DeallocatePlan {
     pub name: Option<String>,
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    pub channel: String,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` to stop listening on all
+    /// channels.
+    pub channel: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct RaisePlan {
     pub severity: NoticeSeverity,
@@ -1488,6 +1566,9 @@ pub struct UpdatePrivilege {
     /// case: the shorthand is not the user explicitly naming a privilege
     /// that doesn't apply to the object type, so warning would be noisy.
     pub acl_from_all: bool,
+    /// The columns receiving the privileges. Empty if the privileges are granted
+    /// or revoked on the whole object.
+    pub columns: Vec<ColumnName>,
 }
 
 #[derive(Debug)]
@@ -1548,6 +1629,11 @@ pub enum TableDataSource {
         desc: DataSourceDesc,
         timeline: Timeline,
     },
+
+    /// The table is a read-only view of the identified materialized view's
+    /// contents as of `as_of`. It shares its persist shard with the
+    /// materialized view, and holds back that shard's since to `as_of`.
+    Snapshot { from: GlobalId, as_of: Timestamp },
 }
 
 #[derive(Clone, Debug)]
@@ -1728,6 +1814,9 @@ pub enum ConnectionDetails {
     Gcp(GcpConnection),
     MySql(MySqlConnection<ReferencedConnection>),
     SqlServer(SqlServerConnectionDetails<ReferencedConnection>),
+    MongoDb(MongoDbConnection),
+    Nats(NatsConnection),
+    Mqtt(MqttConnection),
     IcebergCatalog(IcebergCatalogConnection<ReferencedConnection>),
 }
 
@@ -1758,6 +1847,15 @@ impl ConnectionDetails {
             ConnectionDetails::SqlServer(c) => {
                 mz_storage_types::connections::Connection::SqlServer(c.clone())
             }
+            ConnectionDetails::MongoDb(c) => {
+                mz_storage_types::connections::Connection::MongoDb(c.clone())
+            }
+            ConnectionDetails::Nats(c) => {
+                mz_storage_types::connections::Connection::Nats(c.clone())
+            }
+            ConnectionDetails::Mqtt(c) => {
+                mz_storage_types::connections::Connection::Mqtt(c.clone())
+            }
             ConnectionDetails::IcebergCatalog(c) => {
                 mz_storage_types::connections::Connection::IcebergCatalog(c.clone())
             }
@@ -1927,6 +2025,16 @@ pub struct Sink {
     pub commit_interval: Option<Duration>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// Parse-able SQL that is stored durably and defines this notification.
+    pub create_sql: String,
+    /// Collection whose changes are delivered as notifications.
+    pub from: GlobalId,
+    /// The description of the relation the notification was created on.
+    pub desc: RelationDesc,
+}
+
 #[derive(Clone, Debug)]
 pub struct View {
     /// Parse-able SQL that is stored durably and defines this view.
@@ -1960,6 +2068,8 @@ pub struct MaterializedView {
     pub compaction_window: Option<CompactionWindow>,
     pub refresh_schedule: Option<RefreshSchedule>,
     pub as_of: Option<Timestamp>,
+    /// If this materialized view is created in the temporary schema, e.g. `CREATE TEMPORARY ...`.
+    pub temporary: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1971,6 +2081,8 @@ pub struct Index {
     pub keys: Vec<mz_expr::MirScalarExpr>,
     pub compaction_window: Option<CompactionWindow>,
     pub cluster_id: ClusterId,
+    /// If this index is created in the temporary schema, e.g. `CREATE TEMPORARY ...`.
+    pub temporary: bool,
 }
 
 #[derive(Clone, Debug)]
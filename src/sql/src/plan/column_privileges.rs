@@ -0,0 +1,305 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Privileges on columns.
+//!
+//! `GRANT SELECT (a, b) ON t TO r` allows a role to read some of the columns of
+//! a relation without holding the privilege on the relation as a whole. The
+//! privileges are stored as the `COLUMN PRIVILEGES` option of the relation's
+//! `CREATE` statement, in the text format of `mz_aclitem`, e.g.:
+//!
+//! ```sql
+//! CREATE TABLE t (a int, b int) WITH (COLUMN PRIVILEGES = (a = 'u2=r/u1'))
+//! ```
+//!
+//! Privileges on columns are enforced while planning. When the active role
+//! lacks the `SELECT` privilege on a relation but holds it on some of the
+//! relation's columns, references to the other columns are rejected, and the
+//! RBAC checks accept the privileges on the columns in place of the privilege
+//! on the relation.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
+use mz_repr::role_id::RoleId;
+use mz_repr::{ColumnName, RelationDesc};
+use mz_sql_parser::ast::{
+    ColumnPrivilege, CreateSourceOptionName, Ident, MaterializedViewOptionName, Raw, Statement,
+    TableFromSourceOptionName, TableOptionName, ViewOptionName, WithOptionValue,
+};
+use mz_sql_parser::parser::parse_statements;
+
+use crate::catalog::CatalogItem;
+use crate::names::{FullItemName, QualifiedItemName, SchemaSpecifier};
+use crate::normalize;
+use crate::plan::PlanError;
+use crate::plan::scope::Scope;
+use crate::plan::statement::StatementContext;
+
+/// The privileges on the columns of a relation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnPrivileges(BTreeMap<ColumnName, PrivilegeMap>);
+
+impl ColumnPrivileges {
+    /// Extracts the privileges on columns from the `CREATE` statement of a
+    /// relation.
+    pub fn from_statement(stmt: &Statement<Raw>) -> ColumnPrivileges {
+        let values: Vec<&Option<WithOptionValue<Raw>>> = match stmt {
+            Statement::CreateTable(stmt) => stmt
+                .with_options
+                .iter()
+                .filter(|o| o.name == TableOptionName::ColumnPrivileges)
+                .map(|o| &o.value)
+                .collect(),
+            Statement::CreateTableFromSource(stmt) => stmt
+                .with_options
+                .iter()
+                .filter(|o| o.name == TableFromSourceOptionName::ColumnPrivileges)
+                .map(|o| &o.value)
+                .collect(),
+            Statement::CreateSource(stmt) => stmt
+                .with_options
+                .iter()
+                .filter(|o| o.name == CreateSourceOptionName::ColumnPrivileges)
+                .map(|o| &o.value)
+                .collect(),
+            Statement::CreateMaterializedView(stmt) => stmt
+                .with_options
+                .iter()
+                .filter(|o| o.name == MaterializedViewOptionName::ColumnPrivileges)
+                .map(|o| &o.value)
+                .collect(),
+            Statement::CreateView(stmt) => stmt
+                .definition
+                .with_options
+                .iter()
+                .filter(|o| o.name == ViewOptionName::ColumnPrivileges)
+                .map(|o| &o.value)
+                .collect(),
+            _ => vec![],
+        };
+
+        let mut privileges = ColumnPrivileges::default();
+        for value in values {
+            let Some(WithOptionValue::ColumnPrivileges(items)) = value else {
+                continue;
+            };
+            for ColumnPrivilege { column, acl_item } in items {
+                // Malformed values are rejected when the statement is planned.
+                if let Ok(acl_item) = acl_item.parse() {
+                    privileges.grant(normalize::column_name(column.clone()), acl_item);
+                }
+            }
+        }
+        privileges
+    }
+
+    /// Extracts the privileges on columns from the `CREATE` SQL of a relation.
+    pub fn from_create_sql(create_sql: &str) -> ColumnPrivileges {
+        // Avoid parsing the create SQL of every relation, as only relations
+        // that mention the option can have privileges on columns.
+        if !create_sql.contains("COLUMN PRIVILEGES") {
+            return ColumnPrivileges::default();
+        }
+        match parse_statements(create_sql).as_deref() {
+            Ok([stmt]) => ColumnPrivileges::from_statement(&stmt.ast),
+            _ => ColumnPrivileges::default(),
+        }
+    }
+
+    /// Reports whether no privileges on columns exist.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the privileges on each column.
+    pub fn iter(&self) -> impl Iterator<Item = (&ColumnName, &PrivilegeMap)> {
+        self.0.iter()
+    }
+
+    /// Grants `privilege` on `column`.
+    pub fn grant(&mut self, column: ColumnName, privilege: MzAclItem) {
+        self.0.entry(column).or_default().grant(privilege);
+    }
+
+    /// Revokes `acl_mode` on `column` from `grantee`, regardless of the role
+    /// that granted it.
+    pub fn revoke(&mut self, column: &ColumnName, grantee: &RoleId, acl_mode: AclMode) {
+        let Some(privileges) = self.0.get_mut(column) else {
+            return;
+        };
+        let revokes: Vec<_> = privileges
+            .get_acl_items_for_grantee(grantee)
+            .map(|privilege| MzAclItem {
+                grantee: privilege.grantee,
+                grantor: privilege.grantor,
+                acl_mode,
+            })
+            .collect();
+        for revoke in &revokes {
+            privileges.revoke(revoke);
+        }
+        if privileges.all_values().next().is_none() {
+            self.0.remove(column);
+        }
+    }
+
+    /// Revokes `acl_mode` on every column from `grantee`.
+    pub fn revoke_all_columns(&mut self, grantee: &RoleId, acl_mode: AclMode) {
+        let columns: Vec<_> = self.0.keys().cloned().collect();
+        for column in &columns {
+            self.revoke(column, grantee, acl_mode);
+        }
+    }
+
+    /// Returns the columns on which any of `roles` holds `acl_mode`.
+    pub fn columns_granted_to(
+        &self,
+        roles: &BTreeSet<RoleId>,
+        acl_mode: AclMode,
+    ) -> BTreeSet<&ColumnName> {
+        self.0
+            .iter()
+            .filter(|(_, privileges)| holds(privileges, roles, acl_mode))
+            .map(|(column, _)| column)
+            .collect()
+    }
+
+    /// Returns the value of the `COLUMN PRIVILEGES` option that stores these
+    /// privileges.
+    pub fn to_ast(&self) -> Vec<ColumnPrivilege> {
+        self.0
+            .iter()
+            .flat_map(|(column, privileges)| {
+                privileges.all_values().map(|privilege| ColumnPrivilege {
+                    column: Ident::new_unchecked(column.as_str()),
+                    acl_item: privilege.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reports whether any of `roles` holds `acl_mode` in `privileges`.
+pub(crate) fn holds(
+    privileges: &PrivilegeMap,
+    roles: &BTreeSet<RoleId>,
+    acl_mode: AclMode,
+) -> bool {
+    roles
+        .iter()
+        .flat_map(|role_id| privileges.get_acl_items_for_grantee(role_id))
+        .fold(AclMode::empty(), |accum, privilege| {
+            accum.union(privilege.acl_mode)
+        })
+        .contains(acl_mode)
+}
+
+/// Returns the columns of `item` that the active role may read, if the active
+/// role may only read some of the columns of `item`.
+fn granted_columns(scx: &StatementContext, item: &dyn CatalogItem) -> Option<BTreeSet<ColumnName>> {
+    if !scx.catalog.rbac_checks_enabled() || !item.id().is_user() {
+        return None;
+    }
+    let privileges = ColumnPrivileges::from_create_sql(item.create_sql());
+    if privileges.is_empty() {
+        return None;
+    }
+    let roles = scx
+        .catalog
+        .collect_role_membership(scx.catalog.active_role_id());
+    if holds(item.privileges(), &roles, AclMode::SELECT) {
+        return None;
+    }
+    let granted: BTreeSet<_> = privileges
+        .columns_granted_to(&roles, AclMode::SELECT)
+        .into_iter()
+        .cloned()
+        .collect();
+    // Without privileges on any column, the RBAC checks reject the read.
+    if granted.is_empty() {
+        return None;
+    }
+    Some(granted)
+}
+
+/// Rejects references to the columns of `item` in `scope` that the active role
+/// may not read, if the active role may only read some of the columns of
+/// `item`.
+pub(crate) fn restrict_scope(scx: &StatementContext, item: &dyn CatalogItem, scope: &mut Scope) {
+    let Some(granted) = granted_columns(scx, item) else {
+        return;
+    };
+    for scope_item in &mut scope.items {
+        if !granted.contains(&scope_item.column_name) {
+            scope_item.error_if_referenced =
+                Some(|table, column| PlanError::ColumnPermissionDenied {
+                    table: table.cloned(),
+                    column: column.clone(),
+                });
+        }
+    }
+}
+
+/// Returns an error if the active role may only read some of the columns of
+/// `item`, for reads of every column, like those of sinks and subscriptions.
+pub(crate) fn require_all_columns(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    name: &FullItemName,
+) -> Result<(), PlanError> {
+    let (Some(granted), Some(desc)) = (granted_columns(scx, item), item.relation_desc()) else {
+        return Ok(());
+    };
+    match desc.iter_names().find(|column| !granted.contains(*column)) {
+        Some(column) => Err(PlanError::ColumnPermissionDenied {
+            table: Some(name.clone().into()),
+            column: column.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates the `COLUMN PRIVILEGES` option of a `CREATE` statement for a
+/// relation with the given name and description.
+pub(crate) fn plan_options(
+    scx: &StatementContext,
+    name: &QualifiedItemName,
+    desc: &RelationDesc,
+    privileges: Option<&[ColumnPrivilege]>,
+) -> Result<(), PlanError> {
+    let Some(privileges) = privileges else {
+        return Ok(());
+    };
+    if name.qualifiers.schema_spec == SchemaSpecifier::Temporary {
+        sql_bail!("temporary objects do not support privileges on columns");
+    }
+    for ColumnPrivilege { column, acl_item } in privileges {
+        let column = normalize::column_name(column.clone());
+        if desc.get_by_name(&column).is_none() {
+            return Err(PlanError::UnknownColumn {
+                table: None,
+                column,
+                similar: Box::new([]),
+            });
+        }
+        let acl_item: MzAclItem = acl_item
+            .parse()
+            .map_err(|e| sql_err!("invalid column privilege: {e}"))?;
+        for role_id in [&acl_item.grantee, &acl_item.grantor] {
+            if !role_id.is_public() && scx.catalog.try_get_role(role_id).is_none() {
+                sql_bail!("invalid column privilege: unknown role {role_id}");
+            }
+        }
+        if !AclMode::SELECT.contains(acl_item.acl_mode) {
+            sql_bail!("only the SELECT privilege can be granted on columns");
+        }
+    }
+    Ok(())
+}
@@ -16,6 +16,7 @@ use std::{fmt, io};
 
 use itertools::Itertools;
 use mz_expr::EvalError;
+use mz_mongodb_util::MongoDbError;
 use mz_mysql_util::MySqlError;
 use mz_ore::error::ErrorExt;
 use mz_ore::stack::RecursionLimitError;
@@ -43,8 +44,11 @@ use crate::plan::scope::ScopeItem;
 use crate::plan::typeconv::CastContext;
 use crate::pure::error::{
     CsrPurificationError, GluePurificationError, IcebergSinkPurificationError,
-    KafkaSinkPurificationError, KafkaSourcePurificationError, LoadGeneratorSourcePurificationError,
-    MySqlSourcePurificationError, PgSourcePurificationError, SqlServerSourcePurificationError,
+    IcebergSourcePurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
+    KinesisSourcePurificationError, LoadGeneratorSourcePurificationError,
+    MongoDbSourcePurificationError, MqttSourcePurificationError, MySqlSourcePurificationError,
+    NatsSourcePurificationError, PgSourcePurificationError, PostgresSinkPurificationError,
+    SqlServerSourcePurificationError,
 };
 use crate::session::vars::VarError;
 
@@ -194,6 +198,9 @@ pub enum PlanError {
     SqlServerConnectionErr {
         cause: Arc<SqlServerError>,
     },
+    MongoDbConnectionErr {
+        cause: Arc<MongoDbError>,
+    },
     SubsourceNameConflict {
         name: UnresolvedItemName,
         upstream_references: Vec<UnresolvedItemName>,
@@ -206,6 +213,10 @@ pub enum PlanError {
     InvalidProtobufSchema {
         cause: protobuf_native::OperationFailedError,
     },
+    ProtobufMessageRequired {
+        subject: String,
+        option_name: String,
+    },
     InvalidOptionValue {
         // Expected to be generated from the `to_ast_string` value on the option
         // name.
@@ -256,11 +267,39 @@ pub enum PlanError {
     InvalidPartitionByEnvelopeDebezium {
         column_name: String,
     },
+    InvalidTopicByEnvelopeDebezium {
+        column_name: String,
+    },
     InvalidOrderByInSubscribeWithinTimestampOrderBy,
     FromValueRequiresParen,
     VarError(VarError),
     UnsolvablePolymorphicFunctionInput,
     ShowCommandInView,
+    SnapshotInMaintainedQuery {
+        name: String,
+    },
+    RowLevelSecurityInMaintainedQuery {
+        name: String,
+    },
+    RowLevelSecurityUnsupported {
+        name: String,
+        item_type: String,
+    },
+    RowLevelSecurityPolicyAlreadyExists {
+        name: String,
+        relation: String,
+    },
+    RowLevelSecurityPolicyDoesNotExist {
+        name: String,
+        relation: String,
+    },
+    RowLevelSecurityPolicyDependency {
+        name: String,
+    },
+    ColumnPermissionDenied {
+        table: Option<PartialItemName>,
+        column: ColumnName,
+    },
     WebhookValidationDoesNotUseColumns,
     WebhookValidationNonDeterministic,
     InternalFunctionCall,
@@ -278,11 +317,17 @@ pub enum PlanError {
     KafkaSourcePurification(KafkaSourcePurificationError),
     KafkaSinkPurification(KafkaSinkPurificationError),
     IcebergSinkPurification(IcebergSinkPurificationError),
+    PostgresSinkPurification(PostgresSinkPurificationError),
     LoadGeneratorSourcePurification(LoadGeneratorSourcePurificationError),
     CsrPurification(CsrPurificationError),
     GluePurification(GluePurificationError),
     MySqlSourcePurification(MySqlSourcePurificationError),
     SqlServerSourcePurificationError(SqlServerSourcePurificationError),
+    MongoDbSourcePurification(MongoDbSourcePurificationError),
+    IcebergSourcePurification(IcebergSourcePurificationError),
+    KinesisSourcePurification(KinesisSourcePurificationError),
+    NatsSourcePurification(NatsSourcePurificationError),
+    MqttSourcePurification(MqttSourcePurificationError),
     UseTablesForSources(String),
     MissingName(CatalogItemType),
     InvalidRefreshAt,
@@ -378,12 +423,18 @@ impl PlanError {
             Self::PgSourcePurification(e) => e.detail(),
             Self::MySqlSourcePurification(e) => e.detail(),
             Self::SqlServerSourcePurificationError(e) => e.detail(),
+            Self::MongoDbSourcePurification(e) => e.detail(),
+            Self::IcebergSourcePurification(e) => e.detail(),
+            Self::KinesisSourcePurification(e) => e.detail(),
+            Self::NatsSourcePurification(e) => e.detail(),
+            Self::MqttSourcePurification(e) => e.detail(),
             Self::KafkaSourcePurification(e) => e.detail(),
             Self::LoadGeneratorSourcePurification(e) => e.detail(),
             Self::CsrPurification(e) => e.detail(),
             Self::GluePurification(e) => e.detail(),
             Self::KafkaSinkPurification(e) => e.detail(),
             Self::IcebergSinkPurification(e) => e.detail(),
+            Self::PostgresSinkPurification(e) => e.detail(),
             Self::SubsourceNameConflict {
                 name: _,
                 upstream_references,
@@ -401,6 +452,9 @@ impl PlanError {
             Self::InvalidPartitionByEnvelopeDebezium { .. } => Some(
                 "When using ENVELOPE DEBEZIUM, only columns in the key can be referenced in the PARTITION BY expression.".to_string()
             ),
+            Self::InvalidTopicByEnvelopeDebezium { .. } => Some(
+                "When using ENVELOPE DEBEZIUM, only columns in the key can be referenced in the TOPIC BY expression.".to_string()
+            ),
             Self::NoTablesFoundForSchemas(schemas) => Some(format!(
                 "missing schemas: {}",
                 separated(", ", schemas.iter().map(|c| c.quoted()))
@@ -415,6 +469,9 @@ impl PlanError {
                 Some("Use DROP MATERIALIZED VIEW to remove a materialized view.".into())
             }
             Self::DependentObjectsStillExist {..} => Some("Use DROP ... CASCADE to drop the dependent objects too.".into()),
+            Self::ProtobufMessageRequired { option_name, .. } => Some(format!(
+                "Use the {option_name} option to specify the fully qualified name of the message to decode."
+            )),
             Self::AlterViewOnMaterializedView(_) => {
                 Some("Use ALTER MATERIALIZED VIEW to rename a materialized view.".into())
             }
@@ -483,11 +540,17 @@ impl PlanError {
             Self::PgSourcePurification(e) => e.hint(),
             Self::MySqlSourcePurification(e) => e.hint(),
             Self::SqlServerSourcePurificationError(e) => e.hint(),
+            Self::MongoDbSourcePurification(e) => e.hint(),
+            Self::IcebergSourcePurification(e) => e.hint(),
+            Self::KinesisSourcePurification(e) => e.hint(),
+            Self::NatsSourcePurification(e) => e.hint(),
+            Self::MqttSourcePurification(e) => e.hint(),
             Self::KafkaSourcePurification(e) => e.hint(),
             Self::LoadGeneratorSourcePurification(e) => e.hint(),
             Self::CsrPurification(e) => e.hint(),
             Self::GluePurification(e) => e.hint(),
             Self::KafkaSinkPurification(e) => e.hint(),
+            Self::PostgresSinkPurification(e) => e.hint(),
             Self::UnknownColumn { table, similar, .. } => {
                 let suffix = "Make sure to surround case sensitive names in double quotes.";
                 match &similar[..] {
@@ -698,6 +761,9 @@ impl fmt::Display for PlanError {
             Self::SqlServerConnectionErr { cause } => {
                 write!(f, "failed to connect to SQL Server database: {}", cause)
             }
+            Self::MongoDbConnectionErr { cause } => {
+                write!(f, "failed to connect to MongoDB deployment: {}", cause)
+            }
             Self::SubsourceNameConflict {
                 name , upstream_references: _,
             } => {
@@ -717,6 +783,9 @@ impl fmt::Display for PlanError {
             Self::InvalidProtobufSchema { .. } => {
                 write!(f, "invalid protobuf schema")
             }
+            Self::ProtobufMessageRequired { subject, .. } => {
+                write!(f, "protobuf schema of subject {} has multiple messages", subject.quoted())
+            }
             Self::DependentObjectsStillExist {object_type, object_name, dependents} => {
                 let reason = match &dependents[..] {
                     [] => " because other objects depend on it".to_string(),
@@ -785,6 +854,13 @@ impl fmt::Display for PlanError {
                     column_name.quoted(),
                 )
             }
+            Self::InvalidTopicByEnvelopeDebezium { column_name } => {
+                write!(
+                    f,
+                    "TOPIC BY expression cannot refer to non-key column {}",
+                    column_name.quoted(),
+                )
+            }
             Self::InvalidOrderByInSubscribeWithinTimestampOrderBy => {
                 write!(f, "invalid ORDER BY in SUBSCRIBE WITHIN TIMESTAMP ORDER BY")
             }
@@ -796,6 +872,43 @@ impl fmt::Display for PlanError {
                 "could not determine polymorphic type because input has type unknown"
             ),
             Self::ShowCommandInView => f.write_str("SHOW commands are not allowed in views"),
+            Self::SnapshotInMaintainedQuery { name } => write!(
+                f,
+                "snapshot {} can only be referenced by one-off queries",
+                name.quoted()
+            ),
+            Self::RowLevelSecurityInMaintainedQuery { name } => write!(
+                f,
+                "{} has row level security enabled, so only its owner can reference it outside of one-off queries and SUBSCRIBE",
+                name.quoted()
+            ),
+            Self::RowLevelSecurityUnsupported { name, item_type } => write!(
+                f,
+                "{item_type} {} does not support row level security",
+                name.quoted()
+            ),
+            Self::RowLevelSecurityPolicyAlreadyExists { name, relation } => write!(
+                f,
+                "policy {} for relation {} already exists",
+                name.quoted(),
+                relation.quoted()
+            ),
+            Self::RowLevelSecurityPolicyDoesNotExist { name, relation } => write!(
+                f,
+                "policy {} for relation {} does not exist",
+                name.quoted(),
+                relation.quoted()
+            ),
+            Self::RowLevelSecurityPolicyDependency { name } => write!(
+                f,
+                "row level security policies cannot depend on user objects, but found {}",
+                name.quoted()
+            ),
+            Self::ColumnPermissionDenied { table, column } => write!(
+                f,
+                "permission denied for column {}",
+                ColumnDisplay { table, column },
+            ),
             Self::WebhookValidationDoesNotUseColumns => f.write_str(
                 "expression provided in CHECK does not reference any columns"
             ),
@@ -817,10 +930,16 @@ impl fmt::Display for PlanError {
             Self::LoadGeneratorSourcePurification(e) => write!(f, "LOAD GENERATOR source validation: {}", e),
             Self::KafkaSinkPurification(e) => write!(f, "KAFKA sink validation: {}", e),
             Self::IcebergSinkPurification(e) => write!(f, "ICEBERG sink validation: {}", e),
+            Self::PostgresSinkPurification(e) => write!(f, "POSTGRES sink validation: {}", e),
             Self::CsrPurification(e) => write!(f, "CONFLUENT SCHEMA REGISTRY validation: {}", e),
             Self::GluePurification(e) => write!(f, "AWS GLUE SCHEMA REGISTRY validation: {}", e),
             Self::MySqlSourcePurification(e) => write!(f, "MYSQL source validation: {}", e),
             Self::SqlServerSourcePurificationError(e) => write!(f, "SQL SERVER source validation: {}", e),
+            Self::MongoDbSourcePurification(e) => write!(f, "MONGODB source validation: {}", e),
+            Self::IcebergSourcePurification(e) => write!(f, "ICEBERG source validation: {}", e),
+            Self::KinesisSourcePurification(e) => write!(f, "KINESIS source validation: {}", e),
+            Self::NatsSourcePurification(e) => write!(f, "NATS source validation: {}", e),
+            Self::MqttSourcePurification(e) => write!(f, "MQTT source validation: {}", e),
             Self::UseTablesForSources(command) => write!(f, "{command} not supported; use CREATE TABLE .. FROM SOURCE instead"),
             Self::MangedReplicaName(name) => {
                 write!(f, "{name} is reserved for replicas of managed clusters")
@@ -999,6 +1118,12 @@ impl From<SqlServerError> for PlanError {
     }
 }
 
+impl From<MongoDbError> for PlanError {
+    fn from(e: MongoDbError) -> PlanError {
+        PlanError::MongoDbConnectionErr { cause: Arc::new(e) }
+    }
+}
+
 impl From<VarError> for PlanError {
     fn from(e: VarError) -> Self {
         PlanError::VarError(e)
@@ -1029,6 +1154,12 @@ impl From<IcebergSinkPurificationError> for PlanError {
     }
 }
 
+impl From<PostgresSinkPurificationError> for PlanError {
+    fn from(e: PostgresSinkPurificationError) -> Self {
+        PlanError::PostgresSinkPurification(e)
+    }
+}
+
 impl From<GluePurificationError> for PlanError {
     fn from(e: GluePurificationError) -> Self {
         PlanError::GluePurification(e)
@@ -1059,6 +1190,36 @@ impl From<SqlServerSourcePurificationError> for PlanError {
     }
 }
 
+impl From<MongoDbSourcePurificationError> for PlanError {
+    fn from(e: MongoDbSourcePurificationError) -> Self {
+        PlanError::MongoDbSourcePurification(e)
+    }
+}
+
+impl From<IcebergSourcePurificationError> for PlanError {
+    fn from(e: IcebergSourcePurificationError) -> Self {
+        PlanError::IcebergSourcePurification(e)
+    }
+}
+
+impl From<KinesisSourcePurificationError> for PlanError {
+    fn from(e: KinesisSourcePurificationError) -> Self {
+        PlanError::KinesisSourcePurification(e)
+    }
+}
+
+impl From<NatsSourcePurificationError> for PlanError {
+    fn from(e: NatsSourcePurificationError) -> Self {
+        PlanError::NatsSourcePurification(e)
+    }
+}
+
+impl From<MqttSourcePurificationError> for PlanError {
+    fn from(e: MqttSourcePurificationError) -> Self {
+        PlanError::MqttSourcePurification(e)
+    }
+}
+
 impl From<IdentError> for PlanError {
     fn from(e: IdentError) -> Self {
         PlanError::InvalidIdent(e)
@@ -33,6 +33,10 @@ pub enum PlanNotice {
         name: String,
         object_type: ObjectType,
     },
+    PolicyDoesNotExist {
+        name: String,
+        relation: String,
+    },
     ColumnAlreadyExists {
         column_name: String,
         object_name: String,
@@ -85,6 +89,14 @@ impl fmt::Display for PlanNotice {
                     name.quoted()
                 )
             }
+            PlanNotice::PolicyDoesNotExist { name, relation } => {
+                write!(
+                    f,
+                    "policy {} for relation {} does not exist, skipping",
+                    name.quoted(),
+                    relation.quoted()
+                )
+            }
             PlanNotice::ColumnAlreadyExists {
                 column_name,
                 object_name,
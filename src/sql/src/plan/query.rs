@@ -105,7 +105,7 @@ use crate::plan::statement::{StatementContext, StatementDesc, show};
 use crate::plan::typeconv::{self, CastContext, plan_hypothetical_cast};
 use crate::plan::{
     Params, PlanContext, QueryWhen, ShowCreatePlan, WebhookValidation, WebhookValidationSecret,
-    literal, transform_ast,
+    column_privileges, literal, row_level_security, transform_ast,
 };
 use crate::session::vars::ENABLE_WITH_ORDINALITY_LEGACY_FALLBACK;
 use crate::session::vars::{self, FeatureFlag};
@@ -379,12 +379,14 @@ pub fn plan_insert_query(
 
     let returning = {
         let (scope, typ) = if let ResolvedItemName::Item {
+            id,
             full_name,
             version: _,
             ..
         } = table_name
         {
-            let scope = Scope::from_source(Some(full_name.clone().into()), desc.iter_names());
+            let mut scope = Scope::from_source(Some(full_name.clone().into()), desc.iter_names());
+            column_privileges::restrict_scope(scx, scx.get_item(&id), &mut scope);
             let typ = desc.typ().clone();
             (scope, typ)
         } else {
@@ -1248,6 +1250,32 @@ pub fn plan_webhook_validate_using(
     Ok(validation)
 }
 
+/// Plans the `USING` predicate of a row level security policy against the
+/// columns of the relation that the policy belongs to.
+pub fn plan_row_level_security_policy(
+    scx: &StatementContext,
+    lifetime: QueryLifetime,
+    table_name: Option<PartialItemName>,
+    desc: &RelationDesc,
+    expr: &Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    let qcx = QueryContext::root(scx, lifetime);
+    let scope = Scope::from_source(table_name, desc.iter_names().cloned());
+    let mut expr = expr.clone();
+    transform_ast::transform(scx, &mut expr)?;
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "USING clause of a row level security policy",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(ecx, &expr)?.type_as(ecx, &SqlScalarType::Bool)
+}
+
 pub fn plan_default_expr(
     scx: &StatementContext,
     expr: &Expr<Aug>,
@@ -3673,10 +3701,11 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.is_from_table(&table_name))
                 .map(|(i, item)| {
+                    check_implicit_reference(item)?;
                     let name = item.column_name.clone();
-                    (ExpandedSelectItem::InputOrdinal(i), name)
+                    Ok((ExpandedSelectItem::InputOrdinal(i), name))
                 })
-                .collect();
+                .collect::<Result<_, PlanError>>()?;
             if out.is_empty() {
                 sql_bail!("no table named '{}' in scope", table_name);
             }
@@ -3746,10 +3775,11 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.allow_unqualified_references)
                 .map(|(i, item)| {
+                    check_implicit_reference(item)?;
                     let name = item.column_name.clone();
-                    (ExpandedSelectItem::InputOrdinal(i), name)
+                    Ok((ExpandedSelectItem::InputOrdinal(i), name))
                 })
-                .collect();
+                .collect::<Result<_, PlanError>>()?;
 
             Ok(items)
         }
@@ -3764,6 +3794,19 @@ fn expand_select_item<'a>(
     }
 }
 
+/// Returns the error for referencing `item`, if `item` cannot be referenced.
+/// Wildcards and whole-row references implicitly reference each column that
+/// they cover without going through [`Scope::resolve_column`].
+fn check_implicit_reference(item: &ScopeItem) -> Result<(), PlanError> {
+    match item.error_if_referenced {
+        Some(error_if_referenced) => Err(error_if_referenced(
+            item.table_name.as_ref(),
+            &item.column_name,
+        )),
+        None => Ok(()),
+    }
+}
+
 fn plan_join(
     left_qcx: &QueryContext,
     left: HirRelationExpr,
@@ -5393,15 +5436,19 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
                     if item.is_exists_column_for_a_table_function_that_was_in_the_target_list {
                         has_exists_column = Some(column);
                         None
+                    } else if let Err(e) = check_implicit_reference(item) {
+                        Some(Err(e))
                     } else {
                         let expr = HirScalarExpr::named_column(
                             column,
                             ecx.qcx.name_manager.borrow_mut().intern_scope_item(item),
                         );
                         let name = item.column_name.clone();
-                        Some((expr, name))
+                        Some(Ok((expr, name)))
                     }
                 })
+                .collect::<Result<Vec<_>, PlanError>>()?
+                .into_iter()
                 .unzip();
             // For the special case of a table function with a single column, the single column is instead not wrapped.
             let expr = if exprs.len() == 1 && has_exists_column.is_some() {
@@ -6788,13 +6835,31 @@ impl<'a> QueryContext<'a> {
                         });
                     }
                 };
-                let expr = HirRelationExpr::Get {
+                // Snapshots share a shard with the materialized view they were
+                // taken from, so they only have well-defined contents when read
+                // at their pinned time.
+                if item.snapshot_details().is_some() && !self.lifetime.is_one_shot() {
+                    return Err(PlanError::SnapshotInMaintainedQuery {
+                        name: full_name.to_string(),
+                    });
+                }
+                let mut expr = HirRelationExpr::Get {
                     id: Id::Global(item.global_id()),
                     typ: desc.typ().clone(),
                 };
+                if let Some(predicate) = row_level_security::plan_enforced_predicate(
+                    self.scx,
+                    self.scx.get_item(&id),
+                    &full_name,
+                    &desc,
+                    self.lifetime,
+                )? {
+                    expr = expr.filter(vec![predicate]);
+                }
 
                 let name = full_name.into();
-                let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let mut scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                column_privileges::restrict_scope(self.scx, self.scx.get_item(&id), &mut scope);
 
                 Ok((expr, scope))
             }
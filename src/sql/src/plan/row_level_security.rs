@@ -0,0 +1,322 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Row level security.
+//!
+//! A relation opts in to row level security with `ALTER ... ENABLE ROW LEVEL
+//! SECURITY`. From then on, queries by roles that do not own the relation only
+//! observe the rows that satisfy at least one of the relation's policies, as
+//! installed by `CREATE POLICY`. A relation without policies shows no rows to
+//! those roles at all.
+//!
+//! Both the setting and the policies are stored as options on the relation's
+//! `CREATE` statement, e.g.:
+//!
+//! ```sql
+//! CREATE TABLE t (owner text) WITH (
+//!     ROW LEVEL SECURITY = true,
+//!     ROW LEVEL SECURITY POLICIES = (mine USING (owner = current_user))
+//! )
+//! ```
+//!
+//! Policies are enforced while planning, by filtering every read of the
+//! relation with the disjunction of its policies. Maintained objects cannot
+//! depend on the identity of the role that queries them, so roles that are
+//! subject to a relation's policies can only read the relation from one-off
+//! queries and `SUBSCRIBE`.
+
+use mz_repr::RelationDesc;
+use mz_repr::role_id::RoleId;
+use mz_sql_parser::ast::{
+    CreateSourceOptionName, Expr, Ident, MaterializedViewOptionName, Raw, RowLevelSecurityPolicy,
+    Statement, TableFromSourceOptionName, TableOptionName, Value, ViewOptionName, WithOptionValue,
+};
+use mz_sql_parser::parser::parse_statements;
+
+use crate::ast::Aug;
+use crate::catalog::{CatalogItem, SessionCatalog};
+use crate::names::{self, FullItemName, PartialItemName, QualifiedItemName, SchemaSpecifier};
+use crate::plan::query::{self, QueryLifetime};
+use crate::plan::statement::StatementContext;
+use crate::plan::{HirScalarExpr, PlanError};
+use crate::session::vars;
+
+/// The row level security configuration of a relation.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RowLevelSecurity {
+    /// Whether the policies are enforced.
+    pub enabled: bool,
+    /// The policies that grant access to the rows of the relation.
+    pub policies: Vec<RowLevelSecurityPolicy<Raw>>,
+}
+
+impl RowLevelSecurity {
+    /// Extracts the row level security configuration from the `CREATE`
+    /// statement of a relation.
+    ///
+    /// Returns `None` if the statement creates an object that does not support
+    /// row level security.
+    pub(crate) fn from_statement(stmt: &Statement<Raw>) -> Option<RowLevelSecurity> {
+        // Whether each option configures the policies or the setting.
+        let options: Vec<(bool, &Option<WithOptionValue<Raw>>)> = match stmt {
+            // Temporary objects are only visible to the session that created
+            // them, which makes row level security moot.
+            Statement::CreateTable(stmt) if !stmt.temporary => stmt
+                .with_options
+                .iter()
+                .filter_map(|o| match o.name {
+                    TableOptionName::RowLevelSecurity => Some((false, &o.value)),
+                    TableOptionName::RowLevelSecurityPolicies => Some((true, &o.value)),
+                    _ => None,
+                })
+                .collect(),
+            Statement::CreateTableFromSource(stmt) => stmt
+                .with_options
+                .iter()
+                .filter_map(|o| match o.name {
+                    TableFromSourceOptionName::RowLevelSecurity => Some((false, &o.value)),
+                    TableFromSourceOptionName::RowLevelSecurityPolicies => Some((true, &o.value)),
+                    _ => None,
+                })
+                .collect(),
+            Statement::CreateSource(stmt) => stmt
+                .with_options
+                .iter()
+                .filter_map(|o| match o.name {
+                    CreateSourceOptionName::RowLevelSecurity => Some((false, &o.value)),
+                    CreateSourceOptionName::RowLevelSecurityPolicies => Some((true, &o.value)),
+                    _ => None,
+                })
+                .collect(),
+            Statement::CreateMaterializedView(stmt) if !stmt.temporary => stmt
+                .with_options
+                .iter()
+                .filter_map(|o| match o.name {
+                    MaterializedViewOptionName::RowLevelSecurity => Some((false, &o.value)),
+                    MaterializedViewOptionName::RowLevelSecurityPolicies => Some((true, &o.value)),
+                    _ => None,
+                })
+                .collect(),
+            Statement::CreateView(stmt) if !stmt.temporary => stmt
+                .definition
+                .with_options
+                .iter()
+                .map(|o| match o.name {
+                    ViewOptionName::RowLevelSecurity => (false, &o.value),
+                    ViewOptionName::RowLevelSecurityPolicies => (true, &o.value),
+                })
+                .collect(),
+            _ => return None,
+        };
+
+        let mut rls = RowLevelSecurity::default();
+        for (is_policies, value) in options {
+            match (is_policies, value) {
+                (false, None) => rls.enabled = true,
+                (false, Some(WithOptionValue::Value(Value::Boolean(enabled)))) => {
+                    rls.enabled = *enabled
+                }
+                (true, Some(WithOptionValue::RowLevelSecurityPolicies(policies))) => {
+                    rls.policies.extend(policies.iter().cloned())
+                }
+                // Malformed values are rejected when the statement is planned.
+                _ => {}
+            }
+        }
+        Some(rls)
+    }
+
+    /// Extracts the row level security configuration of a catalog item.
+    ///
+    /// Returns `None` if the item does not support row level security.
+    pub(crate) fn from_item(item: &dyn CatalogItem) -> Result<Option<RowLevelSecurity>, PlanError> {
+        let stmts = parse_statements(item.create_sql())?;
+        match stmts.as_slice() {
+            [stmt] => Ok(RowLevelSecurity::from_statement(&stmt.ast)),
+            _ => Err(PlanError::Internal(format!(
+                "create SQL of {} was not exactly one statement",
+                item.id()
+            ))),
+        }
+    }
+
+    /// Reports whether a policy with the given name exists.
+    pub(crate) fn contains_policy(&self, name: &Ident) -> bool {
+        self.policies.iter().any(|p| &p.name == name)
+    }
+}
+
+/// Reports whether the active role is exempt from the row level security
+/// policies of `item`, which is the case for system roles and for members of
+/// the role that owns `item`.
+pub(crate) fn is_exempt(scx: &StatementContext, item: &dyn CatalogItem) -> bool {
+    is_role_exempt(scx.catalog, item, scx.catalog.active_role_id())
+}
+
+/// Like [`is_exempt`], but for an arbitrary role.
+fn is_role_exempt(catalog: &dyn SessionCatalog, item: &dyn CatalogItem, role_id: &RoleId) -> bool {
+    role_id.is_system()
+        || catalog
+            .collect_role_membership(role_id)
+            .contains(&item.owner_id())
+}
+
+/// Returns the row level security configuration of `item` if its policies
+/// apply to `role_id`.
+fn enforced_for_role(
+    catalog: &dyn SessionCatalog,
+    item: &dyn CatalogItem,
+    role_id: &RoleId,
+) -> Result<Option<RowLevelSecurity>, PlanError> {
+    // Avoid parsing the create SQL of every relation that is read, as only
+    // user objects that mention the option can have row level security.
+    if !item.id().is_user()
+        || !item.create_sql().contains("ROW LEVEL SECURITY")
+        || is_role_exempt(catalog, item, role_id)
+    {
+        return Ok(None);
+    }
+    Ok(RowLevelSecurity::from_item(item)?.filter(|rls| rls.enabled))
+}
+
+/// Returns the row level security configuration of `item` if its policies
+/// apply to the active role.
+fn enforced(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+) -> Result<Option<RowLevelSecurity>, PlanError> {
+    enforced_for_role(scx.catalog, item, scx.catalog.active_role_id())
+}
+
+/// Reports whether the row level security policies of `item` restrict the
+/// rows that `role_id` observes.
+///
+/// Reads that are not planned on behalf of `role_id`, like the delivery of
+/// notifications, must not pass rows of such relations on to `role_id`.
+pub fn restricts_role(
+    catalog: &dyn SessionCatalog,
+    item: &dyn CatalogItem,
+    role_id: &RoleId,
+) -> Result<bool, PlanError> {
+    Ok(enforced_for_role(catalog, item, role_id)?.is_some())
+}
+
+/// Returns an error if the row level security policies of `item` apply to the
+/// active role, for reads that cannot be filtered by the policies, like those
+/// of sinks.
+pub(crate) fn require_exempt(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    name: &FullItemName,
+) -> Result<(), PlanError> {
+    match enforced(scx, item)? {
+        Some(_) => Err(PlanError::RowLevelSecurityInMaintainedQuery {
+            name: name.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Plans the predicate that reads of `item` by the active role must be
+/// filtered by, if the relation's row level security policies apply to the
+/// active role.
+pub(crate) fn plan_enforced_predicate(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    name: &FullItemName,
+    desc: &RelationDesc,
+    lifetime: QueryLifetime,
+) -> Result<Option<HirScalarExpr>, PlanError> {
+    let Some(rls) = enforced(scx, item)? else {
+        return Ok(None);
+    };
+    match lifetime {
+        QueryLifetime::OneShot | QueryLifetime::Subscribe => {}
+        QueryLifetime::Index
+        | QueryLifetime::MaterializedView
+        | QueryLifetime::View
+        | QueryLifetime::Source => {
+            return Err(PlanError::RowLevelSecurityInMaintainedQuery {
+                name: name.to_string(),
+            });
+        }
+    }
+    let table_name = PartialItemName::from(name.clone());
+    let mut predicates = vec![];
+    for policy in rls.policies {
+        let (policy, _) = names::resolve(scx.catalog, policy)?;
+        predicates.push(query::plan_row_level_security_policy(
+            scx,
+            lifetime,
+            Some(table_name.clone()),
+            desc,
+            &policy.using,
+        )?);
+    }
+    // Relations without policies hide all of their rows.
+    Ok(Some(HirScalarExpr::variadic_or(predicates)))
+}
+
+/// Validates the row level security options of a `CREATE` statement for a
+/// relation with the given name and description.
+pub(crate) fn plan_options(
+    scx: &StatementContext,
+    name: &QualifiedItemName,
+    desc: &RelationDesc,
+    enabled: Option<bool>,
+    policies: Option<&[RowLevelSecurityPolicy<Aug>]>,
+) -> Result<(), PlanError> {
+    if enabled.is_none() && policies.is_none() {
+        return Ok(());
+    }
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    if name.qualifiers.schema_spec == SchemaSpecifier::Temporary {
+        sql_bail!("temporary objects do not support row level security");
+    }
+    let name = scx.catalog.resolve_full_name(name);
+    let policies = policies.unwrap_or_default();
+    for (i, policy) in policies.iter().enumerate() {
+        if policies[..i].iter().any(|p| p.name == policy.name) {
+            return Err(PlanError::RowLevelSecurityPolicyAlreadyExists {
+                name: policy.name.to_string(),
+                relation: name.to_string(),
+            });
+        }
+        plan_policy(scx, &name, desc, &policy.using)?;
+    }
+    Ok(())
+}
+
+/// Validates the `USING` predicate of a row level security policy on the
+/// relation with the given name and description.
+pub(crate) fn plan_policy(
+    scx: &StatementContext,
+    name: &FullItemName,
+    desc: &RelationDesc,
+    using: &Expr<Aug>,
+) -> Result<(), PlanError> {
+    // Policies are re-resolved whenever the relation is read, so they must
+    // not depend on objects that can be dropped or renamed.
+    for id in names::visit_dependencies(scx.catalog, using).items() {
+        if !id.is_system() {
+            let item = scx.catalog.get_item(id);
+            return Err(PlanError::RowLevelSecurityPolicyDependency {
+                name: scx.catalog.resolve_full_name(item.name()).to_string(),
+            });
+        }
+    }
+    query::plan_row_level_security_policy(
+        scx,
+        QueryLifetime::OneShot,
+        Some(PartialItemName::from(name.clone())),
+        desc,
+        using,
+    )?;
+    Ok(())
+}
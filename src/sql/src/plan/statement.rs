@@ -51,7 +51,7 @@ mod tcl;
 mod validate;
 
 use crate::session::vars;
-pub(crate) use ddl::PgConfigOptionExtracted;
+pub(crate) use ddl::{PgConfigOptionExtracted, PostgresSinkConfigOptionExtracted};
 use mz_controller_types::ClusterId;
 use mz_pgrepr::oid::{FIRST_MATERIALIZE_OID, FIRST_USER_OID};
 use mz_repr::role_id::RoleId;
@@ -133,6 +133,9 @@ pub fn describe(
         Statement::AlterObjectSwap(stmt) => ddl::describe_alter_object_swap(&scx, stmt)?,
         Statement::AlterRetainHistory(stmt) => ddl::describe_alter_retain_history(&scx, stmt)?,
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
+        Statement::AlterRowLevelSecurity(stmt) => {
+            ddl::describe_alter_row_level_security(&scx, stmt)?
+        }
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSetCluster(stmt) => ddl::describe_alter_set_cluster(&scx, stmt)?,
         Statement::AlterSink(stmt) => ddl::describe_alter_sink(&scx, stmt)?,
@@ -165,8 +168,12 @@ pub fn describe(
             ddl::describe_create_materialized_view(&scx, stmt)?
         }
         Statement::CreateNetworkPolicy(stmt) => ddl::describe_create_network_policy(&scx, stmt)?,
+        Statement::CreatePolicy(stmt) => ddl::describe_create_policy(&scx, stmt)?,
+        Statement::CreateSnapshot(stmt) => ddl::describe_create_snapshot(&scx, stmt)?,
+        Statement::CreateNotification(stmt) => ddl::describe_create_notification(&scx, stmt)?,
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropOwned(stmt) => ddl::describe_drop_owned(&scx, stmt)?,
+        Statement::DropPolicy(stmt) => ddl::describe_drop_policy(&scx, stmt)?,
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::describe_alter_owner(&scx, stmt)?,
@@ -217,6 +224,8 @@ pub fn describe(
         // SCL statements.
         Statement::Close(stmt) => scl::describe_close(&scx, stmt)?,
         Statement::Deallocate(stmt) => scl::describe_deallocate(&scx, stmt)?,
+        Statement::Listen(stmt) => scl::describe_listen(&scx, stmt)?,
+        Statement::Unlisten(stmt) => scl::describe_unlisten(&scx, stmt)?,
         Statement::Declare(stmt) => scl::describe_declare(&scx, stmt, param_types_in)?,
         Statement::Discard(stmt) => scl::describe_discard(&scx, stmt)?,
         Statement::Execute(stmt) => scl::describe_execute(&scx, stmt)?,
@@ -338,6 +347,7 @@ pub fn plan(
         Statement::AlterObjectSwap(stmt) => ddl::plan_alter_object_swap(scx, stmt),
         Statement::AlterRetainHistory(stmt) => ddl::plan_alter_retain_history(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
+        Statement::AlterRowLevelSecurity(stmt) => ddl::plan_alter_row_level_security(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSetCluster(stmt) => ddl::plan_alter_item_set_cluster(scx, stmt),
         Statement::AlterSink(stmt) => ddl::plan_alter_sink(scx, stmt),
@@ -366,8 +376,12 @@ pub fn plan(
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt),
         Statement::CreateMaterializedView(stmt) => ddl::plan_create_materialized_view(scx, stmt),
         Statement::CreateNetworkPolicy(stmt) => ddl::plan_create_network_policy(scx, stmt),
+        Statement::CreatePolicy(stmt) => ddl::plan_create_policy(scx, stmt),
+        Statement::CreateSnapshot(stmt) => ddl::plan_create_snapshot(scx, stmt),
+        Statement::CreateNotification(stmt) => ddl::plan_create_notification(scx, stmt),
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropOwned(stmt) => ddl::plan_drop_owned(scx, stmt),
+        Statement::DropPolicy(stmt) => ddl::plan_drop_policy(scx, stmt),
 
         // `ACL` statements.
         Statement::AlterOwner(stmt) => acl::plan_alter_owner(scx, stmt),
@@ -430,6 +444,8 @@ pub fn plan(
         // SCL statements.
         Statement::Close(stmt) => scl::plan_close(scx, stmt),
         Statement::Deallocate(stmt) => scl::plan_deallocate(scx, stmt),
+        Statement::Listen(stmt) => scl::plan_listen(scx, stmt),
+        Statement::Unlisten(stmt) => scl::plan_unlisten(scx, stmt),
         Statement::Declare(stmt) => scl::plan_declare(scx, stmt, params),
         Statement::Discard(stmt) => scl::plan_discard(scx, stmt),
         Statement::Execute(stmt) => scl::plan_execute(scx, stmt),
@@ -1062,6 +1078,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterNetworkPolicy(_) => DDL,
             Statement::AlterRetainHistory(_) => DDL,
             Statement::AlterRole(_) => DDL,
+            Statement::AlterRowLevelSecurity(_) => DDL,
             Statement::AlterSecret(_) => DDL,
             Statement::AlterSetCluster(_) => DDL,
             Statement::AlterSink(_) => DDL,
@@ -1089,8 +1106,12 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateView(_) => DDL,
             Statement::CreateMaterializedView(_) => DDL,
             Statement::CreateNetworkPolicy(_) => DDL,
+            Statement::CreatePolicy(_) => DDL,
+            Statement::CreateSnapshot(_) => DDL,
+            Statement::CreateNotification(_) => DDL,
             Statement::DropObjects(_) => DDL,
             Statement::DropOwned(_) => DDL,
+            Statement::DropPolicy(_) => DDL,
 
             // `ACL` statements.
             Statement::AlterOwner(_) => ACL,
@@ -1131,6 +1152,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             // SCL statements.
             Statement::Close(_) => SCL,
             Statement::Deallocate(_) => SCL,
+            Statement::Listen(_) => SCL,
+            Statement::Unlisten(_) => SCL,
             Statement::Declare(_) => SCL,
             Statement::Discard(_) => SCL,
             Statement::Execute(_) => SCL,
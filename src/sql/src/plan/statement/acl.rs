@@ -25,6 +25,7 @@ use crate::catalog::{
 use crate::names::{
     Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
+use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::{
     resolve_cluster, resolve_database, resolve_item_or_type, resolve_network_policy, resolve_schema,
@@ -425,6 +426,33 @@ fn plan_update_privilege(
     target: GrantTargetSpecification<Aug>,
     roles: Vec<ResolvedRoleName>,
 ) -> Result<UpdatePrivilegesPlan, PlanError> {
+    let columns = match &privileges {
+        PrivilegeSpecification::Columns(privileges) => {
+            if !matches!(
+                &target,
+                GrantTargetSpecification::Object {
+                    object_spec_inner: GrantTargetSpecificationInner::Objects { .. },
+                    ..
+                }
+            ) {
+                sql_bail!("privileges on columns can only be granted on named relations");
+            }
+            if privileges
+                .iter()
+                .any(|privilege| privilege.privilege != Privilege::SELECT)
+            {
+                sql_bail!("only the SELECT privilege can be granted on columns");
+            }
+            let columns: BTreeSet<_> = privileges
+                .iter()
+                .flat_map(|privilege| privilege.columns.iter())
+                .map(|column| normalize::column_name(column.clone()))
+                .collect();
+            Some(columns)
+        }
+        PrivilegeSpecification::All | PrivilegeSpecification::Privileges(_) => None,
+    };
+
     let (object_type, target_ids) = match target {
         GrantTargetSpecification::Object {
             object_type,
@@ -575,6 +603,32 @@ fn plan_update_privilege(
         // `REVOKE ALL ON TABLE <view>` expands to the full TABLE privilege set
         // (SELECT|INSERT|UPDATE|DELETE) rather than only the privileges valid
         // for a view on its own (SELECT).
+        if let Some(columns) = &columns {
+            let SystemObjectId::Object(ObjectId::Item(id)) = &target_id else {
+                sql_bail!("privileges on columns can only be granted on relations");
+            };
+            if object_type != SystemObjectType::Object(ObjectType::Table) {
+                sql_bail!("privileges on columns can only be granted on relations");
+            }
+            let item = scx.get_item(id);
+            let object_name = scx.catalog.resolve_full_name(item.name());
+            if item.name().qualifiers.schema_spec == SchemaSpecifier::Temporary {
+                sql_bail!("temporary objects do not support privileges on columns");
+            }
+            let Some(desc) = item.relation_desc() else {
+                sql_bail!("{} does not have columns", object_name.to_string().quoted());
+            };
+            for column in columns {
+                if desc.get_by_name(column).is_none() {
+                    return Err(PlanError::UnknownColumn {
+                        table: Some(object_name.clone().into()),
+                        column: column.clone(),
+                        similar: Box::new([]),
+                    });
+                }
+            }
+        }
+
         let acl_mode = privilege_spec_to_acl_mode(scx, &privileges, reference_object_type);
         let acl_from_all = matches!(privileges, PrivilegeSpecification::All);
 
@@ -608,6 +662,7 @@ fn plan_update_privilege(
             target_id,
             grantor,
             acl_from_all,
+            columns: columns.iter().flatten().cloned().collect(),
         });
     }
 
@@ -631,6 +686,10 @@ fn privilege_spec_to_acl_mode(
             .map(|privilege| privilege_to_acl_mode(privilege.clone()))
             // PostgreSQL doesn't care about duplicate privileges, so we don't either.
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
+        PrivilegeSpecification::Columns(privileges) => privileges
+            .into_iter()
+            .map(|privilege| privilege_to_acl_mode(privilege.privilege.clone()))
+            .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
     }
 }
 
@@ -665,6 +724,9 @@ pub fn plan_alter_default_privileges(
     }: AlterDefaultPrivilegesStatement<Aug>,
 ) -> Result<Plan, PlanError> {
     let object_type: ObjectType = (*grant_or_revoke.object_type()).into();
+    if let PrivilegeSpecification::Columns(_) = grant_or_revoke.privileges() {
+        sql_bail!("ALTER DEFAULT PRIVILEGES does not support privileges on columns");
+    }
     match object_type {
         ObjectType::View | ObjectType::MaterializedView | ObjectType::Source => sql_bail!(
             "{object_type}S is not valid for ALTER DEFAULT PRIVILEGES, use TABLES instead"
@@ -30,9 +30,11 @@ use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::num::NonNeg;
 use mz_ore::str::StrExt;
 use mz_ore::{soft_assert_or_log, soft_panic_or_log};
+use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams};
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::bytes::ByteSize;
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
@@ -47,78 +49,105 @@ use mz_sql_parser::ast::{
     AlterConnectionOptionName, AlterConnectionStatement, AlterIndexAction, AlterIndexStatement,
     AlterMaterializedViewApplyReplacementStatement, AlterNetworkPolicyStatement,
     AlterObjectRenameStatement, AlterObjectSwapStatement, AlterRetainHistoryStatement,
-    AlterRoleOption, AlterRoleStatement, AlterSecretStatement, AlterSetClusterStatement,
-    AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
-    AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAddColumnStatement, AvroSchema,
-    AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
-    ClusterAlterOptionValue, ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
+    AlterRoleOption, AlterRoleStatement, AlterRowLevelSecurityStatement, AlterSecretStatement,
+    AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement, AlterSourceAction,
+    AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName, AlterSourceStatement,
+    AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
+    AlterTableAddColumnStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName,
+    ClusterAlterOption, ClusterAlterOptionName, ClusterAlterOptionValue,
+    ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
     ClusterAutoScalingStrategyOptionValue, ClusterFeature, ClusterFeatureName, ClusterOption,
-    ClusterOptionName, ClusterScheduleOptionValue, ColumnDef, ColumnOption, CommentObjectType,
-    CommentStatement, ConnectionOption, ConnectionOptionName, CreateClusterReplicaStatement,
-    CreateClusterStatement, CreateConnectionOption, CreateConnectionOptionName,
-    CreateConnectionStatement, CreateConnectionType, CreateDatabaseStatement, CreateIndexStatement,
-    CreateMaterializedViewStatement, CreateNetworkPolicyStatement, CreateRoleStatement,
-    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
-    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceOption,
-    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
-    CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
-    CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
-    CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
-    CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
-    DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
-    FormatSpecifier, GlueAvroOption, GlueAvroOptionName, IcebergSinkConfigOption, Ident,
-    IfExistsBehavior, IndexOption, IndexOptionName, KafkaSinkConfigOption, KeyConstraint,
-    LoadGeneratorOption, LoadGeneratorOptionName, MaterializedViewOption,
-    MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, NetworkPolicyOption,
-    NetworkPolicyOptionName, NetworkPolicyRuleDefinition, NetworkPolicyRuleOption,
-    NetworkPolicyRuleOptionName, OnHydrationOptionValue, PgConfigOption, PgConfigOptionName,
-    ProtobufSchema, QualifiedReplica, RefreshAtOptionValue, RefreshEveryOptionValue,
-    RefreshOptionValue, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
-    SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata, SqlServerConfigOption,
-    SqlServerConfigOptionName, Statement, TableConstraint, TableFromSourceColumns,
-    TableFromSourceOption, TableFromSourceOptionName, TableOption, TableOptionName,
-    UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
-    ViewDefinition, WithOptionValue,
+    ClusterOptionName, ClusterScheduleOptionValue, ColumnDef, ColumnOption, ColumnPrivilege,
+    CommentObjectType, CommentStatement, ConnectionOption, ConnectionOptionName,
+    CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionOption,
+    CreateConnectionOptionName, CreateConnectionStatement, CreateConnectionType,
+    CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
+    CreateNetworkPolicyStatement, CreateNotificationStatement, CreatePolicyStatement,
+    CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection,
+    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSnapshotStatement,
+    CreateSourceConnection, CreateSourceOption, CreateSourceOptionName, CreateSourceStatement,
+    CreateSubsourceOption, CreateSubsourceOptionName, CreateSubsourceStatement,
+    CreateTableFromSourceStatement, CreateTableStatement, CreateTypeAs, CreateTypeListOption,
+    CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement,
+    CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName,
+    CsrConnection, CsrConnectionAvro, CsrConnectionJson, CsrConnectionProtobuf, CsrSeedJson,
+    CsrSeedProtobuf, CsvColumns, DeferredItemName, DocOnIdentifier, DocOnSchema,
+    DropObjectsStatement, DropOwnedStatement, DropPolicyStatement, Expr, Format, FormatSpecifier,
+    GlueAvroOption, GlueAvroOptionName, HttpSinkConfigOption, IcebergConfigOption,
+    IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption, IndexOptionName,
+    KafkaSinkConfigOption, KeyConstraint, KinesisConfigOption, LoadGeneratorOption,
+    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName,
+    MongoDbConfigOption, MqttConfigOption, MySqlConfigOption, MySqlConfigOptionName,
+    NatsConfigOption, NetworkPolicyOption, NetworkPolicyOptionName, NetworkPolicyRuleDefinition,
+    NetworkPolicyRuleOption, NetworkPolicyRuleOptionName, OnHydrationOptionValue, PgConfigOption,
+    PgConfigOptionName, PostgresSinkConfigOption, ProtobufSchema, QualifiedReplica,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition,
+    ReplicaOption, ReplicaOptionName, RoleAttribute, RowLevelSecurityPolicy, S3ConfigOption,
+    S3SinkConfigOption, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
+    SqlServerConfigOption, SqlServerConfigOptionName, Statement, TableConstraint,
+    TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName, TableOption,
+    TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
+    UnresolvedSchemaName, Value, ViewDefinition, ViewOption, ViewOptionName, WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
 use mz_storage_types::connections::inline::ReferencedConnection;
+use mz_storage_types::connections::string_or_secret::StringOrSecret;
 use mz_storage_types::connections::{Connection, KafkaTopicOptions};
+use mz_storage_types::oneshot_sources::ContentFormat;
 use mz_storage_types::sinks::{
-    IcebergSinkConnection, KafkaIdStyle, KafkaSinkConnection, KafkaSinkFormat, KafkaSinkFormatType,
-    SinkEnvelope, StorageSinkConnection, iceberg_type_overrides,
+    HTTP_SINK_DEFAULT_BATCH_SIZE, HTTP_SINK_DEFAULT_SIGNING_HEADER, HttpSinkBodyFormat,
+    HttpSinkConnection, HttpSinkSigning, IcebergSinkConnection, KafkaIdStyle, KafkaSinkConnection,
+    KafkaSinkFormat, KafkaSinkFormatType, MAX_S3_SINK_FILE_SIZE, MIN_S3_SINK_FILE_SIZE,
+    PostgresSinkConnection, S3_SINK_DEFAULT_MAX_FILE_SIZE, S3_SINK_DEFAULT_ROLL_INTERVAL,
+    S3_SINK_DIFF_COLUMN, S3_SINK_TIMESTAMP_COLUMN, S3SinkConnection, S3SinkFormat, SinkEnvelope,
+    StorageSinkConnection, iceberg_type_overrides,
 };
 use mz_storage_types::sources::encoding::{
-    AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, ProtobufEncoding, RegexEncoding,
-    SourceDataEncoding, included_column_desc,
+    AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, JsonSchemaEncoding, ProtobufEncoding,
+    RegexEncoding, SourceDataEncoding, included_column_desc,
 };
 use mz_storage_types::sources::envelope::{
     KeyEnvelope, NoneEnvelope, SourceEnvelope, UnplannedSourceEnvelope, UpsertStyle,
 };
+use mz_storage_types::sources::iceberg::{IcebergSourceConnection, IcebergSourceExportDetails};
 use mz_storage_types::sources::kafka::{
-    KafkaMetadataKind, KafkaSourceConnection, KafkaSourceExportDetails, kafka_metadata_columns_desc,
+    KafkaMetadataKind, KafkaSourceConnection, KafkaSourceExportDetails, KafkaTopicSet,
+    kafka_metadata_columns_desc,
+};
+use mz_storage_types::sources::kinesis::{
+    KinesisMetadataKind, KinesisSourceConnection, KinesisSourceExportDetails,
+    kinesis_metadata_columns_desc,
 };
 use mz_storage_types::sources::load_generator::{
     KeyValueLoadGenerator, LOAD_GENERATOR_KEY_VALUE_OFFSET_DEFAULT, LoadGenerator,
     LoadGeneratorOutput, LoadGeneratorSourceConnection, LoadGeneratorSourceExportDetails,
 };
+use mz_storage_types::sources::mongodb::{MongoDbSourceConnection, MongoDbSourceExportDetails};
+use mz_storage_types::sources::mqtt::{
+    MqttMetadataKind, MqttSourceConnection, MqttSourceExportDetails, mqtt_metadata_columns_desc,
+};
 use mz_storage_types::sources::mysql::{
-    MySqlSourceConnection, MySqlSourceDetails, ProtoMySqlSourceDetails,
+    MySqlMetadataKind, MySqlSourceConnection, MySqlSourceDetails, ProtoMySqlSourceDetails,
+    mysql_metadata_columns_desc,
+};
+use mz_storage_types::sources::nats::{
+    NatsMetadataKind, NatsSourceConnection, NatsSourceExportDetails, nats_metadata_columns_desc,
 };
 use mz_storage_types::sources::postgres::{
-    PostgresSourceConnection, PostgresSourcePublicationDetails,
-    ProtoPostgresSourcePublicationDetails,
+    PostgresMetadataKind, PostgresSourceConnection, PostgresSourcePublicationDetails,
+    ProtoPostgresSourcePublicationDetails, postgres_metadata_columns_desc,
 };
+use mz_storage_types::sources::s3::{S3SourceConnection, S3SourceExportDetails};
 use mz_storage_types::sources::sql_server::{
-    ProtoSqlServerSourceExtras, SqlServerSourceExportDetails,
+    ProtoSqlServerSourceExtras, SqlServerMetadataKind, SqlServerSourceExportDetails,
+    sql_server_metadata_columns_desc,
 };
 use mz_storage_types::sources::{
-    GenericSourceConnection, MySqlSourceExportDetails, PostgresSourceExportDetails,
-    ProtoSourceExportStatementDetails, SourceConnection, SourceDesc, SourceExportDataConfig,
-    SourceExportDetails, SourceExportStatementDetails, SqlServerSourceConnection,
-    SqlServerSourceExtras, Timeline,
+    GenericSourceConnection, MySqlSourceExportDetails, PostgresMessagesSourceExportDetails,
+    PostgresSourceExportDetails, ProtoSourceExportStatementDetails, SchemaChangePolicy,
+    SourceConnection, SourceDesc, SourceExportDataConfig, SourceExportDetails,
+    SourceExportStatementDetails, SqlServerSourceConnection, SqlServerSourceExtras, Timeline,
 };
 use mz_storage_types::wire_format::WireFormat;
 use prost::Message;
@@ -128,47 +157,56 @@ use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
     CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
 };
-use crate::iceberg::IcebergSinkConfigOptionExtracted;
+use crate::iceberg::{IcebergSinkConfigOptionExtracted, plan_iceberg_partition_by};
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
-    Aug, CommentObjectId, DatabaseId, DependencyIds, ObjectId, PartialItemName, QualifiedItemName,
-    ResolvedClusterName, ResolvedColumnReference, ResolvedDataType, ResolvedDatabaseSpecifier,
-    ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier, SystemObjectId,
+    Aug, CommentObjectId, DatabaseId, DependencyIds, FullItemName, ItemQualifiers, ObjectId,
+    PartialItemName, QualifiedItemName, ResolvedClusterName, ResolvedColumnReference,
+    ResolvedDataType, ResolvedDatabaseSpecifier, ResolvedItemName, ResolvedNetworkPolicyName,
+    SchemaSpecifier, SystemObjectId,
 };
 use crate::normalize::{self, ident};
+use crate::plan::column_privileges;
 use crate::plan::error::PlanError;
 use crate::plan::query::{
     ExprContext, QueryLifetime, TypeResolutionBudget, plan_expr, scalar_type_from_sql,
 };
+use crate::plan::row_level_security::{self, RowLevelSecurity};
 use crate::plan::scope::Scope;
 use crate::plan::statement::ddl::connection::{INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS};
 use crate::plan::statement::{StatementContext, StatementDesc, scl};
 use crate::plan::typeconv::CastContext;
-use crate::plan::with_options::{OptionalDuration, OptionalString, TryFromValue};
+use crate::plan::with_options::{self, OptionalDuration, OptionalString, TryFromValue};
 use crate::plan::{
     AlterClusterPlan, AlterClusterPlanStrategy, AlterClusterRenamePlan,
     AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterConnectionPlan, AlterItemRenamePlan,
     AlterMaterializedViewApplyReplacementPlan, AlterNetworkPolicyPlan, AlterNoopPlan,
-    AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterSchemaRenamePlan,
-    AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan,
-    AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
+    AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterRowLevelSecurityPlan,
+    AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan,
+    AlterSinkPlan, AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
     AlterSystemSetPlan, AlterTablePlan, AutoScalingStrategy, ClusterSchedule, CommentPlan,
     ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
     CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan, CreateDatabasePlan,
-    CreateIndexPlan, CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan,
-    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
-    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan,
-    HirRelationExpr, Index, MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction,
-    NetworkPolicyRuleDirection, OnHydration, Plan, PlanClusterOption, PlanNotice, PolicyAddress,
-    QueryContext, ReplicaConfig, Secret, Sink, Source, Table, TableDataSource, Type, VariableValue,
-    View, WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal,
-    plan_utils, query, transform_ast,
+    CreateIndexPlan, CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateNotificationPlan,
+    CreatePolicyPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
+    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
+    DropObjectsPlan, DropOwnedPlan, DropPolicyPlan, HirRelationExpr, Index, MaterializedView,
+    NetworkPolicyRule, NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Notification,
+    OnHydration, Plan, PlanClusterOption, PlanNotice, PolicyAddress, QueryContext, ReplicaConfig,
+    Secret, Sink, Source, Table, TableDataSource, Type, VariableValue, View, WebhookBodyFormat,
+    WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal, plan_utils, query,
+    transform_ast,
 };
 use crate::session::vars::{
-    self, ENABLE_AUTO_SCALING_STRATEGY, ENABLE_CLUSTER_SCHEDULE_REFRESH,
-    ENABLE_COLLECTION_PARTITION_BY, ENABLE_CREATE_TABLE_FROM_SOURCE, ENABLE_KAFKA_SINK_HEADERS,
-    ENABLE_REFRESH_EVERY_MVS, ENABLE_REPLICA_TARGETED_MATERIALIZED_VIEWS, VarInput,
+    self, ENABLE_AUTO_SCALING_STRATEGY, ENABLE_AVRO_DECODE_OPTIONS,
+    ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_COLLECTION_PARTITION_BY,
+    ENABLE_CREATE_TABLE_FROM_SOURCE, ENABLE_HTTP_SINK, ENABLE_ICEBERG_SOURCE,
+    ENABLE_JSON_ARRAY_FORMAT, ENABLE_JSON_SCHEMA_FORMAT, ENABLE_KAFKA_SINK_HEADERS,
+    ENABLE_KAFKA_SINK_PROTOBUF_FORMAT, ENABLE_KAFKA_SINK_TOPIC_BY, ENABLE_KINESIS_SOURCE,
+    ENABLE_MONGODB_SOURCE, ENABLE_MQTT_SOURCE, ENABLE_NATS_SOURCE, ENABLE_POSTGRES_SINK,
+    ENABLE_REFRESH_EVERY_MVS, ENABLE_REPLICA_TARGETED_MATERIALIZED_VIEWS, ENABLE_S3_SINK,
+    ENABLE_S3_SOURCE, VarInput,
 };
 use crate::{names, parse};
 
@@ -477,7 +515,7 @@ pub fn plan_create_table(
     // TODO(alter_table): Will need to reconsider this when we support ALTERing
     // the PARTITION BY columns.
     let original_desc = desc.at_version(RelationVersionSelector::Specific(RelationVersion::root()));
-    let options = plan_table_options(scx, &original_desc, with_options.clone())?;
+    let options = plan_table_options(scx, &name, &original_desc, with_options.clone())?;
 
     let compaction_window = options.iter().find_map(|o| {
         #[allow(irrefutable_let_patterns)]
@@ -502,6 +540,93 @@ pub fn plan_create_table(
     }))
 }
 
+pub fn describe_create_snapshot(
+    _: &StatementContext,
+    _: CreateSnapshotStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_snapshot(
+    scx: &StatementContext,
+    mut stmt: CreateSnapshotStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_CREATE_SNAPSHOT)?;
+
+    let CreateSnapshotStatement {
+        name,
+        if_not_exists,
+        from,
+        as_of,
+    } = &stmt;
+    let if_not_exists = *if_not_exists;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?;
+
+    // Check for an object in the catalog with this same name
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    // For PostgreSQL compatibility, we need to prevent creating tables when
+    // there is an existing object *or* type of the same name.
+    if let (false, Ok(item)) = (
+        if_not_exists,
+        scx.catalog.resolve_item_or_type(&partial_name),
+    ) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    let from = scx.get_item_by_resolved_name(from)?;
+    if from.item_type() != CatalogItemType::MaterializedView {
+        sql_bail!(
+            "cannot create snapshot of {} {}; only materialized views can be snapshotted",
+            from.item_type(),
+            scx.catalog.minimal_qualification(from.name()),
+        );
+    }
+    // The snapshot is a table owned by the role that creates it, so it would
+    // expose every row and column of the materialized view to that role.
+    row_level_security::require_exempt(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
+    column_privileges::require_all_columns(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
+    let desc = from
+        .relation_desc()
+        .expect("materialized views have a desc")
+        .into_owned();
+    let as_of = query::plan_as_of_or_up_to(scx, as_of.clone())?;
+
+    // Record the evaluated timestamp rather than the original expression, so
+    // that re-planning the `create_sql` on restart cannot be influenced by
+    // session state such as the `TimeZone`.
+    stmt.as_of = Expr::Value(Value::Number(as_of.to_string()));
+    let create_sql = normalize::create_statement(scx, Statement::CreateSnapshot(stmt))?;
+
+    let table = Table {
+        create_sql,
+        desc: VersionedRelationDesc::new(desc),
+        temporary: false,
+        compaction_window: None,
+        data_source: TableDataSource::Snapshot {
+            from: from.global_id(),
+            as_of,
+        },
+    };
+    Ok(Plan::CreateTable(CreateTablePlan {
+        name,
+        table,
+        if_not_exists,
+    }))
+}
+
 pub fn describe_create_table_from_source(
     _: &StatementContext,
     _: CreateTableFromSourceStatement<Aug>,
@@ -533,7 +658,10 @@ pub fn describe_create_subsource(
 generate_extracted_config!(
     CreateSourceOption,
     (TimestampInterval, Duration),
-    (RetainHistory, OptionalDuration)
+    (RetainHistory, OptionalDuration),
+    (RowLevelSecurity, bool),
+    (RowLevelSecurityPolicies, Vec<RowLevelSecurityPolicy<Aug>>),
+    (ColumnPrivileges, Vec<ColumnPrivilege>)
 );
 
 generate_extracted_config!(
@@ -558,6 +686,73 @@ generate_extracted_config!(
     (ExcludeColumns, Vec::<UnresolvedItemName>, Default(vec![]))
 );
 
+generate_extracted_config!(MongoDbConfigOption, (Database, String));
+
+generate_extracted_config!(
+    PostgresSinkConfigOption,
+    (Schema, String, Default("public".to_string())),
+    (Table, String)
+);
+
+generate_extracted_config!(
+    HttpSinkConfigOption,
+    (Url, String),
+    (
+        Headers,
+        BTreeMap<String, StringOrSecret>,
+        Default(BTreeMap::new())
+    ),
+    (SigningSecret, with_options::Secret),
+    (
+        SigningHeader,
+        String,
+        Default(HTTP_SINK_DEFAULT_SIGNING_HEADER.to_string())
+    ),
+    (BodyFormat, String, Default("json".to_string())),
+    (BatchSize, u64, Default(HTTP_SINK_DEFAULT_BATCH_SIZE))
+);
+
+generate_extracted_config!(
+    S3SinkConfigOption,
+    (Uri, String),
+    (Format, String),
+    (
+        MaxFileSize,
+        ByteSize,
+        Default(S3_SINK_DEFAULT_MAX_FILE_SIZE)
+    ),
+    (
+        RollInterval,
+        Duration,
+        Default(S3_SINK_DEFAULT_ROLL_INTERVAL)
+    )
+);
+
+generate_extracted_config!(IcebergConfigOption, (Namespace, String));
+
+generate_extracted_config!(KinesisConfigOption, (Stream, String));
+
+generate_extracted_config!(
+    NatsConfigOption,
+    (Stream, String),
+    (Consumer, String),
+    (Subject, String)
+);
+
+generate_extracted_config!(MqttConfigOption, (Topic, String), (SharedGroup, String));
+
+generate_extracted_config!(
+    S3ConfigOption,
+    (Uri, String),
+    (Pattern, String),
+    (Format, String),
+    (Header, bool),
+    (Delimiter, String),
+    (Quote, String),
+    (Escape, String),
+    (Null, String)
+);
+
 pub fn plan_create_webhook_source(
     scx: &StatementContext,
     mut stmt: CreateWebhookSourceStatement<Aug>,
@@ -821,6 +1016,9 @@ pub fn plan_create_source(
     let CreateSourceOptionExtracted {
         timestamp_interval,
         retain_history,
+        row_level_security,
+        row_level_security_policies,
+        column_privileges,
         seen: _,
     } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
 
@@ -940,7 +1138,17 @@ pub fn plan_create_source(
                 },
                 GenericSourceConnection::Postgres(_)
                 | GenericSourceConnection::MySql(_)
-                | GenericSourceConnection::SqlServer(_) => SourceExportDetails::None,
+                | GenericSourceConnection::SqlServer(_)
+                | GenericSourceConnection::MongoDb(_)
+                | GenericSourceConnection::S3(_)
+                | GenericSourceConnection::Iceberg(_) => SourceExportDetails::None,
+                // These sources only output to tables, which purification enforces.
+                GenericSourceConnection::Kinesis(_)
+                | GenericSourceConnection::Nats(_)
+                | GenericSourceConnection::Mqtt(_) => sql_bail!(
+                    "[internal error] {} sources do not support the legacy source syntax",
+                    external_connection.name()
+                ),
             };
 
             let data_source = DataSourceDesc::OldSyntaxIngestion {
@@ -985,6 +1193,16 @@ pub fn plan_create_source(
         });
     }
 
+    row_level_security::plan_options(
+        scx,
+        &name,
+        &desc,
+        row_level_security,
+        row_level_security_policies.as_deref(),
+    )?;
+
+    column_privileges::plan_options(scx, &name, &desc, column_privileges.as_deref())?;
+
     // We will rewrite the cluster if one is not provided, so we must use the
     // `in_cluster` value we plan to normalize when we canonicalize the create
     // statement.
@@ -1033,6 +1251,20 @@ pub fn plan_generic_source_connection(
             options,
             include_metadata,
         )?),
+        CreateSourceConnection::Kinesis {
+            connection,
+            options,
+        } => GenericSourceConnection::Kinesis(plan_kinesis_source_connection(
+            scx, connection, options,
+        )?),
+        CreateSourceConnection::Nats {
+            connection,
+            options,
+        } => GenericSourceConnection::Nats(plan_nats_source_connection(scx, connection, options)?),
+        CreateSourceConnection::Mqtt {
+            connection,
+            options,
+        } => GenericSourceConnection::Mqtt(plan_mqtt_source_connection(scx, connection, options)?),
         CreateSourceConnection::Postgres {
             connection,
             options,
@@ -1051,6 +1283,24 @@ pub fn plan_generic_source_connection(
         } => {
             GenericSourceConnection::MySql(plan_mysql_source_connection(scx, connection, options)?)
         }
+        CreateSourceConnection::MongoDb {
+            connection,
+            options,
+        } => GenericSourceConnection::MongoDb(plan_mongodb_source_connection(
+            scx, connection, options,
+        )?),
+        CreateSourceConnection::S3 {
+            connection,
+            options,
+        } => GenericSourceConnection::S3(plan_s3_source_connection(scx, connection, options)?),
+        CreateSourceConnection::Iceberg {
+            catalog_connection,
+            options,
+        } => GenericSourceConnection::Iceberg(plan_iceberg_source_connection(
+            scx,
+            catalog_connection,
+            options,
+        )?),
         CreateSourceConnection::LoadGenerator { generator, options } => {
             GenericSourceConnection::LoadGenerator(plan_load_generator_source_connection(
                 scx,
@@ -1154,6 +1404,332 @@ fn plan_sqlserver_source_connection(
     })
 }
 
+fn plan_mongodb_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<MongoDbConfigOption<Aug>>,
+) -> Result<MongoDbSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_MONGODB_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::MongoDb(connection) => connection,
+        _ => sql_bail!(
+            "{} is not a MongoDB connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let MongoDbConfigOptionExtracted { database, .. } = options.clone().try_into()?;
+    let database = database.ok_or_else(|| sql_err!("DATABASE option is required"))?;
+    Ok(MongoDbSourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        database,
+    })
+}
+
+fn plan_iceberg_source_connection(
+    scx: &StatementContext<'_>,
+    catalog_connection: &ResolvedItemName,
+    options: &Vec<IcebergConfigOption<Aug>>,
+) -> Result<IcebergSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_ICEBERG_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(catalog_connection)?;
+    match connection_item.connection()? {
+        Connection::IcebergCatalog(connection) => connection,
+        _ => sql_bail!(
+            "{} is not an iceberg catalog connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let IcebergConfigOptionExtracted { namespace, .. } = options.clone().try_into()?;
+    let namespace = namespace.ok_or_else(|| sql_err!("NAMESPACE option is required"))?;
+    Ok(IcebergSourceConnection {
+        catalog_connection_id: connection_item.id(),
+        catalog_connection: connection_item.id(),
+        namespace,
+    })
+}
+
+fn plan_kinesis_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<KinesisConfigOption<Aug>>,
+) -> Result<KinesisSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_KINESIS_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::Aws(connection) => connection,
+        _ => sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let KinesisConfigOptionExtracted { stream, .. } = options.clone().try_into()?;
+    let stream = stream.ok_or_else(|| sql_err!("STREAM option is required"))?;
+    Ok(KinesisSourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        stream,
+    })
+}
+
+/// Plans the `INCLUDE` clause of a Kinesis source table. The key of a record is its
+/// partition key, the partition is the shard it was read from and the offset is its sequence
+/// number.
+fn plan_kinesis_metadata_columns(
+    include_metadata: &[SourceIncludeMetadata],
+) -> Result<Vec<(String, KinesisMetadataKind)>, PlanError> {
+    let mut metadata_columns = Vec::with_capacity(include_metadata.len());
+    for item in include_metadata {
+        let (alias, default_name, kind) = match item {
+            SourceIncludeMetadata::Timestamp { alias } => {
+                (alias, "timestamp", KinesisMetadataKind::Timestamp)
+            }
+            SourceIncludeMetadata::Partition { alias } => {
+                (alias, "partition", KinesisMetadataKind::Partition)
+            }
+            SourceIncludeMetadata::Offset { alias } => {
+                (alias, "offset", KinesisMetadataKind::Offset)
+            }
+            // Handled by the key envelope.
+            SourceIncludeMetadata::Key { .. } => continue,
+            SourceIncludeMetadata::Topic { .. }
+            | SourceIncludeMetadata::Headers { .. }
+            | SourceIncludeMetadata::Header { .. }
+            | SourceIncludeMetadata::CommitTimestamp { .. }
+            | SourceIncludeMetadata::Lsn { .. }
+            | SourceIncludeMetadata::Gtid { .. }
+            | SourceIncludeMetadata::TransactionId { .. } => bail_unsupported!(format!(
+                "INCLUDE {} with Kinesis sources",
+                item.to_ast_string_simple()
+            )),
+        };
+        let name = match alias {
+            Some(name) => name.to_string(),
+            None => default_name.to_owned(),
+        };
+        metadata_columns.push((name, kind));
+    }
+    Ok(metadata_columns)
+}
+
+fn plan_nats_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<NatsConfigOption<Aug>>,
+) -> Result<NatsSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_NATS_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::Nats(connection) => connection,
+        _ => sql_bail!(
+            "{} is not a NATS connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let NatsConfigOptionExtracted {
+        stream,
+        consumer,
+        subject,
+        ..
+    } = options.clone().try_into()?;
+    let stream = stream.ok_or_else(|| sql_err!("STREAM option is required"))?;
+    Ok(NatsSourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        stream,
+        consumer,
+        subject,
+    })
+}
+
+/// Plans the `INCLUDE` clause of a NATS source table. The key of a message is its
+/// subject and the offset is its sequence number in the stream.
+fn plan_nats_metadata_columns(
+    include_metadata: &[SourceIncludeMetadata],
+) -> Result<Vec<(String, NatsMetadataKind)>, PlanError> {
+    let mut metadata_columns = Vec::with_capacity(include_metadata.len());
+    for item in include_metadata {
+        let (alias, default_name, kind) = match item {
+            SourceIncludeMetadata::Timestamp { alias } => {
+                (alias, "timestamp", NatsMetadataKind::Timestamp)
+            }
+            SourceIncludeMetadata::Offset { alias } => (alias, "offset", NatsMetadataKind::Offset),
+            SourceIncludeMetadata::Headers { alias } => {
+                (alias, "headers", NatsMetadataKind::Headers)
+            }
+            // Handled by the key envelope.
+            SourceIncludeMetadata::Key { .. } => continue,
+            SourceIncludeMetadata::Topic { .. }
+            | SourceIncludeMetadata::Partition { .. }
+            | SourceIncludeMetadata::Header { .. }
+            | SourceIncludeMetadata::CommitTimestamp { .. }
+            | SourceIncludeMetadata::Lsn { .. }
+            | SourceIncludeMetadata::Gtid { .. }
+            | SourceIncludeMetadata::TransactionId { .. } => bail_unsupported!(format!(
+                "INCLUDE {} with NATS sources",
+                item.to_ast_string_simple()
+            )),
+        };
+        let name = match alias {
+            Some(name) => name.to_string(),
+            None => default_name.to_owned(),
+        };
+        metadata_columns.push((name, kind));
+    }
+    Ok(metadata_columns)
+}
+
+fn plan_mqtt_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<MqttConfigOption<Aug>>,
+) -> Result<MqttSourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_MQTT_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::Mqtt(connection) => connection,
+        _ => sql_bail!(
+            "{} is not an MQTT connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let MqttConfigOptionExtracted {
+        topic,
+        shared_group,
+        ..
+    } = options.clone().try_into()?;
+    let topic = topic.ok_or_else(|| sql_err!("TOPIC option is required"))?;
+    Ok(MqttSourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        topic,
+        shared_group,
+    })
+}
+
+/// Plans the `INCLUDE` clause of an MQTT source table. The key of a message is its
+/// topic and its headers are its user properties.
+fn plan_mqtt_metadata_columns(
+    include_metadata: &[SourceIncludeMetadata],
+) -> Result<Vec<(String, MqttMetadataKind)>, PlanError> {
+    let mut metadata_columns = Vec::with_capacity(include_metadata.len());
+    for item in include_metadata {
+        let (alias, default_name, kind) = match item {
+            SourceIncludeMetadata::Headers { alias } => {
+                (alias, "headers", MqttMetadataKind::Headers)
+            }
+            // Handled by the key envelope.
+            SourceIncludeMetadata::Key { .. } => continue,
+            SourceIncludeMetadata::Topic { .. }
+            | SourceIncludeMetadata::Partition { .. }
+            | SourceIncludeMetadata::Offset { .. }
+            | SourceIncludeMetadata::Timestamp { .. }
+            | SourceIncludeMetadata::Header { .. }
+            | SourceIncludeMetadata::CommitTimestamp { .. }
+            | SourceIncludeMetadata::Lsn { .. }
+            | SourceIncludeMetadata::Gtid { .. }
+            | SourceIncludeMetadata::TransactionId { .. } => bail_unsupported!(format!(
+                "INCLUDE {} with MQTT sources",
+                item.to_ast_string_simple()
+            )),
+        };
+        let name = match alias {
+            Some(name) => name.to_string(),
+            None => default_name.to_owned(),
+        };
+        metadata_columns.push((name, kind));
+    }
+    Ok(metadata_columns)
+}
+
+fn plan_s3_source_connection(
+    scx: &StatementContext<'_>,
+    connection: &ResolvedItemName,
+    options: &Vec<S3ConfigOption<Aug>>,
+) -> Result<S3SourceConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_S3_SOURCE)?;
+    let connection_item = scx.get_item_by_resolved_name(connection)?;
+    match connection_item.connection()? {
+        Connection::Aws(connection) => connection,
+        _ => sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+    let S3ConfigOptionExtracted {
+        uri,
+        pattern,
+        format,
+        header,
+        delimiter,
+        quote,
+        escape,
+        null,
+        ..
+    } = options.clone().try_into()?;
+
+    let uri = uri.ok_or_else(|| sql_err!("URI option is required"))?;
+    let uri = parse_s3_source_uri(&uri)?;
+
+    let format = match format.map(|f| f.to_lowercase()).as_deref() {
+        Some("csv") => {
+            let param = |v: Option<String>, name: &str| match v {
+                Some(v) if v.len() == 1 => Ok(Some(v.as_bytes()[0])),
+                Some(_) => sql_bail!("{name} must be a single one-byte character"),
+                None => Ok(None),
+            };
+            let params = CopyCsvFormatParams::try_new(
+                param(delimiter, "DELIMITER")?,
+                param(quote, "QUOTE")?,
+                param(escape, "ESCAPE")?,
+                header,
+                null,
+            )
+            .map_err(|e| sql_err!("{}", e))?;
+            ContentFormat::Csv(params)
+        }
+        Some("parquet") => {
+            if header.is_some()
+                || delimiter.is_some()
+                || quote.is_some()
+                || escape.is_some()
+                || null.is_some()
+            {
+                sql_bail!(
+                    "HEADER, DELIMITER, QUOTE, ESCAPE and NULL are only supported with FORMAT CSV"
+                );
+            }
+            ContentFormat::Parquet
+        }
+        Some(other) => bail_unsupported!(format!("FORMAT {other} with S3 sources")),
+        None => sql_bail!("FORMAT option is required"),
+    };
+
+    Ok(S3SourceConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        uri: uri.to_string(),
+        pattern,
+        format,
+    })
+}
+
+/// Parses the `URI` option of an S3 source. The source lists the objects under the bucket and
+/// prefix of the URI, so it must name at least a bucket.
+pub(crate) fn parse_s3_source_uri(uri: &str) -> Result<http::Uri, PlanError> {
+    let uri = uri
+        .parse::<http::Uri>()
+        .map_err(|e| sql_err!("invalid URI: {e}"))?;
+    if uri.scheme_str() != Some("s3") {
+        sql_bail!("only 's3://...' URIs are supported by S3 sources");
+    }
+    if uri.host().is_none() {
+        sql_bail!("missing bucket name from 's3://...' URI");
+    }
+    Ok(uri)
+}
+
 fn plan_postgres_source_connection(
     scx: &StatementContext<'_>,
     connection: &ResolvedItemName,
@@ -1206,13 +1782,33 @@ fn plan_kafka_source_connection(
     let KafkaSourceConfigOptionExtracted {
         group_id_prefix,
         topic,
+        topics,
+        topic_pattern,
         topic_metadata_refresh_interval,
         start_timestamp: _, // purified into `start_offset`
         start_offset,
         seen: _,
     }: KafkaSourceConfigOptionExtracted = options.clone().try_into()?;
     // Validated during purification.
-    let topic = topic.ok_or_else(|| internal_err!("TOPIC option is required"))?;
+    let (topic, topic_set) = match (topic, topics, topic_pattern) {
+        (Some(topic), None, None) => (topic, None),
+        (None, Some(topics), None) => {
+            let topic = topics
+                .first()
+                .cloned()
+                .ok_or_else(|| sql_err!("TOPICS must list at least one topic"))?;
+            (topic, Some(KafkaTopicSet::List(topics)))
+        }
+        (None, None, Some(pattern)) => {
+            let topic_set = KafkaTopicSet::Pattern(pattern.clone());
+            if let Err(e) = topic_set.matcher() {
+                sql_bail!("invalid TOPIC PATTERN: {e}");
+            }
+            (pattern, Some(topic_set))
+        }
+        (None, None, None) => Err(internal_err!("TOPIC option is required"))?,
+        _ => sql_bail!("only one of TOPIC, TOPICS, or TOPIC PATTERN may be specified"),
+    };
     let mut start_offsets = BTreeMap::new();
     if let Some(offsets) = start_offset {
         for (part, offset) in offsets.iter().enumerate() {
@@ -1222,6 +1818,9 @@ fn plan_kafka_source_connection(
             start_offsets.insert(i32::try_from(part)?, *offset);
         }
     }
+    if topic_set.is_some() && !start_offsets.is_empty() {
+        bail_unsupported!("START OFFSET with TOPICS or TOPIC PATTERN");
+    }
     if topic_metadata_refresh_interval > Duration::from_secs(60 * 60) {
         // This is a librdkafka-enforced restriction that, if violated,
         // would result in a runtime error for the source.
@@ -1232,6 +1831,7 @@ fn plan_kafka_source_connection(
         // would result in a runtime error for the source.
         sql_bail!("TOPIC METADATA REFRESH INTERVAL must be at least 1 second");
     }
+    reject_cdc_metadata(include_metadata, "Kafka")?;
     let metadata_columns = include_metadata
         .into_iter()
         .flat_map(|item| match item {
@@ -1249,6 +1849,13 @@ fn plan_kafka_source_connection(
                 };
                 Some((name, KafkaMetadataKind::Partition))
             }
+            SourceIncludeMetadata::Topic { alias } => {
+                let name = match alias {
+                    Some(name) => name.to_string(),
+                    None => "topic".to_owned(),
+                };
+                Some((name, KafkaMetadataKind::Topic))
+            }
             SourceIncludeMetadata::Offset { alias } => {
                 let name = match alias {
                     Some(name) => name.to_string(),
@@ -1278,12 +1885,20 @@ fn plan_kafka_source_connection(
                 // handled below
                 None
             }
+            SourceIncludeMetadata::CommitTimestamp { .. }
+            | SourceIncludeMetadata::Lsn { .. }
+            | SourceIncludeMetadata::Gtid { .. }
+            | SourceIncludeMetadata::TransactionId { .. } => {
+                // rejected above
+                None
+            }
         })
         .collect();
     Ok(KafkaSourceConnection {
         connection: connection_item.id(),
         connection_id: connection_item.id(),
         topic,
+        topic_set,
         start_offsets,
         group_id_prefix,
         topic_metadata_refresh_interval,
@@ -1291,7 +1906,85 @@ fn plan_kafka_source_connection(
     })
 }
 
-fn apply_source_envelope_encoding(
+/// The transaction metadata that change-data-capture sources can expose via `INCLUDE`.
+#[derive(Clone, Copy, Debug)]
+enum CdcMetadataKind {
+    CommitTimestamp,
+    Lsn,
+    Gtid,
+    TransactionId,
+}
+
+/// Returns an error if `include_metadata` requests transaction metadata, which only
+/// change-data-capture sources support.
+fn reject_cdc_metadata(
+    include_metadata: &[SourceIncludeMetadata],
+    source_type: &str,
+) -> Result<(), PlanError> {
+    for item in include_metadata {
+        if matches!(
+            item,
+            SourceIncludeMetadata::CommitTimestamp { .. }
+                | SourceIncludeMetadata::Lsn { .. }
+                | SourceIncludeMetadata::Gtid { .. }
+                | SourceIncludeMetadata::TransactionId { .. }
+        ) {
+            bail_unsupported!(format!(
+                "INCLUDE {} with {} sources",
+                item.to_ast_string_simple(),
+                source_type
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Plans the `INCLUDE` clause of a table created from a change-data-capture source.
+///
+/// `to_kind` maps each requested piece of transaction metadata to the source's own metadata kind,
+/// returning `None` if the source cannot provide it.
+fn plan_cdc_metadata_columns<K>(
+    include_metadata: &[SourceIncludeMetadata],
+    source_type: &str,
+    to_kind: impl Fn(CdcMetadataKind) -> Option<K>,
+) -> Result<Vec<(String, K)>, PlanError> {
+    let mut metadata_columns = Vec::with_capacity(include_metadata.len());
+    for item in include_metadata {
+        let (alias, default_name, kind) = match item {
+            SourceIncludeMetadata::CommitTimestamp { alias } => {
+                (alias, "commit_timestamp", CdcMetadataKind::CommitTimestamp)
+            }
+            SourceIncludeMetadata::Lsn { alias } => (alias, "lsn", CdcMetadataKind::Lsn),
+            SourceIncludeMetadata::Gtid { alias } => (alias, "gtid", CdcMetadataKind::Gtid),
+            SourceIncludeMetadata::TransactionId { alias } => {
+                (alias, "transaction_id", CdcMetadataKind::TransactionId)
+            }
+            SourceIncludeMetadata::Headers { .. } => {
+                sql_bail!("INCLUDE HEADERS with non-Kafka source table not supported")
+            }
+            _ => bail_unsupported!(format!(
+                "INCLUDE {} with {} source tables",
+                item.to_ast_string_simple(),
+                source_type
+            )),
+        };
+        let Some(kind) = to_kind(kind) else {
+            bail_unsupported!(format!(
+                "INCLUDE {} with {} source tables",
+                item.to_ast_string_simple(),
+                source_type
+            ));
+        };
+        let name = match alias {
+            Some(name) => name.to_string(),
+            None => default_name.to_owned(),
+        };
+        metadata_columns.push((name, kind));
+    }
+    Ok(metadata_columns)
+}
+
+fn apply_source_envelope_encoding(
     scx: &StatementContext,
     envelope: &ast::SourceEnvelope,
     format: &Option<FormatSpecifier<Aug>>,
@@ -1315,6 +2008,13 @@ fn apply_source_envelope_encoding(
 
     let (key_desc, value_desc) = match &encoding {
         Some(encoding) => {
+            // Only Kafka sources explode JSON arrays into rows during decoding.
+            if matches!(encoding.value, DataEncoding::JsonArray)
+                && !matches!(source_connection, GenericSourceConnection::Kafka(_))
+            {
+                sql_bail!("JSON ARRAY format is only supported for Kafka sources");
+            }
+
             // If we are applying an encoding we need to ensure that the incoming value_desc is a
             // single column of type bytes.
             match value_desc.typ().columns() {
@@ -1663,6 +2363,13 @@ pub fn plan_create_subsource(
                     cast_oid_full_range,
                 )?,
                 table,
+                metadata_columns: vec![],
+                retract_on_truncate: false,
+                exclude_columns: exclude_columns
+                    .iter()
+                    .map(|c| c.clone().into_string())
+                    .collect(),
+                schema_change_policy: SchemaChangePolicy::None,
             }),
             SourceExportStatementDetails::MySql {
                 table,
@@ -1677,6 +2384,8 @@ pub fn plan_create_subsource(
                     .map(|c| c.into_string())
                     .collect(),
                 binlog_full_metadata,
+                metadata_columns: vec![],
+                schema_change_policy: SchemaChangePolicy::None,
             }),
             SourceExportStatementDetails::SqlServer {
                 table,
@@ -1691,6 +2400,7 @@ pub fn plan_create_subsource(
                     .into_iter()
                     .map(|c| c.into_string())
                     .collect(),
+                metadata_columns: vec![],
             }),
             SourceExportStatementDetails::LoadGenerator { output } => {
                 SourceExportDetails::LoadGenerator(LoadGeneratorSourceExportDetails { output })
@@ -1698,6 +2408,31 @@ pub fn plan_create_subsource(
             SourceExportStatementDetails::Kafka {} => {
                 bail_unsupported!("subsources cannot reference Kafka sources")
             }
+            SourceExportStatementDetails::Kinesis {} => {
+                bail_unsupported!("subsources cannot reference Kinesis sources")
+            }
+            SourceExportStatementDetails::Nats {} => {
+                bail_unsupported!("subsources cannot reference NATS sources")
+            }
+            SourceExportStatementDetails::Mqtt {} => {
+                bail_unsupported!("subsources cannot reference MQTT sources")
+            }
+            SourceExportStatementDetails::S3 {} => {
+                bail_unsupported!("subsources cannot reference S3 sources")
+            }
+            SourceExportStatementDetails::Iceberg { .. } => {
+                bail_unsupported!("subsources cannot reference Iceberg sources")
+            }
+            SourceExportStatementDetails::PostgresMessages {} => {
+                SourceExportDetails::PostgresMessages(PostgresMessagesSourceExportDetails {})
+            }
+            SourceExportStatementDetails::MongoDb {
+                collection,
+                initial_cluster_time,
+            } => SourceExportDetails::MongoDb(MongoDbSourceExportDetails {
+                collection,
+                initial_cluster_time,
+            }),
         };
         DataSourceDesc::IngestionExport {
             ingestion_id,
@@ -1746,7 +2481,16 @@ generate_extracted_config!(
     (ExcludeColumns, Vec::<Ident>, Default(vec![])),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (Details, String)
+    (Details, String),
+    (RowLevelSecurity, bool),
+    (RowLevelSecurityPolicies, Vec<RowLevelSecurityPolicy<Aug>>),
+    (ColumnPrivileges, Vec<ColumnPrivilege>),
+    (RetractOnTruncate, bool, Default(false)),
+    (
+        SchemaChangePolicy,
+        SchemaChangePolicy,
+        Default(SchemaChangePolicy::None)
+    )
 );
 
 pub fn plan_create_table_from_source(
@@ -1778,11 +2522,27 @@ pub fn plan_create_table_from_source(
         retain_history,
         partition_by,
         details,
+        row_level_security,
+        row_level_security_policies,
+        column_privileges,
+        retract_on_truncate,
+        schema_change_policy,
         seen: _,
     } = with_options.clone().try_into()?;
 
     let source_item = scx.get_item_by_resolved_name(source)?;
     let ingestion_id = source_item.id();
+    // Like a sink, the table receives every row that the source ingests.
+    row_level_security::require_exempt(
+        scx,
+        scx.get_item(&ingestion_id),
+        &scx.catalog.resolve_full_name(source_item.name()),
+    )?;
+    column_privileges::require_all_columns(
+        scx,
+        scx.get_item(&ingestion_id),
+        &scx.catalog.resolve_full_name(source_item.name()),
+    )?;
 
     // Decode the details option stored on the statement, which contains information
     // created during the purification process.
@@ -1795,21 +2555,54 @@ pub fn plan_create_table_from_source(
     let details =
         SourceExportStatementDetails::from_proto(details).map_err(|e| sql_err!("{}", e))?;
 
-    if !matches!(details, SourceExportStatementDetails::Kafka { .. })
-        && include_metadata
-            .iter()
-            .any(|sic| matches!(sic, SourceIncludeMetadata::Headers { .. }))
+    if !matches!(
+        details,
+        SourceExportStatementDetails::Kafka { .. }
+            | SourceExportStatementDetails::Nats { .. }
+            | SourceExportStatementDetails::Mqtt { .. }
+    ) && include_metadata
+        .iter()
+        .any(|sic| matches!(sic, SourceIncludeMetadata::Headers { .. }))
     {
         // TODO(guswynn): should this be `bail_unsupported!`?
         sql_bail!("INCLUDE HEADERS with non-Kafka source table not supported");
     }
-    if !matches!(
-        details,
-        SourceExportStatementDetails::Kafka { .. }
-            | SourceExportStatementDetails::LoadGenerator { .. }
-    ) && !include_metadata.is_empty()
+    if matches!(details, SourceExportStatementDetails::LoadGenerator { .. }) {
+        reject_cdc_metadata(include_metadata, "load generator")?;
+    }
+    if matches!(details, SourceExportStatementDetails::MySql { .. })
+        && include_metadata
+            .iter()
+            .any(|sic| matches!(sic, SourceIncludeMetadata::TransactionId { .. }))
     {
-        bail_unsupported!("INCLUDE metadata with non-Kafka source table");
+        // MySQL only reports a transaction's XID after all of its row events, so there is nothing
+        // to stamp rows with as they are decoded.
+        sql_bail!(
+            "INCLUDE TRANSACTION ID is not supported with MySQL source tables; \
+             use INCLUDE GTID to identify the upstream transaction instead"
+        );
+    }
+    if retract_on_truncate && !matches!(details, SourceExportStatementDetails::Postgres { .. }) {
+        sql_bail!("RETRACT ON TRUNCATE is only supported with PostgreSQL source tables");
+    }
+    if schema_change_policy != SchemaChangePolicy::None {
+        if !matches!(
+            details,
+            SourceExportStatementDetails::Postgres { .. }
+                | SourceExportStatementDetails::MySql { .. }
+        ) {
+            sql_bail!(
+                "SCHEMA CHANGE POLICY is only supported with PostgreSQL and MySQL source tables"
+            );
+        }
+        // Added columns are appended to the table, which would place them after the metadata
+        // columns and break the layout of the rows the source produces.
+        if let Some(item) = include_metadata.first() {
+            bail_unsupported!(format!(
+                "INCLUDE {} with SCHEMA CHANGE POLICY",
+                item.to_ast_string_simple()
+            ));
+        }
     }
 
     let details = match details {
@@ -1824,6 +2617,20 @@ pub fn plan_create_table_from_source(
                 cast_oid_full_range,
             )?,
             table,
+            metadata_columns: plan_cdc_metadata_columns(include_metadata, "PostgreSQL", |kind| {
+                match kind {
+                    CdcMetadataKind::CommitTimestamp => Some(PostgresMetadataKind::CommitTimestamp),
+                    CdcMetadataKind::Lsn => Some(PostgresMetadataKind::Lsn),
+                    CdcMetadataKind::Gtid => None,
+                    CdcMetadataKind::TransactionId => Some(PostgresMetadataKind::TransactionId),
+                }
+            })?,
+            retract_on_truncate,
+            exclude_columns: exclude_columns
+                .iter()
+                .map(|c| c.clone().into_string())
+                .collect(),
+            schema_change_policy,
         }),
         SourceExportStatementDetails::MySql {
             table,
@@ -1838,6 +2645,16 @@ pub fn plan_create_table_from_source(
                 .map(|c| c.into_string())
                 .collect(),
             binlog_full_metadata,
+            metadata_columns: plan_cdc_metadata_columns(
+                include_metadata,
+                "MySQL",
+                |kind| match kind {
+                    CdcMetadataKind::CommitTimestamp => Some(MySqlMetadataKind::CommitTimestamp),
+                    CdcMetadataKind::Gtid => Some(MySqlMetadataKind::Gtid),
+                    CdcMetadataKind::Lsn | CdcMetadataKind::TransactionId => None,
+                },
+            )?,
+            schema_change_policy,
         }),
         SourceExportStatementDetails::SqlServer {
             table,
@@ -1852,10 +2669,91 @@ pub fn plan_create_table_from_source(
                 .into_iter()
                 .map(|c| c.into_string())
                 .collect(),
+            metadata_columns: plan_cdc_metadata_columns(include_metadata, "SQL Server", |kind| {
+                match kind {
+                    CdcMetadataKind::CommitTimestamp => {
+                        Some(SqlServerMetadataKind::CommitTimestamp)
+                    }
+                    CdcMetadataKind::Lsn => Some(SqlServerMetadataKind::Lsn),
+                    CdcMetadataKind::Gtid => None,
+                    CdcMetadataKind::TransactionId => Some(SqlServerMetadataKind::TransactionId),
+                }
+            })?,
         }),
         SourceExportStatementDetails::LoadGenerator { output } => {
             SourceExportDetails::LoadGenerator(LoadGeneratorSourceExportDetails { output })
         }
+        SourceExportStatementDetails::PostgresMessages {} => {
+            if !text_columns.is_empty() || !exclude_columns.is_empty() {
+                sql_bail!(
+                    "TEXT COLUMNS and EXCLUDE COLUMNS are not supported with PostgreSQL logical \
+                     message tables"
+                );
+            }
+            if let Some(item) = include_metadata.first() {
+                bail_unsupported!(format!(
+                    "INCLUDE {} with PostgreSQL logical message tables",
+                    item.to_ast_string_simple()
+                ));
+            }
+            SourceExportDetails::PostgresMessages(PostgresMessagesSourceExportDetails {})
+        }
+        SourceExportStatementDetails::MongoDb {
+            collection,
+            initial_cluster_time,
+        } => {
+            if !text_columns.is_empty() || !exclude_columns.is_empty() {
+                sql_bail!(
+                    "TEXT COLUMNS and EXCLUDE COLUMNS are not supported with MongoDB source tables"
+                );
+            }
+            if let Some(item) = include_metadata.first() {
+                bail_unsupported!(format!(
+                    "INCLUDE {} with MongoDB source tables",
+                    item.to_ast_string_simple()
+                ));
+            }
+            SourceExportDetails::MongoDb(MongoDbSourceExportDetails {
+                collection,
+                initial_cluster_time,
+            })
+        }
+        SourceExportStatementDetails::S3 {} => {
+            if !text_columns.is_empty() || !exclude_columns.is_empty() {
+                sql_bail!(
+                    "TEXT COLUMNS and EXCLUDE COLUMNS are not supported with S3 source tables"
+                );
+            }
+            if let Some(item) = include_metadata.first() {
+                bail_unsupported!(format!(
+                    "INCLUDE {} with S3 source tables",
+                    item.to_ast_string_simple()
+                ));
+            }
+            // Objects are decoded according to the FORMAT of the source, directly into the
+            // columns of the table.
+            if format.is_some() {
+                sql_bail!("FORMAT cannot be specified for S3 source tables");
+            }
+            if !matches!(envelope, ast::SourceEnvelope::None) {
+                bail_unsupported!(format!("ENVELOPE {} with S3 source tables", envelope));
+            }
+            SourceExportDetails::S3(S3SourceExportDetails {})
+        }
+        SourceExportStatementDetails::Iceberg { table, table_uuid } => {
+            if !text_columns.is_empty() || !exclude_columns.is_empty() {
+                sql_bail!(
+                    "TEXT COLUMNS and EXCLUDE COLUMNS are not supported with Iceberg source tables"
+                );
+            }
+            if let Some(item) = include_metadata.first() {
+                bail_unsupported!(format!(
+                    "INCLUDE {} with Iceberg source tables",
+                    item.to_ast_string_simple()
+                ));
+            }
+            SourceExportDetails::Iceberg(IcebergSourceExportDetails { table, table_uuid })
+        }
         SourceExportStatementDetails::Kafka {} => {
             if !include_metadata.is_empty()
                 && !matches!(
@@ -1868,6 +2766,7 @@ pub fn plan_create_table_from_source(
                 // TODO(guswynn): should this be `bail_unsupported!`?
                 sql_bail!("INCLUDE <metadata> requires ENVELOPE (NONE|UPSERT|DEBEZIUM)");
             }
+            reject_cdc_metadata(include_metadata, "Kafka")?;
 
             let metadata_columns = include_metadata
                 .into_iter()
@@ -1886,6 +2785,13 @@ pub fn plan_create_table_from_source(
                         };
                         Some((name, KafkaMetadataKind::Partition))
                     }
+                    SourceIncludeMetadata::Topic { alias } => {
+                        let name = match alias {
+                            Some(name) => name.to_string(),
+                            None => "topic".to_owned(),
+                        };
+                        Some((name, KafkaMetadataKind::Topic))
+                    }
                     SourceIncludeMetadata::Offset { alias } => {
                         let name = match alias {
                             Some(name) => name.to_string(),
@@ -1915,11 +2821,60 @@ pub fn plan_create_table_from_source(
                         // handled below
                         None
                     }
+                    SourceIncludeMetadata::CommitTimestamp { .. }
+                    | SourceIncludeMetadata::Lsn { .. }
+                    | SourceIncludeMetadata::Gtid { .. }
+                    | SourceIncludeMetadata::TransactionId { .. } => {
+                        // rejected above
+                        None
+                    }
                 })
                 .collect();
 
             SourceExportDetails::Kafka(KafkaSourceExportDetails { metadata_columns })
         }
+        SourceExportStatementDetails::Kinesis {} => {
+            if !include_metadata.is_empty()
+                && !matches!(
+                    envelope,
+                    ast::SourceEnvelope::Upsert { .. }
+                        | ast::SourceEnvelope::None
+                        | ast::SourceEnvelope::Debezium
+                )
+            {
+                sql_bail!("INCLUDE <metadata> requires ENVELOPE (NONE|UPSERT|DEBEZIUM)");
+            }
+            let metadata_columns = plan_kinesis_metadata_columns(include_metadata)?;
+            SourceExportDetails::Kinesis(KinesisSourceExportDetails { metadata_columns })
+        }
+        SourceExportStatementDetails::Nats {} => {
+            if !include_metadata.is_empty()
+                && !matches!(
+                    envelope,
+                    ast::SourceEnvelope::Upsert { .. }
+                        | ast::SourceEnvelope::None
+                        | ast::SourceEnvelope::Debezium
+                )
+            {
+                sql_bail!("INCLUDE <metadata> requires ENVELOPE (NONE|UPSERT|DEBEZIUM)");
+            }
+            let metadata_columns = plan_nats_metadata_columns(include_metadata)?;
+            SourceExportDetails::Nats(NatsSourceExportDetails { metadata_columns })
+        }
+        SourceExportStatementDetails::Mqtt {} => {
+            if !include_metadata.is_empty()
+                && !matches!(
+                    envelope,
+                    ast::SourceEnvelope::Upsert { .. }
+                        | ast::SourceEnvelope::None
+                        | ast::SourceEnvelope::Debezium
+                )
+            {
+                sql_bail!("INCLUDE <metadata> requires ENVELOPE (NONE|UPSERT|DEBEZIUM)");
+            }
+            let metadata_columns = plan_mqtt_metadata_columns(include_metadata)?;
+            SourceExportDetails::Mqtt(MqttSourceExportDetails { metadata_columns })
+        }
     };
 
     let source_connection = &source_item
@@ -1931,24 +2886,70 @@ pub fn plan_create_table_from_source(
     // during purification and define the `columns` and `constraints` fields for the statement,
     // whereas other source-types (e.g. kafka, single-output load-gen sources) do not, so instead
     // we use the source connection's default schema.
-    let (key_desc, value_desc) =
-        if matches!(columns, TableFromSourceColumns::Defined(_)) || !constraints.is_empty() {
-            let columns = match columns {
-                TableFromSourceColumns::Defined(columns) => columns,
-                _ => bail_internal!("expected column definitions to be present"),
-            };
-            let desc = plan_source_export_desc(scx, name, columns, constraints)?;
-            (None, desc)
-        } else {
-            let key_desc = source_connection.default_key_desc();
-            let value_desc = source_connection.default_value_desc();
-            (Some(key_desc), value_desc)
+    //
+    // Columns that were added to the table after it was created are planned separately and only
+    // added to the description once it is otherwise complete, since they make up later versions of
+    // the table.
+    let mut added_columns = BTreeMap::new();
+    let (key_desc, value_desc) = if matches!(columns, TableFromSourceColumns::Defined(_))
+        || !constraints.is_empty()
+    {
+        let columns = match columns {
+            TableFromSourceColumns::Defined(columns) => columns,
+            _ => bail_internal!("expected column definitions to be present"),
         };
+        let (versioned_columns, columns): (Vec<_>, Vec<_>) =
+            columns.iter().cloned().partition(|c| {
+                c.options
+                    .iter()
+                    .any(|o| matches!(o.option, ColumnOption::Versioned { .. }))
+            });
+        for c in versioned_columns {
+            let typ = query::scalar_type_from_sql(scx, &c.data_type)?.nullable(true);
+            for option in &c.options {
+                match &option.option {
+                    ColumnOption::Versioned { action: _, version } => {
+                        let column_name = normalize::column_name(c.name.clone());
+                        added_columns
+                            .insert(RelationVersion::from(*version), (column_name, typ.clone()));
+                    }
+                    other => bail_unsupported!(format!(
+                        "Source export with column constraint on an added column: {}",
+                        other
+                    )),
+                }
+            }
+        }
+        let desc = plan_source_export_desc(scx, name, &columns, constraints)?;
+        (None, desc)
+    } else {
+        let key_desc = source_connection.default_key_desc();
+        let value_desc = source_connection.default_value_desc();
+        (Some(key_desc), value_desc)
+    };
 
     let metadata_columns_desc = match &details {
         SourceExportDetails::Kafka(KafkaSourceExportDetails {
             metadata_columns, ..
         }) => kafka_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::Kinesis(KinesisSourceExportDetails {
+            metadata_columns, ..
+        }) => kinesis_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::Nats(NatsSourceExportDetails {
+            metadata_columns, ..
+        }) => nats_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::Mqtt(MqttSourceExportDetails {
+            metadata_columns, ..
+        }) => mqtt_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::Postgres(PostgresSourceExportDetails {
+            metadata_columns, ..
+        }) => postgres_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::MySql(MySqlSourceExportDetails {
+            metadata_columns, ..
+        }) => mysql_metadata_columns_desc(metadata_columns),
+        SourceExportDetails::SqlServer(SqlServerSourceExportDetails {
+            metadata_columns, ..
+        }) => sql_server_metadata_columns_desc(metadata_columns),
         _ => vec![],
     };
 
@@ -1973,6 +2974,18 @@ pub fn plan_create_table_from_source(
 
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.clone())?)?;
 
+    let mut versioned_desc = VersionedRelationDesc::new(desc.clone());
+    for (version, (column_name, typ)) in added_columns {
+        if names.contains(&column_name) {
+            sql_bail!("column {} specified more than once", column_name.quoted());
+        }
+        if versioned_desc.add_column(column_name, typ) != version {
+            return Err(PlanError::InvalidTable {
+                name: scx.catalog.resolve_full_name(&name).item,
+            });
+        }
+    }
+
     // Allow users to specify a timeline. If they do not, determine a default
     // timeline for the source.
     let timeline = match envelope {
@@ -1987,6 +3000,16 @@ pub fn plan_create_table_from_source(
         check_partition_by(&desc, partition_by)?;
     }
 
+    row_level_security::plan_options(
+        scx,
+        &name,
+        &desc,
+        row_level_security,
+        row_level_security_policies.as_deref(),
+    )?;
+
+    column_privileges::plan_options(scx, &name, &desc, column_privileges.as_deref())?;
+
     let data_source = DataSourceDesc::IngestionExport {
         ingestion_id,
         // Populated during purification.
@@ -2005,7 +3028,7 @@ pub fn plan_create_table_from_source(
     let compaction_window = plan_retain_history_option(scx, retain_history)?;
     let table = Table {
         create_sql,
-        desc: VersionedRelationDesc::new(desc),
+        desc: versioned_desc,
         temporary: false,
         compaction_window,
         data_source: TableDataSource::DataSource {
@@ -2273,6 +3296,17 @@ fn get_encoding(
         sql_bail!("ENVELOPE [DEBEZIUM] UPSERT requires that KEY FORMAT be specified");
     };
 
+    // Each element of a JSON array becomes its own row, so the elements of a message can't be
+    // identified by the message key.
+    if matches!(encoding.key, Some(DataEncoding::JsonArray)) {
+        sql_bail!("JSON ARRAY format is not supported for keys");
+    }
+    if matches!(encoding.value, DataEncoding::JsonArray)
+        && !matches!(envelope, ast::SourceEnvelope::None)
+    {
+        sql_bail!("JSON ARRAY format requires ENVELOPE NONE");
+    }
+
     Ok(encoding)
 }
 
@@ -2326,6 +3360,10 @@ pub struct Schema {
     /// schemas from. Built directly by each `AvroSchema` variant rather
     /// than reconstructed from a (csr_connection, bool) pair downstream.
     pub wire_format: WireFormat<ReferencedConnection>,
+    /// The depth past which values are decoded as `jsonb`.
+    pub max_depth: Option<u32>,
+    /// Whether unions are decoded as a record of their variants.
+    pub union_as_record: bool,
 }
 
 fn get_encoding_inner(
@@ -2341,6 +3379,8 @@ fn get_encoding_inner(
                 key_reference_schemas,
                 value_reference_schemas,
                 wire_format,
+                max_depth,
+                union_as_record,
             } = match schema {
                 // TODO(jldlaughlin): we need a way to pass in primary key information
                 // when building a source from a string or file.
@@ -2363,6 +3403,8 @@ fn get_encoding_inner(
                         key_reference_schemas: vec![],
                         value_reference_schemas: vec![],
                         wire_format,
+                        max_depth: None,
+                        union_as_record: false,
                     }
                 }
                 AvroSchema::Csr {
@@ -2388,6 +3430,15 @@ fn get_encoding_inner(
                         }
                     };
 
+                    let options: CsrConfigOptionExtracted =
+                        connection.options.clone().try_into()?;
+                    if options.has_avro_decode_options() {
+                        scx.require_feature_flag(&ENABLE_AVRO_DECODE_OPTIONS)?;
+                    }
+                    if options.avro_max_depth == Some(0) {
+                        sql_bail!("AVRO MAX DEPTH must be greater than 0");
+                    }
+
                     if let Some(seed) = seed {
                         Schema {
                             key_schema: seed.key_schema.clone(),
@@ -2397,6 +3448,8 @@ fn get_encoding_inner(
                             wire_format: WireFormat::Confluent {
                                 registry: Some(csr_connection),
                             },
+                            max_depth: options.avro_max_depth,
+                            union_as_record: options.avro_union_as_record,
                         }
                     } else {
                         sql_bail!("Avro CSR seed resolution has not been performed")
@@ -2436,6 +3489,8 @@ fn get_encoding_inner(
                         wire_format: WireFormat::Glue {
                             registry: Some(glue_connection),
                         },
+                        max_depth: None,
+                        union_as_record: false,
                     }
                 }
             };
@@ -2446,11 +3501,15 @@ fn get_encoding_inner(
                         schema: key_schema,
                         reference_schemas: key_reference_schemas,
                         wire_format: wire_format.clone(),
+                        max_depth,
+                        union_as_record,
                     })),
                     value: DataEncoding::Avro(AvroEncoding {
                         schema: value_schema,
                         reference_schemas: value_reference_schemas,
                         wire_format,
+                        max_depth,
+                        union_as_record,
                     }),
                 });
             } else {
@@ -2458,6 +3517,8 @@ fn get_encoding_inner(
                     schema: value_schema,
                     reference_schemas: value_reference_schemas,
                     wire_format,
+                    max_depth,
+                    union_as_record,
                 })
             }
         }
@@ -2488,14 +3549,31 @@ fn get_encoding_inner(
                         }
                     };
 
-                    if !options.is_empty() {
-                        sql_bail!("Protobuf CSR connections do not support any options");
+                    // The message options are applied during purification, when
+                    // the message names are recorded in the seed.
+                    let options: CsrConfigOptionExtracted = options.clone().try_into()?;
+                    if options.avro_key_fullname.is_some()
+                        || options.avro_value_fullname.is_some()
+                        || options.null_defaults
+                        || !options.value_doc_options.is_empty()
+                        || !options.key_doc_options.is_empty()
+                        || options.key_compatibility_level.is_some()
+                        || options.value_compatibility_level.is_some()
+                        || options.has_avro_decode_options()
+                    {
+                        sql_bail!(
+                            "Protobuf CSR connections only support the KEY MESSAGE, VALUE MESSAGE and RECURSION LIMIT options"
+                        );
                     }
+                    let recursion_limit = options
+                        .recursion_limit
+                        .unwrap_or(mz_interchange::protobuf::DEFAULT_RECURSION_LIMIT);
 
                     let value = DataEncoding::Protobuf(ProtobufEncoding {
                         descriptors: strconv::parse_bytes(&value.schema)?,
                         message_name: value.message_name.clone(),
                         confluent_wire_format: true,
+                        recursion_limit,
                     });
                     if let Some(key) = key {
                         return Ok(SourceDataEncoding {
@@ -2503,6 +3581,7 @@ fn get_encoding_inner(
                                 descriptors: strconv::parse_bytes(&key.schema)?,
                                 message_name: key.message_name.clone(),
                                 confluent_wire_format: true,
+                                recursion_limit,
                             })),
                             value,
                         });
@@ -2522,6 +3601,7 @@ fn get_encoding_inner(
                     descriptors,
                     message_name: message_name.to_owned(),
                     confluent_wire_format: false,
+                    recursion_limit: mz_interchange::protobuf::DEFAULT_RECURSION_LIMIT,
                 })
             }
         },
@@ -2548,7 +3628,59 @@ fn get_encoding_inner(
             })
         }
         Format::Json { array: false } => DataEncoding::Json,
-        Format::Json { array: true } => bail_unsupported!("JSON ARRAY format in sources"),
+        Format::Json { array: true } => {
+            scx.require_feature_flag(&ENABLE_JSON_ARRAY_FORMAT)?;
+            DataEncoding::JsonArray
+        }
+        Format::JsonCsr {
+            csr_connection:
+                CsrConnectionJson {
+                    connection:
+                        CsrConnection {
+                            connection,
+                            options,
+                        },
+                    seed,
+                },
+        } => {
+            scx.require_feature_flag(&ENABLE_JSON_SCHEMA_FORMAT)?;
+            let Some(CsrSeedJson {
+                key_schema,
+                value_schema,
+            }) = seed
+            else {
+                sql_bail!("JSON CSR seed resolution has not been performed")
+            };
+            let item = scx.get_item_by_resolved_name(connection)?;
+            if !matches!(item.connection()?, Connection::Csr(_)) {
+                sql_bail!(
+                    "{} is not a schema registry connection",
+                    scx.catalog
+                        .resolve_full_name(item.name())
+                        .to_string()
+                        .quoted()
+                )
+            }
+
+            if !options.is_empty() {
+                sql_bail!("JSON CSR connections do not support any options");
+            }
+
+            let value = DataEncoding::JsonSchema(JsonSchemaEncoding {
+                schema: value_schema.clone(),
+                confluent_wire_format: true,
+            });
+            if let Some(key_schema) = key_schema {
+                return Ok(SourceDataEncoding {
+                    key: Some(DataEncoding::JsonSchema(JsonSchemaEncoding {
+                        schema: key_schema.clone(),
+                        confluent_wire_format: true,
+                    })),
+                    value,
+                });
+            }
+            value
+        }
         Format::Text => DataEncoding::Text,
     };
     Ok(SourceDataEncoding { key: None, value })
@@ -2595,10 +3727,13 @@ fn get_unnamed_key_envelope(
     //
     // Otherwise it gets the names of the columns in the type
     let is_composite = match key {
-        Some(DataEncoding::Bytes | DataEncoding::Json | DataEncoding::Text) => false,
+        Some(
+            DataEncoding::Bytes | DataEncoding::Json | DataEncoding::JsonArray | DataEncoding::Text,
+        ) => false,
         Some(
             DataEncoding::Avro(_)
             | DataEncoding::Csv(_)
+            | DataEncoding::JsonSchema(_)
             | DataEncoding::Protobuf(_)
             | DataEncoding::Regex { .. },
         ) => true,
@@ -2619,6 +3754,13 @@ pub fn describe_create_view(
     Ok(StatementDesc::new(None))
 }
 
+generate_extracted_config!(
+    ViewOption,
+    (RowLevelSecurity, bool),
+    (RowLevelSecurityPolicies, Vec<RowLevelSecurityPolicy<Aug>>),
+    (ColumnPrivileges, Vec<ColumnPrivilege>)
+);
+
 pub fn plan_view(
     scx: &StatementContext,
     def: &mut ViewDefinition<Aug>,
@@ -2636,6 +3778,7 @@ pub fn plan_view(
     let ViewDefinition {
         name,
         columns,
+        with_options,
         query,
     } = def;
 
@@ -2681,6 +3824,21 @@ pub fn plan_view(
         sql_bail!("column {} specified more than once", dup.quoted());
     }
 
+    let ViewOptionExtracted {
+        row_level_security,
+        row_level_security_policies,
+        column_privileges,
+        seen: _,
+    } = with_options.clone().try_into()?;
+    row_level_security::plan_options(
+        scx,
+        &name,
+        &desc,
+        row_level_security,
+        row_level_security_policies.as_deref(),
+    )?;
+    column_privileges::plan_options(scx, &name, &desc, column_privileges.as_deref())?;
+
     let view = View {
         create_sql,
         expr,
@@ -2850,7 +4008,11 @@ pub fn plan_create_materialized_view(
         normalize::create_statement(scx, Statement::CreateMaterializedView(stmt.clone()))?;
 
     let partial_name = normalize::unresolved_item_name(stmt.name)?;
-    let name = scx.allocate_qualified_name(partial_name.clone())?;
+    let name = if stmt.temporary {
+        scx.allocate_temporary_qualified_name(partial_name.clone())?
+    } else {
+        scx.allocate_qualified_name(partial_name.clone())?
+    };
 
     let query::PlannedRootQuery {
         expr,
@@ -2881,6 +4043,9 @@ pub fn plan_create_materialized_view(
         partition_by,
         retain_history,
         refresh,
+        row_level_security,
+        row_level_security_policies,
+        column_privileges,
         seen: _,
     }: MaterializedViewOptionExtracted = stmt.with_options.try_into()?;
 
@@ -2889,6 +4054,16 @@ pub fn plan_create_materialized_view(
         check_partition_by(&desc, partition_by)?;
     }
 
+    row_level_security::plan_options(
+        scx,
+        &name,
+        &desc,
+        row_level_security,
+        row_level_security_policies.as_deref(),
+    )?;
+
+    column_privileges::plan_options(scx, &name, &desc, column_privileges.as_deref())?;
+
     let refresh_schedule = {
         let mut refresh_schedule = RefreshSchedule::default();
         let mut on_commits_seen = 0;
@@ -3175,6 +4350,7 @@ pub fn plan_create_materialized_view(
             compaction_window,
             refresh_schedule,
             as_of,
+            temporary: stmt.temporary,
         },
         replace,
         drop_ids,
@@ -3188,7 +4364,10 @@ generate_extracted_config!(
     (AssertNotNull, Ident, AllowMultiple),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (Refresh, RefreshOptionValue<Aug>, AllowMultiple)
+    (Refresh, RefreshOptionValue<Aug>, AllowMultiple),
+    (RowLevelSecurity, bool),
+    (RowLevelSecurityPolicies, Vec<RowLevelSecurityPolicy<Aug>>),
+    (ColumnPrivileges, Vec<ColumnPrivilege>)
 );
 
 pub fn describe_create_sink(
@@ -3227,6 +4406,134 @@ pub fn plan_create_sink(
     plan_sink(scx, stmt)
 }
 
+pub fn describe_create_notification(
+    _: &StatementContext,
+    _: CreateNotificationStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+/// Plans a `CREATE NOTIFICATION` statement.
+///
+/// Notifications are not exported by storage. Their changes are delivered to
+/// the sessions listening on the notification's channel by a subscribe that
+/// the coordinator maintains.
+pub fn plan_create_notification(
+    scx: &StatementContext,
+    mut stmt: CreateNotificationStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_NOTIFICATIONS)?;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(stmt.name.clone())?)?;
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let (false, Ok(item)) = (stmt.if_not_exists, scx.catalog.resolve_item(&partial_name)) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+    ensure_notification_channel_unused(scx, &name, &name.item)?;
+
+    let from = scx.get_item_by_resolved_name(&stmt.on)?;
+    {
+        use CatalogItemType::*;
+        match from.item_type() {
+            Table | Source | MaterializedView | View => {
+                if from.replacement_target().is_some() {
+                    let name = scx.catalog.minimal_qualification(from.name());
+                    return Err(PlanError::InvalidSinkFrom {
+                        name: name.to_string(),
+                        item_type: format!("replacement {}", from.item_type()),
+                    });
+                }
+            }
+            Sink | Index | Type | Func | Secret | Connection => {
+                let name = scx.catalog.minimal_qualification(from.name());
+                return Err(PlanError::InvalidSinkFrom {
+                    name: name.to_string(),
+                    item_type: from.item_type().to_string(),
+                });
+            }
+        }
+    }
+    if from.id().is_system() {
+        bail_unsupported!("creating a notification directly on a catalog object");
+    }
+    row_level_security::require_exempt(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
+    column_privileges::require_all_columns(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
+
+    let desc = from
+        .relation_desc()
+        .ok_or_else(|| sql_err!("item does not have a relation description"))?
+        .into_owned();
+
+    // The predicate is evaluated by the subscribe that maintains the
+    // notification, so here we only ensure that it is a valid filter over the
+    // relation.
+    if let Some(selection) = &stmt.selection {
+        let scope = Scope::from_source(None, desc.iter_names());
+        let ecx = &ExprContext {
+            qcx: &QueryContext::root(scx, QueryLifetime::OneShot),
+            name: "CREATE NOTIFICATION",
+            scope: &scope,
+            relation_type: desc.typ(),
+            allow_aggregates: false,
+            allow_subqueries: false,
+            allow_parameters: false,
+            allow_windows: false,
+        };
+        plan_expr(ecx, selection)?.type_as(ecx, &SqlScalarType::Bool)?;
+    }
+
+    let in_cluster = source_sink_cluster_config(scx, &mut stmt.in_cluster)?;
+    let if_not_exists = stmt.if_not_exists;
+    let create_sql = normalize::create_statement(scx, Statement::CreateNotification(stmt))?;
+
+    Ok(Plan::CreateNotification(CreateNotificationPlan {
+        name,
+        notification: Notification {
+            create_sql,
+            from: from.global_id(),
+            desc,
+        },
+        if_not_exists,
+        in_cluster: in_cluster.id(),
+    }))
+}
+
+/// Ensures that no notification other than `name` delivers on `channel`.
+///
+/// The channel of a notification is its unqualified name, which `LISTEN`
+/// takes, so it must be unique among all notifications rather than only
+/// within the notification's schema.
+fn ensure_notification_channel_unused(
+    scx: &StatementContext,
+    name: &QualifiedItemName,
+    channel: &str,
+) -> Result<(), PlanError> {
+    let conflict =
+        scx.catalog.get_items().into_iter().find(|item| {
+            item.is_notification() && item.name().item == channel && item.name() != name
+        });
+    if let Some(item) = conflict {
+        sql_bail!(
+            "notification channel \"{}\" is already used by {}",
+            channel,
+            scx.catalog.resolve_full_name(item.name())
+        );
+    }
+    Ok(())
+}
+
 /// This function will plan a sink as if it does not exist in the catalog. This is so the planning
 /// logic is reused by both CREATE SINK and ALTER SINK planning. It is the responsibility of the
 /// callers (plan_create_sink and plan_alter_sink) to check for name collisions if this is
@@ -3279,6 +4586,45 @@ fn plan_sink(
         (CreateSinkConnection::Iceberg { .. }, Some(_), _) => {
             sql_bail!("ENVELOPE is not supported for Iceberg sinks, use MODE instead")
         }
+        // Postgres sinks use ENVELOPE, but only apply upserts
+        (CreateSinkConnection::Postgres { .. }, Some(ast::SinkEnvelope::Upsert), None) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Postgres { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
+            sql_bail!("ENVELOPE DEBEZIUM is not supported for Postgres sinks")
+        }
+        (CreateSinkConnection::Postgres { .. }, None, None) => {
+            sql_bail!("ENVELOPE clause is required")
+        }
+        (CreateSinkConnection::Postgres { .. }, _, Some(_)) => {
+            sql_bail!("MODE is not supported for Postgres sinks, use ENVELOPE instead")
+        }
+        // HTTP sinks use ENVELOPE
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Upsert), None) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
+            SinkEnvelope::Debezium
+        }
+        (CreateSinkConnection::Http { .. }, None, None) => {
+            sql_bail!("ENVELOPE clause is required")
+        }
+        (CreateSinkConnection::Http { .. }, _, Some(_)) => {
+            sql_bail!("MODE is not supported for HTTP sinks, use ENVELOPE instead")
+        }
+        // S3 sinks use MODE, but only append changes
+        (CreateSinkConnection::S3 { .. }, None, Some(ast::IcebergSinkMode::Append)) => {
+            SinkEnvelope::Append
+        }
+        (CreateSinkConnection::S3 { .. }, None, Some(ast::IcebergSinkMode::Upsert)) => {
+            sql_bail!("MODE UPSERT is not supported for S3 sinks")
+        }
+        (CreateSinkConnection::S3 { .. }, None, None) => {
+            sql_bail!("MODE clause is required")
+        }
+        (CreateSinkConnection::S3 { .. }, Some(_), _) => {
+            sql_bail!("ENVELOPE is not supported for S3 sinks, use MODE instead")
+        }
     };
 
     let from_name = &from;
@@ -3309,13 +4655,27 @@ fn plan_sink(
     if from.id().is_system() {
         bail_unsupported!("creating a sink directly on a catalog object");
     }
+    // Sinks emit every row of the relation, so they cannot honor the row level
+    // security policies of the role that creates them.
+    row_level_security::require_exempt(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
+    column_privileges::require_all_columns(
+        scx,
+        scx.get_item(&from.id()),
+        &scx.catalog.resolve_full_name(from.name()),
+    )?;
 
     let desc = from
         .relation_desc()
         .ok_or_else(|| sql_err!("item does not have a relation description"))?;
     let key_indices = match &connection {
         CreateSinkConnection::Kafka { key: Some(key), .. }
-        | CreateSinkConnection::Iceberg { key: Some(key), .. } => {
+        | CreateSinkConnection::Iceberg { key: Some(key), .. }
+        | CreateSinkConnection::Postgres { key: Some(key), .. }
+        | CreateSinkConnection::Http { key: Some(key), .. } => {
             let key_columns = key
                 .key_columns
                 .clone()
@@ -3430,7 +4790,10 @@ fn plan_sink(
             Some(indices)
         }
         CreateSinkConnection::Kafka { key: None, .. }
-        | CreateSinkConnection::Iceberg { key: None, .. } => None,
+        | CreateSinkConnection::Iceberg { key: None, .. }
+        | CreateSinkConnection::Postgres { key: None, .. }
+        | CreateSinkConnection::Http { key: None, .. }
+        | CreateSinkConnection::S3 { .. } => None,
     };
 
     if key_indices.is_some() && envelope == SinkEnvelope::Append {
@@ -3455,6 +4818,19 @@ fn plan_sink(
                 }
             }
         }
+        if let CreateSinkConnection::S3 { .. } = &connection {
+            for (col_name, _) in desc.iter() {
+                if col_name.as_str() == S3_SINK_DIFF_COLUMN
+                    || col_name.as_str() == S3_SINK_TIMESTAMP_COLUMN
+                {
+                    sql_bail!(
+                        "column {} conflicts with the system column that S3 sinks add to \
+                         every file",
+                        col_name.quoted()
+                    );
+                }
+            }
+        }
     }
 
     let headers_index = match &connection {
@@ -3480,11 +4856,26 @@ fn plan_sink(
                 sql_bail!("HEADERS column ({}) is ambiguous", headers);
             }
 
+            let is_header_value =
+                |ty: &SqlScalarType| matches!(ty, SqlScalarType::String | SqlScalarType::Bytes);
+            let is_header_record = |ty: &SqlScalarType| match ty {
+                SqlScalarType::Record { fields, .. } => matches!(
+                    &**fields,
+                    [(key_name, key_type), (value_name, value_type)]
+                        if key_name.as_str() == "key"
+                            && key_type.scalar_type == SqlScalarType::String
+                            && value_name.as_str() == "value"
+                            && is_header_value(&value_type.scalar_type)
+                ),
+                _ => false,
+            };
             match &ty.scalar_type {
-                SqlScalarType::Map { value_type, .. }
-                    if matches!(&**value_type, SqlScalarType::String | SqlScalarType::Bytes) => {}
+                SqlScalarType::Map { value_type, .. } if is_header_value(value_type) => {}
+                // A list of key/value records allows repeating a header key, which a map can't.
+                SqlScalarType::List { element_type, .. } if is_header_record(element_type) => {}
                 _ => sql_bail!(
-                    "HEADERS column must have type map[text => text] or map[text => bytea]"
+                    "HEADERS column must have type map[text => text] or map[text => bytea], \
+                     or be a list of records with a text key field and a text or bytea value field"
                 ),
             }
 
@@ -3542,15 +4933,54 @@ fn plan_sink(
             aws_connection,
             options,
             ..
-        } => iceberg_sink_builder(
+        } => {
+            let relation_version = match from_name {
+                ResolvedItemName::Item {
+                    version: RelationVersionSelector::Specific(version),
+                    ..
+                } => Some(*version),
+                _ => from.latest_version(),
+            };
+            iceberg_sink_builder(
+                scx,
+                catalog_connection,
+                aws_connection,
+                options,
+                relation_key_indices,
+                key_desc_and_indices,
+                commit_interval,
+                &desc,
+                relation_version,
+            )?
+        }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
             scx,
-            catalog_connection,
-            aws_connection,
+            connection,
             options,
             relation_key_indices,
             key_desc_and_indices,
-            commit_interval,
-            &desc,
+            desc.into_owned(),
+        )?,
+        CreateSinkConnection::Http { options, .. } => http_sink_builder(
+            scx,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            desc.into_owned(),
+        )?,
+        CreateSinkConnection::S3 {
+            connection,
+            options,
+        } => s3_sink_builder(
+            scx,
+            connection,
+            options,
+            relation_key_indices,
+            desc.into_owned(),
         )?,
     };
 
@@ -3615,6 +5045,23 @@ pub struct CsrConfigOptionExtracted {
     pub(crate) key_doc_options: BTreeMap<DocTarget, String>,
     pub(crate) key_compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
     pub(crate) value_compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    pub(crate) key_message: Option<String>,
+    pub(crate) value_message: Option<String>,
+    pub(crate) recursion_limit: Option<u32>,
+    pub(crate) avro_max_depth: Option<u32>,
+    pub(crate) avro_union_as_record: bool,
+}
+
+impl CsrConfigOptionExtracted {
+    /// Whether any of the options that only apply to Protobuf are set.
+    fn has_protobuf_options(&self) -> bool {
+        self.key_message.is_some() || self.value_message.is_some() || self.recursion_limit.is_some()
+    }
+
+    /// Whether any of the options that only apply to decoding Avro are set.
+    fn has_avro_decode_options(&self) -> bool {
+        self.avro_max_depth.is_some() || self.avro_union_as_record
+    }
 }
 
 impl std::convert::TryFrom<Vec<CsrConfigOption<Aug>>> for CsrConfigOptionExtracted {
@@ -3654,6 +5101,14 @@ impl std::convert::TryFrom<Vec<CsrConfigOption<Aug>>> for CsrConfigOptionExtract
                     extracted.avro_value_fullname =
                         <Option<String>>::try_from_value(option.value).map_err(better_error)?;
                 }
+                CsrConfigOptionName::AvroMaxDepth => {
+                    extracted.avro_max_depth =
+                        <Option<u32>>::try_from_value(option.value).map_err(better_error)?;
+                }
+                CsrConfigOptionName::AvroUnionAsRecord => {
+                    extracted.avro_union_as_record =
+                        <bool>::try_from_value(option.value).map_err(better_error)?;
+                }
                 CsrConfigOptionName::NullDefaults => {
                     extracted.null_defaults =
                         <bool>::try_from_value(option.value).map_err(better_error)?;
@@ -3698,6 +5153,18 @@ impl std::convert::TryFrom<Vec<CsrConfigOption<Aug>>> for CsrConfigOptionExtract
                 CsrConfigOptionName::ValueCompatibilityLevel => {
                     extracted.value_compatibility_level = to_compatibility_level(option.value)?;
                 }
+                CsrConfigOptionName::KeyMessage => {
+                    extracted.key_message =
+                        <Option<String>>::try_from_value(option.value).map_err(better_error)?;
+                }
+                CsrConfigOptionName::ValueMessage => {
+                    extracted.value_message =
+                        <Option<String>>::try_from_value(option.value).map_err(better_error)?;
+                }
+                CsrConfigOptionName::RecursionLimit => {
+                    extracted.recursion_limit =
+                        <Option<u32>>::try_from_value(option.value).map_err(better_error)?;
+                }
             }
         }
 
@@ -3722,6 +5189,7 @@ fn iceberg_sink_builder(
     key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
     commit_interval: Option<Duration>,
     desc: &RelationDesc,
+    relation_version: Option<RelationVersion>,
 ) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
     // Reject types that arrow-rs's parquet writer cannot handle, before
     // sink creation. Pass the iceberg overrides so types iceberg remaps
@@ -3760,37 +5228,266 @@ fn iceberg_sink_builder(
     let IcebergSinkConfigOptionExtracted {
         table,
         namespace,
+        partition_by,
+        compaction_interval,
+        snapshot_retention,
+        seen: _,
+    }: IcebergSinkConfigOptionExtracted = options.try_into()?;
+
+    let Some(table) = table else {
+        sql_bail!("Iceberg sink must specify TABLE");
+    };
+    let Some(namespace) = namespace else {
+        sql_bail!("Iceberg sink must specify NAMESPACE");
+    };
+    match commit_interval {
+        None => sql_bail!("Iceberg sink must specify COMMIT INTERVAL"),
+        // The sink truncates the interval to whole milliseconds, and a
+        // truncated interval of zero would make it mint zero-width batches in
+        // a busy loop, never committing any data. Require a full second so
+        // truncation is irrelevant, matching the TOPIC METADATA REFRESH
+        // INTERVAL minimum.
+        Some(interval) if interval < Duration::from_secs(1) => {
+            sql_bail!("COMMIT INTERVAL must be at least 1 second")
+        }
+        Some(_) => {}
+    }
+    if let (Some(compaction), Some(commit)) = (compaction_interval, commit_interval)
+        && compaction < commit
+    {
+        sql_bail!("COMPACTION INTERVAL must not be shorter than COMMIT INTERVAL");
+    }
+    if snapshot_retention == Some(Duration::ZERO) {
+        sql_bail!("SNAPSHOT RETENTION must be positive");
+    }
+    let partition_by = plan_iceberg_partition_by(scx, partition_by, desc)?;
+
+    Ok(StorageSinkConnection::Iceberg(IcebergSinkConnection {
+        catalog_connection_id,
+        catalog_connection: catalog_connection_id,
+        storage_connection_id,
+        storage_connection: storage_connection_id,
+        table,
+        namespace,
+        relation_key_indices,
+        key_desc_and_indices,
+        partition_by,
+        relation_version,
+        compaction_interval,
+        snapshot_retention,
+    }))
+}
+
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PostgresSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_POSTGRES_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    if !matches!(connection_item.connection()?, Connection::Postgres(_)) {
+        sql_bail!(
+            "{} is not a postgres connection",
+            scx.catalog
+                .resolve_full_name(connection_item.name())
+                .to_string()
+                .quoted()
+        );
+    }
+
+    let PostgresSinkConfigOptionExtracted {
+        schema,
+        table,
+        seen: _,
+    } = options.try_into()?;
+    let Some(table) = table else {
+        sql_bail!("Postgres sink must specify TABLE");
+    };
+    // `plan_sink` rejects upsert sinks without a key.
+    let key_desc_and_indices =
+        key_desc_and_indices.ok_or_else(|| internal_err!("Postgres sink without KEY"))?;
+
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id: connection_item.id(),
+        connection: connection_item.id(),
+        schema,
+        table,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+    }))
+}
+
+fn http_sink_builder(
+    scx: &StatementContext,
+    options: Vec<HttpSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_HTTP_SINK)?;
+
+    let HttpSinkConfigOptionExtracted {
+        url,
+        headers,
+        signing_secret,
+        signing_header,
+        body_format,
+        batch_size,
+        seen: _,
+    } = options.try_into()?;
+
+    let Some(url) = url else {
+        sql_bail!("HTTP sink must specify URL");
+    };
+    let parsed: reqwest::Url = url
+        .parse()
+        .map_err(|e| sql_err!("parsing HTTP sink URL: {e}"))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        sql_bail!(
+            "HTTP sink URL must use the http or https scheme, got {}",
+            parsed.scheme().quoted()
+        );
+    }
+    for name in headers.keys() {
+        if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+            sql_bail!("invalid HTTP header name {}", name.quoted());
+        }
+    }
+    if reqwest::header::HeaderName::from_bytes(signing_header.as_bytes()).is_err() {
+        sql_bail!("invalid SIGNING HEADER {}", signing_header.quoted());
+    }
+    let signing = signing_secret.map(|secret| HttpSinkSigning {
+        secret: secret.into(),
+        header: signing_header,
+    });
+    let body_format = match body_format.to_lowercase().as_str() {
+        "json" => HttpSinkBodyFormat::Json,
+        "ndjson" => HttpSinkBodyFormat::Ndjson,
+        _ => sql_bail!(
+            "unknown BODY FORMAT {}, expected 'json' or 'ndjson'",
+            body_format.quoted()
+        ),
+    };
+    if batch_size == 0 {
+        sql_bail!("BATCH SIZE must be greater than 0");
+    }
+
+    Ok(StorageSinkConnection::Http(HttpSinkConnection {
+        url,
+        headers,
+        signing,
+        body_format,
+        batch_size,
+        relation_key_indices,
+        key_desc_and_indices,
+        value_desc,
+    }))
+}
+
+fn s3_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<S3SinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&ENABLE_S3_SINK)?;
+
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    if !matches!(connection_item.connection()?, Connection::Aws(_)) {
+        sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog
+                .resolve_full_name(connection_item.name())
+                .to_string()
+                .quoted()
+        );
+    }
+
+    let S3SinkConfigOptionExtracted {
+        uri,
+        format,
+        max_file_size,
+        roll_interval,
         seen: _,
-    }: IcebergSinkConfigOptionExtracted = options.try_into()?;
+    } = options.try_into()?;
 
-    let Some(table) = table else {
-        sql_bail!("Iceberg sink must specify TABLE");
+    let Some(uri) = uri else {
+        sql_bail!("S3 sink must specify URI");
     };
-    let Some(namespace) = namespace else {
-        sql_bail!("Iceberg sink must specify NAMESPACE");
+    let parsed = uri
+        .parse::<http::Uri>()
+        .map_err(|e| sql_err!("invalid URI: {e}"))?;
+    if parsed.scheme_str() != Some("s3") {
+        sql_bail!("only 's3://...' URIs are supported by S3 sinks");
+    }
+    if parsed.host().is_none() {
+        sql_bail!("missing bucket name from 's3://...' URI");
+    }
+
+    let Some(format) = format else {
+        sql_bail!("S3 sink must specify FORMAT");
     };
-    match commit_interval {
-        None => sql_bail!("Iceberg sink must specify COMMIT INTERVAL"),
-        // The sink truncates the interval to whole milliseconds, and a
-        // truncated interval of zero would make it mint zero-width batches in
-        // a busy loop, never committing any data. Require a full second so
-        // truncation is irrelevant, matching the TOPIC METADATA REFRESH
-        // INTERVAL minimum.
-        Some(interval) if interval < Duration::from_secs(1) => {
-            sql_bail!("COMMIT INTERVAL must be at least 1 second")
+    let format = match format.to_lowercase().as_str() {
+        "csv" => S3SinkFormat::PgCopy(CopyFormatParams::Csv(
+            CopyCsvFormatParams::try_new(None, None, None, Some(true), None)
+                .map_err(|e| sql_err!("{}", e))?,
+        )),
+        "parquet" => {
+            // The files also carry the timestamp and diff of every change, so
+            // validate those columns along with the relation's own.
+            let desc = value_desc.clone().concat(
+                RelationDesc::builder()
+                    .with_column(
+                        S3_SINK_TIMESTAMP_COLUMN,
+                        SqlScalarType::MzTimestamp.nullable(false),
+                    )
+                    .with_column(S3_SINK_DIFF_COLUMN, SqlScalarType::Int64.nullable(false))
+                    .finish(),
+            );
+            ArrowBuilder::validate_desc_for_parquet(&desc, |_| None)
+                .map_err(|e| sql_err!("{}", e))?;
+            S3SinkFormat::Parquet
         }
-        Some(_) => {}
+        _ => sql_bail!(
+            "unknown FORMAT {}, expected 'csv' or 'parquet'",
+            format.quoted()
+        ),
+    };
+
+    if max_file_size.as_bytes() < MIN_S3_SINK_FILE_SIZE.as_bytes() {
+        sql_bail!(
+            "MAX FILE SIZE cannot be less than {}",
+            MIN_S3_SINK_FILE_SIZE
+        );
+    }
+    if max_file_size.as_bytes() > MAX_S3_SINK_FILE_SIZE.as_bytes() {
+        sql_bail!(
+            "MAX FILE SIZE cannot be greater than {}",
+            MAX_S3_SINK_FILE_SIZE
+        );
+    }
+    // The sink writes a manifest every ROLL INTERVAL even if nothing changed,
+    // so require a full second, matching the COMMIT INTERVAL minimum of
+    // Iceberg sinks.
+    if roll_interval < Duration::from_secs(1) {
+        sql_bail!("ROLL INTERVAL must be at least 1 second");
     }
 
-    Ok(StorageSinkConnection::Iceberg(IcebergSinkConnection {
-        catalog_connection_id,
-        catalog_connection: catalog_connection_id,
-        storage_connection_id,
-        storage_connection: storage_connection_id,
-        table,
-        namespace,
+    Ok(StorageSinkConnection::S3(S3SinkConnection {
+        connection_id: connection_item.id(),
+        aws_connection: connection_item.id(),
+        uri,
+        format,
+        max_file_size: max_file_size.as_bytes(),
+        roll_interval,
         relation_key_indices,
-        key_desc_and_indices,
+        value_desc,
     }))
 }
 
@@ -3829,6 +5526,7 @@ fn kafka_sink_builder(
         progress_group_id_prefix,
         transactional_id_prefix,
         legacy_ids,
+        topic_by,
         topic_config,
         topic_metadata_refresh_interval,
         topic_partition_count,
@@ -3916,6 +5614,16 @@ fn kafka_sink_builder(
         };
         let extracted_options: CsrConfigOptionExtracted = options.try_into()?;
 
+        if extracted_options.has_protobuf_options() {
+            sql_bail!(
+                "Avro CSR connections do not support the KEY MESSAGE, VALUE MESSAGE or RECURSION LIMIT options"
+            );
+        }
+
+        if extracted_options.has_avro_decode_options() {
+            sql_bail!("AVRO MAX DEPTH and AVRO UNION AS RECORD are only supported for sources");
+        }
+
         if key_desc_and_indices.is_none() && extracted_options.avro_key_fullname.is_some() {
             sql_bail!("Cannot specify AVRO KEY FULLNAME without a corresponding KEY field");
         }
@@ -3949,7 +5657,86 @@ fn kafka_sink_builder(
         Format::Bytes | Format::Text => {
             bail_unsupported!("BYTES or TEXT format with multiple columns")
         }
-        Format::Json { array: true } => bail_unsupported!("JSON ARRAY format in sinks"),
+        Format::Json { array: true } => {
+            scx.require_feature_flag(&ENABLE_JSON_ARRAY_FORMAT)?;
+            if is_key {
+                sql_bail!("JSON ARRAY format is not supported for keys");
+            }
+            Ok(KafkaSinkFormatType::JsonArray)
+        }
+        Format::JsonCsr {
+            csr_connection:
+                CsrConnectionJson {
+                    connection:
+                        CsrConnection {
+                            connection,
+                            options,
+                        },
+                    seed,
+                },
+        } => {
+            scx.require_feature_flag(&ENABLE_JSON_SCHEMA_FORMAT)?;
+            if seed.is_some() {
+                sql_bail!("SEED option does not make sense with sinks");
+            }
+
+            let item = scx.get_item_by_resolved_name(&connection)?;
+            let csr_connection = match item.connection()? {
+                Connection::Csr(_) => item.id(),
+                _ => {
+                    sql_bail!(
+                        "{} is not a schema registry connection",
+                        scx.catalog
+                            .resolve_full_name(item.name())
+                            .to_string()
+                            .quoted()
+                    )
+                }
+            };
+            let CsrConfigOptionExtracted {
+                avro_key_fullname,
+                avro_value_fullname,
+                null_defaults,
+                value_doc_options,
+                key_doc_options,
+                key_compatibility_level,
+                value_compatibility_level,
+                key_message,
+                value_message,
+                recursion_limit,
+                avro_max_depth,
+                avro_union_as_record,
+                seen: _,
+            } = options.try_into()?;
+            if avro_key_fullname.is_some()
+                || avro_value_fullname.is_some()
+                || null_defaults
+                || !value_doc_options.is_empty()
+                || !key_doc_options.is_empty()
+                || key_message.is_some()
+                || value_message.is_some()
+                || recursion_limit.is_some()
+                || avro_max_depth.is_some()
+                || avro_union_as_record
+            {
+                sql_bail!("JSON CSR connections only support the COMPATIBILITY LEVEL options");
+            }
+
+            let schema = mz_interchange::json_schema::build_row_schema_json(
+                desc.clone(),
+                !is_key && matches!(envelope, SinkEnvelope::Debezium),
+            )
+            .to_string();
+            Ok(KafkaSinkFormatType::JsonSchema {
+                schema,
+                compatibility_level: if is_key {
+                    key_compatibility_level
+                } else {
+                    value_compatibility_level
+                },
+                csr_connection,
+            })
+        }
         Format::Avro(AvroSchema::Csr { csr_connection }) => {
             let (csr_connection, options) = gen_avro_schema_options(csr_connection)?;
             let schema = if is_key {
@@ -4109,11 +5896,93 @@ fn kafka_sink_builder(
                 },
             })
         }
+        Format::Protobuf(ProtobufSchema::Csr {
+            csr_connection:
+                CsrConnectionProtobuf {
+                    connection:
+                        CsrConnection {
+                            connection,
+                            options,
+                        },
+                    seed,
+                },
+        }) => {
+            scx.require_feature_flag(&ENABLE_KAFKA_SINK_PROTOBUF_FORMAT)?;
+            if seed.is_some() {
+                sql_bail!("SEED option does not make sense with sinks");
+            }
+
+            let item = scx.get_item_by_resolved_name(&connection)?;
+            let csr_connection = match item.connection()? {
+                Connection::Csr(_) => item.id(),
+                _ => {
+                    sql_bail!(
+                        "{} is not a schema registry connection",
+                        scx.catalog
+                            .resolve_full_name(item.name())
+                            .to_string()
+                            .quoted()
+                    )
+                }
+            };
+            let CsrConfigOptionExtracted {
+                avro_key_fullname,
+                avro_value_fullname,
+                null_defaults,
+                value_doc_options,
+                key_doc_options,
+                key_compatibility_level,
+                value_compatibility_level,
+                key_message,
+                value_message,
+                recursion_limit,
+                avro_max_depth,
+                avro_union_as_record,
+                seen: _,
+            } = options.try_into()?;
+            if avro_key_fullname.is_some()
+                || avro_value_fullname.is_some()
+                || null_defaults
+                || !value_doc_options.is_empty()
+                || !key_doc_options.is_empty()
+                || key_message.is_some()
+                || value_message.is_some()
+                || recursion_limit.is_some()
+                || avro_max_depth.is_some()
+                || avro_union_as_record
+            {
+                sql_bail!("Protobuf CSR sinks only support the COMPATIBILITY LEVEL options");
+            }
+
+            let schema = mz_interchange::protobuf::build_row_schema_proto(
+                desc.clone(),
+                !is_key && matches!(envelope, SinkEnvelope::Debezium),
+            )
+            .map_err(|e| PlanError::Unstructured(format!("{e:#}")))?;
+            Ok(KafkaSinkFormatType::Protobuf {
+                schema,
+                compatibility_level: if is_key {
+                    key_compatibility_level
+                } else {
+                    value_compatibility_level
+                },
+                csr_connection,
+            })
+        }
+        Format::Protobuf(ProtobufSchema::InlineSchema { .. }) => {
+            sql_bail!("Protobuf sinks require a CONFLUENT SCHEMA REGISTRY connection")
+        }
         format => bail_unsupported!(format!("sink format {:?}", format)),
     };
 
-    let partition_by = match &partition_by {
-        Some(partition_by) => {
+    // PARTITION BY and TOPIC BY expressions are evaluated against the row that produced each
+    // message. With ENVELOPE DEBEZIUM, they may only refer to key columns, as those are the only
+    // columns guaranteed to be the same in `before` and `after`.
+    let plan_message_expr =
+        |expr: &Expr<Aug>,
+         name: &'static str,
+         non_key_error: fn(Option<&PartialItemName>, &ColumnName) -> PlanError,
+         target_type: &SqlScalarType| {
             let mut scope = Scope::from_source(None, value_desc.iter_names());
 
             match envelope {
@@ -4127,11 +5996,7 @@ fn kafka_sink_builder(
                         .collect();
                     for (i, item) in scope.items.iter_mut().enumerate() {
                         if !key_indices.contains(&i) {
-                            item.error_if_referenced = Some(|_table, column| {
-                                PlanError::InvalidPartitionByEnvelopeDebezium {
-                                    column_name: column.to_string(),
-                                }
-                            });
+                            item.error_if_referenced = Some(non_key_error);
                         }
                     }
                 }
@@ -4139,7 +6004,7 @@ fn kafka_sink_builder(
 
             let ecx = &ExprContext {
                 qcx: &QueryContext::root(scx, QueryLifetime::OneShot),
-                name: "PARTITION BY",
+                name,
                 scope: &scope,
                 relation_type: value_desc.typ(),
                 allow_aggregates: false,
@@ -4147,17 +6012,38 @@ fn kafka_sink_builder(
                 allow_parameters: false,
                 allow_windows: false,
             };
-            let expr = plan_expr(ecx, partition_by)?.cast_to(
-                ecx,
-                CastContext::Assignment,
+            let expr = plan_expr(ecx, expr)?.cast_to(ecx, CastContext::Assignment, target_type)?;
+            Ok::<_, PlanError>(expr.lower_uncorrelated(scx.catalog.system_vars())?)
+        };
+
+    let partition_by = partition_by
+        .as_ref()
+        .map(|partition_by| {
+            plan_message_expr(
+                partition_by,
+                "PARTITION BY",
+                |_table, column| PlanError::InvalidPartitionByEnvelopeDebezium {
+                    column_name: column.to_string(),
+                },
                 &SqlScalarType::UInt64,
-            )?;
-            let expr = expr.lower_uncorrelated(scx.catalog.system_vars())?;
+            )
+        })
+        .transpose()?;
 
-            Some(expr)
-        }
-        _ => None,
-    };
+    let topic_by = topic_by
+        .as_ref()
+        .map(|topic_by| {
+            scx.require_feature_flag(&ENABLE_KAFKA_SINK_TOPIC_BY)?;
+            plan_message_expr(
+                topic_by,
+                "TOPIC BY",
+                |_table, column| PlanError::InvalidTopicByEnvelopeDebezium {
+                    column_name: column.to_string(),
+                },
+                &SqlScalarType::String,
+            )
+        })
+        .transpose()?;
 
     // Map from the format specifier of the statement to the individual key/value formats for the sink.
     let format = match format {
@@ -4184,6 +6070,13 @@ fn kafka_sink_builder(
         None => bail_unsupported!("sink without format"),
     };
 
+    // Rows are batched into arrays per timestamp, which is only meaningful for a change log
+    // without keys.
+    if matches!(format.value_format, KafkaSinkFormatType::JsonArray) && format.key_format.is_some()
+    {
+        sql_bail!("JSON ARRAY format is not supported for sinks with a KEY");
+    }
+
     Ok(StorageSinkConnection::Kafka(KafkaSinkConnection {
         connection_id,
         connection: connection_id,
@@ -4194,6 +6087,7 @@ fn kafka_sink_builder(
         headers_index,
         value_desc,
         partition_by,
+        topic_by,
         compression_type,
         progress_group_id,
         transactional_id,
@@ -4224,6 +6118,7 @@ pub fn plan_create_index(
         key_parts,
         with_options,
         if_not_exists,
+        temporary,
     } = &mut stmt;
     let on = scx.get_item_by_resolved_name(on_name)?;
 
@@ -4279,14 +6174,27 @@ pub fn plan_create_index(
     };
     let keys = query::plan_index_exprs(scx, &on_desc, filled_key_parts.clone())?;
 
+    // Indexes on temporary objects are themselves temporary, as in PostgreSQL.
+    // Temporary indexes live in the temporary schema, regardless of the schema
+    // of the object they index.
+    *temporary |= on.name().qualifiers.schema_spec == SchemaSpecifier::Temporary;
+    let qualifiers = if *temporary {
+        ItemQualifiers {
+            database_spec: ResolvedDatabaseSpecifier::Ambient,
+            schema_spec: SchemaSpecifier::Temporary,
+        }
+    } else {
+        on.name().qualifiers.clone()
+    };
+
     let index_name = if let Some(name) = name {
         QualifiedItemName {
-            qualifiers: on.name().qualifiers.clone(),
+            qualifiers,
             item: normalize::ident(name.clone()),
         }
     } else {
         let mut idx_name = QualifiedItemName {
-            qualifiers: on.name().qualifiers.clone(),
+            qualifiers,
             item: on.name().item.clone(),
         };
         if key_parts.is_none() {
@@ -4356,6 +6264,7 @@ pub fn plan_create_index(
     *name = Some(Ident::new(index_name.item.clone())?);
     *key_parts = Some(filled_key_parts);
     let if_not_exists = *if_not_exists;
+    let temporary = *temporary;
 
     let create_sql = normalize::create_statement(scx, Statement::CreateIndex(stmt))?;
     let compaction_window = options.iter().find_map(|o| {
@@ -4375,6 +6284,7 @@ pub fn plan_create_index(
             keys,
             cluster_id,
             compaction_window,
+            temporary,
         },
         if_not_exists,
     }))
@@ -6335,11 +8245,15 @@ generate_extracted_config!(
     TableOption,
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (RedactedTest, String)
+    (RedactedTest, String),
+    (RowLevelSecurity, bool),
+    (RowLevelSecurityPolicies, Vec<RowLevelSecurityPolicy<Aug>>),
+    (ColumnPrivileges, Vec<ColumnPrivilege>)
 );
 
 fn plan_table_options(
     scx: &StatementContext,
+    name: &QualifiedItemName,
     desc: &RelationDesc,
     with_opts: Vec<TableOption<Aug>>,
 ) -> Result<Vec<crate::plan::TableOption>, PlanError> {
@@ -6347,6 +8261,9 @@ fn plan_table_options(
         partition_by,
         retain_history,
         redacted_test,
+        row_level_security,
+        row_level_security_policies,
+        column_privileges,
         ..
     }: TableOptionExtracted = with_opts.try_into()?;
 
@@ -6355,6 +8272,16 @@ fn plan_table_options(
         check_partition_by(desc, partition_by)?;
     }
 
+    row_level_security::plan_options(
+        scx,
+        name,
+        desc,
+        row_level_security,
+        row_level_security_policies.as_deref(),
+    )?;
+
+    column_privileges::plan_options(scx, name, desc, column_privileges.as_deref())?;
+
     if redacted_test.is_some() {
         scx.require_feature_flag(&vars::ENABLE_REDACTED_TEST_OPTION)?;
     }
@@ -7013,6 +8940,9 @@ pub fn plan_alter_item_rename(
             if conflicting_type_exists || conflicting_item_exists {
                 sql_bail!("catalog item '{}' already exists", to_item_name);
             }
+            if entry.is_notification() {
+                ensure_notification_channel_unused(scx, entry.name(), &proposed_name.item)?;
+            }
 
             Ok(Plan::AlterItemRename(AlterItemRenamePlan {
                 id: entry.id(),
@@ -7303,6 +9233,171 @@ fn alter_retain_history(
     }
 }
 
+pub fn describe_create_policy(
+    _: &StatementContext,
+    _: CreatePolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_policy(
+    scx: &StatementContext,
+    CreatePolicyStatement { name, on, using }: CreatePolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    let (id, full_name, rls) = resolve_row_level_security_relation(scx, &on)?;
+    if rls.contains_policy(&name) {
+        return Err(PlanError::RowLevelSecurityPolicyAlreadyExists {
+            name: name.to_string(),
+            relation: full_name.to_string(),
+        });
+    }
+    let desc = scx
+        .get_item_by_resolved_name(&on)?
+        .relation_desc()
+        .expect("relations that support row level security have a desc")
+        .into_owned();
+    row_level_security::plan_policy(scx, &full_name, &desc, &using)?;
+
+    // The policy is stored in the create SQL of the relation, so round-trip it
+    // through its stable representation, which refers to types by ID.
+    let using = mz_sql_parser::parser::parse_expr(&using.to_ast_string_stable())?;
+    Ok(Plan::CreatePolicy(CreatePolicyPlan {
+        id,
+        policy: RowLevelSecurityPolicy { name, using },
+    }))
+}
+
+pub fn describe_drop_policy(
+    _: &StatementContext,
+    _: DropPolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_policy(
+    scx: &StatementContext,
+    DropPolicyStatement {
+        if_exists,
+        name,
+        on,
+    }: DropPolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    let (id, full_name, rls) = resolve_row_level_security_relation(scx, &on)?;
+    let name = if rls.contains_policy(&name) {
+        Some(name)
+    } else if if_exists {
+        scx.catalog.add_notice(PlanNotice::PolicyDoesNotExist {
+            name: name.to_string(),
+            relation: full_name.to_string(),
+        });
+        None
+    } else {
+        return Err(PlanError::RowLevelSecurityPolicyDoesNotExist {
+            name: name.to_string(),
+            relation: full_name.to_string(),
+        });
+    };
+    Ok(Plan::DropPolicy(DropPolicyPlan { id, name }))
+}
+
+/// Resolves the relation named by `CREATE POLICY` or `DROP POLICY`, returning
+/// its ID, its full name, and its current row level security configuration.
+fn resolve_row_level_security_relation(
+    scx: &StatementContext,
+    name: &ResolvedItemName,
+) -> Result<(CatalogItemId, FullItemName, RowLevelSecurity), PlanError> {
+    let id = scx.get_item_by_resolved_name(name)?.id();
+    let item = scx.get_item(&id);
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    let rls = if id.is_user() {
+        RowLevelSecurity::from_item(item)?
+    } else {
+        None
+    };
+    match rls {
+        Some(rls) => Ok((id, full_name, rls)),
+        None => Err(PlanError::RowLevelSecurityUnsupported {
+            name: full_name.to_string(),
+            item_type: item.item_type().to_string(),
+        }),
+    }
+}
+
+pub fn describe_alter_row_level_security(
+    _: &StatementContext,
+    _: AlterRowLevelSecurityStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_row_level_security(
+    scx: &StatementContext,
+    AlterRowLevelSecurityStatement {
+        object_type,
+        if_exists,
+        name,
+        enabled,
+    }: AlterRowLevelSecurityStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    let object_type = object_type.into();
+    let name = match (object_type, name) {
+        (
+            ObjectType::View
+            | ObjectType::MaterializedView
+            | ObjectType::Table
+            | ObjectType::Source,
+            UnresolvedObjectName::Item(name),
+        ) => name,
+        (object_type, _) => {
+            bail_unsupported!(format!("ROW LEVEL SECURITY on {object_type}"))
+        }
+    };
+    match resolve_item_or_type(scx, object_type, name.clone(), if_exists)? {
+        Some(entry) => {
+            let full_name = scx.catalog.resolve_full_name(entry.name());
+            let item_type = entry.item_type();
+
+            // Return a more helpful error on `ALTER VIEW <materialized-view>`.
+            if object_type == ObjectType::View && item_type == CatalogItemType::MaterializedView {
+                return Err(PlanError::AlterViewOnMaterializedView(
+                    full_name.to_string(),
+                ));
+            } else if object_type != item_type {
+                sql_bail!(
+                    "\"{}\" is a {} not a {}",
+                    full_name,
+                    entry.item_type(),
+                    format!("{object_type}").to_lowercase()
+                )
+            }
+
+            if !entry.id().is_user() || RowLevelSecurity::from_item(entry)?.is_none() {
+                return Err(PlanError::RowLevelSecurityUnsupported {
+                    name: full_name.to_string(),
+                    item_type: item_type.to_string(),
+                });
+            }
+
+            Ok(Plan::AlterRowLevelSecurity(AlterRowLevelSecurityPlan {
+                id: entry.id(),
+                object_type,
+                enabled,
+            }))
+        }
+        None => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_ast_string_simple(),
+                object_type,
+            });
+
+            Ok(Plan::AlterNoop(AlterNoopPlan { object_type }))
+        }
+    }
+}
+
 fn alter_source_timestamp_interval(
     scx: &StatementContext,
     if_exists: bool,
@@ -7499,6 +9594,9 @@ pub fn plan_alter_connection(
         Connection::Ssh(_) => CreateConnectionType::Ssh,
         Connection::MySql(_) => CreateConnectionType::MySql,
         Connection::SqlServer(_) => CreateConnectionType::SqlServer,
+        Connection::MongoDb(_) => CreateConnectionType::MongoDb,
+        Connection::Nats(_) => CreateConnectionType::Nats,
+        Connection::Mqtt(_) => CreateConnectionType::Mqtt,
         Connection::IcebergCatalog(_) => CreateConnectionType::IcebergCatalog,
     };
 
@@ -7645,8 +9743,12 @@ pub fn plan_alter_sink(
     let [stmt]: [StatementParseResult; 1] = stmts
         .try_into()
         .map_err(|_| internal_err!("create SQL of sink was not exactly one statement"))?;
-    let Statement::CreateSink(stmt) = stmt.ast else {
-        bail_internal!("create SQL of sink is not a CREATE SINK statement");
+    let stmt = match stmt.ast {
+        Statement::CreateSink(stmt) => stmt,
+        Statement::CreateNotification(_) => {
+            bail_unsupported!("ALTER SINK on notifications")
+        }
+        _ => bail_internal!("create SQL of sink is not a CREATE SINK statement"),
     };
     let (mut stmt, _) = crate::names::resolve(scx.catalog, stmt)?;
 
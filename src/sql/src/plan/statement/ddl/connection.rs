@@ -36,9 +36,10 @@ use mz_storage_types::connections::{
     AwsPrivatelink, AwsPrivatelinkConnection, AwsPrivatelinkRule, CsrConnection,
     CsrConnectionHttpAuth, GlueSchemaRegistryConnection, IcebergCatalogAuth,
     IcebergCatalogConnection, IcebergCatalogImpl, IcebergCatalogType, KafkaConnection,
-    KafkaSaslConfig, KafkaTlsConfig, KafkaTopicOptions, MySqlConnection, MySqlSslMode,
-    PostgresConnection, RestIcebergCatalog, S3TablesRestIcebergCatalog, SqlServerConnectionDetails,
-    SshConnection, SshTunnel, TlsIdentity, Tunnel,
+    KafkaSaslConfig, KafkaTlsConfig, KafkaTopicOptions, MongoDbConnection, MongoDbTlsMode,
+    MqttConnection, MqttTlsMode, MySqlConnection, MySqlSslMode, NatsConnection, PostgresConnection,
+    RestIcebergCatalog, S3TablesRestIcebergCatalog, SqlServerConnectionDetails, SshConnection,
+    SshTunnel, TlsIdentity, Tunnel,
 };
 
 use crate::names::Aug;
@@ -189,6 +190,19 @@ pub(super) fn validate_options_per_connection_type(
             SslMode,
             User,
         ],
+        CreateConnectionType::MongoDb => &[
+            Database,
+            Host,
+            Password,
+            Port,
+            SslCertificateAuthority,
+            SslMode,
+            User,
+        ],
+        CreateConnectionType::Nats => &[Password, Url, User],
+        CreateConnectionType::Mqtt => {
+            &[Host, Password, Port, SslCertificateAuthority, SslMode, User]
+        }
         CreateConnectionType::IcebergCatalog => &[
             AwsConnection,
             CatalogType,
@@ -673,6 +687,86 @@ impl ConnectionOptionExtracted {
                     tls_root_cert: self.ssl_certificate_authority,
                 })
             }
+            CreateConnectionType::MongoDb => {
+                let tls_mode = match self
+                    .ssl_mode
+                    .map(|mode| mode.to_uppercase())
+                    .as_ref()
+                    .map(|mode| mode.as_str())
+                {
+                    None | Some("DISABLED") => MongoDbTlsMode::Disabled,
+                    Some("REQUIRED") | Some("REQUIRE") => MongoDbTlsMode::Required,
+                    Some("VERIFY_IDENTITY") | Some("VERIFY-IDENTITY") => {
+                        MongoDbTlsMode::VerifyIdentity
+                    }
+                    Some(mode) => {
+                        sql_bail!("invalid CONNECTION: unknown SSL MODE {}", mode.quoted())
+                    }
+                };
+                if self.password.is_some() && self.user.is_none() {
+                    sql_bail!("invalid CONNECTION: PASSWORD requires USER");
+                }
+
+                ConnectionDetails::MongoDb(MongoDbConnection {
+                    host: self
+                        .host
+                        .ok_or_else(|| sql_err!("HOST option is required"))?,
+                    // 27017 is the default port of `mongod` and `mongos`.
+                    port: self.port.unwrap_or(27017_u16),
+                    user: self.user,
+                    password: self.password.map(|password| password.into()),
+                    auth_source: self.database,
+                    tls_mode,
+                    tls_root_cert: self.ssl_certificate_authority,
+                })
+            }
+            CreateConnectionType::Nats => {
+                if self.password.is_some() && self.user.is_none() {
+                    sql_bail!("invalid CONNECTION: PASSWORD requires USER");
+                }
+                let url = self.url.ok_or_else(|| sql_err!("URL option is required"))?;
+                if let Err(e) = url.parse::<async_nats::ServerAddr>() {
+                    sql_bail!("invalid CONNECTION: invalid URL {}: {}", url.quoted(), e);
+                }
+
+                ConnectionDetails::Nats(NatsConnection {
+                    url,
+                    user: self.user,
+                    password: self.password.map(|password| password.into()),
+                })
+            }
+            CreateConnectionType::Mqtt => {
+                let tls_mode = match self
+                    .ssl_mode
+                    .map(|mode| mode.to_uppercase())
+                    .as_ref()
+                    .map(|mode| mode.as_str())
+                {
+                    None | Some("DISABLED") => MqttTlsMode::Disabled,
+                    Some("REQUIRED") | Some("REQUIRE") => MqttTlsMode::Required,
+                    Some(mode) => {
+                        sql_bail!("invalid CONNECTION: unknown SSL MODE {}", mode.quoted())
+                    }
+                };
+                if self.ssl_certificate_authority.is_some() && tls_mode == MqttTlsMode::Disabled {
+                    sql_bail!("invalid CONNECTION: SSL CERTIFICATE AUTHORITY requires SSL MODE");
+                }
+                if self.password.is_some() && self.user.is_none() {
+                    sql_bail!("invalid CONNECTION: PASSWORD requires USER");
+                }
+
+                ConnectionDetails::Mqtt(MqttConnection {
+                    host: self
+                        .host
+                        .ok_or_else(|| sql_err!("HOST option is required"))?,
+                    // 1883 is the registered port for MQTT over TCP.
+                    port: self.port.unwrap_or(1883_u16),
+                    user: self.user,
+                    password: self.password.map(|password| password.into()),
+                    tls_mode,
+                    tls_root_cert: self.ssl_certificate_authority,
+                })
+            }
             CreateConnectionType::IcebergCatalog => {
                 let catalog_type = self.catalog_type.clone().ok_or_else(|| {
                     sql_err!("invalid CONNECTION: ICEBERG connections must specify CATALOG TYPE")
@@ -17,7 +17,7 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use itertools::Itertools;
 use mz_arrow_util::builder::ArrowBuilder;
-use mz_expr::{ColumnOrder, RowSetFinishing};
+use mz_expr::{CollectionPlan, ColumnOrder, Id, RowSetFinishing};
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::separated;
@@ -59,11 +59,12 @@ use crate::plan::statement::show::ShowSelect;
 use crate::plan::statement::{StatementContext, StatementDesc, ddl};
 use crate::plan::{
     self, CopyFromFilter, CopyToPlan, CreateSinkPlan, ExplainPushdownPlan, ExplainSinkSchemaPlan,
-    ExplainTimestampPlan, HirRelationExpr, side_effecting_func, transform_ast,
+    ExplainTimestampPlan, HirRelationExpr, column_privileges, row_level_security,
+    side_effecting_func, transform_ast,
 };
 use crate::plan::{
     CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan, PlanError,
-    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
+    QueryContext, QueryWhen, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
 };
 use crate::plan::{CopyFromSource, with_options};
 use crate::session::vars::{self, DISALLOW_UNMATERIALIZABLE_FUNCTIONS_AS_OF};
@@ -226,6 +227,7 @@ fn plan_select_inner(
         scope: _,
     } = query::plan_root_query(scx, select.query.clone(), lifetime)?;
     expr.bind_parameters_and_simplify_offset(scx, lifetime, params)?;
+    let when = plan_snapshot_query_when(scx, &expr, when)?;
 
     // We need to concretize the `limit` and `offset` of the RowSetFinishing, so that we go from
     // `RowSetFinishing<HirScalarExpr, HirScalarExpr>` to `RowSetFinishing`.
@@ -290,6 +292,35 @@ fn plan_select_inner(
     Ok((plan, desc))
 }
 
+/// Pins `when` to the timestamp of the snapshots that `expr` reads from, if
+/// any. Snapshots have no well-defined contents at any other time.
+fn plan_snapshot_query_when(
+    scx: &StatementContext,
+    expr: &HirRelationExpr,
+    when: QueryWhen,
+) -> Result<QueryWhen, PlanError> {
+    let mut pinned = None;
+    for id in expr.depends_on() {
+        let Some((_from, as_of)) = scx.catalog.get_item_by_global_id(&id).snapshot_details() else {
+            continue;
+        };
+        match pinned {
+            Some(pinned) if pinned != as_of => {
+                sql_bail!("cannot query snapshots pinned at different timestamps in one query")
+            }
+            _ => pinned = Some(as_of),
+        }
+    }
+    match (pinned, when) {
+        (None, when) => Ok(when),
+        (Some(as_of), QueryWhen::Immediately | QueryWhen::FreshestTableWrite) => {
+            Ok(QueryWhen::AtTimestamp(as_of))
+        }
+        (Some(as_of), QueryWhen::AtTimestamp(ts)) if ts == as_of => Ok(QueryWhen::AtTimestamp(ts)),
+        (Some(as_of), _) => sql_bail!("snapshots can only be queried AS OF {as_of}"),
+    }
+}
+
 pub fn describe_explain_plan(
     scx: &StatementContext,
     explain: ExplainPlanStatement<Aug>,
@@ -1657,11 +1688,29 @@ pub fn plan_subscribe(
                 _ => None,
             };
             let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
-            (
-                SubscribeFrom::Id(item.global_id()),
-                desc.into_owned(),
-                scope,
-            )
+            column_privileges::require_all_columns(
+                scx,
+                scx.get_item(&item.id()),
+                &scx.catalog.resolve_full_name(item.name()),
+            )?;
+            let from = match row_level_security::plan_enforced_predicate(
+                scx,
+                scx.get_item(&item.id()),
+                &scx.catalog.resolve_full_name(item.name()),
+                &desc,
+                QueryLifetime::Subscribe,
+            )? {
+                Some(predicate) => SubscribeFrom::Query {
+                    expr: HirRelationExpr::Get {
+                        id: Id::Global(item.global_id()),
+                        typ: desc.typ().clone(),
+                    }
+                    .filter(vec![predicate]),
+                    desc: desc.clone().into_owned(),
+                },
+                None => SubscribeFrom::Id(item.global_id()),
+            };
+            (from, desc.into_owned(), scope)
         }
         SubscribeRelation::Query(query) => {
             #[allow(deprecated)] // TODO(aalexandrov): Use HirRelationExpr in Subscribe
@@ -20,15 +20,17 @@ use uncased::UncasedStr;
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     CloseStatement, DeallocateStatement, DeclareStatement, DiscardStatement, DiscardTarget,
-    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, PrepareStatement,
-    ResetVariableStatement, SetVariableStatement, SetVariableTo, ShowVariableStatement,
+    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, ListenStatement,
+    PrepareStatement, ResetVariableStatement, SetVariableStatement, SetVariableTo,
+    ShowVariableStatement, UnlistenStatement,
 };
 use crate::names::{self, Aug};
+use crate::normalize;
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::{
     ClosePlan, DeallocatePlan, DeclarePlan, ExecutePlan, ExecuteTimeout, FetchPlan,
-    InspectShardPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan, SetVariablePlan,
-    ShowVariablePlan, VariableValue, describe, query,
+    InspectShardPlan, ListenPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan,
+    SetVariablePlan, ShowVariablePlan, UnlistenPlan, VariableValue, describe, query,
 };
 use crate::session::vars;
 use crate::session::vars::{SCHEMA_ALIAS, VarInput};
@@ -351,3 +353,38 @@ pub fn plan_deallocate(
         name: name.map(|name| name.to_string()),
     }))
 }
+
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    scx: &StatementContext,
+    ListenStatement { channel }: ListenStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_NOTIFICATIONS)?;
+    Ok(Plan::Listen(ListenPlan {
+        channel: normalize::ident(channel),
+    }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    _: &StatementContext,
+    UnlistenStatement { channel }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    // Unlike `LISTEN`, `UNLISTEN` is not gated behind the feature flag, as
+    // connection poolers routinely issue `UNLISTEN *` when resetting sessions.
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: channel.map(normalize::ident),
+    }))
+}
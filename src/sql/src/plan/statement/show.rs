@@ -26,6 +26,7 @@ use mz_sql_parser::ast::{
     SqlServerConfigOptionName, SystemObjectType, UnresolvedItemName, WithOptionValue,
 };
 use mz_sql_pretty::PrettyConfig;
+use mz_storage_types::sources::GenericSourceConnection;
 use query::QueryContext;
 
 use crate::ast::display::escaped_string_literal;
@@ -1095,20 +1096,36 @@ fn humanize_sql_for_show_create(
                 TableFromSourceOptionName::Details => false,
                 TableFromSourceOptionName::PartitionBy => true,
                 TableFromSourceOptionName::RetainHistory => true,
+                TableFromSourceOptionName::ColumnPrivileges => true,
+                TableFromSourceOptionName::RowLevelSecurity => true,
+                TableFromSourceOptionName::RowLevelSecurityPolicies => true,
+                TableFromSourceOptionName::RetractOnTruncate => true,
+                TableFromSourceOptionName::SchemaChangePolicy => true,
             });
-            // The `Defined` column list and constraints are populated during
-            // purification (from the upstream schema), and `CREATE TABLE ... FROM
-            // SOURCE` rejects them as input. Omit them so the statement
-            // roundtrips; purification re-derives them from the source on replay,
-            // and the schema-affecting `TEXT COLUMNS` / `EXCLUDE COLUMNS` options
-            // are retained above so the re-derived schema matches. A user-typed
-            // `Named` column list is left intact, since it does roundtrip.
-            if matches!(stmt.columns, TableFromSourceColumns::Defined(_)) {
-                stmt.columns = TableFromSourceColumns::NotSpecified;
+            // The columns of S3 source tables are declared by the user, so they
+            // roundtrip as they are.
+            let user_defined_columns = match &stmt.source {
+                ResolvedItemName::Item { id, .. } => matches!(
+                    catalog.get_item(id).source_desc(),
+                    Ok(Some(desc)) if matches!(desc.connection, GenericSourceConnection::S3(_))
+                ),
+                _ => false,
+            };
+            if !user_defined_columns {
+                // The `Defined` column list and constraints are populated during
+                // purification (from the upstream schema), and `CREATE TABLE ... FROM
+                // SOURCE` rejects them as input. Omit them so the statement
+                // roundtrips; purification re-derives them from the source on replay,
+                // and the schema-affecting `TEXT COLUMNS` / `EXCLUDE COLUMNS` options
+                // are retained above so the re-derived schema matches. A user-typed
+                // `Named` column list is left intact, since it does roundtrip.
+                if matches!(stmt.columns, TableFromSourceColumns::Defined(_)) {
+                    stmt.columns = TableFromSourceColumns::NotSpecified;
+                }
+                // Constraints are never valid input here (purification populates them
+                // alongside `Defined` columns), so always drop them.
+                stmt.constraints = Vec::new();
             }
-            // Constraints are never valid input here (purification populates them
-            // alongside `Defined` columns), so always drop them.
-            stmt.constraints = Vec::new();
         }
         // `CREATE SOURCE` statements should roundtrip. However, sources and
         // their subsources have a complex relationship, so we need to do a lot
@@ -1265,6 +1282,12 @@ fn humanize_sql_for_show_create(
                     stmt.external_references = Some(ExternalReferences::All);
                 }
                 CreateSourceConnection::Kafka { .. }
+                | CreateSourceConnection::Kinesis { .. }
+                | CreateSourceConnection::Nats { .. }
+                | CreateSourceConnection::Mqtt { .. }
+                | CreateSourceConnection::MongoDb { .. }
+                | CreateSourceConnection::S3 { .. }
+                | CreateSourceConnection::Iceberg { .. }
                 | CreateSourceConnection::LoadGenerator { .. } => {}
             }
 
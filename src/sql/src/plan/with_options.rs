@@ -12,16 +12,19 @@
 use std::collections::BTreeMap;
 use std::time::Duration;
 
+use mz_ore::str::StrExt;
 use mz_repr::adt::interval::Interval;
 use mz_repr::bytes::ByteSize;
 use mz_repr::{CatalogItemId, RelationVersionSelector, strconv};
 use mz_sql_parser::ast::{
     ClusterAlterOptionValue, ClusterAutoScalingStrategyOptionValue, ClusterScheduleOptionValue,
-    ConnectionDefaultAwsPrivatelink, Expr, Ident, KafkaBroker, KafkaMatchingBrokerRule,
-    NetworkPolicyRuleDefinition, RefreshOptionValue, ReplicaDefinition,
+    ColumnPrivilege, ConnectionDefaultAwsPrivatelink, Expr, IcebergPartitionField, Ident,
+    KafkaBroker, KafkaMatchingBrokerRule, NetworkPolicyRuleDefinition, RefreshOptionValue,
+    ReplicaDefinition, RowLevelSecurityPolicy,
 };
 use mz_storage_types::connections::IcebergCatalogType;
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
+use mz_storage_types::sources::SchemaChangePolicy;
 use serde::{Deserialize, Serialize};
 
 use crate::ast::{AstInfo, UnresolvedItemName, Value, WithOptionValue};
@@ -68,6 +71,36 @@ impl ImpliedValue for IcebergCatalogType {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for SchemaChangePolicy {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match String::try_from_value(v)? {
+            s if s.eq_ignore_ascii_case("none") => Ok(SchemaChangePolicy::None),
+            s if s.eq_ignore_ascii_case("add_columns") => Ok(SchemaChangePolicy::AddColumns),
+            s => sql_bail!(
+                "invalid SCHEMA CHANGE POLICY {}: must be 'none' or 'add_columns'",
+                s.quoted()
+            ),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::Value(Value::String(match self {
+            SchemaChangePolicy::None => "none".to_string(),
+            SchemaChangePolicy::AddColumns => "add_columns".to_string(),
+        })))
+    }
+
+    fn name() -> String {
+        "schema change policy".to_string()
+    }
+}
+
+impl ImpliedValue for SchemaChangePolicy {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a schema change policy")
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Secret(CatalogItemId);
 
@@ -712,7 +745,10 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::Refresh(_)
             | WithOptionValue::ClusterScheduleOptionValue(_)
             | WithOptionValue::ClusterAutoScalingStrategyOptionValue(_)
-            | WithOptionValue::NetworkPolicyRules(_) => sql_bail!(
+            | WithOptionValue::NetworkPolicyRules(_)
+            | WithOptionValue::RowLevelSecurityPolicies(_)
+            | WithOptionValue::ColumnPrivileges(_)
+            | WithOptionValue::IcebergPartitionBy(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
                     // The first few are unreachable because they are handled at the top of the outer match.
@@ -736,6 +772,11 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::ClusterAutoScalingStrategyOptionValue(_) =>
                         "cluster auto scaling strategy",
                     WithOptionValue::NetworkPolicyRules(_) => "network policy rules",
+                    WithOptionValue::RowLevelSecurityPolicies(_) => {
+                        "row level security policies"
+                    }
+                    WithOptionValue::ColumnPrivileges(_) => "column privileges",
+                    WithOptionValue::IcebergPartitionBy(_) => "iceberg partition fields",
                 },
                 V::name()
             ),
@@ -1088,3 +1129,72 @@ impl ImpliedValue for Vec<NetworkPolicyRuleDefinition<Aug>> {
         sql_bail!("must provide a set of network policy rules")
     }
 }
+
+impl TryFromValue<WithOptionValue<Aug>> for Vec<RowLevelSecurityPolicy<Aug>> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::RowLevelSecurityPolicies(policies) => Ok(policies),
+            _ => sql_bail!("cannot use value as row level security policies"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::RowLevelSecurityPolicies(self))
+    }
+
+    fn name() -> String {
+        "row level security policies".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<RowLevelSecurityPolicy<Aug>> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a set of row level security policies")
+    }
+}
+
+impl TryFromValue<WithOptionValue<Aug>> for Vec<ColumnPrivilege> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::ColumnPrivileges(privileges) => Ok(privileges),
+            _ => sql_bail!("cannot use value as column privileges"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::ColumnPrivileges(self))
+    }
+
+    fn name() -> String {
+        "column privileges".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<ColumnPrivilege> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a set of column privileges")
+    }
+}
+
+impl TryFromValue<WithOptionValue<Aug>> for Vec<IcebergPartitionField> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::IcebergPartitionBy(fields) => Ok(fields),
+            _ => sql_bail!("cannot use value as iceberg partition fields"),
+        }
+    }
+
+    fn try_into_value(self, _catalog: &dyn SessionCatalog) -> Option<WithOptionValue<Aug>> {
+        Some(WithOptionValue::IcebergPartitionBy(self))
+    }
+
+    fn name() -> String {
+        "iceberg partition fields".to_string()
+    }
+}
+
+impl ImpliedValue for Vec<IcebergPartitionField> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a set of partition fields")
+    }
+}
@@ -41,26 +41,31 @@ use mz_sql_parser::ast::{
     ColumnName, CreateMaterializedViewStatement, CreateSinkConnection, CreateSinkOptionName,
     CreateSinkStatement, CreateSourceOptionName, CreateSubsourceOption, CreateSubsourceOptionName,
     CreateTableFromSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema, DeferredItemName, DocOnIdentifier,
-    DocOnSchema, Expr, Function, FunctionArgs, GlueAvroOption, GlueAvroSeed, Ident,
-    KafkaSourceConfigOption, KafkaSourceConfigOptionName, LoadGenerator, LoadGeneratorOption,
-    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
-    MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, RawItemName,
-    ReaderSchemaSelectionStrategy, RefreshAtOptionValue, RefreshEveryOptionValue,
-    RefreshOptionValue, SourceEnvelope, SqlServerConfigOption, SqlServerConfigOptionName,
-    Statement, TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName,
-    UnresolvedItemName,
+    CsrSeedAvro, CsrSeedJson, CsrSeedProtobuf, CsrSeedProtobufSchema, DeferredItemName,
+    DocOnIdentifier, DocOnSchema, Expr, Function, FunctionArgs, GlueAvroOption, GlueAvroSeed,
+    Ident, KafkaSourceConfigOption, KafkaSourceConfigOptionName, LoadGenerator,
+    LoadGeneratorOption, LoadGeneratorOptionName, MaterializedViewOption,
+    MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName, PgConfigOption,
+    PgConfigOptionName, RawItemName, ReaderSchemaSelectionStrategy, RefreshAtOptionValue,
+    RefreshEveryOptionValue, RefreshOptionValue, SourceEnvelope, SqlServerConfigOption,
+    SqlServerConfigOptionName, Statement, TableFromSourceColumns, TableFromSourceOption,
+    TableFromSourceOptionName, UnresolvedItemName,
 };
 use mz_sql_server_util::desc::SqlServerTableDesc;
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::connections::Connection;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::errors::ContextCreationError;
+use mz_storage_types::sources::kafka::KafkaTopicSet;
 use mz_storage_types::sources::load_generator::LoadGeneratorOutput;
+use mz_storage_types::sources::mongodb::MONGODB_COLLECTION_DESC;
 use mz_storage_types::sources::mysql::MySqlSourceDetails;
-use mz_storage_types::sources::postgres::PostgresSourcePublicationDetails;
+use mz_storage_types::sources::postgres::{
+    PG_LOGICAL_MESSAGES_DESC, PG_LOGICAL_MESSAGES_REFERENCE, PostgresSourcePublicationDetails,
+};
 use mz_storage_types::sources::{
-    GenericSourceConnection, SourceDesc, SourceExportStatementDetails, SqlServerSourceExtras,
+    GenericSourceConnection, ProtoSourceExportStatementDetails, SourceDesc,
+    SourceExportStatementDetails, SqlServerSourceExtras,
 };
 use prost::Message;
 use protobuf_native::MessageLite;
@@ -71,8 +76,9 @@ use uuid::Uuid;
 
 use crate::ast::{
     AlterSourceAddSubsourceOption, AvroSchema, CreateSourceConnection, CreateSourceStatement,
-    CreateSubsourceStatement, CsrConnectionAvro, CsrConnectionProtobuf, ExternalReferenceExport,
-    ExternalReferences, Format, FormatSpecifier, ProtobufSchema, Value, WithOptionValue,
+    CreateSubsourceStatement, CsrConnectionAvro, CsrConnectionJson, CsrConnectionProtobuf,
+    ExternalReferenceExport, ExternalReferences, Format, FormatSpecifier, ProtobufSchema, Value,
+    WithOptionValue,
 };
 use crate::catalog::{CatalogItemType, SessionCatalog};
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
@@ -83,13 +89,17 @@ use crate::names::{
 use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::load_generator_ast_to_generator;
 use crate::plan::{SourceReferences, StatementContext};
-use crate::pure::error::{IcebergSinkPurificationError, SqlServerSourcePurificationError};
+use crate::pure::error::{
+    IcebergSinkPurificationError, IcebergSourcePurificationError, KinesisSourcePurificationError,
+    MqttSourcePurificationError, NatsSourcePurificationError, SqlServerSourcePurificationError,
+};
 use crate::pure::mysql::{ensure_binlog_full_metadata, is_binlog_full_metadata};
 use crate::{kafka_util, normalize};
 
 use self::error::{
     CsrPurificationError, KafkaSinkPurificationError, KafkaSourcePurificationError,
-    LoadGeneratorSourcePurificationError, MySqlSourcePurificationError, PgSourcePurificationError,
+    LoadGeneratorSourcePurificationError, MongoDbSourcePurificationError,
+    MySqlSourcePurificationError, PgSourcePurificationError, PostgresSinkPurificationError,
 };
 
 pub(crate) mod error;
@@ -267,10 +277,25 @@ pub enum PurifiedExportDetails {
         initial_lsn: mz_sql_server_util::cdc::Lsn,
     },
     Kafka {},
+    Kinesis {},
+    Nats {},
+    Mqtt {},
     LoadGenerator {
         table: Option<RelationDesc>,
         output: LoadGeneratorOutput,
     },
+    /// The logical decoding messages of a PostgreSQL source.
+    PostgresMessages {},
+    MongoDb {
+        collection: String,
+        initial_cluster_time: mz_mongodb_util::ClusterTime,
+    },
+    S3 {},
+    Iceberg {
+        table: String,
+        table_uuid: Uuid,
+        desc: RelationDesc,
+    },
 }
 
 /// Purifies a statement, removing any dependencies on external state.
@@ -308,7 +333,7 @@ pub async fn purify_statement(
             )
         }
         Statement::CreateTableFromSource(stmt) => (
-            purify_create_table_from_source(catalog, stmt, storage_configuration).await,
+            purify_create_table_from_source(&catalog, stmt, storage_configuration).await,
             None,
         ),
         o => (
@@ -348,6 +373,7 @@ pub(crate) fn purify_create_sink_avro_doc_on_options(
         | Format::Bytes
         | Format::Csv { .. }
         | Format::Json { .. }
+        | Format::JsonCsr { .. }
         | Format::Protobuf(..)
         | Format::Regex(..)
         | Format::Text => (),
@@ -630,6 +656,92 @@ async fn purify_create_sink(
                 .await
                 .map_err(|e| IcebergSinkPurificationError::CatalogError(Arc::new(e)))?;
         }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key,
+        } => {
+            // The sink writes into a table owned by the user, so we only check
+            // that the table exists and that the KEY columns can be used as an
+            // `ON CONFLICT` target. The progress table is created by the sink
+            // itself once it starts running.
+            let scx = StatementContext::new(None, &catalog);
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::Postgres(connection) => {
+                    connection.clone().into_inline_connection(scx.catalog)
+                }
+                _ => Err(PostgresSinkPurificationError::NotPgConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+
+            let crate::plan::statement::PostgresSinkConfigOptionExtracted { schema, table, .. } =
+                options.clone().try_into()?;
+            let Some(table) = table else {
+                sql_bail!("Postgres sink must specify TABLE");
+            };
+
+            let client = connection
+                .validate(connection_item.id(), storage_configuration)
+                .await?;
+
+            let key_columns: Vec<String> = key
+                .iter()
+                .flat_map(|key| key.key_columns.iter())
+                .map(|column| normalize::ident(column.clone()))
+                .collect();
+
+            let row = mz_postgres_util::query_opt(
+                &*client,
+                mz_postgres_util::sql!(
+                    "
+                    SELECT EXISTS (
+                        SELECT 1
+                        FROM pg_index AS i
+                        WHERE
+                            i.indrelid = c.oid
+                            AND i.indisunique
+                            AND i.indpred IS NULL
+                            AND i.indexprs IS NULL
+                            AND (
+                                SELECT array_agg(a.attname::text ORDER BY a.attname)
+                                FROM unnest(i.indkey::int2[]) WITH ORDINALITY AS k (attnum, ord)
+                                JOIN pg_attribute AS a
+                                    ON a.attrelid = c.oid AND a.attnum = k.attnum
+                                WHERE k.ord <= i.indnkeyatts
+                            ) = (SELECT array_agg(col ORDER BY col) FROM unnest($3::text[]) AS col)
+                    ) AS key_is_unique
+                    FROM pg_class AS c
+                    JOIN pg_namespace AS n ON c.relnamespace = n.oid
+                    WHERE n.nspname = $1 AND c.relname = $2 AND c.relkind IN ('r', 'p')"
+                ),
+                &[&schema, &table, &key_columns],
+            )
+            .await?;
+
+            let qualified_table = format!("{}.{}", schema, table);
+            match row {
+                None => Err(PostgresSinkPurificationError::TableMissing {
+                    table: qualified_table,
+                })?,
+                Some(row) if key.is_some() && !row.get::<_, bool>("key_is_unique") => {
+                    Err(PostgresSinkPurificationError::KeyNotUnique {
+                        table: qualified_table,
+                        key: key_columns,
+                    })?
+                }
+                Some(_) => (),
+            }
+        }
+        CreateSinkConnection::Http { .. } => {
+            // There is nothing to validate without sending a request, which
+            // the receiver might mistake for a batch of changes.
+        }
+        CreateSinkConnection::S3 { .. } => {
+            // The sink lists the manifests under its URI as soon as it starts,
+            // which reports missing permissions through its status.
+        }
     }
 
     let mut csr_connection_ids = BTreeSet::new();
@@ -647,7 +759,10 @@ async fn purify_create_sink(
         })
         | Format::Protobuf(ProtobufSchema::Csr {
             csr_connection: CsrConnectionProtobuf { connection, .. },
-        }) => {
+        })
+        | Format::JsonCsr {
+            csr_connection: CsrConnectionJson { connection, .. },
+        } => {
             csr_connection_ids.insert(*connection.connection.item_id());
         }
     });
@@ -744,6 +859,20 @@ async fn purify_create_source(
         || external_references.is_some()
         || progress_subsource.is_some();
 
+    // Newer source types only ever output to tables.
+    if uses_old_syntax
+        && matches!(
+            source_connection,
+            CreateSourceConnection::Kinesis { .. }
+                | CreateSourceConnection::Nats { .. }
+                | CreateSourceConnection::Mqtt { .. }
+        )
+    {
+        Err(PlanError::UseTablesForSources(
+            "CREATE SOURCE (ENVELOPE|FORMAT|INCLUDE) for Kinesis, NATS and MQTT".to_string(),
+        ))?;
+    }
+
     if let Some(DeferredItemName::Named(_)) = progress_subsource {
         sql_bail!("Cannot manually ID qualify progress subsource")
     }
@@ -754,6 +883,11 @@ async fn purify_create_source(
         CreateSourceConnection::Kafka { .. } => {
             &mz_storage_types::sources::kafka::KAFKA_PROGRESS_DESC
         }
+        CreateSourceConnection::Kinesis { .. } => {
+            &mz_storage_types::sources::kinesis::KINESIS_PROGRESS_DESC
+        }
+        CreateSourceConnection::Nats { .. } => &mz_storage_types::sources::nats::NATS_PROGRESS_DESC,
+        CreateSourceConnection::Mqtt { .. } => &mz_storage_types::sources::mqtt::MQTT_PROGRESS_DESC,
         CreateSourceConnection::Postgres { .. } => {
             &mz_storage_types::sources::postgres::PG_PROGRESS_DESC
         }
@@ -763,6 +897,13 @@ async fn purify_create_source(
         CreateSourceConnection::MySql { .. } => {
             &mz_storage_types::sources::mysql::MYSQL_PROGRESS_DESC
         }
+        CreateSourceConnection::MongoDb { .. } => {
+            &mz_storage_types::sources::mongodb::MONGODB_PROGRESS_DESC
+        }
+        CreateSourceConnection::S3 { .. } => &mz_storage_types::sources::s3::S3_PROGRESS_DESC,
+        CreateSourceConnection::Iceberg { .. } => {
+            &mz_storage_types::sources::iceberg::ICEBERG_PROGRESS_DESC
+        }
         CreateSourceConnection::LoadGenerator { .. } => {
             &mz_storage_types::sources::load_generator::LOAD_GEN_PROGRESS_DESC
         }
@@ -814,9 +955,24 @@ async fn purify_create_source(
             let extracted_options: KafkaSourceConfigOptionExtracted =
                 base_with_options.clone().try_into()?;
 
-            let topic = extracted_options
-                .topic
-                .ok_or(KafkaSourcePurificationError::ConnectionMissingTopic)?;
+            // A source over more than one topic is referenced by its first listed topic, or by
+            // its pattern.
+            let (topic, topic_set) = match (
+                extracted_options.topic,
+                extracted_options.topics,
+                extracted_options.topic_pattern,
+            ) {
+                (Some(topic), None, None) => (topic, None),
+                (None, Some(topics), None) => {
+                    let topic = topics.first().cloned().unwrap_or_default();
+                    (topic, Some(KafkaTopicSet::List(topics)))
+                }
+                (None, None, Some(pattern)) => {
+                    (pattern.clone(), Some(KafkaTopicSet::Pattern(pattern)))
+                }
+                (None, None, None) => Err(KafkaSourcePurificationError::ConnectionMissingTopic)?,
+                _ => Err(KafkaSourcePurificationError::MultipleTopicOptions)?,
+            };
 
             let consumer = connection
                 .create_with_context(
@@ -834,10 +990,36 @@ async fn purify_create_source(
                 })?;
             let consumer = Arc::new(consumer);
 
+            // The topic whose schema the source is planned against.
+            let schema_topic = match &topic_set {
+                None => topic.clone(),
+                Some(topic_set) => {
+                    if extracted_options.start_offset.is_some()
+                        || extracted_options.start_timestamp.is_some()
+                    {
+                        Err(KafkaSourcePurificationError::StartOffsetWithTopicSet)?
+                    }
+                    // Validate that the listed topics exist, or that the pattern matches at
+                    // least one topic.
+                    kafka_util::first_topic_in_set(
+                        Arc::clone(&consumer),
+                        topic_set,
+                        storage_configuration
+                            .parameters
+                            .kafka_timeout_config
+                            .fetch_metadata_timeout,
+                    )
+                    .await?
+                }
+            };
+
             match (
                 extracted_options.start_offset,
                 extracted_options.start_timestamp,
             ) {
+                (None, None) if topic_set.is_some() => {
+                    // Validated above.
+                }
                 (None, None) => {
                     // Validate that the topic at least exists.
                     kafka_util::ensure_topic_exists(
@@ -900,7 +1082,145 @@ async fn purify_create_source(
             let reference_client = SourceReferenceClient::Kafka { topic: &topic };
             retrieved_source_references = reference_client.get_source_references().await?;
 
-            format_options = SourceFormatOptions::Kafka { topic };
+            format_options = SourceFormatOptions::Kafka {
+                topic: schema_topic,
+            };
+        }
+        CreateSourceConnection::Kinesis {
+            connection,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_KINESIS_SOURCE)?;
+
+            if let Some(external_references) = external_references {
+                Err(KinesisSourcePurificationError::ReferencedSubsources(
+                    external_references.clone(),
+                ))?;
+            }
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let aws_connection = match connection_item.connection()? {
+                Connection::Aws(connection) => connection.clone(),
+                _ => Err(KinesisSourcePurificationError::NotAwsConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let crate::plan::statement::ddl::KinesisConfigOptionExtracted { stream, seen: _ } =
+                options.clone().try_into()?;
+            let stream = stream.ok_or(KinesisSourcePurificationError::ConnectionMissingStream)?;
+
+            // Validate that the stream exists and that the connection can read it.
+            let sdk_config = aws_connection
+                .load_sdk_config(
+                    &storage_configuration.connection_context,
+                    connection_item.id(),
+                    InTask::No,
+                    mz_storage_types::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES
+                        .get(storage_configuration.config_set()),
+                )
+                .await
+                .map_err(|e| KinesisSourcePurificationError::LoadSdkConfigError(Arc::new(e)))?;
+            let client = mz_aws_util::kinesis::new_client(&sdk_config);
+            mz_aws_util::kinesis::list_shards(&client, &stream)
+                .await
+                .map_err(|e| KinesisSourcePurificationError::ListShardsError {
+                    stream: stream.clone(),
+                    cause: Arc::new(e.into()),
+                })?;
+
+            let reference_client = SourceReferenceClient::Kinesis { stream: &stream };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            format_options = SourceFormatOptions::Kinesis { stream };
+        }
+        CreateSourceConnection::Nats {
+            connection,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_NATS_SOURCE)?;
+
+            if let Some(external_references) = external_references {
+                Err(NatsSourcePurificationError::ReferencedSubsources(
+                    external_references.clone(),
+                ))?;
+            }
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let nats_connection = match connection_item.connection()? {
+                Connection::Nats(connection) => connection.clone(),
+                _ => Err(NatsSourcePurificationError::NotNatsConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let crate::plan::statement::ddl::NatsConfigOptionExtracted { stream, .. } =
+                options.clone().try_into()?;
+            let stream = stream.ok_or(NatsSourcePurificationError::ConnectionMissingStream)?;
+
+            // Validate that the stream exists and that the connection can read it.
+            let client = nats_connection
+                .connect(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await
+                .map_err(|e| NatsSourcePurificationError::ConnectError(Arc::new(e)))?;
+            async_nats::jetstream::new(client)
+                .get_stream(&stream)
+                .await
+                .map_err(|e| NatsSourcePurificationError::GetStreamError {
+                    stream: stream.clone(),
+                    cause: Arc::new(e.into()),
+                })?;
+
+            let reference_client = SourceReferenceClient::Nats { stream: &stream };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            format_options = SourceFormatOptions::Nats { stream };
+        }
+        CreateSourceConnection::Mqtt {
+            connection,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_MQTT_SOURCE)?;
+
+            if let Some(external_references) = external_references {
+                Err(MqttSourcePurificationError::ReferencedSubsources(
+                    external_references.clone(),
+                ))?;
+            }
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            match connection_item.connection()? {
+                Connection::Mqtt(_) => {}
+                _ => Err(MqttSourcePurificationError::NotMqttConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let crate::plan::statement::ddl::MqttConfigOptionExtracted {
+                topic,
+                shared_group,
+                ..
+            } = options.clone().try_into()?;
+            let topic = topic.ok_or(MqttSourcePurificationError::ConnectionMissingTopic)?;
+            // Brokers only report invalid subscriptions once the source subscribes, so check
+            // them upfront. Connecting is left to the validation of the connection, as the
+            // broker would take over the session of the running source.
+            if !is_valid_mqtt_topic_filter(&topic) {
+                Err(MqttSourcePurificationError::InvalidTopicFilter(
+                    topic.clone(),
+                ))?;
+            }
+            if let Some(group) = shared_group {
+                if group.is_empty() || group.contains(['/', '+', '#']) {
+                    Err(MqttSourcePurificationError::InvalidSharedGroup(group))?;
+                }
+            }
+
+            let reference_client = SourceReferenceClient::Mqtt { topic: &topic };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            format_options = SourceFormatOptions::Mqtt;
         }
         CreateSourceConnection::Postgres {
             connection,
@@ -1171,6 +1491,98 @@ async fn purify_create_source(
                     Some(WithOptionValue::Sequence(normalized_exclude_columns));
             }
         }
+        CreateSourceConnection::MongoDb {
+            connection,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_MONGODB_SOURCE)?;
+
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::MongoDb(connection) => connection.clone(),
+                _ => Err(MongoDbSourcePurificationError::NotMongoDbConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let crate::plan::statement::ddl::MongoDbConfigOptionExtracted { database, seen: _ } =
+                options.clone().try_into()?;
+            let database =
+                database.ok_or(MongoDbSourcePurificationError::ConnectionMissingDatabase)?;
+
+            let client = connection
+                .validate(connection_item.id(), storage_configuration)
+                .await
+                .map_err(MongoDbSourcePurificationError::InvalidConnection)?;
+
+            let reference_client = SourceReferenceClient::MongoDb {
+                client: &client,
+                database: &database,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            // Collections are only ever added with `CREATE TABLE .. FROM SOURCE`.
+            if let Some(requested) = external_references {
+                Err(PlanError::UseTablesForSources(requested.to_string()))?
+            }
+        }
+        CreateSourceConnection::S3 {
+            connection: _,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_S3_SOURCE)?;
+
+            let crate::plan::statement::ddl::S3ConfigOptionExtracted { uri, .. } =
+                options.clone().try_into()?;
+            let uri = uri.ok_or_else(|| sql_err!("URI option is required"))?;
+            let uri = crate::plan::statement::ddl::parse_s3_source_uri(&uri)?;
+
+            let reference_client = SourceReferenceClient::S3 {
+                bucket: uri.host().expect("validated"),
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            // The columns of the objects are only ever declared with
+            // `CREATE TABLE .. FROM SOURCE`.
+            if let Some(requested) = external_references {
+                Err(PlanError::UseTablesForSources(requested.to_string()))?
+            }
+        }
+        CreateSourceConnection::Iceberg {
+            catalog_connection,
+            options,
+        } => {
+            scx.require_feature_flag(&crate::session::vars::ENABLE_ICEBERG_SOURCE)?;
+
+            let connection_item = scx.get_item_by_resolved_name(catalog_connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::IcebergCatalog(connection) => {
+                    connection.clone().into_inline_connection(scx.catalog)
+                }
+                _ => Err(IcebergSourcePurificationError::NotIcebergCatalogConnection(
+                    scx.catalog.resolve_full_name(connection_item.name()),
+                ))?,
+            };
+            let crate::plan::statement::ddl::IcebergConfigOptionExtracted { namespace, seen: _ } =
+                options.clone().try_into()?;
+            let namespace =
+                namespace.ok_or(IcebergSourcePurificationError::ConnectionMissingNamespace)?;
+
+            let iceberg_catalog = connection
+                .connect(storage_configuration, InTask::No)
+                .await
+                .map_err(|e| IcebergSourcePurificationError::CatalogError(Arc::new(e)))?;
+
+            let reference_client = SourceReferenceClient::Iceberg {
+                catalog: &*iceberg_catalog,
+                namespace: &namespace,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            // Tables are only ever added with `CREATE TABLE .. FROM SOURCE`.
+            if let Some(requested) = external_references {
+                Err(PlanError::UseTablesForSources(requested.to_string()))?
+            }
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let load_generator =
                 load_generator_ast_to_generator(&scx, generator, options, include_metadata)?;
@@ -1316,7 +1728,10 @@ async fn purify_create_source(
         let mut progress_with_options: Vec<_> = with_options
             .iter()
             .filter_map(|opt| match opt.name {
-                CreateSourceOptionName::TimestampInterval => None,
+                CreateSourceOptionName::TimestampInterval
+                | CreateSourceOptionName::ColumnPrivileges
+                | CreateSourceOptionName::RowLevelSecurity
+                | CreateSourceOptionName::RowLevelSecurityPolicies => None,
                 CreateSourceOptionName::RetainHistory => Some(CreateSubsourceOption {
                     name: CreateSubsourceOptionName::RetainHistory,
                     value: opt.value.clone(),
@@ -1737,6 +2152,43 @@ async fn purify_alter_source_refresh_references(
             .await?;
             source_references
         }
+        GenericSourceConnection::MongoDb(mongodb_source) => {
+            let config = mongodb_source
+                .connection
+                .config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let client = config.connect().await?;
+
+            let reference_client = SourceReferenceClient::MongoDb {
+                client: &client,
+                database: &mongodb_source.database,
+            };
+            reference_client.get_source_references().await?
+        }
+        GenericSourceConnection::S3(s3_source) => {
+            let uri = crate::plan::statement::ddl::parse_s3_source_uri(&s3_source.uri)?;
+            let reference_client = SourceReferenceClient::S3 {
+                bucket: uri.host().expect("validated"),
+            };
+            reference_client.get_source_references().await?
+        }
+        GenericSourceConnection::Iceberg(iceberg_source) => {
+            let iceberg_catalog = iceberg_source
+                .catalog_connection
+                .connect(storage_configuration, InTask::No)
+                .await
+                .map_err(|e| IcebergSourcePurificationError::CatalogError(Arc::new(e)))?;
+
+            let reference_client = SourceReferenceClient::Iceberg {
+                catalog: &*iceberg_catalog,
+                namespace: &iceberg_source.namespace,
+            };
+            reference_client.get_source_references().await?
+        }
         GenericSourceConnection::LoadGenerator(load_gen_connection) => {
             let reference_client = SourceReferenceClient::LoadGenerator {
                 generator: &load_gen_connection.load_generator,
@@ -1749,6 +2201,24 @@ async fn purify_alter_source_refresh_references(
             };
             reference_client.get_source_references().await?
         }
+        GenericSourceConnection::Kinesis(kinesis_conn) => {
+            let reference_client = SourceReferenceClient::Kinesis {
+                stream: &kinesis_conn.stream,
+            };
+            reference_client.get_source_references().await?
+        }
+        GenericSourceConnection::Nats(nats_conn) => {
+            let reference_client = SourceReferenceClient::Nats {
+                stream: &nats_conn.stream,
+            };
+            reference_client.get_source_references().await?
+        }
+        GenericSourceConnection::Mqtt(mqtt_conn) => {
+            let reference_client = SourceReferenceClient::Mqtt {
+                topic: &mqtt_conn.topic,
+            };
+            reference_client.get_source_references().await?
+        }
     };
     Ok(PurifiedStatement::PurifiedAlterSourceRefreshReferences {
         source_name: resolved_source_name,
@@ -1757,11 +2227,11 @@ async fn purify_alter_source_refresh_references(
 }
 
 async fn purify_create_table_from_source(
-    catalog: impl SessionCatalog,
+    catalog: &impl SessionCatalog,
     mut stmt: CreateTableFromSourceStatement<Aug>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<PurifiedStatement, PlanError> {
-    let scx = StatementContext::new(None, &catalog);
+    let scx = StatementContext::new(None, catalog);
     let CreateTableFromSourceStatement {
         name: _,
         columns,
@@ -1775,16 +2245,6 @@ async fn purify_create_table_from_source(
         with_options,
     } = &mut stmt;
 
-    // Columns and constraints cannot be specified by the user but will be populated below.
-    if matches!(columns, TableFromSourceColumns::Defined(_)) {
-        sql_bail!("CREATE TABLE .. FROM SOURCE column definitions cannot be specified directly");
-    }
-    if !constraints.is_empty() {
-        sql_bail!(
-            "CREATE TABLE .. FROM SOURCE constraint definitions cannot be specified directly"
-        );
-    }
-
     // Get the source item
     let item = match scx.get_item_by_resolved_name(source_name) {
         Ok(item) => item,
@@ -1798,6 +2258,21 @@ async fn purify_create_table_from_source(
             sql_bail!("cannot ALTER this type of source")
         }
     };
+
+    // Columns and constraints cannot be specified by the user but will be populated below,
+    // except for S3 sources, whose objects don't describe their own columns.
+    if !matches!(desc.connection, GenericSourceConnection::S3(_)) {
+        if matches!(columns, TableFromSourceColumns::Defined(_)) {
+            sql_bail!(
+                "CREATE TABLE .. FROM SOURCE column definitions cannot be specified directly"
+            );
+        }
+        if !constraints.is_empty() {
+            sql_bail!(
+                "CREATE TABLE .. FROM SOURCE constraint definitions cannot be specified directly"
+            );
+        }
+    }
     let unresolved_source_name: UnresolvedItemName = source_name.full_item_name().clone().into();
 
     let crate::plan::statement::ddl::TableFromSourceOptionExtracted {
@@ -1806,6 +2281,11 @@ async fn purify_create_table_from_source(
         retain_history: _,
         details,
         partition_by: _,
+        row_level_security: _,
+        row_level_security_policies: _,
+        column_privileges: _,
+        retract_on_truncate: _,
+        schema_change_policy: _,
         seen: _,
     } = with_options.clone().try_into()?;
     if details.is_some() {
@@ -1861,32 +2341,47 @@ async fn purify_create_table_from_source(
                 .validate(pg_source_connection.connection_id, storage_configuration)
                 .await?;
 
-            let reference_client = SourceReferenceClient::Postgres {
-                client: &client,
-                publication: &pg_source_connection.publication,
-                database: &pg_connection.database,
-            };
-            retrieved_source_references = reference_client.get_source_references().await?;
-
-            let postgres::PurifiedSourceExports {
-                source_exports,
-                // TODO(database-issues#8620): Remove once subsources are removed
-                // This `normalized_text_columns` is not relevant for us and is only returned for
-                // `CREATE SOURCE` statements that automatically generate subsources
-                normalized_text_columns: _,
-            } = postgres::purify_source_exports(
-                &client,
-                &retrieved_source_references,
-                &requested_references,
-                qualified_text_columns,
-                qualified_exclude_columns,
-                &unresolved_source_name,
-                &SourceReferencePolicy::Required,
-            )
-            .await?;
-            // There should be exactly one source_export returned for this statement
-            let (_, purified_export) = source_exports.into_element();
-            purified_export
+            // The logical decoding messages are not part of the publication, so there is no
+            // upstream reference to resolve.
+            if external_reference
+                .as_ref()
+                .is_some_and(postgres::is_logical_messages_reference)
+            {
+                postgres::validate_logical_messages(&client).await?;
+                PurifiedSourceExport {
+                    external_reference: UnresolvedItemName::unqualified(Ident::new_unchecked(
+                        PG_LOGICAL_MESSAGES_REFERENCE,
+                    )),
+                    details: PurifiedExportDetails::PostgresMessages {},
+                }
+            } else {
+                let reference_client = SourceReferenceClient::Postgres {
+                    client: &client,
+                    publication: &pg_source_connection.publication,
+                    database: &pg_connection.database,
+                };
+                retrieved_source_references = reference_client.get_source_references().await?;
+
+                let postgres::PurifiedSourceExports {
+                    source_exports,
+                    // TODO(database-issues#8620): Remove once subsources are removed
+                    // This `normalized_text_columns` is not relevant for us and is only returned
+                    // for `CREATE SOURCE` statements that automatically generate subsources
+                    normalized_text_columns: _,
+                } = postgres::purify_source_exports(
+                    &client,
+                    &retrieved_source_references,
+                    &requested_references,
+                    qualified_text_columns,
+                    qualified_exclude_columns,
+                    &unresolved_source_name,
+                    &SourceReferencePolicy::Required,
+                )
+                .await?;
+                // There should be exactly one source_export returned for this statement
+                let (_, purified_export) = source_exports.into_element();
+                purified_export
+            }
         }
         GenericSourceConnection::MySql(mysql_source_connection) => {
             let mysql_connection = &mysql_source_connection.connection;
@@ -1981,13 +2476,60 @@ async fn purify_create_table_from_source(
             let (_, purified_export) = purified_source_exports.source_exports.into_element();
             purified_export
         }
-        GenericSourceConnection::LoadGenerator(load_gen_connection) => {
-            let reference_client = SourceReferenceClient::LoadGenerator {
-                generator: &load_gen_connection.load_generator,
-            };
-            retrieved_source_references = reference_client.get_source_references().await?;
-
-            let requested_exports = retrieved_source_references
+        GenericSourceConnection::MongoDb(mongodb_source) => {
+            let config = mongodb_source
+                .connection
+                .config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let client = config.connect().await?;
+
+            let reference_client = SourceReferenceClient::MongoDb {
+                client: &client,
+                database: &mongodb_source.database,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            let collection = export
+                .meta
+                .mongodb_collection()
+                .ok_or_else(|| internal_err!("expected MongoDB source reference"))?;
+
+            // Without the pre-images of updates and deletes we can't retract the previous
+            // version of a document.
+            if !collection.pre_and_post_images {
+                Err(MongoDbSourcePurificationError::MissingPreAndPostImages(
+                    collection.name.clone(),
+                ))?;
+            }
+
+            // Every write visible in the table's snapshot has a cluster time at or before this
+            // one, which lets the source skip the changes the snapshot already reflects.
+            let initial_cluster_time =
+                mz_mongodb_util::inspect::get_current_cluster_time(&client).await?;
+
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::MongoDb {
+                    collection: collection.name.clone(),
+                    initial_cluster_time,
+                },
+            }
+        }
+        GenericSourceConnection::LoadGenerator(load_gen_connection) => {
+            let reference_client = SourceReferenceClient::LoadGenerator {
+                generator: &load_gen_connection.load_generator,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            let requested_exports = retrieved_source_references
                 .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
             // There should be exactly one source_export returned
             let export = requested_exports.into_element();
@@ -2017,18 +2559,159 @@ async fn purify_create_table_from_source(
             // There should be exactly one source_export returned
             let export = requested_exports.into_element();
 
-            format_options = SourceFormatOptions::Kafka {
-                topic: kafka_conn.topic.clone(),
+            let topic = match &kafka_conn.topic_set {
+                None => kafka_conn.topic.clone(),
+                Some(topic_set) => {
+                    let consumer = kafka_conn
+                        .connection
+                        .create_with_context(
+                            storage_configuration,
+                            MzClientContext::default(),
+                            &BTreeMap::new(),
+                            InTask::No,
+                        )
+                        .await
+                        .map_err(|e| {
+                            KafkaSourcePurificationError::KafkaConsumerError(
+                                e.display_with_causes().to_string(),
+                            )
+                        })?;
+                    kafka_util::first_topic_in_set(
+                        Arc::new(consumer),
+                        topic_set,
+                        storage_configuration
+                            .parameters
+                            .kafka_timeout_config
+                            .fetch_metadata_timeout,
+                    )
+                    .await?
+                }
             };
+            format_options = SourceFormatOptions::Kafka { topic };
             PurifiedSourceExport {
                 external_reference: export.external_reference,
                 details: PurifiedExportDetails::Kafka {},
             }
         }
+        GenericSourceConnection::Kinesis(kinesis_conn) => {
+            let reference_client = SourceReferenceClient::Kinesis {
+                stream: &kinesis_conn.stream,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            format_options = SourceFormatOptions::Kinesis {
+                stream: kinesis_conn.stream.clone(),
+            };
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::Kinesis {},
+            }
+        }
+        GenericSourceConnection::Nats(nats_conn) => {
+            let reference_client = SourceReferenceClient::Nats {
+                stream: &nats_conn.stream,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            format_options = SourceFormatOptions::Nats {
+                stream: nats_conn.stream.clone(),
+            };
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::Nats {},
+            }
+        }
+        GenericSourceConnection::Mqtt(mqtt_conn) => {
+            let reference_client = SourceReferenceClient::Mqtt {
+                topic: &mqtt_conn.topic,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            format_options = SourceFormatOptions::Mqtt;
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::Mqtt {},
+            }
+        }
+        GenericSourceConnection::S3(s3_source) => {
+            let uri = crate::plan::statement::ddl::parse_s3_source_uri(&s3_source.uri)?;
+            let reference_client = SourceReferenceClient::S3 {
+                bucket: uri.host().expect("validated"),
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::S3 {},
+            }
+        }
+        GenericSourceConnection::Iceberg(iceberg_source) => {
+            let iceberg_catalog = iceberg_source
+                .catalog_connection
+                .connect(storage_configuration, InTask::No)
+                .await
+                .map_err(|e| IcebergSourcePurificationError::CatalogError(Arc::new(e)))?;
+
+            let reference_client = SourceReferenceClient::Iceberg {
+                catalog: &*iceberg_catalog,
+                namespace: &iceberg_source.namespace,
+            };
+            retrieved_source_references = reference_client.get_source_references().await?;
+
+            let requested_exports = retrieved_source_references
+                .requested_source_exports(requested_references.as_ref(), &unresolved_source_name)?;
+            // There should be exactly one source_export returned
+            let export = requested_exports.into_element();
+            let table_name = export
+                .meta
+                .iceberg_table()
+                .ok_or_else(|| internal_err!("expected Iceberg source reference"))?;
+
+            let table = iceberg_catalog
+                .load_table(&iceberg::TableIdent::new(
+                    iceberg::NamespaceIdent::new(iceberg_source.namespace.clone()),
+                    table_name.to_string(),
+                ))
+                .await
+                .map_err(|e| IcebergSourcePurificationError::CatalogError(Arc::new(e.into())))?;
+            let metadata = table.metadata();
+
+            // The source orders the snapshots of a table by their sequence numbers, which
+            // format version 1 doesn't assign.
+            if matches!(metadata.format_version(), iceberg::spec::FormatVersion::V1) {
+                Err(IcebergSourcePurificationError::UnsupportedFormatVersion {
+                    table: table_name.to_string(),
+                    version: metadata.format_version() as u8,
+                })?;
+            }
+
+            PurifiedSourceExport {
+                external_reference: export.external_reference,
+                details: PurifiedExportDetails::Iceberg {
+                    table: table_name.to_string(),
+                    table_uuid: metadata.uuid(),
+                    desc: crate::iceberg::iceberg_schema_to_relation_desc(
+                        metadata.current_schema(),
+                    )?,
+                },
+            }
+        }
     };
 
     purify_source_format(
-        &catalog,
+        catalog,
         format,
         &format_options,
         envelope,
@@ -2250,6 +2933,132 @@ async fn purify_create_table_from_source(
                 )))),
             })
         }
+        PurifiedExportDetails::Kinesis {} => {
+            // Like Kafka tables, the schema of Kinesis tables is purified into the format field.
+            let details = SourceExportStatementDetails::Kinesis {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::Nats {} => {
+            let details = SourceExportStatementDetails::Nats {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::Mqtt {} => {
+            let details = SourceExportStatementDetails::Mqtt {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::PostgresMessages {} => {
+            let (gen_columns, gen_constraints) =
+                scx.relation_desc_into_table_defs(&PG_LOGICAL_MESSAGES_DESC)?;
+            match columns {
+                TableFromSourceColumns::Defined(_) => bail_internal!(
+                    "column definitions cannot be explicitly set for this source type"
+                ),
+                TableFromSourceColumns::NotSpecified => {
+                    *columns = TableFromSourceColumns::Defined(gen_columns);
+                    *constraints = gen_constraints;
+                }
+                TableFromSourceColumns::Named(_) => {
+                    sql_bail!("columns cannot be named for PostgreSQL logical message tables")
+                }
+            }
+            let details = SourceExportStatementDetails::PostgresMessages {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::MongoDb {
+            collection,
+            initial_cluster_time,
+        } => {
+            let (gen_columns, gen_constraints) =
+                scx.relation_desc_into_table_defs(&MONGODB_COLLECTION_DESC)?;
+            match columns {
+                TableFromSourceColumns::Defined(_) => bail_internal!(
+                    "column definitions cannot be explicitly set for this source type"
+                ),
+                TableFromSourceColumns::NotSpecified => {
+                    *columns = TableFromSourceColumns::Defined(gen_columns);
+                    *constraints = gen_constraints;
+                }
+                TableFromSourceColumns::Named(_) => {
+                    sql_bail!("columns cannot be named for MongoDB sources")
+                }
+            }
+            let details = SourceExportStatementDetails::MongoDb {
+                collection: collection.clone(),
+                initial_cluster_time: *initial_cluster_time,
+            };
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::Iceberg {
+            table,
+            table_uuid,
+            desc,
+        } => {
+            let (gen_columns, gen_constraints) = scx.relation_desc_into_table_defs(desc)?;
+            match columns {
+                TableFromSourceColumns::Defined(_) => bail_internal!(
+                    "column definitions cannot be explicitly set for this source type"
+                ),
+                TableFromSourceColumns::NotSpecified => {
+                    *columns = TableFromSourceColumns::Defined(gen_columns);
+                    *constraints = gen_constraints;
+                }
+                TableFromSourceColumns::Named(_) => {
+                    sql_bail!("columns cannot be named for Iceberg sources")
+                }
+            }
+            let details = SourceExportStatementDetails::Iceberg {
+                table: table.clone(),
+                table_uuid: *table_uuid,
+            };
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
+        PurifiedExportDetails::S3 {} => {
+            // The objects of an S3 source are decoded directly into the columns of the table,
+            // which the user must declare.
+            match columns {
+                TableFromSourceColumns::Defined(_) => {}
+                TableFromSourceColumns::NotSpecified | TableFromSourceColumns::Named(_) => {
+                    sql_bail!("S3 source tables require column definitions")
+                }
+            }
+            let details = SourceExportStatementDetails::S3 {};
+            with_options.push(TableFromSourceOption {
+                name: TableFromSourceOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
     };
 
     // TODO: We might as well use the retrieved available references to update the source
@@ -2258,9 +3067,187 @@ async fn purify_create_table_from_source(
     Ok(PurifiedStatement::PurifiedCreateTableFromSource { stmt })
 }
 
+/// A column that was added to the upstream table of a `CREATE TABLE .. FROM SOURCE` statement.
+#[derive(Debug, Clone)]
+pub struct SourceTableAddedColumn {
+    pub name: mz_repr::ColumnName,
+    pub typ: mz_repr::SqlColumnType,
+    pub raw_sql_type: mz_sql_parser::ast::RawDataType,
+    /// The hex-encoded `DETAILS` option of the table once this column has been added to it.
+    pub details: String,
+}
+
+/// Determines which columns were added to the upstream table of a PostgreSQL or MySQL source
+/// table since its statement was last purified, in upstream order.
+///
+/// The columns are described the same way purification describes the columns of a new table, so
+/// the table's `TEXT COLUMNS` and `EXCLUDE COLUMNS` options apply to them. They are always
+/// nullable, because the rows the table already contains have no value for them.
+pub async fn purify_source_table_added_columns(
+    catalog: impl SessionCatalog,
+    stmt: CreateTableFromSourceStatement<Aug>,
+    storage_configuration: &StorageConfiguration,
+) -> Result<Vec<SourceTableAddedColumn>, PlanError> {
+    let details = crate::plan::statement::ddl::TableFromSourceOptionExtracted::try_from(
+        stmt.with_options.clone(),
+    )?
+    .details
+    .ok_or_else(|| internal_err!("source-export missing details"))?;
+    let details = hex::decode(details).map_err(|e| sql_err!("{}", e))?;
+    let details =
+        ProtoSourceExportStatementDetails::decode(&*details).map_err(|e| sql_err!("{}", e))?;
+    let details =
+        SourceExportStatementDetails::from_proto(details).map_err(|e| sql_err!("{}", e))?;
+
+    // Purify the statement as if the table was created right now, which describes the current
+    // upstream table.
+    let mut fresh = stmt;
+    fresh.columns = TableFromSourceColumns::NotSpecified;
+    fresh.constraints = vec![];
+    fresh
+        .with_options
+        .retain(|o| o.name != TableFromSourceOptionName::Details);
+    let fresh =
+        match purify_create_table_from_source(&catalog, fresh, storage_configuration).await? {
+            PurifiedStatement::PurifiedCreateTableFromSource { stmt } => stmt,
+            _ => bail_internal!("expected purified CREATE TABLE .. FROM SOURCE statement"),
+        };
+    let fresh_columns = match &fresh.columns {
+        TableFromSourceColumns::Defined(columns) => columns,
+        _ => bail_internal!("purification must define the columns of the table"),
+    };
+    let fresh_details = crate::plan::statement::ddl::TableFromSourceOptionExtracted::try_from(
+        fresh.with_options.clone(),
+    )?
+    .details
+    .ok_or_else(|| internal_err!("source-export missing details"))?;
+    let fresh_details = hex::decode(fresh_details).map_err(|e| sql_err!("{}", e))?;
+    let fresh_details = ProtoSourceExportStatementDetails::decode(&*fresh_details)
+        .map_err(|e| sql_err!("{}", e))?;
+    let fresh_details =
+        SourceExportStatementDetails::from_proto(fresh_details).map_err(|e| sql_err!("{}", e))?;
+
+    // The upstream columns that are not yet part of the table, along with the details of the
+    // table after each of them has been added.
+    let mut added = vec![];
+    match (details, fresh_details) {
+        (
+            SourceExportStatementDetails::Postgres {
+                mut table,
+                cast_oid_full_range,
+            },
+            SourceExportStatementDetails::Postgres {
+                table: fresh_table, ..
+            },
+        ) => {
+            for column in fresh_table.columns {
+                if table.columns.iter().any(|c| c.name == column.name) {
+                    continue;
+                }
+                let name = column.name.clone();
+                table.columns.push(column);
+                let details = SourceExportStatementDetails::Postgres {
+                    table: table.clone(),
+                    cast_oid_full_range,
+                };
+                added.push((name, details));
+            }
+        }
+        (
+            SourceExportStatementDetails::MySql {
+                mut table,
+                initial_gtid_set,
+                binlog_full_metadata,
+            },
+            SourceExportStatementDetails::MySql {
+                table: fresh_table, ..
+            },
+        ) => {
+            for column in fresh_table.columns {
+                if table.columns.iter().any(|c| c.name == column.name) {
+                    continue;
+                }
+                let name = column.name.clone();
+                table.columns.push(column);
+                let details = SourceExportStatementDetails::MySql {
+                    table: table.clone(),
+                    initial_gtid_set: initial_gtid_set.clone(),
+                    binlog_full_metadata,
+                };
+                added.push((name, details));
+            }
+        }
+        _ => sql_bail!("only PostgreSQL and MySQL source tables can follow upstream columns"),
+    }
+
+    let scx = StatementContext::new(None, &catalog);
+    let mut columns = vec![];
+    for (name, details) in added {
+        let details = hex::encode(details.into_proto().encode_to_vec());
+        // Columns without a definition are excluded from the table.
+        let Some(column) = fresh_columns.iter().find(|c| c.name.as_str() == name) else {
+            continue;
+        };
+        let typ = crate::plan::query::scalar_type_from_sql(&scx, &column.data_type)?;
+        let raw_sql_type =
+            mz_sql_parser::parser::parse_data_type(&column.data_type.to_ast_string_stable())?;
+        columns.push(SourceTableAddedColumn {
+            name: normalize::column_name(column.name.clone()),
+            typ: typ.nullable(true),
+            raw_sql_type,
+            details,
+        });
+    }
+    Ok(columns)
+}
+
 enum SourceFormatOptions {
     Default,
     Kafka { topic: String },
+    Kinesis { stream: String },
+    Nats { stream: String },
+    Mqtt,
+}
+
+impl SourceFormatOptions {
+    /// Returns the name that schema registry subjects are derived from, if the source can decode
+    /// its records using a schema registry. Kinesis and NATS streams take the place of Kafka
+    /// topics.
+    fn subject_name(&self) -> Option<&str> {
+        match self {
+            SourceFormatOptions::Default | SourceFormatOptions::Mqtt => None,
+            SourceFormatOptions::Kafka { topic } => Some(topic),
+            SourceFormatOptions::Kinesis { stream } | SourceFormatOptions::Nats { stream } => {
+                Some(stream)
+            }
+        }
+    }
+
+    /// Returns whether the records of the source have a key that can be decoded separately from
+    /// their value.
+    fn supports_key_value(&self) -> bool {
+        match self {
+            SourceFormatOptions::Default => false,
+            SourceFormatOptions::Kafka { .. }
+            | SourceFormatOptions::Kinesis { .. }
+            | SourceFormatOptions::Nats { .. }
+            | SourceFormatOptions::Mqtt => true,
+        }
+    }
+}
+
+/// Reports whether `filter` is a valid MQTT topic filter, i.e. a non-empty filter in which the
+/// `#` wildcard only appears as the last level and wildcards always occupy a whole level.
+fn is_valid_mqtt_topic_filter(filter: &str) -> bool {
+    if filter.is_empty() || filter.contains('\0') {
+        return false;
+    }
+    let levels: Vec<_> = filter.split('/').collect();
+    levels.iter().enumerate().all(|(i, level)| match *level {
+        "#" => i == levels.len() - 1,
+        "+" => true,
+        level => !level.contains(['#', '+']),
+    })
 }
 
 async fn purify_source_format(
@@ -2270,10 +3257,11 @@ async fn purify_source_format(
     envelope: &Option<SourceEnvelope>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
-    if matches!(format, Some(FormatSpecifier::KeyValue { .. }))
-        && !matches!(options, SourceFormatOptions::Kafka { .. })
-    {
-        sql_bail!("Kafka sources are the only source type that can provide KEY/VALUE formats")
+    if matches!(format, Some(FormatSpecifier::KeyValue { .. })) && !options.supports_key_value() {
+        sql_bail!(
+            "Kafka, Kinesis, NATS and MQTT sources are the only source types that can provide \
+            KEY/VALUE formats"
+        )
     }
 
     match format.as_mut() {
@@ -2342,6 +3330,16 @@ async fn purify_source_format_single(
             }
             ProtobufSchema::InlineSchema { .. } => {}
         },
+        Format::JsonCsr { csr_connection } => {
+            purify_csr_connection_json(
+                catalog,
+                options,
+                csr_connection,
+                envelope,
+                storage_configuration,
+            )
+            .await?;
+        }
         Format::Bytes
         | Format::Regex(_)
         | Format::Json { .. }
@@ -2431,6 +3429,27 @@ pub fn generate_subsource_statements(
 
             subsource_stmts
         }
+        PurifiedExportDetails::PostgresMessages { .. } => {
+            bail_internal!("subsources cannot be generated for logical decoding messages")
+        }
+        PurifiedExportDetails::MongoDb { .. } => {
+            bail_internal!("subsources cannot be generated for MongoDB sources")
+        }
+        PurifiedExportDetails::S3 { .. } => {
+            bail_internal!("subsources cannot be generated for S3 sources")
+        }
+        PurifiedExportDetails::Iceberg { .. } => {
+            bail_internal!("subsources cannot be generated for Iceberg sources")
+        }
+        PurifiedExportDetails::Kinesis { .. } => {
+            bail_internal!("subsources cannot be generated for Kinesis sources")
+        }
+        PurifiedExportDetails::Nats { .. } => {
+            bail_internal!("subsources cannot be generated for NATS sources")
+        }
+        PurifiedExportDetails::Mqtt { .. } => {
+            bail_internal!("subsources cannot be generated for MQTT sources")
+        }
         PurifiedExportDetails::Kafka { .. } => {
             // TODO: as part of database-issues#8322, Kafka sources will begin
             // producing data––we'll need to understand the schema
@@ -2451,20 +3470,27 @@ async fn purify_csr_connection_proto(
     envelope: &Option<SourceEnvelope>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
-    let SourceFormatOptions::Kafka { topic } = options else {
-        sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+    let Some(topic) = options.subject_name() else {
+        sql_bail!(
+            "Confluent Schema Registry is only supported with Kafka, Kinesis and NATS sources"
+        )
     };
 
     let CsrConnectionProtobuf {
         seed,
         connection: CsrConnection {
             connection,
-            options: _,
+            options,
         },
     } = csr_connection;
     match seed {
         None => {
             let scx = StatementContext::new(None, &*catalog);
+            let crate::plan::statement::ddl::CsrConfigOptionExtracted {
+                key_message,
+                value_message,
+                ..
+            } = options.clone().try_into()?;
 
             let ccsr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
                 Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
@@ -2476,10 +3502,21 @@ async fn purify_csr_connection_proto(
                 .await
                 .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
 
-            let value = compile_proto(&format!("{}-value", topic), &ccsr_client).await?;
-            let key = compile_proto(&format!("{}-key", topic), &ccsr_client)
-                .await
-                .ok();
+            let value = compile_proto(
+                &format!("{}-value", topic),
+                &ccsr_client,
+                value_message.as_deref(),
+                "VALUE MESSAGE",
+            )
+            .await?;
+            let key = compile_proto(
+                &format!("{}-key", topic),
+                &ccsr_client,
+                key_message.as_deref(),
+                "KEY MESSAGE",
+            )
+            .await
+            .ok();
 
             if matches!(envelope, Some(SourceEnvelope::Debezium)) && key.is_none() {
                 sql_bail!("Key schema is required for ENVELOPE DEBEZIUM");
@@ -2493,6 +3530,67 @@ async fn purify_csr_connection_proto(
     Ok(())
 }
 
+async fn purify_csr_connection_json(
+    catalog: &dyn SessionCatalog,
+    options: &SourceFormatOptions,
+    csr_connection: &mut CsrConnectionJson<Aug>,
+    envelope: &Option<SourceEnvelope>,
+    storage_configuration: &StorageConfiguration,
+) -> Result<(), PlanError> {
+    let Some(topic) = options.subject_name() else {
+        sql_bail!(
+            "Confluent Schema Registry is only supported with Kafka, Kinesis and NATS sources"
+        )
+    };
+
+    let CsrConnectionJson {
+        seed,
+        connection: CsrConnection {
+            connection,
+            options: _,
+        },
+    } = csr_connection;
+    if seed.is_none() {
+        let scx = StatementContext::new(None, &*catalog);
+
+        let ccsr_connection = match scx.get_item_by_resolved_name(connection)?.connection()? {
+            Connection::Csr(connection) => connection.clone().into_inline_connection(catalog),
+            _ => sql_bail!("{} is not a schema registry connection", connection),
+        };
+
+        let ccsr_client = ccsr_connection
+            .connect(storage_configuration, InTask::No)
+            .await
+            .map_err(|e| CsrPurificationError::ClientError(Arc::new(e)))?;
+
+        let value_subject = format!("{}-value", topic);
+        let value_schema = ccsr_client
+            .get_schema_by_subject(&value_subject)
+            .await
+            .map_err(|e| PlanError::FetchingCsrSchemaFailed {
+                schema_lookup: format!("subject {}", value_subject.quoted()),
+                cause: Arc::new(e),
+            })?
+            .raw;
+        let key_schema = ccsr_client
+            .get_schema_by_subject(&format!("{}-key", topic))
+            .await
+            .ok()
+            .map(|schema| schema.raw);
+
+        if matches!(envelope, Some(SourceEnvelope::Debezium)) && key_schema.is_none() {
+            sql_bail!("Key schema is required for ENVELOPE DEBEZIUM");
+        }
+
+        *seed = Some(CsrSeedJson {
+            key_schema,
+            value_schema,
+        });
+    }
+
+    Ok(())
+}
+
 async fn purify_csr_connection_avro(
     catalog: &dyn SessionCatalog,
     options: &SourceFormatOptions,
@@ -2500,8 +3598,10 @@ async fn purify_csr_connection_avro(
     envelope: &Option<SourceEnvelope>,
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
-    let SourceFormatOptions::Kafka { topic } = options else {
-        sql_bail!("Confluent Schema Registry is only supported with Kafka sources")
+    let Some(topic) = options.subject_name() else {
+        sql_bail!(
+            "Confluent Schema Registry is only supported with Kafka, Kinesis and NATS sources"
+        )
     };
 
     let CsrConnectionAvro {
@@ -2557,9 +3657,9 @@ async fn purify_glue_connection_avro(
     storage_configuration: &StorageConfiguration,
 ) -> Result<(), PlanError> {
     use crate::pure::error::GluePurificationError;
-    let SourceFormatOptions::Kafka { .. } = options else {
-        sql_bail!("AWS Glue Schema Registry is only supported with Kafka sources")
-    };
+    if options.subject_name().is_none() {
+        sql_bail!("AWS Glue Schema Registry is only supported with Kafka, Kinesis and NATS sources")
+    }
 
     let scx = StatementContext::new(None, &*catalog);
     let item = scx.get_item_by_resolved_name(connection)?;
@@ -2749,9 +3849,15 @@ async fn get_remote_csr_schema(
 }
 
 /// Collect protobuf message descriptor from CSR and compile the descriptor.
+///
+/// The message to decode is `message_name` if given, and otherwise the only
+/// message in the schema. `option_name` names the option that sets
+/// `message_name`, for error messages.
 async fn compile_proto(
     subject_name: &String,
     ccsr_client: &Client,
+    message_name: Option<&str>,
+    option_name: &str,
 ) -> Result<CsrSeedProtobufSchema, PlanError> {
     let (primary_subject, dependency_subjects) = ccsr_client
         .get_subject_and_references(subject_name)
@@ -2781,12 +3887,18 @@ async fn compile_proto(
         .build_file_descriptor_set(&[Path::new(&primary_subject.name)])
         .map_err(|cause| PlanError::InvalidProtobufSchema { cause })?;
 
-    // Ensure there is exactly one message in the file.
+    // Unless a message was named, ensure there is exactly one message in the file.
     let primary_fd = fds.file(0);
-    let message_name = match primary_fd.message_type_size() {
-        1 => String::from_utf8_lossy(primary_fd.message_type(0).name()).into_owned(),
-        0 => bail_unsupported!(29603, "Protobuf schemas with no messages"),
-        _ => bail_unsupported!(29603, "Protobuf schemas with multiple messages"),
+    let message_name = match (message_name, primary_fd.message_type_size()) {
+        (Some(message_name), _) => message_name.to_owned(),
+        (None, 1) => String::from_utf8_lossy(primary_fd.message_type(0).name()).into_owned(),
+        (None, 0) => bail_unsupported!(29603, "Protobuf schemas with no messages"),
+        (None, _) => {
+            return Err(PlanError::ProtobufMessageRequired {
+                subject: subject_name.clone(),
+                option_name: option_name.to_owned(),
+            });
+        }
     };
 
     // Encode the file descriptor set into a SQL byte string.
@@ -10,11 +10,13 @@
 use std::sync::Arc;
 
 use mz_ccsr::ListError;
+use mz_ore::str::StrExt;
 use mz_repr::adt::system::Oid;
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{ExternalReferences, UnresolvedItemName};
 use mz_storage_types::connections::{
-    MySqlConnectionValidationError, PostgresConnectionValidationError,
+    MongoDbConnectionValidationError, MySqlConnectionValidationError,
+    PostgresConnectionValidationError,
 };
 use mz_storage_types::errors::{ContextCreationError, CsrConnectError};
 
@@ -58,6 +60,8 @@ pub enum PgSourcePurificationError {
     ConnectionMissingPublication,
     #[error(transparent)]
     InvalidConnection(PostgresConnectionValidationError),
+    #[error("logical decoding messages require PostgreSQL 14 or later")]
+    LogicalMessagesUnsupported { major_version: u32 },
 }
 
 impl PgSourcePurificationError {
@@ -98,6 +102,9 @@ impl PgSourcePurificationError {
                 )
             )),
             Self::InvalidConnection(e) => e.detail(),
+            Self::LogicalMessagesUnsupported { major_version } => Some(format!(
+                "the upstream server runs PostgreSQL {major_version}"
+            )),
             _ => None,
         }
     }
@@ -136,6 +143,10 @@ pub enum KafkaSourcePurificationError {
     ReferencedSubsources(ExternalReferences),
     #[error("KAFKA CONNECTION without TOPIC")]
     ConnectionMissingTopic,
+    #[error("only one of TOPIC, TOPICS, or TOPIC PATTERN may be specified")]
+    MultipleTopicOptions,
+    #[error("START OFFSET and START TIMESTAMP are not supported with TOPICS or TOPIC PATTERN")]
+    StartOffsetWithTopicSet,
     #[error("{0} is not a KAFKA CONNECTION")]
     NotKafkaConnection(FullItemName),
     #[error("failed to create and connect Kafka consumer")]
@@ -253,6 +264,41 @@ impl IcebergSinkPurificationError {
     }
 }
 
+/// Logical errors detectable during purification for a POSTGRES SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PostgresSinkPurificationError {
+    #[error("{0} is not a POSTGRES CONNECTION")]
+    NotPgConnection(FullItemName),
+    #[error("table {} does not exist", table.quoted())]
+    TableMissing { table: String },
+    #[error("KEY does not match a unique index on {}", table.quoted())]
+    KeyNotUnique { table: String, key: Vec<String> },
+}
+
+impl PostgresSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::KeyNotUnique { key, .. } => Some(format!(
+                "KEY columns: {}",
+                itertools::join(key.iter().map(|c| c.quoted()), ", ")
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::TableMissing { .. } => Some(
+                "Postgres sinks write into an existing table; create the table in the upstream database first.".into(),
+            ),
+            Self::KeyNotUnique { .. } => Some(
+                "Create a primary key or unique index over exactly the KEY columns in the upstream table.".into(),
+            ),
+            Self::NotPgConnection(_) => None,
+        }
+    }
+}
+
 use mz_ore::error::ErrorExt;
 
 /// Logical errors detectable during purification for Confluent Schema Registry.
@@ -435,6 +481,208 @@ impl MySqlSourcePurificationError {
     }
 }
 
+/// Logical errors detectable during purification for a MongoDB SOURCE.
+#[derive(Debug, thiserror::Error)]
+pub enum MongoDbSourcePurificationError {
+    #[error("{0} is not a MONGODB CONNECTION")]
+    NotMongoDbConnection(FullItemName),
+    #[error("MONGODB source must specify DATABASE")]
+    ConnectionMissingDatabase,
+    #[error(transparent)]
+    InvalidConnection(#[from] MongoDbConnectionValidationError),
+    #[error("database {0} has no collections")]
+    EmptyDatabase(String),
+    #[error(
+        "collection {} does not have changeStreamPreAndPostImages enabled",
+        .0.quoted()
+    )]
+    MissingPreAndPostImages(String),
+}
+
+impl MongoDbSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::InvalidConnection(e) => e.detail(),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::EmptyDatabase(_) => Some(
+                "No collections were found to replicate. This could be because \
+                the user does not have privileges on the intended collections."
+                    .into(),
+            ),
+            Self::MissingPreAndPostImages(collection) => Some(format!(
+                "Change streams only report the previous version of updated and deleted \
+                documents if the collection records it. Run \
+                db.runCommand({{collMod: {}, changeStreamPreAndPostImages: {{enabled: true}}}}) \
+                against the collection's database.",
+                collection.quoted()
+            )),
+            Self::InvalidConnection(e) => e.hint(),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an ICEBERG SOURCE.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum IcebergSourcePurificationError {
+    #[error("{0} is not an ICEBERG CATALOG CONNECTION")]
+    NotIcebergCatalogConnection(FullItemName),
+    #[error("ICEBERG source must specify NAMESPACE")]
+    ConnectionMissingNamespace,
+    #[error("catalog connection errored")]
+    CatalogError(Arc<anyhow::Error>),
+    #[error("namespace {} has no tables", .0.quoted())]
+    EmptyNamespace(String),
+    #[error("table {} uses unsupported format version {version}", .table.quoted())]
+    UnsupportedFormatVersion { table: String, version: u8 },
+    #[error("column {} of type {ty} is not supported", .column.quoted())]
+    UnsupportedType { column: String, ty: String },
+}
+
+impl IcebergSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::CatalogError(e) => Some(e.to_string_with_causes()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UnsupportedFormatVersion { .. } => Some(
+                "Iceberg sources require format version 2 or later, whose snapshots carry \
+                sequence numbers."
+                    .into(),
+            ),
+            Self::UnsupportedType { .. } => Some(
+                "Iceberg sources support maps only with string keys and timestamps only \
+                with microsecond precision when they carry a time zone."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a KINESIS SOURCE.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum KinesisSourcePurificationError {
+    #[error("{0} is not an AWS CONNECTION")]
+    NotAwsConnection(FullItemName),
+    #[error("KINESIS source must specify STREAM")]
+    ConnectionMissingStream,
+    #[error("referencing specific streams is not supported")]
+    ReferencedSubsources(ExternalReferences),
+    #[error("failed to load AWS SDK config")]
+    LoadSdkConfigError(Arc<anyhow::Error>),
+    #[error("failed to list the shards of stream {}", .stream.quoted())]
+    ListShardsError {
+        stream: String,
+        cause: Arc<anyhow::Error>,
+    },
+}
+
+impl KinesisSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::LoadSdkConfigError(e) => Some(e.to_string_with_causes()),
+            Self::ListShardsError { cause, .. } => Some(cause.to_string_with_causes()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::ListShardsError { .. } => Some(
+                "Check that the stream exists in the region of the AWS connection, and that the \
+                connection is allowed to call kinesis:ListShards, kinesis:GetShardIterator and \
+                kinesis:GetRecords on it."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a NATS SOURCE.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NatsSourcePurificationError {
+    #[error("{0} is not a NATS CONNECTION")]
+    NotNatsConnection(FullItemName),
+    #[error("NATS source must specify STREAM")]
+    ConnectionMissingStream,
+    #[error("referencing specific streams is not supported")]
+    ReferencedSubsources(ExternalReferences),
+    #[error("failed to connect to NATS server")]
+    ConnectError(Arc<anyhow::Error>),
+    #[error("failed to look up stream {}", .stream.quoted())]
+    GetStreamError {
+        stream: String,
+        cause: Arc<anyhow::Error>,
+    },
+}
+
+impl NatsSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::ConnectError(e) => Some(e.to_string_with_causes()),
+            Self::GetStreamError { cause, .. } => Some(cause.to_string_with_causes()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::GetStreamError { .. } => Some(
+                "Check that the stream exists on the server, and that the user of the connection \
+                is allowed to create and read from consumers of it."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an MQTT SOURCE.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MqttSourcePurificationError {
+    #[error("{0} is not an MQTT CONNECTION")]
+    NotMqttConnection(FullItemName),
+    #[error("MQTT source must specify TOPIC")]
+    ConnectionMissingTopic,
+    #[error("referencing specific topics is not supported")]
+    ReferencedSubsources(ExternalReferences),
+    #[error("invalid topic filter {}", .0.quoted())]
+    InvalidTopicFilter(String),
+    #[error("invalid shared subscription group {}", .0.quoted())]
+    InvalidSharedGroup(String),
+}
+
+impl MqttSourcePurificationError {
+    pub fn detail(&self) -> Option<String> {
+        None
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::InvalidTopicFilter(_) => Some(
+                "The multi-level wildcard # may only appear as the last level of a filter, and \
+                wildcards must occupy a whole level, e.g. devices/+/telemetry."
+                    .into(),
+            ),
+            Self::InvalidSharedGroup(_) => {
+                Some("Shared subscription groups must not be empty or contain /, + or #.".into())
+            }
+            _ => None,
+        }
+    }
+}
+
 /// Logical errors detectable during purification for a SQL Server SOURCE.
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum SqlServerSourcePurificationError {
@@ -22,7 +22,7 @@ use mz_sql_parser::ast::{
 };
 use mz_storage_types::sources::SourceExportStatementDetails;
 use mz_storage_types::sources::casts::{CastFunc, StorageScalarExpr};
-use mz_storage_types::sources::postgres::CastType;
+use mz_storage_types::sources::postgres::{CastType, PG_LOGICAL_MESSAGES_REFERENCE};
 use prost::Message;
 use tokio_postgres::Client;
 use tokio_postgres::types::Oid;
@@ -49,6 +49,22 @@ pub(super) async fn validate_requested_references_privileges(
     Ok(())
 }
 
+/// Returns whether `reference` selects the logical decoding messages of the source rather than an
+/// upstream table.
+pub(super) fn is_logical_messages_reference(reference: &UnresolvedItemName) -> bool {
+    matches!(&*reference.0, [name] if name.as_str() == PG_LOGICAL_MESSAGES_REFERENCE)
+}
+
+/// Ensures that the upstream server can send us logical decoding messages, which `pgoutput`
+/// supports as of PostgreSQL 14.
+pub(super) async fn validate_logical_messages(client: &Client) -> Result<(), PlanError> {
+    let major_version = mz_postgres_util::schemas::get_pg_major_version(client).await?;
+    if major_version < 14 {
+        Err(PgSourcePurificationError::LogicalMessagesUnsupported { major_version })?;
+    }
+    Ok(())
+}
+
 /// Map a list of column references to a map of table oids to column names.
 ///
 /// Additionally, modify `columns` so that they contain database-qualified
@@ -19,12 +19,16 @@ use mz_sql_server_util::desc::SqlServerTableRaw;
 use mz_storage_types::sources::load_generator::{
     LOAD_GENERATOR_DATABASE_NAME, LoadGenerator, LoadGeneratorOutput,
 };
+use mz_storage_types::sources::mongodb::MONGODB_COLLECTION_DESC;
 use mz_storage_types::sources::{ExternalReferenceResolutionError, SourceReferenceResolver};
 
 use crate::names::{FullItemName, RawDatabaseSpecifier};
 use crate::plan::{PlanError, SourceReference, SourceReferences};
 
-use super::{RequestedSourceExport, error::PgSourcePurificationError};
+use super::RequestedSourceExport;
+use super::error::{
+    IcebergSourcePurificationError, MongoDbSourcePurificationError, PgSourcePurificationError,
+};
 
 /// A client that allows determining all available source references and resolving
 /// them to a user-specified source reference during purification.
@@ -44,9 +48,29 @@ pub(super) enum SourceReferenceClient<'a> {
         client: &'a mut mz_sql_server_util::Client,
         database: Arc<str>,
     },
+    MongoDb {
+        client: &'a mongodb::Client,
+        database: &'a str,
+    },
     Kafka {
         topic: &'a str,
     },
+    Kinesis {
+        stream: &'a str,
+    },
+    Nats {
+        stream: &'a str,
+    },
+    Mqtt {
+        topic: &'a str,
+    },
+    S3 {
+        bucket: &'a str,
+    },
+    Iceberg {
+        catalog: &'a dyn iceberg::Catalog,
+        namespace: &'a str,
+    },
     LoadGenerator {
         generator: &'a LoadGenerator,
     },
@@ -65,7 +89,19 @@ pub(super) enum ReferenceMetadata {
         database: Arc<str>,
         capture_instance: Arc<str>,
     },
+    MongoDb {
+        collection: mz_mongodb_util::inspect::CollectionInfo,
+        database: String,
+    },
     Kafka(String),
+    Kinesis(String),
+    Nats(String),
+    Mqtt(String),
+    S3(String),
+    Iceberg {
+        table: String,
+        namespace: String,
+    },
     LoadGenerator {
         name: String,
         desc: Option<RelationDesc>,
@@ -80,7 +116,13 @@ impl ReferenceMetadata {
             ReferenceMetadata::Postgres { table, .. } => Some(&table.namespace),
             ReferenceMetadata::MySql(table) => Some(&table.schema_name),
             ReferenceMetadata::SqlServer { table, .. } => Some(table.schema_name.as_ref()),
+            ReferenceMetadata::MongoDb { database, .. } => Some(database),
             ReferenceMetadata::Kafka(_) => None,
+            ReferenceMetadata::Kinesis(_) => None,
+            ReferenceMetadata::Nats(_) => None,
+            ReferenceMetadata::Mqtt(_) => None,
+            ReferenceMetadata::S3(_) => None,
+            ReferenceMetadata::Iceberg { namespace, .. } => Some(namespace),
             ReferenceMetadata::LoadGenerator { namespace, .. } => Some(namespace),
         }
     }
@@ -90,7 +132,13 @@ impl ReferenceMetadata {
             ReferenceMetadata::Postgres { table, .. } => &table.name,
             ReferenceMetadata::MySql(table) => &table.name,
             ReferenceMetadata::SqlServer { table, .. } => table.name.as_ref(),
+            ReferenceMetadata::MongoDb { collection, .. } => &collection.name,
             ReferenceMetadata::Kafka(topic) => topic,
+            ReferenceMetadata::Kinesis(stream) => stream,
+            ReferenceMetadata::Nats(stream) => stream,
+            ReferenceMetadata::Mqtt(topic) => topic,
+            ReferenceMetadata::S3(bucket) => bucket,
+            ReferenceMetadata::Iceberg { table, .. } => table,
             ReferenceMetadata::LoadGenerator { name, .. } => name,
         }
     }
@@ -125,6 +173,20 @@ impl ReferenceMetadata {
         }
     }
 
+    pub(super) fn mongodb_collection(&self) -> Option<&mz_mongodb_util::inspect::CollectionInfo> {
+        match self {
+            ReferenceMetadata::MongoDb { collection, .. } => Some(collection),
+            _ => None,
+        }
+    }
+
+    pub(super) fn iceberg_table(&self) -> Option<&str> {
+        match self {
+            ReferenceMetadata::Iceberg { table, .. } => Some(table),
+            _ => None,
+        }
+    }
+
     pub(super) fn load_generator_desc(&self) -> Option<&Option<RelationDesc>> {
         match self {
             ReferenceMetadata::LoadGenerator { desc, .. } => Some(desc),
@@ -164,9 +226,31 @@ impl ReferenceMetadata {
                 Ident::new(table.schema_name.as_ref())?,
                 Ident::new(table.name.as_ref())?,
             ])),
+            ReferenceMetadata::MongoDb {
+                collection,
+                database,
+            } => Ok(UnresolvedItemName::qualified(&[
+                Ident::new(database)?,
+                Ident::new(&collection.name)?,
+            ])),
             ReferenceMetadata::Kafka(topic) => {
                 Ok(UnresolvedItemName::qualified(&[Ident::new(topic)?]))
             }
+            ReferenceMetadata::Kinesis(stream) | ReferenceMetadata::Nats(stream) => {
+                Ok(UnresolvedItemName::qualified(&[Ident::new(stream)?]))
+            }
+            ReferenceMetadata::Mqtt(topic) => {
+                Ok(UnresolvedItemName::qualified(&[Ident::new(topic)?]))
+            }
+            ReferenceMetadata::S3(bucket) => {
+                Ok(UnresolvedItemName::qualified(&[Ident::new(bucket)?]))
+            }
+            ReferenceMetadata::Iceberg { table, namespace } => {
+                Ok(UnresolvedItemName::qualified(&[
+                    Ident::new(namespace)?,
+                    Ident::new(table)?,
+                ]))
+            }
             ReferenceMetadata::LoadGenerator {
                 name, namespace, ..
             } => {
@@ -192,9 +276,11 @@ pub(super) struct RetrievedSourceReferences {
 /// The name of the fake database used to fit references into the 3-layer catalog
 /// model of the [`SourceReferenceResolver`] for source types whose
 /// [`ReferenceMetadata::external_reference`] stores no database component
-/// (MySQL and Kafka). Because those references are never fully qualified with a
-/// database, the resolver's database name is never matched against and this
-/// placeholder is never stored in the catalog.
+/// (MySQL, MongoDB, Kafka, Kinesis, NATS, MQTT, S3 and Iceberg). MongoDB
+/// references store the upstream database in place of a schema, and Iceberg
+/// references the table's namespace. Because those references are never fully
+/// qualified with a database, the resolver's database name is never matched
+/// against and this placeholder is never stored in the catalog.
 ///
 /// Note: this is *not* usable for every non-Postgres source. SQL Server and load
 /// generators do store a database in their external reference (the real upstream
@@ -305,9 +391,61 @@ impl<'a> SourceReferenceClient<'a> {
                     })
                     .collect::<Result<_, SqlServerError>>()?
             }
+            SourceReferenceClient::MongoDb { client, database } => {
+                let collections =
+                    mz_mongodb_util::inspect::list_collections(&client.database(database)).await?;
+
+                if collections.is_empty() {
+                    Err(MongoDbSourcePurificationError::EmptyDatabase(
+                        database.to_string(),
+                    ))?;
+                }
+
+                collections
+                    .into_iter()
+                    .map(|collection| ReferenceMetadata::MongoDb {
+                        collection,
+                        database: database.to_string(),
+                    })
+                    .collect()
+            }
             SourceReferenceClient::Kafka { topic } => {
                 vec![ReferenceMetadata::Kafka(topic.to_string())]
             }
+            SourceReferenceClient::Kinesis { stream } => {
+                vec![ReferenceMetadata::Kinesis(stream.to_string())]
+            }
+            SourceReferenceClient::Nats { stream } => {
+                vec![ReferenceMetadata::Nats(stream.to_string())]
+            }
+            SourceReferenceClient::Mqtt { topic } => {
+                vec![ReferenceMetadata::Mqtt(topic.to_string())]
+            }
+            SourceReferenceClient::S3 { bucket } => {
+                vec![ReferenceMetadata::S3(bucket.to_string())]
+            }
+            SourceReferenceClient::Iceberg { catalog, namespace } => {
+                let tables = catalog
+                    .list_tables(&iceberg::NamespaceIdent::new(namespace.to_string()))
+                    .await
+                    .map_err(|e| {
+                        IcebergSourcePurificationError::CatalogError(Arc::new(e.into()))
+                    })?;
+
+                if tables.is_empty() {
+                    Err(IcebergSourcePurificationError::EmptyNamespace(
+                        namespace.to_string(),
+                    ))?;
+                }
+
+                tables
+                    .into_iter()
+                    .map(|table| ReferenceMetadata::Iceberg {
+                        table: table.name().to_string(),
+                        namespace: namespace.to_string(),
+                    })
+                    .collect()
+            }
             SourceReferenceClient::LoadGenerator { generator } => {
                 let mut references = generator
                     .views()
@@ -350,9 +488,11 @@ impl<'a> SourceReferenceClient<'a> {
         // fully-qualified reference we store (and print in `SHOW CREATE TABLE`)
         // won't resolve when fed back in. Postgres and SQL Server store the real
         // upstream database; load generators store the synthetic
-        // `mz_load_generators` database. MySQL and Kafka store no database
-        // component, so the resolver's database is never matched against and the
-        // fake name is fine.
+        // `mz_load_generators` database. MySQL, Kafka, Kinesis, NATS, MQTT and S3
+        // store no database component, so the resolver's database is never
+        // matched against and the fake name is fine. MongoDB and Iceberg store
+        // their upstream database or namespace where the other source types
+        // store a schema, so they use the fake name too.
         let resolver = match self {
             SourceReferenceClient::Postgres { database, .. } => {
                 SourceReferenceResolver::new(database, &reference_names)
@@ -363,7 +503,14 @@ impl<'a> SourceReferenceClient<'a> {
             SourceReferenceClient::LoadGenerator { .. } => {
                 SourceReferenceResolver::new(LOAD_GENERATOR_DATABASE_NAME, &reference_names)
             }
-            SourceReferenceClient::MySql { .. } | SourceReferenceClient::Kafka { .. } => {
+            SourceReferenceClient::MySql { .. }
+            | SourceReferenceClient::MongoDb { .. }
+            | SourceReferenceClient::Kafka { .. }
+            | SourceReferenceClient::Kinesis { .. }
+            | SourceReferenceClient::Nats { .. }
+            | SourceReferenceClient::Mqtt { .. }
+            | SourceReferenceClient::S3 { .. }
+            | SourceReferenceClient::Iceberg { .. } => {
                 SourceReferenceResolver::new(DATABASE_FAKE_NAME, &reference_names)
             }
         }?;
@@ -409,11 +556,45 @@ impl RetrievedSourceReferences {
                             .map(|c| c.name.to_string())
                             .collect(),
                     },
+                    ReferenceMetadata::MongoDb {
+                        collection,
+                        database,
+                    } => SourceReference {
+                        name: collection.name,
+                        namespace: Some(database),
+                        columns: MONGODB_COLLECTION_DESC
+                            .iter_names()
+                            .map(|n| n.to_string())
+                            .collect(),
+                    },
                     ReferenceMetadata::Kafka(topic) => SourceReference {
                         name: topic,
                         namespace: None,
                         columns: vec![],
                     },
+                    ReferenceMetadata::Kinesis(stream) | ReferenceMetadata::Nats(stream) => {
+                        SourceReference {
+                            name: stream,
+                            namespace: None,
+                            columns: vec![],
+                        }
+                    }
+                    ReferenceMetadata::Mqtt(topic) => SourceReference {
+                        name: topic,
+                        namespace: None,
+                        columns: vec![],
+                    },
+                    ReferenceMetadata::S3(bucket) => SourceReference {
+                        name: bucket,
+                        namespace: None,
+                        columns: vec![],
+                    },
+                    // Listing a namespace doesn't load the schemas of its tables.
+                    ReferenceMetadata::Iceberg { table, namespace } => SourceReference {
+                        name: table,
+                        namespace: Some(namespace),
+                        columns: vec![],
+                    },
                     ReferenceMetadata::LoadGenerator {
                         name,
                         desc,
@@ -31,7 +31,8 @@ use crate::names::{
 };
 use crate::plan::{self, PlanKind};
 use crate::plan::{
-    DataSourceDesc, Explainee, MutationKind, Plan, SideEffectingFunc, UpdatePrivilege,
+    ColumnPrivileges, DataSourceDesc, Explainee, MutationKind, Plan, SideEffectingFunc,
+    UpdatePrivilege,
 };
 use crate::session::metadata::SessionMetadata;
 use crate::session::user::{MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
@@ -80,18 +81,26 @@ fn filter_requirements(
     session_meta: &dyn SessionMetadata,
     rbac_requirements: RbacRequirements,
 ) -> RbacRequirements {
+    if !is_rbac_enforced_for_session(catalog.system_vars(), session_meta) {
+        return rbac_requirements.filter_to_mandatory_requirements();
+    }
+
+    rbac_requirements
+}
+
+/// Returns true if the non-mandatory RBAC checks apply to a session.
+pub fn is_rbac_enforced_for_session(
+    system_vars: &SystemVars,
+    session_meta: &dyn SessionMetadata,
+) -> bool {
     // Skip RBAC non-mandatory checks if RBAC is disabled. However, we never skip RBAC checks for
     // system roles. This allows us to limit access of system users even when RBAC is off.
-    let is_rbac_disabled = !is_rbac_enabled_for_session(catalog.system_vars(), session_meta)
+    let is_rbac_disabled = !is_rbac_enabled_for_session(system_vars, session_meta)
         && !session_meta.role_metadata().current_role.is_system()
         && !session_meta.role_metadata().session_role.is_system();
     // Skip RBAC checks on user items if the session is a superuser.
     let is_superuser = session_meta.is_superuser();
-    if is_rbac_disabled || is_superuser {
-        return rbac_requirements.filter_to_mandatory_requirements();
-    }
-
-    rbac_requirements
+    !is_rbac_disabled && !is_superuser
 }
 
 // The default item types that most statements require USAGE privileges for.
@@ -630,19 +639,51 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::CreateNotification(plan::CreateNotificationPlan {
+            name,
+            notification,
+            if_not_exists: _,
+            in_cluster,
+        }) => {
+            let mut privileges = vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )];
+            let items = iter::once(notification.from).map(|gid| catalog.resolve_item_id(&gid));
+            privileges.extend_from_slice(&generate_read_privileges(catalog, items, role_id));
+            privileges.push((
+                SystemObjectId::Object(in_cluster.into()),
+                AclMode::CREATE,
+                role_id,
+            ));
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::CreateTable(plan::CreateTablePlan {
             name,
-            table: _,
+            table,
             if_not_exists: _,
-        }) => RbacRequirements {
-            privileges: vec![(
+        }) => {
+            let mut privileges = vec![(
                 SystemObjectId::Object(name.qualifiers.clone().into()),
                 AclMode::CREATE,
                 role_id,
-            )],
-            item_usage: &CREATE_ITEM_USAGE,
-            ..Default::default()
-        },
+            )];
+            // Taking a snapshot of a materialized view reads its contents.
+            if let plan::TableDataSource::Snapshot { from, as_of: _ } = &table.data_source {
+                let id = catalog.get_item_by_global_id(from).id();
+                privileges.push((SystemObjectId::Object(id.into()), AclMode::SELECT, role_id));
+            }
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::CreateView(plan::CreateViewPlan {
             name,
             view: _,
@@ -1109,6 +1150,21 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterRowLevelSecurity(plan::AlterRowLevelSecurityPlan {
+            id,
+            object_type: _,
+            enabled: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreatePolicy(plan::CreatePolicyPlan { id, policy: _ })
+        | Plan::DropPolicy(plan::DropPolicyPlan { id, name: _ }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterSourceTimestampInterval(plan::AlterSourceTimestampIntervalPlan {
             id,
             value: _,
@@ -1632,6 +1688,8 @@ fn generate_rbac_requirements(
         })
         | Plan::Execute(plan::ExecutePlan { name: _, params: _ })
         | Plan::Deallocate(plan::DeallocatePlan { name: _ })
+        | Plan::Listen(plan::ListenPlan { channel: _ })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ })
         | Plan::Raise(plan::RaisePlan { severity: _ }) => Default::default(),
     }
 }
@@ -1853,7 +1911,15 @@ fn check_object_privileges(
             .flat_map(|role_id| object_privileges.get_acl_items_for_grantee(role_id))
             .map(|mz_acl_item| mz_acl_item.acl_mode)
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode));
-        if !role_privileges.contains(acl_mode) {
+        if !role_privileges.contains(acl_mode)
+            && !holds_column_privileges(
+                catalog,
+                &object_id,
+                acl_mode,
+                role_privileges,
+                role_membership,
+            )
+        {
             let role_name = catalog.get_role(&role_id).name().to_string();
             let privileges = acl_mode.to_error_string();
             return Err(UnauthorizedError::Privilege {
@@ -1867,6 +1933,28 @@ fn check_object_privileges(
     Ok(())
 }
 
+/// Reports whether the privileges on the columns of `object_id` held by `role_membership` make up
+/// for the privileges in `acl_mode` missing from `role_privileges`. The privileges on columns
+/// allow reading a relation; the planner rejects references to the columns that they don't cover.
+fn holds_column_privileges(
+    catalog: &impl SessionCatalog,
+    object_id: &SystemObjectId,
+    acl_mode: AclMode,
+    role_privileges: AclMode,
+    role_membership: &BTreeSet<RoleId>,
+) -> bool {
+    let SystemObjectId::Object(ObjectId::Item(item_id)) = object_id else {
+        return false;
+    };
+    if acl_mode.difference(role_privileges) != AclMode::SELECT {
+        return false;
+    }
+    let item = catalog.get_item(item_id);
+    !ColumnPrivileges::from_create_sql(item.create_sql())
+        .columns_granted_to(role_membership, AclMode::SELECT)
+        .is_empty()
+}
+
 pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
     const TABLE_ACL_MODE: AclMode = AclMode::INSERT
         .union(AclMode::SELECT)
@@ -2192,6 +2192,12 @@ feature_flags!(
         default: false,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_kafka_sink_topic_by,
+        desc: "TOPIC BY option for Kafka sinks",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_unlimited_retain_history,
         desc: "Disable limits on RETAIN HISTORY (below 1s default, and 0 disables compaction).",
@@ -2222,6 +2228,84 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_mongodb_source,
+        desc: "Whether to allow creating MongoDB sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_s3_source,
+        desc: "Whether to allow creating S3 sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_kinesis_source,
+        desc: "Whether to allow creating Kinesis sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_nats_source,
+        desc: "Whether to allow creating NATS JetStream sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_mqtt_source,
+        desc: "Whether to allow creating MQTT sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_postgres_sink,
+        desc: "Whether to allow creating Postgres sinks.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_http_sink,
+        desc: "Whether to allow creating HTTP sinks.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_s3_sink,
+        desc: "Whether to allow creating S3 sinks.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_json_schema_format,
+        desc: "Whether to allow FORMAT JSON USING CONFLUENT SCHEMA REGISTRY for sources and sinks.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_json_array_format,
+        desc: "FORMAT JSON ARRAY for Kafka sources and sinks",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_kafka_sink_protobuf_format,
+        desc: "FORMAT PROTOBUF for Kafka sinks",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_avro_decode_options,
+        desc: "AVRO MAX DEPTH and AVRO UNION AS RECORD options",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_iceberg_source,
+        desc: "Whether to allow creating Iceberg sources.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_create_table_from_source,
         desc: "Whether to allow CREATE TABLE .. FROM SOURCE syntax.",
@@ -2330,6 +2414,24 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: false,
     },
+    {
+        name: enable_create_snapshot,
+        desc: "CREATE SNAPSHOT",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_notifications,
+        desc: "LISTEN and CREATE NOTIFICATION",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_row_level_security,
+        desc: "row level security",
+        default: false,
+        enable_for_item_parsing: true,
+    },
 );
 
 impl From<&super::SystemVars> for OptimizerFeatures {
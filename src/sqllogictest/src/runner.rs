@@ -2951,6 +2951,7 @@ fn generate_view_sql(
             name: name.clone(),
             columns: columns.clone(),
             query,
+            with_options: vec![],
         },
     })
     .to_ast_string_stable();
@@ -2974,6 +2975,7 @@ fn generate_view_sql(
         },
         with_options: Vec::new(),
         if_not_exists: false,
+        temporary: false,
     })
     .to_ast_string_stable();
 
@@ -3183,6 +3185,7 @@ fn mutate(sql: &str) -> Vec<String> {
                     ),
                     with_options: Vec::new(),
                     if_not_exists: false,
+                    temporary: stmt.temporary,
                 })
                 .to_ast_string_stable(),
             ),
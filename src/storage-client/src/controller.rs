@@ -522,6 +522,21 @@ pub trait StorageController: Debug {
         register_ts: Timestamp,
     ) -> Result<(), StorageError>;
 
+    /// Alters the description of a source export, e.g. a table that reads from a source, to
+    /// `new_desc` and its details to `new_details`.
+    ///
+    /// Like [`StorageController::alter_table_desc`] this creates a new collection that shares the
+    /// shard of the existing one. The new collection takes over ingesting into the shard from the
+    /// existing one, which remains readable at its old description.
+    async fn alter_ingestion_export_desc(
+        &mut self,
+        existing_collection: GlobalId,
+        new_collection: GlobalId,
+        new_desc: RelationDesc,
+        new_details: SourceExportDetails,
+        expected_version: RelationVersion,
+    ) -> Result<(), StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState, StorageError>;
 
@@ -255,7 +255,7 @@ pub trait StorageCollections: Debug + Sync {
         migrated_storage_collections: &BTreeSet<GlobalId>,
     ) -> Result<(), StorageError>;
 
-    /// Updates the [`RelationDesc`] for the specified table.
+    /// Updates the [`RelationDesc`] for the specified table or source export.
     async fn alter_table_desc(
         &self,
         existing_collection: GlobalId,
@@ -1999,9 +1999,11 @@ impl StorageCollections for StorageCollectionsImpl {
                         Ingestion(ingestion) => {
                             use GenericSourceConnection::*;
                             match ingestion.desc.connection {
-                                // Kafka, Postgres, MySql, and SQL Server sources all
-                                // follow wall clock.
-                                Kafka(_) | Postgres(_) | MySql(_) | SqlServer(_) => {
+                                // Kafka, Postgres, MySql, SQL Server, MongoDB, S3,
+                                // Iceberg, Kinesis, NATS, and MQTT sources all follow
+                                // wall clock.
+                                Kafka(_) | Postgres(_) | MySql(_) | SqlServer(_) | MongoDb(_)
+                                | S3(_) | Iceberg(_) | Kinesis(_) | Nats(_) | Mqtt(_) => {
                                     Some(TimeDependence::default())
                                 }
                                 // Load generators not further specified.
@@ -2100,13 +2102,16 @@ impl StorageCollections for StorageCollectionsImpl {
         new_desc: RelationDesc,
         expected_version: RelationVersion,
     ) -> Result<(), StorageError> {
-        let data_shard = {
+        let (data_shard, txns_shard) = {
             let self_collections = self.collections.lock().expect("lock poisoned");
             let existing = self_collections
                 .get(&existing_collection)
                 .ok_or_else(|| StorageError::IdentifierMissing(existing_collection))?;
 
-            existing.collection_metadata.data_shard
+            (
+                existing.collection_metadata.data_shard,
+                existing.collection_metadata.txns_shard.clone(),
+            )
         };
 
         let persist_client = self
@@ -2210,12 +2215,13 @@ impl StorageCollections for StorageCollectionsImpl {
             let mut changes = ChangeBatch::new();
             changes.extend(implied_capability.iter().map(|t| (*t, 1)));
 
-            // Note: The new collection is now the "primary collection".
+            // Note: The new collection is now the "primary collection". Tables are written through
+            // txn-wal, whereas source exports are written directly by their ingestion.
             let collection_meta = CollectionMetadata {
                 persist_location: self.persist_location.clone(),
                 relation_desc: new_desc.clone(),
                 data_shard,
-                txns_shard: Some(self.txns_read.txns_id().clone()),
+                txns_shard: txns_shard.clone(),
             };
             let collection_state = CollectionState::new(
                 None,
@@ -2238,8 +2244,12 @@ impl StorageCollections for StorageCollectionsImpl {
             );
         };
 
-        // TODO(alter_table): Support changes to sources.
-        self.register_handles(new_collection, true, since_handle, write_handle);
+        self.register_handles(
+            new_collection,
+            txns_shard.is_some(),
+            since_handle,
+            write_handle,
+        );
 
         info!(%existing_collection, %new_collection, ?new_desc, "altered table");
 
@@ -360,6 +360,7 @@ mod tests {
                 ),
                 partition_by: Default::default(),
                 topic: Default::default(),
+                topic_by: Default::default(),
                 topic_options: Default::default(),
                 compression_type: KafkaSinkCompressionType::None,
                 progress_group_id: KafkaIdStyle::Legacy,
@@ -82,7 +82,7 @@ use mz_storage_types::read_policy::ReadPolicy;
 use mz_storage_types::sinks::{StorageSinkConnection, StorageSinkDesc};
 use mz_storage_types::sources::{
     GenericSourceConnection, IngestionDescription, SourceConnection, SourceData, SourceDesc,
-    SourceExport, SourceExportDataConfig,
+    SourceExport, SourceExportDataConfig, SourceExportDetails,
 };
 use mz_storage_types::{AlterCompatible, StorageDiff, dyncfgs};
 use mz_txn_wal::metrics::Metrics as TxnMetrics;
@@ -1467,6 +1467,119 @@ impl StorageController for Controller {
         Ok(())
     }
 
+    async fn alter_ingestion_export_desc(
+        &mut self,
+        existing_collection: GlobalId,
+        new_collection: GlobalId,
+        new_desc: RelationDesc,
+        new_details: SourceExportDetails,
+        expected_version: RelationVersion,
+    ) -> Result<(), StorageError> {
+        let existing = self
+            .collections
+            .get(&existing_collection)
+            .ok_or(StorageError::IdentifierMissing(existing_collection))?;
+        let (ingestion_id, data_config) = match &existing.data_source {
+            DataSource::IngestionExport {
+                ingestion_id,
+                details: _,
+                data_config,
+            } => (*ingestion_id, data_config.clone()),
+            _ => return Err(StorageError::IdentifierInvalid(existing_collection)),
+        };
+
+        // Let StorageCollections know!
+        self.storage_collections
+            .alter_table_desc(
+                existing_collection,
+                new_collection,
+                new_desc,
+                expected_version,
+            )
+            .await?;
+        let collection_metadata = self
+            .storage_collections
+            .collection_metadata(new_collection)?;
+
+        // Move the export over to the new collection, so the ingestion writes to the shard using
+        // the new description from now on.
+        let ingestion_collection = self
+            .collections
+            .get_mut(&ingestion_id)
+            .ok_or(StorageError::IdentifierMissing(ingestion_id))?;
+        let (instance_id, remap_collection_id) = match &mut ingestion_collection.data_source {
+            DataSource::Ingestion(ingestion_desc) => {
+                ingestion_desc.source_exports.remove(&existing_collection);
+                ingestion_desc.source_exports.insert(
+                    new_collection,
+                    SourceExport {
+                        storage_metadata: (),
+                        details: new_details.clone(),
+                        data_config: data_config.clone(),
+                    },
+                );
+                (
+                    ingestion_desc.instance_id,
+                    ingestion_desc.remap_collection_id,
+                )
+            }
+            o => {
+                tracing::warn!("alter_ingestion_export_desc called on {:?}", o);
+                Err(StorageError::IdentifierInvalid(ingestion_id))?
+            }
+        };
+
+        // The existing collection no longer receives writes of its own. Dropping its ingestion
+        // state releases the holds it had on its dependencies.
+        let existing = self
+            .collections
+            .get_mut(&existing_collection)
+            .expect("existing collection missing");
+        existing.data_source = DataSource::Other;
+        existing.extra_state = CollectionStateExtra::None;
+
+        // Like any other ingestion export, the new collection holds back its own since and that of
+        // the remap collection.
+        let dependency_read_holds = self
+            .storage_collections
+            .acquire_read_holds(vec![new_collection, remap_collection_id])
+            .expect("can acquire read holds");
+        let mut dependency_since = Antichain::from_elem(Timestamp::MIN);
+        for read_hold in dependency_read_holds.iter() {
+            dependency_since.join_assign(read_hold.since());
+        }
+        let ingestion_state = IngestionState {
+            read_capabilities: MutableAntichain::from(dependency_since.clone()),
+            dependency_read_holds,
+            derived_since: dependency_since,
+            write_frontier: Antichain::from_elem(Timestamp::MIN),
+            hold_policy: ReadPolicy::step_back(),
+            instance_id,
+            hydrated_on: BTreeSet::new(),
+        };
+
+        let wallclock_lag_metrics = self
+            .metrics
+            .wallclock_lag_metrics(new_collection, Some(instance_id));
+        let collection_state = CollectionState::new(
+            DataSource::IngestionExport {
+                ingestion_id,
+                details: new_details,
+                data_config,
+            },
+            collection_metadata,
+            CollectionStateExtra::Ingestion(ingestion_state),
+            wallclock_lag_metrics,
+        );
+        self.collections.insert(new_collection, collection_state);
+
+        self.append_shard_mappings([new_collection].into_iter(), Diff::ONE);
+
+        self.run_ingestion(ingestion_id)?;
+
+        Ok(())
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState, StorageError> {
         self.collections
             .get(&id)
@@ -1774,7 +1887,11 @@ impl StorageController for Controller {
             .into_iter()
             .partition(|id| match self.collections[id].data_source {
                 DataSource::Table => true,
-                DataSource::IngestionExport { .. } | DataSource::Webhook => false,
+                // Earlier versions of source tables that had columns added
+                // upstream are tracked as `Other`.
+                DataSource::IngestionExport { .. } | DataSource::Webhook | DataSource::Other => {
+                    false
+                }
                 _ => panic!("identifier is not a table: {}", id),
             });
 
@@ -2515,15 +2632,22 @@ impl StorageController for Controller {
                     GenericSourceConnection::Kafka(_)
                     | GenericSourceConnection::Postgres(_)
                     | GenericSourceConnection::MySql(_)
-                    | GenericSourceConnection::SqlServer(_) => {
+                    | GenericSourceConnection::SqlServer(_)
+                    | GenericSourceConnection::MongoDb(_) => {
                         (connection.clone(), *remap_collection_id)
                     }
 
-                    // These internal sources do not yet (and might never)
-                    // support RTR. However, erroring if they're selected from
-                    // poses an annoying user experience, so instead just skip
-                    // over them.
-                    GenericSourceConnection::LoadGenerator(_) => continue,
+                    // These sources do not yet (and might never) support RTR.
+                    // However, erroring if they're selected from poses an
+                    // annoying user experience, so instead just skip over them.
+                    GenericSourceConnection::LoadGenerator(_)
+                    | GenericSourceConnection::Kinesis(_)
+                    | GenericSourceConnection::Nats(_)
+                    | GenericSourceConnection::Mqtt(_)
+                    | GenericSourceConnection::S3(_)
+                    | GenericSourceConnection::Iceberg(_) => {
+                        continue;
+                    }
                 },
                 // Skip over all other objects
                 _ => {
@@ -37,10 +37,12 @@ use crate::Timestamp;
 /// system which `self` connects to.
 ///
 /// # Panics
-/// - If `self` is a [`GenericSourceConnection::LoadGenerator`]. Load
-///   generator sources do not yet (or might never) support real-time
-///   recency. You can avoid this panic by choosing to not call this
-///   function on load generator sources.
+/// - If `self` is a [`GenericSourceConnection::LoadGenerator`], a
+///   [`GenericSourceConnection::Kinesis`], a [`GenericSourceConnection::Nats`], a
+///   [`GenericSourceConnection::Mqtt`], a [`GenericSourceConnection::S3`] or a
+///   [`GenericSourceConnection::Iceberg`].
+///   These sources do not yet (or might never) support real-time recency. You can avoid this panic by choosing
+///   to not call this function on them.
 pub(super) async fn real_time_recency_ts(
     connection: GenericSourceConnection,
     id: GlobalId,
@@ -105,12 +107,38 @@ pub(super) async fn real_time_recency_ts(
             )
             .await
         }
+        GenericSourceConnection::MongoDb(mongodb) => {
+            let external_frontier = mongodb
+                .fetch_write_frontier(&config)
+                .await
+                .map_err(StorageError::Generic)?;
+
+            decode_remap_data_until_geq_external_frontier(
+                id,
+                external_frontier,
+                as_of,
+                remap_subscribe,
+            )
+            .await
+        }
         // Load generator sources have no "external system" to reach out to,
-        // so it's unclear what RTR would mean for them.
-        s @ GenericSourceConnection::LoadGenerator(_) => unreachable!(
-            "do not try to determine RTR timestamp on {} source",
-            s.name()
-        ),
+        // so it's unclear what RTR would mean for them. Kinesis exposes no
+        // write frontier short of reading the tip of every shard. NATS sources
+        // read through a consumer whose subject filter may skip the tip of the
+        // stream, and MQTT brokers have no notion of a write frontier. S3 sources
+        // would need to list their whole bucket to learn of every object that
+        // exists, and Iceberg sources to load every table they ingest.
+        s @ (GenericSourceConnection::LoadGenerator(_)
+        | GenericSourceConnection::Kinesis(_)
+        | GenericSourceConnection::Nats(_)
+        | GenericSourceConnection::Mqtt(_)
+        | GenericSourceConnection::S3(_)
+        | GenericSourceConnection::Iceberg(_)) => {
+            unreachable!(
+                "do not try to determine RTR timestamp on {} source",
+                s.name()
+            )
+        }
     }
 }
 
@@ -81,6 +81,7 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_repr::{DatumVec, GlobalId, Row, RowArena, Timestamp};
 use mz_storage_types::StorageDiff;
 use mz_storage_types::connections::ConnectionContext;
+use mz_storage_types::connections::aws::AwsConnection;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::oneshot_sources::{
     ContentFilter, ContentFormat, ContentSource, OneshotIngestionRequest,
@@ -172,11 +173,7 @@ where
             connection_id,
             uri,
         } => {
-            // Checksum validation does not work with GCS when using ranges, which happens with parquet.
-            // So, we disable checksum if both the endpoint is overridden to a non-AWS endpoint and the format is Parquet.
-            let use_checksum = !(connection.endpoint.is_some()
-                && !connection.endpoint.as_ref().unwrap().contains("amazonaws"))
-                || format != ContentFormat::Parquet;
+            let use_checksum = aws_s3_use_checksum(&connection, &format);
             if !use_checksum {
                 tracing::info!(
                     "disabling checksum validation for S3 source because endpoint: {:?} is overridden and format is Parquet",
@@ -700,6 +697,16 @@ pub fn render_completion_operator<'scope, T, F>(
     });
 }
 
+/// Returns whether checksums should be validated when fetching objects in `format` from S3.
+///
+/// Checksum validation does not work with GCS when using ranges, which happens with parquet. So,
+/// we disable checksum if both the endpoint is overridden to a non-AWS endpoint and the format is
+/// Parquet.
+pub fn aws_s3_use_checksum(connection: &AwsConnection, format: &ContentFormat) -> bool {
+    !(connection.endpoint.is_some() && !connection.endpoint.as_ref().unwrap().contains("amazonaws"))
+        || *format != ContentFormat::Parquet
+}
+
 /// An object that will be fetched from a [`OneshotSource`].
 pub trait OneshotObject {
     /// Name of the object, including any extensions.
@@ -907,7 +914,7 @@ pub trait OneshotFormat: Clone {
 /// provides a convenient place to add [`StorageErrorXContext::context`] for all of our format
 /// types.
 #[derive(Clone, Debug)]
-pub(crate) enum FormatKind {
+pub enum FormatKind {
     Csv(CsvDecoder),
     Parquet(ParquetFormat),
 }
@@ -993,18 +1000,18 @@ impl OneshotFormat for FormatKind {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub(crate) enum RequestKind<O, C> {
+pub enum RequestKind<O, C> {
     Csv(CsvWorkRequest<O, C>),
     Parquet(ParquetWorkRequest<O, C>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub(crate) enum RecordChunkKind {
+pub enum RecordChunkKind {
     Csv(CsvRecord),
     Parquet(ParquetRowGroup),
 }
 
-pub(crate) enum ObjectFilter {
+pub enum ObjectFilter {
     None,
     Files(BTreeSet<Box<str>>),
     Pattern(glob::Pattern),
@@ -1047,6 +1054,13 @@ pub struct StorageErrorX {
     context: LinkedList<String>,
 }
 
+impl StorageErrorX {
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> &StorageErrorXKind {
+        &self.kind
+    }
+}
+
 impl fmt::Display for StorageErrorX {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "error: {}", self.kind)?;
@@ -23,8 +23,14 @@ fn main() {
                 "storage-types/src/errors.proto",
                 "storage-types/src/sources.proto",
                 "storage-types/src/sources/kafka.proto",
+                "storage-types/src/sources/kinesis.proto",
+                "storage-types/src/sources/iceberg.proto",
+                "storage-types/src/sources/mongodb.proto",
+                "storage-types/src/sources/mqtt.proto",
                 "storage-types/src/sources/mysql.proto",
+                "storage-types/src/sources/nats.proto",
                 "storage-types/src/sources/postgres.proto",
+                "storage-types/src/sources/s3.proto",
                 "storage-types/src/sources/sql_server.proto",
                 "storage-types/src/sources/load_generator.proto",
             ],
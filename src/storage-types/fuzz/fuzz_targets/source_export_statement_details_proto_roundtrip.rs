@@ -8,8 +8,10 @@
 // by the Apache License, Version 2.0.
 
 //! Fuzz target: a `SourceExportStatementDetails` must survive a proto encode +
-//! decode round trip losslessly. The Rust side is a 5-variant enum
-//! (Postgres / MySql / SqlServer / LoadGenerator / Kafka), so the conversion
+//! decode round trip losslessly. The Rust side is a 12-variant enum
+//! (Postgres / MySql / SqlServer / MongoDb / LoadGenerator / Kafka /
+//! PostgresMessages / S3 / Kinesis / NATS / MQTT / Iceberg),
+//! so the conversion
 //! has plenty of branches that need to round-trip. This value is serialized to
 //! the catalog, so a decoder bug here is a corruption/migration risk.
 //!
@@ -18,10 +20,13 @@
 //!  * **Structured arm.** Synthesizes a *valid, populated* value. The
 //!    Postgres / MySql / SqlServer variants carry a full table descriptor, which
 //!    we generate with each `*TableDesc`'s proptest `Arbitrary` (driven from the
-//!    libFuzzer byte stream). The load-generator output and the empty Kafka
-//!    variant are picked directly. Random proto bytes essentially never produce
-//!    a non-empty table descriptor, so this is where the nested column /
-//!    constraint / key conversions actually get exercised.
+//!    libFuzzer byte stream). The load-generator output and the empty Kafka,
+//!    Kinesis, NATS, MQTT and PostgresMessages variants are picked directly.
+//!    Random proto bytes essentially never produce a non-empty table
+//!    descriptor, so this is where the nested column / constraint / key
+//!    conversions actually get exercised.
+//!    The Iceberg variant's table UUID is encoded as a string, which the raw-bytes
+//!    arm exercises with unparseable values.
 //!  * **Raw-bytes arm.** Decodes arbitrary bytes straight into the proto,
 //!    exercising the decoder against malformed/adversarial wire input (including
 //!    the SQL Server `Lsn` `try_from` length guard, which is only reachable from
@@ -40,9 +45,9 @@ use mz_proto::ProtoType;
 use mz_sql_server_util::desc::SqlServerTableDesc;
 use mz_storage_types::sources::load_generator::LoadGeneratorOutput;
 use mz_storage_types::sources::{ProtoSourceExportStatementDetails, SourceExportStatementDetails};
-use prost::Message;
 use proptest::strategy::{Strategy, ValueTree};
 use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
+use prost::Message;
 
 /// Build a 32-byte proptest seed from `bytes` (zero-padded / truncated).
 fn seed_from(bytes: &[u8]) -> [u8; 32] {
@@ -88,13 +93,13 @@ fuzz_target!(|data: &[u8]| {
 
     if mode & 1 == 0 {
         // Structured arm: synthesize a valid value. Upper bits of `mode` select
-        // which of the 5 variants to build.
+        // which of the 12 variants to build.
         let seed = seed_from(rest);
         let mut runner = TestRunner::new_with_rng(
             Config::default(),
             TestRng::from_seed(RngAlgorithm::ChaCha, &seed),
         );
-        let value = match (mode >> 1) % 5 {
+        let value = match (mode >> 1) % 12 {
             0 => {
                 let Some(table) = arb::<PostgresTableDesc>(&mut runner) else {
                     return;
@@ -149,7 +154,37 @@ fuzz_target!(|data: &[u8]| {
                 };
                 SourceExportStatementDetails::LoadGenerator { output }
             }
-            _ => SourceExportStatementDetails::Kafka {},
+            4 => SourceExportStatementDetails::Kafka {},
+            5 => {
+                let Some(collection) = arb::<String>(&mut runner) else {
+                    return;
+                };
+                let Some(initial_cluster_time) = arb::<mz_mongodb_util::ClusterTime>(&mut runner)
+                else {
+                    return;
+                };
+                SourceExportStatementDetails::MongoDb {
+                    collection,
+                    initial_cluster_time,
+                }
+            }
+            6 => SourceExportStatementDetails::PostgresMessages {},
+            7 => SourceExportStatementDetails::S3 {},
+            8 => SourceExportStatementDetails::Kinesis {},
+            9 => SourceExportStatementDetails::Nats {},
+            10 => SourceExportStatementDetails::Mqtt {},
+            _ => {
+                let Some(table) = arb::<String>(&mut runner) else {
+                    return;
+                };
+                let Some(table_uuid) = arb::<u128>(&mut runner) else {
+                    return;
+                };
+                SourceExportStatementDetails::Iceberg {
+                    table,
+                    table_uuid: uuid::Uuid::from_u128(table_uuid),
+                }
+            }
         };
         assert_roundtrip(value);
     } else {
@@ -13,7 +13,7 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use anyhow::{Context, anyhow};
 use async_trait::async_trait;
@@ -44,6 +44,7 @@ use mz_kafka_util::client::{
     BrokerAddr, BrokerRewrite, HostMappingRules, MzClientContext, MzKafkaError, TunnelConfig,
     TunnelingClientContext,
 };
+use mz_mongodb_util::MongoDbError;
 use mz_mysql_util::{MySqlConn, MySqlError};
 use mz_ore::assert_none;
 use mz_ore::error::ErrorExt;
@@ -378,6 +379,9 @@ pub enum Connection<C: ConnectionAccess = InlinedConnection> {
     Gcp(gcp::GcpConnection),
     MySql(MySqlConnection<C>),
     SqlServer(SqlServerConnectionDetails<C>),
+    MongoDb(MongoDbConnection),
+    Nats(NatsConnection),
+    Mqtt(MqttConnection),
     IcebergCatalog(IcebergCatalogConnection<C>),
 }
 
@@ -400,6 +404,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<Connection, R>
             Connection::SqlServer(sql_server) => {
                 Connection::SqlServer(sql_server.into_inline_connection(r))
             }
+            Connection::MongoDb(mongodb) => Connection::MongoDb(mongodb),
+            Connection::Nats(nats) => Connection::Nats(nats),
+            Connection::Mqtt(mqtt) => Connection::Mqtt(mqtt),
             Connection::IcebergCatalog(iceberg) => {
                 Connection::IcebergCatalog(iceberg.into_inline_connection(r))
             }
@@ -421,6 +428,9 @@ impl<C: ConnectionAccess> Connection<C> {
             Connection::Gcp(conn) => conn.validate_by_default(),
             Connection::MySql(conn) => conn.validate_by_default(),
             Connection::SqlServer(conn) => conn.validate_by_default(),
+            Connection::MongoDb(conn) => conn.validate_by_default(),
+            Connection::Nats(conn) => conn.validate_by_default(),
+            Connection::Mqtt(conn) => conn.validate_by_default(),
             Connection::IcebergCatalog(conn) => conn.validate_by_default(),
         }
     }
@@ -452,6 +462,11 @@ impl Connection<InlinedConnection> {
             Connection::SqlServer(conn) => {
                 conn.validate(id, storage_configuration).await?;
             }
+            Connection::MongoDb(conn) => {
+                conn.validate(id, storage_configuration).await?;
+            }
+            Connection::Nats(conn) => conn.validate(id, storage_configuration).await?,
+            Connection::Mqtt(conn) => conn.validate(id, storage_configuration).await?,
             Connection::IcebergCatalog(conn) => conn.validate(id, storage_configuration).await?,
         }
         Ok(())
@@ -485,6 +500,27 @@ impl Connection<InlinedConnection> {
         }
     }
 
+    pub fn unwrap_mongodb(self) -> <InlinedConnection as ConnectionAccess>::MongoDb {
+        match self {
+            Self::MongoDb(conn) => conn,
+            o => unreachable!("{o:?} is not a MongoDB connection"),
+        }
+    }
+
+    pub fn unwrap_nats(self) -> <InlinedConnection as ConnectionAccess>::Nats {
+        match self {
+            Self::Nats(conn) => conn,
+            o => unreachable!("{o:?} is not a NATS connection"),
+        }
+    }
+
+    pub fn unwrap_mqtt(self) -> <InlinedConnection as ConnectionAccess>::Mqtt {
+        match self {
+            Self::Mqtt(conn) => conn,
+            o => unreachable!("{o:?} is not an MQTT connection"),
+        }
+    }
+
     pub fn unwrap_aws(self) -> <InlinedConnection as ConnectionAccess>::Aws {
         match self {
             Self::Aws(conn) => conn,
@@ -540,6 +576,8 @@ pub enum ConnectionValidationError {
     #[error(transparent)]
     SqlServer(#[from] SqlServerConnectionValidationError),
     #[error(transparent)]
+    MongoDb(#[from] MongoDbConnectionValidationError),
+    #[error(transparent)]
     Aws(#[from] AwsConnectionValidationError),
     #[error(transparent)]
     Gcp(#[from] gcp::GcpConnectionValidationError),
@@ -554,6 +592,7 @@ impl ConnectionValidationError {
             ConnectionValidationError::Postgres(e) => e.detail(),
             ConnectionValidationError::MySql(e) => e.detail(),
             ConnectionValidationError::SqlServer(e) => e.detail(),
+            ConnectionValidationError::MongoDb(e) => e.detail(),
             ConnectionValidationError::Aws(e) => e.detail(),
             ConnectionValidationError::Gcp(e) => e.detail(),
             ConnectionValidationError::Other(_) => None,
@@ -566,6 +605,7 @@ impl ConnectionValidationError {
             ConnectionValidationError::Postgres(e) => e.hint(),
             ConnectionValidationError::MySql(e) => e.hint(),
             ConnectionValidationError::SqlServer(e) => e.hint(),
+            ConnectionValidationError::MongoDb(e) => e.hint(),
             ConnectionValidationError::Aws(e) => e.hint(),
             ConnectionValidationError::Gcp(e) => e.hint(),
             ConnectionValidationError::Other(_) => None,
@@ -584,6 +624,9 @@ impl<C: ConnectionAccess> AlterCompatible for Connection<C> {
             (Self::Kafka(s), Self::Kafka(o)) => s.alter_compatible(id, o),
             (Self::Postgres(s), Self::Postgres(o)) => s.alter_compatible(id, o),
             (Self::MySql(s), Self::MySql(o)) => s.alter_compatible(id, o),
+            (Self::MongoDb(s), Self::MongoDb(o)) => s.alter_compatible(id, o),
+            (Self::Nats(s), Self::Nats(o)) => s.alter_compatible(id, o),
+            (Self::Mqtt(s), Self::Mqtt(o)) => s.alter_compatible(id, o),
             _ => {
                 tracing::warn!(
                     "Connection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
@@ -2984,6 +3027,397 @@ impl<C: ConnectionAccess> AlterCompatible for SqlServerConnectionDetails<C> {
     }
 }
 
+/// How to secure a connection to a MongoDB deployment.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MongoDbTlsMode {
+    Disabled,
+    Required,
+    VerifyIdentity,
+}
+
+/// A connection to a MongoDB replica set or sharded cluster.
+///
+/// For specifics of connecting to MongoDB for purposes of creating a
+/// Materialize Source, see [`MongoDbSourceConnection`] which wraps this type.
+///
+/// [`MongoDbSourceConnection`]: crate::sources::MongoDbSourceConnection
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MongoDbConnection {
+    /// The hostname of a member of the deployment.
+    pub host: String,
+    /// The port of the member.
+    pub port: u16,
+    /// The username to authenticate as, if any.
+    pub user: Option<StringOrSecret>,
+    /// An optional password for authentication.
+    pub password: Option<CatalogItemId>,
+    /// The database that the user is defined in.
+    pub auth_source: Option<String>,
+    /// Whether to use TLS for encryption, and whether to verify the server's
+    /// certificate and identity.
+    pub tls_mode: MongoDbTlsMode,
+    /// An optional root TLS certificate in PEM format, to verify the server's
+    /// identity.
+    pub tls_root_cert: Option<StringOrSecret>,
+}
+
+impl MongoDbConnection {
+    fn validate_by_default(&self) -> bool {
+        true
+    }
+
+    pub async fn config(
+        &self,
+        secrets_reader: &Arc<dyn mz_secrets::SecretsReader>,
+        storage_configuration: &StorageConfiguration,
+        in_task: InTask,
+    ) -> Result<mz_mongodb_util::Config, anyhow::Error> {
+        // Ensure any host we connect to is resolved to an external address.
+        // The driver discovers the other members of the deployment from the
+        // one we connect to.
+        resolve_address(
+            &self.host,
+            ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set()),
+        )
+        .await?;
+
+        let user = match &self.user {
+            Some(user) => Some(user.get_string(in_task, secrets_reader).await?),
+            None => None,
+        };
+        let password = match self.password {
+            Some(password) => Some(
+                secrets_reader
+                    .read_string_in_task_if(in_task, password)
+                    .await?,
+            ),
+            None => None,
+        };
+        let tls_root_cert = match &self.tls_root_cert {
+            Some(cert) => Some(cert.get_string(in_task, secrets_reader).await?),
+            None => None,
+        };
+
+        Ok(mz_mongodb_util::Config {
+            hosts: vec![(self.host.clone(), self.port)],
+            user,
+            password,
+            auth_source: self.auth_source.clone(),
+            tls_mode: match self.tls_mode {
+                MongoDbTlsMode::Disabled => mz_mongodb_util::TlsMode::Disabled,
+                MongoDbTlsMode::Required => mz_mongodb_util::TlsMode::Required,
+                MongoDbTlsMode::VerifyIdentity => mz_mongodb_util::TlsMode::VerifyIdentity,
+            },
+            tls_root_cert,
+            connect_timeout: mz_mongodb_util::DEFAULT_CONNECT_TIMEOUT,
+        })
+    }
+
+    /// Attempts to connect to the deployment, and ensures it supports change
+    /// streams.
+    pub async fn validate(
+        &self,
+        _id: CatalogItemId,
+        storage_configuration: &StorageConfiguration,
+    ) -> Result<mongodb::Client, MongoDbConnectionValidationError> {
+        let config = self
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                // We are in a normal tokio context during validation, already.
+                InTask::No,
+            )
+            .await?;
+        let client = config.connect().await?;
+
+        match mz_mongodb_util::inspect::ensure_change_stream_support(&client).await {
+            Err(MongoDbError::InvalidSystemSetting {
+                setting,
+                expected,
+                actual,
+            }) => Err(MongoDbConnectionValidationError::ReplicationSettingsError(
+                vec![(setting, expected, actual)],
+            )),
+            Err(e) => Err(e.into()),
+            Ok(()) => Ok(client),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MongoDbConnectionValidationError {
+    #[error("Invalid MongoDB deployment settings")]
+    ReplicationSettingsError(Vec<(String, String, String)>),
+    #[error(transparent)]
+    Client(#[from] MongoDbError),
+    #[error("{}", .0.display_with_causes())]
+    Other(#[from] anyhow::Error),
+}
+
+impl MongoDbConnectionValidationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::ReplicationSettingsError(settings) => Some(format!(
+                "Invalid MongoDB deployment settings: {}",
+                itertools::join(
+                    settings.iter().map(|(setting, expected, actual)| format!(
+                        "{}: expected {}, got {}",
+                        setting, expected, actual
+                    )),
+                    "; "
+                )
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::ReplicationSettingsError(_) => Some(
+                "MongoDB sources require a replica set or sharded cluster running MongoDB 6.0 or later."
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl AlterCompatible for MongoDbConnection {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        // All options may change arbitrarily.
+        Ok(())
+    }
+}
+
+/// How long to wait for a NATS server to accept a connection.
+const NATS_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A connection to a NATS server with JetStream enabled.
+///
+/// For specifics of reading from NATS for purposes of creating a Materialize
+/// Source, see [`NatsSourceConnection`] which wraps this type.
+///
+/// [`NatsSourceConnection`]: crate::sources::NatsSourceConnection
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct NatsConnection {
+    /// The URL of the server, e.g. `nats://nats.example.com:4222`. A `tls://`
+    /// URL requires TLS.
+    pub url: String,
+    /// The username to authenticate as, if any.
+    pub user: Option<StringOrSecret>,
+    /// An optional password for authentication.
+    pub password: Option<CatalogItemId>,
+}
+
+impl NatsConnection {
+    fn validate_by_default(&self) -> bool {
+        true
+    }
+
+    /// Connects to the server.
+    pub async fn connect(
+        &self,
+        secrets_reader: &Arc<dyn mz_secrets::SecretsReader>,
+        storage_configuration: &StorageConfiguration,
+        in_task: InTask,
+    ) -> Result<async_nats::Client, anyhow::Error> {
+        let addr: async_nats::ServerAddr = self
+            .url
+            .parse()
+            .with_context(|| format!("invalid NATS URL: {}", self.url))?;
+        // Ensure the host we connect to is resolved to an external address.
+        // Servers the client learns about from the cluster are ignored below.
+        resolve_address(
+            addr.host(),
+            ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set()),
+        )
+        .await?;
+
+        let mut options = async_nats::ConnectOptions::new()
+            .ignore_discovered_servers()
+            .connection_timeout(NATS_CONNECT_TIMEOUT)
+            .require_tls(addr.is_tls_required());
+        if let Some(user) = &self.user {
+            let user = user.get_string(in_task, secrets_reader).await?;
+            let password = match self.password {
+                Some(password) => {
+                    secrets_reader
+                        .read_string_in_task_if(in_task, password)
+                        .await?
+                }
+                None => String::new(),
+            };
+            options = options.user_and_password(user, password);
+        }
+
+        let client = options
+            .connect(addr)
+            .await
+            .context("connecting to NATS server")?;
+        Ok(client)
+    }
+
+    /// Attempts to connect to the server, and ensures JetStream is enabled.
+    pub async fn validate(
+        &self,
+        _id: CatalogItemId,
+        storage_configuration: &StorageConfiguration,
+    ) -> Result<(), anyhow::Error> {
+        let client = self
+            .connect(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                // We are in a normal tokio context during validation, already.
+                InTask::No,
+            )
+            .await?;
+        async_nats::jetstream::new(client)
+            .query_account()
+            .await
+            .context("querying JetStream account; is JetStream enabled on the server?")?;
+        Ok(())
+    }
+}
+
+impl AlterCompatible for NatsConnection {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        // All options may change arbitrarily.
+        Ok(())
+    }
+}
+
+/// How to secure a connection to an MQTT broker.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MqttTlsMode {
+    Disabled,
+    Required,
+}
+
+/// How long to wait for an MQTT broker to acknowledge a connection during
+/// validation.
+const MQTT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A connection to an MQTT 5 broker.
+///
+/// For specifics of subscribing to MQTT topics for purposes of creating a
+/// Materialize Source, see [`MqttSourceConnection`] which wraps this type.
+///
+/// [`MqttSourceConnection`]: crate::sources::MqttSourceConnection
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MqttConnection {
+    /// The hostname of the broker.
+    pub host: String,
+    /// The port of the broker.
+    pub port: u16,
+    /// The username to authenticate as, if any.
+    pub user: Option<StringOrSecret>,
+    /// An optional password for authentication.
+    pub password: Option<CatalogItemId>,
+    /// Whether to use TLS for encryption.
+    pub tls_mode: MqttTlsMode,
+    /// An optional root TLS certificate in PEM format, to verify the broker's
+    /// identity. If not set, the system's root certificates are used.
+    pub tls_root_cert: Option<StringOrSecret>,
+}
+
+impl MqttConnection {
+    fn validate_by_default(&self) -> bool {
+        true
+    }
+
+    /// Returns the options for a client that identifies itself to the broker
+    /// as `client_id`.
+    pub async fn options(
+        &self,
+        client_id: String,
+        secrets_reader: &Arc<dyn mz_secrets::SecretsReader>,
+        storage_configuration: &StorageConfiguration,
+        in_task: InTask,
+    ) -> Result<rumqttc::v5::MqttOptions, anyhow::Error> {
+        // Ensure the host we connect to is resolved to an external address.
+        resolve_address(
+            &self.host,
+            ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set()),
+        )
+        .await?;
+
+        let mut options = rumqttc::v5::MqttOptions::new(client_id, &self.host, self.port);
+        if let Some(user) = &self.user {
+            let user = user.get_string(in_task, secrets_reader).await?;
+            let password = match self.password {
+                Some(password) => {
+                    secrets_reader
+                        .read_string_in_task_if(in_task, password)
+                        .await?
+                }
+                None => String::new(),
+            };
+            options.set_credentials(user, password);
+        }
+        match self.tls_mode {
+            MqttTlsMode::Disabled => {}
+            MqttTlsMode::Required => {
+                let transport = match &self.tls_root_cert {
+                    Some(cert) => {
+                        let cert = cert.get_string(in_task, secrets_reader).await?;
+                        rumqttc::Transport::tls(cert.into_bytes(), None, None)
+                    }
+                    None => rumqttc::Transport::tls_with_default_config(),
+                };
+                options.set_transport(transport);
+            }
+        }
+        Ok(options)
+    }
+
+    /// Attempts to connect to the broker.
+    pub async fn validate(
+        &self,
+        id: CatalogItemId,
+        storage_configuration: &StorageConfiguration,
+    ) -> Result<(), anyhow::Error> {
+        let client_id = format!(
+            "materialize-{}-{}-validate",
+            storage_configuration.connection_context.environment_id, id,
+        );
+        let options = self
+            .options(
+                client_id,
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                // We are in a normal tokio context during validation, already.
+                InTask::No,
+            )
+            .await?;
+        let (client, mut eventloop) = rumqttc::v5::AsyncClient::new(options, 1);
+        let connect = async {
+            loop {
+                match eventloop.poll().await {
+                    Ok(rumqttc::v5::Event::Incoming(rumqttc::v5::Incoming::ConnAck(_))) => {
+                        break Ok(());
+                    }
+                    Ok(_) => {}
+                    Err(e) => break Err(e),
+                }
+            }
+        };
+        tokio::time::timeout(MQTT_CONNECT_TIMEOUT, connect)
+            .await
+            .map_err(|_| anyhow!("timed out connecting to MQTT broker"))?
+            .context("connecting to MQTT broker")?;
+        // Best effort, the broker cleans up after the dropped connection otherwise.
+        let _ = client.try_disconnect();
+        Ok(())
+    }
+}
+
+impl AlterCompatible for MqttConnection {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        // All options may change arbitrarily.
+        Ok(())
+    }
+}
+
 /// A connection to an SSH tunnel.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct SshConnection {
@@ -125,6 +125,30 @@ pub trait ConnectionAccess: Clone + Debug + Eq + PartialEq + Serialize + 'static
         + Serialize
         + for<'a> Deserialize<'a>
         + AlterCompatible;
+    type MongoDb: Clone
+        + Debug
+        + Eq
+        + PartialEq
+        + Hash
+        + Serialize
+        + for<'a> Deserialize<'a>
+        + AlterCompatible;
+    type Nats: Clone
+        + Debug
+        + Eq
+        + PartialEq
+        + Hash
+        + Serialize
+        + for<'a> Deserialize<'a>
+        + AlterCompatible;
+    type Mqtt: Clone
+        + Debug
+        + Eq
+        + PartialEq
+        + Hash
+        + Serialize
+        + for<'a> Deserialize<'a>
+        + AlterCompatible;
     type IcebergCatalog: Clone
         + Debug
         + Eq
@@ -151,6 +175,9 @@ impl ConnectionAccess for ReferencedConnection {
     type GlueSchemaRegistry = CatalogItemId;
     type MySql = CatalogItemId;
     type SqlServer = CatalogItemId;
+    type MongoDb = CatalogItemId;
+    type Nats = CatalogItemId;
+    type Mqtt = CatalogItemId;
     type IcebergCatalog = CatalogItemId;
 }
 
@@ -168,5 +195,8 @@ impl ConnectionAccess for InlinedConnection {
     type GlueSchemaRegistry = super::GlueSchemaRegistryConnection;
     type MySql = super::MySqlConnection;
     type SqlServer = super::SqlServerConnectionDetails;
+    type MongoDb = super::MongoDbConnection;
+    type Nats = super::NatsConnection;
+    type Mqtt = super::MqttConnection;
     type IcebergCatalog = super::IcebergCatalogConnection;
 }
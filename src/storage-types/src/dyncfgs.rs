@@ -244,6 +244,46 @@ pub static SQL_SERVER_SOURCE_VALIDATE_RESTORE_HISTORY: Config<bool> = Config::ne
     "Whether to treat a restore history change as a definite error",
 );
 
+// S3
+
+/// Interval at which an S3 source lists its bucket for new objects.
+pub const S3_SOURCE_LIST_INTERVAL: Config<Duration> = Config::new(
+    "s3_source_list_interval",
+    Duration::from_secs(10),
+    "Interval at which an S3 source lists its bucket for new objects.",
+);
+
+// Iceberg
+
+/// Interval at which an Iceberg source checks its tables for new snapshots.
+pub const ICEBERG_SOURCE_POLL_INTERVAL: Config<Duration> = Config::new(
+    "iceberg_source_poll_interval",
+    Duration::from_secs(10),
+    "Interval at which an Iceberg source checks its tables for new snapshots.",
+);
+
+// Kinesis
+
+/// Interval at which a Kinesis source polls each of the shards it reads for new records.
+///
+/// Kinesis allows five `GetRecords` calls per second and shard, shared by every consumer of the
+/// stream.
+pub const KINESIS_SOURCE_POLL_INTERVAL: Config<Duration> = Config::new(
+    "kinesis_source_poll_interval",
+    Duration::from_millis(1000),
+    "Interval at which a Kinesis source polls each of its shards for new records.",
+);
+
+// NATS
+
+/// Interval at which a NATS source fetches new messages from its consumer, while it is caught up
+/// with the stream.
+pub const NATS_SOURCE_POLL_INTERVAL: Config<Duration> = Config::new(
+    "nats_source_poll_interval",
+    Duration::from_millis(100),
+    "Interval at which a NATS source fetches new messages from its consumer.",
+);
+
 // AWS
 
 /// The AWS SDK's connect timeout on the AssumeRole prefetcher's STS calls.
@@ -403,6 +443,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&CLUSTER_SHUTDOWN_GRACE_PERIOD)
         .add(&DELAY_SOURCES_PAST_REHYDRATION)
         .add(&ENFORCE_EXTERNAL_ADDRESSES)
+        .add(&ICEBERG_SOURCE_POLL_INTERVAL)
         .add(&KAFKA_BUFFERED_EVENT_RESIZE_THRESHOLD_ELEMENTS)
         .add(&KAFKA_CLIENT_ID_ENRICHMENT_RULES)
         .add(&KAFKA_DEFAULT_AWS_PRIVATELINK_ENDPOINT_IDENTIFICATION_ALGORITHM)
@@ -415,12 +456,15 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&KAFKA_SINK_MESSAGE_MAX_BYTES)
         .add(&KAFKA_SINK_BATCH_SIZE)
         .add(&KAFKA_SINK_BATCH_NUM_MESSAGES)
+        .add(&KINESIS_SOURCE_POLL_INTERVAL)
+        .add(&NATS_SOURCE_POLL_INTERVAL)
         .add(&MYSQL_REPLICATION_HEARTBEAT_INTERVAL)
         .add(&ORE_OVERFLOWING_BEHAVIOR)
         .add(&PG_FETCH_SLOT_RESUME_LSN_INTERVAL)
         .add(&PG_SCHEMA_VALIDATION_INTERVAL)
         .add(&PG_SOURCE_VALIDATE_TIMELINE)
         .add(&REPLICA_METRICS_HISTORY_RETENTION_INTERVAL)
+        .add(&S3_SOURCE_LIST_INTERVAL)
         .add(&SINK_ENSURE_TOPIC_CONFIG)
         .add(&SINK_PROGRESS_SEARCH)
         .add(&SQL_SERVER_SOURCE_VALIDATE_RESTORE_HISTORY)
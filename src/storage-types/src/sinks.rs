@@ -10,6 +10,7 @@
 //! Types and traits related to reporting changing collections out of `dataflow`.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::time::Duration;
 
@@ -17,7 +18,7 @@ use mz_dyncfg::ConfigSet;
 use mz_expr::MirScalarExpr;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::bytes::ByteSize;
-use mz_repr::{CatalogItemId, GlobalId, RelationDesc};
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, RelationVersion};
 #[cfg(any(test, feature = "proptest"))]
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,7 @@ use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
 };
+use crate::connections::string_or_secret::StringOrSecret;
 use crate::connections::{ConnectionContext, KafkaConnection, KafkaTopicOptions};
 use crate::controller::AlterError;
 use crate::wire_format::WireFormat;
@@ -126,10 +128,10 @@ impl<S: Debug + PartialEq, T: Debug + PartialEq + PartialOrder> AlterCompatible
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum SinkEnvelope {
-    /// Only used for Kafka.
+    /// Only used for Kafka and HTTP sinks.
     Debezium,
     Upsert,
-    /// Only used for Iceberg.
+    /// Only used for Iceberg and S3 sinks.
     Append,
 }
 
@@ -137,6 +139,9 @@ pub enum SinkEnvelope {
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
     Iceberg(IcebergSinkConnection<C>),
+    Postgres(PostgresSinkConnection<C>),
+    Http(HttpSinkConnection),
+    S3(S3SinkConnection<C>),
 }
 
 impl<C: ConnectionAccess> StorageSinkConnection<C> {
@@ -159,6 +164,15 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
             (StorageSinkConnection::Iceberg(s), StorageSinkConnection::Iceberg(o)) => {
                 s.alter_compatible(id, o)?
             }
+            (StorageSinkConnection::Postgres(s), StorageSinkConnection::Postgres(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Http(s), StorageSinkConnection::Http(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::S3(s), StorageSinkConnection::S3(o)) => {
+                s.alter_compatible(id, o)?
+            }
             _ => {
                 tracing::warn!(
                     "StorageSinkConnection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
@@ -180,6 +194,9 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
             Self::Iceberg(conn) => StorageSinkConnection::Iceberg(conn.into_inline_connection(r)),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
+            Self::Http(conn) => StorageSinkConnection::Http(conn),
+            Self::S3(conn) => StorageSinkConnection::S3(conn.into_inline_connection(r)),
         }
     }
 }
@@ -194,6 +211,9 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
                 catalog_connection_id: connection_id,
                 ..
             }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
+            S3(S3SinkConnection { connection_id, .. }) => Some(*connection_id),
+            Http(_) => None,
         }
     }
 
@@ -203,6 +223,9 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         match self {
             Kafka(_) => "kafka",
             Iceberg(_) => "iceberg",
+            Postgres(_) => "postgres",
+            Http(_) => "http",
+            S3(_) => "s3",
         }
     }
 }
@@ -240,12 +263,20 @@ pub struct KafkaSinkConnection<C: ConnectionAccess = InlinedConnection> {
     /// The user-specified key for the sink.
     pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
     /// The index of the column containing message headers value, if any.
+    ///
+    /// The column is either a `map[text => text|bytea]` or a list of records with a `key` and a
+    /// `value` field, which allows repeating a header key.
     pub headers_index: Option<usize>,
     pub value_desc: RelationDesc,
     /// An expression that, if present, computes a hash value that should be
     /// used to determine the partition for each message.
     pub partition_by: Option<MirScalarExpr>,
+    /// The default topic for messages, and the topic for messages whose `topic_by` evaluates to
+    /// `NULL` or an error.
     pub topic: String,
+    /// An expression that, if present, computes the name of the topic each message should be
+    /// written to.
+    pub topic_by: Option<MirScalarExpr>,
     /// Options to use when creating the topic if it doesn't already exist.
     pub topic_options: KafkaTopicOptions,
     pub compression_type: KafkaSinkCompressionType,
@@ -340,6 +371,7 @@ impl<C: ConnectionAccess> KafkaSinkConnection<C> {
             value_desc,
             partition_by,
             topic,
+            topic_by,
             compression_type,
             progress_group_id,
             transactional_id,
@@ -366,6 +398,7 @@ impl<C: ConnectionAccess> KafkaSinkConnection<C> {
             (value_desc == &other.value_desc, "value_desc"),
             (partition_by == &other.partition_by, "partition_by"),
             (topic == &other.topic, "topic"),
+            (topic_by == &other.topic_by, "topic_by"),
             (
                 compression_type == &other.compression_type,
                 "compression_type",
@@ -414,6 +447,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkConnection, R>
             value_desc,
             partition_by,
             topic,
+            topic_by,
             compression_type,
             progress_group_id,
             transactional_id,
@@ -430,6 +464,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkConnection, R>
             value_desc,
             partition_by,
             topic,
+            topic_by,
             compression_type,
             progress_group_id,
             transactional_id,
@@ -469,6 +504,23 @@ pub enum KafkaSinkFormatType<C: ConnectionAccess = InlinedConnection> {
         wire_format: WireFormat<C>,
     },
     Json,
+    /// A JSON array per timestamp, holding the JSON encoding of each row changed at that
+    /// timestamp. Only valid as a value format.
+    JsonArray,
+    /// JSON in the Confluent wire format, described by a JSON Schema that is
+    /// published to the schema registry under the topic-derived subject.
+    JsonSchema {
+        schema: String,
+        compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+        csr_connection: C::Csr,
+    },
+    /// Protobuf in the Confluent wire format, described by a `.proto` schema
+    /// that is published to the schema registry under the topic-derived subject.
+    Protobuf {
+        schema: String,
+        compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+        csr_connection: C::Csr,
+    },
     Text,
     Bytes,
 }
@@ -477,7 +529,9 @@ impl<C: ConnectionAccess> KafkaSinkFormatType<C> {
     pub fn get_format_name(&self) -> &str {
         match self {
             Self::Avro { .. } => "avro",
-            Self::Json => "json",
+            Self::Json | Self::JsonSchema { .. } => "json",
+            Self::JsonArray => "json-array",
+            Self::Protobuf { .. } => "protobuf",
             Self::Text => "text",
             Self::Bytes => "bytes",
         }
@@ -495,7 +549,14 @@ impl<C: ConnectionAccess> KafkaSinkFormat<C> {
                 (KafkaSinkFormatType::Avro { .. }, KafkaSinkFormatType::Avro { .. }) => {
                     "avro".into()
                 }
-                (KafkaSinkFormatType::Json, KafkaSinkFormatType::Json) => "json".into(),
+                (KafkaSinkFormatType::Json, KafkaSinkFormatType::Json)
+                | (
+                    KafkaSinkFormatType::JsonSchema { .. },
+                    KafkaSinkFormatType::JsonSchema { .. },
+                ) => "json".into(),
+                (KafkaSinkFormatType::Protobuf { .. }, KafkaSinkFormatType::Protobuf { .. }) => {
+                    "protobuf".into()
+                }
                 (keyf, valuef) => format!(
                     "key-{}-value-{}",
                     keyf.get_format_name(),
@@ -623,6 +684,25 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSinkFormatType, R>
                 wire_format: wire_format.into_inline_connection(r),
             },
             KafkaSinkFormatType::Json => KafkaSinkFormatType::Json,
+            KafkaSinkFormatType::JsonArray => KafkaSinkFormatType::JsonArray,
+            KafkaSinkFormatType::JsonSchema {
+                schema,
+                compatibility_level,
+                csr_connection,
+            } => KafkaSinkFormatType::JsonSchema {
+                schema,
+                compatibility_level,
+                csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
+            },
+            KafkaSinkFormatType::Protobuf {
+                schema,
+                compatibility_level,
+                csr_connection,
+            } => KafkaSinkFormatType::Protobuf {
+                schema,
+                compatibility_level,
+                csr_connection: r.resolve_connection(csr_connection).unwrap_csr(),
+            },
             KafkaSinkFormatType::Text => KafkaSinkFormatType::Text,
             KafkaSinkFormatType::Bytes => KafkaSinkFormatType::Bytes,
         }
@@ -720,6 +800,44 @@ pub struct IcebergSinkConnection<C: ConnectionAccess = InlinedConnection> {
     pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
     pub namespace: String,
     pub table: String,
+    /// The partition spec to create the table with. Empty for an
+    /// unpartitioned table. Ignored if the table already exists.
+    #[serde(default)]
+    pub partition_by: Vec<IcebergPartitionField>,
+    /// The version of the sinked relation, recorded in the properties of
+    /// every snapshot. `None` if the relation is not versioned.
+    #[serde(default)]
+    pub relation_version: Option<RelationVersion>,
+    /// How often to compact the table, rewriting small data files and
+    /// resolving delete files. `None` disables compaction.
+    #[serde(default)]
+    pub compaction_interval: Option<Duration>,
+    /// How long to retain snapshots before expiring them. `None` disables
+    /// snapshot expiration.
+    #[serde(default)]
+    pub snapshot_retention: Option<Duration>,
+}
+
+/// A field of the partition spec of an Iceberg sink's table.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub struct IcebergPartitionField {
+    /// The name of the source column in the sinked relation.
+    pub column: String,
+    pub transform: IcebergPartitionTransform,
+}
+
+/// A partition transform, as defined by the Iceberg spec.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "proptest"), derive(Arbitrary))]
+pub enum IcebergPartitionTransform {
+    Identity,
+    Bucket(u32),
+    Truncate(u32),
+    Year,
+    Month,
+    Day,
+    Hour,
 }
 
 impl<C: ConnectionAccess> IcebergSinkConnection<C> {
@@ -740,6 +858,10 @@ impl<C: ConnectionAccess> IcebergSinkConnection<C> {
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            relation_version,
+            compaction_interval,
+            snapshot_retention,
         } = self;
 
         let compatibility_checks = [
@@ -782,6 +904,19 @@ impl<C: ConnectionAccess> IcebergSinkConnection<C> {
             ),
             (namespace == &other.namespace, "namespace"),
             (table == &other.table, "table"),
+            (partition_by == &other.partition_by, "partition_by"),
+            (
+                relation_version == &other.relation_version,
+                "relation_version",
+            ),
+            (
+                compaction_interval == &other.compaction_interval,
+                "compaction_interval",
+            ),
+            (
+                snapshot_retention == &other.snapshot_retention,
+                "snapshot_retention",
+            ),
         ];
         for (compatible, field) in compatibility_checks {
             if !compatible {
@@ -812,6 +947,10 @@ impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            relation_version,
+            compaction_interval,
+            snapshot_retention,
         } = self;
         IcebergSinkConnection {
             catalog_connection_id,
@@ -824,6 +963,358 @@ impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
             key_desc_and_indices,
             namespace,
             table,
+            partition_by,
+            relation_version,
+            compaction_interval,
+            snapshot_retention,
+        }
+    }
+}
+
+/// A sink that applies the changes to a relation to a table in a PostgreSQL
+/// database.
+///
+/// Every update is applied by key: an insertion or update becomes an
+/// `INSERT ... ON CONFLICT` and a deletion a `DELETE`. The updates of each
+/// timestamp are applied together, in one transaction with the frontier they
+/// complete, which the sink records in [`POSTGRES_SINK_PROGRESS_TABLE`] in the
+/// table's schema.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub connection: C::Pg,
+    /// The schema of the target table.
+    pub schema: String,
+    /// The name of the target table.
+    pub table: String,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink, which must match a unique
+    /// constraint of the target table.
+    pub key_desc_and_indices: (RelationDesc, Vec<usize>),
+    pub value_desc: RelationDesc,
+}
+
+/// The name of the table in which Postgres sinks record their progress. It is
+/// created, if necessary, in the schema of each sink's target table.
+pub const POSTGRES_SINK_PROGRESS_TABLE: &str = "_mz_sink_progress";
+
+impl<C: ConnectionAccess> PostgresSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (schema == &other.schema, "schema"),
+            (table == &other.table, "table"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "PostgresSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+        PostgresSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_pg(),
+            schema,
+            table,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        }
+    }
+}
+
+/// A sink that POSTs the changes to a relation to an HTTP endpoint.
+///
+/// The changes at each timestamp are delivered in one or more requests of at
+/// most `batch_size` changes. Every request carries an idempotency key derived
+/// from the sink, the timestamp and the position of the request among those of
+/// the timestamp, so that receivers can discard the requests that the sink
+/// repeats after a restart.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkConnection {
+    /// The URL to POST to.
+    pub url: String,
+    /// Additional headers to send with every request.
+    pub headers: BTreeMap<String, StringOrSecret>,
+    /// How to sign the body of every request, if at all.
+    pub signing: Option<HttpSinkSigning>,
+    pub body_format: HttpSinkBodyFormat,
+    /// The maximum number of changes in a request.
+    pub batch_size: u64,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    pub value_desc: RelationDesc,
+}
+
+/// The HMAC-SHA256 signature of the request body, encoded as base64, which an
+/// HTTP sink sends in `header`.
+///
+/// This matches the signatures that the `CHECK` clause of webhook sources
+/// typically validates with `hmac(body, secret, 'sha256')`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkSigning {
+    pub secret: CatalogItemId,
+    pub header: String,
+}
+
+/// The default header of an HTTP sink's signature.
+pub const HTTP_SINK_DEFAULT_SIGNING_HEADER: &str = "x-signature";
+
+/// The default number of changes in a request of an HTTP sink.
+pub const HTTP_SINK_DEFAULT_BATCH_SIZE: u64 = 1000;
+
+/// How an HTTP sink lays out the changes in the body of a request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HttpSinkBodyFormat {
+    /// A JSON array with one element per change.
+    Json,
+    /// One JSON document per change, each followed by a newline.
+    Ndjson,
+}
+
+impl HttpSinkBodyFormat {
+    /// The `Content-Type` of a request body in this format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            HttpSinkBodyFormat::Json => "application/json",
+            HttpSinkBodyFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+impl HttpSinkConnection {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations.
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let HttpSinkConnection {
+            url,
+            headers,
+            signing,
+            body_format,
+            batch_size,
+            relation_key_indices,
+            key_desc_and_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (headers == &other.headers, "headers"),
+            (signing == &other.signing, "signing"),
+            (body_format == &other.body_format, "body_format"),
+            (batch_size == &other.batch_size, "batch_size"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "HttpSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A sink that continuously writes the changes to a relation to files in S3.
+///
+/// Every change is written as a row of the sinked relation followed by its
+/// timestamp ([`S3_SINK_TIMESTAMP_COLUMN`]) and diff ([`S3_SINK_DIFF_COLUMN`]).
+/// Files are rolled once they reach `max_file_size` or `roll_interval` has
+/// passed, and placed under a prefix derived from the timestamp of their
+/// changes. Whenever the sink rolls its files it writes a manifest under
+/// [`S3_SINK_MANIFEST_PREFIX`] listing them along with the range of
+/// timestamps they cover, so that readers can restrict themselves to
+/// consistent cuts of the relation.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3SinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub aws_connection: C::Aws,
+    /// The `s3://bucket/prefix` URI under which to write.
+    pub uri: String,
+    pub format: S3SinkFormat,
+    /// The size in bytes at which to roll a data file.
+    pub max_file_size: u64,
+    /// The maximum amount of time between two manifests.
+    pub roll_interval: Duration,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    pub value_desc: RelationDesc,
+}
+
+/// Column name appended by S3 sinks to record the logical timestamp.
+pub const S3_SINK_TIMESTAMP_COLUMN: &str = "mz_timestamp";
+/// Column name appended by S3 sinks to record the diff (+1/−1).
+pub const S3_SINK_DIFF_COLUMN: &str = "mz_diff";
+/// The prefix, relative to the sink's URI, under which S3 sinks write their
+/// manifests.
+pub const S3_SINK_MANIFEST_PREFIX: &str = "_manifests";
+
+/// The default `ROLL INTERVAL` of an S3 sink.
+pub const S3_SINK_DEFAULT_ROLL_INTERVAL: Duration = Duration::from_secs(60);
+/// The default `MAX FILE SIZE` of an S3 sink.
+pub const S3_SINK_DEFAULT_MAX_FILE_SIZE: ByteSize = ByteSize::mb(256);
+
+impl<C: ConnectionAccess> S3SinkConnection<C> {
+    /// Returns the bucket of the sink's URI and the key prefix under which
+    /// the sink writes, without leading or trailing slashes.
+    pub fn bucket_and_prefix(&self) -> (String, String) {
+        // The URI was validated when the sink was planned.
+        let uri = self.uri.parse::<http::Uri>().expect("valid s3 uri");
+        let bucket = uri.host().expect("s3 bucket").to_string();
+        let prefix = uri.path().trim_matches('/').to_string();
+        (bucket, prefix)
+    }
+
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let S3SinkConnection {
+            connection_id,
+            aws_connection,
+            uri,
+            format,
+            max_file_size,
+            roll_interval,
+            relation_key_indices,
+            value_desc,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                aws_connection
+                    .alter_compatible(id, &other.aws_connection)
+                    .is_ok(),
+                "aws_connection",
+            ),
+            (uri == &other.uri, "uri"),
+            (format == &other.format, "format"),
+            (max_file_size == &other.max_file_size, "max_file_size"),
+            (roll_interval == &other.roll_interval, "roll_interval"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (value_desc == &other.value_desc, "value_desc"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "S3SinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<S3SinkConnection, R>
+    for S3SinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> S3SinkConnection {
+        let S3SinkConnection {
+            connection_id,
+            aws_connection,
+            uri,
+            format,
+            max_file_size,
+            roll_interval,
+            relation_key_indices,
+            value_desc,
+        } = self;
+        S3SinkConnection {
+            connection_id,
+            aws_connection: r.resolve_connection(aws_connection).unwrap_aws(),
+            uri,
+            format,
+            max_file_size,
+            roll_interval,
+            relation_key_indices,
+            value_desc,
         }
     }
 }
@@ -50,6 +50,7 @@ use serde::{Deserialize, Serialize};
 use timely::order::{PartialOrder, TotalOrder};
 use timely::progress::timestamp::Refines;
 use timely::progress::{PathSummary, Timestamp};
+use uuid::Uuid;
 
 use crate::AlterCompatible;
 use crate::connections::inline::{
@@ -64,17 +65,31 @@ use crate::sources::sql_server::SqlServerSourceExportDetails;
 pub mod casts;
 pub mod encoding;
 pub mod envelope;
+pub mod iceberg;
 pub mod kafka;
+pub mod kinesis;
 pub mod load_generator;
+pub mod mongodb;
+pub mod mqtt;
 pub mod mysql;
+pub mod nats;
 pub mod postgres;
+pub mod s3;
 pub mod sql_server;
 
 pub use crate::sources::envelope::SourceEnvelope;
+pub use crate::sources::iceberg::{IcebergSourceConnection, IcebergSourceExportDetails};
 pub use crate::sources::kafka::KafkaSourceConnection;
+pub use crate::sources::kinesis::{KinesisSourceConnection, KinesisSourceExportDetails};
 pub use crate::sources::load_generator::LoadGeneratorSourceConnection;
+pub use crate::sources::mongodb::{MongoDbSourceConnection, MongoDbSourceExportDetails};
+pub use crate::sources::mqtt::{MqttSourceConnection, MqttSourceExportDetails};
 pub use crate::sources::mysql::{MySqlSourceConnection, MySqlSourceExportDetails};
-pub use crate::sources::postgres::{PostgresSourceConnection, PostgresSourceExportDetails};
+pub use crate::sources::nats::{NatsSourceConnection, NatsSourceExportDetails};
+pub use crate::sources::postgres::{
+    PostgresMessagesSourceExportDetails, PostgresSourceConnection, PostgresSourceExportDetails,
+};
+pub use crate::sources::s3::{S3SourceConnection, S3SourceExportDetails};
 pub use crate::sources::sql_server::{SqlServerSourceConnection, SqlServerSourceExtras};
 
 include!(concat!(env!("OUT_DIR"), "/mz_storage_types.sources.rs"));
@@ -622,10 +637,22 @@ impl<C: ConnectionAccess> SourceExportDataConfig<C> {
                     GenericSourceConnection::MySql(_) => false,
                     // SQL Server can produce retractions (deletes).
                     GenericSourceConnection::SqlServer(_) => false,
+                    // MongoDB can produce retractions (deletes).
+                    GenericSourceConnection::MongoDb(_) => false,
+                    // S3 sources only ever append the rows of new objects.
+                    GenericSourceConnection::S3(_) => true,
+                    // Iceberg snapshots can delete rows.
+                    GenericSourceConnection::Iceberg(_) => false,
                     // Whether or not a Loadgen source can produce retractions varies.
                     GenericSourceConnection::LoadGenerator(g) => g.load_generator.is_monotonic(),
                     // Kafka exports with `None` envelope are append-only.
                     GenericSourceConnection::Kafka(_) => true,
+                    // Kinesis exports with `None` envelope are append-only.
+                    GenericSourceConnection::Kinesis(_) => true,
+                    // NATS exports with `None` envelope are append-only.
+                    GenericSourceConnection::Nats(_) => true,
+                    // MQTT exports with `None` envelope are append-only.
+                    GenericSourceConnection::Mqtt(_) => true,
                 }
             }
         }
@@ -693,9 +720,15 @@ impl<C: ConnectionAccess> AlterCompatible for SourceDesc<C> {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum GenericSourceConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSourceConnection<C>),
+    Kinesis(KinesisSourceConnection<C>),
+    Nats(NatsSourceConnection<C>),
+    Mqtt(MqttSourceConnection<C>),
     Postgres(PostgresSourceConnection<C>),
     MySql(MySqlSourceConnection<C>),
     SqlServer(SqlServerSourceConnection<C>),
+    MongoDb(MongoDbSourceConnection<C>),
+    S3(S3SourceConnection<C>),
+    Iceberg(IcebergSourceConnection<C>),
     LoadGenerator(LoadGeneratorSourceConnection),
 }
 
@@ -723,6 +756,42 @@ impl<C: ConnectionAccess> From<SqlServerSourceConnection<C>> for GenericSourceCo
     }
 }
 
+impl<C: ConnectionAccess> From<MongoDbSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: MongoDbSourceConnection<C>) -> Self {
+        Self::MongoDb(conn)
+    }
+}
+
+impl<C: ConnectionAccess> From<S3SourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: S3SourceConnection<C>) -> Self {
+        Self::S3(conn)
+    }
+}
+
+impl<C: ConnectionAccess> From<KinesisSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: KinesisSourceConnection<C>) -> Self {
+        Self::Kinesis(conn)
+    }
+}
+
+impl<C: ConnectionAccess> From<NatsSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: NatsSourceConnection<C>) -> Self {
+        Self::Nats(conn)
+    }
+}
+
+impl<C: ConnectionAccess> From<MqttSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: MqttSourceConnection<C>) -> Self {
+        Self::Mqtt(conn)
+    }
+}
+
+impl<C: ConnectionAccess> From<IcebergSourceConnection<C>> for GenericSourceConnection<C> {
+    fn from(conn: IcebergSourceConnection<C>) -> Self {
+        Self::Iceberg(conn)
+    }
+}
+
 impl<C: ConnectionAccess> From<LoadGeneratorSourceConnection> for GenericSourceConnection<C> {
     fn from(conn: LoadGeneratorSourceConnection) -> Self {
         Self::LoadGenerator(conn)
@@ -737,6 +806,15 @@ impl<R: ConnectionResolver> IntoInlineConnection<GenericSourceConnection, R>
             GenericSourceConnection::Kafka(kafka) => {
                 GenericSourceConnection::Kafka(kafka.into_inline_connection(r))
             }
+            GenericSourceConnection::Kinesis(kinesis) => {
+                GenericSourceConnection::Kinesis(kinesis.into_inline_connection(r))
+            }
+            GenericSourceConnection::Nats(nats) => {
+                GenericSourceConnection::Nats(nats.into_inline_connection(r))
+            }
+            GenericSourceConnection::Mqtt(mqtt) => {
+                GenericSourceConnection::Mqtt(mqtt.into_inline_connection(r))
+            }
             GenericSourceConnection::Postgres(pg) => {
                 GenericSourceConnection::Postgres(pg.into_inline_connection(r))
             }
@@ -746,6 +824,15 @@ impl<R: ConnectionResolver> IntoInlineConnection<GenericSourceConnection, R>
             GenericSourceConnection::SqlServer(sql_server) => {
                 GenericSourceConnection::SqlServer(sql_server.into_inline_connection(r))
             }
+            GenericSourceConnection::MongoDb(mongodb) => {
+                GenericSourceConnection::MongoDb(mongodb.into_inline_connection(r))
+            }
+            GenericSourceConnection::S3(s3) => {
+                GenericSourceConnection::S3(s3.into_inline_connection(r))
+            }
+            GenericSourceConnection::Iceberg(iceberg) => {
+                GenericSourceConnection::Iceberg(iceberg.into_inline_connection(r))
+            }
             GenericSourceConnection::LoadGenerator(lg) => {
                 GenericSourceConnection::LoadGenerator(lg)
             }
@@ -757,9 +844,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn name(&self) -> &'static str {
         match self {
             Self::Kafka(conn) => conn.name(),
+            Self::Kinesis(conn) => conn.name(),
+            Self::Nats(conn) => conn.name(),
+            Self::Mqtt(conn) => conn.name(),
             Self::Postgres(conn) => conn.name(),
             Self::MySql(conn) => conn.name(),
             Self::SqlServer(conn) => conn.name(),
+            Self::MongoDb(conn) => conn.name(),
+            Self::S3(conn) => conn.name(),
+            Self::Iceberg(conn) => conn.name(),
             Self::LoadGenerator(conn) => conn.name(),
         }
     }
@@ -767,9 +860,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn external_reference(&self) -> Option<&str> {
         match self {
             Self::Kafka(conn) => conn.external_reference(),
+            Self::Kinesis(conn) => conn.external_reference(),
+            Self::Nats(conn) => conn.external_reference(),
+            Self::Mqtt(conn) => conn.external_reference(),
             Self::Postgres(conn) => conn.external_reference(),
             Self::MySql(conn) => conn.external_reference(),
             Self::SqlServer(conn) => conn.external_reference(),
+            Self::MongoDb(conn) => conn.external_reference(),
+            Self::S3(conn) => conn.external_reference(),
+            Self::Iceberg(conn) => conn.external_reference(),
             Self::LoadGenerator(conn) => conn.external_reference(),
         }
     }
@@ -777,9 +876,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn default_key_desc(&self) -> RelationDesc {
         match self {
             Self::Kafka(conn) => conn.default_key_desc(),
+            Self::Kinesis(conn) => conn.default_key_desc(),
+            Self::Nats(conn) => conn.default_key_desc(),
+            Self::Mqtt(conn) => conn.default_key_desc(),
             Self::Postgres(conn) => conn.default_key_desc(),
             Self::MySql(conn) => conn.default_key_desc(),
             Self::SqlServer(conn) => conn.default_key_desc(),
+            Self::MongoDb(conn) => conn.default_key_desc(),
+            Self::S3(conn) => conn.default_key_desc(),
+            Self::Iceberg(conn) => conn.default_key_desc(),
             Self::LoadGenerator(conn) => conn.default_key_desc(),
         }
     }
@@ -787,9 +892,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn default_value_desc(&self) -> RelationDesc {
         match self {
             Self::Kafka(conn) => conn.default_value_desc(),
+            Self::Kinesis(conn) => conn.default_value_desc(),
+            Self::Nats(conn) => conn.default_value_desc(),
+            Self::Mqtt(conn) => conn.default_value_desc(),
             Self::Postgres(conn) => conn.default_value_desc(),
             Self::MySql(conn) => conn.default_value_desc(),
             Self::SqlServer(conn) => conn.default_value_desc(),
+            Self::MongoDb(conn) => conn.default_value_desc(),
+            Self::S3(conn) => conn.default_value_desc(),
+            Self::Iceberg(conn) => conn.default_value_desc(),
             Self::LoadGenerator(conn) => conn.default_value_desc(),
         }
     }
@@ -797,9 +908,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn timestamp_desc(&self) -> RelationDesc {
         match self {
             Self::Kafka(conn) => conn.timestamp_desc(),
+            Self::Kinesis(conn) => conn.timestamp_desc(),
+            Self::Nats(conn) => conn.timestamp_desc(),
+            Self::Mqtt(conn) => conn.timestamp_desc(),
             Self::Postgres(conn) => conn.timestamp_desc(),
             Self::MySql(conn) => conn.timestamp_desc(),
             Self::SqlServer(conn) => conn.timestamp_desc(),
+            Self::MongoDb(conn) => conn.timestamp_desc(),
+            Self::S3(conn) => conn.timestamp_desc(),
+            Self::Iceberg(conn) => conn.timestamp_desc(),
             Self::LoadGenerator(conn) => conn.timestamp_desc(),
         }
     }
@@ -807,9 +924,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn connection_id(&self) -> Option<CatalogItemId> {
         match self {
             Self::Kafka(conn) => conn.connection_id(),
+            Self::Kinesis(conn) => conn.connection_id(),
+            Self::Nats(conn) => conn.connection_id(),
+            Self::Mqtt(conn) => conn.connection_id(),
             Self::Postgres(conn) => conn.connection_id(),
             Self::MySql(conn) => conn.connection_id(),
             Self::SqlServer(conn) => conn.connection_id(),
+            Self::MongoDb(conn) => conn.connection_id(),
+            Self::S3(conn) => conn.connection_id(),
+            Self::Iceberg(conn) => conn.connection_id(),
             Self::LoadGenerator(conn) => conn.connection_id(),
         }
     }
@@ -817,9 +940,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn supports_read_only(&self) -> bool {
         match self {
             GenericSourceConnection::Kafka(conn) => conn.supports_read_only(),
+            GenericSourceConnection::Kinesis(conn) => conn.supports_read_only(),
+            GenericSourceConnection::Nats(conn) => conn.supports_read_only(),
+            GenericSourceConnection::Mqtt(conn) => conn.supports_read_only(),
             GenericSourceConnection::Postgres(conn) => conn.supports_read_only(),
             GenericSourceConnection::MySql(conn) => conn.supports_read_only(),
             GenericSourceConnection::SqlServer(conn) => conn.supports_read_only(),
+            GenericSourceConnection::MongoDb(conn) => conn.supports_read_only(),
+            GenericSourceConnection::S3(conn) => conn.supports_read_only(),
+            GenericSourceConnection::Iceberg(conn) => conn.supports_read_only(),
             GenericSourceConnection::LoadGenerator(conn) => conn.supports_read_only(),
         }
     }
@@ -827,9 +956,15 @@ impl<C: ConnectionAccess> SourceConnection for GenericSourceConnection<C> {
     fn prefers_single_replica(&self) -> bool {
         match self {
             GenericSourceConnection::Kafka(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::Kinesis(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::Nats(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::Mqtt(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::Postgres(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::MySql(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::SqlServer(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::MongoDb(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::S3(conn) => conn.prefers_single_replica(),
+            GenericSourceConnection::Iceberg(conn) => conn.prefers_single_replica(),
             GenericSourceConnection::LoadGenerator(conn) => conn.prefers_single_replica(),
         }
     }
@@ -841,9 +976,15 @@ impl<C: ConnectionAccess> crate::AlterCompatible for GenericSourceConnection<C>
         }
         let r = match (self, other) {
             (Self::Kafka(conn), Self::Kafka(other)) => conn.alter_compatible(id, other),
+            (Self::Kinesis(conn), Self::Kinesis(other)) => conn.alter_compatible(id, other),
+            (Self::Nats(conn), Self::Nats(other)) => conn.alter_compatible(id, other),
+            (Self::Mqtt(conn), Self::Mqtt(other)) => conn.alter_compatible(id, other),
             (Self::Postgres(conn), Self::Postgres(other)) => conn.alter_compatible(id, other),
             (Self::MySql(conn), Self::MySql(other)) => conn.alter_compatible(id, other),
             (Self::SqlServer(conn), Self::SqlServer(other)) => conn.alter_compatible(id, other),
+            (Self::MongoDb(conn), Self::MongoDb(other)) => conn.alter_compatible(id, other),
+            (Self::S3(conn), Self::S3(other)) => conn.alter_compatible(id, other),
+            (Self::Iceberg(conn), Self::Iceberg(other)) => conn.alter_compatible(id, other),
             (Self::LoadGenerator(conn), Self::LoadGenerator(other)) => {
                 conn.alter_compatible(id, other)
             }
@@ -870,10 +1011,17 @@ pub enum SourceExportDetails {
     /// output to.
     None,
     Kafka(KafkaSourceExportDetails),
+    Kinesis(KinesisSourceExportDetails),
+    Nats(NatsSourceExportDetails),
+    Mqtt(MqttSourceExportDetails),
     Postgres(PostgresSourceExportDetails),
     MySql(MySqlSourceExportDetails),
     SqlServer(SqlServerSourceExportDetails),
+    MongoDb(MongoDbSourceExportDetails),
+    S3(S3SourceExportDetails),
+    Iceberg(IcebergSourceExportDetails),
     LoadGenerator(LoadGeneratorSourceExportDetails),
+    PostgresMessages(PostgresMessagesSourceExportDetails),
 }
 
 impl crate::AlterCompatible for SourceExportDetails {
@@ -884,10 +1032,17 @@ impl crate::AlterCompatible for SourceExportDetails {
         let r = match (self, other) {
             (Self::None, Self::None) => Ok(()),
             (Self::Kafka(s), Self::Kafka(o)) => s.alter_compatible(id, o),
+            (Self::Kinesis(s), Self::Kinesis(o)) => s.alter_compatible(id, o),
+            (Self::Nats(s), Self::Nats(o)) => s.alter_compatible(id, o),
+            (Self::Mqtt(s), Self::Mqtt(o)) => s.alter_compatible(id, o),
             (Self::Postgres(s), Self::Postgres(o)) => s.alter_compatible(id, o),
             (Self::MySql(s), Self::MySql(o)) => s.alter_compatible(id, o),
             (Self::SqlServer(s), Self::SqlServer(o)) => s.alter_compatible(id, o),
+            (Self::MongoDb(s), Self::MongoDb(o)) => s.alter_compatible(id, o),
+            (Self::S3(s), Self::S3(o)) => s.alter_compatible(id, o),
+            (Self::Iceberg(s), Self::Iceberg(o)) => s.alter_compatible(id, o),
             (Self::LoadGenerator(s), Self::LoadGenerator(o)) => s.alter_compatible(id, o),
+            (Self::PostgresMessages(s), Self::PostgresMessages(o)) => s.alter_compatible(id, o),
             _ => Err(AlterError { id }),
         };
 
@@ -903,6 +1058,18 @@ impl crate::AlterCompatible for SourceExportDetails {
     }
 }
 
+/// How a source export reacts to columns being added to its upstream table.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SchemaChangePolicy {
+    /// Upstream columns that the export does not know about are ignored.
+    #[default]
+    None,
+    /// Upstream columns that are added after the export was created are added to the export as
+    /// nullable columns. Until the export has been altered to include them, the source does not
+    /// make progress past the point where they were added.
+    AddColumns,
+}
+
 /// Details necessary to store in the `Details` option of a source export
 /// statement (`CREATE SUBSOURCE` and `CREATE TABLE .. FROM SOURCE` statements),
 /// to generate the appropriate `SourceExportDetails` struct during planning.
@@ -928,10 +1095,24 @@ pub enum SourceExportStatementDetails {
         capture_instance: Arc<str>,
         initial_lsn: mz_sql_server_util::cdc::Lsn,
     },
+    MongoDb {
+        collection: String,
+        initial_cluster_time: mz_mongodb_util::ClusterTime,
+    },
     LoadGenerator {
         output: LoadGeneratorOutput,
     },
     Kafka {},
+    Kinesis {},
+    Nats {},
+    Mqtt {},
+    /// The logical decoding messages of a PostgreSQL source.
+    PostgresMessages {},
+    S3 {},
+    Iceberg {
+        table: String,
+        table_uuid: Uuid,
+    },
 }
 
 impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetails {
@@ -974,6 +1155,17 @@ impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetail
                     },
                 )),
             },
+            SourceExportStatementDetails::MongoDb {
+                collection,
+                initial_cluster_time,
+            } => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::Mongodb(
+                    mongodb::ProtoMongoDbSourceExportStatementDetails {
+                        collection: collection.clone(),
+                        initial_cluster_time: initial_cluster_time.as_u64(),
+                    },
+                )),
+            },
             SourceExportStatementDetails::LoadGenerator { output } => {
                 ProtoSourceExportStatementDetails {
                     kind: Some(proto_source_export_statement_details::Kind::Loadgen(
@@ -988,6 +1180,45 @@ impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetail
                     kafka::ProtoKafkaSourceExportStatementDetails {},
                 )),
             },
+            SourceExportStatementDetails::Kinesis {} => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::Kinesis(
+                    kinesis::ProtoKinesisSourceExportStatementDetails {},
+                )),
+            },
+            SourceExportStatementDetails::Nats {} => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::Nats(
+                    nats::ProtoNatsSourceExportStatementDetails {},
+                )),
+            },
+            SourceExportStatementDetails::Mqtt {} => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::Mqtt(
+                    mqtt::ProtoMqttSourceExportStatementDetails {},
+                )),
+            },
+            SourceExportStatementDetails::PostgresMessages {} => {
+                ProtoSourceExportStatementDetails {
+                    kind: Some(
+                        proto_source_export_statement_details::Kind::PostgresMessages(
+                            postgres::ProtoPostgresMessagesSourceExportStatementDetails {},
+                        ),
+                    ),
+                }
+            }
+            SourceExportStatementDetails::S3 {} => ProtoSourceExportStatementDetails {
+                kind: Some(proto_source_export_statement_details::Kind::S3(
+                    s3::ProtoS3SourceExportStatementDetails {},
+                )),
+            },
+            SourceExportStatementDetails::Iceberg { table, table_uuid } => {
+                ProtoSourceExportStatementDetails {
+                    kind: Some(proto_source_export_statement_details::Kind::Iceberg(
+                        iceberg::ProtoIcebergSourceExportStatementDetails {
+                            table: table.clone(),
+                            table_uuid: table_uuid.to_string(),
+                        },
+                    )),
+                }
+            }
         }
     }
 
@@ -1016,12 +1247,30 @@ impl RustType<ProtoSourceExportStatementDetails> for SourceExportStatementDetail
                 initial_lsn: mz_sql_server_util::cdc::Lsn::try_from(details.initial_lsn.as_slice())
                     .map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))?,
             },
+            Some(Kind::Mongodb(details)) => SourceExportStatementDetails::MongoDb {
+                collection: details.collection,
+                initial_cluster_time: mz_mongodb_util::ClusterTime::from_u64(
+                    details.initial_cluster_time,
+                ),
+            },
             Some(Kind::Loadgen(details)) => SourceExportStatementDetails::LoadGenerator {
                 output: details
                     .output
                     .into_rust_if_some("ProtoLoadGeneratorSourceExportStatementDetails::output")?,
             },
             Some(Kind::Kafka(_details)) => SourceExportStatementDetails::Kafka {},
+            Some(Kind::Kinesis(_details)) => SourceExportStatementDetails::Kinesis {},
+            Some(Kind::Nats(_details)) => SourceExportStatementDetails::Nats {},
+            Some(Kind::Mqtt(_details)) => SourceExportStatementDetails::Mqtt {},
+            Some(Kind::PostgresMessages(_details)) => {
+                SourceExportStatementDetails::PostgresMessages {}
+            }
+            Some(Kind::S3(_details)) => SourceExportStatementDetails::S3 {},
+            Some(Kind::Iceberg(details)) => SourceExportStatementDetails::Iceberg {
+                table: details.table,
+                table_uuid: Uuid::parse_str(&details.table_uuid)
+                    .map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))?,
+            },
             None => {
                 return Err(TryFromProtoError::missing_field(
                     "ProtoSourceExportStatementDetails::kind",
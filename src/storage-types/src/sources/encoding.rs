@@ -11,7 +11,7 @@
 
 use anyhow::Context;
 use itertools::Itertools;
-use mz_interchange::{avro, protobuf};
+use mz_interchange::{avro, json_schema, protobuf};
 use mz_repr::{Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use serde::{Deserialize, Serialize};
 
@@ -99,6 +99,9 @@ pub enum DataEncoding<C: ConnectionAccess = InlinedConnection> {
     Regex(RegexEncoding),
     Bytes,
     Json,
+    /// A JSON array per message, each element of which is decoded into its own row.
+    JsonArray,
+    JsonSchema(JsonSchemaEncoding),
     Text,
 }
 
@@ -113,6 +116,8 @@ impl<R: ConnectionResolver> IntoInlineConnection<DataEncoding, R>
             Self::Regex(conn) => DataEncoding::Regex(conn),
             Self::Bytes => DataEncoding::Bytes,
             Self::Json => DataEncoding::Json,
+            Self::JsonArray => DataEncoding::JsonArray,
+            Self::JsonSchema(conn) => DataEncoding::JsonSchema(conn),
             Self::Text => DataEncoding::Text,
         }
     }
@@ -135,7 +140,7 @@ impl<C: ConnectionAccess> DataEncoding<C> {
             Self::Csv(_) => "csv",
             Self::Regex(_) => "regex",
             Self::Bytes => "bytes",
-            Self::Json => "json",
+            Self::Json | Self::JsonArray | Self::JsonSchema(_) => "json",
             Self::Text => "text",
         }
     }
@@ -151,26 +156,44 @@ impl<C: ConnectionAccess> DataEncoding<C> {
             Self::Json => RelationDesc::builder()
                 .with_column("data", SqlScalarType::Jsonb.nullable(false))
                 .finish(),
-            Self::Avro(AvroEncoding {
+            Self::JsonArray => RelationDesc::builder()
+                .with_column("data", SqlScalarType::Jsonb.nullable(false))
+                .with_column("element_index", SqlScalarType::Int64.nullable(false))
+                .finish(),
+            Self::JsonSchema(JsonSchemaEncoding {
                 schema,
-                reference_schemas,
-                ..
-            }) => {
+                confluent_wire_format: _,
+            }) => json_schema::DecodedSchema::parse(schema)
+                .context("validating JSON Schema")?
+                .desc(),
+            Self::Avro(
+                encoding @ AvroEncoding {
+                    schema,
+                    reference_schemas,
+                    ..
+                },
+            ) => {
                 let parsed_schema = avro::parse_schema(schema, reference_schemas)
                     .context("validating avro schema")?;
-                avro::schema_to_relationdesc(parsed_schema).context("validating avro schema")?
+                avro::schema_to_relationdesc(parsed_schema, &encoding.decode_options())
+                    .context("validating avro schema")?
             }
             Self::Protobuf(ProtobufEncoding {
                 descriptors,
                 message_name,
                 confluent_wire_format: _,
-            }) => protobuf::DecodedDescriptors::from_bytes(descriptors, message_name.to_owned())?
-                .columns()
-                .iter()
-                .fold(RelationDesc::builder(), |desc, (name, ty)| {
-                    desc.with_column(name, ty.clone())
-                })
-                .finish(),
+                recursion_limit,
+            }) => protobuf::DecodedDescriptors::from_bytes(
+                descriptors,
+                message_name.to_owned(),
+                *recursion_limit,
+            )?
+            .columns()
+            .iter()
+            .fold(RelationDesc::builder(), |desc, (name, ty)| {
+                desc.with_column(name, ty.clone())
+            })
+            .finish(),
             Self::Regex(RegexEncoding { regex }) => regex
                 .capture_names()
                 .enumerate()
@@ -215,6 +238,8 @@ impl<C: ConnectionAccess> DataEncoding<C> {
         match self {
             Self::Bytes => "Bytes",
             Self::Json => "Json",
+            Self::JsonArray => "JsonArray",
+            Self::JsonSchema(_) => "JsonSchema",
             Self::Avro(_) => "Avro",
             Self::Protobuf(_) => "Protobuf",
             Self::Regex { .. } => "Regex",
@@ -262,6 +287,24 @@ pub struct AvroEncoding<C: ConnectionAccess = InlinedConnection> {
     /// How schema identifiers are framed in the Kafka payload, and the
     /// registry (if any) used to resolve writer schemas.
     pub wire_format: WireFormat<C>,
+    /// The depth past which records, arrays and maps are decoded as `jsonb`.
+    /// Recursive types are only supported when this is set.
+    #[serde(default)]
+    pub max_depth: Option<u32>,
+    /// Whether unions are decoded as a record of their variants rather than
+    /// flattened into one column per variant.
+    #[serde(default)]
+    pub union_as_record: bool,
+}
+
+impl<C: ConnectionAccess> AvroEncoding<C> {
+    /// The options with which values of this encoding are decoded.
+    pub fn decode_options(&self) -> avro::AvroDecodeOptions {
+        avro::AvroDecodeOptions {
+            max_depth: self.max_depth,
+            union_as_record: self.union_as_record,
+        }
+    }
 }
 
 impl<R: ConnectionResolver> IntoInlineConnection<AvroEncoding, R>
@@ -272,11 +315,15 @@ impl<R: ConnectionResolver> IntoInlineConnection<AvroEncoding, R>
             schema,
             reference_schemas,
             wire_format,
+            max_depth,
+            union_as_record,
         } = self;
         AvroEncoding {
             schema,
             reference_schemas,
             wire_format: wire_format.into_inline_connection(r),
+            max_depth,
+            union_as_record,
         }
     }
 }
@@ -291,6 +338,8 @@ impl<C: ConnectionAccess> AlterCompatible for AvroEncoding<C> {
             schema,
             reference_schemas,
             wire_format,
+            max_depth,
+            union_as_record,
         } = self;
 
         let compatibility_checks = [
@@ -303,6 +352,8 @@ impl<C: ConnectionAccess> AlterCompatible for AvroEncoding<C> {
                 wire_format.alter_compatible(id, &other.wire_format).is_ok(),
                 "wire_format",
             ),
+            (max_depth == &other.max_depth, "max_depth"),
+            (union_as_record == &other.union_as_record, "union_as_record"),
         ];
 
         for (compatible, field) in compatibility_checks {
@@ -327,6 +378,17 @@ pub struct ProtobufEncoding {
     pub descriptors: Vec<u8>,
     pub message_name: String,
     pub confluent_wire_format: bool,
+    /// The number of times a recursive message may be nested within itself
+    /// before it is decoded as `jsonb`.
+    #[serde(default)]
+    pub recursion_limit: u32,
+}
+
+/// Encoding in JSON format, described by a JSON Schema.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JsonSchemaEncoding {
+    pub schema: String,
+    pub confluent_wire_format: bool,
 }
 
 /// Arguments necessary to define how to decode from CSV format
@@ -0,0 +1,181 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to Iceberg sources
+
+use std::sync::LazyLock;
+
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, SqlScalarType};
+use mz_timely_util::order::Partitioned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::controller::AlterError;
+use crate::sources::{MzOffset, SourceConnection};
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.iceberg.rs"
+));
+
+pub static ICEBERG_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("table_uuid_lower", SqlScalarType::Uuid.nullable(false))
+        .with_column("table_uuid_upper", SqlScalarType::Uuid.nullable(false))
+        .with_column("offset", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// The timestamp of an Iceberg source.
+///
+/// Every table is its own partition, identified by the UUID that Iceberg assigns to it when it is
+/// created. The offset of a partition is the sequence number of the snapshot of the table that
+/// will be ingested next, i.e. one more than the sequence number of the last ingested snapshot.
+/// Ranges of identifiers that no ingested table falls into are kept at offset `0`.
+///
+/// This is the same type as [`S3Timestamp`](crate::sources::s3::S3Timestamp), and shares its
+/// encoding.
+pub type IcebergTimestamp = Partitioned<Uuid, MzOffset>;
+
+/// Details about how to create a Materialize Source that ingests the tables of an Iceberg
+/// namespace.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the Iceberg catalog connection this source is using.
+    pub catalog_connection_id: CatalogItemId,
+    /// Configuration for connecting to the Iceberg catalog.
+    pub catalog_connection: C::IcebergCatalog,
+    /// The namespace whose tables the source ingests.
+    pub namespace: String,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<IcebergSourceConnection, R>
+    for IcebergSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> IcebergSourceConnection {
+        let IcebergSourceConnection {
+            catalog_connection_id,
+            catalog_connection,
+            namespace,
+        } = self;
+
+        IcebergSourceConnection {
+            catalog_connection_id,
+            catalog_connection: r
+                .resolve_connection(catalog_connection)
+                .unwrap_iceberg_catalog(),
+            namespace,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for IcebergSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "iceberg"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        None
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        RelationDesc::empty()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        // The Iceberg source only outputs data to its tables. The catalog object
+        // representing the source itself is just an empty relation with no columns
+        RelationDesc::empty()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        ICEBERG_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.catalog_connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        false
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for IcebergSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let IcebergSourceConnection {
+            catalog_connection_id,
+            catalog_connection,
+            namespace,
+        } = self;
+
+        let compatibility_checks = [
+            (
+                catalog_connection_id == &other.catalog_connection_id,
+                "catalog_connection_id",
+            ),
+            (
+                catalog_connection
+                    .alter_compatible(id, &other.catalog_connection)
+                    .is_ok(),
+                "catalog_connection",
+            ),
+            (namespace == &other.namespace, "namespace"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "IcebergSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Specifies the details of an Iceberg source export.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IcebergSourceExportDetails {
+    /// The name of the table the export ingests, within the namespace of the source.
+    pub table: String,
+    /// The UUID of the table as of when the export was created. A table that is dropped and
+    /// recreated under the same name has a different UUID.
+    pub table_uuid: Uuid,
+}
+
+impl AlterCompatible for IcebergSourceExportDetails {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        // compatibility checks are performed against the upstream table in the source render
+        // operators instead
+        let Self {
+            table: _,
+            table_uuid: _,
+        } = self;
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@
 //! Types related kafka sources
 
 use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use std::fmt;
 use std::sync::LazyLock;
 use std::time::Duration;
@@ -17,7 +18,6 @@ use std::time::Duration;
 use dec::OrderedDecimal;
 use mz_dyncfg::ConfigSet;
 use mz_kafka_util::client::MzClientContext;
-use mz_ore::collections::CollectionExt;
 use mz_ore::future::InTask;
 use mz_repr::adt::numeric::Numeric;
 use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
@@ -41,13 +41,188 @@ include!(concat!(
 
 /// A "moment in time" perceivable in Kafka––for each partition, the greatest
 /// visible offset.
-pub type KafkaTimestamp = Partitioned<RangeBound<i32>, MzOffset>;
+pub type KafkaTimestamp = Partitioned<RangeBound<KafkaPartition>, MzOffset>;
+
+/// A partition of a topic read by a Kafka source.
+///
+/// Sources over a single topic always use a `topic` of zero, which makes their timestamps
+/// identical to those of sources that tracked progress by partition ID alone. Sources over a
+/// [`KafkaTopicSet`] identify each topic by its [`KafkaPartition::topic_key`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct KafkaPartition {
+    pub topic: u32,
+    pub partition: i32,
+}
+
+impl KafkaPartition {
+    /// Returns the key that identifies `topic` in the timestamps of sources over a
+    /// [`KafkaTopicSet`].
+    ///
+    /// The key is derived from the topic name alone so that it is stable across restarts and
+    /// never zero, which is reserved for sources over a single topic.
+    pub fn topic_key(topic: &str) -> u32 {
+        let hash = seahash::hash(topic.as_bytes());
+        match u32::try_from(hash >> 32).expect("shifted hash fits in u32") {
+            0 => 1,
+            key => key,
+        }
+    }
+
+    /// Returns the partition that immediately follows this one in the same topic.
+    fn successor(&self) -> Option<Self> {
+        Some(KafkaPartition {
+            topic: self.topic,
+            partition: self.partition.checked_add(1)?,
+        })
+    }
+
+    /// Returns the timestamps, at offset zero, that cover every partition not in `known`.
+    ///
+    /// Partitions that are discovered later, such as new partitions of a topic or partitions of
+    /// newly created topics in a [`KafkaTopicSet::Pattern`], fall into one of these ranges.
+    pub fn unknown_partitions<'a>(
+        known: impl IntoIterator<Item = &'a KafkaPartition>,
+    ) -> Vec<KafkaTimestamp> {
+        // Partition IDs start at zero, so nothing can precede the first partition of a source over
+        // a single topic.
+        let first = KafkaPartition {
+            topic: 0,
+            partition: 0,
+        };
+        let mut timestamps = vec![];
+        let mut prev: Option<KafkaPartition> = None;
+        for pid in known {
+            let gap_lower = match prev {
+                Some(prev) if prev.successor() == Some(*pid) => None,
+                Some(prev) => Some(RangeBound::after(prev)),
+                None if *pid == first => None,
+                None => Some(RangeBound::NegInfinity),
+            };
+            if let Some(lower) = gap_lower {
+                timestamps.push(Partitioned::new_range(
+                    lower,
+                    RangeBound::before(*pid),
+                    MzOffset::from(0),
+                ));
+            }
+            prev = Some(*pid);
+        }
+        let lower = prev
+            .map(RangeBound::after)
+            .unwrap_or(RangeBound::NegInfinity);
+        timestamps.push(Partitioned::new_range(
+            lower,
+            RangeBound::PosInfinity,
+            MzOffset::from(0),
+        ));
+        timestamps
+    }
+}
+
+impl From<i32> for KafkaPartition {
+    fn from(partition: i32) -> Self {
+        KafkaPartition {
+            topic: 0,
+            partition,
+        }
+    }
+}
+
+impl fmt::Display for KafkaPartition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.topic {
+            0 => write!(f, "{}", self.partition),
+            topic => write!(f, "{topic}:{}", self.partition),
+        }
+    }
+}
+
+/// The topics read by a Kafka source over more than one topic.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KafkaTopicSet {
+    /// A fixed list of topics.
+    List(Vec<String>),
+    /// Every topic whose entire name matches a regular expression, including topics that are
+    /// created after the source.
+    Pattern(String),
+}
+
+impl KafkaTopicSet {
+    /// Returns a predicate that reports whether a topic belongs to this set.
+    pub fn matcher(&self) -> Result<impl Fn(&str) -> bool + use<>, regex::Error> {
+        let (topics, pattern) = match self {
+            KafkaTopicSet::List(topics) => (topics.clone(), None),
+            KafkaTopicSet::Pattern(pattern) => (
+                vec![],
+                Some(regex::Regex::new(&format!("^(?:{pattern})$"))?),
+            ),
+        };
+        Ok(move |topic: &str| match &pattern {
+            Some(pattern) => pattern.is_match(topic),
+            None => topics.iter().any(|t| t == topic),
+        })
+    }
+}
+
+/// The topics of a source over a [`KafkaTopicSet`], by their [`KafkaPartition::topic_key`].
+///
+/// Topic keys are derived from topic names, so two topics can share a key, in which case the
+/// progress of one could not be told apart from that of the other. Sources record every topic they
+/// discover and refuse to read a topic whose key already identifies another one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KafkaTopicKeys(BTreeMap<u32, String>);
+
+impl KafkaTopicKeys {
+    /// Records `topic` and returns its key, unless the key already identifies another topic.
+    pub fn insert(&mut self, topic: &str) -> Result<u32, KafkaTopicKeyCollision> {
+        let key = KafkaPartition::topic_key(topic);
+        match self.0.entry(key) {
+            Entry::Occupied(entry) if entry.get() != topic => Err(KafkaTopicKeyCollision {
+                existing: entry.get().clone(),
+                topic: topic.to_string(),
+            }),
+            Entry::Occupied(_) => Ok(key),
+            Entry::Vacant(entry) => {
+                entry.insert(topic.to_string());
+                Ok(key)
+            }
+        }
+    }
+}
+
+/// Two topics of a source over a [`KafkaTopicSet`] that share a [`KafkaPartition::topic_key`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "topics {existing} and {topic} cannot be read by the same source because their progress \
+     cannot be told apart; consider excluding one of them"
+)]
+pub struct KafkaTopicKeyCollision {
+    /// The topic that was discovered first.
+    pub existing: String,
+    /// The topic whose key identifies `existing`.
+    pub topic: String,
+}
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct KafkaSourceConnection<C: ConnectionAccess = InlinedConnection> {
     pub connection: C::Kafka,
     pub connection_id: CatalogItemId,
+    /// The topic the source reads from. For sources over a `topic_set`, this is the topic or
+    /// pattern the source is referenced by and is not read on its own.
     pub topic: String,
+    /// The topics the source reads from, if it reads from more than one.
+    pub topic_set: Option<KafkaTopicSet>,
     // Map from partition -> starting offset
     pub start_offsets: BTreeMap<i32, i64>,
     pub group_id_prefix: Option<String>,
@@ -66,6 +241,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
             connection,
             connection_id,
             topic,
+            topic_set,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -75,6 +251,7 @@ impl<R: ConnectionResolver> IntoInlineConnection<KafkaSourceConnection, R>
             connection: r.resolve_connection(connection).unwrap_kafka(),
             connection_id,
             topic,
+            topic_set,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -149,37 +326,50 @@ impl KafkaSourceConnection {
             .kafka_timeout_config
             .fetch_metadata_timeout;
 
+        let matcher = self
+            .topic_set
+            .as_ref()
+            .map(|set| set.matcher())
+            .transpose()?;
+
         mz_ore::task::spawn_blocking(|| "kafka_fetch_write_frontier_fetch_metadata", {
             move || {
-                let meta = client
-                    .inner()
-                    .fetch_metadata(Some(&self.topic), metadata_timeout)?;
-
-                let pids = meta
-                    .topics()
-                    .into_element()
-                    .partitions()
-                    .iter()
-                    .map(|p| p.id());
+                let meta = match &matcher {
+                    Some(_) => client.inner().fetch_metadata(None, metadata_timeout)?,
+                    None => client
+                        .inner()
+                        .fetch_metadata(Some(&self.topic), metadata_timeout)?,
+                };
 
                 let mut current_upper = Antichain::new();
-                let mut max_pid = 0;
-                for pid in pids {
-                    let (_, high) =
-                        client
-                            .inner()
-                            .fetch_watermarks(&self.topic, pid, metadata_timeout)?;
-                    max_pid = std::cmp::max(pid, max_pid);
-                    current_upper.insert(Partitioned::new_singleton(
-                        RangeBound::Elem(pid, BoundKind::At),
-                        MzOffset::from(u64::try_from(high).unwrap()),
-                    ));
+                let mut known = vec![];
+                let mut topic_keys = KafkaTopicKeys::default();
+                for topic in meta.topics() {
+                    let topic_key = match &matcher {
+                        Some(matcher) if matcher(topic.name()) => {
+                            topic_keys.insert(topic.name())?
+                        }
+                        Some(_) => continue,
+                        None => 0,
+                    };
+                    for pid in topic.partitions().iter().map(|p| p.id()) {
+                        let (_, high) =
+                            client
+                                .inner()
+                                .fetch_watermarks(topic.name(), pid, metadata_timeout)?;
+                        let partition = KafkaPartition {
+                            topic: topic_key,
+                            partition: pid,
+                        };
+                        known.push(partition);
+                        current_upper.insert(Partitioned::new_singleton(
+                            RangeBound::exact(partition),
+                            MzOffset::from(u64::try_from(high).unwrap()),
+                        ));
+                    }
                 }
-                current_upper.insert(Partitioned::new_range(
-                    RangeBound::Elem(max_pid, BoundKind::After),
-                    RangeBound::PosInfinity,
-                    MzOffset::from(0),
-                ));
+                known.sort();
+                current_upper.extend(KafkaPartition::unknown_partitions(&known));
 
                 Ok(current_upper)
             }
@@ -236,6 +426,7 @@ impl<C: ConnectionAccess> crate::AlterCompatible for KafkaSourceConnection<C> {
             connection,
             connection_id,
             topic,
+            topic_set,
             start_offsets,
             group_id_prefix,
             metadata_columns,
@@ -249,6 +440,7 @@ impl<C: ConnectionAccess> crate::AlterCompatible for KafkaSourceConnection<C> {
             ),
             (connection_id == &other.connection_id, "connection_id"),
             (topic == &other.topic, "topic"),
+            (topic_set == &other.topic_set, "topic_set"),
             (start_offsets == &other.start_offsets, "start_offsets"),
             (group_id_prefix == &other.group_id_prefix, "group_id_prefix"),
             (
@@ -285,6 +477,7 @@ pub fn kafka_metadata_columns_desc(
         .iter()
         .map(|(name, kind)| {
             let typ = match kind {
+                KafkaMetadataKind::Topic => SqlScalarType::String.nullable(false),
                 KafkaMetadataKind::Partition => SqlScalarType::Int32.nullable(false),
                 KafkaMetadataKind::Offset => SqlScalarType::UInt64.nullable(false),
                 KafkaMetadataKind::Timestamp => {
@@ -456,7 +649,13 @@ impl SourceTimestamp for KafkaTimestamp {
         let mut row = Row::with_capacity(2);
         let mut packer = row.packer();
 
-        let to_numeric = |p: i32| Datum::from(OrderedDecimal(Numeric::from(p)));
+        // Partitions are encoded as `topic * 2^32 + partition`, which is just the partition ID
+        // for sources over a single topic.
+        let to_numeric = |p: KafkaPartition| {
+            let partition = u32::try_from(p.partition).expect("partition IDs are non-negative");
+            let encoded = (u64::from(p.topic) << 32) | u64::from(partition);
+            Datum::from(OrderedDecimal(Numeric::from(encoded)))
+        };
 
         let (lower, lower_inclusive) = match self.interval().lower {
             RangeBound::NegInfinity => (Datum::Null, false),
@@ -493,14 +692,17 @@ impl SourceTimestamp for KafkaTimestamp {
                 range.canonicalize().expect("ranges must be valid");
                 let range = range.inner.expect("empty range");
 
-                let lower = range.lower.bound.map(|row| {
-                    i32::try_from(row.unwrap_numeric().0)
-                        .expect("only i32 values converted to ranges")
-                });
-                let upper = range.upper.bound.map(|row| {
-                    i32::try_from(row.unwrap_numeric().0)
-                        .expect("only i32 values converted to ranges")
-                });
+                let from_numeric = |datum: Datum| {
+                    let encoded = u64::try_from(datum.unwrap_numeric().0)
+                        .expect("only u64 values converted to ranges");
+                    KafkaPartition {
+                        topic: u32::try_from(encoded >> 32).expect("shifted value fits in u32"),
+                        partition: i32::try_from(encoded & u64::from(u32::MAX))
+                            .expect("only i32 partition IDs converted to ranges"),
+                    }
+                };
+                let lower = range.lower.bound.map(from_numeric);
+                let upper = range.upper.bound.map(from_numeric);
 
                 match (range.lower.inclusive, range.upper.inclusive) {
                     (true, true) => {
@@ -532,9 +734,116 @@ impl SourceTimestamp for KafkaTimestamp {
 /// Which piece of metadata a column corresponds to
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum KafkaMetadataKind {
+    Topic,
     Partition,
     Offset,
     Timestamp,
     Headers,
     Header { key: String, use_bytes: bool },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decNumberFromUInt64` on OS `linux`
+    fn test_timestamp_roundtrip() {
+        let single = KafkaPartition::from(3);
+        let multi = KafkaPartition {
+            topic: KafkaPartition::topic_key("events-eu"),
+            partition: 7,
+        };
+        let timestamps = [
+            Partitioned::new_singleton(RangeBound::exact(single), MzOffset::from(10)),
+            Partitioned::new_singleton(RangeBound::exact(multi), MzOffset::from(10)),
+            Partitioned::new_range(
+                RangeBound::after(single),
+                RangeBound::before(multi),
+                MzOffset::from(0),
+            ),
+            Partitioned::new_range(
+                RangeBound::after(multi),
+                RangeBound::PosInfinity,
+                MzOffset::from(0),
+            ),
+        ];
+        for ts in timestamps {
+            assert_eq!(KafkaTimestamp::decode_row(&ts.encode_row()), ts);
+        }
+
+        // Sources over a single topic encode partitions exactly as they did before sources over
+        // multiple topics existed.
+        let legacy = Partitioned::new_singleton(RangeBound::exact(single), MzOffset::from(10));
+        let row = legacy.encode_row();
+        let Datum::Range(range) = row.iter().next().unwrap() else {
+            panic!("expected range");
+        };
+        let lower = range.inner.unwrap().lower.bound.unwrap().datum();
+        assert_eq!(lower.unwrap_numeric().0, Numeric::from(3));
+    }
+
+    #[mz_ore::test]
+    fn test_topic_key_collision() {
+        let mut keys = KafkaTopicKeys::default();
+        let key = keys.insert("events-eu").unwrap();
+        assert_eq!(key, KafkaPartition::topic_key("events-eu"));
+        assert_eq!(keys.insert("events-eu"), Ok(key));
+
+        // Pretend that another topic was discovered first and has the same key.
+        let mut keys = KafkaTopicKeys(BTreeMap::from([(key, "events-us".to_string())]));
+        assert_eq!(
+            keys.insert("events-eu"),
+            Err(KafkaTopicKeyCollision {
+                existing: "events-us".into(),
+                topic: "events-eu".into(),
+            })
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_unknown_partitions() {
+        let at = |topic, partition| KafkaPartition { topic, partition };
+
+        // A single topic with contiguous partitions only leaves the space after the last one.
+        assert_eq!(
+            KafkaPartition::unknown_partitions(&[at(0, 0), at(0, 1), at(0, 2)]),
+            vec![Partitioned::new_range(
+                RangeBound::after(at(0, 2)),
+                RangeBound::PosInfinity,
+                MzOffset::from(0),
+            )]
+        );
+
+        // Multiple topics leave room before, between and after them.
+        assert_eq!(
+            KafkaPartition::unknown_partitions(&[at(5, 0), at(5, 1), at(9, 0)]),
+            vec![
+                Partitioned::new_range(
+                    RangeBound::NegInfinity,
+                    RangeBound::before(at(5, 0)),
+                    MzOffset::from(0),
+                ),
+                Partitioned::new_range(
+                    RangeBound::after(at(5, 1)),
+                    RangeBound::before(at(9, 0)),
+                    MzOffset::from(0),
+                ),
+                Partitioned::new_range(
+                    RangeBound::after(at(9, 0)),
+                    RangeBound::PosInfinity,
+                    MzOffset::from(0),
+                ),
+            ]
+        );
+
+        assert_eq!(
+            KafkaPartition::unknown_partitions(std::iter::empty()),
+            vec![Partitioned::new_range(
+                RangeBound::NegInfinity,
+                RangeBound::PosInfinity,
+                MzOffset::from(0),
+            )]
+        );
+    }
+}
@@ -0,0 +1,483 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to Kinesis sources
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
+use mz_timely_util::order::Partitioned;
+use serde::{Deserialize, Serialize};
+use timely::order::{PartialOrder, TotalOrder};
+use timely::progress::timestamp::Refines;
+use timely::progress::{PathSummary, Timestamp};
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::controller::AlterError;
+use crate::sources::{SourceConnection, SourceTimestamp};
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.kinesis.rs"
+));
+
+pub static KINESIS_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("shard_lower", SqlScalarType::UInt64.nullable(false))
+        .with_column("shard_upper", SqlScalarType::UInt64.nullable(false))
+        .with_column("sequence_number", SqlScalarType::String.nullable(true))
+        .finish()
+});
+
+/// The sequence number of a record in a Kinesis shard.
+///
+/// Kinesis assigns every record a sequence number that is unique within its shard and increases
+/// over time. Sequence numbers are exposed as decimal strings, which are currently 56 digits
+/// long. They are stored here as a 256-bit unsigned integer, in big-endian order of its 64-bit
+/// words so that the derived impls of [`PartialOrd`] and [`Ord`] order them numerically.
+#[derive(
+    Default,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize
+)]
+pub struct SequenceNumber([u64; 4]);
+
+impl SequenceNumber {
+    /// The largest representable sequence number.
+    pub const MAX: SequenceNumber = SequenceNumber([u64::MAX; 4]);
+
+    /// Returns the sequence number that immediately follows `self`, or `None` if `self` is
+    /// [`SequenceNumber::MAX`].
+    ///
+    /// The returned sequence number may not exist upstream, but it's guaranteed to sort greater
+    /// than `self`.
+    pub fn checked_increment(self) -> Option<SequenceNumber> {
+        let mut words = self.0;
+        for word in words.iter_mut().rev() {
+            let (value, overflow) = word.overflowing_add(1);
+            *word = value;
+            if !overflow {
+                return Some(SequenceNumber(words));
+            }
+        }
+        None
+    }
+
+    /// Returns the sequence number that immediately precedes `self`, or `None` if `self` is
+    /// zero.
+    pub fn checked_decrement(self) -> Option<SequenceNumber> {
+        let mut words = self.0;
+        for word in words.iter_mut().rev() {
+            let (value, overflow) = word.overflowing_sub(1);
+            *word = value;
+            if !overflow {
+                return Some(SequenceNumber(words));
+            }
+        }
+        None
+    }
+
+    /// Multiplies `self` by `factor` and adds `addend`, or returns `None` on overflow.
+    fn checked_mul_add(self, factor: u64, addend: u64) -> Option<SequenceNumber> {
+        let mut words = self.0;
+        let mut carry = u128::from(addend);
+        for word in words.iter_mut().rev() {
+            let value = u128::from(*word) * u128::from(factor) + carry;
+            *word = u64::try_from(value & u128::from(u64::MAX)).expect("masked the high bits");
+            carry = value >> 64;
+        }
+        (carry == 0).then_some(SequenceNumber(words))
+    }
+
+    /// Divides `self` by `divisor` in place, and returns the remainder.
+    fn div_rem(&mut self, divisor: u64) -> u64 {
+        let mut rem = 0u128;
+        for word in self.0.iter_mut() {
+            let value = (rem << 64) | u128::from(*word);
+            *word = u64::try_from(value / u128::from(divisor)).expect("remainder below divisor");
+            rem = value % u128::from(divisor);
+        }
+        u64::try_from(rem).expect("remainder below divisor")
+    }
+}
+
+/// An error parsing a [`SequenceNumber`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSequenceNumberError(String);
+
+impl fmt::Display for ParseSequenceNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Kinesis sequence number: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSequenceNumberError {}
+
+impl FromStr for SequenceNumber {
+    type Err = ParseSequenceNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseSequenceNumberError(s.into()));
+        }
+        s.bytes().try_fold(SequenceNumber::default(), |acc, byte| {
+            let digit = match byte {
+                b'0'..=b'9' => u64::from(byte - b'0'),
+                _ => return Err(ParseSequenceNumberError(s.into())),
+            };
+            acc.checked_mul_add(10, digit)
+                .ok_or_else(|| ParseSequenceNumberError(s.into()))
+        })
+    }
+}
+
+impl fmt::Display for SequenceNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Render the number in chunks of 19 decimal digits, the most that fit in a `u64`.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut value = *self;
+        let mut chunks = vec![];
+        loop {
+            chunks.push(value.div_rem(CHUNK));
+            if value == SequenceNumber::default() {
+                break;
+            }
+        }
+        let mut chunks = chunks.into_iter().rev();
+        write!(f, "{}", chunks.next().expect("at least one chunk"))?;
+        for chunk in chunks {
+            write!(f, "{chunk:019}")?;
+        }
+        Ok(())
+    }
+}
+
+impl columnation::Columnation for SequenceNumber {
+    type InnerRegion = columnation::CopyRegion<SequenceNumber>;
+}
+
+impl Timestamp for SequenceNumber {
+    // No need to describe complex summaries.
+    type Summary = ();
+
+    fn minimum() -> Self {
+        SequenceNumber::default()
+    }
+}
+
+impl PathSummary<SequenceNumber> for () {
+    fn results_in(&self, src: &SequenceNumber) -> Option<SequenceNumber> {
+        Some(*src)
+    }
+
+    fn followed_by(&self, _other: &Self) -> Option<Self> {
+        Some(())
+    }
+}
+
+impl Refines<()> for SequenceNumber {
+    fn to_inner(_other: ()) -> Self {
+        Self::minimum()
+    }
+    fn to_outer(self) -> () {}
+
+    fn summarize(_path: <Self as Timestamp>::Summary) -> () {}
+}
+
+impl PartialOrder for SequenceNumber {
+    fn less_equal(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    fn less_than(&self, other: &Self) -> bool {
+        self < other
+    }
+}
+impl TotalOrder for SequenceNumber {}
+
+/// The timestamp of a Kinesis source.
+///
+/// Every shard is its own partition, identified by the index in its shard ID, e.g. `7` for
+/// `shardId-000000000007`. The sequence number of a partition is the sequence number from which
+/// the shard will be read next, i.e. one more than the sequence number of the last ingested
+/// record. Ranges of indexes that no shard falls into, and shards that haven't been read from
+/// yet, are kept at sequence number `0`. Shards that have been closed by a resharding and read
+/// to their end are omitted from the frontier.
+pub type KinesisTimestamp = Partitioned<u64, SequenceNumber>;
+
+impl SourceTimestamp for KinesisTimestamp {
+    fn encode_row(&self) -> Row {
+        let sequence_number = self.timestamp().to_string();
+        Row::pack(&[
+            Datum::UInt64(self.interval().lower),
+            Datum::UInt64(self.interval().upper),
+            Datum::String(&sequence_number),
+        ])
+    }
+
+    fn decode_row(row: &Row) -> Self {
+        let mut datums = row.iter();
+        match (datums.next(), datums.next(), datums.next(), datums.next()) {
+            (
+                Some(Datum::UInt64(lower)),
+                Some(Datum::UInt64(upper)),
+                Some(Datum::String(sequence_number)),
+                None,
+            ) => {
+                let sequence_number = sequence_number
+                    .parse()
+                    .unwrap_or_else(|e| panic!("invalid row {row:?}: {e}"));
+                Partitioned::new_range(lower, upper, sequence_number)
+            }
+            _ => panic!("invalid row {row:?}"),
+        }
+    }
+}
+
+/// Details about how to create a Materialize Source that reads from a Kinesis data stream.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KinesisSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the AWS connection this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to Kinesis.
+    pub connection: C::Aws,
+    /// The name of the stream the source reads from.
+    pub stream: String,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<KinesisSourceConnection, R>
+    for KinesisSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> KinesisSourceConnection {
+        let KinesisSourceConnection {
+            connection_id,
+            connection,
+            stream,
+        } = self;
+
+        KinesisSourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_aws(),
+            stream,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for KinesisSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "kinesis"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        Some(self.stream.as_str())
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        // Every record has a partition key.
+        RelationDesc::builder()
+            .with_column("key", SqlScalarType::Bytes.nullable(false))
+            .finish()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        RelationDesc::builder()
+            .with_column("value", SqlScalarType::Bytes.nullable(true))
+            .finish()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        KINESIS_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        true
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        false
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for KinesisSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let KinesisSourceConnection {
+            connection_id,
+            connection,
+            stream,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (stream == &other.stream, "stream"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "KinesisSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Which piece of metadata a column corresponds to
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KinesisMetadataKind {
+    /// The ID of the shard the record was read from.
+    Partition,
+    /// The sequence number of the record.
+    Offset,
+    /// The approximate time at which the record was added to the stream.
+    Timestamp,
+}
+
+/// Return the column types used to describe the metadata columns of a kinesis source export.
+pub fn kinesis_metadata_columns_desc(
+    metadata_columns: &Vec<(String, KinesisMetadataKind)>,
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                KinesisMetadataKind::Partition => SqlScalarType::String.nullable(false),
+                KinesisMetadataKind::Offset => SqlScalarType::String.nullable(false),
+                KinesisMetadataKind::Timestamp => {
+                    SqlScalarType::Timestamp { precision: None }.nullable(false)
+                }
+            };
+            (&**name, typ)
+        })
+        .collect()
+}
+
+/// The details of a source export from a kinesis source.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KinesisSourceExportDetails {
+    pub metadata_columns: Vec<(String, KinesisMetadataKind)>,
+}
+
+impl AlterCompatible for KinesisSourceExportDetails {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        let Self { metadata_columns } = self;
+        let compatibility_checks = [(
+            metadata_columns == &other.metadata_columns,
+            "metadata_columns",
+        )];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "KinesisSourceExportDetails incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_sequence_number_roundtrip() {
+        let values = [
+            "0",
+            "1",
+            "18446744073709551615",
+            "18446744073709551616",
+            "49590338271490256608559692538361571095921575989136588898",
+        ];
+        for value in values {
+            let seq: SequenceNumber = value.parse().unwrap();
+            assert_eq!(seq.to_string(), value);
+        }
+        assert_eq!(
+            SequenceNumber::MAX.to_string().parse::<SequenceNumber>(),
+            Ok(SequenceNumber::MAX)
+        );
+
+        assert!("".parse::<SequenceNumber>().is_err());
+        assert!("12a".parse::<SequenceNumber>().is_err());
+        assert!("-1".parse::<SequenceNumber>().is_err());
+        let too_large = format!("{}0", SequenceNumber::MAX);
+        assert!(too_large.parse::<SequenceNumber>().is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_sequence_number_order() {
+        let a: SequenceNumber = "49590338271490256608559692538361571095921575989136588898"
+            .parse()
+            .unwrap();
+        let b: SequenceNumber = "49590338271490256608559692540925702759324208523137515618"
+            .parse()
+            .unwrap();
+        assert!(a < b);
+        assert_eq!(a.checked_increment().unwrap().checked_decrement(), Some(a));
+
+        let word: SequenceNumber = "18446744073709551615".parse().unwrap();
+        let next: SequenceNumber = "18446744073709551616".parse().unwrap();
+        assert_eq!(word.checked_increment(), Some(next));
+        assert_eq!(next.checked_decrement(), Some(word));
+
+        assert_eq!(SequenceNumber::MAX.checked_increment(), None);
+        assert_eq!(SequenceNumber::default().checked_decrement(), None);
+    }
+
+    #[mz_ore::test]
+    fn test_timestamp_roundtrip() {
+        let seq = "49590338271490256608559692538361571095921575989136588898"
+            .parse()
+            .unwrap();
+        let timestamps = [
+            Partitioned::new_singleton(7, seq),
+            Partitioned::new_range(0, 6, SequenceNumber::default()),
+            KinesisTimestamp::minimum(),
+        ];
+        for ts in timestamps {
+            assert_eq!(KinesisTimestamp::decode_row(&ts.encode_row()), ts);
+        }
+    }
+}
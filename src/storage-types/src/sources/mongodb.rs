@@ -0,0 +1,206 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to MongoDB sources
+
+use std::sync::LazyLock;
+
+use mz_mongodb_util::ClusterTime;
+use mz_ore::future::InTask;
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlScalarType};
+use serde::{Deserialize, Serialize};
+use timely::progress::Antichain;
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::controller::AlterError;
+use crate::sources::{SourceConnection, SourceTimestamp};
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.mongodb.rs"
+));
+
+pub static MONGODB_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("cluster_time", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// The shape of every collection a MongoDB source ingests: the `_id` of each
+/// document, which is its key, and the document itself.
+pub static MONGODB_COLLECTION_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("_id", SqlScalarType::Jsonb.nullable(false))
+        .with_column("document", SqlScalarType::Jsonb.nullable(false))
+        .with_key(vec![0])
+        .finish()
+});
+
+/// Details about how to create a Materialize Source that reads from MongoDB.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MongoDbSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the Connection object this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to MongoDB.
+    pub connection: C::MongoDb,
+    /// The database whose collections the source ingests.
+    pub database: String,
+}
+
+impl MongoDbSourceConnection<InlinedConnection> {
+    pub async fn fetch_write_frontier(
+        self,
+        storage_configuration: &crate::configuration::StorageConfiguration,
+    ) -> Result<Antichain<ClusterTime>, anyhow::Error> {
+        let config = self
+            .connection
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::No,
+            )
+            .await?;
+        let client = config.connect().await?;
+
+        let cluster_time = mz_mongodb_util::inspect::get_current_cluster_time(&client).await?;
+        Ok(Antichain::from_elem(cluster_time.increment()))
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<MongoDbSourceConnection, R>
+    for MongoDbSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> MongoDbSourceConnection {
+        let MongoDbSourceConnection {
+            connection_id,
+            connection,
+            database,
+        } = self;
+
+        MongoDbSourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_mongodb(),
+            database,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for MongoDbSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "mongodb"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        None
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        RelationDesc::empty()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        // The MongoDB source only outputs data to its tables. The catalog object
+        // representing the source itself is just an empty relation with no columns
+        RelationDesc::empty()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        MONGODB_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        false
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for MongoDbSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let MongoDbSourceConnection {
+            connection_id,
+            connection,
+            database,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (database == &other.database, "database"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "MongoDbSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Specifies the details of a MongoDB source export.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MongoDbSourceExportDetails {
+    /// The name of the collection the export ingests.
+    pub collection: String,
+    /// The cluster time as of which the export was created.
+    /// This is used as a consistent snapshot point for this export to ensure
+    /// correctness in the case of multiple replicas.
+    pub initial_cluster_time: ClusterTime,
+}
+
+impl AlterCompatible for MongoDbSourceExportDetails {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        // compatibility checks are performed against the upstream collection in the source
+        // render operators instead
+        let Self {
+            collection: _,
+            initial_cluster_time: _,
+        } = self;
+        Ok(())
+    }
+}
+
+impl SourceTimestamp for ClusterTime {
+    fn encode_row(&self) -> Row {
+        Row::pack_slice(&[Datum::UInt64(self.as_u64())])
+    }
+
+    fn decode_row(row: &Row) -> Self {
+        let mut datums = row.iter();
+        match (datums.next(), datums.next()) {
+            (Some(Datum::UInt64(value)), None) => ClusterTime::from_u64(value),
+            _ => panic!("invalid row {row:?}"),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to MQTT sources
+
+use std::sync::LazyLock;
+
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Serialize};
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::connections::{ConnectionContext, KafkaConnection};
+use crate::controller::AlterError;
+use crate::sources::SourceConnection;
+use crate::sources::nats::headers_type;
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.mqtt.rs"
+));
+
+/// MQTT brokers do not assign offsets to messages, so the timestamp of an MQTT source is the
+/// number of messages the source has ingested.
+pub static MQTT_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("offset", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// Details about how to create a Materialize Source that subscribes to topics of an MQTT broker.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MqttSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the MQTT connection this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to the MQTT broker.
+    pub connection: C::Mqtt,
+    /// The topic filter the source subscribes to, e.g. `devices/+/telemetry`.
+    pub topic: String,
+    /// The name of the shared subscription group to subscribe through, if any.
+    pub shared_group: Option<String>,
+}
+
+impl<C: ConnectionAccess> MqttSourceConnection<C> {
+    /// Returns the filter to subscribe with, which routes the subscription through the shared
+    /// subscription group if there is one.
+    pub fn subscription_filter(&self) -> String {
+        match &self.shared_group {
+            Some(group) => format!("$share/{group}/{}", self.topic),
+            None => self.topic.clone(),
+        }
+    }
+}
+
+impl MqttSourceConnection {
+    /// Returns the client identifier of the source with ID `source_id`, which identifies the
+    /// session of the source on the broker.
+    pub fn client_id(&self, connection_context: &ConnectionContext, source_id: GlobalId) -> String {
+        KafkaConnection::id_base(connection_context, self.connection_id, source_id)
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<MqttSourceConnection, R>
+    for MqttSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> MqttSourceConnection {
+        let MqttSourceConnection {
+            connection_id,
+            connection,
+            topic,
+            shared_group,
+        } = self;
+
+        MqttSourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_mqtt(),
+            topic,
+            shared_group,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for MqttSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "mqtt"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        Some(self.topic.as_str())
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        // Every message has a topic.
+        RelationDesc::builder()
+            .with_column("key", SqlScalarType::Bytes.nullable(false))
+            .finish()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        RelationDesc::builder()
+            .with_column("value", SqlScalarType::Bytes.nullable(true))
+            .finish()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        MQTT_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        // Ingesting acknowledges messages to the broker.
+        false
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        // The broker only allows one client per session.
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for MqttSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let MqttSourceConnection {
+            connection_id,
+            connection,
+            topic,
+            shared_group,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (topic == &other.topic, "topic"),
+            (shared_group == &other.shared_group, "shared_group"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "MqttSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Which piece of metadata a column corresponds to
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MqttMetadataKind {
+    /// The user properties of the message.
+    Headers,
+}
+
+/// Return the column types used to describe the metadata columns of an MQTT source export.
+pub fn mqtt_metadata_columns_desc(
+    metadata_columns: &Vec<(String, MqttMetadataKind)>,
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                MqttMetadataKind::Headers => headers_type().nullable(false),
+            };
+            (&**name, typ)
+        })
+        .collect()
+}
+
+/// The details of a source export from an MQTT source.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MqttSourceExportDetails {
+    pub metadata_columns: Vec<(String, MqttMetadataKind)>,
+}
+
+impl AlterCompatible for MqttSourceExportDetails {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        let Self { metadata_columns } = self;
+        let compatibility_checks = [(
+            metadata_columns == &other.metadata_columns,
+            "metadata_columns",
+        )];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "MqttSourceExportDetails incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@ use std::sync::LazyLock;
 use mz_proto::{RustType, TryFromProtoError};
 use mz_repr::CatalogItemId;
 use mz_repr::GlobalId;
-use mz_repr::{Datum, RelationDesc, Row, SqlScalarType};
+use mz_repr::{Datum, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use mz_timely_util::order::Partitioned;
 use mz_timely_util::order::Step;
 use serde::{Deserialize, Serialize};
@@ -32,7 +32,7 @@ use crate::connections::inline::{
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::sources::{SourceConnection, SourceTimestamp};
+use crate::sources::{SchemaChangePolicy, SourceConnection, SourceTimestamp};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -216,6 +216,9 @@ pub struct MySqlSourceExportDetails {
     pub text_columns: Vec<String>,
     pub exclude_columns: Vec<String>,
     pub binlog_full_metadata: bool,
+    /// The transaction metadata columns appended to each row, in order.
+    pub metadata_columns: Vec<(String, MySqlMetadataKind)>,
+    pub schema_change_policy: SchemaChangePolicy,
 }
 
 impl AlterCompatible for MySqlSourceExportDetails {
@@ -232,11 +235,42 @@ impl AlterCompatible for MySqlSourceExportDetails {
             text_columns: _,
             exclude_columns: _,
             binlog_full_metadata: _,
+            metadata_columns: _,
+            schema_change_policy: _,
         } = self;
         Ok(())
     }
 }
 
+/// Which piece of transaction metadata a column corresponds to.
+///
+/// Rows read during the initial snapshot were not replicated as part of a transaction, so all of
+/// their metadata columns are null.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MySqlMetadataKind {
+    /// The commit time of the transaction on the server we replicate from.
+    CommitTimestamp,
+    /// The GTID of the transaction, formatted as `<source_id>:<transaction_id>`.
+    Gtid,
+}
+
+pub fn mysql_metadata_columns_desc(
+    metadata_columns: &[(String, MySqlMetadataKind)],
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                MySqlMetadataKind::CommitTimestamp => {
+                    SqlScalarType::Timestamp { precision: None }.nullable(true)
+                }
+                MySqlMetadataKind::Gtid => SqlScalarType::String.nullable(true),
+            };
+            (name.as_str(), typ)
+        })
+        .collect()
+}
+
 /// Represents a MySQL transaction id
 #[derive(
     Debug,
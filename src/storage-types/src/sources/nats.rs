@@ -0,0 +1,257 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to NATS JetStream sources
+
+use std::sync::LazyLock;
+
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Serialize};
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::connections::{ConnectionContext, KafkaConnection};
+use crate::controller::AlterError;
+use crate::sources::SourceConnection;
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_storage_types.sources.nats.rs"
+));
+
+/// The timestamp of a NATS JetStream source is the sequence number, in the stream, of the next
+/// message the source will ingest. Stream sequence numbers start at `1`.
+pub static NATS_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("sequence", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// Details about how to create a Materialize Source that reads from a NATS JetStream stream.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NatsSourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the NATS connection this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to the NATS server.
+    pub connection: C::Nats,
+    /// The name of the stream the source reads from.
+    pub stream: String,
+    /// The name of the durable consumer the source reads through. If not set, the consumer is
+    /// named after the environment, connection and source, and created if it does not exist.
+    pub consumer: Option<String>,
+    /// A subject filter that restricts which messages of the stream are ingested.
+    pub subject: Option<String>,
+}
+
+impl NatsSourceConnection {
+    /// Returns the name of the durable consumer the source with ID `source_id` reads through.
+    pub fn consumer_name(
+        &self,
+        connection_context: &ConnectionContext,
+        source_id: GlobalId,
+    ) -> String {
+        match &self.consumer {
+            Some(consumer) => consumer.clone(),
+            None => KafkaConnection::id_base(connection_context, self.connection_id, source_id),
+        }
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<NatsSourceConnection, R>
+    for NatsSourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> NatsSourceConnection {
+        let NatsSourceConnection {
+            connection_id,
+            connection,
+            stream,
+            consumer,
+            subject,
+        } = self;
+
+        NatsSourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_nats(),
+            stream,
+            consumer,
+            subject,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for NatsSourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "nats"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        Some(self.stream.as_str())
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        // Every message has a subject.
+        RelationDesc::builder()
+            .with_column("key", SqlScalarType::Bytes.nullable(false))
+            .finish()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        RelationDesc::builder()
+            .with_column("value", SqlScalarType::Bytes.nullable(true))
+            .finish()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        NATS_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        // Ingesting acknowledges messages to the durable consumer.
+        false
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        // Replicas would compete for the messages of the durable consumer.
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for NatsSourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let NatsSourceConnection {
+            connection_id,
+            connection,
+            stream,
+            consumer,
+            subject,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (stream == &other.stream, "stream"),
+            (consumer == &other.consumer, "consumer"),
+            (subject == &other.subject, "subject"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "NatsSourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Which piece of metadata a column corresponds to
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum NatsMetadataKind {
+    /// The sequence number of the message in the stream.
+    Offset,
+    /// The time at which the message was published to the stream.
+    Timestamp,
+    /// The headers of the message.
+    Headers,
+}
+
+/// Return the column types used to describe the metadata columns of a NATS source export.
+pub fn nats_metadata_columns_desc(
+    metadata_columns: &Vec<(String, NatsMetadataKind)>,
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                NatsMetadataKind::Offset => SqlScalarType::UInt64.nullable(false),
+                NatsMetadataKind::Timestamp => {
+                    SqlScalarType::Timestamp { precision: None }.nullable(false)
+                }
+                NatsMetadataKind::Headers => headers_type().nullable(false),
+            };
+            (&**name, typ)
+        })
+        .collect()
+}
+
+/// The type of a column holding the headers of a message, which matches the type of the headers
+/// column of Kafka sources.
+pub(crate) fn headers_type() -> SqlScalarType {
+    SqlScalarType::List {
+        element_type: Box::new(SqlScalarType::Record {
+            fields: [
+                (
+                    "key".into(),
+                    SqlColumnType {
+                        nullable: false,
+                        scalar_type: SqlScalarType::String,
+                    },
+                ),
+                (
+                    "value".into(),
+                    SqlColumnType {
+                        nullable: true,
+                        scalar_type: SqlScalarType::Bytes,
+                    },
+                ),
+            ]
+            .into(),
+            custom_id: None,
+        }),
+        custom_id: None,
+    }
+}
+
+/// The details of a source export from a NATS source.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NatsSourceExportDetails {
+    pub metadata_columns: Vec<(String, NatsMetadataKind)>,
+}
+
+impl AlterCompatible for NatsSourceExportDetails {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        let Self { metadata_columns } = self;
+        let compatibility_checks = [(
+            metadata_columns == &other.metadata_columns,
+            "metadata_columns",
+        )];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "NatsSourceExportDetails incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@
 
 use mz_postgres_util::desc::PostgresTableDesc;
 use mz_proto::{RustType, TryFromProtoError};
-use mz_repr::{CatalogItemId, GlobalId, RelationDesc, SqlScalarType};
+use mz_repr::{CatalogItemId, GlobalId, RelationDesc, SqlColumnType, SqlScalarType};
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
@@ -21,7 +21,7 @@ use crate::connections::inline::{
     ReferencedConnection,
 };
 use crate::controller::AlterError;
-use crate::sources::{MzOffset, SourceConnection};
+use crate::sources::{MzOffset, SchemaChangePolicy, SourceConnection};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -190,6 +190,14 @@ pub struct PostgresSourceExportDetails {
     /// their target types
     pub column_casts: Vec<(CastType, crate::sources::casts::StorageScalarExpr)>,
     pub table: PostgresTableDesc,
+    /// The transaction metadata columns appended to each row, in order.
+    pub metadata_columns: Vec<(String, PostgresMetadataKind)>,
+    /// Whether an upstream `TRUNCATE` retracts the current contents of this export instead of
+    /// putting it into an errored state.
+    pub retract_on_truncate: bool,
+    /// The upstream columns that were deliberately left out of this export.
+    pub exclude_columns: Vec<String>,
+    pub schema_change_policy: SchemaChangePolicy,
 }
 
 impl AlterCompatible for PostgresSourceExportDetails {
@@ -199,11 +207,70 @@ impl AlterCompatible for PostgresSourceExportDetails {
         let Self {
             column_casts: _,
             table: _,
+            metadata_columns: _,
+            retract_on_truncate: _,
+            exclude_columns: _,
+            schema_change_policy: _,
         } = self;
         Ok(())
     }
 }
 
+/// Which piece of transaction metadata a column corresponds to.
+///
+/// Rows read during the initial snapshot were not replicated as part of a transaction, so all of
+/// their metadata columns are null.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PostgresMetadataKind {
+    /// The upstream commit time of the transaction.
+    CommitTimestamp,
+    /// The LSN of the transaction's commit record.
+    Lsn,
+    /// The upstream transaction ID (`xid`).
+    TransactionId,
+}
+
+pub fn postgres_metadata_columns_desc(
+    metadata_columns: &[(String, PostgresMetadataKind)],
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                PostgresMetadataKind::CommitTimestamp => {
+                    SqlScalarType::Timestamp { precision: None }.nullable(true)
+                }
+                PostgresMetadataKind::Lsn => SqlScalarType::UInt64.nullable(true),
+                PostgresMetadataKind::TransactionId => SqlScalarType::UInt32.nullable(true),
+            };
+            (name.as_str(), typ)
+        })
+        .collect()
+}
+
+/// The reference that selects the logical decoding messages of a PostgreSQL source, i.e. the
+/// messages written upstream with `pg_logical_emit_message`.
+pub const PG_LOGICAL_MESSAGES_REFERENCE: &str = "mz_logical_messages";
+
+pub static PG_LOGICAL_MESSAGES_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("prefix", SqlScalarType::String.nullable(false))
+        .with_column("content", SqlScalarType::Bytes.nullable(false))
+        .with_column("lsn", SqlScalarType::UInt64.nullable(false))
+        .finish()
+});
+
+/// The details of an export carrying the logical decoding messages of a PostgreSQL source. Its
+/// shape is always [`PG_LOGICAL_MESSAGES_DESC`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresMessagesSourceExportDetails {}
+
+impl AlterCompatible for PostgresMessagesSourceExportDetails {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PostgresSourcePublicationDetails {
     pub slot: String,
@@ -0,0 +1,194 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Types related to S3 sources
+
+use std::sync::LazyLock;
+
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlScalarType};
+use mz_timely_util::order::Partitioned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::AlterCompatible;
+use crate::connections::inline::{
+    ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
+    ReferencedConnection,
+};
+use crate::controller::AlterError;
+use crate::oneshot_sources::ContentFormat;
+use crate::sources::{MzOffset, SourceConnection, SourceTimestamp};
+
+include!(concat!(env!("OUT_DIR"), "/mz_storage_types.sources.s3.rs"));
+
+pub static S3_PROGRESS_DESC: LazyLock<RelationDesc> = LazyLock::new(|| {
+    RelationDesc::builder()
+        .with_column("object_id_lower", SqlScalarType::Uuid.nullable(false))
+        .with_column("object_id_upper", SqlScalarType::Uuid.nullable(false))
+        .with_column("offset", SqlScalarType::UInt64.nullable(true))
+        .finish()
+});
+
+/// The timestamp of an S3 source.
+///
+/// Every object is identified by a [`Uuid`] derived from its key, and is its own partition. The
+/// offset of a partition is `0` until the object has been ingested, and `1` afterwards. Ranges of
+/// identifiers that no ingested object falls into are kept at offset `0`, which makes the
+/// frontier of the source an exact record of the objects it has ingested.
+pub type S3Timestamp = Partitioned<Uuid, MzOffset>;
+
+impl SourceTimestamp for S3Timestamp {
+    fn encode_row(&self) -> Row {
+        Row::pack(&[
+            Datum::Uuid(self.interval().lower),
+            Datum::Uuid(self.interval().upper),
+            Datum::UInt64(self.timestamp().offset),
+        ])
+    }
+
+    fn decode_row(row: &Row) -> Self {
+        let mut datums = row.iter();
+        match (datums.next(), datums.next(), datums.next(), datums.next()) {
+            (
+                Some(Datum::Uuid(lower)),
+                Some(Datum::Uuid(upper)),
+                Some(Datum::UInt64(offset)),
+                None,
+            ) => Partitioned::new_range(lower, upper, MzOffset::from(offset)),
+            _ => panic!("invalid row {row:?}"),
+        }
+    }
+}
+
+/// Details about how to create a Materialize Source that continuously ingests the objects of an
+/// S3 bucket.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3SourceConnection<C: ConnectionAccess = InlinedConnection> {
+    /// The ID of the AWS connection this source is using.
+    pub connection_id: CatalogItemId,
+    /// Configuration for connecting to S3.
+    pub connection: C::Aws,
+    /// The `s3://bucket/prefix` URI under which objects are listed.
+    pub uri: String,
+    /// A glob pattern the keys of ingested objects must match.
+    pub pattern: Option<String>,
+    /// The format of the ingested objects.
+    pub format: ContentFormat,
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<S3SourceConnection, R>
+    for S3SourceConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> S3SourceConnection {
+        let S3SourceConnection {
+            connection_id,
+            connection,
+            uri,
+            pattern,
+            format,
+        } = self;
+
+        S3SourceConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_aws(),
+            uri,
+            pattern,
+            format,
+        }
+    }
+}
+
+impl<C: ConnectionAccess> SourceConnection for S3SourceConnection<C> {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    fn external_reference(&self) -> Option<&str> {
+        None
+    }
+
+    fn default_key_desc(&self) -> RelationDesc {
+        RelationDesc::empty()
+    }
+
+    fn default_value_desc(&self) -> RelationDesc {
+        // The S3 source only outputs data to its tables, whose columns are chosen by the user.
+        // The catalog object representing the source itself is just an empty relation with no
+        // columns.
+        RelationDesc::empty()
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        S3_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<CatalogItemId> {
+        Some(self.connection_id)
+    }
+
+    fn supports_read_only(&self) -> bool {
+        false
+    }
+
+    fn prefers_single_replica(&self) -> bool {
+        true
+    }
+}
+
+impl<C: ConnectionAccess> AlterCompatible for S3SourceConnection<C> {
+    fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+
+        let S3SourceConnection {
+            connection_id,
+            connection,
+            uri,
+            pattern,
+            format,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (uri == &other.uri, "uri"),
+            (pattern == &other.pattern, "pattern"),
+            (format == &other.format, "format"),
+        ];
+
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "S3SourceConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Specifies the details of an S3 source export. Every export decodes each ingested object
+/// according to its own relation description.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3SourceExportDetails {}
+
+impl AlterCompatible for S3SourceExportDetails {
+    fn alter_compatible(&self, _id: GlobalId, _other: &Self) -> Result<(), AlterError> {
+        Ok(())
+    }
+}
@@ -15,7 +15,7 @@ use std::time::Duration;
 use mz_dyncfg::Config;
 use mz_ore::future::InTask;
 use mz_proto::RustType;
-use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlScalarType};
+use mz_repr::{CatalogItemId, Datum, GlobalId, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use mz_sql_server_util::cdc::Lsn;
 use serde::{Deserialize, Serialize};
 use timely::progress::Antichain;
@@ -250,6 +250,8 @@ pub struct SqlServerSourceExportDetails {
     /// This is used as a consistent snapshot point for this export to ensure
     /// correctness in the case of multiple replicas.
     pub initial_lsn: mz_sql_server_util::cdc::Lsn,
+    /// The transaction metadata columns appended to each row, in order.
+    pub metadata_columns: Vec<(String, SqlServerMetadataKind)>,
 }
 
 impl AlterCompatible for SqlServerSourceExportDetails {
@@ -266,11 +268,56 @@ impl AlterCompatible for SqlServerSourceExportDetails {
             text_columns: _,
             exclude_columns: _,
             initial_lsn: _,
+            metadata_columns: _,
         } = self;
         Ok(())
     }
 }
 
+/// Which piece of transaction metadata a column corresponds to.
+///
+/// Rows read during the initial snapshot were not replicated as part of a transaction, so all of
+/// their metadata columns are null.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SqlServerMetadataKind {
+    /// The upstream commit time of the transaction, in the server's local time zone, as recorded
+    /// in `cdc.lsn_time_mapping`.
+    CommitTimestamp,
+    /// The commit LSN of the transaction, as the 10 bytes SQL Server represents it with.
+    Lsn,
+    /// The upstream transaction ID, as recorded in `cdc.lsn_time_mapping`.
+    TransactionId,
+}
+
+impl SqlServerMetadataKind {
+    /// Whether this metadata is read from `cdc.lsn_time_mapping` alongside each change.
+    pub fn requires_lsn_time_mapping(&self) -> bool {
+        match self {
+            SqlServerMetadataKind::CommitTimestamp | SqlServerMetadataKind::TransactionId => true,
+            SqlServerMetadataKind::Lsn => false,
+        }
+    }
+}
+
+pub fn sql_server_metadata_columns_desc(
+    metadata_columns: &[(String, SqlServerMetadataKind)],
+) -> Vec<(&str, SqlColumnType)> {
+    metadata_columns
+        .iter()
+        .map(|(name, kind)| {
+            let typ = match kind {
+                SqlServerMetadataKind::CommitTimestamp => {
+                    SqlScalarType::Timestamp { precision: None }.nullable(true)
+                }
+                SqlServerMetadataKind::Lsn | SqlServerMetadataKind::TransactionId => {
+                    SqlScalarType::Bytes.nullable(true)
+                }
+            };
+            (name.as_str(), typ)
+        })
+        .collect()
+}
+
 impl SourceTimestamp for Lsn {
     fn encode_row(&self) -> mz_repr::Row {
         Row::pack_slice(&[Datum::Bytes(&self.as_bytes())])
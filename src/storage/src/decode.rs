@@ -21,7 +21,7 @@ use std::time::Duration;
 use differential_dataflow::capture::{Message, Progress};
 use differential_dataflow::{AsCollection, Hashable, VecCollection};
 use futures::StreamExt;
-use mz_interchange::avro::WriterSchemaProvider;
+use mz_interchange::avro::{AvroDecodeOptions, WriterSchemaProvider};
 use mz_ore::error::ErrorExt;
 use mz_ore::future::InTask;
 use mz_repr::{Datum, Diff, Row};
@@ -45,12 +45,14 @@ use timely::scheduling::SyncActivator;
 use tracing::error;
 
 use crate::decode::avro::AvroDecoderState;
+use crate::decode::json_schema::JsonSchemaDecoderState;
 use crate::decode::protobuf::ProtobufDecoderState;
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
 use crate::metrics::decode::DecodeMetricDefs;
 use crate::source::types::{DecodeResult, SourceOutput};
 
 mod avro;
+mod json_schema;
 mod protobuf;
 
 /// Decode delimited CDCv2 messages.
@@ -216,6 +218,10 @@ pub(crate) enum PreDelimitedFormat {
     Bytes,
     Text,
     Json,
+    /// Decodes a JSON array into a row with a single `jsonb` datum. The array is exploded into
+    /// one row per element by [`explode_json_array`].
+    JsonArray,
+    JsonSchema(JsonSchemaDecoderState),
     Regex(Regex, Row),
     Protobuf(ProtobufDecoderState),
 }
@@ -232,6 +238,19 @@ impl PreDelimitedFormat {
                 })?;
                 Ok(Some(j.into_row()))
             }
+            PreDelimitedFormat::JsonArray => {
+                let j = mz_repr::adt::jsonb::Jsonb::from_slice(bytes).map_err(|e| {
+                    DecodeErrorKind::Bytes(
+                        format!("Failed to decode JSON: {}", e.display_with_causes(),).into(),
+                    )
+                })?;
+                if !matches!(j.as_ref().into_datum(), Datum::List(_)) {
+                    return Err(DecodeErrorKind::Bytes(
+                        "Failed to decode JSON array: value is not an array".into(),
+                    ));
+                }
+                Ok(Some(j.into_row()))
+            }
             PreDelimitedFormat::Text => {
                 let s = std::str::from_utf8(bytes)
                     .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".into()))?;
@@ -252,6 +271,7 @@ impl PreDelimitedFormat {
                 );
                 Ok(Some(row_buf.clone()))
             }
+            PreDelimitedFormat::JsonSchema(json) => json.get_value(bytes).map(Some),
             PreDelimitedFormat::Protobuf(pb) => pb.get_value(bytes).transpose(),
         }
     }
@@ -355,6 +375,8 @@ async fn get_decoder(
             schema,
             reference_schemas,
             wire_format,
+            max_depth,
+            union_as_record,
         }) => {
             let writer_schemas = match wire_format {
                 WireFormat::None => WriterSchemaProvider::None,
@@ -398,6 +420,10 @@ async fn get_decoder(
                 &schema,
                 &reference_schemas,
                 writer_schemas,
+                AvroDecodeOptions {
+                    max_depth,
+                    union_as_record,
+                },
                 debug_name.to_string(),
             )
             .expect(
@@ -411,6 +437,8 @@ async fn get_decoder(
         DataEncoding::Text
         | DataEncoding::Bytes
         | DataEncoding::Json
+        | DataEncoding::JsonArray
+        | DataEncoding::JsonSchema(_)
         | DataEncoding::Protobuf(_)
         | DataEncoding::Regex(_) => {
             let after_delimiting = match encoding {
@@ -425,6 +453,11 @@ async fn get_decoder(
                 }
                 DataEncoding::Bytes => PreDelimitedFormat::Bytes,
                 DataEncoding::Json => PreDelimitedFormat::Json,
+                DataEncoding::JsonArray => PreDelimitedFormat::JsonArray,
+                DataEncoding::JsonSchema(encoding) => PreDelimitedFormat::JsonSchema(
+                    JsonSchemaDecoderState::new(encoding)
+                        .expect("JSON Schemas provided to sources are validated in planning"),
+                ),
                 DataEncoding::Text => PreDelimitedFormat::Text,
                 _ => unreachable!(),
             };
@@ -479,6 +512,19 @@ async fn decode_delimited(
     }))
 }
 
+/// Explodes a row holding a decoded JSON array into one row per element of the
+/// array, each holding the element and its index in the array.
+///
+/// An empty array produces no rows.
+fn explode_json_array<'a>(array: &'a Row, row_buf: &'a mut Row) -> impl Iterator<Item = Row> + 'a {
+    let elements = array.unpack_first().unwrap_list();
+    elements.into_iter().enumerate().map(move |(i, element)| {
+        let index = i64::try_from(i).expect("JSON array index fits in an i64");
+        row_buf.packer().extend([element, Datum::Int64(index)]);
+        row_buf.clone()
+    })
+}
+
 /// Decode already delimited records of data.
 ///
 /// Precondition: each record in the stream has at most one key and at most one value.
@@ -510,6 +556,7 @@ pub fn render_decode_delimited<'scope, T: Timestamp, FromTime: Timestamp>(
             .unwrap_or(""),
         value_encoding.op_name()
     );
+    let explode_json_arrays = matches!(value_encoding, DataEncoding::JsonArray);
     let dist = |(x, _, _): &(SourceOutput<FromTime>, _, _)| x.value.hashed();
 
     let mut builder = AsyncOperatorBuilder::new(op_name, input.scope());
@@ -545,6 +592,7 @@ pub fn render_decode_delimited<'scope, T: Timestamp, FromTime: Timestamp>(
             .await?;
 
             let mut output_container = Vec::new();
+            let mut row_buf = Row::default();
 
             while let Some(event) = input.next().await {
                 match event {
@@ -579,13 +627,28 @@ pub fn render_decode_delimited<'scope, T: Timestamp, FromTime: Timestamp>(
                                 n_successes += 1;
                             }
 
-                            let result = DecodeResult {
-                                key,
-                                value,
-                                metadata: output.metadata.clone(),
-                                from_time: output.from_time.clone(),
-                            };
-                            output_container.push((result, ts.clone(), *diff));
+                            match value {
+                                Some(Ok(array)) if explode_json_arrays => {
+                                    for element in explode_json_array(&array, &mut row_buf) {
+                                        let result = DecodeResult {
+                                            key: key.clone(),
+                                            value: Some(Ok(element)),
+                                            metadata: output.metadata.clone(),
+                                            from_time: output.from_time.clone(),
+                                        };
+                                        output_container.push((result, ts.clone(), *diff));
+                                    }
+                                }
+                                value => {
+                                    let result = DecodeResult {
+                                        key,
+                                        value,
+                                        metadata: output.metadata.clone(),
+                                        from_time: output.from_time.clone(),
+                                    };
+                                    output_container.push((result, ts.clone(), *diff));
+                                }
+                            }
                         }
 
                         // Matching historical practice, we only log metrics on the value decoder.
@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use mz_interchange::avro::{Decoder, WriterSchemaProvider};
+use mz_interchange::avro::{AvroDecodeOptions, Decoder, WriterSchemaProvider};
 use mz_ore::error::ErrorExt;
 use mz_repr::Row;
 use mz_storage_types::errors::DecodeErrorKind;
@@ -23,10 +23,17 @@ impl AvroDecoderState {
         value_schema: &str,
         reference_schemas: &[String],
         writer_schemas: WriterSchemaProvider,
+        options: AvroDecodeOptions,
         debug_name: String,
     ) -> Result<Self, anyhow::Error> {
         Ok(AvroDecoderState {
-            decoder: Decoder::new(value_schema, reference_schemas, writer_schemas, debug_name)?,
+            decoder: Decoder::new(
+                value_schema,
+                reference_schemas,
+                writer_schemas,
+                options,
+                debug_name,
+            )?,
             events_success: 0,
         })
     }
@@ -26,10 +26,12 @@ impl ProtobufDecoderState {
             descriptors,
             message_name,
             confluent_wire_format,
+            recursion_limit,
         }: ProtobufEncoding,
     ) -> Result<Self, anyhow::Error> {
-        let descriptors = DecodedDescriptors::from_bytes(&descriptors, message_name)
-            .expect("descriptors provided to protobuf source are pre-validated");
+        let descriptors =
+            DecodedDescriptors::from_bytes(&descriptors, message_name, recursion_limit)
+                .expect("descriptors provided to protobuf source are pre-validated");
         Ok(ProtobufDecoderState {
             decoder: Decoder::new(descriptors, confluent_wire_format)?,
             events_success: 0,
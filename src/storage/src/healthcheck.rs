@@ -61,10 +61,16 @@ pub enum StatusNamespace {
     Postgres,
     MySql,
     SqlServer,
+    MongoDb,
+    S3,
     Ssh,
     Upsert,
     Decode,
     Iceberg,
+    Kinesis,
+    Nats,
+    Mqtt,
+    Http,
     Internal,
 }
 
@@ -83,11 +89,17 @@ impl fmt::Display for StatusNamespace {
             Postgres => write!(f, "postgres"),
             MySql => write!(f, "mysql"),
             SqlServer => write!(f, "sql-server"),
+            MongoDb => write!(f, "mongodb"),
+            S3 => write!(f, "s3"),
             Ssh => write!(f, "ssh"),
             Upsert => write!(f, "upsert"),
             Decode => write!(f, "decode"),
             Internal => write!(f, "internal"),
             Iceberg => write!(f, "iceberg"),
+            Kinesis => write!(f, "kinesis"),
+            Nats => write!(f, "nats"),
+            Mqtt => write!(f, "mqtt"),
+            Http => write!(f, "http"),
         }
     }
 }
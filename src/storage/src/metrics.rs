@@ -167,6 +167,45 @@ impl StorageMetrics {
         )
     }
 
+    /// Get a `MongoDbSourceMetrics` for the given id.
+    pub(crate) fn get_mongodb_source_metrics(
+        &self,
+        id: GlobalId,
+    ) -> source::mongodb::MongoDbSourceMetrics {
+        source::mongodb::MongoDbSourceMetrics::new(&self.source_defs.mongodb_defs, id)
+    }
+
+    /// Get an `IcebergSourceMetrics` for the given id.
+    pub(crate) fn get_iceberg_source_metrics(
+        &self,
+        id: GlobalId,
+    ) -> source::iceberg::IcebergSourceMetrics {
+        source::iceberg::IcebergSourceMetrics::new(&self.source_defs.iceberg_defs, id)
+    }
+
+    /// Get an `S3SourceMetrics` for the given id.
+    pub(crate) fn get_s3_source_metrics(&self, id: GlobalId) -> source::s3::S3SourceMetrics {
+        source::s3::S3SourceMetrics::new(&self.source_defs.s3_defs, id)
+    }
+
+    /// Get a `KinesisSourceMetrics` for the given id.
+    pub(crate) fn get_kinesis_source_metrics(
+        &self,
+        id: GlobalId,
+    ) -> source::kinesis::KinesisSourceMetrics {
+        source::kinesis::KinesisSourceMetrics::new(&self.source_defs.kinesis_defs, id)
+    }
+
+    /// Get a `NatsSourceMetrics` for the given id.
+    pub(crate) fn get_nats_source_metrics(&self, id: GlobalId) -> source::nats::NatsSourceMetrics {
+        source::nats::NatsSourceMetrics::new(&self.source_defs.nats_defs, id)
+    }
+
+    /// Get an `MqttSourceMetrics` for the given id.
+    pub(crate) fn get_mqtt_source_metrics(&self, id: GlobalId) -> source::mqtt::MqttSourceMetrics {
+        source::mqtt::MqttSourceMetrics::new(&self.source_defs.mqtt_defs, id)
+    }
+
     /// Get an `OffsetCommitMetrics` for the given id.
     pub(crate) fn get_offset_commit_metrics(&self, id: GlobalId) -> source::OffsetCommitMetrics {
         source::OffsetCommitMetrics::new(&self.source_defs.source_defs, id)
@@ -41,6 +41,8 @@ impl DecodeMetricDefs {
             | DataDecoderInner::PreDelimited(format) => match format {
                 PreDelimitedFormat::Bytes => "raw",
                 PreDelimitedFormat::Json => "json",
+                PreDelimitedFormat::JsonArray => "json-array",
+                PreDelimitedFormat::JsonSchema(..) => "json-schema",
                 PreDelimitedFormat::Text => "text",
                 PreDelimitedFormat::Regex(..) => "regex",
                 PreDelimitedFormat::Protobuf(..) => "protobuf",
@@ -22,9 +22,15 @@ use mz_ore::metrics::{
 use mz_repr::GlobalId;
 use prometheus::core::{AtomicI64, AtomicU64};
 
+pub mod iceberg;
 pub mod kafka;
+pub mod kinesis;
+pub mod mongodb;
+pub mod mqtt;
 pub mod mysql;
+pub mod nats;
 pub mod postgres;
+pub mod s3;
 pub mod sql_server;
 
 /// Definitions for general metrics about sources that are not specific to the source type.
@@ -228,7 +234,13 @@ pub(crate) struct SourceMetricDefs {
     pub(crate) postgres_defs: postgres::PgSourceMetricDefs,
     pub(crate) mysql_defs: mysql::MySqlSourceMetricDefs,
     pub(crate) kafka_source_defs: kafka::KafkaSourceMetricDefs,
+    pub(crate) kinesis_defs: kinesis::KinesisSourceMetricDefs,
+    pub(crate) nats_defs: nats::NatsSourceMetricDefs,
+    pub(crate) mqtt_defs: mqtt::MqttSourceMetricDefs,
     pub(crate) sql_server_defs: sql_server::SqlServerSourceMetricDefs,
+    pub(crate) mongodb_defs: mongodb::MongoDbSourceMetricDefs,
+    pub(crate) s3_defs: s3::S3SourceMetricDefs,
+    pub(crate) iceberg_defs: iceberg::IcebergSourceMetricDefs,
     /// A cluster-wide counter shared across all sources.
     pub(crate) bytes_read: IntCounter,
 }
@@ -240,7 +252,13 @@ impl SourceMetricDefs {
             postgres_defs: postgres::PgSourceMetricDefs::register_with(registry),
             mysql_defs: mysql::MySqlSourceMetricDefs::register_with(registry),
             kafka_source_defs: kafka::KafkaSourceMetricDefs::register_with(registry),
+            kinesis_defs: kinesis::KinesisSourceMetricDefs::register_with(registry),
+            nats_defs: nats::NatsSourceMetricDefs::register_with(registry),
+            mqtt_defs: mqtt::MqttSourceMetricDefs::register_with(registry),
             sql_server_defs: sql_server::SqlServerSourceMetricDefs::register_with(registry),
+            mongodb_defs: mongodb::MongoDbSourceMetricDefs::register_with(registry),
+            s3_defs: s3::S3SourceMetricDefs::register_with(registry),
+            iceberg_defs: iceberg::IcebergSourceMetricDefs::register_with(registry),
             bytes_read: registry.register(metric!(
                 name: "mz_bytes_read_total",
                 help: "Count of bytes read from sources",
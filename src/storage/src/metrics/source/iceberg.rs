@@ -0,0 +1,73 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for Iceberg.
+
+use mz_ore::metric;
+use mz_ore::metrics::{DeleteOnDropCounter, IntCounterVec, MetricsRegistry};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct IcebergSourceMetricDefs {
+    pub(crate) table_loads: IntCounterVec,
+    pub(crate) snapshots_ingested: IntCounterVec,
+    pub(crate) files_read: IntCounterVec,
+    pub(crate) rows: IntCounterVec,
+}
+
+impl IcebergSourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            table_loads: registry.register(metric!(
+                name: "mz_iceberg_per_source_table_loads",
+                help: "The number of times the metadata of an upstream table was loaded from the catalog for this source",
+                var_labels: ["source_id"],
+            )),
+            snapshots_ingested: registry.register(metric!(
+                name: "mz_iceberg_per_source_snapshots_ingested",
+                help: "The number of table snapshots ingested by this source",
+                var_labels: ["source_id"],
+            )),
+            files_read: registry.register(metric!(
+                name: "mz_iceberg_per_source_files_read",
+                help: "The number of data files read by this source, counting files read to retract their rows",
+                var_labels: ["source_id"],
+            )),
+            rows: registry.register(metric!(
+                name: "mz_iceberg_per_source_rows",
+                help: "The number of rows decoded from data files for all tables of this source",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for Iceberg sources.
+pub(crate) struct IcebergSourceMetrics {
+    pub(crate) table_loads: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) snapshots_ingested: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) files_read: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) rows: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+}
+
+impl IcebergSourceMetrics {
+    /// Create an `IcebergSourceMetrics` from the `IcebergSourceMetricDefs`.
+    pub(crate) fn new(defs: &IcebergSourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            table_loads: defs.table_loads.get_delete_on_drop_metric(labels.to_vec()),
+            snapshots_ingested: defs
+                .snapshots_ingested
+                .get_delete_on_drop_metric(labels.to_vec()),
+            files_read: defs.files_read.get_delete_on_drop_metric(labels.to_vec()),
+            rows: defs.rows.get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
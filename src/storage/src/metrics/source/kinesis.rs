@@ -0,0 +1,99 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for Kinesis.
+
+use mz_ore::metric;
+use mz_ore::metrics::{
+    DeleteOnDropCounter, DeleteOnDropGauge, IntCounterVec, MetricsRegistry, UIntGaugeVec,
+};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct KinesisSourceMetricDefs {
+    pub(crate) get_records_requests: IntCounterVec,
+    pub(crate) throttled_requests: IntCounterVec,
+    pub(crate) records: IntCounterVec,
+    pub(crate) shards_finished: IntCounterVec,
+    pub(crate) active_shards: UIntGaugeVec,
+    pub(crate) millis_behind_latest: UIntGaugeVec,
+}
+
+impl KinesisSourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            get_records_requests: registry.register(metric!(
+                name: "mz_kinesis_per_source_get_records_requests",
+                help: "The number of GetRecords requests issued by this source",
+                var_labels: ["source_id"],
+            )),
+            throttled_requests: registry.register(metric!(
+                name: "mz_kinesis_per_source_throttled_requests",
+                help: "The number of requests of this source that were throttled by Kinesis",
+                var_labels: ["source_id"],
+            )),
+            records: registry.register(metric!(
+                name: "mz_kinesis_per_source_records",
+                help: "The number of records read from the stream of this source",
+                var_labels: ["source_id"],
+            )),
+            shards_finished: registry.register(metric!(
+                name: "mz_kinesis_per_source_shards_finished",
+                help: "The number of closed shards that this source has read to the end",
+                var_labels: ["source_id"],
+            )),
+            active_shards: registry.register(metric!(
+                name: "mz_kinesis_per_source_active_shards",
+                help: "The number of shards that this source is currently reading",
+                var_labels: ["source_id"],
+            )),
+            millis_behind_latest: registry.register(metric!(
+                name: "mz_kinesis_per_source_millis_behind_latest",
+                help: "The largest number of milliseconds that a shard of this source is behind the tip of the stream",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for Kinesis sources.
+pub(crate) struct KinesisSourceMetrics {
+    pub(crate) get_records_requests: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) throttled_requests: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) records: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) shards_finished: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) active_shards: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+    pub(crate) millis_behind_latest: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+}
+
+impl KinesisSourceMetrics {
+    /// Create a `KinesisSourceMetrics` from the `KinesisSourceMetricDefs`.
+    pub(crate) fn new(defs: &KinesisSourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            get_records_requests: defs
+                .get_records_requests
+                .get_delete_on_drop_metric(labels.to_vec()),
+            throttled_requests: defs
+                .throttled_requests
+                .get_delete_on_drop_metric(labels.to_vec()),
+            records: defs.records.get_delete_on_drop_metric(labels.to_vec()),
+            shards_finished: defs
+                .shards_finished
+                .get_delete_on_drop_metric(labels.to_vec()),
+            active_shards: defs
+                .active_shards
+                .get_delete_on_drop_metric(labels.to_vec()),
+            millis_behind_latest: defs
+                .millis_behind_latest
+                .get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
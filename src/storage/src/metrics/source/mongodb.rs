@@ -0,0 +1,97 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for MongoDB.
+
+use mz_ore::metric;
+use mz_ore::metrics::{
+    DeleteOnDropCounter, DeleteOnDropGauge, IntCounterVec, MetricsRegistry, UIntGaugeVec,
+};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct MongoDbSourceMetricDefs {
+    pub(crate) total_events: IntCounterVec,
+    pub(crate) ignored_events: IntCounterVec,
+    pub(crate) insert_documents: IntCounterVec,
+    pub(crate) update_documents: IntCounterVec,
+    pub(crate) delete_documents: IntCounterVec,
+    pub(crate) collections: UIntGaugeVec,
+}
+
+impl MongoDbSourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            total_events: registry.register(metric!(
+                name: "mz_mongodb_per_source_events_total",
+                help: "The total number of change stream events for this source, not expected to be the sum of the other values.",
+                var_labels: ["source_id"],
+            )),
+            ignored_events: registry.register(metric!(
+                name: "mz_mongodb_per_source_ignored_events",
+                help: "The number of change stream events ignored because of an irrelevant type or collection",
+                var_labels: ["source_id"],
+            )),
+            insert_documents: registry.register(metric!(
+                name: "mz_mongodb_per_source_inserts",
+                help: "The number of inserts for all collections in this source",
+                var_labels: ["source_id"],
+            )),
+            update_documents: registry.register(metric!(
+                name: "mz_mongodb_per_source_updates",
+                help: "The number of updates and replacements for all collections in this source",
+                var_labels: ["source_id"],
+            )),
+            delete_documents: registry.register(metric!(
+                name: "mz_mongodb_per_source_deletes",
+                help: "The number of deletes for all collections in this source",
+                var_labels: ["source_id"],
+            )),
+            collections: registry.register(metric!(
+                name: "mz_mongodb_per_source_collections_count",
+                help: "The number of upstream collections for this source",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for MongoDB sources.
+pub(crate) struct MongoDbSourceMetrics {
+    pub(crate) inserts: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) updates: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) deletes: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) ignored: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) total: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) collections: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+}
+
+impl MongoDbSourceMetrics {
+    /// Create a `MongoDbSourceMetrics` from the `MongoDbSourceMetricDefs`.
+    pub(crate) fn new(defs: &MongoDbSourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            inserts: defs
+                .insert_documents
+                .get_delete_on_drop_metric(labels.to_vec()),
+            updates: defs
+                .update_documents
+                .get_delete_on_drop_metric(labels.to_vec()),
+            deletes: defs
+                .delete_documents
+                .get_delete_on_drop_metric(labels.to_vec()),
+            ignored: defs
+                .ignored_events
+                .get_delete_on_drop_metric(labels.to_vec()),
+            total: defs.total_events.get_delete_on_drop_metric(labels.to_vec()),
+            collections: defs.collections.get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
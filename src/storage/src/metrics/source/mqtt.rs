@@ -0,0 +1,67 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for MQTT.
+
+use mz_ore::metric;
+use mz_ore::metrics::{
+    DeleteOnDropCounter, DeleteOnDropGauge, IntCounterVec, MetricsRegistry, UIntGaugeVec,
+};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct MqttSourceMetricDefs {
+    pub(crate) messages: IntCounterVec,
+    pub(crate) acks: IntCounterVec,
+    pub(crate) unacked_messages: UIntGaugeVec,
+}
+
+impl MqttSourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            messages: registry.register(metric!(
+                name: "mz_mqtt_per_source_messages",
+                help: "The number of messages received by this source",
+                var_labels: ["source_id"],
+            )),
+            acks: registry.register(metric!(
+                name: "mz_mqtt_per_source_acks",
+                help: "The number of messages this source acknowledged to the broker",
+                var_labels: ["source_id"],
+            )),
+            unacked_messages: registry.register(metric!(
+                name: "mz_mqtt_per_source_unacked_messages",
+                help: "The number of received messages that this source has yet to acknowledge",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for MQTT sources.
+pub(crate) struct MqttSourceMetrics {
+    pub(crate) messages: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) acks: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) unacked_messages: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+}
+
+impl MqttSourceMetrics {
+    /// Create an `MqttSourceMetrics` from the `MqttSourceMetricDefs`.
+    pub(crate) fn new(defs: &MqttSourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            messages: defs.messages.get_delete_on_drop_metric(labels.to_vec()),
+            acks: defs.acks.get_delete_on_drop_metric(labels.to_vec()),
+            unacked_messages: defs
+                .unacked_messages
+                .get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for NATS JetStream.
+
+use mz_ore::metric;
+use mz_ore::metrics::{
+    DeleteOnDropCounter, DeleteOnDropGauge, IntCounterVec, MetricsRegistry, UIntGaugeVec,
+};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct NatsSourceMetricDefs {
+    pub(crate) fetch_requests: IntCounterVec,
+    pub(crate) messages: IntCounterVec,
+    pub(crate) acks: IntCounterVec,
+    pub(crate) pending_messages: UIntGaugeVec,
+}
+
+impl NatsSourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            fetch_requests: registry.register(metric!(
+                name: "mz_nats_per_source_fetch_requests",
+                help: "The number of fetch requests issued by this source",
+                var_labels: ["source_id"],
+            )),
+            messages: registry.register(metric!(
+                name: "mz_nats_per_source_messages",
+                help: "The number of messages read from the stream of this source",
+                var_labels: ["source_id"],
+            )),
+            acks: registry.register(metric!(
+                name: "mz_nats_per_source_acks",
+                help: "The number of acknowledgements this source sent to its consumer",
+                var_labels: ["source_id"],
+            )),
+            pending_messages: registry.register(metric!(
+                name: "mz_nats_per_source_pending_messages",
+                help: "The number of messages of the stream that this source has yet to read",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for NATS sources.
+pub(crate) struct NatsSourceMetrics {
+    pub(crate) fetch_requests: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) messages: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) acks: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) pending_messages: DeleteOnDropGauge<AtomicU64, Vec<String>>,
+}
+
+impl NatsSourceMetrics {
+    /// Create a `NatsSourceMetrics` from the `NatsSourceMetricDefs`.
+    pub(crate) fn new(defs: &NatsSourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            fetch_requests: defs
+                .fetch_requests
+                .get_delete_on_drop_metric(labels.to_vec()),
+            messages: defs.messages.get_delete_on_drop_metric(labels.to_vec()),
+            acks: defs.acks.get_delete_on_drop_metric(labels.to_vec()),
+            pending_messages: defs
+                .pending_messages
+                .get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
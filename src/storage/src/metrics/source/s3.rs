@@ -0,0 +1,87 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Metrics for S3.
+
+use mz_ore::metric;
+use mz_ore::metrics::{DeleteOnDropCounter, IntCounterVec, MetricsRegistry};
+use mz_repr::GlobalId;
+use prometheus::core::AtomicU64;
+
+#[derive(Clone, Debug)]
+pub(crate) struct S3SourceMetricDefs {
+    pub(crate) list_requests: IntCounterVec,
+    pub(crate) objects_listed: IntCounterVec,
+    pub(crate) objects_ingested: IntCounterVec,
+    pub(crate) objects_failed: IntCounterVec,
+    pub(crate) rows: IntCounterVec,
+}
+
+impl S3SourceMetricDefs {
+    pub(crate) fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            list_requests: registry.register(metric!(
+                name: "mz_s3_per_source_list_requests",
+                help: "The number of times the objects of the bucket were listed for this source",
+                var_labels: ["source_id"],
+            )),
+            objects_listed: registry.register(metric!(
+                name: "mz_s3_per_source_objects_listed",
+                help: "The number of listed objects that matched the pattern of this source, counted once per listing",
+                var_labels: ["source_id"],
+            )),
+            objects_ingested: registry.register(metric!(
+                name: "mz_s3_per_source_objects_ingested",
+                help: "The number of objects ingested by this source",
+                var_labels: ["source_id"],
+            )),
+            objects_failed: registry.register(metric!(
+                name: "mz_s3_per_source_objects_failed",
+                help: "The number of objects that could not be decoded by this source",
+                var_labels: ["source_id"],
+            )),
+            rows: registry.register(metric!(
+                name: "mz_s3_per_source_rows",
+                help: "The number of rows decoded from ingested objects for all tables of this source",
+                var_labels: ["source_id"],
+            )),
+        }
+    }
+}
+
+/// Metrics for S3 sources.
+pub(crate) struct S3SourceMetrics {
+    pub(crate) list_requests: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) objects_listed: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) objects_ingested: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) objects_failed: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+    pub(crate) rows: DeleteOnDropCounter<AtomicU64, Vec<String>>,
+}
+
+impl S3SourceMetrics {
+    /// Create an `S3SourceMetrics` from the `S3SourceMetricDefs`.
+    pub(crate) fn new(defs: &S3SourceMetricDefs, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        Self {
+            list_requests: defs
+                .list_requests
+                .get_delete_on_drop_metric(labels.to_vec()),
+            objects_listed: defs
+                .objects_listed
+                .get_delete_on_drop_metric(labels.to_vec()),
+            objects_ingested: defs
+                .objects_ingested
+                .get_delete_on_drop_metric(labels.to_vec()),
+            objects_failed: defs
+                .objects_failed
+                .get_delete_on_drop_metric(labels.to_vec()),
+            rows: defs.rows.get_delete_on_drop_metric(labels.to_vec()),
+        }
+    }
+}
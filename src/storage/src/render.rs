@@ -309,6 +309,36 @@ pub fn build_ingestion_dataflow(
                     storage_state,
                     base_source_config,
                 ),
+                GenericSourceConnection::Kinesis(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
+                GenericSourceConnection::Nats(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
+                GenericSourceConnection::Mqtt(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
                 GenericSourceConnection::Postgres(c) => crate::render::sources::render_source(
                     mz_scope,
                     root_scope,
@@ -339,6 +369,36 @@ pub fn build_ingestion_dataflow(
                     storage_state,
                     base_source_config,
                 ),
+                GenericSourceConnection::MongoDb(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
+                GenericSourceConnection::S3(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
+                GenericSourceConnection::Iceberg(c) => crate::render::sources::render_source(
+                    mz_scope,
+                    root_scope,
+                    &debug_name,
+                    c,
+                    description.clone(),
+                    feedback,
+                    storage_state,
+                    base_source_config,
+                ),
                 GenericSourceConnection::LoadGenerator(c) => crate::render::sources::render_source(
                     mz_scope,
                     root_scope,
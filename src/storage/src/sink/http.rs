@@ -0,0 +1,808 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`HttpSinkConnection`].
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃   encode    ┃ walks each batch's cursor and encodes
+//!        ┃             ┃ one JSON change per DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ encoded changes
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃    http     ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!  requests │        │ uppers
+//!      ╭────v──╮ ╭───v──────╮
+//!      │ HTTP  │ │   sink   │  <- uppers are advanced once every
+//!      │ endpt │ │  shard   │     request up to them is acknowledged
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Requests
+//!
+//! The sink buffers the encoded changes it receives until the input frontier tells it that a set
+//! of timestamps is complete. The changes at each complete timestamp are sorted, split into
+//! batches of at most `batch_size` changes and POSTed in order, one request per batch. Requests
+//! that fail with a network error, a server error or a `408`/`429` status are retried with
+//! backoff; any other unsuccessful status halts the sink. Redirects are not followed, since the
+//! address they point to has not gone through the external address checks, so a `3xx` status
+//! halts the sink as well.
+//!
+//! # At-least-once
+//!
+//! The sink records its progress as the upper of its own persist shard, which it only advances
+//! once every request for the timestamps before the new upper has been acknowledged. When the
+//! sink restarts it resumes from that upper, which can repeat the requests of the timestamps it
+//! was delivering when it stopped.
+//!
+//! To let receivers discard these repeats, every request carries an `Idempotency-Key` header made
+//! of the sink's ID, the timestamp and the index of the batch among those of the timestamp.
+//! Because the changes of a timestamp are sorted before they are batched, a repeated request has
+//! the same key and the same body as the original.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use base64::Engine;
+use differential_dataflow::{AsCollection, Hashable, VecCollection};
+use hmac::{Hmac, Mac};
+use itertools::Itertools;
+use mz_interchange::encode::column_names_and_types;
+use mz_interchange::envelopes::for_each_diff_pair;
+use mz_interchange::json::encode_datums_as_json;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_ore::netio::resolve_address;
+use mz_ore::retry::{Retry, RetryResult};
+use mz_persist_client::Diagnostics;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::connections::string_or_secret::StringOrSecret;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    HttpSinkBodyFormat, HttpSinkConnection, SinkEnvelope, StorageSinkDesc,
+};
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{Event, OperatorBuilder, PressOnDropButton};
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, LOCATION};
+use sha2::Sha256;
+use timely::PartialOrder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::StreamVec;
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, error, info, warn};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::{PkViolationWarner, SinkBatchStream, SinkRender};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The header that carries the deduplication key of a request.
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// The header that carries the timestamp of the changes in a request.
+const TIMESTAMP_HEADER: &str = "x-materialize-timestamp";
+/// How long to wait for the endpoint to respond to a single request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long to keep retrying a request before halting the sink.
+const RETRY_DURATION: Duration = Duration::from_secs(5 * 60);
+
+impl<'scope> SinkRender<'scope> for HttpSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        batches: SinkBatchStream<'scope>,
+        key_is_synthetic: bool,
+        _err_collection: VecCollection<'scope, Timestamp, DataflowError, Diff>,
+    ) -> (
+        StreamVec<'scope, Timestamp, HealthStatusMessage>,
+        Vec<PressOnDropButton>,
+    ) {
+        let scope = batches.scope();
+
+        let write_handle = {
+            let persist = Arc::clone(&storage_state.persist_clients);
+            let shard_meta = sink.to_storage_metadata.clone();
+            async move {
+                let client = persist.open(shard_meta.persist_location).await?;
+                let handle = client
+                    .open_writer(
+                        shard_meta.data_shard,
+                        Arc::new(shard_meta.relation_desc),
+                        Arc::new(UnitSchema),
+                        Diagnostics::from_purpose("sink handle"),
+                    )
+                    .await?;
+                Ok(handle)
+            }
+        };
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (changes, encode_token) = encode_changes(
+            format!("http-{sink_id}-encode"),
+            batches,
+            sink_id,
+            sink.from,
+            key_is_synthetic,
+            self,
+            sink.envelope,
+        );
+
+        let statistics = storage_state
+            .aggregated_statistics
+            .get_sink(&sink_id)
+            .expect("statistics initialized")
+            .clone();
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("http-{sink_id}-sink"),
+            changes,
+            sink_id,
+            self.clone(),
+            storage_state.storage_configuration.clone(),
+            sink,
+            statistics,
+            write_handle,
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            id: None,
+            update: HealthStatusUpdate::running(),
+            namespace: StatusNamespace::Http,
+        })
+        .to_stream(scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![encode_token, sink_token])
+    }
+}
+
+/// Walks each arrangement batch and encodes one JSON change per `DiffPair`.
+///
+/// With `ENVELOPE UPSERT` a change is `{"key": ..., "value": ...}`, where a `null` value marks the
+/// deletion of the key. With `ENVELOPE DEBEZIUM` a change is `{"before": ..., "after": ...}`,
+/// which also carries the key if the sink has one.
+fn encode_changes<'scope>(
+    name: String,
+    batches: SinkBatchStream<'scope>,
+    sink_id: GlobalId,
+    from_id: GlobalId,
+    key_is_synthetic: bool,
+    connection: &HttpSinkConnection,
+    envelope: SinkEnvelope,
+) -> (
+    VecCollection<'scope, Timestamp, Vec<u8>, Diff>,
+    PressOnDropButton,
+) {
+    let mut builder = OperatorBuilder::new(name, batches.scope());
+    let (output, stream) = builder.new_output::<CapacityContainerBuilder<Vec<_>>>();
+    let mut input = builder.new_input_for(batches, Pipeline, &output);
+
+    let key_columns = connection
+        .key_desc_and_indices
+        .as_ref()
+        .map(|(desc, _indices)| column_names_and_types(desc.clone()));
+    let value_columns = column_names_and_types(connection.value_desc.clone());
+
+    let button = builder.build(move |_caps| async move {
+        let mut pk_warner = (!key_is_synthetic).then(|| PkViolationWarner::new(sink_id, from_id));
+        let encode_value = |value: Option<Row>| match value {
+            Some(row) => encode_datums_as_json(row.iter(), &value_columns),
+            None => serde_json::Value::Null,
+        };
+
+        while let Some(event) = input.next().await {
+            if let Event::Data(cap, mut batches) = event {
+                for batch in batches.drain(..) {
+                    for_each_diff_pair(&batch, |key, time, diff_pair| {
+                        if let Some(warner) = pk_warner.as_mut() {
+                            warner.observe(key, time);
+                        }
+                        let mut change = serde_json::Map::new();
+                        if let Some(columns) = &key_columns {
+                            let key = key.as_ref().expect("keyed sinks have a key");
+                            change.insert("key".into(), encode_datums_as_json(key.iter(), columns));
+                        }
+                        match envelope {
+                            SinkEnvelope::Upsert => {
+                                change.insert("value".into(), encode_value(diff_pair.after));
+                            }
+                            SinkEnvelope::Debezium => {
+                                change.insert("before".into(), encode_value(diff_pair.before));
+                                change.insert("after".into(), encode_value(diff_pair.after));
+                            }
+                            SinkEnvelope::Append => {
+                                unreachable!("HTTP sinks do not support MODE APPEND")
+                            }
+                        }
+                        let change = serde_json::to_vec(&change).expect("JSON is serializable");
+                        output.give(&cap, (change, time, Diff::ONE));
+                    });
+                    // Flush after each batch so the final `(key, time)` group of the walk is
+                    // resolved immediately.
+                    if let Some(warner) = pk_warner.as_mut() {
+                        warner.flush();
+                    }
+                }
+            }
+        }
+    });
+
+    (stream.as_collection(), button.press_on_drop())
+}
+
+fn sink_collection<'scope>(
+    name: String,
+    input: VecCollection<'scope, Timestamp, Vec<u8>, Diff>,
+    sink_id: GlobalId,
+    connection: HttpSinkConnection,
+    storage_configuration: StorageConfiguration,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    statistics: SinkStatistics,
+    write_handle: impl Future<
+        Output = anyhow::Result<WriteHandle<SourceData, (), Timestamp, StorageDiff>>,
+    > + 'static,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (
+    StreamVec<'scope, Timestamp, HealthStatusMessage>,
+    PressOnDropButton,
+) {
+    let scope = input.scope();
+    let mut builder = OperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to send requests, so that they arrive in timestamp order.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+            let resume_upper = write_handle.fetch_recent_upper().await.clone();
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(mut upper) = resume_upper.clone().into_option() else {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            };
+
+            let mut sender =
+                HttpSinkSender::new(sink_id, &connection, &storage_configuration, statistics)
+                    .await?;
+
+            let mut deferred_changes = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (change, time, _diff) in batch {
+                            // Changes before `upper` have already been acknowledged by the
+                            // endpoint during a previous incarnation of this sink.
+                            if time >= upper {
+                                deferred_changes.push((time, change));
+                            }
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier, so
+                        // that a restart with an earlier as_of does not skip the snapshot. See
+                        // the Kafka sink for the full argument.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+
+                        let mut ready: Vec<_> = deferred_changes
+                            .extract_if(.., |(time, _)| !progress.less_equal(time))
+                            .collect();
+                        // Sorting makes the batches of a timestamp, and so their idempotency
+                        // keys, identical across restarts.
+                        ready.sort();
+                        for (time, changes) in &ready.into_iter().chunk_by(|(time, _)| *time) {
+                            let changes: Vec<_> = changes.map(|(_, change)| change).collect();
+                            sender.send(time, changes).await?;
+                        }
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        debug!("{name}: acknowledged up to {}", progress.pretty());
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        match progress.into_option() {
+                            Some(new_upper) => upper = new_upper,
+                            None => break,
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace: StatusNamespace::Http,
+    });
+
+    (statuses, button.press_on_drop())
+}
+
+/// POSTs batches of encoded changes to the endpoint of an HTTP sink.
+struct HttpSinkSender {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    sink_id: GlobalId,
+    /// The headers to send with every request, with all secrets resolved.
+    headers: HeaderMap,
+    /// The header to send the signature in and the key to sign with, if the sink signs requests.
+    signing: Option<(HeaderName, Vec<u8>)>,
+    body_format: HttpSinkBodyFormat,
+    batch_size: usize,
+    statistics: SinkStatistics,
+}
+
+impl HttpSinkSender {
+    /// Resolves the secrets of the sink and builds a client that only connects to the addresses
+    /// the endpoint's host resolves to, so that the sink honors the external address checks.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &HttpSinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<Self, anyhow::Error> {
+        let secrets_reader = &storage_configuration.connection_context.secrets_reader;
+
+        let url: reqwest::Url = connection.url.parse().context("parsing URL")?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow!("URL {} has no host", connection.url))?;
+        let port = url
+            .port_or_known_default()
+            .ok_or_else(|| anyhow!("URL {} has no port", connection.url))?;
+        let enforce_external_addresses =
+            ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set());
+        let addrs: Vec<_> = resolve_address(host, enforce_external_addresses)
+            .await?
+            .into_iter()
+            .map(|ip| SocketAddr::new(ip, port))
+            .collect();
+        let client = Self::client_builder()
+            .resolve_to_addrs(host, &addrs)
+            .build()?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &connection.headers {
+            let value = value.get_string(InTask::Yes, secrets_reader).await?;
+            let mut value = HeaderValue::from_str(&value)
+                .with_context(|| format!("invalid value for header {name}"))?;
+            value.set_sensitive(true);
+            headers.insert(HeaderName::from_bytes(name.as_bytes())?, value);
+        }
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(connection.body_format.content_type()),
+        );
+
+        let signing = match &connection.signing {
+            Some(signing) => {
+                let key = StringOrSecret::Secret(signing.secret)
+                    .get_string(InTask::Yes, secrets_reader)
+                    .await?;
+                let header = HeaderName::from_bytes(signing.header.as_bytes())?;
+                Some((header, key.into_bytes()))
+            }
+            None => None,
+        };
+
+        Ok(HttpSinkSender {
+            client,
+            url,
+            sink_id,
+            headers,
+            signing,
+            body_format: connection.body_format,
+            batch_size: usize::cast_from(connection.batch_size),
+            statistics,
+        })
+    }
+
+    /// Returns a builder for a client that does not follow redirects.
+    fn client_builder() -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(REQUEST_TIMEOUT)
+    }
+
+    /// Sends all the changes at `time`, in batches of at most `batch_size` changes.
+    async fn send(&mut self, time: Timestamp, changes: Vec<Vec<u8>>) -> Result<(), anyhow::Error> {
+        for (index, batch) in changes.chunks(self.batch_size).enumerate() {
+            let body = self.encode_body(batch);
+            let messages = u64::cast_from(batch.len());
+            let bytes = u64::cast_from(body.len());
+            self.statistics.inc_messages_staged_by(messages);
+            self.statistics.inc_bytes_staged_by(bytes);
+
+            let mut headers = self.headers.clone();
+            let idempotency_key = format!("{}-{}-{}", self.sink_id, time, index);
+            headers.insert(
+                IDEMPOTENCY_KEY_HEADER,
+                HeaderValue::from_str(&idempotency_key)?,
+            );
+            headers.insert(TIMESTAMP_HEADER, HeaderValue::from(u64::from(time)));
+            if let Some((header, key)) = &self.signing {
+                let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key");
+                mac.update(&body);
+                let signature =
+                    base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+                headers.insert(header.clone(), HeaderValue::from_str(&signature)?);
+            }
+
+            Retry::default()
+                .initial_backoff(Duration::from_millis(100))
+                .clamp_backoff(Duration::from_secs(30))
+                .max_duration(RETRY_DURATION)
+                .retry_async(|_| {
+                    let idempotency_key = idempotency_key.clone();
+                    let request = self
+                        .client
+                        .post(self.url.clone())
+                        .headers(headers.clone())
+                        .body(body.clone());
+                    async move {
+                        let response = match request.send().await {
+                            Ok(response) => response,
+                            Err(e) => {
+                                warn!("http sink request {idempotency_key} failed: {e}");
+                                return RetryResult::RetryableErr(anyhow::Error::new(e));
+                            }
+                        };
+                        let status = response.status();
+                        if status.is_success() {
+                            return RetryResult::Ok(());
+                        }
+                        if status.is_redirection() {
+                            let location = response
+                                .headers()
+                                .get(LOCATION)
+                                .and_then(|location| location.to_str().ok())
+                                .unwrap_or("<none>");
+                            return RetryResult::FatalErr(anyhow!(
+                                "endpoint responded with {status} to {location}, \
+                                 but HTTP sinks do not follow redirects"
+                            ));
+                        }
+                        let body = response.text().await.unwrap_or_default();
+                        let err = anyhow!("endpoint responded with {status}: {body}");
+                        if status.is_server_error()
+                            || status == StatusCode::REQUEST_TIMEOUT
+                            || status == StatusCode::TOO_MANY_REQUESTS
+                        {
+                            warn!("http sink request {idempotency_key} failed: {err}");
+                            RetryResult::RetryableErr(err)
+                        } else {
+                            RetryResult::FatalErr(err)
+                        }
+                    }
+                })
+                .await?;
+
+            self.statistics.inc_messages_committed_by(messages);
+            self.statistics.inc_bytes_committed_by(bytes);
+        }
+        Ok(())
+    }
+
+    /// Lays out a batch of encoded changes in the sink's body format.
+    fn encode_body(&self, batch: &[Vec<u8>]) -> Vec<u8> {
+        let mut body = vec![];
+        match self.body_format {
+            HttpSinkBodyFormat::Json => {
+                body.push(b'[');
+                for (i, change) in batch.iter().enumerate() {
+                    if i > 0 {
+                        body.push(b',');
+                    }
+                    body.extend_from_slice(change);
+                }
+                body.push(b']');
+            }
+            HttpSinkBodyFormat::Ndjson => {
+                for change in batch {
+                    body.extend_from_slice(change);
+                    body.push(b'\n');
+                }
+            }
+        }
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::Mutex;
+
+    use axum::Router;
+    use axum::body::Bytes;
+    use axum::extract::State;
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::post;
+    use mz_ore::metrics::MetricsRegistry;
+    use tokio::net::TcpListener;
+
+    use crate::statistics::SinkStatisticsMetricDefs;
+
+    use super::*;
+
+    /// A fake endpoint that records the requests it receives.
+    #[derive(Default)]
+    struct Endpoint {
+        /// The statuses to respond with, in order, before responding with `200 OK`.
+        statuses: Mutex<VecDeque<StatusCode>>,
+        /// The path, headers and body of every request received.
+        requests: Mutex<Vec<(String, HeaderMap, Bytes)>>,
+    }
+
+    async fn record(
+        State(endpoint): State<Arc<Endpoint>>,
+        uri: axum::http::Uri,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Response {
+        endpoint.requests.lock().expect("lock poisoned").push((
+            uri.path().to_string(),
+            headers,
+            body,
+        ));
+        let status = endpoint
+            .statuses
+            .lock()
+            .expect("lock poisoned")
+            .pop_front()
+            .unwrap_or(StatusCode::OK);
+        if status.is_redirection() {
+            (status, [(LOCATION, "/elsewhere")]).into_response()
+        } else {
+            status.into_response()
+        }
+    }
+
+    /// Starts a fake endpoint and returns a sender that POSTs to it.
+    async fn start_endpoint(batch_size: usize) -> (Arc<Endpoint>, HttpSinkSender) {
+        let endpoint = Arc::new(Endpoint::default());
+        let app = Router::new()
+            .route("/", post(record))
+            .route("/elsewhere", post(record))
+            .with_state(Arc::clone(&endpoint));
+
+        // Use port 0 to get a dynamically assigned port.
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+        let tcp = TcpListener::bind(addr).await.expect("able to bind");
+        let addr = tcp.local_addr().expect("valid addr");
+        mz_ore::task::spawn(|| "test-server", async move {
+            axum::serve(tcp, app.into_make_service()).await.unwrap();
+        });
+
+        let sink_id = GlobalId::User(1);
+        let metrics = SinkStatisticsMetricDefs::register_with(&MetricsRegistry::new());
+        let sender = HttpSinkSender {
+            client: HttpSinkSender::client_builder().build().unwrap(),
+            url: format!("http://{addr}/").parse().unwrap(),
+            sink_id,
+            headers: HeaderMap::new(),
+            signing: Some((HeaderName::from_static("x-signature"), b"secret".to_vec())),
+            body_format: HttpSinkBodyFormat::Json,
+            batch_size,
+            statistics: SinkStatistics::new(sink_id, 0, &metrics),
+        };
+        (endpoint, sender)
+    }
+
+    fn changes(ids: std::ops::RangeInclusive<u32>) -> Vec<Vec<u8>> {
+        ids.map(|id| format!(r#"{{"id":{id}}}"#).into_bytes())
+            .collect()
+    }
+
+    fn header<'a>(headers: &'a HeaderMap, name: &str) -> &'a str {
+        headers.get(name).unwrap().to_str().unwrap()
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn batches_and_signs_requests() {
+        let (endpoint, mut sender) = start_endpoint(2).await;
+        sender
+            .send(Timestamp::from(7), changes(1..=5))
+            .await
+            .unwrap();
+
+        let requests = endpoint.requests.lock().unwrap();
+        let requests: Vec<_> = requests
+            .iter()
+            .map(|(_, headers, body)| {
+                (
+                    header(headers, IDEMPOTENCY_KEY_HEADER),
+                    header(headers, TIMESTAMP_HEADER),
+                    header(headers, "x-signature"),
+                    std::str::from_utf8(body).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            requests,
+            [
+                (
+                    "u1-7-0",
+                    "7",
+                    "mdQPcqqOeDrlBw4efbC1WvH0qgJk8Qtq7+ja9x0z1n4=",
+                    r#"[{"id":1},{"id":2}]"#,
+                ),
+                (
+                    "u1-7-1",
+                    "7",
+                    "25LK2AK6QN+OAAOUiPmNdPHEwBZ/qLZ6kLYzaPzNAhk=",
+                    r#"[{"id":3},{"id":4}]"#,
+                ),
+                (
+                    "u1-7-2",
+                    "7",
+                    "K4+pPnv0KixqLcMb8fAm036JcLLz50dEwWArsN/N0IA=",
+                    r#"[{"id":5}]"#,
+                ),
+            ]
+        );
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn retries_with_the_same_idempotency_key() {
+        let (endpoint, mut sender) = start_endpoint(10).await;
+        endpoint.statuses.lock().unwrap().extend([
+            StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::REQUEST_TIMEOUT,
+            StatusCode::TOO_MANY_REQUESTS,
+        ]);
+        sender
+            .send(Timestamp::from(7), changes(1..=2))
+            .await
+            .unwrap();
+
+        let requests = endpoint.requests.lock().unwrap();
+        assert_eq!(requests.len(), 5);
+        for (_, headers, body) in requests.iter() {
+            assert_eq!(header(headers, IDEMPOTENCY_KEY_HEADER), "u1-7-0");
+            assert_eq!(body, &requests[0].2);
+        }
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn halts_on_other_statuses() {
+        for status in [
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::NOT_FOUND,
+            StatusCode::MOVED_PERMANENTLY,
+            StatusCode::TEMPORARY_REDIRECT,
+        ] {
+            let (endpoint, mut sender) = start_endpoint(10).await;
+            endpoint.statuses.lock().unwrap().push_back(status);
+            let err = sender
+                .send(Timestamp::from(7), changes(1..=2))
+                .await
+                .unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with(&format!("endpoint responded with {status}")),
+                "unexpected error for {status}: {err}"
+            );
+
+            // Redirects are not followed.
+            let requests = endpoint.requests.lock().unwrap();
+            let paths: Vec<_> = requests.iter().map(|(path, _, _)| path.as_str()).collect();
+            assert_eq!(paths, ["/"], "unexpected requests for {status}");
+        }
+    }
+}
@@ -71,7 +71,10 @@
 //! It matches rows to batches by timestamp; if a batch description hasn't arrived yet,
 //! rows are stashed until it does. This allows batches to be minted ahead of data arrival.
 //! The operator uses an Iceberg `DeltaWriter` to write Parquet data files
-//! (and position delete files if necessary) to object storage.
+//! (and position delete files if necessary) to object storage. If the table is
+//! partitioned, each batch of rows is split by the table's default partition
+//! spec and every partition gets its own writer, so all data and delete files
+//! belong to exactly one partition.
 //! It outputs metadata about the written files along with their batch descriptions
 //! for the commit operator to consume.
 //!
@@ -81,12 +84,38 @@
 //! Iceberg snapshots that include all files for each batch. It updates the Iceberg
 //! table's metadata to reflect the new snapshots, including updating the
 //! `mz-frontier` property to track progress.
+//!
+//! # Schema evolution
+//! When the minter loads an existing table whose schema lacks columns of the
+//! sinked relation, e.g. after `ALTER TABLE ... ADD COLUMN` and `ALTER SINK
+//! ... SET FROM`, it adds them to the table as optional fields with fresh field
+//! IDs before any data is written. Other changes, like dropping a column, are
+//! not supported. Every snapshot records the relation version it was written
+//! from in the `mz-relation-version` property.
+//!
+//! # Partitioning
+//! A sink creates its table with the partition spec given by its `PARTITION
+//! BY` option, but always writes with the table's current default spec, so
+//! the spec of an existing table can be evolved by other engines. If the
+//! default spec changes while the sink is running, the commit operator fails
+//! the batch whose files were written with the old spec, and the restarted
+//! sink rewrites it with the new one.
+//!
+//! # Table maintenance
+//! With `COMPACTION INTERVAL` set, the commit operator periodically rewrites
+//! small data files and resolves delete files into data files. The rewrite is
+//! committed as a `replace` snapshot tagged with `mz-maintenance`, which
+//! frontier recovery skips. With `SNAPSHOT RETENTION` set, it also expires
+//! snapshots older than the retention, always keeping the current snapshot and
+//! the latest one that records a frontier. Maintenance runs between batch
+//! commits, and a failed maintenance run is logged and retried later rather
+//! than halting the sink.
 
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::convert::Infallible;
 use std::future::Future;
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::{cell::RefCell, rc::Rc, sync::Arc};
 
 use anyhow::{Context, anyhow};
@@ -94,16 +123,20 @@ use arrow::array::{ArrayRef, Int32Array, Int64Array, RecordBatch};
 use arrow::datatypes::{DataType, Field, Schema as ArrowSchema};
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::{AsCollection, Hashable, VecCollection};
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use iceberg::ErrorKind;
-use iceberg::arrow::{arrow_schema_to_schema, schema_to_arrow_schema};
+use iceberg::arrow::{
+    ArrowReaderBuilder, RecordBatchPartitionSplitter, arrow_schema_to_schema,
+    schema_to_arrow_schema,
+};
 use iceberg::spec::{
-    DataFile, FormatVersion, Snapshot, StructType, read_data_files_from_avro,
-    write_data_files_to_avro,
+    DataContentType, DataFile, FormatVersion, ListType, MAIN_BRANCH, MapType, NestedField,
+    NestedFieldRef, PartitionSpecRef, Snapshot, Struct, StructType, TableMetadata, Transform,
+    UnboundPartitionSpec, read_data_files_from_avro, write_data_files_to_avro,
 };
-use iceberg::spec::{Schema, SchemaRef};
+use iceberg::spec::{Schema, SchemaRef, Type};
 use iceberg::table::Table;
-use iceberg::transaction::{ApplyTransactionAction, Transaction};
+use iceberg::transaction::{ActionCommit, ApplyTransactionAction, Transaction, TransactionAction};
 use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
 use iceberg::writer::base_writer::equality_delete_writer::{
     EqualityDeleteFileWriterBuilder, EqualityDeleteWriterConfig,
@@ -118,7 +151,7 @@ use iceberg::writer::file_writer::location_generator::{
 };
 use iceberg::writer::file_writer::rolling_writer::RollingFileWriterBuilder;
 use iceberg::writer::{IcebergWriter, IcebergWriterBuilder};
-use iceberg::{Catalog, NamespaceIdent, TableCreation, TableIdent};
+use iceberg::{Catalog, NamespaceIdent, TableCreation, TableIdent, TableRequirement, TableUpdate};
 use itertools::Itertools;
 use mz_arrow_util::builder::{ARROW_EXTENSION_NAME_KEY, ArrowBuilder};
 use mz_interchange::avro::DiffPair;
@@ -137,7 +170,8 @@ use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
 use mz_storage_types::sinks::{
-    IcebergSinkConnection, SinkEnvelope, StorageSinkDesc, iceberg_type_overrides,
+    IcebergPartitionField, IcebergPartitionTransform, IcebergSinkConnection, SinkEnvelope,
+    StorageSinkDesc, iceberg_type_overrides,
 };
 use mz_storage_types::sources::SourceData;
 use mz_timely_util::antichain::AntichainExt;
@@ -173,6 +207,13 @@ const PARQUET_FILE_PREFIX: &str = "mz_data";
 /// The number of batch descriptions to mint ahead of the observed frontier. This determines how
 /// many batches we have in-flight at any given time.
 const INITIAL_DESCRIPTIONS_TO_MINT: u64 = 3;
+/// Compaction rewrites data files smaller than this.
+const COMPACTION_TARGET_FILE_SIZE_BYTES: u64 = 128 * 1024 * 1024;
+/// The snapshot property that marks snapshots committed by the sink's own
+/// table maintenance, which carry no frontier.
+const MAINTENANCE_SNAPSHOT_PROPERTY: &str = "mz-maintenance";
+/// The longest time between checks for snapshots to expire.
+const SNAPSHOT_EXPIRATION_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 /// Shared state produced by the async setup in [`write_data_files`] that both
 /// envelope handlers need to construct Parquet writers.
@@ -181,6 +222,8 @@ struct WriterContext {
     arrow_schema: Arc<ArrowSchema>,
     /// Iceberg table schema, used to configure Parquet writers.
     current_schema: Arc<Schema>,
+    /// The table's default partition spec, which every written file belongs to.
+    partition_spec: PartitionSpecRef,
     /// File I/O for writing Parquet files to object storage.
     file_io: iceberg::io::FileIO,
     /// Generates file paths under the table's data directory.
@@ -190,6 +233,100 @@ struct WriterContext {
     writer_properties: WriterProperties,
 }
 
+impl WriterContext {
+    /// Builds a writer from `builder`, splitting rows by partition if the
+    /// table is partitioned.
+    async fn build_writer<B: IcebergWriterBuilder>(
+        &self,
+        builder: B,
+    ) -> anyhow::Result<Box<dyn IcebergWriter>> {
+        build_partitioned_writer(builder, &self.current_schema, &self.partition_spec).await
+    }
+}
+
+/// Builds a writer from `builder` for a table with the given schema and
+/// partition spec, splitting rows by partition if the spec is partitioned.
+async fn build_partitioned_writer<B: IcebergWriterBuilder>(
+    builder: B,
+    schema: &SchemaRef,
+    partition_spec: &PartitionSpecRef,
+) -> anyhow::Result<Box<dyn IcebergWriter>> {
+    if partition_spec.is_unpartitioned() {
+        return Ok(Box::new(builder.build(None).await?));
+    }
+    let splitter = RecordBatchPartitionSplitter::try_new_with_computed_values(
+        Arc::clone(schema),
+        Arc::clone(partition_spec),
+    )
+    .context("Failed to create partition splitter")?;
+    Ok(Box::new(PartitionedWriter {
+        builder,
+        splitter,
+        writers: Default::default(),
+    }))
+}
+
+/// Returns a generator for data file paths under the table's data directory.
+fn data_location_generator(metadata: &TableMetadata) -> DefaultLocationGenerator {
+    // WORKAROUND: S3 Tables catalog incorrectly sets location to the metadata file path
+    // instead of the warehouse root. Strip off the /metadata/*.metadata.json suffix.
+    // No clear way to detect this properly right now, so we use heuristics.
+    let location = metadata.location();
+    let corrected_location = match location.rsplit_once("/metadata/") {
+        Some((a, b)) if b.ends_with(".metadata.json") => a,
+        _ => location,
+    };
+
+    let data_location = format!("{}/data", corrected_location);
+    DefaultLocationGenerator::with_data_location(data_location)
+}
+
+/// Returns a generator for data file names that are unique across restarts
+/// and workers.
+fn unique_file_name_generator() -> DefaultFileNameGenerator {
+    let unique_suffix = format!("-{}", uuid::Uuid::new_v4());
+    DefaultFileNameGenerator::new(
+        PARQUET_FILE_PREFIX.to_string(),
+        Some(unique_suffix),
+        iceberg::spec::DataFileFormat::Parquet,
+    )
+}
+
+/// An [`IcebergWriter`] for partitioned tables. It splits every record batch by
+/// partition and hands each part to a writer for that partition, created from
+/// `builder` the first time the partition is seen.
+struct PartitionedWriter<B: IcebergWriterBuilder> {
+    builder: B,
+    splitter: RecordBatchPartitionSplitter,
+    #[allow(clippy::disallowed_types)]
+    writers: std::collections::HashMap<Struct, B::R>,
+}
+
+#[async_trait::async_trait]
+impl<B: IcebergWriterBuilder> IcebergWriter for PartitionedWriter<B> {
+    async fn write(&mut self, batch: RecordBatch) -> iceberg::Result<()> {
+        for (partition_key, batch) in self.splitter.split(&batch)? {
+            let writer = match self.writers.entry(partition_key.data().clone()) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    let writer = self.builder.clone().build(Some(partition_key)).await?;
+                    entry.insert(writer)
+                }
+            };
+            writer.write(batch).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> iceberg::Result<Vec<DataFile>> {
+        let mut data_files = Vec::new();
+        for (_, mut writer) in self.writers.drain() {
+            data_files.extend(writer.close().await?);
+        }
+        Ok(data_files)
+    }
+}
+
 /// Envelope-specific logic for writing Iceberg data files.
 trait EnvelopeHandler: Send {
     /// Construct from the shared writer context after async setup completes.
@@ -341,12 +478,10 @@ impl EnvelopeHandler for UpsertEnvelopeHandler {
             builder.with_max_seen_rows(usize::MAX)
         };
 
-        Ok(Box::new(
-            builder
-                .build(None)
-                .await
-                .context("Failed to create DeltaWriter")?,
-        ))
+        self.ctx
+            .build_writer(builder)
+            .await
+            .context("Failed to create DeltaWriter")
     }
 
     /// The `__op` column indicates whether each row is an insert (+1) or delete (-1),
@@ -429,12 +564,10 @@ impl EnvelopeHandler for AppendEnvelopeHandler {
             self.ctx.location_generator.clone(),
             self.ctx.file_name_generator.clone(),
         );
-        Ok(Box::new(
-            DataFileWriterBuilder::new(data_rolling_writer)
-                .build(None)
-                .await
-                .context("Failed to create DataFileWriter")?,
-        ))
+        self.ctx
+            .build_writer(DataFileWriterBuilder::new(data_rolling_writer))
+            .await
+            .context("Failed to create DataFileWriter")
     }
 
     /// Every change is written as a plain data row: the `before` half (if present) gets
@@ -745,12 +878,10 @@ async fn reload_table(
     let namespace_ident = NamespaceIdent::new(namespace.clone());
     let table_ident = TableIdent::new(namespace_ident, table_name.clone());
     let current_schema = current_table.metadata().current_schema_id();
-    let current_partition_spec = current_table.metadata().default_partition_spec_id();
 
     match catalog.load_table(&table_ident).await {
         Ok(table) => {
             let reloaded_schema = table.metadata().current_schema_id();
-            let reloaded_partition_spec = table.metadata().default_partition_spec_id();
             if reloaded_schema != current_schema {
                 return Err(anyhow::anyhow!(
                     "Iceberg table '{}' schema changed during operation but schema evolution isn't supported, expected schema ID {}, got {}",
@@ -760,14 +891,8 @@ async fn reload_table(
                 ));
             }
 
-            if reloaded_partition_spec != current_partition_spec {
-                return Err(anyhow::anyhow!(
-                    "Iceberg table '{}' partition spec changed during operation but partition spec evolution isn't supported, expected partition spec ID {}, got {}",
-                    table_name,
-                    current_partition_spec,
-                    reloaded_partition_spec
-                ));
-            }
+            // The partition spec may be evolved by other engines. Files written
+            // with a previous spec are caught in `try_commit_batch`.
 
             Ok(table)
         }
@@ -781,6 +906,7 @@ async fn reload_table(
 async fn try_commit_batch(
     mut table: Table,
     snapshot_properties: Vec<(String, String)>,
+    partition_spec_ids: &BTreeSet<i32>,
     data_files: Vec<DataFile>,
     delete_files: Vec<DataFile>,
     catalog: &dyn Catalog,
@@ -792,6 +918,23 @@ async fn try_commit_batch(
     batch_upper: &Antichain<Timestamp>,
     metrics: &IcebergSinkMetrics,
 ) -> (Table, RetryResult<(), anyhow::Error>) {
+    // Files can only be added with the table's default partition spec. If it
+    // changed since the files were written, fail so that the restarted sink
+    // rewrites the batch with the new spec.
+    let default_spec_id = table.metadata().default_partition_spec_id();
+    if let Some(partition_spec_id) = partition_spec_ids.iter().find(|id| **id != default_spec_id) {
+        return (
+            table,
+            RetryResult::FatalErr(anyhow!(
+                "Iceberg table '{}' partition spec changed from ID {} to {}, \
+                 restarting to write with the new spec",
+                conn_table,
+                partition_spec_id,
+                default_spec_id,
+            )),
+        );
+    }
+
     let tx = Transaction::new(&table);
     let mut action = tx
         .row_delta()
@@ -915,6 +1058,7 @@ async fn load_or_create_table(
     namespace: String,
     table_name: String,
     schema: &Schema,
+    partition_by: &[IcebergPartitionField],
 ) -> anyhow::Result<iceberg::table::Table> {
     let namespace_ident = NamespaceIdent::new(namespace.clone());
     let table_ident = TableIdent::new(namespace_ident.clone(), table_name.clone());
@@ -922,7 +1066,8 @@ async fn load_or_create_table(
     // Try to load the table first
     match catalog.load_table(&table_ident).await {
         Ok(table) => {
-            // Table exists, return it
+            // Table exists, return it. We keep its partition spec even if it
+            // differs from `partition_by`.
             // TODO: Add proper schema evolution/validation to ensure compatibility
             Ok(table)
         }
@@ -938,9 +1083,7 @@ async fn load_or_create_table(
                 let table_creation = TableCreation::builder()
                     .name(table_name.clone())
                     .schema(schema.clone())
-                    // Use unpartitioned spec by default
-                    // TODO: Consider making partition spec configurable
-                    // .partition_spec(UnboundPartitionSpec::builder().build())
+                    .partition_spec(partition_spec(schema, partition_by)?)
                     .build();
 
                 catalog
@@ -960,9 +1103,417 @@ async fn load_or_create_table(
     }
 }
 
+/// Add the columns of `desired` that `table` lacks to the table's schema.
+///
+/// Existing columns keep their field IDs, and new ones are assigned IDs past
+/// the table's last assigned ID and made optional, as rows already in the table
+/// have no value for them. Columns take the position they have in `desired`.
+async fn evolve_table_schema(
+    catalog: &dyn Catalog,
+    table: Table,
+    desired: &Schema,
+) -> anyhow::Result<Table> {
+    let metadata = table.metadata();
+    let Some(schema) = evolved_schema(
+        metadata.current_schema(),
+        desired,
+        metadata.last_column_id(),
+        metadata
+            .schemas_iter()
+            .map(|s| s.schema_id())
+            .max()
+            .unwrap_or(0)
+            + 1,
+    )?
+    else {
+        return Ok(table);
+    };
+    let action = AddSchemaAction {
+        schema,
+        base_schema_id: metadata.current_schema_id(),
+        base_last_column_id: metadata.last_column_id(),
+    };
+    let tx = Transaction::new(&table);
+    let tx = action
+        .apply(tx)
+        .context("Failed to apply schema update to iceberg table transaction")?;
+    tx.commit(catalog)
+        .await
+        .context("Failed to commit Iceberg table schema update")
+}
+
+/// Computes the schema that results from adding the top-level fields of
+/// `desired` missing from `current`, or `None` if there are none.
+fn evolved_schema(
+    current: &Schema,
+    desired: &Schema,
+    last_column_id: i32,
+    schema_id: i32,
+) -> anyhow::Result<Option<Schema>> {
+    for field in current.as_struct().fields() {
+        if desired.field_by_name(&field.name).is_none() {
+            anyhow::bail!(
+                "column '{}' of the Iceberg table is missing from the sinked relation; \
+                 dropping or renaming columns is not supported",
+                field.name
+            );
+        }
+    }
+
+    let mut next_id = last_column_id + 1;
+    let mut added = false;
+    let fields = desired
+        .as_struct()
+        .fields()
+        .iter()
+        .map(|field| match current.field_by_name(&field.name) {
+            Some(existing) => Arc::clone(existing),
+            None => {
+                added = true;
+                let mut field = reassign_field_ids(field, &mut next_id);
+                Arc::make_mut(&mut field).required = false;
+                field
+            }
+        })
+        .collect();
+    if !added {
+        return Ok(None);
+    }
+
+    let schema = Schema::builder()
+        .with_schema_id(schema_id)
+        .with_identifier_field_ids(current.identifier_field_ids())
+        .with_fields(fields)
+        .build()
+        .context("Failed to build evolved Iceberg schema")?;
+    Ok(Some(schema))
+}
+
+/// Returns a copy of `field` with fresh field IDs, assigned depth-first
+/// starting at `next_id`.
+fn reassign_field_ids(field: &NestedField, next_id: &mut i32) -> NestedFieldRef {
+    let mut field = field.clone();
+    field.id = *next_id;
+    *next_id += 1;
+    let field_type = match field.field_type.as_ref() {
+        Type::Primitive(primitive) => Type::Primitive(primitive.clone()),
+        Type::Struct(fields) => Type::Struct(StructType::new(
+            fields
+                .fields()
+                .iter()
+                .map(|f| reassign_field_ids(f, next_id))
+                .collect(),
+        )),
+        Type::List(list) => Type::List(ListType {
+            element_field: reassign_field_ids(&list.element_field, next_id),
+        }),
+        Type::Map(map) => Type::Map(MapType {
+            key_field: reassign_field_ids(&map.key_field, next_id),
+            value_field: reassign_field_ids(&map.value_field, next_id),
+        }),
+    };
+    field.field_type = Box::new(field_type);
+    Arc::new(field)
+}
+
+/// Adds a schema to a table and makes it the current schema, provided no one
+/// changed the table's schema since it was computed.
+struct AddSchemaAction {
+    schema: Schema,
+    base_schema_id: i32,
+    base_last_column_id: i32,
+}
+
+#[async_trait::async_trait]
+impl TransactionAction for AddSchemaAction {
+    async fn commit(self: Arc<Self>, _table: &Table) -> iceberg::Result<ActionCommit> {
+        let updates = vec![
+            TableUpdate::AddSchema {
+                schema: self.schema.clone(),
+            },
+            // -1 refers to the schema added by this commit.
+            TableUpdate::SetCurrentSchema { schema_id: -1 },
+        ];
+        let requirements = vec![
+            TableRequirement::CurrentSchemaIdMatch {
+                current_schema_id: self.base_schema_id,
+            },
+            TableRequirement::LastAssignedFieldIdMatch {
+                last_assigned_field_id: self.base_last_column_id,
+            },
+        ];
+        Ok(ActionCommit::new(updates, requirements))
+    }
+}
+
+/// Compacts the current snapshot of `table`.
+///
+/// A partition is compacted if it has delete files or more than one data file
+/// smaller than [`COMPACTION_TARGET_FILE_SIZE_BYTES`]. If it has delete files,
+/// all its data files are rewritten with the deletes applied and the delete
+/// files are removed. Otherwise only its small data files are rewritten.
+/// Partitions written with a spec other than the default one are left alone.
+///
+/// The rewrite is committed as a `replace` snapshot tagged with
+/// [`MAINTENANCE_SNAPSHOT_PROPERTY`]. Returns the updated table, or `None` if
+/// there was nothing to compact.
+async fn compact_table(catalog: &dyn Catalog, table: &Table) -> anyhow::Result<Option<Table>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(None);
+    };
+    let file_io = table.file_io();
+
+    // Group the live files of the current snapshot by partition.
+    #[allow(clippy::disallowed_types)]
+    let mut partitions: std::collections::HashMap<Struct, (Vec<DataFile>, Vec<DataFile>)> =
+        std::collections::HashMap::new();
+    let manifest_list = snapshot
+        .load_manifest_list(file_io, metadata)
+        .await
+        .context("Failed to load Iceberg manifest list")?;
+    for manifest_file in manifest_list.entries() {
+        if manifest_file.partition_spec_id != metadata.default_partition_spec_id() {
+            continue;
+        }
+        let manifest = manifest_file
+            .load_manifest(file_io)
+            .await
+            .context("Failed to load Iceberg manifest")?;
+        for entry in manifest.entries().iter().filter(|e| e.is_alive()) {
+            let file = entry.data_file().clone();
+            let (data_files, delete_files) =
+                partitions.entry(file.partition().clone()).or_default();
+            match file.content_type() {
+                DataContentType::Data => data_files.push(file),
+                DataContentType::PositionDeletes | DataContentType::EqualityDeletes => {
+                    delete_files.push(file)
+                }
+            }
+        }
+    }
+
+    let mut rewritten_data_files = Vec::new();
+    let mut removed_delete_files = Vec::new();
+    for (data_files, delete_files) in partitions.into_values() {
+        if !delete_files.is_empty() {
+            rewritten_data_files.extend(data_files);
+            removed_delete_files.extend(delete_files);
+        } else {
+            let small: Vec<_> = data_files
+                .into_iter()
+                .filter(|f| f.file_size_in_bytes() < COMPACTION_TARGET_FILE_SIZE_BYTES)
+                .collect();
+            if small.len() > 1 {
+                rewritten_data_files.extend(small);
+            }
+        }
+    }
+    if rewritten_data_files.is_empty() && removed_delete_files.is_empty() {
+        return Ok(None);
+    }
+
+    // Read the rewritten files with their deletes applied.
+    let paths: BTreeSet<_> = rewritten_data_files
+        .iter()
+        .map(|f| f.file_path().to_string())
+        .collect();
+    let tasks: Vec<_> = table
+        .scan()
+        .snapshot_id(snapshot.snapshot_id())
+        .build()
+        .context("Failed to build Iceberg table scan")?
+        .plan_files()
+        .await
+        .context("Failed to plan Iceberg table scan")?
+        .try_collect::<Vec<_>>()
+        .await
+        .context("Failed to plan Iceberg table scan")?
+        .into_iter()
+        .filter(|task| paths.contains(&task.data_file_path))
+        .collect();
+    let mut batches = ArrowReaderBuilder::new(file_io.clone())
+        .build()
+        .read(Box::pin(futures::stream::iter(tasks.into_iter().map(Ok))))
+        .context("Failed to read Iceberg data files")?;
+
+    let schema = Arc::clone(metadata.current_schema());
+    let parquet_writer = ParquetWriterBuilder::new(WriterProperties::new(), Arc::clone(&schema));
+    let rolling_writer = RollingFileWriterBuilder::new_with_default_file_size(
+        parquet_writer,
+        Arc::clone(&schema),
+        file_io.clone(),
+        data_location_generator(metadata),
+        unique_file_name_generator(),
+    );
+    let mut writer = build_partitioned_writer(
+        DataFileWriterBuilder::new(rolling_writer),
+        &schema,
+        metadata.default_partition_spec(),
+    )
+    .await
+    .context("Failed to create DataFileWriter")?;
+    while let Some(batch) = batches.next().await {
+        let batch = batch.context("Failed to read Iceberg data files")?;
+        writer
+            .write(batch)
+            .await
+            .context("Failed to write compacted data file")?;
+    }
+    let added_data_files = writer
+        .close()
+        .await
+        .context("Failed to close compacted data file writer")?;
+
+    debug!(
+        rewritten_data_files = rewritten_data_files.len(),
+        removed_delete_files = removed_delete_files.len(),
+        added_data_files = added_data_files.len(),
+        "iceberg compaction rewriting files"
+    );
+
+    let tx = Transaction::new(table);
+    let tx = tx
+        .rewrite_files()
+        .set_snapshot_properties(
+            [(
+                MAINTENANCE_SNAPSHOT_PROPERTY.to_string(),
+                "compaction".to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        )
+        .remove_data_files(rewritten_data_files)
+        .remove_delete_files(removed_delete_files)
+        .add_data_files(added_data_files)
+        .apply(tx)
+        .context("Failed to apply compaction to iceberg table transaction")?;
+    let table = tx
+        .commit(catalog)
+        .await
+        .context("Failed to commit Iceberg table compaction")?;
+    Ok(Some(table))
+}
+
+/// Expires the snapshots of `table` that are older than `retention`.
+///
+/// The current snapshot and the latest snapshot that records a frontier, which
+/// the sink needs to resume, are always kept. Expiring only removes snapshots
+/// from the table metadata. The files they reference are left for the
+/// catalog's unreferenced file removal. Returns the updated table, or `None`
+/// if no snapshot expired.
+async fn expire_snapshots(
+    catalog: &dyn Catalog,
+    table: &Table,
+    retention: Duration,
+) -> anyhow::Result<Option<Table>> {
+    let metadata = table.metadata();
+    let Some(current) = metadata.current_snapshot() else {
+        return Ok(None);
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("system time before Unix epoch")?;
+    let cutoff_ms = i64::try_from(now.saturating_sub(retention).as_millis()).unwrap_or(i64::MAX);
+    let frontier_snapshot = metadata
+        .snapshots()
+        .filter(|s| {
+            s.summary()
+                .additional_properties
+                .contains_key("mz-frontier")
+        })
+        .max_by_key(|s| s.sequence_number())
+        .map(|s| s.snapshot_id());
+
+    let snapshot_ids: Vec<_> = metadata
+        .snapshots()
+        .filter(|s| s.timestamp_ms() < cutoff_ms)
+        .map(|s| s.snapshot_id())
+        .filter(|id| *id != current.snapshot_id() && Some(*id) != frontier_snapshot)
+        .collect();
+    if snapshot_ids.is_empty() {
+        return Ok(None);
+    }
+    debug!(
+        expired_snapshots = snapshot_ids.len(),
+        "iceberg expiring snapshots"
+    );
+
+    let action = RemoveSnapshotsAction {
+        snapshot_ids,
+        current_snapshot_id: current.snapshot_id(),
+    };
+    let tx = Transaction::new(table);
+    let tx = action
+        .apply(tx)
+        .context("Failed to apply snapshot expiration to iceberg table transaction")?;
+    let table = tx
+        .commit(catalog)
+        .await
+        .context("Failed to commit Iceberg snapshot expiration")?;
+    Ok(Some(table))
+}
+
+/// Removes snapshots from a table, provided its main branch still points at
+/// the snapshot that was current when they were chosen.
+struct RemoveSnapshotsAction {
+    snapshot_ids: Vec<i64>,
+    current_snapshot_id: i64,
+}
+
+#[async_trait::async_trait]
+impl TransactionAction for RemoveSnapshotsAction {
+    async fn commit(self: Arc<Self>, _table: &Table) -> iceberg::Result<ActionCommit> {
+        let updates = vec![TableUpdate::RemoveSnapshots {
+            snapshot_ids: self.snapshot_ids.clone(),
+        }];
+        let requirements = vec![TableRequirement::RefSnapshotIdMatch {
+            r#ref: MAIN_BRANCH.to_string(),
+            snapshot_id: Some(self.current_snapshot_id),
+        }];
+        Ok(ActionCommit::new(updates, requirements))
+    }
+}
+
+/// Build the partition spec for a new table from the sink's `PARTITION BY`
+/// option. Partition fields are named the way other engines name them, e.g.
+/// `id_bucket` for `bucket(16, id)`.
+fn partition_spec(
+    schema: &Schema,
+    partition_by: &[IcebergPartitionField],
+) -> anyhow::Result<UnboundPartitionSpec> {
+    let mut builder = UnboundPartitionSpec::builder();
+    for field in partition_by {
+        let source = schema.field_by_name(&field.column).ok_or_else(|| {
+            anyhow!(
+                "partition column '{}' does not exist in the table schema",
+                field.column
+            )
+        })?;
+        let (transform, suffix) = match field.transform {
+            IcebergPartitionTransform::Identity => (Transform::Identity, None),
+            IcebergPartitionTransform::Bucket(n) => (Transform::Bucket(n), Some("bucket")),
+            IcebergPartitionTransform::Truncate(n) => (Transform::Truncate(n), Some("trunc")),
+            IcebergPartitionTransform::Year => (Transform::Year, Some("year")),
+            IcebergPartitionTransform::Month => (Transform::Month, Some("month")),
+            IcebergPartitionTransform::Day => (Transform::Day, Some("day")),
+            IcebergPartitionTransform::Hour => (Transform::Hour, Some("hour")),
+        };
+        let name = match suffix {
+            None => field.column.clone(),
+            Some(suffix) => format!("{}_{}", field.column, suffix),
+        };
+        builder = builder
+            .add_partition_field(source.id, name, transform)
+            .with_context(|| format!("Invalid partition field for column '{}'", field.column))?;
+    }
+    Ok(builder.build())
+}
+
 /// Find the most recent Materialize frontier from Iceberg snapshots.
 /// We store the frontier in snapshot metadata to track where we left off after restarts.
-/// Snapshots with operation="replace" (compactions) don't have our metadata and are skipped.
+/// Snapshots committed by the sink's own maintenance, and snapshots with operation="replace"
+/// (compactions by other engines), don't have our metadata and are skipped.
 /// The input slice will be sorted by sequence number in descending order.
 fn retrieve_upper_from_snapshots(
     snapshots: &mut [Arc<Snapshot>],
@@ -984,13 +1535,16 @@ fn retrieve_upper_from_snapshots(
 
             return Ok(Some((frontier, sink_version)));
         }
+        if props.contains_key(MAINTENANCE_SNAPSHOT_PROPERTY) {
+            continue;
+        }
         if snapshot.summary().operation.as_str() != "replace" {
             // This is a bad heuristic, but we have no real other way to identify compactions
             // right now other than assume they will be the only operation writing "replace" operations.
             // That means if we find a snapshot with some other operation, but no mz-frontier, we are in an
             // inconsistent state and have to error out.
             anyhow::bail!(
-                "Iceberg table is in an inconsistent state: snapshot {} has operation '{}' but is missing 'mz-frontier' property. Schema or partition spec evolution is not supported.",
+                "Iceberg table is in an inconsistent state: snapshot {} has operation '{}' but is missing 'mz-frontier' property.",
                 snapshot.snapshot_id(),
                 snapshot.summary().operation.as_str(),
             );
@@ -1165,8 +1719,11 @@ where
                 connection.namespace.clone(),
                 connection.table.clone(),
                 initial_schema.as_ref(),
+                &connection.partition_by,
             )
             .await?;
+            let table =
+                evolve_table_schema(catalog.as_ref(), table, initial_schema.as_ref()).await?;
             debug!(
                 ?sink_id,
                 %name_for_logging,
@@ -1416,6 +1973,8 @@ where
 struct SerializableDataFile {
     pub data_file: DataFile,
     pub schema: Schema,
+    pub partition_spec_id: i32,
+    pub partition_type: StructType,
 }
 
 /// A wrapper around Iceberg's DataFile that implements Serialize and Deserialize.
@@ -1430,6 +1989,9 @@ struct AvroDataFile {
     pub data_file: Vec<u8>,
     /// Schema serialized as JSON bytes to avoid bincode issues with HashMap
     pub schema: Vec<u8>,
+    pub partition_spec_id: i32,
+    /// Partition type serialized as JSON bytes, like the schema.
+    pub partition_type: Vec<u8>,
 }
 
 impl From<SerializableDataFile> for AvroDataFile {
@@ -1438,12 +2000,19 @@ impl From<SerializableDataFile> for AvroDataFile {
         write_data_files_to_avro(
             &mut data_file,
             [value.data_file],
-            &StructType::new(vec![]),
+            &value.partition_type,
             FormatVersion::V2,
         )
         .expect("serialization into buffer");
         let schema = serde_json::to_vec(&value.schema).expect("schema serialization");
-        AvroDataFile { data_file, schema }
+        let partition_type =
+            serde_json::to_vec(&value.partition_type).expect("partition type serialization");
+        AvroDataFile {
+            data_file,
+            schema,
+            partition_spec_id: value.partition_spec_id,
+            partition_type,
+        }
     }
 }
 
@@ -1453,18 +2022,25 @@ impl TryFrom<AvroDataFile> for SerializableDataFile {
     fn try_from(value: AvroDataFile) -> Result<Self, Self::Error> {
         let schema: Schema = serde_json::from_slice(&value.schema)
             .map_err(|e| format!("Failed to deserialize schema: {}", e))?;
+        let partition_type: StructType = serde_json::from_slice(&value.partition_type)
+            .map_err(|e| format!("Failed to deserialize partition type: {}", e))?;
         let data_files = read_data_files_from_avro(
             &mut &*value.data_file,
             &schema,
-            0,
-            &StructType::new(vec![]),
+            value.partition_spec_id,
+            &partition_type,
             FormatVersion::V2,
         )
         .map_err_to_string_with_causes()?;
         let Some(data_file) = data_files.into_iter().next() else {
             return Err("No DataFile found in Avro data".into());
         };
-        Ok(SerializableDataFile { data_file, schema })
+        Ok(SerializableDataFile {
+            data_file,
+            schema,
+            partition_spec_id: value.partition_spec_id,
+            partition_type,
+        })
     }
 }
 
@@ -1479,12 +2055,16 @@ impl BoundedDataFile {
     pub fn new(
         file: DataFile,
         schema: Schema,
+        partition_spec_id: i32,
+        partition_type: StructType,
         batch_desc: (Antichain<Timestamp>, Antichain<Timestamp>),
     ) -> Self {
         Self {
             data_file: SerializableDataFile {
                 data_file: file,
                 schema,
+                partition_spec_id,
+                partition_type,
             },
             batch_desc,
         }
@@ -1498,6 +2078,11 @@ impl BoundedDataFile {
         &self.data_file.data_file
     }
 
+    /// The ID of the partition spec the file was written with.
+    pub fn partition_spec_id(&self) -> i32 {
+        self.data_file.partition_spec_id
+    }
+
     pub fn into_data_file(self) -> DataFile {
         self.data_file.data_file
     }
@@ -1572,6 +2157,10 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
 
             let table_metadata = table.metadata().clone();
             let current_schema = Arc::clone(table_metadata.current_schema());
+            let partition_spec = Arc::clone(table_metadata.default_partition_spec());
+            let partition_type = partition_spec
+                .partition_type(current_schema.as_ref())
+                .context("Failed to compute Iceberg partition type")?;
 
             // Merge Materialize extension metadata into the Iceberg schema.
             // We need extension metadata for ArrowBuilder to work correctly (it uses
@@ -1584,25 +2173,8 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                 .context("Failed to merge Materialize metadata into Iceberg schema")?,
             );
 
-            // WORKAROUND: S3 Tables catalog incorrectly sets location to the metadata file path
-            // instead of the warehouse root. Strip off the /metadata/*.metadata.json suffix.
-            // No clear way to detect this properly right now, so we use heuristics.
-            let location = table_metadata.location();
-            let corrected_location = match location.rsplit_once("/metadata/") {
-                Some((a, b)) if b.ends_with(".metadata.json") => a,
-                _ => location,
-            };
-
-            let data_location = format!("{}/data", corrected_location);
-            let location_generator = DefaultLocationGenerator::with_data_location(data_location);
-
-            // Add a unique suffix to avoid filename collisions across restarts and workers
-            let unique_suffix = format!("-{}", uuid::Uuid::new_v4());
-            let file_name_generator = DefaultFileNameGenerator::new(
-                PARQUET_FILE_PREFIX.to_string(),
-                Some(unique_suffix),
-                iceberg::spec::DataFileFormat::Parquet,
-            );
+            let location_generator = data_location_generator(&table_metadata);
+            let file_name_generator = unique_file_name_generator();
 
             let file_io = table.file_io().clone();
 
@@ -1611,6 +2183,7 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
             let ctx = WriterContext {
                 arrow_schema,
                 current_schema: Arc::clone(&current_schema),
+                partition_spec: Arc::clone(&partition_spec),
                 file_io,
                 location_generator,
                 file_name_generator,
@@ -1888,6 +2461,8 @@ fn write_data_files<'scope, H: EnvelopeHandler + 'static>(
                                 let file = BoundedDataFile::new(
                                     data_file,
                                     current_schema.as_ref().clone(),
+                                    partition_spec.spec_id(),
+                                    partition_type.clone(),
                                     desc.clone(),
                                 );
                                 output.give(&capset[0], file);
@@ -2134,6 +2709,120 @@ mod tests {
             Some(&"materialize.v1.string".to_string()),
         );
     }
+
+    #[mz_ore::test]
+    fn evolved_schema_adds_missing_columns() {
+        use iceberg::spec::NestedField;
+
+        let long = || Type::Primitive(PrimitiveType::Long);
+        let current = Schema::builder()
+            .with_schema_id(0)
+            .with_fields(vec![Arc::new(NestedField::required(1, "a", long()))])
+            .build()
+            .expect("valid schema");
+        // Field IDs of the desired schema are assigned from scratch, so they
+        // collide with the table's.
+        let desired = Schema::builder()
+            .with_fields(vec![
+                Arc::new(NestedField::required(1, "a", long())),
+                Arc::new(NestedField::required(
+                    2,
+                    "b",
+                    Type::Struct(StructType::new(vec![Arc::new(NestedField::optional(
+                        3,
+                        "c",
+                        long(),
+                    ))])),
+                )),
+            ])
+            .build()
+            .expect("valid schema");
+
+        let evolved = evolved_schema(&current, &desired, 5, 1)
+            .expect("valid evolution")
+            .expect("columns added");
+        assert_eq!(evolved.schema_id(), 1);
+        assert_eq!(evolved.field_by_name("a").expect("a").id, 1);
+        let b = evolved.field_by_name("b").expect("b");
+        assert_eq!(b.id, 6);
+        assert!(!b.required);
+        assert_eq!(evolved.field_by_name("b.c").expect("b.c").id, 7);
+
+        assert!(
+            evolved_schema(&current, &current, 5, 1)
+                .expect("valid evolution")
+                .is_none()
+        );
+        // Dropping a column is not supported.
+        assert!(evolved_schema(&desired, &current, 5, 1).is_err());
+    }
+
+    #[mz_ore::test]
+    fn partition_spec_names_fields_after_transforms() {
+        use iceberg::spec::NestedField;
+
+        let schema = Schema::builder()
+            .with_fields(vec![
+                Arc::new(NestedField::required(
+                    1,
+                    "id",
+                    Type::Primitive(PrimitiveType::Long),
+                )),
+                Arc::new(NestedField::optional(
+                    2,
+                    "ts",
+                    Type::Primitive(PrimitiveType::Timestamptz),
+                )),
+                Arc::new(NestedField::optional(
+                    3,
+                    "region",
+                    Type::Primitive(PrimitiveType::String),
+                )),
+            ])
+            .build()
+            .expect("valid schema");
+        let partition_by = [
+            IcebergPartitionField {
+                column: "region".into(),
+                transform: IcebergPartitionTransform::Identity,
+            },
+            IcebergPartitionField {
+                column: "ts".into(),
+                transform: IcebergPartitionTransform::Day,
+            },
+            IcebergPartitionField {
+                column: "id".into(),
+                transform: IcebergPartitionTransform::Bucket(16),
+            },
+        ];
+
+        let spec = partition_spec(&schema, &partition_by).expect("valid spec");
+        let fields: Vec<_> = spec
+            .fields()
+            .iter()
+            .map(|f| (f.source_id, f.name.as_str(), f.transform))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                (3, "region", Transform::Identity),
+                (2, "ts_day", Transform::Day),
+                (1, "id_bucket", Transform::Bucket(16)),
+            ]
+        );
+
+        let missing = [IcebergPartitionField {
+            column: "nope".into(),
+            transform: IcebergPartitionTransform::Identity,
+        }];
+        assert!(partition_spec(&schema, &missing).is_err());
+        assert!(
+            partition_spec(&schema, &[])
+                .expect("valid spec")
+                .fields()
+                .is_empty()
+        );
+    }
 }
 
 /// Commit completed batches to Iceberg as snapshots.
@@ -2211,6 +2900,9 @@ fn commit_to_iceberg<'scope>(
             let mut batch_description_frontier = Antichain::from_elem(Timestamp::minimum());
             let mut input_frontier = Antichain::from_elem(Timestamp::minimum());
 
+            let mut last_compaction = Instant::now();
+            let mut last_expiration = Instant::now();
+
             while !(batch_description_frontier.is_empty() && input_frontier.is_empty()) {
                 tokio::select! {
                     _ = batch_desc_input.ready() => {},
@@ -2282,10 +2974,12 @@ fn commit_to_iceberg<'scope>(
 
                     let mut data_files = vec![];
                     let mut delete_files = vec![];
+                    let mut partition_spec_ids = BTreeSet::new();
                     // Track totals for committed statistics
                     let mut total_messages: u64 = 0;
                     let mut total_bytes: u64 = 0;
                     for file in file_set.data_files {
+                        partition_spec_ids.insert(file.partition_spec_id());
                         total_messages += file.data_file().record_count();
                         total_bytes += file.data_file().file_size_in_bytes();
                         match file.data_file().content_type() {
@@ -2316,11 +3010,17 @@ fn commit_to_iceberg<'scope>(
                     let frontier = batch.1.clone();
                     let frontier_json = serde_json::to_string(&frontier.elements())
                         .context("Failed to serialize frontier to JSON")?;
-                    let snapshot_properties = vec![
+                    let mut snapshot_properties = vec![
                         ("mz-sink-id".to_string(), sink_id.to_string()),
                         ("mz-frontier".to_string(), frontier_json),
                         ("mz-sink-version".to_string(), sink_version.to_string()),
                     ];
+                    if let Some(version) = connection.relation_version {
+                        snapshot_properties.push((
+                            "mz-relation-version".to_string(),
+                            version.into_raw().to_string(),
+                        ));
+                    }
 
                     let (table_state, commit_result) = Retry::default()
                         .max_tries(5)
@@ -2335,10 +3035,12 @@ fn commit_to_iceberg<'scope>(
                             let frontier = frontier.clone();
                             let batch_lower = batch.0.clone();
                             let batch_upper = batch.1.clone();
+                            let partition_spec_ids = &partition_spec_ids;
                             async move {
                                 try_commit_batch(
                                     table,
                                     snapshot_properties,
+                                    partition_spec_ids,
                                     data_files,
                                     delete_files,
                                     catalog.as_ref(),
@@ -2403,6 +3105,43 @@ fn commit_to_iceberg<'scope>(
                     }
                     write_frontier.borrow_mut().clone_from(&frontier);
                 }
+
+                // Table maintenance runs between batch commits, so it never
+                // races with the sink's own commits. Failures are not fatal:
+                // the table is left as it was and we try again next interval.
+                if let Some(interval) = connection.compaction_interval
+                    && last_compaction.elapsed() >= interval
+                {
+                    last_compaction = Instant::now();
+                    match compact_table(catalog.as_ref(), &table).await {
+                        Ok(Some(compacted)) => table = compacted,
+                        Ok(None) => {}
+                        Err(err) => tracing::warn!(
+                            ?sink_id,
+                            "failed to compact Iceberg table '{}.{}': {}",
+                            connection.namespace,
+                            connection.table,
+                            err.display_with_causes()
+                        ),
+                    }
+                }
+                if let Some(retention) = connection.snapshot_retention
+                    && last_expiration.elapsed()
+                        >= retention.min(SNAPSHOT_EXPIRATION_CHECK_INTERVAL)
+                {
+                    last_expiration = Instant::now();
+                    match expire_snapshots(catalog.as_ref(), &table, retention).await {
+                        Ok(Some(expired)) => table = expired,
+                        Ok(None) => {}
+                        Err(err) => tracing::warn!(
+                            ?sink_id,
+                            "failed to expire snapshots of Iceberg table '{}.{}': {}",
+                            connection.namespace,
+                            connection.table,
+                            err.display_with_causes()
+                        ),
+                    }
+                }
             }
 
             Ok(())
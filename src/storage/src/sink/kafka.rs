@@ -77,7 +77,7 @@ use std::collections::BTreeMap;
 use std::future::Future;
 use std::rc::Rc;
 use std::sync::atomic::AtomicU64;
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
@@ -94,6 +94,7 @@ use mz_interchange::avro::{AvroEncoder, AvroSchemaId};
 use mz_interchange::encode::Encode;
 use mz_interchange::envelopes::{dbz_format, for_each_diff_pair};
 use mz_interchange::json::JsonEncoder;
+use mz_interchange::protobuf::Encoder as ProtobufEncoder;
 use mz_interchange::text_binary::{BinaryEncoder, TextEncoder};
 use mz_kafka_util::admin::EnsureTopicConfig;
 use mz_kafka_util::client::{
@@ -113,6 +114,7 @@ use mz_repr::{Datum, DatumVec, Diff, GlobalId, RelationDesc, Row, RowArena, Time
 use mz_storage_client::sink::progress_key::ProgressKey;
 use mz_storage_types::StorageDiff;
 use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::connections::CsrConnection;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::dyncfgs::{
     KAFKA_BUFFERED_EVENT_RESIZE_THRESHOLD_ELEMENTS, KAFKA_SINK_BATCH_NUM_MESSAGES,
@@ -132,6 +134,7 @@ use mz_timely_util::builder_async::{
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::error::KafkaError;
 use rdkafka::message::{Header, OwnedHeaders, ToBytes};
+use rdkafka::metadata::Metadata;
 use rdkafka::producer::{BaseRecord, Producer, ThreadedProducer};
 use rdkafka::types::RDKafkaErrorCode;
 use rdkafka::{Message, Offset, Statistics, TopicPartitionList};
@@ -241,9 +244,15 @@ impl<'scope> SinkRender<'scope> for KafkaSinkConnection {
 }
 
 struct TransactionalProducer {
+    /// The ID of the sink this producer writes for.
+    sink_id: GlobalId,
     /// The task name used for any blocking calls spawned onto the tokio threadpool.
     task_name: String,
-    /// The topic where all the updates go.
+    /// The connection of the sink, used to create the topics that updates are routed to.
+    connection: KafkaSinkConnection,
+    /// The storage configuration, used to create the topics that updates are routed to.
+    storage_configuration: StorageConfiguration,
+    /// The topic where all the updates go, unless they are routed to another topic.
     data_topic: String,
     /// The topic where all the upper frontiers go.
     progress_topic: String,
@@ -253,6 +262,9 @@ struct TransactionalProducer {
     sink_version: u64,
     /// The number of partitions in the target topic.
     partition_count: Arc<AtomicU64>,
+    /// The number of partitions in each topic that updates have been routed to by the sink's
+    /// `TOPIC BY` expression. Topics are added the first time an update is routed to them.
+    routed_partition_counts: Arc<Mutex<BTreeMap<String, u64>>>,
     /// A task to periodically refresh the partition count.
     _partition_count_task: AbortOnDropHandle<()>,
     /// The underlying Kafka producer.
@@ -373,6 +385,8 @@ impl TransactionalProducer {
             })
         };
 
+        let routed_partition_counts = Arc::new(Mutex::new(BTreeMap::new()));
+
         // Start a task that will keep the partition count up to date in the
         // background.
         let partition_count_task = task::spawn(
@@ -383,6 +397,7 @@ impl TransactionalProducer {
                 connection.topic.clone(),
                 connection.topic_metadata_refresh_interval,
                 Arc::clone(&update_partition_count),
+                Arc::clone(&routed_partition_counts),
             ),
         );
 
@@ -390,9 +405,13 @@ impl TransactionalProducer {
         let progress_key = ProgressKey::new(sink_id);
 
         let producer = Self {
+            sink_id,
             task_name,
+            connection: connection.clone(),
+            storage_configuration: storage_configuration.clone(),
             data_topic: connection.topic.clone(),
             partition_count,
+            routed_partition_counts,
             _partition_count_task: partition_count_task.abort_on_drop(),
             progress_topic: connection
                 .progress_topic(&storage_configuration.connection_context)
@@ -478,6 +497,50 @@ impl TransactionalProducer {
         self.spawn_blocking(|p| p.begin_transaction()).await
     }
 
+    /// Ensures that the topic the provided message is routed to exists and that its partition
+    /// count is known. This must be called before the message is passed to `send`.
+    ///
+    /// Routed topics are created the same way as the data topic. This only happens the first time
+    /// a message is routed to a topic, so the cost of checking for the topic is paid once per topic
+    /// and incarnation of the sink.
+    async fn ensure_routed_topic(
+        &self,
+        message: &KafkaMessage,
+    ) -> Result<(), ContextCreationError> {
+        let Some(topic) = message.topic.as_ref() else {
+            return Ok(());
+        };
+        if *topic == self.data_topic
+            || self
+                .routed_partition_counts
+                .lock()
+                .expect("lock poisoned")
+                .contains_key(topic)
+        {
+            return Ok(());
+        }
+
+        mz_storage_client::sink::ensure_kafka_topic(
+            &self.connection,
+            &self.storage_configuration,
+            topic,
+            &self.connection.topic_options,
+            EnsureTopicConfig::Skip,
+        )
+        .await
+        .with_context(|| format!("error creating routed topic {topic}"))?;
+        let partition_count = fetch_partition_count(&self.producer, self.sink_id, topic).await?;
+        info!(
+            sink_id = %self.sink_id,
+            "routing messages to topic {topic} with {partition_count} partitions"
+        );
+        self.routed_partition_counts
+            .lock()
+            .expect("lock poisoned")
+            .insert(topic.clone(), partition_count);
+        Ok(())
+    }
+
     /// Synchronously puts the provided message to librdkafka's send queue. This method only
     /// returns an error if the queue is full. Handling this error by buffering the message and
     /// retrying is equivalent to adjusting the maximum number of queued items in rdkafka so it is
@@ -511,13 +574,22 @@ impl TransactionalProducer {
             });
         }
 
-        let pc = self
-            .partition_count
-            .load(std::sync::atomic::Ordering::SeqCst);
+        let topic = message.topic.as_ref().unwrap_or(&self.data_topic);
+        let pc = if *topic == self.data_topic {
+            self.partition_count
+                .load(std::sync::atomic::Ordering::SeqCst)
+        } else {
+            *self
+                .routed_partition_counts
+                .lock()
+                .expect("lock poisoned")
+                .get(topic)
+                .expect("routed topic ensured before sending")
+        };
         let partition = Some(i32::try_from(message.hash % pc).unwrap());
 
         let record = BaseRecord {
-            topic: &self.data_topic,
+            topic,
             key: message.key.as_ref(),
             payload: message.value.as_ref(),
             headers: Some(headers),
@@ -654,6 +726,9 @@ struct KafkaMessage {
     value: Option<Vec<u8>>,
     /// Message headers.
     headers: Vec<KafkaHeader>,
+    /// The topic to write the message to, if the sink's `TOPIC BY` expression routed it
+    /// somewhere other than the sink's topic.
+    topic: Option<String>,
 }
 
 /// A header to attach to a Kafka message.
@@ -785,6 +860,7 @@ fn sink_collection<'scope>(
                                         producer.begin_transaction().await?;
                                         transaction_begun = true;
                                     }
+                                    producer.ensure_routed_topic(&message).await?;
                                     producer.send(&message, time, diff)?;
                                 }
                                 Ordering::Greater => continue,
@@ -833,6 +909,7 @@ fn sink_collection<'scope>(
                         extra_updates.sort_unstable_by(|a, b| a.1.cmp(&b.1));
 
                         for (message, time, diff) in extra_updates.drain(..) {
+                            producer.ensure_routed_topic(&message).await?;
                             producer.send(&message, time, diff)?;
                         }
                         extra_updates.shrink_to(buffer_min_capacity.get());
@@ -1347,6 +1424,15 @@ async fn fetch_partition_count(
     sink_id: GlobalId,
     topic_name: &str,
 ) -> Result<u64, anyhow::Error> {
+    let meta = fetch_metadata(producer, sink_id).await?;
+    partition_count(&meta, topic_name)
+}
+
+/// Fetches the metadata of all topics in the cluster.
+async fn fetch_metadata(
+    producer: &ThreadedProducer<TunnelingClientContext<MzClientContext>>,
+    sink_id: GlobalId,
+) -> Result<Metadata, anyhow::Error> {
     let meta = task::spawn_blocking(|| format!("kafka_sink_fetch_partition_count:{sink_id}"), {
         let producer = producer.clone();
         move || {
@@ -1357,7 +1443,11 @@ async fn fetch_partition_count(
     })
     .await
     .check_ssh_status(producer.context())?;
+    Ok(meta)
+}
 
+/// Extracts the partition count for the identified topic from `meta`.
+fn partition_count(meta: &Metadata, topic_name: &str) -> Result<u64, anyhow::Error> {
     match meta.topics().iter().find(|t| t.name() == topic_name) {
         Some(topic) => {
             let partition_count = u64::cast_from(topic.partitions().len());
@@ -1370,17 +1460,19 @@ async fn fetch_partition_count(
     }
 }
 
-/// Fetches the partition count for the identified topic at the specified
-/// interval.
+/// Fetches the partition count for the identified topic, and for the topics in
+/// `routed_partition_counts`, at the specified interval.
 ///
 /// When an updated partition count is discovered, invokes
-/// `update_partition_count` with the new partition count.
+/// `update_partition_count` with the new partition count, or updates the entry
+/// in `routed_partition_counts` for routed topics.
 async fn fetch_partition_count_loop<F>(
     producer: ThreadedProducer<TunnelingClientContext<MzClientContext>>,
     sink_id: GlobalId,
     topic_name: String,
     interval: Duration,
     update_partition_count: Arc<F>,
+    routed_partition_counts: Arc<Mutex<BTreeMap<String, u64>>>,
 ) where
     F: Fn(u64),
 {
@@ -1388,13 +1480,24 @@ async fn fetch_partition_count_loop<F>(
     interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
     loop {
         interval.tick().await;
-        match fetch_partition_count(&producer, sink_id, &topic_name).await {
-            Ok(pc) => update_partition_count(pc),
+        let meta = match fetch_metadata(&producer, sink_id).await {
+            Ok(meta) => meta,
             Err(e) => {
                 warn!(%sink_id, "failed updating partition count: {e}");
                 continue;
             }
         };
+        match partition_count(&meta, &topic_name) {
+            Ok(pc) => update_partition_count(pc),
+            Err(e) => warn!(%sink_id, "failed updating partition count: {e}"),
+        }
+        let mut routed_partition_counts = routed_partition_counts.lock().expect("lock poisoned");
+        for (topic, pc) in routed_partition_counts.iter_mut() {
+            match partition_count(&meta, topic) {
+                Ok(new_pc) => *pc = new_pc,
+                Err(e) => warn!(%sink_id, %topic, "failed updating partition count: {e}"),
+            }
+        }
     }
 }
 
@@ -1484,6 +1587,59 @@ async fn build_avro_encoder(
     Ok(AvroEncoder::new(desc, debezium, &schema, schema_id))
 }
 
+/// Register a JSON `schema` with the sink's schema registry under `subject` and
+/// build a [`JsonEncoder`] that frames records with the registered schema id.
+async fn build_json_schema_encoder(
+    desc: RelationDesc,
+    debezium: bool,
+    schema: String,
+    compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    csr_connection: CsrConnection,
+    subject: String,
+    storage_configuration: &StorageConfiguration,
+) -> Result<JsonEncoder, anyhow::Error> {
+    let ccsr = csr_connection
+        .connect(storage_configuration, InTask::Yes)
+        .await?;
+    let schema_id = mz_storage_client::sink::publish_kafka_schema(
+        ccsr,
+        subject,
+        schema,
+        mz_ccsr::SchemaType::Json,
+        compatibility_level,
+    )
+    .await
+    .context("error publishing kafka schemas for sink")?;
+    Ok(JsonEncoder::new_confluent(desc, debezium, schema_id))
+}
+
+/// Register a Protobuf `schema` with the sink's schema registry under `subject`
+/// and build a [`ProtobufEncoder`] that frames records with the registered
+/// schema id.
+async fn build_protobuf_encoder(
+    desc: RelationDesc,
+    debezium: bool,
+    schema: String,
+    compatibility_level: Option<mz_ccsr::CompatibilityLevel>,
+    csr_connection: CsrConnection,
+    subject: String,
+    storage_configuration: &StorageConfiguration,
+) -> Result<ProtobufEncoder, anyhow::Error> {
+    let ccsr = csr_connection
+        .connect(storage_configuration, InTask::Yes)
+        .await?;
+    let schema_id = mz_storage_client::sink::publish_kafka_schema(
+        ccsr,
+        subject,
+        schema,
+        mz_ccsr::SchemaType::Protobuf,
+        compatibility_level,
+    )
+    .await
+    .context("error publishing kafka schemas for sink")?;
+    ProtobufEncoder::new(desc, debezium, schema_id)
+}
+
 /// Walks each arrangement batch and emits encoded Kafka messages, one per
 /// `DiffPair` observed at each `(key, timestamp)`.
 ///
@@ -1528,6 +1684,40 @@ fn encode_collection<'scope>(
                     (Some(desc), Some(KafkaSinkFormatType::Json)) => {
                         Some(Box::new(JsonEncoder::new(desc, false)))
                     }
+                    (Some(desc), Some(KafkaSinkFormatType::JsonSchema {
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                    })) => {
+                        let encoder = build_json_schema_encoder(
+                            desc,
+                            false,
+                            schema,
+                            compatibility_level,
+                            csr_connection,
+                            format!("{}-key", connection.topic),
+                            &storage_configuration,
+                        )
+                        .await?;
+                        Some(Box::new(encoder))
+                    }
+                    (Some(desc), Some(KafkaSinkFormatType::Protobuf {
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                    })) => {
+                        let encoder = build_protobuf_encoder(
+                            desc,
+                            false,
+                            schema,
+                            compatibility_level,
+                            csr_connection,
+                            format!("{}-key", connection.topic),
+                            &storage_configuration,
+                        )
+                        .await?;
+                        Some(Box::new(encoder))
+                    }
                     (Some(desc), Some(KafkaSinkFormatType::Avro {
                         schema,
                         compatibility_level,
@@ -1564,7 +1754,43 @@ fn encode_collection<'scope>(
             let value_encoder: Box<dyn Encode> = match connection.format.value_format {
                 KafkaSinkFormatType::Bytes => Box::new(BinaryEncoder::new(value_desc, debezium)),
                 KafkaSinkFormatType::Text => Box::new(TextEncoder::new(value_desc, debezium)),
-                KafkaSinkFormatType::Json => Box::new(JsonEncoder::new(value_desc, debezium)),
+                KafkaSinkFormatType::Json | KafkaSinkFormatType::JsonArray => {
+                    Box::new(JsonEncoder::new(value_desc, debezium))
+                }
+                KafkaSinkFormatType::JsonSchema {
+                    schema,
+                    compatibility_level,
+                    csr_connection,
+                } => {
+                    let encoder = build_json_schema_encoder(
+                        value_desc,
+                        debezium,
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                        format!("{}-value", connection.topic),
+                        &storage_configuration,
+                    )
+                    .await?;
+                    Box::new(encoder)
+                }
+                KafkaSinkFormatType::Protobuf {
+                    schema,
+                    compatibility_level,
+                    csr_connection,
+                } => {
+                    let encoder = build_protobuf_encoder(
+                        value_desc,
+                        debezium,
+                        schema,
+                        compatibility_level,
+                        csr_connection,
+                        format!("{}-value", connection.topic),
+                        &storage_configuration,
+                    )
+                    .await?;
+                    Box::new(encoder)
+                }
                 KafkaSinkFormatType::Avro {
                     schema,
                     compatibility_level,
@@ -1594,6 +1820,18 @@ fn encode_collection<'scope>(
             // TODO(petrosagg): Make the fallible async operator safe
             *capset = CapabilitySet::new();
 
+            let mut json_arrays =
+                matches!(connection.format.value_format, KafkaSinkFormatType::JsonArray).then(
+                    || {
+                        let max_bytes =
+                            KAFKA_SINK_MESSAGE_MAX_BYTES.get(storage_configuration.config_set());
+                        JsonArrayBatcher::new(max_bytes)
+                    },
+                );
+
+            let progress_topic = connection
+                .progress_topic(&storage_configuration.connection_context)
+                .into_owned();
             let mut row_buf = Row::default();
             let mut datums = DatumVec::new();
             let mut pk_warner =
@@ -1613,14 +1851,17 @@ fn encode_collection<'scope>(
 
                             let mut hash = None;
                             let mut headers = vec![];
+                            let mut topic = None;
                             if connection.headers_index.is_some()
                                 || connection.partition_by.is_some()
+                                || connection.topic_by.is_some()
                             {
-                                // Header values and partition by values are derived from the row
-                                // that produces an event. But it is ambiguous whether to use the
-                                // `before` or `after` from the event. The rule applied here is
-                                // simple: use `after` if it exists (insertions and updates),
-                                // otherwise fall back to `before` (deletions).
+                                // Header values, partition by values and topic by values are
+                                // derived from the row that produces an event. But it is
+                                // ambiguous whether to use the `before` or `after` from the
+                                // event. The rule applied here is simple: use `after` if it
+                                // exists (insertions and updates), otherwise fall back to
+                                // `before` (deletions).
                                 //
                                 // It is up to the SQL planner to ensure this produces sensible
                                 // results. (When using the upsert envelope and both `before` and
@@ -1643,6 +1884,10 @@ fn encode_collection<'scope>(
                                 if let Some(partition_by) = &connection.partition_by {
                                     hash = Some(evaluate_partition_by(partition_by, &row));
                                 }
+
+                                if let Some(topic_by) = &connection.topic_by {
+                                    topic = evaluate_topic_by(topic_by, &row, &progress_topic);
+                                }
                             }
                             let (encoded_key, hash) = match key_for_message {
                                 Some(key) => {
@@ -1666,11 +1911,17 @@ fn encode_collection<'scope>(
                                 }
                             };
                             let value = value.map(|value| value_encoder.encode_unchecked(value));
+                            if let Some(json_arrays) = json_arrays.as_mut() {
+                                let value = value.expect("JSON ARRAY sinks always have a value");
+                                json_arrays.push(time, topic, value);
+                                return;
+                            }
                             let message = KafkaMessage {
                                 hash,
                                 key: encoded_key,
                                 value,
                                 headers,
+                                topic,
                             };
                             output.give(&cap, (message, time, Diff::ONE));
                         });
@@ -1680,6 +1931,13 @@ fn encode_collection<'scope>(
                         if let Some(warner) = pk_warner.as_mut() {
                             warner.flush();
                         }
+                        // The batches of a worker cover disjoint time intervals, so the arrays of
+                        // the batch are complete once it has been walked.
+                        if let Some(json_arrays) = json_arrays.as_mut() {
+                            for (time, message) in json_arrays.drain() {
+                                output.give(&cap, (message, time, Diff::ONE));
+                            }
+                        }
                     }
                 }
             }
@@ -1697,13 +1955,74 @@ fn encode_collection<'scope>(
     (stream.as_collection(), statuses, button.press_on_drop())
 }
 
-fn encode_headers(datum: Datum) -> Vec<KafkaHeader> {
-    let mut out = vec![];
-    if datum.is_null() {
-        return out;
+/// Collects the encoded JSON values of a `FORMAT JSON ARRAY` sink into one JSON array per
+/// timestamp and topic.
+///
+/// Arrays are split into multiple messages so that no message exceeds the producer's maximum
+/// message size, unless a single value exceeds it on its own.
+struct JsonArrayBatcher {
+    /// The maximum size of an encoded array.
+    max_bytes: usize,
+    /// The encoded values of each array, by timestamp and `TOPIC BY` topic.
+    values: BTreeMap<(Timestamp, Option<String>), Vec<Vec<u8>>>,
+}
+
+impl JsonArrayBatcher {
+    /// The number of bytes of `message.max.bytes` reserved for the message's key, headers and
+    /// framing.
+    const FRAMING_BYTES: usize = 1024;
+
+    fn new(message_max_bytes: usize) -> Self {
+        Self {
+            max_bytes: message_max_bytes.saturating_sub(Self::FRAMING_BYTES),
+            values: BTreeMap::new(),
+        }
+    }
+
+    fn push(&mut self, time: Timestamp, topic: Option<String>, value: Vec<u8>) {
+        self.values.entry((time, topic)).or_default().push(value);
+    }
+
+    /// Drains the collected values into messages, each holding a JSON array.
+    fn drain(&mut self) -> Vec<(Timestamp, KafkaMessage)> {
+        let mut messages = vec![];
+        for ((time, topic), values) in std::mem::take(&mut self.values) {
+            let mut array: Vec<u8> = vec![];
+            for value in values {
+                // Account for the separating comma and the closing bracket.
+                if !array.is_empty() && array.len() + value.len() + 2 > self.max_bytes {
+                    array.push(b']');
+                    messages.push((time, Self::message(&topic, std::mem::take(&mut array))));
+                }
+                array.push(if array.is_empty() { b'[' } else { b',' });
+                array.extend(value);
+            }
+            if !array.is_empty() {
+                array.push(b']');
+                messages.push((time, Self::message(&topic, array)));
+            }
+        }
+        messages
+    }
+
+    fn message(topic: &Option<String>, array: Vec<u8>) -> KafkaMessage {
+        KafkaMessage {
+            hash: 0,
+            key: None,
+            value: Some(array),
+            headers: vec![],
+            topic: topic.clone(),
+        }
     }
-    for (key, value) in datum.unwrap_map().iter() {
-        out.push(KafkaHeader {
+}
+
+/// Encodes the value of the headers column of a row.
+///
+/// The column is either a map, or a list of `(key, value)` records, which allows repeating a
+/// header key. List elements that are `NULL` or have a `NULL` key are skipped.
+fn encode_headers(datum: Datum) -> Vec<KafkaHeader> {
+    fn encode_header(key: &str, value: Datum) -> KafkaHeader {
+        KafkaHeader {
             key: key.into(),
             value: match value {
                 Datum::Null => None,
@@ -1711,9 +2030,27 @@ fn encode_headers(datum: Datum) -> Vec<KafkaHeader> {
                 Datum::Bytes(b) => Some(b.to_vec()),
                 _ => panic!("encode_headers called with unexpected header value {value:?}"),
             },
-        })
+        }
+    }
+
+    match datum {
+        Datum::Null => vec![],
+        Datum::Map(map) => map
+            .iter()
+            .map(|(key, value)| encode_header(key, value))
+            .collect(),
+        Datum::List(list) => list
+            .iter()
+            .filter(|header| !header.is_null())
+            .filter_map(|header| {
+                let mut fields = header.unwrap_list().iter();
+                let key = fields.next().expect("header record has a key");
+                let value = fields.next().expect("header record has a value");
+                (!key.is_null()).then(|| encode_header(key.unwrap_str(), value))
+            })
+            .collect(),
+        _ => panic!("encode_headers called with unexpected headers {datum:?}"),
     }
-    out
 }
 
 /// Evaluates a partition by expression on the given row, returning the hash
@@ -1745,12 +2082,91 @@ fn evaluate_partition_by(partition_by: &MirScalarExpr, row: &[Datum]) -> u64 {
     }
 }
 
+/// Evaluates a topic by expression on the given row, returning the name of the
+/// topic the message should be written to.
+///
+/// The provided expression must have type `String`. If the expression produces
+/// an error or `NULL` when evaluated, this function returns `None` and the
+/// message is written to the sink's topic, for the same reasons that
+/// [`evaluate_partition_by`] maps errors to partition 0. The same applies to
+/// names that messages cannot be routed to, see [`is_routable_topic`].
+fn evaluate_topic_by(
+    topic_by: &MirScalarExpr,
+    row: &[Datum],
+    progress_topic: &str,
+) -> Option<String> {
+    let temp_storage = RowArena::new();
+    match topic_by.eval(row, &temp_storage) {
+        Ok(Datum::String(topic)) if is_routable_topic(topic, progress_topic) => {
+            Some(topic.to_owned())
+        }
+        Ok(Datum::String(_)) => None,
+        Ok(datum) => {
+            // If we are here the only valid type that we should be seeing is
+            // null. Anything else is a bug in the planner.
+            soft_assert_or_log!(datum.is_null(), "unexpected topic_by result: {datum:?}");
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+/// Reports whether a `TOPIC BY` expression may route messages to `topic`.
+///
+/// The name must be a legal Kafka topic name, i.e. at most 249 ASCII letters,
+/// digits, `.`, `_` and `-`, other than `.` and `..`. It must also not name the
+/// sink's progress topic or a topic starting with `__`, which Kafka reserves for
+/// its internal topics, as writing data messages to either would corrupt them.
+fn is_routable_topic(topic: &str, progress_topic: &str) -> bool {
+    const MAX_TOPIC_NAME_LEN: usize = 249;
+
+    let is_legal = !topic.is_empty()
+        && topic.len() <= MAX_TOPIC_NAME_LEN
+        && topic != "."
+        && topic != ".."
+        && topic
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'));
+    is_legal && !topic.starts_with("__") && topic != progress_topic
+}
+
 #[cfg(test)]
 mod test {
     use mz_ore::assert_err;
 
     use super::*;
 
+    #[mz_ore::test]
+    fn json_array_batcher() {
+        let mut batcher = JsonArrayBatcher::new(JsonArrayBatcher::FRAMING_BYTES + 8);
+        batcher.push(2.into(), None, b"4".to_vec());
+        batcher.push(1.into(), None, b"1".to_vec());
+        batcher.push(1.into(), None, b"22".to_vec());
+        batcher.push(1.into(), None, b"333".to_vec());
+        batcher.push(1.into(), Some("other".into()), b"5".to_vec());
+        batcher.push(2.into(), None, b"666666666".to_vec());
+
+        let messages: Vec<_> = batcher
+            .drain()
+            .into_iter()
+            .map(|(time, message)| {
+                let value = String::from_utf8(message.value.unwrap()).unwrap();
+                (time, message.topic, value)
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (1.into(), None, "[1,22]".into()),
+                (1.into(), None, "[333]".into()),
+                (1.into(), Some("other".into()), "[5]".into()),
+                (2.into(), None, "[4]".into()),
+                (2.into(), None, "[666666666]".into()),
+            ]
+        );
+        assert!(batcher.drain().is_empty());
+    }
+
     #[mz_ore::test]
     fn progress_record_migration() {
         assert_err!(parse_progress_record(b"{}"));
@@ -1797,4 +2213,45 @@ mod test {
 
         assert_err!(parse_progress_record(b"{\"frontier\":null}"));
     }
+
+    #[mz_ore::test]
+    fn list_headers() {
+        let mut row = Row::default();
+        row.packer().push_list_with(|packer| {
+            packer.push_list([Datum::String("a"), Datum::String("b")]);
+            packer.push(Datum::Null);
+            packer.push_list([Datum::String("a"), Datum::Bytes(b"c")]);
+            packer.push_list([Datum::Null, Datum::String("d")]);
+            packer.push_list([Datum::String("e"), Datum::Null]);
+        });
+        let headers: Vec<_> = encode_headers(row.unpack_first())
+            .into_iter()
+            .map(|header| (header.key, header.value))
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                ("a".to_string(), Some(b"b".to_vec())),
+                ("a".to_string(), Some(b"c".to_vec())),
+                ("e".to_string(), None),
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn routable_topics() {
+        let progress = "_materialize-progress-u1";
+        assert!(is_routable_topic("events-a_1.v2", progress));
+        assert!(is_routable_topic(&"x".repeat(249), progress));
+
+        assert!(!is_routable_topic("", progress));
+        assert!(!is_routable_topic(".", progress));
+        assert!(!is_routable_topic("..", progress));
+        assert!(!is_routable_topic(&"x".repeat(250), progress));
+        assert!(!is_routable_topic("events a", progress));
+        assert!(!is_routable_topic("events/a", progress));
+        assert!(!is_routable_topic("événements", progress));
+        assert!(!is_routable_topic("__consumer_offsets", progress));
+        assert!(!is_routable_topic(progress, progress));
+    }
 }
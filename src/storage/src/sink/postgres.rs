@@ -0,0 +1,717 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`PostgresSinkConnection`].
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃   batch     ┃ walks each batch's cursor and emits one
+//!        ┃   walker    ┃ `(key, Option<value>)` update per DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ keyed updates
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃  postgres   ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!   updates │        │ uppers
+//!      ╭────v──╮ ╭───v──────╮
+//!      │target │ │ progress │  <- updates and uppers are written
+//!      │ table │ │  table   │     in the same transaction
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Applying updates
+//!
+//! The sink buffers the updates it receives until the input frontier tells it that a set of
+//! timestamps is complete. All the updates at those timestamps are then applied to the target
+//! table in a single transaction: the updates are consolidated by key, keeping the latest value,
+//! and copied into two temporary tables using `COPY`. Keys whose latest value is a deletion are
+//! removed from the target with a `DELETE ... USING` and all other keys are written with an
+//! `INSERT ... ON CONFLICT DO UPDATE`, which requires the sink's key to match a unique index of
+//! the target table. Consolidating across timestamps is safe because no intermediate state of a
+//! transaction is ever visible to readers of the target table.
+//!
+//! # Exactly-once
+//!
+//! The transaction that applies the updates also records the new upper frontier in the
+//! [`POSTGRES_SINK_PROGRESS_TABLE`] table, which lives in the same schema as the target table and
+//! holds one row per sink. When the sink restarts it resumes from the frontier stored in that
+//! row, so every timestamp is applied exactly once.
+//!
+//! The row also stores the version of the sink that last wrote to it. A sink refuses to write if
+//! it finds a newer version in the row, and every transaction checks that the stored frontier is
+//! still the one it last committed, which fences out any other replica running the same sink.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow, bail};
+use bytes::Bytes;
+use differential_dataflow::{AsCollection, Hashable, VecCollection};
+use futures::SinkExt;
+use mz_interchange::envelopes::for_each_diff_pair;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_persist_client::Diagnostics;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_pgcopy::{CopyFormatParams, CopyTextFormatParams};
+use mz_postgres_util::{Client, PostgresError, Sql, sql};
+use mz_repr::{Diff, GlobalId, Row, SqlRelationType, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    POSTGRES_SINK_PROGRESS_TABLE, PostgresSinkConnection, StorageSinkDesc,
+};
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{Event, OperatorBuilder, PressOnDropButton};
+use timely::PartialOrder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::StreamVec;
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, error, info};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::{PkViolationWarner, SinkBatchStream, SinkRender};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The temporary table that holds the rows to upsert into the target table.
+const UPSERTS_TABLE: &str = "mz_sink_upserts";
+/// The temporary table that holds the keys to delete from the target table.
+const DELETES_TABLE: &str = "mz_sink_deletes";
+/// The amount of `COPY` data to buffer before sending it to the server.
+const COPY_CHUNK_SIZE: usize = 1 << 20;
+
+impl<'scope> SinkRender<'scope> for PostgresSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        Some(self.key_desc_and_indices.1.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        batches: SinkBatchStream<'scope>,
+        key_is_synthetic: bool,
+        _err_collection: VecCollection<'scope, Timestamp, DataflowError, Diff>,
+    ) -> (
+        StreamVec<'scope, Timestamp, HealthStatusMessage>,
+        Vec<PressOnDropButton>,
+    ) {
+        let scope = batches.scope();
+
+        let write_handle = {
+            let persist = Arc::clone(&storage_state.persist_clients);
+            let shard_meta = sink.to_storage_metadata.clone();
+            async move {
+                let client = persist.open(shard_meta.persist_location).await?;
+                let handle = client
+                    .open_writer(
+                        shard_meta.data_shard,
+                        Arc::new(shard_meta.relation_desc),
+                        Arc::new(UnitSchema),
+                        Diagnostics::from_purpose("sink handle"),
+                    )
+                    .await?;
+                Ok(handle)
+            }
+        };
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (updates, walk_token) = walk_sink_arrangement(
+            format!("postgres-{sink_id}-walk"),
+            batches,
+            sink_id,
+            sink.from,
+            key_is_synthetic,
+        );
+
+        let statistics = storage_state
+            .aggregated_statistics
+            .get_sink(&sink_id)
+            .expect("statistics initialized")
+            .clone();
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("postgres-{sink_id}-sink"),
+            updates,
+            sink_id,
+            self.clone(),
+            storage_state.storage_configuration.clone(),
+            sink,
+            statistics,
+            write_handle,
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            id: None,
+            update: HealthStatusUpdate::running(),
+            namespace: StatusNamespace::Postgres,
+        })
+        .to_stream(scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![walk_token, sink_token])
+    }
+}
+
+/// Walks each arrangement batch and emits one `(key, Option<value>)` update per `DiffPair`,
+/// where a missing value marks the deletion of the key.
+fn walk_sink_arrangement<'scope>(
+    name: String,
+    batches: SinkBatchStream<'scope>,
+    sink_id: GlobalId,
+    from_id: GlobalId,
+    key_is_synthetic: bool,
+) -> (
+    VecCollection<'scope, Timestamp, (Row, Option<Row>), Diff>,
+    PressOnDropButton,
+) {
+    let mut builder = OperatorBuilder::new(name, batches.scope());
+    let (output, stream) = builder.new_output::<CapacityContainerBuilder<Vec<_>>>();
+    let mut input = builder.new_input_for(batches, Pipeline, &output);
+
+    let button = builder.build(move |_caps| async move {
+        let mut pk_warner = (!key_is_synthetic).then(|| PkViolationWarner::new(sink_id, from_id));
+
+        while let Some(event) = input.next().await {
+            if let Event::Data(cap, mut batches) = event {
+                for batch in batches.drain(..) {
+                    for_each_diff_pair(&batch, |key, time, diff_pair| {
+                        if let Some(warner) = pk_warner.as_mut() {
+                            warner.observe(key, time);
+                        }
+                        let key = key.clone().expect("postgres sinks are always keyed");
+                        output.give(&cap, ((key, diff_pair.after), time, Diff::ONE));
+                    });
+                    // Flush after each batch so the final `(key, time)` group of the walk is
+                    // resolved immediately.
+                    if let Some(warner) = pk_warner.as_mut() {
+                        warner.flush();
+                    }
+                }
+            }
+        }
+    });
+
+    (stream.as_collection(), button.press_on_drop())
+}
+
+fn sink_collection<'scope>(
+    name: String,
+    input: VecCollection<'scope, Timestamp, (Row, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: PostgresSinkConnection,
+    storage_configuration: StorageConfiguration,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    statistics: SinkStatistics,
+    write_handle: impl Future<
+        Output = anyhow::Result<WriteHandle<SourceData, (), Timestamp, StorageDiff>>,
+    > + 'static,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (
+    StreamVec<'scope, Timestamp, HealthStatusMessage>,
+    PressOnDropButton,
+) {
+    let scope = input.scope();
+    let mut builder = OperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write to the target table.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let sink_version = sink.version;
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+
+            let mut writer = PostgresSinkWriter::new(
+                sink_id,
+                &connection,
+                &storage_configuration,
+                statistics,
+                sink_version,
+            )
+            .await?;
+            let resume_upper = writer.upper.clone();
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(mut upper) = resume_upper.clone().into_option() else {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            };
+
+            let mut deferred_updates = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (update, time, _diff) in batch {
+                            // Updates before `upper` have already been committed by a previous
+                            // incarnation of this sink.
+                            match upper.cmp(&time) {
+                                Ordering::Less | Ordering::Equal => {
+                                    deferred_updates.push((update, time));
+                                }
+                                Ordering::Greater => continue,
+                            }
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier, so
+                        // that a restart with an earlier as_of does not skip the snapshot. See
+                        // the Kafka sink for the full argument.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+
+                        let mut ready: Vec<_> = deferred_updates
+                            .extract_if(.., |(_, time)| !progress.less_equal(time))
+                            .collect();
+                        // Stable, so updates at the same time keep their arrival order.
+                        ready.sort_by_key(|(_, time)| *time);
+                        let mut latest = BTreeMap::new();
+                        for ((key, value), _time) in ready {
+                            latest.insert(key, value);
+                        }
+
+                        debug!("{name}: committing transaction for {}", progress.pretty());
+                        writer.commit(latest, progress.clone()).await?;
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        match progress.into_option() {
+                            Some(new_upper) => upper = new_upper,
+                            None => break,
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| {
+        let is_ssh = matches!(
+            error.downcast_ref::<PostgresError>(),
+            Some(PostgresError::Ssh(_) | PostgresError::SshIo(_))
+        );
+        HealthStatusMessage {
+            id: None,
+            update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+            namespace: if is_ssh {
+                StatusNamespace::Ssh
+            } else {
+                StatusNamespace::Postgres
+            },
+        }
+    });
+
+    (statuses, button.press_on_drop())
+}
+
+/// Applies batches of keyed updates to the target table of a Postgres sink, transactionally with
+/// the sink's progress.
+struct PostgresSinkWriter {
+    client: Client,
+    /// The identifier of the sink's row in the progress table.
+    sink_id: String,
+    /// The version of this sink, used to fence out previous versions from writing.
+    sink_version: i64,
+    /// The frontier of the last committed transaction.
+    upper: Antichain<Timestamp>,
+    /// The qualified name of the progress table.
+    progress_table: Sql,
+    key_typ: SqlRelationType,
+    value_typ: SqlRelationType,
+    copy_upserts: Sql,
+    copy_deletes: Sql,
+    apply_upserts: Sql,
+    apply_deletes: Sql,
+    statistics: SinkStatistics,
+}
+
+impl PostgresSinkWriter {
+    /// Connects to the upstream database, prepares the session's temporary tables and registers
+    /// this sink version in the progress table, returning a writer positioned at the last
+    /// committed frontier.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &PostgresSinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+        sink_version: u64,
+    ) -> Result<Self, anyhow::Error> {
+        let config = connection
+            .connection
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::Yes,
+            )
+            .await?;
+        let client = config
+            .connect(
+                &format!("postgres sink {sink_id}"),
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+        let schema = Sql::ident(&connection.schema);
+        let target = sql!("{}.{}", schema.clone(), Sql::ident(&connection.table));
+        let progress_table = sql!("{}.{}", schema, Sql::ident(POSTGRES_SINK_PROGRESS_TABLE));
+
+        let (key_desc, _) = &connection.key_desc_and_indices;
+        let key_names: Vec<_> = key_desc.iter_names().map(|name| name.as_str()).collect();
+        let value_names: Vec<_> = connection
+            .value_desc
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect();
+        let idents = |names: &[&str]| Sql::join(names.iter().map(|name| Sql::ident(name)), ", ");
+
+        // Shaping the temporary tables after the target table lets the server convert the copied
+        // text into the target's column types, and fails early if a column is missing.
+        // `WITH NO DATA` also ensures they carry none of the target's constraints.
+        for (temp_table, names) in [(UPSERTS_TABLE, &value_names), (DELETES_TABLE, &key_names)] {
+            mz_postgres_util::batch_execute(
+                &*client,
+                sql!(
+                    "CREATE TEMPORARY TABLE {} ON COMMIT DELETE ROWS AS SELECT {} FROM {} WITH NO DATA",
+                    Sql::ident(temp_table),
+                    idents(names),
+                    target.clone()
+                ),
+            )
+            .await?;
+        }
+
+        let copy_upserts = sql!(
+            "COPY pg_temp.{} ({}) FROM STDIN",
+            Sql::ident(UPSERTS_TABLE),
+            idents(&value_names)
+        );
+        let copy_deletes = sql!(
+            "COPY pg_temp.{} ({}) FROM STDIN",
+            Sql::ident(DELETES_TABLE),
+            idents(&key_names)
+        );
+
+        let non_key_names: Vec<_> = value_names
+            .iter()
+            .filter(|name| !key_names.contains(name))
+            .copied()
+            .collect();
+        let conflict_action = if non_key_names.is_empty() {
+            sql!("NOTHING")
+        } else {
+            let assignments = non_key_names
+                .iter()
+                .map(|name| sql!("{} = EXCLUDED.{}", Sql::ident(name), Sql::ident(name)));
+            sql!("UPDATE SET {}", Sql::join(assignments, ", "))
+        };
+        let apply_upserts = sql!(
+            "INSERT INTO {} ({}) SELECT {} FROM pg_temp.{} ON CONFLICT ({}) DO {}",
+            target.clone(),
+            idents(&value_names),
+            idents(&value_names),
+            Sql::ident(UPSERTS_TABLE),
+            idents(&key_names),
+            conflict_action
+        );
+        let key_matches = key_names
+            .iter()
+            .map(|name| sql!("t.{} = d.{}", Sql::ident(name), Sql::ident(name)));
+        let apply_deletes = sql!(
+            "DELETE FROM {} AS t USING pg_temp.{} AS d WHERE {}",
+            target,
+            Sql::ident(DELETES_TABLE),
+            Sql::join(key_matches, " AND ")
+        );
+
+        let sink_version = i64::try_from(sink_version)?;
+        let sink_id = sink_id.to_string();
+        let upper = register_sink_version(&client, &progress_table, &sink_id, sink_version).await?;
+
+        Ok(PostgresSinkWriter {
+            client,
+            sink_id,
+            sink_version,
+            upper,
+            progress_table,
+            key_typ: key_desc.typ().clone(),
+            value_typ: connection.value_desc.typ().clone(),
+            copy_upserts,
+            copy_deletes,
+            apply_upserts,
+            apply_deletes,
+            statistics,
+        })
+    }
+
+    /// Applies the latest value of each key, deleting keys whose value is `None`, and records
+    /// `frontier` as the new upper of the sink, all in one transaction.
+    async fn commit(
+        &mut self,
+        updates: BTreeMap<Row, Option<Row>>,
+        frontier: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let client = &*self.client;
+        let mut upserts = vec![];
+        let mut deletes = vec![];
+        for (key, value) in updates {
+            match value {
+                Some(value) => upserts.push(value),
+                None => deletes.push(key),
+            }
+        }
+
+        mz_postgres_util::simple_query(client, sql!("BEGIN")).await?;
+
+        let row = mz_postgres_util::query_opt(
+            client,
+            sql!(
+                "SELECT version, frontier FROM {} WHERE sink_id = $1 FOR UPDATE",
+                self.progress_table.clone()
+            ),
+            &[&self.sink_id],
+        )
+        .await?;
+        let Some(row) = row else {
+            bail!("progress record for sink {} was deleted", self.sink_id);
+        };
+        let version: i64 = row.get("version");
+        let stored_upper = parse_frontier(row.get("frontier"))?;
+        if version != self.sink_version || stored_upper != self.upper {
+            bail!(
+                "sink {} was fenced out: progress record is at version {version} and frontier {}, \
+                 expected version {} and frontier {}",
+                self.sink_id,
+                stored_upper.pretty(),
+                self.sink_version,
+                self.upper.pretty()
+            );
+        }
+
+        let mut bytes = 0;
+        if !deletes.is_empty() {
+            bytes += copy_rows(client, &self.copy_deletes, &deletes, &self.key_typ).await?;
+            mz_postgres_util::execute(client, self.apply_deletes.clone(), &[]).await?;
+        }
+        if !upserts.is_empty() {
+            bytes += copy_rows(client, &self.copy_upserts, &upserts, &self.value_typ).await?;
+            mz_postgres_util::execute(client, self.apply_upserts.clone(), &[]).await?;
+        }
+        let messages = u64::cast_from(upserts.len() + deletes.len());
+        self.statistics.inc_messages_staged_by(messages);
+        self.statistics.inc_bytes_staged_by(bytes);
+
+        mz_postgres_util::execute(
+            client,
+            sql!(
+                "UPDATE {} SET frontier = $2 WHERE sink_id = $1",
+                self.progress_table.clone()
+            ),
+            &[&self.sink_id, &serialize_frontier(&frontier)?],
+        )
+        .await?;
+        mz_postgres_util::simple_query(client, sql!("COMMIT")).await?;
+
+        self.statistics.inc_messages_committed_by(messages);
+        self.statistics.inc_bytes_committed_by(bytes);
+        self.upper = frontier;
+        Ok(())
+    }
+}
+
+/// Ensures the progress table exists and holds a record for this sink that is owned by
+/// `sink_version`, returning the frontier the sink should resume from.
+async fn register_sink_version(
+    client: &Client,
+    progress_table: &Sql,
+    sink_id: &str,
+    sink_version: i64,
+) -> Result<Antichain<Timestamp>, anyhow::Error> {
+    let client = &**client;
+    mz_postgres_util::batch_execute(
+        client,
+        sql!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                sink_id text PRIMARY KEY,
+                version bigint NOT NULL,
+                frontier text NOT NULL
+            )",
+            progress_table.clone()
+        ),
+    )
+    .await?;
+    let initial_frontier = serialize_frontier(&Antichain::from_elem(Timestamp::minimum()))?;
+    mz_postgres_util::execute(
+        client,
+        sql!(
+            "INSERT INTO {} (sink_id, version, frontier) VALUES ($1, $2, $3)
+            ON CONFLICT (sink_id) DO NOTHING",
+            progress_table.clone()
+        ),
+        &[&sink_id, &sink_version, &initial_frontier],
+    )
+    .await?;
+
+    mz_postgres_util::simple_query(client, sql!("BEGIN")).await?;
+    let row = mz_postgres_util::query_one(
+        client,
+        sql!(
+            "SELECT version, frontier FROM {} WHERE sink_id = $1 FOR UPDATE",
+            progress_table.clone()
+        ),
+        &[&sink_id],
+    )
+    .await?;
+    let version: i64 = row.get("version");
+    if version > sink_version {
+        bail!("sink {sink_id} was fenced out by version {version}, this is version {sink_version}");
+    }
+    if version < sink_version {
+        mz_postgres_util::execute(
+            client,
+            sql!(
+                "UPDATE {} SET version = $2 WHERE sink_id = $1",
+                progress_table.clone()
+            ),
+            &[&sink_id, &sink_version],
+        )
+        .await?;
+    }
+    mz_postgres_util::simple_query(client, sql!("COMMIT")).await?;
+
+    parse_frontier(row.get("frontier"))
+}
+
+/// Copies `rows` into the temporary table targeted by the `COPY ... FROM STDIN` statement
+/// `copy`, returning the number of bytes sent.
+async fn copy_rows(
+    client: &tokio_postgres::Client,
+    copy: &Sql,
+    rows: &[Row],
+    typ: &SqlRelationType,
+) -> Result<u64, anyhow::Error> {
+    let params = CopyFormatParams::Text(CopyTextFormatParams::default());
+    let mut sink = pin!(client.copy_in::<_, Bytes>(copy.as_str()).await?);
+    let mut bytes = 0;
+    let mut buf = Vec::with_capacity(COPY_CHUNK_SIZE);
+    for row in rows {
+        mz_pgcopy::encode_copy_format(&params, row, typ, &mut buf)?;
+        if buf.len() >= COPY_CHUNK_SIZE {
+            bytes += u64::cast_from(buf.len());
+            sink.send(Bytes::from(std::mem::take(&mut buf))).await?;
+        }
+    }
+    if !buf.is_empty() {
+        bytes += u64::cast_from(buf.len());
+        sink.send(Bytes::from(buf)).await?;
+    }
+    sink.as_mut().finish().await?;
+    Ok(bytes)
+}
+
+fn serialize_frontier(frontier: &Antichain<Timestamp>) -> Result<String, anyhow::Error> {
+    Ok(serde_json::to_string(frontier.elements())?)
+}
+
+fn parse_frontier(frontier: &str) -> Result<Antichain<Timestamp>, anyhow::Error> {
+    let times: Vec<Timestamp> = serde_json::from_str(frontier)
+        .with_context(|| format!("invalid progress record frontier: {frontier}"))?;
+    Ok(Antichain::from(times))
+}
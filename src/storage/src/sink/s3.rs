@@ -0,0 +1,899 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`S3SinkConnection`].
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ stream of arrangement batches (trace reader dropped)
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃   flatten   ┃ walks each batch's cursor and emits
+//!        ┃             ┃ one (row, time, ±1) per half of a DiffPair
+//!        ┗━━━━━━┯━━━━━━┛
+//!               │ changes
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃     s3      ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!   files + │        │ uppers
+//! manifests │        │
+//!      ╭────v──╮ ╭───v──────╮
+//!      │  S3   │ │   sink   │  <- uppers are advanced once the
+//!      │bucket │ │  shard   │     manifest up to them is written
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Layout
+//!
+//! Every change is written as a row of the sinked relation followed by its timestamp and diff.
+//! Changes are placed in files under `date=YYYY-MM-DD/hour=HH/` prefixes derived from their
+//! timestamp. A file is closed once it reaches the sink's `max_file_size`, and all open files are
+//! closed once `roll_interval` has passed since the last manifest.
+//!
+//! # Manifests
+//!
+//! Whenever the sink closes all of its files, it writes a manifest to
+//! `_manifests/{lower}.json` that lists them along with the range `[lower, upper)` of timestamps
+//! they cover. The files of a manifest contain exactly the changes in that range, and the
+//! manifests of a sink cover contiguous ranges, so a reader that reads the files of every
+//! manifest up to some `upper` sees a consistent cut of the relation as of `upper`. Files that
+//! are not listed in a manifest must be ignored: they are either still being written or were
+//! abandoned by an earlier incarnation of the sink.
+//!
+//! # Restarts
+//!
+//! The manifests are the sink's record of its progress: when the sink restarts it resumes from
+//! the upper of the last manifest, and it only advances the upper of its persist shard once a
+//! manifest up to the new upper has been written.
+//!
+//! Manifests are written with a conditional `PUT` that fails if the object already exists, so
+//! that if two replicas of the sink run concurrently only one of them can commit each range of
+//! timestamps; the other halts. Data files include a nonce that is unique to each incarnation of
+//! the sink, so a repeated attempt at a range never overwrites the files that a manifest refers
+//! to.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{Context, anyhow};
+use aws_sdk_s3::Client;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_types::sdk_config::SdkConfig;
+use chrono::{DateTime, Utc};
+use differential_dataflow::{AsCollection, Hashable, VecCollection};
+use mz_arrow_util::builder::ArrowBuilder;
+use mz_aws_util::s3_uploader::{
+    AWS_S3_MAX_PART_COUNT, CompletedUpload, S3MultiPartUploader, S3MultiPartUploaderConfig,
+};
+use mz_interchange::envelopes::for_each_diff_pair;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::{InTask, OreFutureExt};
+use mz_persist_client::Diagnostics;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_pgcopy::{CopyFormatParams, encode_copy_format, encode_copy_format_header};
+use mz_repr::{Datum, Diff, GlobalId, RelationDesc, Row, SqlScalarType, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{
+    S3_SINK_DIFF_COLUMN, S3_SINK_MANIFEST_PREFIX, S3_SINK_TIMESTAMP_COLUMN, S3SinkConnection,
+    S3SinkFormat, StorageSinkDesc,
+};
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{Event, OperatorBuilder, PressOnDropButton};
+use parquet::arrow::arrow_writer::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use serde::{Deserialize, Serialize};
+use timely::PartialOrder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::StreamVec;
+use timely::dataflow::channels::pact::{Exchange, Pipeline};
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::{PkViolationWarner, SinkBatchStream, SinkRender};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The size of the parts in which data files are uploaded.
+const PART_SIZE_BYTES: u64 = 8 * 1024 * 1024;
+/// The percentage of `max_file_size` at which a Parquet row group is flushed.
+const PARQUET_ROW_GROUP_RATIO: u64 = 20;
+/// The percentage of the row group size that the Arrow builder buffers before it is flushed into
+/// the Parquet writer.
+const ARROW_BUILDER_BUFFER_RATIO: u64 = 150;
+const DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY: usize = 1024;
+const DEFAULT_ARRAY_BUILDER_DATA_CAPACITY: usize = 1024;
+
+impl<'scope> SinkRender<'scope> for S3SinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        batches: SinkBatchStream<'scope>,
+        key_is_synthetic: bool,
+        _err_collection: VecCollection<'scope, Timestamp, DataflowError, Diff>,
+    ) -> (
+        StreamVec<'scope, Timestamp, HealthStatusMessage>,
+        Vec<PressOnDropButton>,
+    ) {
+        let scope = batches.scope();
+
+        let write_handle = {
+            let persist = Arc::clone(&storage_state.persist_clients);
+            let shard_meta = sink.to_storage_metadata.clone();
+            async move {
+                let client = persist.open(shard_meta.persist_location).await?;
+                let handle = client
+                    .open_writer(
+                        shard_meta.data_shard,
+                        Arc::new(shard_meta.relation_desc),
+                        Arc::new(UnitSchema),
+                        Diagnostics::from_purpose("sink handle"),
+                    )
+                    .await?;
+                Ok(handle)
+            }
+        };
+
+        let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, Rc::clone(&write_frontier));
+
+        let (changes, flatten_token) = flatten_changes(
+            format!("s3-{sink_id}-flatten"),
+            batches,
+            sink_id,
+            sink.from,
+            key_is_synthetic,
+        );
+
+        let statistics = storage_state
+            .aggregated_statistics
+            .get_sink(&sink_id)
+            .expect("statistics initialized")
+            .clone();
+
+        let (sink_status, sink_token) = sink_collection(
+            format!("s3-{sink_id}-sink"),
+            changes,
+            sink_id,
+            self.clone(),
+            storage_state.storage_configuration.clone(),
+            sink,
+            statistics,
+            write_handle,
+            write_frontier,
+        );
+
+        let running_status = Some(HealthStatusMessage {
+            id: None,
+            update: HealthStatusUpdate::running(),
+            namespace: StatusNamespace::S3,
+        })
+        .to_stream(scope);
+
+        let status = scope.concatenate([running_status, sink_status]);
+
+        (status, vec![flatten_token, sink_token])
+    }
+}
+
+/// Walks each arrangement batch and emits the `before` of every `DiffPair` as a retraction and
+/// its `after` as an insertion.
+fn flatten_changes<'scope>(
+    name: String,
+    batches: SinkBatchStream<'scope>,
+    sink_id: GlobalId,
+    from_id: GlobalId,
+    key_is_synthetic: bool,
+) -> (
+    VecCollection<'scope, Timestamp, Row, Diff>,
+    PressOnDropButton,
+) {
+    let mut builder = OperatorBuilder::new(name, batches.scope());
+    let (output, stream) = builder.new_output::<CapacityContainerBuilder<Vec<_>>>();
+    let mut input = builder.new_input_for(batches, Pipeline, &output);
+
+    let button = builder.build(move |_caps| async move {
+        let mut pk_warner = (!key_is_synthetic).then(|| PkViolationWarner::new(sink_id, from_id));
+
+        while let Some(event) = input.next().await {
+            if let Event::Data(cap, mut batches) = event {
+                for batch in batches.drain(..) {
+                    for_each_diff_pair(&batch, |key, time, diff_pair| {
+                        if let Some(warner) = pk_warner.as_mut() {
+                            warner.observe(key, time);
+                        }
+                        if let Some(before) = diff_pair.before {
+                            output.give(&cap, (before, time, Diff::MINUS_ONE));
+                        }
+                        if let Some(after) = diff_pair.after {
+                            output.give(&cap, (after, time, Diff::ONE));
+                        }
+                    });
+                    // Flush after each batch so the final `(key, time)` group of the walk is
+                    // resolved immediately.
+                    if let Some(warner) = pk_warner.as_mut() {
+                        warner.flush();
+                    }
+                }
+            }
+        }
+    });
+
+    (stream.as_collection(), button.press_on_drop())
+}
+
+fn sink_collection<'scope>(
+    name: String,
+    input: VecCollection<'scope, Timestamp, Row, Diff>,
+    sink_id: GlobalId,
+    connection: S3SinkConnection,
+    storage_configuration: StorageConfiguration,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    statistics: SinkStatistics,
+    write_handle: impl Future<
+        Output = anyhow::Result<WriteHandle<SourceData, (), Timestamp, StorageDiff>>,
+    > + 'static,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+) -> (
+    StreamVec<'scope, Timestamp, HealthStatusMessage>,
+    PressOnDropButton,
+) {
+    let scope = input.scope();
+    let mut builder = OperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write manifests, so that they cover contiguous ranges.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+            let mut writer =
+                S3SinkWriter::new(sink_id, &connection, &storage_configuration, statistics).await?;
+            let resume_upper = writer.fetch_resume_upper().await?;
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(lower) = resume_upper.clone().into_option() else {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            };
+            writer.lower = lower;
+
+            let mut deferred_changes = vec![];
+            let mut last_roll = Instant::now();
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        for (row, time, diff) in batch {
+                            // Changes before `lower` are covered by the manifests of a previous
+                            // incarnation of this sink.
+                            if time >= writer.lower {
+                                deferred_changes.push((time, row, diff));
+                            }
+                        }
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier, so
+                        // that a restart with an earlier as_of does not skip the snapshot. See
+                        // the Kafka sink for the full argument.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+
+                        let mut ready: Vec<_> = deferred_changes
+                            .extract_if(.., |(time, _, _)| !progress.less_equal(time))
+                            .collect();
+                        ready.sort_by_key(|(time, _, _)| *time);
+                        for (time, row, diff) in ready {
+                            writer.append(time, &row, diff).await?;
+                        }
+
+                        // Only write a manifest once the roll interval has passed, unless the
+                        // input is complete and no more manifests will follow.
+                        if !progress.is_empty() && last_roll.elapsed() < connection.roll_interval {
+                            continue;
+                        }
+                        if PartialOrder::less_equal(&progress, &Antichain::from_elem(writer.lower))
+                        {
+                            continue;
+                        }
+                        writer.commit(progress.as_option().copied()).await?;
+                        last_roll = Instant::now();
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        debug!("{name}: committed up to {}", progress.pretty());
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        match progress.into_option() {
+                            Some(new_lower) => writer.lower = new_lower,
+                            None => break,
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace: StatusNamespace::S3,
+    });
+
+    (statuses, button.press_on_drop())
+}
+
+/// The contents of a manifest, which lists the files that contain the changes in
+/// `[lower, upper)`. An `upper` of `None` means the sink has written every change of its input.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    sink_id: String,
+    lower: u64,
+    upper: Option<u64>,
+    files: Vec<ManifestFile>,
+}
+
+/// A data file listed in a [`Manifest`].
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestFile {
+    /// The key of the file, relative to the bucket.
+    key: String,
+    /// The time partition of the file, e.g. `date=2024-01-01/hour=00`.
+    partition: String,
+    rows: u64,
+    bytes: u64,
+}
+
+/// Writes the changes of an S3 sink into data files and commits them with manifests.
+struct S3SinkWriter {
+    client: Client,
+    sdk_config: SdkConfig,
+    sink_id: GlobalId,
+    bucket: String,
+    /// The key prefix of the sink, without leading or trailing slashes.
+    prefix: String,
+    format: S3SinkFormat,
+    /// The relation desc of the rows in the data files, i.e. the sinked relation followed by the
+    /// timestamp and diff columns.
+    desc: Arc<RelationDesc>,
+    max_file_size: u64,
+    /// Distinguishes the data files of this incarnation of the sink from those of others.
+    nonce: Uuid,
+    /// The lower bound of the timestamps of the changes that have not yet been committed.
+    lower: Timestamp,
+    /// The index of the next data file since `lower`.
+    next_file_index: u64,
+    /// The data files being written, by time partition.
+    open_files: BTreeMap<String, S3SinkFile>,
+    /// The data files that have been uploaded since `lower`.
+    completed_files: Vec<ManifestFile>,
+    statistics: SinkStatistics,
+}
+
+impl S3SinkWriter {
+    async fn new(
+        sink_id: GlobalId,
+        connection: &S3SinkConnection,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<Self, anyhow::Error> {
+        let sdk_config = connection
+            .aws_connection
+            .load_sdk_config(
+                &storage_configuration.connection_context,
+                connection.connection_id,
+                InTask::Yes,
+                ENFORCE_EXTERNAL_ADDRESSES.get(storage_configuration.config_set()),
+            )
+            .await?;
+        let client = mz_aws_util::s3::new_client(&sdk_config);
+        let (bucket, prefix) = connection.bucket_and_prefix();
+
+        let desc = connection.value_desc.clone().concat(
+            RelationDesc::builder()
+                .with_column(
+                    S3_SINK_TIMESTAMP_COLUMN,
+                    SqlScalarType::MzTimestamp.nullable(false),
+                )
+                .with_column(S3_SINK_DIFF_COLUMN, SqlScalarType::Int64.nullable(false))
+                .finish(),
+        );
+
+        Ok(S3SinkWriter {
+            client,
+            sdk_config,
+            sink_id,
+            bucket,
+            prefix,
+            format: connection.format.clone(),
+            desc: Arc::new(desc),
+            max_file_size: connection.max_file_size,
+            nonce: Uuid::new_v4(),
+            lower: Timestamp::minimum(),
+            next_file_index: 0,
+            open_files: BTreeMap::new(),
+            completed_files: vec![],
+            statistics,
+        })
+    }
+
+    /// Returns the full key of `path`, relative to the sink's prefix.
+    fn key(&self, path: &str) -> String {
+        if self.prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}/{}", self.prefix, path)
+        }
+    }
+
+    /// Returns the upper of the last manifest, or the minimum timestamp if there is none.
+    async fn fetch_resume_upper(&self) -> Result<Antichain<Timestamp>, anyhow::Error> {
+        let manifest_prefix = self.key(&format!("{S3_SINK_MANIFEST_PREFIX}/"));
+
+        // Manifest keys are zero-padded, so the last key in lexicographic order is the manifest
+        // with the greatest lower.
+        let mut last_key: Option<String> = None;
+        let mut continuation_token = None;
+        loop {
+            let response = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(&manifest_prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .with_context(|| format!("listing s3://{}/{}", self.bucket, manifest_prefix))?;
+            for object in response.contents() {
+                if let Some(key) = object.key()
+                    && last_key.as_deref().map_or(true, |last| key > last)
+                {
+                    last_key = Some(key.to_string());
+                }
+            }
+            match response.next_continuation_token() {
+                Some(token) => continuation_token = Some(token.to_string()),
+                None => break,
+            }
+        }
+
+        let Some(last_key) = last_key else {
+            return Ok(Antichain::from_elem(Timestamp::minimum()));
+        };
+        let body = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&last_key)
+            .send()
+            .await
+            .with_context(|| format!("reading manifest s3://{}/{}", self.bucket, last_key))?
+            .body
+            .collect()
+            .await?
+            .into_bytes();
+        let manifest: Manifest = serde_json::from_slice(&body)
+            .with_context(|| format!("parsing manifest s3://{}/{}", self.bucket, last_key))?;
+        if manifest.sink_id != self.sink_id.to_string() {
+            return Err(anyhow!(
+                "s3://{}/{} already contains the manifests of sink {}",
+                self.bucket,
+                self.prefix,
+                manifest.sink_id
+            ));
+        }
+        Ok(match manifest.upper {
+            Some(upper) => Antichain::from_elem(Timestamp::from(upper)),
+            None => Antichain::new(),
+        })
+    }
+
+    /// Appends a change to the data file of its time partition, closing the file if it has
+    /// reached the maximum file size.
+    async fn append(
+        &mut self,
+        time: Timestamp,
+        row: &Row,
+        diff: Diff,
+    ) -> Result<(), anyhow::Error> {
+        let mut full_row = Row::default();
+        let mut packer = full_row.packer();
+        packer.extend_by_row(row);
+        packer.push(Datum::MzTimestamp(time));
+        packer.push(Datum::Int64(diff.into_inner()));
+
+        let partition = time_partition(time);
+        if !self.open_files.contains_key(&partition) {
+            let file = self.start_file(&partition).await?;
+            self.open_files.insert(partition.clone(), file);
+        }
+        let file = self.open_files.get_mut(&partition).expect("inserted above");
+        file.append(&full_row, &self.desc)?;
+        self.statistics.inc_messages_staged_by(1);
+
+        if file.size_estimate() >= self.max_file_size {
+            debug!(
+                "s3 sink {}: file size limit exceeded, rolling file",
+                self.sink_id
+            );
+            let file = self.open_files.remove(&partition).expect("known to exist");
+            self.finish_file(file).await?;
+        }
+        Ok(())
+    }
+
+    /// Starts the upload of a new data file in `partition`.
+    async fn start_file(&mut self, partition: &str) -> Result<S3SinkFile, anyhow::Error> {
+        let extension = match &self.format {
+            S3SinkFormat::PgCopy(params) => params.file_extension(),
+            S3SinkFormat::Parquet => "parquet",
+        };
+        let key = self.key(&format!(
+            "{partition}/part-{:020}-{}-{:04}.{extension}",
+            u64::from(self.lower),
+            self.nonce,
+            self.next_file_index,
+        ));
+        self.next_file_index += 1;
+
+        let sdk_config = self.sdk_config.clone();
+        let bucket = self.bucket.clone();
+        let upload_key = key.clone();
+        let uploader = async move {
+            S3MultiPartUploader::try_new(
+                &sdk_config,
+                bucket,
+                upload_key,
+                S3MultiPartUploaderConfig {
+                    part_size_limit: PART_SIZE_BYTES,
+                    // We enforce the max file size ourselves, so only limit the upload to the
+                    // largest file the part size allows.
+                    file_size_limit: PART_SIZE_BYTES
+                        .checked_mul(AWS_S3_MAX_PART_COUNT.try_into().expect("known safe"))
+                        .expect("known safe"),
+                },
+            )
+            .await
+        }
+        .run_in_task(|| "s3_sink::start_file")
+        .await?;
+
+        let encoder = match &self.format {
+            S3SinkFormat::PgCopy(params) => FileEncoder::PgCopy(params.clone()),
+            S3SinkFormat::Parquet => {
+                let row_group_size = self.max_file_size * PARQUET_ROW_GROUP_RATIO / 100;
+                FileEncoder::Parquet(ParquetEncoder::new(
+                    Arc::clone(&self.desc),
+                    row_group_size,
+                    row_group_size * ARROW_BUILDER_BUFFER_RATIO / 100,
+                )?)
+            }
+        };
+        let mut file = S3SinkFile {
+            key,
+            partition: partition.to_string(),
+            rows: 0,
+            uploader,
+            encoder,
+        };
+        file.start(&self.desc)?;
+        info!("s3 sink {}: started file {}", self.sink_id, file.key);
+        Ok(file)
+    }
+
+    /// Completes the upload of `file` and records it for the next manifest.
+    async fn finish_file(&mut self, file: S3SinkFile) -> Result<(), anyhow::Error> {
+        let key = file.key.clone();
+        let partition = file.partition.clone();
+        let rows = file.rows;
+        let upload = file.finish().run_in_task(|| "s3_sink::finish_file").await?;
+        info!(
+            "s3 sink {}: finished file {}, bytes_uploaded {}, parts_uploaded {}",
+            self.sink_id, upload.key, upload.total_bytes_uploaded, upload.part_count
+        );
+        self.statistics
+            .inc_bytes_staged_by(upload.total_bytes_uploaded);
+        self.completed_files.push(ManifestFile {
+            key,
+            partition,
+            rows,
+            bytes: upload.total_bytes_uploaded,
+        });
+        Ok(())
+    }
+
+    /// Closes all open data files and writes the manifest of `[lower, upper)`.
+    ///
+    /// Fails if the manifest already exists, which means that another incarnation of the sink has
+    /// committed the same range.
+    async fn commit(&mut self, upper: Option<Timestamp>) -> Result<(), anyhow::Error> {
+        for (_, file) in std::mem::take(&mut self.open_files) {
+            self.finish_file(file).await?;
+        }
+
+        let manifest = Manifest {
+            sink_id: self.sink_id.to_string(),
+            lower: u64::from(self.lower),
+            upper: upper.map(u64::from),
+            files: std::mem::take(&mut self.completed_files),
+        };
+        let messages = manifest.files.iter().map(|f| f.rows).sum();
+        let bytes = manifest.files.iter().map(|f| f.bytes).sum();
+        let key = self.key(&format!(
+            "{S3_SINK_MANIFEST_PREFIX}/{:020}.json",
+            manifest.lower
+        ));
+        let body = serde_json::to_vec(&manifest).expect("manifest is serializable");
+
+        let result = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .content_type("application/json")
+            .if_none_match("*")
+            .body(ByteStream::from(body))
+            .send()
+            .await;
+        if let Err(e) = result {
+            let precondition_failed = e
+                .raw_response()
+                .is_some_and(|response| response.status().as_u16() == 412);
+            if precondition_failed {
+                return Err(anyhow!(
+                    "manifest s3://{}/{} was written concurrently by another replica",
+                    self.bucket,
+                    key
+                ));
+            }
+            return Err(anyhow::Error::new(e)
+                .context(format!("writing manifest s3://{}/{}", self.bucket, key)));
+        }
+
+        self.statistics.inc_messages_committed_by(messages);
+        self.statistics.inc_bytes_committed_by(bytes);
+        self.next_file_index = 0;
+        Ok(())
+    }
+}
+
+/// Returns the time partition of the changes at `time`.
+fn time_partition(time: Timestamp) -> String {
+    let millis = i64::try_from(u64::from(time)).unwrap_or(i64::MAX);
+    let datetime = DateTime::from_timestamp_millis(millis).unwrap_or(DateTime::<Utc>::MAX_UTC);
+    datetime.format("date=%Y-%m-%d/hour=%H").to_string()
+}
+
+/// A data file whose upload is in progress.
+struct S3SinkFile {
+    key: String,
+    partition: String,
+    rows: u64,
+    uploader: S3MultiPartUploader,
+    encoder: FileEncoder,
+}
+
+enum FileEncoder {
+    PgCopy(CopyFormatParams<'static>),
+    Parquet(ParquetEncoder),
+}
+
+impl S3SinkFile {
+    /// Writes the header of the file, if its format has one.
+    fn start(&mut self, desc: &RelationDesc) -> Result<(), anyhow::Error> {
+        if let FileEncoder::PgCopy(params) = &self.encoder
+            && params.requires_header()
+        {
+            let mut buf = vec![];
+            encode_copy_format_header(params, desc, &mut buf)
+                .map_err(|_| anyhow!("error encoding header"))?;
+            self.uploader.buffer_chunk(&buf)?;
+        }
+        Ok(())
+    }
+
+    fn append(&mut self, row: &Row, desc: &RelationDesc) -> Result<(), anyhow::Error> {
+        match &mut self.encoder {
+            FileEncoder::PgCopy(params) => {
+                let mut buf = vec![];
+                encode_copy_format(params, row, desc.typ(), &mut buf)
+                    .map_err(|_| anyhow!("error encoding row"))?;
+                self.uploader.buffer_chunk(&buf)?;
+            }
+            FileEncoder::Parquet(encoder) => encoder.add_row(row, &mut self.uploader)?,
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Returns an approximate size of the file.
+    fn size_estimate(&self) -> u64 {
+        let buffered = match &self.encoder {
+            FileEncoder::PgCopy(_) => 0,
+            FileEncoder::Parquet(encoder) => u64::cast_from(encoder.writer.in_progress_size()),
+        };
+        buffered + self.uploader.added_bytes()
+    }
+
+    async fn finish(mut self) -> Result<CompletedUpload, anyhow::Error> {
+        if let FileEncoder::Parquet(mut encoder) = self.encoder {
+            encoder.flush_builder(&mut self.uploader)?;
+            let buffer = encoder.writer.into_inner()?;
+            self.uploader.buffer_chunk(buffer.as_slice())?;
+        }
+        Ok(self.uploader.finish().await?)
+    }
+}
+
+/// Encodes rows into a Parquet file, flushing row groups into the file's uploader.
+struct ParquetEncoder {
+    builder: ArrowBuilder,
+    writer: ArrowWriter<Vec<u8>>,
+    desc: Arc<RelationDesc>,
+    row_group_size: u64,
+    arrow_builder_buffer_bytes: u64,
+}
+
+impl ParquetEncoder {
+    fn new(
+        desc: Arc<RelationDesc>,
+        row_group_size: u64,
+        arrow_builder_buffer_bytes: u64,
+    ) -> Result<Self, anyhow::Error> {
+        let builder = ArrowBuilder::new(
+            &desc,
+            DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY,
+            DEFAULT_ARRAY_BUILDER_DATA_CAPACITY,
+        )?;
+        let props = WriterProperties::builder()
+            // We flush row groups based on their size in bytes, not their number of rows.
+            .set_max_row_group_size(usize::MAX)
+            // Max compatibility
+            .set_writer_version(WriterVersion::PARQUET_1_0)
+            .set_compression(Compression::SNAPPY)
+            .set_statistics_enabled(EnabledStatistics::None)
+            .build();
+        let writer = ArrowWriter::try_new(Vec::new(), builder.schema().into(), Some(props))?;
+        Ok(ParquetEncoder {
+            builder,
+            writer,
+            desc,
+            row_group_size,
+            arrow_builder_buffer_bytes,
+        })
+    }
+
+    fn add_row(
+        &mut self,
+        row: &Row,
+        uploader: &mut S3MultiPartUploader,
+    ) -> Result<(), anyhow::Error> {
+        self.builder.add_row(row)?;
+        if u64::cast_from(self.builder.row_size_bytes()) > self.arrow_builder_buffer_bytes {
+            self.flush_builder(uploader)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the Arrow builder into the Parquet writer, and any row group the writer completes
+    /// into the uploader.
+    fn flush_builder(&mut self, uploader: &mut S3MultiPartUploader) -> Result<(), anyhow::Error> {
+        let builder = std::mem::replace(
+            &mut self.builder,
+            ArrowBuilder::new(
+                &self.desc,
+                DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY,
+                DEFAULT_ARRAY_BUILDER_DATA_CAPACITY,
+            )?,
+        );
+        let batch = builder.to_record_batch()?;
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let before_groups = self.writer.flushed_row_groups().len();
+        self.writer.write(&batch)?;
+        if u64::cast_from(self.writer.in_progress_size()) > self.row_group_size {
+            self.writer.flush()?;
+        }
+        if self.writer.flushed_row_groups().len() > before_groups {
+            let buffer = self.writer.inner_mut();
+            uploader.buffer_chunk(buffer.as_slice())?;
+            buffer.clear();
+        }
+        Ok(())
+    }
+}
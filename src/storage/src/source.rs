@@ -27,15 +27,22 @@
 
 use crate::source::types::SourceMessage;
 
+mod contents;
 mod probe;
 pub(crate) mod reclock;
 mod source_reader_pipeline;
 pub mod types;
 
 pub mod generator;
+mod iceberg;
 mod kafka;
+mod kinesis;
+mod mongodb;
+mod mqtt;
 mod mysql;
+mod nats;
 mod postgres;
+mod s3;
 mod sql_server;
 
 pub use kafka::KafkaSourceReader;
@@ -0,0 +1,174 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The contents of source outputs, for sources whose upstream changes don't fully describe the
+//! rows they remove.
+//!
+//! A PostgreSQL `TRUNCATE` doesn't say which rows it removed, and the transaction metadata that CDC
+//! sources can include in their outputs is that of the transaction that inserted a row, which the
+//! transaction that removes the row doesn't know about. Sources keep the contents of the affected
+//! outputs to fill in the blanks. The contents are seeded from the persist shard of the output as
+//! of the point the output resumes from, and cost memory proportional to the size of the output.
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::sync::Arc;
+
+use mz_persist_client::Diagnostics;
+use mz_persist_client::read::Since;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::sources::SourceData;
+use timely::progress::Antichain;
+
+use crate::source::RawSourceCreationConfig;
+
+/// The rows of a source output, split into their value and the metadata columns appended to it.
+/// Errors are never retracted, so they are not part of the contents.
+#[derive(Debug)]
+pub(crate) struct OutputContents {
+    /// The number of metadata columns of the output.
+    metadata_len: usize,
+    /// The multiplicity of every row, by value and then by metadata.
+    rows: BTreeMap<Row, BTreeMap<Row, Diff>>,
+}
+
+impl OutputContents {
+    /// Returns empty contents for an output with `metadata_len` metadata columns.
+    pub(crate) fn new(metadata_len: usize) -> Self {
+        Self {
+            metadata_len,
+            rows: BTreeMap::new(),
+        }
+    }
+
+    /// Reads the contents of the persist shard of `export_id`, which has `metadata_len` metadata
+    /// columns, as of the point the output resumes from.
+    ///
+    /// Fails if the shard has been compacted past that point, which can happen if the output's
+    /// read hold was released in the meantime. Callers should restart the source, which then
+    /// resumes from a readable point.
+    pub(crate) async fn hydrate(
+        config: &RawSourceCreationConfig,
+        export_id: GlobalId,
+        metadata_len: usize,
+    ) -> Result<Self, anyhow::Error> {
+        let mut contents = Self::new(metadata_len);
+
+        let resume_upper = &config.resume_uppers[&export_id];
+        let Some(as_of) = resume_upper.as_option().and_then(|upper| upper.step_back()) else {
+            // Either nothing was ever committed to the output or it will never be written to
+            // again.
+            return Ok(contents);
+        };
+
+        let metadata = &config.source_exports[&export_id].storage_metadata;
+        let client = config
+            .persist_clients
+            .open(metadata.persist_location.clone())
+            .await
+            .expect("location unavailable");
+        let mut read_handle = client
+            .open_leased_reader::<SourceData, (), mz_repr::Timestamp, StorageDiff>(
+                metadata.data_shard,
+                Arc::new(metadata.relation_desc.clone()),
+                Arc::new(UnitSchema),
+                Diagnostics {
+                    shard_name: export_id.to_string(),
+                    handle_purpose: format!("output contents {}", config.id),
+                },
+                false,
+            )
+            .await
+            .expect("shard unavailable");
+        let updates = match read_handle
+            .snapshot_and_fetch(Antichain::from_elem(as_of))
+            .await
+        {
+            Ok(updates) => updates,
+            Err(Since(since)) => {
+                read_handle.expire().await;
+                anyhow::bail!(
+                    "contents of {export_id} are not readable at {as_of}, the shard's since is \
+                     {since:?}"
+                );
+            }
+        };
+        let mut value = Row::default();
+        let mut metadata = Row::default();
+        for ((SourceData(data), ()), _, diff) in updates {
+            let Ok(row) = data else {
+                continue;
+            };
+            let value_len = row.iter().count().saturating_sub(metadata_len);
+            value.packer().extend(row.iter().take(value_len));
+            metadata.packer().extend(row.iter().skip(value_len));
+            contents.update(&value, &metadata, Diff::from(diff));
+        }
+
+        read_handle.expire().await;
+        Ok(contents)
+    }
+
+    /// Applies an update of the row with the given `value` and `metadata` to the contents.
+    pub(crate) fn update(&mut self, value: &Row, metadata: &Row, diff: Diff) {
+        let versions = self.rows.entry(value.clone()).or_default();
+        match versions.entry(metadata.clone()) {
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() += diff;
+                if entry.get().is_zero() {
+                    entry.remove();
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(diff);
+            }
+        }
+        if versions.is_empty() {
+            self.rows.remove(value);
+        }
+    }
+
+    /// Replaces `metadata` with the metadata that a retraction of `value` must carry to retract a
+    /// row of the contents.
+    ///
+    /// Rows with `metadata` itself are preferred, then the row with the smallest metadata. If no
+    /// row has `value` it was inserted by the snapshot of the output, whose rows have all of their
+    /// metadata columns set to null.
+    pub(crate) fn retraction_metadata(&self, value: &Row, metadata: &mut Row) {
+        let mut versions = self
+            .rows
+            .get(value)
+            .into_iter()
+            .flatten()
+            .filter(|(_, diff)| diff.is_positive())
+            .map(|(metadata, _)| metadata);
+        let Some(first) = versions.next() else {
+            metadata
+                .packer()
+                .extend(std::iter::repeat_n(Datum::Null, self.metadata_len));
+            return;
+        };
+        if first != metadata && !versions.any(|version| version == metadata) {
+            metadata.clone_from(first);
+        }
+    }
+
+    /// Removes all rows from the contents, returning their value, metadata and multiplicity.
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = (Row, Row, Diff)> + use<> {
+        std::mem::take(&mut self.rows)
+            .into_iter()
+            .flat_map(|(value, versions)| {
+                versions
+                    .into_iter()
+                    .map(move |(metadata, diff)| (value.clone(), metadata, diff))
+            })
+    }
+}
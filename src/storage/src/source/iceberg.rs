@@ -0,0 +1,426 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of an [`IcebergSourceConnection`].
+//!
+//! An Iceberg source ingests tables of a namespace of an Iceberg catalog. Every
+//! table of the source first ingests the snapshot of its upstream table that
+//! is current when the table starts ingesting, and then periodically ingests
+//! the difference between the last snapshot it ingested and the current one.
+//! The difference is computed from the data files of the two snapshots along
+//! with the delete files that apply to them, so appends, position deletes and
+//! equality deletes are all reflected in the table.
+//!
+//! # Timestamps
+//!
+//! The source is timestamped with [`IcebergTimestamp`], in which every table is
+//! its own partition identified by its UUID. The offset of a partition is one
+//! more than the sequence number of the last snapshot of the table that has
+//! been ingested, and the updates that move a table to a snapshot are emitted
+//! at the sequence number of that snapshot. Tables that can no longer be
+//! ingested, because they were dropped, replaced, or had their history
+//! rewritten, are omitted from the frontier altogether.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use differential_dataflow::AsCollection;
+use iceberg::spec::{Snapshot, SnapshotRef, TableMetadata};
+use iceberg::table::Table;
+use iceberg::{Catalog, ErrorKind, TableIdent};
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId, RelationDesc};
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::iceberg::IcebergTimestamp;
+use mz_storage_types::sources::{
+    IcebergSourceConnection, MzOffset, SourceExport, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::PressOnDropButton;
+use mz_timely_util::order::{Partitioned, Step};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Concat;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+use uuid::Uuid;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::RawSourceCreationConfig;
+use crate::source::types::{Probe, SourceMessage, SourceRender, StackedCollection};
+
+mod progress;
+mod reader;
+
+#[derive(Debug, Clone)]
+struct SourceOutputInfo {
+    /// The name of the upstream table.
+    table: String,
+    /// The UUID of the upstream table.
+    table_uuid: Uuid,
+    /// The columns of the table.
+    desc: RelationDesc,
+    /// Upper to resume ingestion from.
+    resume_upper: Antichain<IcebergTimestamp>,
+    /// An index to split the timely stream.
+    partition_index: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReplicationError {
+    #[error(transparent)]
+    Transient(#[from] Rc<TransientError>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransientError {
+    #[error(transparent)]
+    Iceberg(#[from] iceberg::Error),
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+/// An error that prevents a single table of the source from ingesting any further snapshots.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("table {0} was dropped")]
+    TableDropped(String),
+    #[error("table {0} was dropped and replaced by another table with the same name")]
+    TableReplaced(String),
+    #[error(
+        "the history of table {table} no longer contains the snapshot with sequence number \
+        {sequence_number}, which was rolled back or expired before it was ingested past"
+    )]
+    HistoryRewritten { table: String, sequence_number: u64 },
+    #[error("column {column} of table {table} was dropped")]
+    ColumnDropped { table: String, column: String },
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+/// Returns the frontier at which each of the tables in `tables` has been ingested up to its
+/// offset. Tables without an offset are omitted from the frontier, which means that their
+/// partitions are complete.
+fn tables_frontier(tables: &BTreeMap<Uuid, Option<MzOffset>>) -> Antichain<IcebergTimestamp> {
+    let mut frontier = Antichain::new();
+    // The lowest partition that hasn't been added to the frontier yet.
+    let mut lower = Some(Uuid::nil());
+    for (id, offset) in tables {
+        let Some(range_lower) = lower else {
+            unreachable!("no partitions after {id}");
+        };
+        if let Some(range_upper) = id.backward_checked(1)
+            && range_lower <= range_upper
+        {
+            frontier.insert(Partitioned::new_range(
+                range_lower,
+                range_upper,
+                MzOffset::from(0),
+            ));
+        }
+        if let Some(offset) = offset {
+            frontier.insert(Partitioned::new_singleton(*id, *offset));
+        }
+        lower = id.forward_checked(1);
+    }
+    if let Some(range_lower) = lower {
+        let range_upper = Uuid::from_u128(u128::MAX);
+        frontier.insert(Partitioned::new_range(
+            range_lower,
+            range_upper,
+            MzOffset::from(0),
+        ));
+    }
+    frontier
+}
+
+/// Returns the offset of `table` at `frontier`, or `None` if the frontier has no element for
+/// the table, i.e. the table has ended.
+fn table_offset(frontier: &[IcebergTimestamp], table: Uuid) -> Option<MzOffset> {
+    frontier
+        .iter()
+        .filter(|ts| ts.interval().contains(&table))
+        .map(|ts| *ts.timestamp())
+        .min()
+}
+
+/// Returns the sequence number of `snapshot`.
+fn sequence_number(snapshot: &Snapshot) -> u64 {
+    // Sequence numbers are never negative.
+    u64::try_from(snapshot.sequence_number()).unwrap_or(0)
+}
+
+/// Returns the sequence number of the current snapshot of the table, or `0` if the table has no
+/// snapshots yet.
+fn current_sequence_number(metadata: &TableMetadata) -> u64 {
+    metadata
+        .current_snapshot()
+        .map(|snapshot| sequence_number(snapshot))
+        .unwrap_or(0)
+}
+
+/// Returns the snapshot with the given sequence number among the current snapshot of the table
+/// and its ancestors.
+fn ancestor_with_sequence_number(
+    metadata: &TableMetadata,
+    sequence_number: u64,
+) -> Option<SnapshotRef> {
+    let mut snapshot = metadata.current_snapshot().cloned();
+    while let Some(current) = snapshot {
+        let current_sequence_number = self::sequence_number(&current);
+        if current_sequence_number == sequence_number {
+            return Some(current);
+        }
+        if current_sequence_number < sequence_number {
+            return None;
+        }
+        snapshot = current
+            .parent_snapshot_id()
+            .and_then(|id| metadata.snapshot_by_id(id))
+            .cloned();
+    }
+    None
+}
+
+/// Loads the table `ident`, which the source ingests as the table with the UUID `uuid`.
+///
+/// Returns a definite error if the table no longer exists, or if it has been replaced by another
+/// table with the same name.
+async fn load_table(
+    catalog: &dyn Catalog,
+    ident: &TableIdent,
+    uuid: Uuid,
+) -> Result<Result<Table, DefiniteError>, TransientError> {
+    match catalog.load_table(ident).await {
+        Ok(table) if table.metadata().uuid() == uuid => Ok(Ok(table)),
+        Ok(_) => Ok(Err(DefiniteError::TableReplaced(ident.name().to_string()))),
+        Err(err)
+            if matches!(err.kind(), ErrorKind::TableNotFound { .. })
+                || err
+                    .message()
+                    .contains("Tried to load a table that does not exist") =>
+        {
+            Ok(Err(DefiniteError::TableDropped(ident.name().to_string())))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+impl SourceRender for IcebergSourceConnection {
+    type Time = IcebergTimestamp;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::Iceberg;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, IcebergTimestamp>,
+        config: &RawSourceCreationConfig,
+        resume_uppers: impl futures::Stream<Item = Antichain<IcebergTimestamp>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        // Timely Collection for each Source Export defined in the provided `config`.
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, IcebergTimestamp, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, IcebergTimestamp, HealthStatusMessage>,
+        StreamVec<'scope, IcebergTimestamp, Probe<IcebergTimestamp>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // Collect the source outputs that we will be exporting.
+        let mut source_outputs = BTreeMap::new();
+        for (idx, (id, export)) in config.source_exports.iter().enumerate() {
+            let SourceExport {
+                details,
+                storage_metadata,
+                data_config: _,
+            } = export;
+
+            let details = match details {
+                SourceExportDetails::Iceberg(details) => details,
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            };
+
+            let resume_upper = config
+                .source_resume_uppers
+                .get(id)
+                .expect("missing resume upper")
+                .iter()
+                .map(IcebergTimestamp::decode_row);
+
+            let output_info = SourceOutputInfo {
+                table: details.table.clone(),
+                table_uuid: details.table_uuid,
+                desc: storage_metadata.relation_desc.clone(),
+                resume_upper: Antichain::from_iter(resume_upper),
+                partition_index: u64::cast_from(idx),
+            };
+            source_outputs.insert(*id, output_info);
+        }
+
+        let metrics = config.metrics.get_iceberg_source_metrics(config.id);
+
+        let (repl_updates, repl_errs, repl_token) = reader::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs.clone(),
+            self.clone(),
+            metrics,
+        );
+
+        let (progress_errs, progress_probes, progress_token) = progress::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs,
+            self.clone(),
+            resume_uppers,
+        );
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = repl_updates
+            .inner
+            .partition::<CapacityContainerBuilder<_>, _, _>(
+                partition_count,
+                move |((partition_idx, data), time, diff): (
+                    (u64, Result<SourceMessage, DataflowError>),
+                    IcebergTimestamp,
+                    Diff,
+                )| { (partition_idx, (data, time, diff)) },
+            );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = repl_errs.concat(progress_errs).map(move |err| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            let update = HealthStatusUpdate::halting(err_string, None);
+
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update,
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (
+            data_collections,
+            health,
+            progress_probes,
+            vec![repl_token, progress_token],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use timely::PartialOrder;
+    use timely::progress::Timestamp;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_tables_frontier_roundtrip() {
+        let a = Uuid::from_u128(10);
+        let b = Uuid::from_u128(20);
+        let c = Uuid::from_u128(30);
+        let tables = BTreeMap::from([
+            (a, Some(MzOffset::from(1))),
+            (b, Some(MzOffset::from(7))),
+            (c, None),
+        ]);
+        let frontier = tables_frontier(&tables);
+        // Every table with an offset is a singleton, with a range in between each of them.
+        assert_eq!(frontier.len(), 6);
+        assert_eq!(
+            table_offset(frontier.elements(), a),
+            Some(MzOffset::from(1))
+        );
+        assert_eq!(
+            table_offset(frontier.elements(), b),
+            Some(MzOffset::from(7))
+        );
+        // Ended tables are omitted from the frontier.
+        assert_eq!(table_offset(frontier.elements(), c), None);
+        // Other partitions stay at the minimum offset.
+        assert_eq!(
+            table_offset(frontier.elements(), Uuid::from_u128(15)),
+            Some(MzOffset::from(0))
+        );
+
+        assert_eq!(
+            tables_frontier(&BTreeMap::new()),
+            Antichain::from_elem(IcebergTimestamp::minimum())
+        );
+        assert_eq!(
+            table_offset(&[IcebergTimestamp::minimum()], a),
+            Some(MzOffset::from(0))
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_tables_frontier_advances() {
+        let a = Uuid::from_u128(10);
+        let before = tables_frontier(&BTreeMap::from([(a, Some(MzOffset::from(3)))]));
+        let after = tables_frontier(&BTreeMap::from([(a, Some(MzOffset::from(5)))]));
+        let ended = tables_frontier(&BTreeMap::from([(a, None)]));
+        assert!(PartialOrder::less_than(&before, &after));
+        assert!(PartialOrder::less_than(&after, &ended));
+
+        // The updates that move a table to a snapshot are emitted at its sequence number, which
+        // is beyond the frontier before the snapshot is ingested but not after.
+        let time = Partitioned::new_singleton(a, MzOffset::from(4));
+        assert!(before.less_equal(&time));
+        assert!(!after.less_equal(&time));
+    }
+
+    #[mz_ore::test]
+    fn test_tables_frontier_extremes() {
+        let tables = BTreeMap::from([
+            (Uuid::nil(), Some(MzOffset::from(2))),
+            (Uuid::from_u128(u128::MAX), None),
+        ]);
+        let frontier = tables_frontier(&tables);
+        assert_eq!(frontier.len(), 2);
+        assert_eq!(
+            table_offset(frontier.elements(), Uuid::nil()),
+            Some(MzOffset::from(2))
+        );
+        assert_eq!(
+            table_offset(frontier.elements(), Uuid::from_u128(u128::MAX)),
+            None
+        );
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A "non-critical" operator that tracks the progress of an [`IcebergSourceConnection`].
+//!
+//! The operator does the following:
+//!
+//! * At some cadence `timestamp_interval` will load every table of the source,
+//!   emit the frontier at which the current snapshot of each of them has been
+//!   ingested as the upstream frontier, and update `SourceStatistics` with the
+//!   sequence number of the current snapshot of each table.
+//! * Listen to a provided [`futures::Stream`] of resume uppers, which represents
+//!   the durably committed upper for _all_ of the exports associated with this
+//!   source, and update `SourceStatistics` with the sequence number of the last
+//!   ingested snapshot of each table.
+//!
+//! The upstream frontier of a table never regresses, even if its history is
+//! rolled back. Tables that have been dropped or replaced are omitted from the
+//! upstream frontier from then on.
+//!
+//! [`IcebergSourceConnection`]: mz_storage_types::sources::IcebergSourceConnection
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use futures::StreamExt;
+use iceberg::{NamespaceIdent, TableIdent};
+use mz_ore::future::InTask;
+use mz_repr::GlobalId;
+use mz_storage_types::sources::iceberg::IcebergTimestamp;
+use mz_storage_types::sources::{IcebergSourceConnection, MzOffset};
+use mz_timely_util::builder_async::{OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+use uuid::Uuid;
+
+use crate::source::iceberg::{
+    ReplicationError, SourceOutputInfo, TransientError, current_sequence_number, load_table,
+    table_offset, tables_frontier,
+};
+use crate::source::types::Probe;
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// Used as a partition ID to determine the worker that is responsible for
+/// handling progress.
+static PROGRESS_WORKER: &str = "progress";
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, IcebergTimestamp>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: IcebergSourceConnection,
+    committed_uppers: impl futures::Stream<Item = Antichain<IcebergTimestamp>> + 'static,
+) -> (
+    StreamVec<'scope, IcebergTimestamp, ReplicationError>,
+    StreamVec<'scope, IcebergTimestamp, Probe<IcebergTimestamp>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("IcebergProgress({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        Box::pin(async move {
+            let [probe_cap]: &mut [_; 1] = caps.try_into().unwrap();
+
+            // Emit 0 to mark this worker as having started up correctly.
+            for stat in config.statistics.values() {
+                stat.set_offset_known(0);
+                stat.set_offset_committed(0);
+            }
+
+            // Only a single worker is responsible for processing progress.
+            if !config.responsible_for(PROGRESS_WORKER) {
+                return Ok(());
+            }
+
+            let namespace = NamespaceIdent::new(source.namespace.clone());
+            let tables: BTreeMap<Uuid, TableIdent> = outputs
+                .values()
+                .map(|output| {
+                    let ident = TableIdent::new(namespace.clone(), output.table.clone());
+                    (output.table_uuid, ident)
+                })
+                .collect();
+            // The upstream frontier of every table that can still be ingested.
+            let mut upstream: BTreeMap<Uuid, MzOffset> = BTreeMap::new();
+            // The tables that have been dropped or replaced.
+            let mut ended = BTreeSet::new();
+
+            let catalog = source
+                .catalog_connection
+                .connect(&config.config, InTask::Yes)
+                .await?;
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker = probe::Ticker::new(move || timestamp_interval, config.now_fn);
+
+            let mut committed_uppers = std::pin::pin!(committed_uppers);
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        for (id, ident) in tables.iter() {
+                            if ended.contains(id) {
+                                continue;
+                            }
+                            let table = match load_table(&*catalog, ident, *id).await? {
+                                Ok(table) => table,
+                                Err(_) => {
+                                    // The reader reports the error to the outputs of the table.
+                                    ended.insert(*id);
+                                    upstream.remove(id);
+                                    continue;
+                                }
+                            };
+                            let current = current_sequence_number(table.metadata());
+                            let frontier = upstream.entry(*id).or_insert(MzOffset::from(0));
+                            *frontier = std::cmp::max(*frontier, MzOffset::from(current + 1));
+                        }
+                        for (id, output) in outputs.iter() {
+                            if let Some(stat) = config.statistics.get(id)
+                                && let Some(frontier) = upstream.get(&output.table_uuid)
+                            {
+                                stat.set_offset_known(frontier.offset - 1);
+                            }
+                        }
+                        let upstream_frontier = tables
+                            .keys()
+                            .map(|id| (*id, upstream.get(id).copied()))
+                            .collect();
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: tables_frontier(&upstream_frontier),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                    },
+                    Some(committed_upper) = committed_uppers.next() => {
+                        if committed_upper.is_empty() {
+                            // It's possible that the source has been dropped, in which case this
+                            // can observe an empty upper. This operator should continue to loop
+                            // until the drop dataflow propagates.
+                            continue;
+                        }
+                        for (id, output) in outputs.iter() {
+                            let offset =
+                                table_offset(committed_upper.elements(), output.table_uuid);
+                            if let Some(stat) = config.statistics.get(id)
+                                && let Some(offset) = offset
+                            {
+                                stat.set_offset_committed(offset.offset.saturating_sub(1));
+                            }
+                        }
+                    }
+                };
+            }
+        })
+    });
+
+    let error_stream = transient_errors.map(ReplicationError::Transient);
+
+    (error_stream, probe_stream, button.press_on_drop())
+}
@@ -0,0 +1,423 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the reader of an [`IcebergSourceConnection`].
+//!
+//! Every table of the source is ingested by a single worker. That worker loads
+//! the table from the catalog every `iceberg_source_poll_interval`, and brings
+//! every output of the table up to the current snapshot of the table by
+//! diffing the files of the snapshot it last ingested against the files of the
+//! current snapshot:
+//!
+//! * A data file that is only part of the current snapshot is read and its
+//!   rows are inserted.
+//! * A data file that is only part of the last ingested snapshot is read as of
+//!   that snapshot and its rows are retracted.
+//! * A data file that is part of both snapshots, but to which a different set
+//!   of delete files applies, is read as of both snapshots, retracting the
+//!   rows of the former and inserting the rows of the latter.
+//!
+//! Reading a data file applies the position and equality deletes of the
+//! snapshot it is read as of. The last ingested snapshot must remain in the
+//! history of the table until the source has ingested a later snapshot.
+//!
+//! [`IcebergSourceConnection`]: mz_storage_types::sources::IcebergSourceConnection
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use arrow::array::StructArray;
+use futures::StreamExt;
+use iceberg::scan::FileScanTask;
+use iceberg::table::Table;
+use iceberg::{NamespaceIdent, TableIdent};
+use mz_arrow_util::reader::ArrowReader;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row};
+use mz_storage_types::dyncfgs::ICEBERG_SOURCE_POLL_INTERVAL;
+use mz_storage_types::errors::{DataflowError, DecodeError, DecodeErrorKind};
+use mz_storage_types::sources::iceberg::IcebergTimestamp;
+use mz_storage_types::sources::{IcebergSourceConnection, MzOffset};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use mz_timely_util::order::Partitioned;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Capability;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::{Scope, StreamVec};
+use uuid::Uuid;
+
+use crate::metrics::source::iceberg::IcebergSourceMetrics;
+use crate::source::RawSourceCreationConfig;
+use crate::source::iceberg::{
+    DefiniteError, ReplicationError, SourceOutputInfo, TransientError,
+    ancestor_with_sequence_number, load_table, sequence_number, table_offset, tables_frontier,
+};
+use crate::source::types::{FuelSize, SignaledFuture, SourceMessage, StackedCollection};
+
+/// An output of the source that is still ingesting its table.
+struct ReaderOutput {
+    partition_index: u64,
+    desc: RelationDesc,
+    /// One more than the sequence number of the last snapshot this output has ingested.
+    position: MzOffset,
+}
+
+/// A table that this worker is responsible for ingesting.
+struct ReaderTable {
+    ident: TableIdent,
+    outputs: Vec<ReaderOutput>,
+}
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, IcebergTimestamp>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: IcebergSourceConnection,
+    metrics: IcebergSourceMetrics,
+) -> (
+    StackedCollection<'scope, IcebergTimestamp, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, IcebergTimestamp, ReplicationError>,
+    PressOnDropButton,
+) {
+    let op_name = format!("IcebergReader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set]: &mut [_; 1] = caps.try_into().unwrap();
+
+            let namespace = NamespaceIdent::new(source.namespace.clone());
+            // Every table of the source. Tables that another worker is responsible for, or that
+            // have ended, are omitted from the frontier of this worker.
+            let mut all_tables = BTreeSet::new();
+            let mut tables: BTreeMap<Uuid, ReaderTable> = BTreeMap::new();
+            for output in outputs.into_values() {
+                all_tables.insert(output.table_uuid);
+                if !config.responsible_for(output.table_uuid) {
+                    continue;
+                }
+                let Some(position) =
+                    table_offset(output.resume_upper.elements(), output.table_uuid)
+                else {
+                    // The output has already ended.
+                    continue;
+                };
+                let table = tables
+                    .entry(output.table_uuid)
+                    .or_insert_with(|| ReaderTable {
+                        ident: TableIdent::new(namespace.clone(), output.table.clone()),
+                        outputs: Vec::new(),
+                    });
+                table.outputs.push(ReaderOutput {
+                    partition_index: output.partition_index,
+                    desc: output.desc,
+                    position,
+                });
+            }
+            let frontier = |tables: &BTreeMap<Uuid, ReaderTable>| {
+                let offsets = all_tables
+                    .iter()
+                    .map(|id| {
+                        let position = tables
+                            .get(id)
+                            .and_then(|table| table.outputs.iter().map(|o| o.position).min());
+                        (*id, position)
+                    })
+                    .collect();
+                tables_frontier(&offsets)
+            };
+            data_cap_set.downgrade(frontier(&tables).iter());
+
+            if tables.is_empty() {
+                // Hold on to the partitions that no table falls into, which the source may ingest
+                // new tables into later.
+                return futures::future::pending().await;
+            }
+
+            let catalog = source
+                .catalog_connection
+                .connect(&config.config, InTask::Yes)
+                .await?;
+
+            loop {
+                for (id, table) in tables.iter_mut() {
+                    let upstream = load_table(&*catalog, &table.ident, *id).await?;
+                    metrics.table_loads.inc();
+                    let upstream = match upstream {
+                        Ok(upstream) => upstream,
+                        Err(err) => {
+                            for output in table.outputs.drain(..) {
+                                let time = Partitioned::new_singleton(*id, output.position);
+                                let cap = data_cap_set.delayed(&time);
+                                let update = (
+                                    (output.partition_index, Err(err.clone().into())),
+                                    time,
+                                    Diff::ONE,
+                                );
+                                let size = update.fuel_size();
+                                data_output.give_fueled(&cap, update, size).await;
+                            }
+                            continue;
+                        }
+                    };
+
+                    let metadata = upstream.metadata();
+                    let Some(current) = metadata.current_snapshot() else {
+                        // The table is empty until its first snapshot is committed.
+                        for output in table.outputs.iter_mut() {
+                            output.position = std::cmp::max(output.position, MzOffset::from(1));
+                        }
+                        continue;
+                    };
+                    let current_sequence_number = sequence_number(current);
+                    let next_position = MzOffset::from(current_sequence_number + 1);
+                    let time =
+                        Partitioned::new_singleton(*id, MzOffset::from(current_sequence_number));
+                    let schema = current.schema(metadata)?;
+
+                    for mut output in std::mem::take(&mut table.outputs) {
+                        if output.position == next_position {
+                            table.outputs.push(output);
+                            continue;
+                        }
+                        // The sequence number of the snapshot this output last ingested, which
+                        // is `0` if it hasn't ingested any snapshot yet.
+                        let last = output.position.offset.saturating_sub(1);
+                        let from = match last {
+                            0 => None,
+                            _ => match ancestor_with_sequence_number(metadata, last) {
+                                Some(snapshot) => Some(snapshot.snapshot_id()),
+                                None => {
+                                    let err = DefiniteError::HistoryRewritten {
+                                        table: table.ident.name().to_string(),
+                                        sequence_number: last,
+                                    };
+                                    let time = Partitioned::new_singleton(*id, output.position);
+                                    let cap = data_cap_set.delayed(&time);
+                                    let update = (
+                                        (output.partition_index, Err(err.into())),
+                                        time,
+                                        Diff::ONE,
+                                    );
+                                    let size = update.fuel_size();
+                                    data_output.give_fueled(&cap, update, size).await;
+                                    continue;
+                                }
+                            },
+                        };
+
+                        let dropped = output
+                            .desc
+                            .iter_names()
+                            .find(|name| schema.field_by_name(name.as_str()).is_none());
+                        if let Some(column) = dropped {
+                            let err = DefiniteError::ColumnDropped {
+                                table: table.ident.name().to_string(),
+                                column: column.to_string(),
+                            };
+                            let time = Partitioned::new_singleton(*id, output.position);
+                            let cap = data_cap_set.delayed(&time);
+                            let update =
+                                ((output.partition_index, Err(err.into())), time, Diff::ONE);
+                            let size = update.fuel_size();
+                            data_output.give_fueled(&cap, update, size).await;
+                            continue;
+                        }
+
+                        tracing::info!(
+                            %config.id,
+                            table = %table.ident.name(),
+                            from = %output.position,
+                            to = %current_sequence_number,
+                            "timely-{} ingesting snapshot",
+                            config.worker_id,
+                        );
+                        let cap = data_cap_set.delayed(&time);
+                        ingest(
+                            &upstream,
+                            from,
+                            current.snapshot_id(),
+                            &output,
+                            &data_output,
+                            &cap,
+                            &metrics,
+                        )
+                        .await?;
+                        metrics.snapshots_ingested.inc();
+                        output.position = next_position;
+                        table.outputs.push(output);
+                    }
+                }
+                tables.retain(|_, table| !table.outputs.is_empty());
+                data_cap_set.downgrade(frontier(&tables).iter());
+
+                let interval = ICEBERG_SOURCE_POLL_INTERVAL.get(config.config.config_set());
+                tokio::time::sleep(interval).await;
+            }
+        }))
+    });
+
+    let error_stream = transient_errors.map(ReplicationError::Transient);
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+/// Emits the updates that move `output` from the snapshot `from` of `table`, or from an empty
+/// table if `from` is `None`, to the snapshot `to`, at the time of `cap`.
+async fn ingest(
+    table: &Table,
+    from: Option<i64>,
+    to: i64,
+    output: &ReaderOutput,
+    data_output: &StackedAsyncOutputHandle<
+        IcebergTimestamp,
+        (u64, Result<SourceMessage, DataflowError>),
+    >,
+    cap: &Capability<IcebergTimestamp>,
+    metrics: &IcebergSourceMetrics,
+) -> Result<(), TransientError> {
+    let mut from_files = match from {
+        Some(from) => plan_files(table, from, &output.desc).await?,
+        None => BTreeMap::new(),
+    };
+    let to_files = plan_files(table, to, &output.desc).await?;
+
+    for (path, to_task) in to_files {
+        if let Some(from_task) = from_files.remove(&path) {
+            if delete_files(&from_task) == delete_files(&to_task) {
+                continue;
+            }
+            read_file(
+                table,
+                from_task,
+                output,
+                Diff::MINUS_ONE,
+                data_output,
+                cap,
+                metrics,
+            )
+            .await?;
+        }
+        read_file(table, to_task, output, Diff::ONE, data_output, cap, metrics).await?;
+    }
+    for (_path, from_task) in from_files {
+        read_file(
+            table,
+            from_task,
+            output,
+            Diff::MINUS_ONE,
+            data_output,
+            cap,
+            metrics,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Returns the data files of the snapshot `snapshot_id` of `table`, keyed by their path, along
+/// with the delete files that apply to them.
+async fn plan_files(
+    table: &Table,
+    snapshot_id: i64,
+    desc: &RelationDesc,
+) -> Result<BTreeMap<String, FileScanTask>, TransientError> {
+    let scan = table
+        .scan()
+        .snapshot_id(snapshot_id)
+        .select(desc.iter_names().map(|name| name.as_str().to_string()))
+        .build()?;
+    let mut tasks = scan.plan_files().await?;
+    let mut files = BTreeMap::new();
+    while let Some(task) = tasks.next().await {
+        let task = task?;
+        files.insert(task.data_file_path.clone(), task);
+    }
+    Ok(files)
+}
+
+/// Returns the paths of the delete files that apply to the data file of `task`.
+fn delete_files(task: &FileScanTask) -> BTreeSet<&str> {
+    task.deletes
+        .iter()
+        .map(|delete| delete.file_path.as_str())
+        .collect()
+}
+
+/// Reads the data file of `task` and emits each of its rows with `diff` to `output`.
+///
+/// Rows that can't be decoded into the columns of the output are emitted as errors, with the same
+/// `diff`, so that retracting a data file also retracts its errors.
+async fn read_file(
+    table: &Table,
+    task: FileScanTask,
+    output: &ReaderOutput,
+    diff: Diff,
+    data_output: &StackedAsyncOutputHandle<
+        IcebergTimestamp,
+        (u64, Result<SourceMessage, DataflowError>),
+    >,
+    cap: &Capability<IcebergTimestamp>,
+    metrics: &IcebergSourceMetrics,
+) -> Result<(), TransientError> {
+    metrics.files_read.inc();
+    let path = task.data_file_path.clone();
+    let tasks = futures::stream::iter([Ok(task)]).boxed();
+    let mut batches = table.reader_builder().build().read(tasks)?;
+    let mut rows = Vec::new();
+    while let Some(batch) = batches.next().await {
+        let array = StructArray::from(batch?);
+        let result =
+            ArrowReader::new(&output.desc, array).and_then(|reader| reader.read_all(&mut rows));
+        match result {
+            Ok(count) => {
+                metrics.rows.inc_by(u64::cast_from(count));
+                for row in rows.drain(..) {
+                    let message = SourceMessage {
+                        key: Row::default(),
+                        value: row,
+                        metadata: Row::default(),
+                    };
+                    let update = ((output.partition_index, Ok(message)), *cap.time(), diff);
+                    let size = update.fuel_size();
+                    data_output.give_fueled(cap, update, size).await;
+                }
+            }
+            Err(err) => {
+                rows.clear();
+                let kind = DecodeErrorKind::Text(
+                    format!("failed to decode {}: {}", path, err.display_with_causes()).into(),
+                );
+                let err = DataflowError::DecodeError(Box::new(DecodeError {
+                    kind,
+                    raw: path.as_bytes().to_vec(),
+                }));
+                let update = ((output.partition_index, Err(err)), *cap.time(), diff);
+                let size = update.fuel_size();
+                data_output.give_fueled(cap, update, size).await;
+            }
+        }
+    }
+    Ok(())
+}
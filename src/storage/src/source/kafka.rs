@@ -7,8 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeMap;
+use std::cell::RefCell;
 use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
 use std::str::{self};
 use std::sync::Arc;
 use std::thread;
@@ -21,7 +23,8 @@ use futures::StreamExt;
 use itertools::Itertools;
 use maplit::btreemap;
 use mz_kafka_util::client::{
-    GetPartitionsError, MzClientContext, PartitionId, TunnelingClientContext, get_partitions,
+    GetPartitionsError, MzClientContext, PartitionId, TunnelingClientContext,
+    get_matching_partitions, get_partitions,
 };
 use mz_ore::assert_none;
 use mz_ore::cast::CastFrom;
@@ -35,7 +38,8 @@ use mz_storage_types::errors::{
     ContextCreationError, DataflowError, SourceError, SourceErrorDetails,
 };
 use mz_storage_types::sources::kafka::{
-    KafkaMetadataKind, KafkaSourceConnection, KafkaTimestamp, RangeBound,
+    KafkaMetadataKind, KafkaPartition, KafkaSourceConnection, KafkaTimestamp,
+    KafkaTopicKeyCollision, KafkaTopicKeys, KafkaTopicSet, RangeBound,
 };
 use mz_storage_types::sources::{MzOffset, SourceExport, SourceExportDetails, SourceTimestamp};
 use mz_timely_util::antichain::AntichainExt;
@@ -65,6 +69,7 @@ use tokio::sync::{Notify, mpsc};
 use tracing::{error, info, trace};
 
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::metrics::StorageMetrics;
 use crate::metrics::source::kafka::KafkaSourceMetrics;
 use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceRender, StackedCollection};
 use crate::source::{RawSourceCreationConfig, SourceMessage, probe};
@@ -104,8 +109,10 @@ impl HealthStatus {
 
 /// Contains all information necessary to ingest data from Kafka
 pub struct KafkaSourceReader {
-    /// Name of the topic on which this source is backed on
-    topic_name: String,
+    /// The topics this source reads from
+    topics: Rc<SourceTopics>,
+    /// Name of each topic this source has discovered, by topic key
+    topic_names: Rc<RefCell<BTreeMap<u32, String>>>,
     /// Name of the source (will have format kafka-source-id)
     source_name: String,
     /// Source global ID
@@ -121,15 +128,17 @@ pub struct KafkaSourceReader {
     /// The most recently read offset for each partition known to this source
     /// reader by output-index. An offset of -1 indicates that no prior message
     /// has been read for the given partition.
-    last_offsets: BTreeMap<usize, BTreeMap<PartitionId, i64>>,
+    last_offsets: BTreeMap<usize, BTreeMap<KafkaPartition, i64>>,
     /// The offset to start reading from for each partition.
-    start_offsets: BTreeMap<PartitionId, i64>,
+    start_offsets: BTreeMap<KafkaPartition, i64>,
     /// Channel to receive Kafka statistics JSON blobs from the stats callback.
     stats_rx: crossbeam_channel::Receiver<Jsonb>,
-    /// A handle to the partition specific metrics
-    partition_metrics: KafkaSourceMetrics,
+    /// Used to create the partition specific metrics of newly discovered topics
+    metrics: StorageMetrics,
+    /// A handle to the partition specific metrics, by topic
+    partition_metrics: BTreeMap<String, KafkaSourceMetrics>,
     /// Per partition capabilities used to produce messages
-    partition_capabilities: BTreeMap<PartitionId, PartitionCapability>,
+    partition_capabilities: BTreeMap<KafkaPartition, PartitionCapability>,
 }
 
 struct PartitionCapability {
@@ -147,17 +156,54 @@ type PartitionWatermark = u64;
 /// storing them in the `progress_statistics` to be emitted later.
 pub struct KafkaResumeUpperProcessor {
     config: RawSourceCreationConfig,
-    topic_name: String,
+    topic_names: Rc<RefCell<BTreeMap<u32, String>>>,
     consumer: Arc<BaseConsumer<TunnelingClientContext<GlueConsumerContext>>>,
     statistics: Vec<SourceStatistics>,
 }
 
 /// Computes whether this worker is responsible for consuming a partition. It assigns partitions to
 /// workers in a round-robin fashion, starting at an arbitrary worker based on the hash of the
-/// source id.
-fn responsible_for_pid(config: &RawSourceCreationConfig, pid: i32) -> bool {
-    let pid = usize::try_from(pid).expect("positive pid");
-    ((config.responsible_worker(config.id) + pid) % config.worker_count) == config.worker_id
+/// source id and the partition's topic.
+fn responsible_for_pid(config: &RawSourceCreationConfig, pid: KafkaPartition) -> bool {
+    let topic = usize::cast_from(pid.topic);
+    let pid = usize::try_from(pid.partition).expect("positive pid");
+    ((config.responsible_worker(config.id) + topic + pid) % config.worker_count) == config.worker_id
+}
+
+/// The topics a Kafka source reads from.
+enum SourceTopics {
+    /// A single topic, whose partitions are identified by partition ID alone.
+    Topic(String),
+    /// Every topic in a [`KafkaTopicSet`].
+    Set(Box<dyn Fn(&str) -> bool + Send>),
+}
+
+impl SourceTopics {
+    fn new(topic: &str, topic_set: Option<&KafkaTopicSet>) -> Self {
+        match topic_set {
+            None => SourceTopics::Topic(topic.to_string()),
+            Some(set) => {
+                let matcher = set.matcher().expect("topic set validated during planning");
+                SourceTopics::Set(Box::new(matcher))
+            }
+        }
+    }
+
+    /// Returns the key that identifies `topic` in the source's timestamps.
+    fn topic_key(&self, topic: &str) -> u32 {
+        match self {
+            SourceTopics::Topic(_) => 0,
+            SourceTopics::Set(_) => KafkaPartition::topic_key(topic),
+        }
+    }
+
+    /// Returns the partition `pid` of `topic` as it is identified in the source's timestamps.
+    fn partition(&self, topic: &str, pid: PartitionId) -> KafkaPartition {
+        KafkaPartition {
+            topic: self.topic_key(topic),
+            partition: pid,
+        }
+    }
 }
 
 struct SourceOutputInfo {
@@ -265,7 +311,7 @@ fn render_reader<'scope>(
                 .get(id)
                 .expect("all source exports must be present in source resume uppers")
                 .iter()
-                .map(Partitioned::<RangeBound<PartitionId>, MzOffset>::decode_row),
+                .map(KafkaTimestamp::decode_row),
         );
 
         let metadata_columns = match details {
@@ -311,6 +357,7 @@ fn render_reader<'scope>(
             let KafkaSourceConnection {
                 connection,
                 topic,
+                topic_set,
                 topic_metadata_refresh_interval,
                 start_offsets,
                 metadata_columns: _,
@@ -413,16 +460,24 @@ fn render_reader<'scope>(
                 }
             };
 
-            // Start offsets is a map from partition to the next offset to read from.
+            let topics = Rc::new(SourceTopics::new(&topic, topic_set.as_ref()));
+            let topic_names = Rc::new(RefCell::new(BTreeMap::new()));
+            if let SourceTopics::Topic(topic) = &*topics {
+                topic_names.borrow_mut().insert(0, topic.clone());
+            }
+
+            // Start offsets is a map from partition to the next offset to read from. Sources
+            // over a topic set never have start offsets.
             let mut start_offsets: BTreeMap<_, u64> = start_offsets
                 .clone()
                 .into_iter()
+                .map(|(pid, offset)| (KafkaPartition::from(pid), offset))
                 .filter(|(pid, _offset)| responsible_for_pid(&config, *pid))
                 .map(|(pid, offset)| (pid, u64::try_from(offset).expect("start offsets must be non-negative and fit into u64")))
                 .collect();
 
             let mut partition_capabilities = BTreeMap::new();
-            let mut max_pid = None;
+            let mut known_partitions = BTreeSet::new();
             let resume_upper = Antichain::from_iter(
                 outputs
                     .iter()
@@ -440,7 +495,7 @@ fn render_reader<'scope>(
             for ts in resume_upper.elements() {
                 if let Some(pid) = ts.interval().singleton() {
                     let pid = pid.unwrap_exact();
-                    max_pid = std::cmp::max(max_pid, Some(*pid));
+                    known_partitions.insert(*pid);
 
                     if responsible_for_pid(&config, *pid) {
                         let restored_offset = ts.timestamp().offset;
@@ -461,19 +516,18 @@ fn render_reader<'scope>(
                     }
                 }
             }
-            let lower = max_pid
-                .map(RangeBound::after)
-                .unwrap_or(RangeBound::NegInfinity);
-            let future_ts =
-                Partitioned::new_range(lower, RangeBound::PosInfinity, MzOffset::from(0));
-            data_cap.downgrade(&future_ts);
+            // Capabilities for the ranges of partitions before and between the known partitions.
+            // `data_cap` covers the range after the last known partition.
+            let mut gap_caps = vec![];
+            downgrade_unknown_partitions(&mut data_cap, &mut gap_caps, &known_partitions);
 
             if mz_storage_types::dyncfgs::KAFKA_LOW_WATERMARK_CHECK
                 .get(config.config.config_set())
             {
                 let low_watermarks = fetch_partition_info(
                     &consumer,
-                    topic.as_str(),
+                    &topics,
+                    &mut KafkaTopicKeys::default(),
                     config
                         .config
                         .parameters
@@ -546,10 +600,13 @@ fn render_reader<'scope>(
                             data_output.give(&data_cap, update);
                         }
                     }
-                    BTreeMap::new()
+                    SourcePartitions::default()
                 });
-                for (pid, lwm) in &low_watermarks {
+                topic_names.borrow_mut().extend(low_watermarks.topics.clone());
+                for (pid, lwm) in &low_watermarks.watermarks {
                     if responsible_for_pid(&config, *pid) {
+                        let topic = &low_watermarks.topics[&pid.topic];
+                        let partition = pid.partition;
                         // If a start offset exists for this partition, then either the user specified it
                         // or we restored it from the resume upper. In either case, if the low watermark is
                         // greater than the start offset, we know for certain that the offset we need to
@@ -561,7 +618,7 @@ fn render_reader<'scope>(
                                 source_id = config.id.to_string(),
                                 worker_id = config.worker_id,
                                 num_workers = config.worker_count,
-                                "restored offset {start_offset} for topic {topic} partition {pid} with low watermark {lwm}"
+                                "restored offset {start_offset} for topic {topic} partition {partition} with low watermark {lwm}"
                             );
                             if lwm > start_offset {
                                 tracing::error!(
@@ -569,11 +626,11 @@ fn render_reader<'scope>(
                                     worker_id = config.worker_id,
                                     num_workers = config.worker_count,
                                     "start offset and resume upper {start_offset} for topic {topic} \
-                                    partition {pid} is behind the low watermark {lwm}. This likely \
+                                    partition {partition} is behind the low watermark {lwm}. This likely \
                                     means that the offsets have been compacted away by Kafka."
                                 );
                                 let err_str = format!(
-                                    "Low watermark {lwm} of kafka topic {topic} partition {pid} \
+                                    "Low watermark {lwm} of kafka topic {topic} partition {partition} \
                                     is past the start offset/resume upper: {start_offset} \
                                     This likely means that the offsets have been compacted away \
                                     by Kafka. Please consider setting a higher start offset or \
@@ -614,7 +671,7 @@ fn render_reader<'scope>(
                                 source_id = config.id.to_string(),
                                 worker_id = config.worker_id,
                                 num_workers = config.worker_count,
-                                "partition {pid} has a non-zero low watermark {lwm}, but no start offset or \
+                                "partition {partition} has a non-zero low watermark {lwm}, but no start offset or \
                                 resume upper was found for this partition. Setting start offset to low watermark"
                             );
                             start_offsets.insert(*pid, *lwm);
@@ -634,12 +691,12 @@ fn render_reader<'scope>(
                 "kafka worker noticed rehydration is finished, starting partition queues..."
             );
 
-            let partition_ids = start_offsets.keys().copied().collect();
             let offset_commit_metrics = config.metrics.get_offset_commit_metrics(config.id);
             let start_offsets = start_offsets.iter().map(|(pid, offset)| (*pid, i64::try_from(*offset).expect("start offsets must fit into i64"))).collect();
 
             let mut reader = KafkaSourceReader {
-                topic_name: topic.clone(),
+                topics: Rc::clone(&topics),
+                topic_names: Rc::clone(&topic_names),
                 source_name: config.name.clone(),
                 id: config.id,
                 partition_consumers: Vec::new(),
@@ -652,17 +709,14 @@ fn render_reader<'scope>(
                     .collect(),
                 start_offsets,
                 stats_rx,
-                partition_metrics: config.metrics.get_kafka_source_metrics(
-                    partition_ids,
-                    topic.clone(),
-                    config.id,
-                ),
+                metrics: config.metrics.clone(),
+                partition_metrics: BTreeMap::new(),
                 partition_capabilities,
             };
 
             let offset_committer = KafkaResumeUpperProcessor {
                 config: config.clone(),
-                topic_name: topic.clone(),
+                topic_names: Rc::clone(&topic_names),
                 consumer,
                 statistics: all_export_stats.clone(),
             };
@@ -748,18 +802,10 @@ fn render_reader<'scope>(
 
                 match metadata_update.take() {
                     Some(MetadataUpdate::Partitions(partitions)) => {
-                        let max_pid = partitions.keys().last().cloned();
-                        let lower = max_pid
-                            .map(RangeBound::after)
-                            .unwrap_or(RangeBound::NegInfinity);
-                        let future_ts = Partitioned::new_range(
-                            lower,
-                            RangeBound::PosInfinity,
-                            MzOffset::from(0),
-                        );
+                        reader.topic_names.borrow_mut().extend(partitions.topics.clone());
 
                         let mut offset_known = 0;
-                        for (&pid, &high_watermark) in &partitions {
+                        for (&pid, &high_watermark) in &partitions.watermarks {
                             if responsible_for_pid(&config, pid) {
                                 offset_known += high_watermark;
                                 reader.ensure_partition(pid);
@@ -774,13 +820,18 @@ fn render_reader<'scope>(
                                         RangeBound::exact(pid),
                                         MzOffset::from(start_offset),
                                     );
+                                    let cap = std::iter::once(&data_cap)
+                                        .chain(&gap_caps)
+                                        .find(|cap| cap.time().less_equal(&part_since_ts))
+                                        .expect("new partitions are covered by a future capability");
 
                                     entry.insert(PartitionCapability {
-                                        data: data_cap.delayed(&part_since_ts),
+                                        data: cap.delayed(&part_since_ts),
                                     });
                                 }
                             }
                         }
+                        known_partitions.extend(partitions.watermarks.keys().copied());
 
                         // If we are snapshotting, record our first set of partitions as the snapshot
                         // size.
@@ -821,7 +872,11 @@ fn render_reader<'scope>(
                             export_stat.set_offset_known(offset_known);
                         }
 
-                        data_cap.downgrade(&future_ts);
+                        downgrade_unknown_partitions(
+                            &mut data_cap,
+                            &mut gap_caps,
+                            &known_partitions,
+                        );
                     }
                     Some(MetadataUpdate::TransientError(status)) => {
                         if let Some(update) = status.kafka {
@@ -906,7 +961,7 @@ fn render_reader<'scope>(
                         Err(e) => {
                             let error = format!(
                                 "kafka error when polling consumer for source: {} topic: {} : {}",
-                                reader.source_name, reader.topic_name, e
+                                reader.source_name, topic, e
                             );
                             let status = HealthStatusUpdate::stalled(error, None);
                             health_output.give(
@@ -934,6 +989,7 @@ fn render_reader<'scope>(
                                 .map(|output| {
                                     let (message, ts) = construct_source_message(
                                         &message,
+                                        &reader.topics,
                                         &output.metadata_columns,
                                     );
                                     (output.output_index, message, ts)
@@ -985,8 +1041,11 @@ fn render_reader<'scope>(
                         for output in outputs.iter() {
                             let message = match &message {
                                 Ok((msg, pid)) => {
-                                    let (msg, ts) =
-                                        construct_source_message(msg, &output.metadata_columns);
+                                    let (msg, ts) = construct_source_message(
+                                        msg,
+                                        &reader.topics,
+                                        &output.metadata_columns,
+                                    );
                                     assert_eq!(*pid, ts.0);
                                     Ok(reader.handle_message(msg, ts, &output.output_index))
                                 }
@@ -1020,10 +1079,12 @@ fn render_reader<'scope>(
 
                                     let status = HealthStatusUpdate::stalled(
                                         format!(
-                                            "error consuming from source: {} topic: {topic}:\
-                                             partition: {pid} last processed offset:\
+                                            "error consuming from source: {} topic: {}:\
+                                             partition: {} last processed offset:\
                                              {last_offset} : {err}",
-                                            config.name
+                                            config.name,
+                                            consumer.topic(),
+                                            pid.partition,
                                         ),
                                         None,
                                     );
@@ -1056,14 +1117,13 @@ fn render_reader<'scope>(
                 reader.partition_consumers = consumers;
 
                 let positions = reader.consumer.position().unwrap();
-                let topic_positions = positions.elements_for_topic(&reader.topic_name);
                 let mut snapshot_staged = 0;
 
-                for position in topic_positions {
+                for position in positions.elements() {
                     // The offset begins in the `Offset::Invalid` state in which case we simply
                     // skip this partition.
                     if let Offset::Offset(offset) = position.offset() {
-                        let pid = position.partition();
+                        let pid = reader.topics.partition(position.topic(), position.partition());
                         let upper_offset = MzOffset::from(u64::try_from(offset).unwrap());
                         let upper =
                             Partitioned::new_singleton(RangeBound::exact(pid), upper_offset);
@@ -1154,12 +1214,18 @@ impl KafkaResumeUpperProcessor {
 
         if !offsets.is_empty() {
             let mut tpl = TopicPartitionList::new();
+            let topic_names = self.topic_names.borrow();
             for (pid, offset) in offsets {
+                // Partitions of topics that have since been deleted have no name.
+                let Some(topic) = topic_names.get(&pid.topic) else {
+                    continue;
+                };
                 let offset_to_commit =
                     Offset::Offset(offset.offset.try_into().expect("offset to be vald i64"));
-                tpl.add_partition_offset(&self.topic_name, pid, offset_to_commit)
+                tpl.add_partition_offset(topic, pid.partition, offset_to_commit)
                     .expect("offset known to be valid");
             }
+            drop(topic_names);
             let consumer = Arc::clone(&self.consumer);
             mz_ore::task::spawn_blocking(
                 || format!("source({}) kafka offset commit", self.config.id),
@@ -1173,7 +1239,7 @@ impl KafkaResumeUpperProcessor {
 
 impl KafkaSourceReader {
     /// Ensures that a partition queue for `pid` exists.
-    fn ensure_partition(&mut self, pid: PartitionId) {
+    fn ensure_partition(&mut self, pid: KafkaPartition) {
         if self.last_offsets.is_empty() {
             tracing::info!(
                 source_id = %self.id,
@@ -1189,8 +1255,12 @@ impl KafkaSourceReader {
             }
         }
 
+        let Some(topic) = self.topic_names.borrow().get(&pid.topic).cloned() else {
+            // The topic was deleted before this worker learned about it.
+            return;
+        };
         let start_offset = self.start_offsets.get(&pid).copied().unwrap_or(0);
-        self.create_partition_queue(pid, Offset::Offset(start_offset));
+        self.create_partition_queue(topic, pid, Offset::Offset(start_offset));
 
         for last_offsets in self.last_offsets.values_mut() {
             let prev = last_offsets.insert(pid, start_offset - 1);
@@ -1198,14 +1268,20 @@ impl KafkaSourceReader {
         }
     }
 
-    /// Creates a new partition queue for `partition_id`.
-    fn create_partition_queue(&mut self, partition_id: PartitionId, initial_offset: Offset) {
+    /// Creates a new partition queue for partition `pid` of `topic`.
+    fn create_partition_queue(
+        &mut self,
+        topic: String,
+        pid: KafkaPartition,
+        initial_offset: Offset,
+    ) {
+        let partition_id = pid.partition;
         info!(
             source_id = self.id.to_string(),
             worker_id = self.worker_id,
             num_workers = self.worker_count,
             "activating Kafka queue for topic {}, partition {}",
-            self.topic_name,
+            topic,
             partition_id,
         );
 
@@ -1213,14 +1289,14 @@ impl KafkaSourceReader {
         let tpl = self.consumer.assignment().unwrap();
         // Create list from assignments
         let mut partition_list = TopicPartitionList::new();
-        for partition in tpl.elements_for_topic(&self.topic_name) {
+        for partition in tpl.elements() {
             partition_list
                 .add_partition_offset(partition.topic(), partition.partition(), partition.offset())
                 .expect("offset known to be valid");
         }
         // Add new partition
         partition_list
-            .add_partition_offset(&self.topic_name, partition_id, initial_offset)
+            .add_partition_offset(&topic, partition_id, initial_offset)
             .expect("offset known to be valid");
         self.consumer
             .assign(&partition_list)
@@ -1232,7 +1308,7 @@ impl KafkaSourceReader {
         for pc in &mut self.partition_consumers {
             pc.partition_queue = self
                 .consumer
-                .split_partition_queue(&self.topic_name, pc.pid)
+                .split_partition_queue(&pc.topic, pc.pid.partition)
                 .expect("partition known to be valid");
             pc.partition_queue.set_nonempty_callback({
                 let context = Arc::clone(&context);
@@ -1242,17 +1318,13 @@ impl KafkaSourceReader {
 
         let mut partition_queue = self
             .consumer
-            .split_partition_queue(&self.topic_name, partition_id)
+            .split_partition_queue(&topic, partition_id)
             .expect("partition known to be valid");
         partition_queue.set_nonempty_callback(move || context.inner().activate());
         self.partition_consumers
-            .push(PartitionConsumer::new(partition_id, partition_queue));
+            .push(PartitionConsumer::new(topic, pid, partition_queue));
         assert_eq!(
-            self.consumer
-                .assignment()
-                .unwrap()
-                .elements_for_topic(&self.topic_name)
-                .len(),
+            self.consumer.assignment().unwrap().count(),
             self.partition_consumers.len()
         );
     }
@@ -1262,15 +1334,27 @@ impl KafkaSourceReader {
         while let Ok(stats) = self.stats_rx.try_recv() {
             match serde_json::from_str::<Statistics>(&stats.to_string()) {
                 Ok(statistics) => {
-                    let topic = statistics.topics.get(&self.topic_name);
-                    match topic {
-                        Some(topic) => {
-                            for (id, partition) in &topic.partitions {
-                                self.partition_metrics
-                                    .set_offset_max(*id, partition.hi_offset);
+                    let topic_names = self.topic_names.borrow();
+                    for topic_name in topic_names.values() {
+                        let Some(topic) = statistics.topics.get(topic_name) else {
+                            if let SourceTopics::Topic(_) = *self.topics {
+                                error!("No stats found for topic: {}", topic_name);
                             }
+                            continue;
+                        };
+                        let metrics = self
+                            .partition_metrics
+                            .entry(topic_name.clone())
+                            .or_insert_with(|| {
+                                self.metrics.get_kafka_source_metrics(
+                                    vec![],
+                                    topic_name.clone(),
+                                    self.id,
+                                )
+                            });
+                        for (id, partition) in &topic.partitions {
+                            metrics.set_offset_max(*id, partition.hi_offset);
                         }
-                        None => error!("No stats found for topic: {}", &self.topic_name),
                     }
                 }
                 Err(e) => {
@@ -1285,7 +1369,7 @@ impl KafkaSourceReader {
     fn handle_message(
         &mut self,
         message: Result<SourceMessage, KafkaHeaderParseError>,
-        (partition, offset): (PartitionId, MzOffset),
+        (partition, offset): (KafkaPartition, MzOffset),
         output_index: &usize,
     ) -> Option<(
         Result<SourceMessage, KafkaHeaderParseError>,
@@ -1328,8 +1412,11 @@ impl KafkaSourceReader {
                  source {} (reading topic {}, partition {}, output {}) \
                  received offset {} expected offset {:?}",
                 self.source_name,
-                self.topic_name,
-                partition,
+                self.topic_names
+                    .borrow()
+                    .get(&partition.topic)
+                    .map_or("", |t| t.as_str()),
+                partition.partition,
                 output_index,
                 offset.offset,
                 last_offset + 1,
@@ -1347,12 +1434,14 @@ impl KafkaSourceReader {
 
 fn construct_source_message(
     msg: &BorrowedMessage<'_>,
+    topics: &SourceTopics,
     metadata_columns: &[KafkaMetadataKind],
 ) -> (
     Result<SourceMessage, KafkaHeaderParseError>,
-    (PartitionId, MzOffset),
+    (KafkaPartition, MzOffset),
 ) {
-    let pid = msg.partition();
+    let partition = topics.partition(msg.topic(), msg.partition());
+    let pid = partition.partition;
     let Ok(offset) = u64::try_from(msg.offset()) else {
         panic!(
             "got negative offset ({}) from otherwise non-error'd kafka message",
@@ -1364,6 +1453,7 @@ fn construct_source_message(
     let mut packer = metadata.packer();
     for kind in metadata_columns {
         match kind {
+            KafkaMetadataKind::Topic => packer.push(Datum::String(msg.topic())),
             KafkaMetadataKind::Partition => packer.push(Datum::from(pid)),
             KafkaMetadataKind::Offset => packer.push(Datum::UInt64(offset)),
             KafkaMetadataKind::Timestamp => {
@@ -1410,7 +1500,7 @@ fn construct_source_message(
                         match d {
                             Ok(d) => packer.push(d),
                             //abort with a definite error when the header is not found or cannot be parsed correctly
-                            Err(err) => return (Err(err), (pid, offset.into())),
+                            Err(err) => return (Err(err), (partition, offset.into())),
                         }
                     }
                     None => packer.push(Datum::Null),
@@ -1451,14 +1541,16 @@ fn construct_source_message(
             value,
             metadata,
         }),
-        (pid, offset.into()),
+        (partition, offset.into()),
     )
 }
 
 /// Wrapper around a partition containing the underlying consumer
 struct PartitionConsumer {
-    /// the partition id with which this consumer is associated
-    pid: PartitionId,
+    /// the topic of the partition with which this consumer is associated
+    topic: String,
+    /// the partition with which this consumer is associated
+    pid: KafkaPartition,
     /// The underlying Kafka partition queue
     partition_queue: PartitionQueue<TunnelingClientContext<GlueConsumerContext>>,
 }
@@ -1466,10 +1558,12 @@ struct PartitionConsumer {
 impl PartitionConsumer {
     /// Creates a new partition consumer from underlying Kafka consumer
     fn new(
-        pid: PartitionId,
+        topic: String,
+        pid: KafkaPartition,
         partition_queue: PartitionQueue<TunnelingClientContext<GlueConsumerContext>>,
     ) -> Self {
         PartitionConsumer {
+            topic,
             pid,
             partition_queue,
         }
@@ -1481,7 +1575,9 @@ impl PartitionConsumer {
     /// be transformed into empty values.
     ///
     /// The inner `Option` represents if there is a message to process.
-    fn get_next_message(&self) -> Result<Option<(BorrowedMessage<'_>, PartitionId)>, KafkaError> {
+    fn get_next_message(
+        &self,
+    ) -> Result<Option<(BorrowedMessage<'_>, KafkaPartition)>, KafkaError> {
         match self.partition_queue.poll(Duration::from_millis(0)) {
             Some(Ok(msg)) => Ok(Some((msg, self.pid))),
             Some(Err(err)) => Err(err),
@@ -1489,10 +1585,15 @@ impl PartitionConsumer {
         }
     }
 
-    /// Return the partition id for this PartitionConsumer
-    fn pid(&self) -> PartitionId {
+    /// Return the partition for this PartitionConsumer
+    fn pid(&self) -> KafkaPartition {
         self.pid
     }
+
+    /// Return the topic of the partition for this PartitionConsumer
+    fn topic(&self) -> &str {
+        &self.topic
+    }
 }
 
 /// An implementation of [`ConsumerContext`] that forwards statistics to the
@@ -1630,42 +1731,108 @@ mod tests {
     }
 }
 
-/// Fetches the list of partitions and their corresponding high watermark.
+/// The partitions of the topics a Kafka source reads from.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize
+)]
+struct SourcePartitions {
+    /// The name of each topic, by the key that identifies it in the source's timestamps.
+    topics: BTreeMap<u32, String>,
+    /// The high or low watermark of each partition.
+    watermarks: BTreeMap<KafkaPartition, PartitionWatermark>,
+}
+
+/// Fetches the list of partitions of the source's topics and their corresponding watermark.
+///
+/// The topics of sources over a [`KafkaTopicSet`] are recorded in `topic_keys`, and fetching fails
+/// with a [`KafkaTopicKeyCollision`] if a topic can't be told apart from one recorded before.
 fn fetch_partition_info<C: ConsumerContext>(
     consumer: &BaseConsumer<C>,
-    topic: &str,
+    topics: &SourceTopics,
+    topic_keys: &mut KafkaTopicKeys,
     fetch_timeout: Duration,
     offset_requested: Offset,
-) -> Result<BTreeMap<PartitionId, PartitionWatermark>, GetPartitionsError> {
-    let pids = get_partitions(consumer.client(), topic, fetch_timeout)?;
+) -> Result<SourcePartitions, GetPartitionsError> {
+    let partitions = match topics {
+        SourceTopics::Topic(topic) => {
+            let pids = get_partitions(consumer.client(), topic, fetch_timeout)?;
+            btreemap! { topic.clone() => pids }
+        }
+        SourceTopics::Set(matches) => {
+            get_matching_partitions(consumer.client(), |topic| matches(topic), fetch_timeout)?
+        }
+    };
 
-    let mut offset_requests = TopicPartitionList::with_capacity(pids.len());
-    for pid in pids {
-        offset_requests.add_partition_offset(topic, pid, offset_requested)?;
+    let mut result = SourcePartitions::default();
+    let mut offset_requests = TopicPartitionList::new();
+    for (topic, pids) in &partitions {
+        let key = match topics {
+            SourceTopics::Topic(_) => topics.topic_key(topic),
+            SourceTopics::Set(_) => topic_keys.insert(topic).map_err(anyhow::Error::from)?,
+        };
+        result.topics.insert(key, topic.clone());
+        for pid in pids {
+            offset_requests.add_partition_offset(topic, *pid, offset_requested)?;
+        }
     }
 
+    if offset_requests.count() == 0 {
+        return Ok(result);
+    }
     let offset_responses = consumer.offsets_for_times(offset_requests, fetch_timeout)?;
 
-    let mut result = BTreeMap::new();
     for entry in offset_responses.elements() {
         let offset = match entry.offset() {
             Offset::Offset(offset) => offset,
             offset => Err(anyhow!("unexpected high watermark offset: {offset:?}"))?,
         };
 
-        let pid = entry.partition();
+        let pid = topics.partition(entry.topic(), entry.partition());
         let watermark = offset.try_into().expect("invalid negative offset");
-        result.insert(pid, watermark);
+        result.watermarks.insert(pid, watermark);
     }
 
     Ok(result)
 }
 
+/// Downgrades the capabilities of the partitions that are not in `known`.
+///
+/// Afterwards, `data_cap` covers the partitions after the last known partition, and `gap_caps`
+/// cover the partitions before and between the known partitions.
+fn downgrade_unknown_partitions(
+    data_cap: &mut Capability<KafkaTimestamp>,
+    gap_caps: &mut Vec<Capability<KafkaTimestamp>>,
+    known: &BTreeSet<KafkaPartition>,
+) {
+    let mut unknown = KafkaPartition::unknown_partitions(known);
+    let future_ts = unknown.pop().expect("range after the known partitions");
+    let new_gap_caps = unknown
+        .iter()
+        .map(|ts| {
+            std::iter::once(&*data_cap)
+                .chain(gap_caps.iter())
+                .find(|cap| cap.time().less_equal(ts))
+                .expect("unknown partitions only ever shrink")
+                .delayed(ts)
+        })
+        .collect();
+    *gap_caps = new_gap_caps;
+    data_cap.downgrade(&future_ts);
+}
+
 /// An update produced by the metadata fetcher.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 enum MetadataUpdate {
     /// The current IDs and high watermarks of all topic partitions.
-    Partitions(BTreeMap<PartitionId, PartitionWatermark>),
+    Partitions(SourcePartitions),
     /// A transient error.
     ///
     /// Transient errors stall the source until their cause has been resolved.
@@ -1681,14 +1848,9 @@ impl MetadataUpdate {
     fn upstream_frontier(&self) -> Option<Antichain<KafkaTimestamp>> {
         match self {
             Self::Partitions(partitions) => {
-                let max_pid = partitions.keys().last().copied();
-                let lower = max_pid
-                    .map(RangeBound::after)
-                    .unwrap_or(RangeBound::NegInfinity);
-                let future_ts =
-                    Partitioned::new_range(lower, RangeBound::PosInfinity, MzOffset::from(0));
-
-                let mut frontier = Antichain::from_elem(future_ts);
+                let partitions = &partitions.watermarks;
+                let mut frontier =
+                    Antichain::from_iter(KafkaPartition::unknown_partitions(partitions.keys()));
                 for (pid, high_watermark) in partitions {
                     frontier.insert(Partitioned::new_singleton(
                         RangeBound::exact(*pid),
@@ -1761,9 +1923,11 @@ fn render_metadata_fetcher<'scope>(
         let KafkaSourceConnection {
             connection,
             topic,
+            topic_set,
             topic_metadata_refresh_interval,
             ..
         } = connection;
+        let topics = SourceTopics::new(&topic, topic_set.as_ref());
 
         let consumer: Result<BaseConsumer<_>, _> = connection
             .create_with_context(
@@ -1808,8 +1972,24 @@ fn render_metadata_fetcher<'scope>(
             }
         };
 
+        // Sources over a topic set keep reporting the partitions of topics that have been
+        // deleted, or that no longer match, at their last known watermark, so that their
+        // disappearance does not look like a regression of the upstream frontier.
+        let mut retained_partitions = match topic_set {
+            Some(_) => Some(
+                resume_upper
+                    .iter()
+                    .filter_map(|ts| {
+                        let pid = ts.interval().singleton()?.unwrap_exact();
+                        Some((*pid, ts.timestamp().offset))
+                    })
+                    .collect::<BTreeMap<_, _>>(),
+            ),
+            None => None,
+        };
+
         let (tx, mut rx) = mpsc::unbounded_channel();
-        spawn_metadata_thread(config, consumer, topic, tx);
+        spawn_metadata_thread(config, consumer, topics, tx);
 
         let mut prev_upstream_frontier = resume_upper;
 
@@ -1818,6 +1998,15 @@ fn render_metadata_fetcher<'scope>(
                 return;
             }
 
+            if let (Some(retained), MetadataUpdate::Partitions(partitions)) =
+                (&mut retained_partitions, &mut update)
+            {
+                for (pid, watermark) in &partitions.watermarks {
+                    retained.insert(*pid, *watermark);
+                }
+                partitions.watermarks = retained.clone();
+            }
+
             if let Some(upstream_frontier) = update.upstream_frontier() {
                 // Topics are identified by name but it's possible that a user recreates a topic
                 // with the same name. Ideally we'd want to catch all of these cases and
@@ -1856,7 +2045,7 @@ fn render_metadata_fetcher<'scope>(
 fn spawn_metadata_thread<C: ConsumerContext>(
     config: RawSourceCreationConfig,
     consumer: BaseConsumer<TunnelingClientContext<C>>,
-    topic: String,
+    topics: SourceTopics,
     tx: mpsc::UnboundedSender<(mz_repr::Timestamp, MetadataUpdate)>,
 ) {
     // Linux thread names are limited to 15 characters. Use a truncated ID to fit the name.
@@ -1872,12 +2061,16 @@ fn spawn_metadata_thread<C: ConsumerContext>(
 
             let timestamp_interval = config.timestamp_interval;
             let mut ticker = probe::Ticker::new(move || timestamp_interval, config.now_fn);
+            // Every topic discovered since the source started, including those that have since
+            // been deleted.
+            let mut topic_keys = KafkaTopicKeys::default();
 
             loop {
                 let probe_ts = ticker.tick_blocking();
                 let result = fetch_partition_info(
                     &consumer,
-                    &topic,
+                    &topics,
+                    &mut topic_keys,
                     config
                         .config
                         .parameters
@@ -1909,6 +2102,12 @@ fn spawn_metadata_thread<C: ConsumerContext>(
                         };
                         MetadataUpdate::DefiniteError(error)
                     }
+                    Err(GetPartitionsError::Other(e)) if e.is::<KafkaTopicKeyCollision>() => {
+                        let error = SourceError {
+                            error: SourceErrorDetails::Other(e.to_string().into()),
+                        };
+                        MetadataUpdate::DefiniteError(error)
+                    }
                     Err(e) => {
                         let kafka_status = Some(HealthStatusUpdate::stalled(
                             format!("{}", e.display_with_causes()),
@@ -0,0 +1,327 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of a [`KinesisSourceConnection`].
+//!
+//! A Kinesis source reads every record of a Kinesis data stream, from the
+//! oldest record that is still retained by the stream. The partition key of a
+//! record is its key and the data of the record is its value, which are
+//! decoded with the formats and envelopes that Kafka sources support.
+//!
+//! # Timestamps
+//!
+//! The source is timestamped with [`KinesisTimestamp`], in which every shard
+//! is its own partition identified by the index in its shard ID. The frontier
+//! of a shard is one more than the sequence number of the last record that
+//! was read from it. Kinesis reshards a stream by closing shards and
+//! replacing them with child shards, so once a closed shard has been read to
+//! its end it is removed from the frontier. A shard that doesn't have an
+//! element in the frontier has therefore been read completely.
+//!
+//! Records with the same partition key move from a parent shard to its child
+//! shards, so a child shard is only read once all of its parents have been
+//! read to their end. This keeps the records of every partition key in order,
+//! which the `UPSERT` envelope relies on.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use differential_dataflow::AsCollection;
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId};
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::kinesis::{KinesisMetadataKind, KinesisTimestamp, SequenceNumber};
+use mz_storage_types::sources::{
+    KinesisSourceConnection, SourceExport, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::PressOnDropButton;
+use mz_timely_util::order::Partitioned;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Concat;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::RawSourceCreationConfig;
+use crate::source::types::{Probe, SourceMessage, SourceRender, StackedCollection};
+
+mod reader;
+
+#[derive(Debug, Clone)]
+struct SourceOutputInfo {
+    /// Upper to resume ingestion from.
+    resume_upper: Antichain<KinesisTimestamp>,
+    /// An index to split the timely stream.
+    partition_index: u64,
+    /// The metadata that is included with every record of this output.
+    metadata_columns: Vec<KinesisMetadataKind>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReplicationError {
+    #[error(transparent)]
+    Transient(#[from] Rc<TransientError>),
+    #[error(transparent)]
+    DefiniteError(#[from] Rc<DefiniteError>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransientError {
+    #[error("kinesis error: {0}")]
+    Kinesis(String),
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("stream {0} does not exist")]
+    StreamNotFound(String),
+    #[error("invalid shard ID {0}")]
+    InvalidShardId(String),
+    #[error("shard {shard} returned an invalid sequence number {sequence_number}")]
+    InvalidSequenceNumber {
+        shard: String,
+        sequence_number: String,
+    },
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+/// Returns the index of the shard with the given ID, e.g. `7` for `shardId-000000000007`.
+fn shard_index(shard_id: &str) -> Option<u64> {
+    shard_id.strip_prefix("shardId-")?.parse().ok()
+}
+
+/// Returns the sequence number from which the shard with `index` is read next at `frontier`, or
+/// `None` if the frontier has no element for the shard, i.e. the shard has been read to its end.
+fn shard_position(frontier: &[KinesisTimestamp], index: u64) -> Option<SequenceNumber> {
+    frontier
+        .iter()
+        .filter(|ts| ts.interval().contains(&index))
+        .map(|ts| *ts.timestamp())
+        .min()
+}
+
+/// Returns the frontier at which every shard in `shards` has been read up to its position. A
+/// shard whose position is `None` has been read to its end, and shards that aren't in `shards`
+/// haven't been read from at all.
+fn shards_frontier(shards: &BTreeMap<u64, Option<SequenceNumber>>) -> Antichain<KinesisTimestamp> {
+    let mut frontier = Antichain::new();
+    // The lowest partition that hasn't been added to the frontier yet.
+    let mut lower = Some(0);
+    for (index, position) in shards {
+        let Some(range_lower) = lower else {
+            unreachable!("no partitions after {index}");
+        };
+        if let Some(range_upper) = index.checked_sub(1)
+            && range_lower <= range_upper
+        {
+            frontier.insert(Partitioned::new_range(
+                range_lower,
+                range_upper,
+                SequenceNumber::default(),
+            ));
+        }
+        if let Some(position) = position {
+            frontier.insert(Partitioned::new_singleton(*index, *position));
+        }
+        lower = index.checked_add(1);
+    }
+    if let Some(range_lower) = lower {
+        frontier.insert(Partitioned::new_range(
+            range_lower,
+            u64::MAX,
+            SequenceNumber::default(),
+        ));
+    }
+    frontier
+}
+
+impl SourceRender for KinesisSourceConnection {
+    type Time = KinesisTimestamp;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::Kinesis;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, KinesisTimestamp>,
+        config: &RawSourceCreationConfig,
+        _resume_uppers: impl futures::Stream<Item = Antichain<KinesisTimestamp>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        // Timely Collection for each Source Export defined in the provided `config`.
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, KinesisTimestamp, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, KinesisTimestamp, HealthStatusMessage>,
+        StreamVec<'scope, KinesisTimestamp, Probe<KinesisTimestamp>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // Collect the source outputs that we will be exporting.
+        let mut source_outputs = BTreeMap::new();
+        for (idx, (id, export)) in config.source_exports.iter().enumerate() {
+            let SourceExport {
+                details,
+                storage_metadata: _,
+                data_config: _,
+            } = export;
+
+            let metadata_columns = match details {
+                SourceExportDetails::Kinesis(details) => details
+                    .metadata_columns
+                    .iter()
+                    .map(|(_name, kind)| kind.clone())
+                    .collect(),
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            };
+
+            let resume_upper = config
+                .source_resume_uppers
+                .get(id)
+                .expect("missing resume upper")
+                .iter()
+                .map(KinesisTimestamp::decode_row);
+
+            let output_info = SourceOutputInfo {
+                resume_upper: Antichain::from_iter(resume_upper),
+                partition_index: u64::cast_from(idx),
+                metadata_columns,
+            };
+            source_outputs.insert(*id, output_info);
+        }
+
+        let metrics = config.metrics.get_kinesis_source_metrics(config.id);
+
+        let (repl_updates, repl_errs, probes, repl_token) = reader::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs,
+            self.clone(),
+            metrics,
+        );
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = repl_updates
+            .inner
+            .partition::<CapacityContainerBuilder<_>, _, _>(
+                partition_count,
+                move |((partition_idx, data), time, diff): (
+                    (u64, Result<SourceMessage, DataflowError>),
+                    KinesisTimestamp,
+                    Diff,
+                )| { (partition_idx, (data, time, diff)) },
+            );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = repl_errs.map(move |err| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            let update = HealthStatusUpdate::halting(err_string, None);
+
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update,
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (data_collections, health, probes, vec![repl_token])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use timely::PartialOrder;
+    use timely::progress::Timestamp;
+
+    use super::*;
+
+    fn seq(n: u64) -> SequenceNumber {
+        n.to_string().parse().unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_shard_index() {
+        assert_eq!(shard_index("shardId-000000000000"), Some(0));
+        assert_eq!(shard_index("shardId-000000000123"), Some(123));
+        assert_eq!(shard_index("shard-1"), None);
+        assert_eq!(shard_index("shardId-abc"), None);
+    }
+
+    #[mz_ore::test]
+    fn test_shards_frontier_roundtrip() {
+        let shards = BTreeMap::from([(0, None), (1, Some(seq(42))), (3, Some(seq(0)))]);
+        let frontier = shards_frontier(&shards);
+        assert_eq!(shard_position(frontier.elements(), 0), None);
+        assert_eq!(shard_position(frontier.elements(), 1), Some(seq(42)));
+        // Shards that aren't known yet haven't been read from.
+        assert_eq!(shard_position(frontier.elements(), 2), Some(seq(0)));
+        assert_eq!(shard_position(frontier.elements(), 3), Some(seq(0)));
+        assert_eq!(shard_position(frontier.elements(), u64::MAX), Some(seq(0)));
+
+        assert_eq!(
+            shards_frontier(&BTreeMap::new()),
+            Antichain::from_elem(KinesisTimestamp::minimum())
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_shards_frontier_advances() {
+        let before = shards_frontier(&BTreeMap::from([(0, Some(seq(10))), (1, Some(seq(0)))]));
+        let after = shards_frontier(&BTreeMap::from([(0, None), (1, Some(seq(5)))]));
+        assert!(PartialOrder::less_than(&before, &after));
+
+        // A record of a closed shard is beyond the frontier until the shard has been read to
+        // its end.
+        let time = Partitioned::new_singleton(0, seq(20));
+        assert!(before.less_equal(&time));
+        assert!(!after.less_equal(&time));
+    }
+
+    #[mz_ore::test]
+    fn test_shards_frontier_extremes() {
+        let shards = BTreeMap::from([(0, Some(seq(1))), (u64::MAX, None)]);
+        let frontier = shards_frontier(&shards);
+        assert_eq!(frontier.len(), 2);
+        assert_eq!(shard_position(frontier.elements(), 0), Some(seq(1)));
+        assert_eq!(shard_position(frontier.elements(), u64::MAX), None);
+    }
+}
@@ -0,0 +1,648 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the reader of a [`KinesisSourceConnection`].
+//!
+//! A single worker reads every shard of the stream. Every
+//! `kinesis_source_poll_interval` it issues a `GetRecords` request for each
+//! shard it is reading, and polls again sooner if any shard is behind the tip
+//! of the stream. Shards are listed when the source starts and whenever a
+//! shard has been read to its end, which is when a resharding has closed it
+//! and its child shards can be read.
+//!
+//! Kinesis doesn't expose the sequence number of the latest record of a
+//! shard, so the operator probes its own frontier as the upstream frontier.
+//!
+//! [`KinesisSourceConnection`]: mz_storage_types::sources::KinesisSourceConnection
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use aws_sdk_kinesis::error::DisplayErrorContext;
+use aws_sdk_kinesis::types::{Record, Shard, ShardIteratorType};
+use chrono::{DateTime, NaiveDateTime};
+use mz_aws_util::kinesis::Client;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_storage_types::dyncfgs::{ENFORCE_EXTERNAL_ADDRESSES, KINESIS_SOURCE_POLL_INTERVAL};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::KinesisSourceConnection;
+use mz_storage_types::sources::kinesis::{KinesisMetadataKind, KinesisTimestamp, SequenceNumber};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use mz_timely_util::order::Partitioned;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::operators::{CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::metrics::source::kinesis::KinesisSourceMetrics;
+use crate::source::kinesis::{
+    DefiniteError, ReplicationError, SourceOutputInfo, TransientError, shard_index, shard_position,
+    shards_frontier,
+};
+use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceMessage, StackedCollection};
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// How soon a shard is polled again while it's behind the tip of the stream. Kinesis allows
+/// five `GetRecords` calls per second and shard.
+const CATCH_UP_INTERVAL: Duration = Duration::from_millis(200);
+
+/// A shard of the stream.
+struct ReaderShard {
+    /// The ID of the shard, e.g. `shardId-000000000007`.
+    id: String,
+    /// The indexes of the shards this shard was split from or merged from.
+    parents: Vec<u64>,
+    /// The sequence number from which the shard is read next, or `None` if the shard has been
+    /// read to its end.
+    position: Option<SequenceNumber>,
+    /// The iterator that reads the shard from `position`, if one has been created.
+    iterator: Option<String>,
+}
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, KinesisTimestamp>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: KinesisSourceConnection,
+    metrics: KinesisSourceMetrics,
+) -> (
+    StackedCollection<'scope, KinesisTimestamp, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, KinesisTimestamp, ReplicationError>,
+    StreamVec<'scope, KinesisTimestamp, Probe<KinesisTimestamp>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("KinesisReader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    // Captures DefiniteErrors that affect the entire source, including all outputs
+    let (definite_error_handle, definite_errors) =
+        builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set, probe_cap, definite_error_cap_set]: &mut [_; 3] =
+                caps.try_into().unwrap();
+
+            // Sequence numbers don't fit into the offsets of the statistics, so they only mark
+            // this worker as having started up correctly.
+            for stat in config.statistics.values() {
+                stat.set_offset_known(0);
+                stat.set_offset_committed(0);
+            }
+
+            // Only a single worker reads the stream, which is what allows it to read child
+            // shards only after their parents.
+            if !config.responsible_for(&source.stream) {
+                return Ok(());
+            }
+
+            let outputs: Vec<_> = outputs
+                .into_values()
+                .filter(|output| !output.resume_upper.is_empty())
+                .collect();
+            // The stream is read from the earliest position of any output.
+            let resume_upper: Antichain<_> = outputs
+                .iter()
+                .flat_map(|output| output.resume_upper.iter().cloned())
+                .collect();
+            if resume_upper.is_empty() {
+                return Ok(());
+            }
+
+            let sdk_config = source
+                .connection
+                .load_sdk_config(
+                    &config.config.connection_context,
+                    source.connection_id,
+                    InTask::Yes,
+                    ENFORCE_EXTERNAL_ADDRESSES.get(config.config.config_set()),
+                )
+                .await?;
+            let client = mz_aws_util::kinesis::new_client(&sdk_config);
+
+            // Every shard that has been read to its end according to the resume upper. These
+            // need to stay out of the frontier even if the stream no longer retains them.
+            let mut shards: BTreeMap<u64, ReaderShard> = finished_shards(resume_upper.elements())
+                .into_iter()
+                .map(|index| {
+                    let shard = ReaderShard {
+                        id: format!("shardId-{index:012}"),
+                        parents: vec![],
+                        position: None,
+                        iterator: None,
+                    };
+                    (index, shard)
+                })
+                .collect();
+
+            let listed = match list_shards(&client, &source.stream).await? {
+                Ok(listed) => listed,
+                Err(err) => {
+                    return_definite_error(
+                        err,
+                        outputs.iter(),
+                        data_output,
+                        data_cap_set,
+                        definite_error_handle,
+                        definite_error_cap_set,
+                    )
+                    .await;
+                    return Ok(());
+                }
+            };
+            if let Err(err) = add_shards(&mut shards, listed, resume_upper.elements()) {
+                return_definite_error(
+                    err,
+                    outputs.iter(),
+                    data_output,
+                    data_cap_set,
+                    definite_error_handle,
+                    definite_error_cap_set,
+                )
+                .await;
+                return Ok(());
+            }
+            data_cap_set.downgrade(shards_frontier(&positions(&shards)).iter());
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker =
+                probe::Ticker::new(move || timestamp_interval, config.now_fn.clone());
+            let mut next_poll = tokio::time::Instant::now();
+
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: shards_frontier(&positions(&shards)),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                    },
+                    _ = tokio::time::sleep_until(next_poll) => {
+                        let result = poll_and_list_shards(
+                            &client,
+                            &source.stream,
+                            &mut shards,
+                            &outputs,
+                            resume_upper.elements(),
+                            &data_output,
+                            data_cap_set,
+                            &metrics,
+                        )
+                        .await?;
+                        let behind = match result {
+                            Ok(behind) => behind,
+                            Err(err) => {
+                                return_definite_error(
+                                    err,
+                                    outputs.iter(),
+                                    data_output,
+                                    data_cap_set,
+                                    definite_error_handle,
+                                    definite_error_cap_set,
+                                )
+                                .await;
+                                return Ok(());
+                            }
+                        };
+                        data_cap_set.downgrade(shards_frontier(&positions(&shards)).iter());
+
+                        let interval = if behind {
+                            CATCH_UP_INTERVAL
+                        } else {
+                            KINESIS_SOURCE_POLL_INTERVAL.get(config.config.config_set())
+                        };
+                        next_poll = tokio::time::Instant::now() + interval;
+                    },
+                };
+            }
+        }))
+    });
+
+    let error_stream = definite_errors.concat(transient_errors.map(ReplicationError::Transient));
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        probe_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+type DataOutput =
+    StackedAsyncOutputHandle<KinesisTimestamp, (u64, Result<SourceMessage, DataflowError>)>;
+
+/// The outcome of polling every shard once.
+struct PollResult {
+    /// Whether any shard is behind the tip of the stream.
+    behind: bool,
+    /// Whether any shard has been read to its end.
+    finished: bool,
+}
+
+/// Returns the shards that have been read to their end at `frontier`, i.e. the shards that the
+/// frontier has no element for.
+fn finished_shards(frontier: &[KinesisTimestamp]) -> Vec<u64> {
+    let mut intervals: Vec<_> = frontier
+        .iter()
+        .map(|ts| (ts.interval().lower, ts.interval().upper))
+        .collect();
+    intervals.sort();
+    let mut finished = vec![];
+    // The lowest shard that isn't known to be covered by the frontier.
+    let mut next = Some(0);
+    for (lower, upper) in intervals {
+        let Some(start) = next else {
+            break;
+        };
+        if lower > start {
+            finished.extend(start..lower);
+        }
+        if upper >= start {
+            next = upper.checked_add(1);
+        }
+    }
+    finished
+}
+
+/// Returns the position of every known shard.
+fn positions(shards: &BTreeMap<u64, ReaderShard>) -> BTreeMap<u64, Option<SequenceNumber>> {
+    shards
+        .iter()
+        .map(|(index, shard)| (*index, shard.position))
+        .collect()
+}
+
+/// Lists the shards of `stream`.
+async fn list_shards(
+    client: &Client,
+    stream: &str,
+) -> Result<Result<Vec<Shard>, DefiniteError>, TransientError> {
+    match mz_aws_util::kinesis::list_shards(client, stream).await {
+        Ok(shards) => Ok(Ok(shards)),
+        Err(err) => match err.as_service_error() {
+            Some(e) if e.is_resource_not_found_exception() => {
+                Ok(Err(DefiniteError::StreamNotFound(stream.to_string())))
+            }
+            _ => Err(TransientError::Kinesis(
+                DisplayErrorContext(err).to_string(),
+            )),
+        },
+    }
+}
+
+/// Adds the shards in `listed` that aren't known yet to `shards`, starting them from their
+/// position at `resume_upper`.
+///
+/// Known shards that are no longer listed have been removed by the stream after its retention
+/// period, and are considered to be read to their end.
+fn add_shards(
+    shards: &mut BTreeMap<u64, ReaderShard>,
+    listed: Vec<Shard>,
+    resume_upper: &[KinesisTimestamp],
+) -> Result<(), DefiniteError> {
+    let mut listed_indexes = vec![];
+    for shard in listed {
+        let id = shard.shard_id();
+        let index = shard_index(id).ok_or_else(|| DefiniteError::InvalidShardId(id.into()))?;
+        listed_indexes.push(index);
+        if shards.contains_key(&index) {
+            continue;
+        }
+        let parents = [shard.parent_shard_id(), shard.adjacent_parent_shard_id()]
+            .into_iter()
+            .flatten()
+            .map(|id| shard_index(id).ok_or_else(|| DefiniteError::InvalidShardId(id.into())))
+            .collect::<Result<_, _>>()?;
+        let shard = ReaderShard {
+            id: id.to_string(),
+            parents,
+            position: shard_position(resume_upper, index),
+            iterator: None,
+        };
+        shards.insert(index, shard);
+    }
+    for (index, shard) in shards.iter_mut() {
+        if shard.position.is_some() && !listed_indexes.contains(index) {
+            tracing::warn!(
+                shard = %shard.id,
+                "kinesis shard expired before it was read to its end",
+            );
+            shard.position = None;
+            shard.iterator = None;
+        }
+    }
+    // Any shard that hasn't been listed yet is still covered by the resume upper, so it can only
+    // be behind its position in the resume upper.
+    for ts in resume_upper {
+        if let Some(index) = ts.interval().singleton()
+            && !shards.contains_key(index)
+        {
+            tracing::warn!(
+                shard = %format!("shardId-{index:012}"),
+                "kinesis shard expired before it was read to its end",
+            );
+            let shard = ReaderShard {
+                id: format!("shardId-{index:012}"),
+                parents: vec![],
+                position: None,
+                iterator: None,
+            };
+            shards.insert(*index, shard);
+        }
+    }
+    Ok(())
+}
+
+/// Polls every shard once, and lists the shards of the stream again if any shard has been read
+/// to its end. Returns whether any shard is behind the tip of the stream.
+async fn poll_and_list_shards(
+    client: &Client,
+    stream: &str,
+    shards: &mut BTreeMap<u64, ReaderShard>,
+    outputs: &[SourceOutputInfo],
+    resume_upper: &[KinesisTimestamp],
+    data_output: &DataOutput,
+    data_cap_set: &CapabilitySet<KinesisTimestamp>,
+    metrics: &KinesisSourceMetrics,
+) -> Result<Result<bool, DefiniteError>, TransientError> {
+    let result = match poll_shards(
+        client,
+        stream,
+        shards,
+        outputs,
+        data_output,
+        data_cap_set,
+        metrics,
+    )
+    .await?
+    {
+        Ok(result) => result,
+        Err(err) => return Ok(Err(err)),
+    };
+    if result.finished {
+        // The children of the finished shards can be read now.
+        let listed = match list_shards(client, stream).await? {
+            Ok(listed) => listed,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Err(err) = add_shards(shards, listed, resume_upper) {
+            return Ok(Err(err));
+        }
+    }
+    Ok(Ok(result.behind))
+}
+
+/// Issues a `GetRecords` request for every shard that can be read, and emits the records to the
+/// outputs that haven't ingested them yet.
+async fn poll_shards(
+    client: &Client,
+    stream: &str,
+    shards: &mut BTreeMap<u64, ReaderShard>,
+    outputs: &[SourceOutputInfo],
+    data_output: &DataOutput,
+    data_cap_set: &CapabilitySet<KinesisTimestamp>,
+    metrics: &KinesisSourceMetrics,
+) -> Result<Result<PollResult, DefiniteError>, TransientError> {
+    let mut result = PollResult {
+        behind: false,
+        finished: false,
+    };
+    let mut millis_behind_latest = 0;
+    let indexes: Vec<_> = shards.keys().copied().collect();
+    for index in indexes {
+        let parents_finished = shards[&index]
+            .parents
+            .iter()
+            .all(|parent| shards.get(parent).is_none_or(|p| p.position.is_none()));
+        let shard = shards.get_mut(&index).expect("known shard");
+        let Some(position) = shard.position else {
+            continue;
+        };
+        if !parents_finished {
+            continue;
+        }
+
+        let iterator = match &shard.iterator {
+            Some(iterator) => iterator.clone(),
+            None => {
+                let request = client
+                    .get_shard_iterator()
+                    .stream_name(stream)
+                    .shard_id(&shard.id);
+                let request = match position.checked_decrement() {
+                    Some(last) => request
+                        .shard_iterator_type(ShardIteratorType::AfterSequenceNumber)
+                        .starting_sequence_number(last.to_string()),
+                    None => request.shard_iterator_type(ShardIteratorType::TrimHorizon),
+                };
+                match request.send().await {
+                    Ok(response) => match response.shard_iterator() {
+                        Some(iterator) => iterator.to_string(),
+                        None => {
+                            // The shard is closed and has been read to its end.
+                            shard.position = None;
+                            result.finished = true;
+                            continue;
+                        }
+                    },
+                    Err(err) => match err.as_service_error() {
+                        Some(e) if e.is_provisioned_throughput_exceeded_exception() => {
+                            metrics.throttled_requests.inc();
+                            continue;
+                        }
+                        Some(e) if e.is_resource_not_found_exception() => {
+                            // The shard has expired, which the next listing confirms, or the
+                            // stream has been deleted, which the next listing reports.
+                            result.finished = true;
+                            continue;
+                        }
+                        _ => {
+                            let err = DisplayErrorContext(err).to_string();
+                            return Err(TransientError::Kinesis(err));
+                        }
+                    },
+                }
+            }
+        };
+
+        metrics.get_records_requests.inc();
+        let response = match client.get_records().shard_iterator(iterator).send().await {
+            Ok(response) => response,
+            Err(err) => match err.as_service_error() {
+                Some(e)
+                    if e.is_provisioned_throughput_exceeded_exception()
+                        || e.is_kms_throttling_exception() =>
+                {
+                    metrics.throttled_requests.inc();
+                    continue;
+                }
+                Some(e) if e.is_expired_iterator_exception() => {
+                    // Iterators expire five minutes after they are created. A new one is created
+                    // from the position of the shard in the next round.
+                    shard.iterator = None;
+                    continue;
+                }
+                Some(e) if e.is_resource_not_found_exception() => {
+                    shard.iterator = None;
+                    result.finished = true;
+                    continue;
+                }
+                _ => {
+                    let err = DisplayErrorContext(err).to_string();
+                    return Err(TransientError::Kinesis(err));
+                }
+            },
+        };
+
+        let records = response.records();
+        if !records.is_empty() {
+            let cap = data_cap_set.delayed(&Partitioned::new_singleton(index, position));
+            let mut last = position;
+            for record in records {
+                let sequence_number: SequenceNumber = match record.sequence_number().parse() {
+                    Ok(sequence_number) => sequence_number,
+                    Err(_) => {
+                        return Ok(Err(DefiniteError::InvalidSequenceNumber {
+                            shard: shard.id.clone(),
+                            sequence_number: record.sequence_number().to_string(),
+                        }));
+                    }
+                };
+                let time = Partitioned::new_singleton(index, sequence_number);
+                for output in outputs {
+                    if !output.resume_upper.less_equal(&time) {
+                        continue;
+                    }
+                    let message =
+                        construct_source_message(&shard.id, record, &output.metadata_columns);
+                    let update = ((output.partition_index, Ok(message)), time, Diff::ONE);
+                    let size = update.fuel_size();
+                    data_output.give_fueled(&cap, update, size).await;
+                }
+                last = sequence_number;
+            }
+            metrics.records.inc_by(u64::cast_from(records.len()));
+            let position = last
+                .checked_increment()
+                .expect("sequence numbers are below the maximum");
+            shard.position = Some(position);
+        }
+
+        match response.next_shard_iterator() {
+            Some(next) => shard.iterator = Some(next.to_string()),
+            None => {
+                // The shard has been closed by a resharding and read to its end.
+                tracing::info!(shard = %shard.id, "kinesis shard finished");
+                metrics.shards_finished.inc();
+                shard.position = None;
+                shard.iterator = None;
+                result.finished = true;
+            }
+        }
+
+        let behind = response
+            .millis_behind_latest()
+            .and_then(|millis| u64::try_from(millis).ok())
+            .unwrap_or(0);
+        if behind > 0 && !records.is_empty() {
+            result.behind = true;
+        }
+        millis_behind_latest = std::cmp::max(millis_behind_latest, behind);
+    }
+
+    let active = shards.values().filter(|s| s.iterator.is_some()).count();
+    metrics.active_shards.set(u64::cast_from(active));
+    metrics.millis_behind_latest.set(millis_behind_latest);
+    Ok(Ok(result))
+}
+
+/// Constructs the message of `record`, with the metadata that `metadata_columns` asks for.
+fn construct_source_message(
+    shard_id: &str,
+    record: &Record,
+    metadata_columns: &[KinesisMetadataKind],
+) -> SourceMessage {
+    let mut metadata = Row::default();
+    let mut packer = metadata.packer();
+    for kind in metadata_columns {
+        match kind {
+            KinesisMetadataKind::Partition => packer.push(Datum::String(shard_id)),
+            KinesisMetadataKind::Offset => packer.push(Datum::String(record.sequence_number())),
+            KinesisMetadataKind::Timestamp => {
+                let d: Datum = record
+                    .approximate_arrival_timestamp()
+                    .and_then(|ts| ts.to_millis().ok())
+                    .and_then(DateTime::from_timestamp_millis)
+                    .and_then(|dt| {
+                        let ct: Option<CheckedTimestamp<NaiveDateTime>> =
+                            dt.naive_utc().try_into().ok();
+                        ct
+                    })
+                    .into();
+                packer.push(d)
+            }
+        }
+    }
+
+    SourceMessage {
+        key: Row::pack([Datum::Bytes(record.partition_key().as_bytes())]),
+        value: Row::pack([Datum::Bytes(record.data().as_ref())]),
+        metadata,
+    }
+}
+
+/// Helper method to return a "definite" error upstream.
+async fn return_definite_error<'a>(
+    err: DefiniteError,
+    outputs: impl Iterator<Item = &'a SourceOutputInfo>,
+    data_handle: DataOutput,
+    data_capset: &CapabilitySet<KinesisTimestamp>,
+    errs_handle: AsyncOutputHandle<
+        KinesisTimestamp,
+        CapacityContainerBuilder<Vec<ReplicationError>>,
+    >,
+    errs_capset: &CapabilitySet<KinesisTimestamp>,
+) {
+    for output in outputs {
+        // The error is a property of the stream itself, so it is emitted at the minimum time of
+        // the outputs that have not ingested anything yet, which is every output that could have
+        // observed the error.
+        if *output.resume_upper != [KinesisTimestamp::minimum()] {
+            continue;
+        }
+        let update = (
+            (output.partition_index, Err(err.clone().into())),
+            KinesisTimestamp::minimum(),
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_handle.give_fueled(&data_capset[0], update, size).await;
+    }
+    errs_handle.give(
+        &errs_capset[0],
+        ReplicationError::DefiniteError(Rc::new(err)),
+    );
+}
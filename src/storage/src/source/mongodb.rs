@@ -0,0 +1,225 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of a [`MongoDbSourceConnection`].
+//!
+//! A MongoDB source ingests the collections of a single database. Every
+//! collection is exported as a table of `(_id, document)` pairs, and is kept
+//! up to date by following the change stream of the database. The source is
+//! timestamped with the [`ClusterTime`] of the deployment, which is also what
+//! resume tokens, the positions of a change stream, are made of.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use differential_dataflow::AsCollection;
+use itertools::Itertools;
+use mz_mongodb_util::{ClusterTime, MongoDbError};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId};
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::{
+    MongoDbSourceConnection, SourceExport, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::PressOnDropButton;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Concat;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::RawSourceCreationConfig;
+use crate::source::types::{Probe, SourceMessage, SourceRender, StackedCollection};
+
+mod progress;
+mod replication;
+
+#[derive(Debug, Clone)]
+struct SourceOutputInfo {
+    /// Name of the upstream collection.
+    collection: String,
+    /// Upper to resume replication from.
+    resume_upper: Antichain<ClusterTime>,
+    /// An index to split the timely stream.
+    partition_index: u64,
+    /// The basis for the resumption cluster time when snapshotting.
+    initial_cluster_time: ClusterTime,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReplicationError {
+    #[error(transparent)]
+    Transient(#[from] Rc<TransientError>),
+    #[error(transparent)]
+    DefiniteError(#[from] Rc<DefiniteError>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransientError {
+    #[error("stream ended prematurely")]
+    ReplicationEOF,
+    #[error(transparent)]
+    MongoDb(#[from] MongoDbError),
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+impl From<mongodb::error::Error> for TransientError {
+    fn from(err: mongodb::error::Error) -> Self {
+        TransientError::MongoDb(err.into())
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("collection {0} was dropped")]
+    CollectionDropped(String),
+    #[error("collection {0} was renamed to {1}")]
+    CollectionRenamed(String, String),
+    #[error("database {0} was dropped")]
+    DatabaseDropped(String),
+    #[error("change stream was invalidated")]
+    StreamInvalidated,
+    #[error(
+        "change stream history is no longer available at cluster time {0}, \
+         the oplog may be too small to retain it"
+    )]
+    HistoryLost(ClusterTime),
+    #[error("programming error: {0}")]
+    ProgrammingError(String),
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+impl SourceRender for MongoDbSourceConnection {
+    type Time = ClusterTime;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::MongoDb;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, ClusterTime>,
+        config: &RawSourceCreationConfig,
+        resume_uppers: impl futures::Stream<Item = Antichain<ClusterTime>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        // Timely Collection for each Source Export defined in the provided `config`.
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, ClusterTime, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, ClusterTime, HealthStatusMessage>,
+        StreamVec<'scope, ClusterTime, Probe<ClusterTime>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // Collect the source outputs that we will be exporting.
+        let mut source_outputs = BTreeMap::new();
+        for (idx, (id, export)) in config.source_exports.iter().enumerate() {
+            let SourceExport {
+                details,
+                storage_metadata: _,
+                data_config: _,
+            } = export;
+
+            let details = match details {
+                SourceExportDetails::MongoDb(details) => details,
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            };
+
+            let resume_upper = config
+                .source_resume_uppers
+                .get(id)
+                .expect("missing resume upper")
+                .iter()
+                .map(ClusterTime::decode_row);
+
+            let output_info = SourceOutputInfo {
+                collection: details.collection.clone(),
+                resume_upper: Antichain::from_iter(resume_upper),
+                partition_index: u64::cast_from(idx),
+                initial_cluster_time: details.initial_cluster_time,
+            };
+            source_outputs.insert(*id, output_info);
+        }
+
+        let metrics = config.metrics.get_mongodb_source_metrics(config.id);
+
+        let (repl_updates, repl_errs, repl_token) = replication::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs.clone(),
+            self.clone(),
+            metrics,
+        );
+
+        let (progress_errs, progress_probes, progress_token) =
+            progress::render(scope.clone(), config.clone(), self.clone(), resume_uppers);
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = repl_updates
+            .inner
+            .partition::<CapacityContainerBuilder<_>, _, _>(
+                partition_count,
+                move |((partition_idx, data), time, diff): (
+                    (u64, Result<SourceMessage, DataflowError>),
+                    ClusterTime,
+                    Diff,
+                )| { (partition_idx, (data, time, diff)) },
+            );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = repl_errs.concat(progress_errs).map(move |err| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            let update = HealthStatusUpdate::halting(err_string, None);
+
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update,
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (
+            data_collections,
+            health,
+            progress_probes,
+            vec![repl_token, progress_token],
+        )
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A "non-critical" operator that tracks the progress of a [`MongoDbSourceConnection`].
+//!
+//! The operator does the following:
+//!
+//! * At some cadence `timestamp_interval` will probe the source for the
+//!   current [`ClusterTime`], emit the upstream known offset, and update
+//!   `SourceStatistics`.
+//! * Listen to a provided [`futures::Stream`] of resume uppers, which represents
+//!   the durably committed upper for _all_ of the exports associated with this
+//!   source, and update `SourceStatistics` to notify listeners of a new
+//!   "committed cluster time".
+//!
+//! [`MongoDbSourceConnection`]: mz_storage_types::sources::MongoDbSourceConnection
+
+use futures::StreamExt;
+use mz_mongodb_util::ClusterTime;
+use mz_mongodb_util::inspect::get_current_cluster_time;
+use mz_ore::future::InTask;
+use mz_storage_types::sources::MongoDbSourceConnection;
+use mz_timely_util::builder_async::{OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::source::mongodb::{ReplicationError, TransientError};
+use crate::source::types::Probe;
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// Used as a partition ID to determine the worker that is responsible for
+/// handling progress.
+static PROGRESS_WORKER: &str = "progress";
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, ClusterTime>,
+    config: RawSourceCreationConfig,
+    source: MongoDbSourceConnection,
+    committed_uppers: impl futures::Stream<Item = Antichain<ClusterTime>> + 'static,
+) -> (
+    StreamVec<'scope, ClusterTime, ReplicationError>,
+    StreamVec<'scope, ClusterTime, Probe<ClusterTime>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("MongoDbProgress({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        Box::pin(async move {
+            let [probe_cap]: &mut [_; 1] = caps.try_into().unwrap();
+
+            // Only a single worker is responsible for processing progress.
+            if !config.responsible_for(PROGRESS_WORKER) {
+                // Emit 0 to mark this worker as having started up correctly.
+                for stat in config.statistics.values() {
+                    stat.set_offset_known(0);
+                    stat.set_offset_committed(0);
+                }
+                return Ok(());
+            }
+
+            let connection_config = source
+                .connection
+                .config(
+                    &config.config.connection_context.secrets_reader,
+                    &config.config,
+                    InTask::Yes,
+                )
+                .await?;
+            let client = connection_config.connect().await?;
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker = probe::Ticker::new(move || timestamp_interval, config.now_fn);
+
+            // The last cluster time observed upstream. Tracked to detect a
+            // deployment whose cluster time moves backwards.
+            let mut prev_offset_known: Option<ClusterTime> = None;
+
+            let mut committed_uppers = std::pin::pin!(committed_uppers);
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        // Every write that is visible upstream has a cluster
+                        // time less than or equal to the current one, so the
+                        // upstream frontier is the time just past it.
+                        let known_time = get_current_cluster_time(&client).await?.increment();
+                        if let Some(prev) = prev_offset_known
+                            && known_time < prev
+                        {
+                            mz_ore::soft_panic_or_log!(
+                                "upstream MongoDB went backwards in time, \
+                                 current cluster time: {known_time}, last known {prev}",
+                            );
+                            continue;
+                        }
+                        for stat in config.statistics.values() {
+                            stat.set_offset_known(known_time.as_u64());
+                        }
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: Antichain::from_elem(known_time),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                        prev_offset_known = Some(known_time);
+                    },
+                    Some(committed_upper) = committed_uppers.next() => {
+                        let Some(committed_upper) = committed_upper.as_option() else {
+                            // It's possible that the source has been dropped, in which case this can
+                            // observe an empty upper. This operator should continue to loop until
+                            // the drop dataflow propagates.
+                            continue;
+                        };
+                        for stat in config.statistics.values() {
+                            stat.set_offset_committed(committed_upper.as_u64());
+                        }
+                    }
+                };
+            }
+        })
+    });
+
+    let error_stream = transient_errors.map(ReplicationError::Transient);
+
+    (error_stream, probe_stream, button.press_on_drop())
+}
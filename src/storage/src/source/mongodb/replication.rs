@@ -0,0 +1,561 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the replication side of a [`MongoDbSourceConnection`].
+//!
+//! # Snapshot
+//!
+//! Every export that hasn't ingested anything yet is snapshotted first. All
+//! collections are snapshotted as of the same cluster time, `snapshot_time`,
+//! and the documents of the snapshot are emitted at [`ClusterTime::minimum`].
+//!
+//! # Rewinds
+//!
+//! Each export carries the cluster time at which it was created,
+//! `initial_cluster_time`, which was observed during purification and is the
+//! same for every replica. Replication starts just past it, so the change
+//! stream reports the changes in `(initial_cluster_time, snapshot_time]` that
+//! the snapshot already contains. To ensure that all replicas emit the same
+//! updates at every time, these changes are emitted at their cluster time and
+//! also retracted at [`ClusterTime::minimum`], which makes the snapshot look
+//! like it was taken at `initial_cluster_time`. The data capability is held
+//! at [`ClusterTime::minimum`] until the stream moves past `snapshot_time`.
+//!
+//! # Frontier
+//!
+//! The change stream of the database is opened at the smallest resume upper of
+//! the exports, and each export ignores the changes before its own resume
+//! upper. Whenever a batch of changes has been emitted, the post batch resume
+//! token tells us how far the stream has read: if it's the token of the last
+//! change, later changes of the same transaction may still follow so the
+//! frontier is its cluster time, otherwise every change at or before its
+//! cluster time has been seen and the frontier is just past it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use differential_dataflow::AsCollection;
+use futures::StreamExt;
+use mongodb::bson::{Bson, Document};
+use mongodb::change_stream::event::{OperationType, ResumeToken};
+use mongodb::error::ErrorKind;
+use mongodb::options::{FullDocumentBeforeChangeType, FullDocumentType};
+use mz_mongodb_util::inspect::{
+    ensure_change_stream_support, ensure_pre_and_post_images, get_current_cluster_time,
+};
+use mz_mongodb_util::snapshot::snapshot;
+use mz_mongodb_util::{ClusterTime, pack_document};
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_repr::{Diff, GlobalId, Row};
+use mz_storage_types::errors::{DataflowError, DecodeError, DecodeErrorKind};
+use mz_storage_types::sources::MongoDbSourceConnection;
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::operators::{CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::metrics::source::mongodb::MongoDbSourceMetrics;
+use crate::source::RawSourceCreationConfig;
+use crate::source::mongodb::{DefiniteError, ReplicationError, SourceOutputInfo, TransientError};
+use crate::source::types::{FuelSize, SignaledFuture, SourceMessage, StackedCollection};
+
+/// Used as a partition ID to determine the worker that is responsible for
+/// reading data from MongoDB.
+static REPL_READER: &str = "reader";
+
+/// How often the progress of a snapshot is reported to the source statistics.
+const SNAPSHOT_PROGRESS_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The error code MongoDB reports when a change stream can't be resumed
+/// because the oplog no longer contains the resume point.
+const CHANGE_STREAM_HISTORY_LOST: i32 = 286;
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, ClusterTime>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: MongoDbSourceConnection,
+    metrics: MongoDbSourceMetrics,
+) -> (
+    StackedCollection<'scope, ClusterTime, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, ClusterTime, ReplicationError>,
+    PressOnDropButton,
+) {
+    let op_name = format!("MongoDbReplicationReader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+
+    // Captures DefiniteErrors that affect the entire source, including all outputs
+    let (definite_error_handle, definite_errors) =
+        builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set, definite_error_cap_set]: &mut [_; 2] = caps.try_into().unwrap();
+
+            // Maps each collection to the output indexes of its exports.
+            let mut collections: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+            // Maps each collection to the output indexes and initial cluster
+            // times of the exports that this worker will snapshot.
+            let mut collections_to_snapshot: BTreeMap<String, Vec<(u64, ClusterTime)>> =
+                BTreeMap::new();
+            // The resume upper of each output index.
+            let mut resume_uppers: BTreeMap<u64, Antichain<ClusterTime>> = BTreeMap::new();
+            // Export statistics for a given collection.
+            let mut export_statistics: BTreeMap<String, Vec<_>> = BTreeMap::new();
+
+            for (export_id, output) in outputs.iter() {
+                collections
+                    .entry(output.collection.clone())
+                    .or_default()
+                    .push(output.partition_index);
+                resume_uppers.insert(output.partition_index, output.resume_upper.clone());
+
+                if *output.resume_upper == [ClusterTime::minimum()] {
+                    collections_to_snapshot
+                        .entry(output.collection.clone())
+                        .or_default()
+                        .push((output.partition_index, output.initial_cluster_time));
+                }
+                export_statistics
+                    .entry(output.collection.clone())
+                    .or_default()
+                    .push(
+                        config
+                            .statistics
+                            .get(export_id)
+                            .expect("statistics have been intialized")
+                            .clone(),
+                    );
+            }
+
+            // A worker *must* emit a count even if not responsible for snapshotting a
+            // collection as statistic summarization will return null if any worker hasn't set
+            // a value. This will also reset snapshot stats for any exports not snapshotting.
+            if !collections_to_snapshot.is_empty() {
+                for stats in config.statistics.values() {
+                    stats.set_snapshot_records_known(0);
+                    stats.set_snapshot_records_staged(0);
+                }
+            }
+            if !config.responsible_for(REPL_READER) {
+                return Ok::<_, TransientError>(());
+            }
+            metrics.collections.set(u64::cast_from(collections.len()));
+
+            let connection_config = source
+                .connection
+                .config(
+                    &config.config.connection_context.secrets_reader,
+                    &config.config,
+                    InTask::Yes,
+                )
+                .await?;
+            let client = connection_config.connect().await?;
+            let db = client.database(&source.database);
+
+            // The deployment, or the collections, may have been reconfigured
+            // since the source was created.
+            ensure_change_stream_support(&client).await?;
+            let collection_names: Vec<_> = collections.keys().map(String::as_str).collect();
+            ensure_pre_and_post_images(&db, &collection_names).await?;
+
+            // Snapshot every collection that requires it, all at the same cluster time.
+            let snapshot_time = get_current_cluster_time(&client).await?;
+            for (collection, partition_indexes) in &collections_to_snapshot {
+                let stats = export_statistics.get(collection).unwrap();
+                let estimate = db
+                    .collection::<Document>(collection)
+                    .estimated_document_count()
+                    .await?;
+                for export_stat in stats {
+                    export_stat.set_snapshot_records_known(estimate);
+                    export_stat.set_snapshot_records_staged(0);
+                }
+
+                tracing::info!(
+                    %config.id,
+                    %collection,
+                    %snapshot_time,
+                    "timely-{} snapshot start",
+                    config.worker_id,
+                );
+
+                let snapshot = snapshot(db.clone(), collection.clone(), snapshot_time);
+                let mut snapshot = std::pin::pin!(snapshot);
+                let mut last_report = Instant::now();
+                let mut snapshot_staged = 0;
+                while let Some(document) = snapshot.next().await {
+                    let message = decode(Some(document?));
+                    for (partition_idx, _) in partition_indexes {
+                        let update = (
+                            (*partition_idx, message.clone()),
+                            ClusterTime::minimum(),
+                            Diff::ONE,
+                        );
+                        let size = update.fuel_size();
+                        data_output
+                            .give_fueled(&data_cap_set[0], update, size)
+                            .await;
+                    }
+                    snapshot_staged += 1;
+
+                    if last_report.elapsed() > SNAPSHOT_PROGRESS_REPORT_INTERVAL {
+                        last_report = Instant::now();
+                        for export_stat in stats {
+                            export_stat.set_snapshot_records_staged(snapshot_staged);
+                        }
+                    }
+                }
+
+                tracing::info!(
+                    %config.id,
+                    %collection,
+                    %snapshot_time,
+                    "timely-{} snapshot complete",
+                    config.worker_id,
+                );
+                // The estimate may be off, so the staged count is the total.
+                for export_stat in stats {
+                    export_stat.set_snapshot_records_staged(snapshot_staged);
+                    export_stat.set_snapshot_records_known(snapshot_staged);
+                }
+            }
+
+            // Maps the output index of every snapshotted export to its
+            // initial cluster time and the snapshot time, see the module
+            // documentation.
+            let mut rewinds: BTreeMap<u64, (ClusterTime, ClusterTime)> = collections_to_snapshot
+                .values()
+                .flatten()
+                .map(|(idx, initial_cluster_time)| (*idx, (*initial_cluster_time, snapshot_time)))
+                .collect();
+            // The initial cluster time was observed before the snapshot time,
+            // if it's not smaller the deployment appeared to go back in time.
+            for (initial_cluster_time, snapshot_time) in rewinds.values() {
+                assert!(
+                    initial_cluster_time <= snapshot_time,
+                    "initial_cluster_time={initial_cluster_time} snapshot_time={snapshot_time}"
+                );
+            }
+            tracing::debug!("rewinds to process: {rewinds:?}");
+
+            // Resume from the smallest upper of all exports. Snapshotted
+            // exports have seen everything up to their initial cluster time.
+            let resume_time = outputs
+                .values()
+                .map(|output| match output.resume_upper.as_option() {
+                    Some(time) if *time != ClusterTime::minimum() => *time,
+                    Some(_) => output.initial_cluster_time.increment(),
+                    None => panic!("resume_upper has at least one value"),
+                })
+                .min();
+            let Some(resume_time) = resume_time else {
+                // There are no exports to replicate to.
+                return Ok(());
+            };
+
+            tracing::info!(
+                %config.id,
+                %resume_time,
+                "timely-{} replication starting",
+                config.worker_id,
+            );
+            let stream = db
+                .watch()
+                .full_document(FullDocumentType::Required)
+                .full_document_before_change(FullDocumentBeforeChangeType::Required)
+                .start_at_operation_time(resume_time.into())
+                .max_await_time(config.timestamp_interval)
+                .await;
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(err) if is_history_lost(&err) => {
+                    return_definite_error(
+                        DefiniteError::HistoryLost(resume_time),
+                        collections.values().flatten().copied(),
+                        data_output,
+                        data_cap_set,
+                        definite_error_handle,
+                        definite_error_cap_set,
+                    )
+                    .await;
+                    return Ok(());
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let mut upper = resume_time;
+            let mut last_event_token: Option<ResumeToken> = None;
+            let mut errored_partitions = BTreeSet::new();
+
+            loop {
+                let event = match stream.next_if_any().await {
+                    Ok(event) => event,
+                    Err(err) if is_history_lost(&err) => {
+                        return_definite_error(
+                            DefiniteError::HistoryLost(upper),
+                            collections.values().flatten().copied(),
+                            data_output,
+                            data_cap_set,
+                            definite_error_handle,
+                            definite_error_cap_set,
+                        )
+                        .await;
+                        return Ok(());
+                    }
+                    Err(err) => return Err(err.into()),
+                };
+
+                let Some(event) = event else {
+                    // We've received all of the changes of the batch, so
+                    // downgrade our capability.
+                    let Some(token) = stream.resume_token() else {
+                        continue;
+                    };
+                    let Some(token_time) = ClusterTime::from_resume_token(&token) else {
+                        return Err(TransientError::Generic(anyhow::anyhow!(
+                            "unable to read the cluster time of resume token {token:?}"
+                        )));
+                    };
+                    let frontier = if last_event_token.as_ref() == Some(&token) {
+                        token_time
+                    } else {
+                        token_time.increment()
+                    };
+                    if frontier <= upper {
+                        continue;
+                    }
+                    upper = frontier;
+                    tracing::debug!(?config.id, %upper, "got a closed cluster time");
+
+                    // cannot downgrade capability until rewinds have been processed,
+                    // we must be able to produce data at the minimum offset.
+                    rewinds.retain(|_, (_, snapshot_time)| upper <= *snapshot_time);
+                    if rewinds.is_empty() {
+                        data_cap_set.downgrade(Antichain::from_elem(upper));
+                    } else {
+                        tracing::debug!("rewinds remaining: {:?}", rewinds);
+                    }
+                    continue;
+                };
+
+                metrics.total.inc();
+                last_event_token = Some(event.id.clone());
+                let Some(time) = event
+                    .cluster_time
+                    .map(ClusterTime::from)
+                    .or_else(|| ClusterTime::from_resume_token(&event.id))
+                else {
+                    return Err(TransientError::Generic(anyhow::anyhow!(
+                        "change event without a cluster time: {event:?}"
+                    )));
+                };
+
+                match event.operation_type {
+                    OperationType::DropDatabase => {
+                        return_definite_error(
+                            DefiniteError::DatabaseDropped(source.database.clone()),
+                            collections.values().flatten().copied(),
+                            data_output,
+                            data_cap_set,
+                            definite_error_handle,
+                            definite_error_cap_set,
+                        )
+                        .await;
+                        return Ok(());
+                    }
+                    OperationType::Invalidate => {
+                        return_definite_error(
+                            DefiniteError::StreamInvalidated,
+                            collections.values().flatten().copied(),
+                            data_output,
+                            data_cap_set,
+                            definite_error_handle,
+                            definite_error_cap_set,
+                        )
+                        .await;
+                        return Ok(());
+                    }
+                    _ => {}
+                }
+
+                let Some((collection, partition_indexes)) = event
+                    .ns
+                    .as_ref()
+                    .and_then(|ns| ns.coll.as_ref())
+                    .and_then(|coll| collections.get_key_value(coll))
+                else {
+                    metrics.ignored.inc();
+                    continue;
+                };
+
+                // The exports of the collection that need this change, along
+                // with whether the change must be rewound for them.
+                let partitions: Vec<_> = partition_indexes
+                    .iter()
+                    .filter(|idx| !errored_partitions.contains(*idx))
+                    .filter(|idx| resume_uppers[*idx].less_equal(&time))
+                    .filter_map(|idx| match rewinds.get(idx) {
+                        Some((initial_cluster_time, _)) if time <= *initial_cluster_time => None,
+                        Some((_, snapshot_time)) => Some((*idx, time <= *snapshot_time)),
+                        None => Some((*idx, false)),
+                    })
+                    .collect();
+
+                let updates = match event.operation_type {
+                    OperationType::Insert => {
+                        metrics.inserts.inc();
+                        vec![(decode(event.full_document), Diff::ONE)]
+                    }
+                    OperationType::Update | OperationType::Replace => {
+                        metrics.updates.inc();
+                        vec![
+                            (decode(event.full_document_before_change), Diff::MINUS_ONE),
+                            (decode(event.full_document), Diff::ONE),
+                        ]
+                    }
+                    OperationType::Delete => {
+                        metrics.deletes.inc();
+                        vec![(decode(event.full_document_before_change), Diff::MINUS_ONE)]
+                    }
+                    OperationType::Drop | OperationType::Rename => {
+                        let error = match event.to.and_then(|to| to.coll) {
+                            Some(to) => DefiniteError::CollectionRenamed(collection.clone(), to),
+                            None => DefiniteError::CollectionDropped(collection.clone()),
+                        };
+                        for (partition_idx, _) in partitions {
+                            let update =
+                                ((partition_idx, Err(error.clone().into())), time, Diff::ONE);
+                            let size = update.fuel_size();
+                            data_output
+                                .give_fueled(&data_cap_set[0], update, size)
+                                .await;
+                            errored_partitions.insert(partition_idx);
+                        }
+                        continue;
+                    }
+                    _ => {
+                        metrics.ignored.inc();
+                        continue;
+                    }
+                };
+
+                for (partition_idx, rewind) in partitions {
+                    for (message, diff) in &updates {
+                        if rewind {
+                            let update = (
+                                (partition_idx, message.clone()),
+                                ClusterTime::minimum(),
+                                -*diff,
+                            );
+                            let size = update.fuel_size();
+                            data_output
+                                .give_fueled(&data_cap_set[0], update, size)
+                                .await;
+                        }
+                        let update = ((partition_idx, message.clone()), time, *diff);
+                        let size = update.fuel_size();
+                        data_output
+                            .give_fueled(&data_cap_set[0], update, size)
+                            .await;
+                    }
+                }
+            }
+        }))
+    });
+
+    let error_stream = definite_errors.concat(transient_errors.map(ReplicationError::Transient));
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+/// Returns whether `err` reports that the change stream can no longer be
+/// resumed from the requested position.
+fn is_history_lost(err: &mongodb::error::Error) -> bool {
+    matches!(&*err.kind, ErrorKind::Command(err) if err.code == CHANGE_STREAM_HISTORY_LOST)
+}
+
+/// Helper method to decode a document of a snapshot or change event into a
+/// [`SourceMessage`].
+fn decode(document: Option<Document>) -> Result<SourceMessage, DataflowError> {
+    let Some(document) = document else {
+        let kind = DecodeErrorKind::Text(
+            "change event is missing the pre- or post-image of the document".into(),
+        );
+        return Err(DataflowError::DecodeError(Box::new(DecodeError {
+            kind,
+            raw: vec![],
+        })));
+    };
+    let mut row = Row::default();
+    match pack_document(&mut row, document.clone()) {
+        Ok(()) => Ok(SourceMessage {
+            key: Row::default(),
+            value: row,
+            metadata: Row::default(),
+        }),
+        Err(e) => {
+            let kind = DecodeErrorKind::Text(e.to_string().into());
+            let raw = Bson::Document(document).into_relaxed_extjson().to_string();
+            Err(DataflowError::DecodeError(Box::new(DecodeError {
+                kind,
+                raw: raw.into_bytes(),
+            })))
+        }
+    }
+}
+
+/// Helper method to return a "definite" error upstream.
+async fn return_definite_error(
+    err: DefiniteError,
+    outputs: impl Iterator<Item = u64>,
+    data_handle: StackedAsyncOutputHandle<ClusterTime, (u64, Result<SourceMessage, DataflowError>)>,
+    data_capset: &CapabilitySet<ClusterTime>,
+    errs_handle: AsyncOutputHandle<ClusterTime, CapacityContainerBuilder<Vec<ReplicationError>>>,
+    errs_capset: &CapabilitySet<ClusterTime>,
+) {
+    for output_idx in outputs {
+        let update = (
+            (output_idx, Err(err.clone().into())),
+            // Select a cluster time that should not conflict with a previously
+            // observed one. Ideally we could identify the cluster time that
+            // resulted in the definite error so that all replicas would emit
+            // the same updates for the same times.
+            ClusterTime {
+                time: u32::MAX,
+                increment: u32::MAX,
+            },
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_handle.give_fueled(&data_capset[0], update, size).await;
+    }
+    errs_handle.give(
+        &errs_capset[0],
+        ReplicationError::DefiniteError(Rc::new(err)),
+    );
+}
@@ -0,0 +1,210 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of an [`MqttSourceConnection`].
+//!
+//! An MQTT source subscribes to a topic filter of an MQTT 5 broker with QoS 1,
+//! optionally through a shared subscription group. The topic of a message is
+//! its key and the payload of the message is its value, which are decoded with
+//! the formats and envelopes that Kafka sources support.
+//!
+//! # Timestamps
+//!
+//! MQTT brokers don't assign offsets to messages, so the source is
+//! timestamped with an [`MzOffset`] that counts the messages the source has
+//! ingested.
+//!
+//! # Acknowledgements
+//!
+//! The source keeps its session on the broker across connections, and only
+//! acknowledges a message once it has been committed. The broker delivers the
+//! messages that haven't been acknowledged again when the source reconnects,
+//! so the source ingests every message at least once. Messages that are
+//! delivered again are counted as new messages.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use differential_dataflow::AsCollection;
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId};
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::mqtt::MqttMetadataKind;
+use mz_storage_types::sources::{
+    MqttSourceConnection, MzOffset, SourceExport, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::PressOnDropButton;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Concat;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::RawSourceCreationConfig;
+use crate::source::types::{Probe, SourceMessage, SourceRender, StackedCollection};
+
+mod reader;
+
+#[derive(Debug, Clone)]
+struct SourceOutputInfo {
+    /// Upper to resume ingestion from.
+    resume_upper: Antichain<MzOffset>,
+    /// An index to split the timely stream.
+    partition_index: u64,
+    /// The metadata that is included with every message of this output.
+    metadata_columns: Vec<MqttMetadataKind>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReplicationError {
+    #[error(transparent)]
+    Transient(#[from] Rc<TransientError>),
+    #[error(transparent)]
+    DefiniteError(#[from] Rc<DefiniteError>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransientError {
+    #[error("mqtt error: {0}")]
+    Mqtt(String),
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("broker rejected the subscription to {filter}: {reason}")]
+    SubscriptionRejected { filter: String, reason: String },
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+impl SourceRender for MqttSourceConnection {
+    type Time = MzOffset;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::Mqtt;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, MzOffset>,
+        config: &RawSourceCreationConfig,
+        resume_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        // Timely Collection for each Source Export defined in the provided `config`.
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, MzOffset, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, MzOffset, HealthStatusMessage>,
+        StreamVec<'scope, MzOffset, Probe<MzOffset>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // Collect the source outputs that we will be exporting.
+        let mut source_outputs = BTreeMap::new();
+        for (idx, (id, export)) in config.source_exports.iter().enumerate() {
+            let SourceExport {
+                details,
+                storage_metadata: _,
+                data_config: _,
+            } = export;
+
+            let metadata_columns = match details {
+                SourceExportDetails::Mqtt(details) => details
+                    .metadata_columns
+                    .iter()
+                    .map(|(_name, kind)| kind.clone())
+                    .collect(),
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            };
+
+            let resume_upper = config
+                .source_resume_uppers
+                .get(id)
+                .expect("missing resume upper")
+                .iter()
+                .map(MzOffset::decode_row);
+
+            let output_info = SourceOutputInfo {
+                resume_upper: Antichain::from_iter(resume_upper),
+                partition_index: u64::cast_from(idx),
+                metadata_columns,
+            };
+            source_outputs.insert(*id, output_info);
+        }
+
+        let metrics = config.metrics.get_mqtt_source_metrics(config.id);
+
+        let (repl_updates, repl_errs, probes, repl_token) = reader::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs,
+            self.clone(),
+            resume_uppers,
+            metrics,
+        );
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = repl_updates
+            .inner
+            .partition::<CapacityContainerBuilder<_>, _, _>(
+                partition_count,
+                move |((partition_idx, data), time, diff): (
+                    (u64, Result<SourceMessage, DataflowError>),
+                    MzOffset,
+                    Diff,
+                )| { (partition_idx, (data, time, diff)) },
+            );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = repl_errs.map(move |err| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            let update = HealthStatusUpdate::halting(err_string, None);
+
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update,
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (data_collections, health, probes, vec![repl_token])
+    }
+}
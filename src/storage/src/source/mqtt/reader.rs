@@ -0,0 +1,352 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the reader of an [`MqttSourceConnection`].
+//!
+//! A single worker connects to the broker, with a client identifier that is
+//! derived from the ID of the source so that the broker keeps the session of
+//! the source across connections. The event loop of the client runs in its own
+//! task, which forwards the events to the operator, so that the operator can
+//! acknowledge messages without blocking the event loop.
+//!
+//! Any error of the connection halts the source, as the packet identifiers of
+//! the messages that haven't been acknowledged are only valid for the
+//! connection that received them. The broker delivers those messages again on
+//! the next connection.
+//!
+//! The broker doesn't expose how many messages it holds for the session, so the
+//! operator probes its own frontier as the upstream frontier.
+//!
+//! [`MqttSourceConnection`]: mz_storage_types::sources::MqttSourceConnection
+
+use std::collections::{BTreeMap, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_ore::task::AbortOnDropHandle;
+use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::mqtt::MqttMetadataKind;
+use mz_storage_types::sources::{MqttSourceConnection, MzOffset};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use rumqttc::v5::mqttbytes::QoS;
+use rumqttc::v5::mqttbytes::v5::{Packet, Publish, SubscribeReasonCode};
+use rumqttc::v5::{AsyncClient, ConnectionError, Event, EventLoop};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::operators::{CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp};
+use tokio::sync::mpsc;
+
+use crate::metrics::source::mqtt::MqttSourceMetrics;
+use crate::source::mqtt::{DefiniteError, ReplicationError, SourceOutputInfo, TransientError};
+use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceMessage, StackedCollection};
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// How long, in seconds, the broker keeps the session of the source after the source
+/// disconnects.
+const SESSION_EXPIRY_INTERVAL: u32 = 24 * 60 * 60;
+
+/// The capacity of the channel of requests to the event loop.
+const REQUEST_CHANNEL_CAPACITY: usize = 1024;
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, MzOffset>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: MqttSourceConnection,
+    committed_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
+    metrics: MqttSourceMetrics,
+) -> (
+    StackedCollection<'scope, MzOffset, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, MzOffset, ReplicationError>,
+    StreamVec<'scope, MzOffset, Probe<MzOffset>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("MqttReader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    // Captures DefiniteErrors that affect the entire source, including all outputs
+    let (definite_error_handle, definite_errors) =
+        builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set, probe_cap, definite_error_cap_set]: &mut [_; 3] =
+                caps.try_into().unwrap();
+
+            // Only a single worker subscribes, as the broker only allows a single connection
+            // per session.
+            if !config.responsible_for(&source.topic) {
+                // Emit 0, to mark this worker as having started up correctly.
+                for stat in config.statistics.values() {
+                    stat.set_offset_known(0);
+                    stat.set_offset_committed(0);
+                }
+                return Ok(());
+            }
+
+            let outputs: Vec<_> = outputs
+                .into_values()
+                .filter(|output| !output.resume_upper.is_empty())
+                .collect();
+            // The broker can't replay messages, so the count continues from the latest position
+            // of any output.
+            let Some(mut next_offset) = outputs
+                .iter()
+                .flat_map(|output| output.resume_upper.iter().copied())
+                .max()
+            else {
+                return Ok(());
+            };
+            data_cap_set.downgrade([&next_offset]);
+
+            let client_id = source.client_id(&config.config.connection_context, config.id);
+            let mut options = source
+                .connection
+                .options(
+                    client_id,
+                    &config.config.connection_context.secrets_reader,
+                    &config.config,
+                    InTask::Yes,
+                )
+                .await?;
+            options
+                .set_clean_start(false)
+                .set_session_expiry_interval(Some(SESSION_EXPIRY_INTERVAL))
+                .set_manual_acks(true)
+                .set_receive_maximum(Some(u16::MAX));
+            let (client, eventloop) = AsyncClient::new(options, REQUEST_CHANNEL_CAPACITY);
+            let (events_tx, mut events) = mpsc::unbounded_channel();
+            let _eventloop_handle = spawn_eventloop(config.id, eventloop, events_tx);
+
+            let filter = source.subscription_filter();
+            client
+                .subscribe(filter.clone(), QoS::AtLeastOnce)
+                .await
+                .map_err(|e| TransientError::Mqtt(e.to_string()))?;
+
+            // The messages that have been ingested but not acknowledged, by offset. Only the
+            // packet identifier and QoS of a message are needed to acknowledge it, so their
+            // payloads are dropped.
+            let mut unacked: VecDeque<(MzOffset, Publish)> = VecDeque::new();
+            let mut committed_uppers = std::pin::pin!(committed_uppers);
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker =
+                probe::Ticker::new(move || timestamp_interval, config.now_fn.clone());
+
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        for stat in config.statistics.values() {
+                            stat.set_offset_known(next_offset.offset);
+                        }
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: Antichain::from_elem(next_offset),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                    },
+                    Some(upper) = committed_uppers.next() => {
+                        if let Some(upper) = upper.into_option() {
+                            while let Some((offset, _)) = unacked.front()
+                                && *offset < upper
+                            {
+                                let (_, publish) = unacked.pop_front().expect("known to exist");
+                                client
+                                    .ack(&publish)
+                                    .await
+                                    .map_err(|e| TransientError::Mqtt(e.to_string()))?;
+                                metrics.acks.inc();
+                            }
+                            metrics.unacked_messages.set(u64::cast_from(unacked.len()));
+                            for stat in config.statistics.values() {
+                                stat.set_offset_committed(upper.offset);
+                            }
+                        }
+                    },
+                    event = events.recv() => {
+                        let event = event
+                            .ok_or_else(|| TransientError::Mqtt("event loop ended".into()))?
+                            .map_err(|e| TransientError::Mqtt(e.to_string()))?;
+                        match event {
+                            Event::Incoming(Packet::Publish(mut publish)) => {
+                                let time = next_offset;
+                                let cap = data_cap_set.delayed(&time);
+                                for output in &outputs {
+                                    let message = construct_source_message(
+                                        &publish,
+                                        &output.metadata_columns,
+                                    );
+                                    let update =
+                                        ((output.partition_index, Ok(message)), time, Diff::ONE);
+                                    let size = update.fuel_size();
+                                    data_output.give_fueled(&cap, update, size).await;
+                                }
+                                metrics.messages.inc();
+                                next_offset = MzOffset::from(next_offset.offset + 1);
+                                data_cap_set.downgrade([&next_offset]);
+
+                                publish.payload = Default::default();
+                                publish.properties = None;
+                                unacked.push_back((time, publish));
+                                metrics.unacked_messages.set(u64::cast_from(unacked.len()));
+                            }
+                            Event::Incoming(Packet::SubAck(suback)) => {
+                                let rejected = suback
+                                    .return_codes
+                                    .iter()
+                                    .find(|code| !matches!(code, SubscribeReasonCode::Success(_)));
+                                match rejected {
+                                    None => {}
+                                    // The broker won't accept the subscription until the source
+                                    // is recreated.
+                                    Some(
+                                        code @ (SubscribeReasonCode::TopicFilterInvalid
+                                        | SubscribeReasonCode::SharedSubscriptionsNotSupported
+                                        | SubscribeReasonCode::WildcardSubscriptionsNotSupported),
+                                    ) => {
+                                        let err = DefiniteError::SubscriptionRejected {
+                                            filter: filter.clone(),
+                                            reason: format!("{code:?}"),
+                                        };
+                                        return_definite_error(
+                                            err,
+                                            outputs.iter(),
+                                            data_output,
+                                            data_cap_set,
+                                            definite_error_handle,
+                                            definite_error_cap_set,
+                                        )
+                                        .await;
+                                        return Ok(());
+                                    }
+                                    Some(code) => {
+                                        let err = format!(
+                                            "broker rejected the subscription to {filter}: \
+                                             {code:?}"
+                                        );
+                                        return Err(TransientError::Mqtt(err));
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    },
+                };
+            }
+        }))
+    });
+
+    let error_stream = definite_errors.concat(transient_errors.map(ReplicationError::Transient));
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        probe_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+type DataOutput = StackedAsyncOutputHandle<MzOffset, (u64, Result<SourceMessage, DataflowError>)>;
+
+/// Polls `eventloop` in its own task and forwards its events to `events`, until the connection
+/// fails or the operator goes away.
+fn spawn_eventloop(
+    id: GlobalId,
+    mut eventloop: EventLoop,
+    events: mpsc::UnboundedSender<Result<Event, ConnectionError>>,
+) -> AbortOnDropHandle<()> {
+    mz_ore::task::spawn(|| format!("mqtt_eventloop:{id}"), async move {
+        loop {
+            let event = eventloop.poll().await;
+            let failed = event.is_err();
+            if events.send(event).is_err() || failed {
+                break;
+            }
+        }
+    })
+    .abort_on_drop()
+}
+
+/// Constructs the source message of `publish`, with the metadata that `metadata_columns` asks
+/// for.
+fn construct_source_message(
+    publish: &Publish,
+    metadata_columns: &[MqttMetadataKind],
+) -> SourceMessage {
+    let mut metadata = Row::default();
+    let mut packer = metadata.packer();
+    for kind in metadata_columns {
+        match kind {
+            MqttMetadataKind::Headers => {
+                packer.push_list_with(|r| {
+                    let properties = publish.properties.iter();
+                    for (key, value) in properties.flat_map(|p| &p.user_properties) {
+                        r.push_list_with(|record_row| {
+                            record_row.push(Datum::String(key));
+                            record_row.push(Datum::Bytes(value.as_bytes()));
+                        });
+                    }
+                });
+            }
+        }
+    }
+
+    SourceMessage {
+        key: Row::pack([Datum::Bytes(&publish.topic)]),
+        value: Row::pack([Datum::Bytes(&publish.payload)]),
+        metadata,
+    }
+}
+
+/// Helper method to return a "definite" error upstream.
+async fn return_definite_error<'a>(
+    err: DefiniteError,
+    outputs: impl Iterator<Item = &'a SourceOutputInfo>,
+    data_handle: DataOutput,
+    data_capset: &CapabilitySet<MzOffset>,
+    errs_handle: AsyncOutputHandle<MzOffset, CapacityContainerBuilder<Vec<ReplicationError>>>,
+    errs_capset: &CapabilitySet<MzOffset>,
+) {
+    for output in outputs {
+        // The error is a property of the subscription itself, so it is emitted at the minimum
+        // time of the outputs that have not ingested anything yet, which is every output that
+        // could have observed the error.
+        if *output.resume_upper != [MzOffset::minimum()] {
+            continue;
+        }
+        let update = (
+            (output.partition_index, Err(err.clone().into())),
+            MzOffset::minimum(),
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_handle.give_fueled(&data_capset[0], update, size).await;
+    }
+    errs_handle.give(
+        &errs_capset[0],
+        ReplicationError::DefiniteError(Rc::new(err)),
+    );
+}
@@ -53,6 +53,7 @@
 use std::collections::BTreeMap;
 use std::fmt;
 use std::io;
+use std::num::NonZeroU64;
 use std::rc::Rc;
 
 use differential_dataflow::AsCollection;
@@ -61,6 +62,7 @@ use mz_mysql_util::quote_identifier;
 use mz_ore::cast::CastFrom;
 use mz_repr::Diff;
 use mz_repr::GlobalId;
+use mz_repr::{Datum, Row};
 use mz_storage_types::errors::{DataflowError, SourceError};
 use mz_storage_types::sources::SourceExport;
 use mz_timely_util::containers::stack::FueledBuilder;
@@ -79,8 +81,12 @@ use mz_mysql_util::{
 };
 use mz_ore::error::ErrorExt;
 use mz_storage_types::errors::SourceErrorDetails;
-use mz_storage_types::sources::mysql::{GtidPartition, GtidState, gtid_set_frontier};
-use mz_storage_types::sources::{MySqlSourceConnection, SourceExportDetails, SourceTimestamp};
+use mz_storage_types::sources::mysql::{
+    GtidPartition, GtidState, MySqlMetadataKind, gtid_set_frontier,
+};
+use mz_storage_types::sources::{
+    MySqlSourceConnection, SchemaChangePolicy, SourceExportDetails, SourceTimestamp,
+};
 use mz_timely_util::builder_async::{AsyncOutputHandle, PressOnDropButton};
 use mz_timely_util::order::Extrema;
 
@@ -152,6 +158,12 @@ impl SourceRender for MySqlSourceConnection {
                 resume_upper,
                 export_id: id.clone(),
                 binlog_full_metadata: details.binlog_full_metadata,
+                metadata_columns: details
+                    .metadata_columns
+                    .iter()
+                    .map(|(_, kind)| kind.clone())
+                    .collect(),
+                schema_change_policy: details.schema_change_policy,
             });
         }
 
@@ -259,6 +271,49 @@ struct SourceOutputInfo {
     resume_upper: Antichain<GtidPartition>,
     export_id: GlobalId,
     binlog_full_metadata: bool,
+    /// The transaction metadata columns requested by this output, in order.
+    metadata_columns: Vec<MySqlMetadataKind>,
+    schema_change_policy: SchemaChangePolicy,
+}
+
+/// Metadata about the upstream transaction that produced a replicated row, as reported by its
+/// `GTID` event.
+#[derive(Clone, Debug)]
+struct TransactionInfo {
+    source_id: Uuid,
+    tx_id: NonZeroU64,
+    /// The commit time of the transaction on the server we replicate from, in microseconds since
+    /// the Unix epoch. Servers older than MySQL 8.0 do not report it.
+    commit_time: Option<u64>,
+}
+
+/// Packs the requested metadata `kinds` of `tx` into `row`. Rows that were not replicated as
+/// part of a transaction, i.e. snapshot rows and their retractions, have all of their metadata
+/// columns set to null.
+fn pack_metadata(kinds: &[MySqlMetadataKind], tx: Option<&TransactionInfo>, row: &mut Row) {
+    let mut packer = row.packer();
+    for kind in kinds {
+        let Some(tx) = tx else {
+            packer.push(Datum::Null);
+            continue;
+        };
+        match kind {
+            MySqlMetadataKind::CommitTimestamp => {
+                let ts = tx
+                    .commit_time
+                    .and_then(|micros| i64::try_from(micros).ok())
+                    .and_then(chrono::DateTime::from_timestamp_micros)
+                    .and_then(|dt| dt.naive_utc().try_into().ok());
+                match ts {
+                    Some(ts) => packer.push(Datum::Timestamp(ts)),
+                    None => packer.push(Datum::Null),
+                }
+            }
+            MySqlMetadataKind::Gtid => {
+                packer.push(Datum::String(&format!("{}:{}", tx.source_id, tx.tx_id)))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -282,6 +337,8 @@ pub enum TransientError {
     SQLClient(#[from] mysql_async::Error),
     #[error("ident decode error")]
     IdentError(#[from] mz_sql_parser::ast::IdentError),
+    #[error("upstream table {0} has new columns that are not yet part of its source tables")]
+    PendingSchemaChange(String),
     #[error(transparent)]
     MySqlError(#[from] MySqlError),
     #[error(transparent)]
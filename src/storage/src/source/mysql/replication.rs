@@ -38,6 +38,14 @@
 //! of pending rewinds that we've received from the snapshot operator, and when we see updates
 //! for an output that were present in the snapshot, we negate the snapshot update
 //! (at the minimum timestamp) and send it again at the correct GTID.
+//!
+//! # Transaction metadata
+//!
+//! Outputs that include transaction metadata retract rows with the metadata of the transaction
+//! that inserted them, rather than of the one that removed them. The replication reader keeps the
+//! contents of such outputs, seeded from their persist shards, to know what that metadata was.
+//! Rows that the replication stream doesn't know about were inserted by the snapshot and are
+//! retracted with null metadata, like rewound updates.
 
 use std::collections::BTreeMap;
 use std::num::NonZeroU64;
@@ -76,11 +84,12 @@ use mz_timely_util::builder_async::{
 
 use crate::metrics::source::mysql::MySqlSourceMetrics;
 use crate::source::RawSourceCreationConfig;
+use crate::source::contents::OutputContents;
 use crate::source::types::{SignaledFuture, SourceMessage, StackedCollection};
 
 use super::{
-    DefiniteError, ReplicationError, RewindRequest, SourceOutputInfo, TransientError,
-    return_definite_error, validate_mysql_repl_settings,
+    DefiniteError, ReplicationError, RewindRequest, SourceOutputInfo, TransactionInfo,
+    TransientError, return_definite_error, validate_mysql_repl_settings,
 };
 
 mod context;
@@ -283,6 +292,16 @@ pub(crate) fn render<'scope>(
             }
             trace!(%id, "timely-{worker_id} pending rewinds {rewinds:?}");
 
+            let mut contents = BTreeMap::new();
+            for output in table_info.values().flatten() {
+                if !output.metadata_columns.is_empty() {
+                    let metadata_len = output.metadata_columns.len();
+                    let output_contents =
+                        OutputContents::hydrate(&config, output.export_id, metadata_len).await?;
+                    contents.insert(output.output_index, output_contents);
+                }
+            }
+
             // We don't use _conn_tunnel_handle here, but need to keep it around to ensure that the
             // SSH tunnel is not dropped until the replication stream is dropped.
             let (binlog_stream, _conn_tunnel_handle) =
@@ -320,9 +339,12 @@ pub(crate) fn render<'scope>(
                 &mut data_output,
                 data_cap_set,
                 rewinds,
+                contents,
             );
 
             let mut active_tx: Option<(Uuid, NonZeroU64)> = None;
+            // The commit time of the active transaction, if the server reports one.
+            let mut active_tx_commit_time: Option<u64> = None;
 
             let mut row_event_buffer = Vec::new();
 
@@ -384,6 +406,11 @@ pub(crate) fn render<'scope>(
                         }
                         // Store the information of the active transaction for the subsequent events
                         active_tx = Some((source_id, tx_id));
+                        // Servers that predate commit timestamps in the binlog report zero.
+                        active_tx_commit_time = match event.immediate_commit_timestamp() {
+                            0 => None,
+                            ts => Some(ts),
+                        };
                     }
                     Some(EventData::RowsEvent(data)) => {
                         let (source_id, tx_id) = active_tx
@@ -391,11 +418,17 @@ pub(crate) fn render<'scope>(
                             .expect("gtid cap should be set by previous GtidEvent");
                         let cur_gtid =
                             GtidPartition::new_singleton(source_id, GtidState::Active(tx_id));
+                        let tx_info = TransactionInfo {
+                            source_id,
+                            tx_id,
+                            commit_time: active_tx_commit_time,
+                        };
 
                         events::handle_rows_event(
                             data,
                             &mut repl_context,
                             &cur_gtid,
+                            &tx_info,
                             &mut row_event_buffer,
                         )
                         .await?;
@@ -21,6 +21,7 @@ use mz_storage_types::sources::mysql::{GtidPartition, GtidState};
 
 use crate::metrics::source::mysql::MySqlSourceMetrics;
 use crate::source::RawSourceCreationConfig;
+use crate::source::contents::OutputContents;
 use crate::source::mysql::{
     MySqlTableName, RewindRequest, SourceOutputInfo, StackedAsyncOutputHandle,
 };
@@ -42,6 +43,9 @@ pub(super) struct ReplContext<'a> {
     // Owned values:
     pub(super) rewinds: BTreeMap<usize, (Capability<GtidPartition>, RewindRequest)>,
     pub(super) errored_outputs: BTreeSet<usize>,
+    /// The contents of the outputs that include transaction metadata, whose retractions carry the
+    /// metadata of the rows they retract.
+    pub(super) contents: BTreeMap<usize, OutputContents>,
 }
 
 impl<'a> ReplContext<'a> {
@@ -57,6 +61,7 @@ impl<'a> ReplContext<'a> {
         >,
         data_cap_set: &'a mut CapabilitySet<GtidPartition>,
         rewinds: BTreeMap<usize, (Capability<GtidPartition>, RewindRequest)>,
+        contents: BTreeMap<usize, OutputContents>,
     ) -> Self {
         Self {
             config,
@@ -68,6 +73,7 @@ impl<'a> ReplContext<'a> {
             data_cap_set,
             rewinds,
             errored_outputs: BTreeSet::new(),
+            contents,
         }
     }
 
@@ -27,8 +27,8 @@ use tracing::trace;
 use crate::source::mysql::SourceOutputInfo;
 use crate::source::types::{FuelSize, SourceMessage};
 
-use super::super::schemas::verify_schemas;
-use super::super::{DefiniteError, MySqlTableName, TransientError};
+use super::super::schemas::{has_pending_added_columns, verify_schemas};
+use super::super::{DefiniteError, MySqlTableName, TransactionInfo, TransientError, pack_metadata};
 use super::context::ReplContext;
 
 const DIALECT: MySqlDialect = MySqlDialect {};
@@ -139,6 +139,12 @@ pub(super) async fn handle_query_event(
                         &ctx.config.config.connection_context.ssh_tunnel_manager,
                     )
                     .await?;
+                // Changes made after columns were added must not be ingested by outputs that
+                // follow upstream column additions until they have been altered to include the
+                // new columns, so we retry from before this event until that has happened.
+                if has_pending_added_columns(&mut *conn, &table, info).await? {
+                    return Err(TransientError::PendingSchemaChange(table.to_string()));
+                }
                 for (err_output, err) in
                     verify_schemas(&mut *conn, btreemap! { &table => info }).await?
                 {
@@ -311,6 +317,7 @@ pub(super) async fn handle_rows_event(
     event: RowsEventData<'_>,
     ctx: &mut ReplContext<'_>,
     new_gtid: &GtidPartition,
+    tx_info: &TransactionInfo,
     event_buffer: &mut Vec<(
         (usize, Result<SourceMessage, DataflowError>),
         GtidPartition,
@@ -359,6 +366,7 @@ pub(super) async fn handle_rows_event(
     // to accomodate for updates and deletes (which include a before_row),
     // and updates and inserts (which inclued an after row).
     let mut final_row = Row::default();
+    let mut metadata_row = Row::default();
     let mut rows_iter = event.rows(table_map_event);
     let mut rewind_count = 0;
     let mut additions = 0;
@@ -386,6 +394,11 @@ pub(super) async fn handle_rows_event(
         for (binlog_row, diff) in updates.into_iter().flatten() {
             let row = mysql_async::Row::try_from(binlog_row)?;
             for (output, row_val) in outputs.iter().repeat_clone(row) {
+                // Updates that were already present in the snapshot are rewound.
+                let rewinding = ctx
+                    .rewinds
+                    .get(&output.output_index)
+                    .is_some_and(|(_, req)| !req.snapshot_upper.less_equal(new_gtid));
                 let event = if !has_full_metadata && output.binlog_full_metadata {
                     ctx.errored_outputs.insert(output.output_index);
                     Err(DataflowError::from(DefiniteError::ValueDecodeError(
@@ -402,11 +415,29 @@ pub(super) async fn handle_rows_event(
                         Some(&gtid_str),
                         output.binlog_full_metadata,
                     ) {
-                        Ok(row) => Ok(SourceMessage {
-                            key: Row::default(),
-                            value: row,
-                            metadata: Row::default(),
-                        }),
+                        Ok(row) => {
+                            // An update that is rewound is reflected in the snapshot, whose rows
+                            // carry no transaction metadata, so it must not carry any either.
+                            let tx_info = if rewinding { None } else { Some(tx_info) };
+                            pack_metadata(&output.metadata_columns, tx_info, &mut metadata_row);
+                            // Updates before the resumption point of the output are already
+                            // reflected in its contents.
+                            let contents = ctx
+                                .contents
+                                .get_mut(&output.output_index)
+                                .filter(|_| !rewinding && output.resume_upper.less_equal(new_gtid));
+                            if let Some(contents) = contents {
+                                if diff.is_negative() {
+                                    contents.retraction_metadata(&row, &mut metadata_row);
+                                }
+                                contents.update(&row, &metadata_row, diff);
+                            }
+                            Ok(SourceMessage {
+                                key: Row::default(),
+                                value: row,
+                                metadata: metadata_row.clone(),
+                            })
+                        }
                         // Produce a DefiniteError in the stream for any rows that fail to decode
                         Err(err @ MySqlError::ValueDecodeError { .. }) => Err(DataflowError::from(
                             DefiniteError::ValueDecodeError(err.to_string()),
@@ -418,12 +449,9 @@ pub(super) async fn handle_rows_event(
                 let data = (output.output_index, event);
 
                 // Rewind this update if it was already present in the snapshot
-                if let Some((_rewind_data_cap, rewind_req)) = ctx.rewinds.get(&output.output_index)
-                {
-                    if !rewind_req.snapshot_upper.less_equal(new_gtid) {
-                        rewind_count += 1;
-                        event_buffer.push((data.clone(), GtidPartition::minimum(), -diff));
-                    }
+                if rewinding {
+                    rewind_count += 1;
+                    event_buffer.push((data.clone(), GtidPartition::minimum(), -diff));
                 }
                 if diff.is_positive() {
                     additions += 1;
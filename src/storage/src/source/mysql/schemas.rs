@@ -12,6 +12,8 @@ use std::collections::{BTreeMap, BTreeSet};
 use mysql_async::prelude::Queryable;
 use mz_mysql_util::{MySqlError, SchemaRequest, schema_info};
 
+use mz_storage_types::sources::SchemaChangePolicy;
+
 use super::{DefiniteError, MySqlTableName, SourceOutputInfo};
 
 /// Given a map of tables and the expected schemas of their outputs, retrieve the current
@@ -85,3 +87,52 @@ where
         })
         .collect())
 }
+
+/// Given a table and the outputs it feeds, returns whether the current schema of the table has
+/// columns that one of its outputs created with `SCHEMA CHANGE POLICY = 'add_columns'` doesn't
+/// know about yet.
+///
+/// Schemas that can't be described are left to [`verify_schemas`] to report.
+pub(super) async fn has_pending_added_columns<Q>(
+    conn: &mut Q,
+    table: &MySqlTableName,
+    outputs: &[SourceOutputInfo],
+) -> Result<bool, MySqlError>
+where
+    Q: Queryable,
+{
+    let outputs: Vec<_> = outputs
+        .iter()
+        .filter(|output| output.schema_change_policy == SchemaChangePolicy::AddColumns)
+        .collect();
+    if outputs.is_empty() {
+        return Ok(false);
+    }
+
+    let schemas = schema_info(
+        conn,
+        &SchemaRequest::Tables(vec![(table.0.as_str(), table.1.as_str())]),
+    )
+    .await?;
+    let Some(schema) = schemas.into_iter().next() else {
+        return Ok(false);
+    };
+
+    Ok(outputs.into_iter().any(|output| {
+        let desc = schema.clone().to_desc(
+            Some(&BTreeSet::from_iter(
+                output.text_columns.iter().map(|s| s.as_str()),
+            )),
+            Some(&BTreeSet::from_iter(
+                output.exclude_columns.iter().map(|s| s.as_str()),
+            )),
+        );
+        match desc {
+            Ok(desc) => desc
+                .columns
+                .iter()
+                .any(|col| !output.desc.columns.iter().any(|c| c.name == col.name)),
+            Err(_) => false,
+        }
+    }))
+}
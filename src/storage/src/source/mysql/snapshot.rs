@@ -392,6 +392,7 @@ pub(crate) fn render<'scope>(
 
                 // Read the snapshot data from the tables
                 let mut final_row = Row::default();
+                let mut metadata_row = Row::default();
 
                 let mut snapshot_staged_total = 0;
                 for (table, outputs) in &reader_snapshot_table_info {
@@ -413,11 +414,18 @@ pub(crate) fn render<'scope>(
                                 None,
                                 output.binlog_full_metadata,
                             ) {
-                                Ok(row) => Ok(SourceMessage {
-                                    key: Row::default(),
-                                    value: row,
-                                    metadata: Row::default(),
-                                }),
+                                Ok(row) => {
+                                    super::pack_metadata(
+                                        &output.metadata_columns,
+                                        None,
+                                        &mut metadata_row,
+                                    );
+                                    Ok(SourceMessage {
+                                        key: Row::default(),
+                                        value: row,
+                                        metadata: metadata_row.clone(),
+                                    })
+                                }
                                 // Produce a DefiniteError in the stream for any rows that fail to decode
                                 Err(err @ MySqlError::ValueDecodeError { .. }) => {
                                     Err(DataflowError::from(DefiniteError::ValueDecodeError(
@@ -626,6 +634,7 @@ where
 mod tests {
     use super::*;
     use mz_mysql_util::{MySqlColumnDesc, MySqlTableDesc};
+    use mz_storage_types::sources::SchemaChangePolicy;
     use timely::progress::Antichain;
 
     #[mz_ore::test]
@@ -657,6 +666,8 @@ mod tests {
             resume_upper: Antichain::default(),
             export_id: mz_repr::GlobalId::User(1),
             binlog_full_metadata: false,
+            metadata_columns: vec![],
+            schema_change_policy: SchemaChangePolicy::None,
         };
         let query = build_snapshot_query(&[info.clone(), info]);
         assert_eq!(
@@ -0,0 +1,551 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the reader of a [`NatsSourceConnection`].
+//!
+//! A single worker reads the stream. When the source starts, it gets or
+//! creates the durable consumer of the source, and reads the messages that the
+//! consumer has delivered before but the source has not committed through an
+//! ordered consumer. It then fetches batches of messages from the durable
+//! consumer every `nats_source_poll_interval`, and fetches again right away
+//! while the batches are full.
+//!
+//! The upstream frontier is one more than the last sequence number of the
+//! stream, which the operator probes along with the consumer to advance its
+//! frontier past messages that don't match the filter of the consumer.
+//!
+//! [`NatsSourceConnection`]: mz_storage_types::sources::NatsSourceConnection
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_nats::jetstream::consumer::pull::{Config as PullConfig, OrderedConfig};
+use async_nats::jetstream::consumer::{AckPolicy, Consumer, DeliverPolicy};
+use async_nats::jetstream::context::GetStreamErrorKind;
+use async_nats::jetstream::message::Acker;
+use async_nats::jetstream::stream::Stream;
+use async_nats::jetstream::{self, ErrorCode};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use futures::{StreamExt, TryStreamExt};
+use mz_ore::future::InTask;
+use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_storage_types::dyncfgs::NATS_SOURCE_POLL_INTERVAL;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::nats::NatsMetadataKind;
+use mz_storage_types::sources::{MzOffset, NatsSourceConnection};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::operators::{CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::metrics::source::nats::NatsSourceMetrics;
+use crate::source::nats::{
+    DefiniteError, ReplicationError, SourceOutputInfo, TransientError, caught_up_frontier,
+};
+use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceMessage, StackedCollection};
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// The largest number of messages fetched from the consumer at once.
+const FETCH_BATCH_SIZE: usize = 1024;
+
+/// How long the server waits for the acknowledgement of a delivered message before it delivers
+/// the message again. Messages are only acknowledged once they are committed, which can take a
+/// while.
+const ACK_WAIT: Duration = Duration::from_secs(300);
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, MzOffset>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: NatsSourceConnection,
+    committed_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
+    metrics: NatsSourceMetrics,
+) -> (
+    StackedCollection<'scope, MzOffset, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, MzOffset, ReplicationError>,
+    StreamVec<'scope, MzOffset, Probe<MzOffset>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("NatsReader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    // Captures DefiniteErrors that affect the entire source, including all outputs
+    let (definite_error_handle, definite_errors) =
+        builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set, probe_cap, definite_error_cap_set]: &mut [_; 3] =
+                caps.try_into().unwrap();
+
+            // Only a single worker reads the stream, as the messages of a consumer are only
+            // ordered for a single client.
+            if !config.responsible_for(&source.stream) {
+                // Emit 0, to mark this worker as having started up correctly.
+                for stat in config.statistics.values() {
+                    stat.set_offset_known(0);
+                    stat.set_offset_committed(0);
+                }
+                return Ok(());
+            }
+
+            let outputs: Vec<_> = outputs
+                .into_values()
+                .filter(|output| !output.resume_upper.is_empty())
+                .collect();
+            // The stream is read from the earliest position of any output.
+            let Some(resume_offset) = outputs
+                .iter()
+                .flat_map(|output| output.resume_upper.iter().copied())
+                .min()
+            else {
+                return Ok(());
+            };
+
+            let client = source
+                .connection
+                .connect(
+                    &config.config.connection_context.secrets_reader,
+                    &config.config,
+                    InTask::Yes,
+                )
+                .await?;
+            let context = jetstream::new(client);
+
+            let mut stream = match get_stream(&context, &source.stream).await? {
+                Ok(stream) => stream,
+                Err(err) => {
+                    return_definite_error(
+                        err,
+                        outputs.iter(),
+                        data_output,
+                        data_cap_set,
+                        definite_error_handle,
+                        definite_error_cap_set,
+                    )
+                    .await;
+                    return Ok(());
+                }
+            };
+
+            let consumer_name = source.consumer_name(&config.config.connection_context, config.id);
+            let subject = source.subject.clone().unwrap_or_default();
+            let mut consumer =
+                match get_consumer(&stream, &consumer_name, &subject, resume_offset).await? {
+                    Ok(consumer) => consumer,
+                    Err(err) => {
+                        return_definite_error(
+                            err,
+                            outputs.iter(),
+                            data_output,
+                            data_cap_set,
+                            definite_error_handle,
+                            definite_error_cap_set,
+                        )
+                        .await;
+                        return Ok(());
+                    }
+                };
+
+            let mut frontier = resume_offset;
+            data_cap_set.downgrade([&frontier]);
+
+            // Read the messages that the consumer delivered before the source restarted, but
+            // that the source has not committed. The consumer won't deliver them again before
+            // their acknowledgement times out.
+            let delivered = consumer.cached_info().delivered.stream_sequence;
+            if frontier.offset <= delivered {
+                backfill(
+                    &stream,
+                    &subject,
+                    frontier,
+                    delivered,
+                    &outputs,
+                    &data_output,
+                    data_cap_set,
+                    &metrics,
+                )
+                .await?;
+                frontier = MzOffset::from(delivered + 1);
+                data_cap_set.downgrade([&frontier]);
+            }
+
+            // The acknowledgers of the messages that have been read but not committed, by
+            // sequence number.
+            let mut ackers: BTreeMap<u64, Acker> = BTreeMap::new();
+            let mut committed_uppers = std::pin::pin!(committed_uppers);
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker =
+                probe::Ticker::new(move || timestamp_interval, config.now_fn.clone());
+            let mut next_poll = tokio::time::Instant::now();
+
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        let info = stream
+                            .info()
+                            .await
+                            .map_err(|e| TransientError::Nats(e.to_string()))?;
+                        let last_sequence = info.state.last_sequence;
+                        let info = consumer
+                            .info()
+                            .await
+                            .map_err(|e| TransientError::Nats(e.to_string()))?;
+                        metrics.pending_messages.set(info.num_pending);
+                        frontier = caught_up_frontier(
+                            frontier,
+                            last_sequence,
+                            info.num_pending,
+                            info.delivered.stream_sequence,
+                        );
+                        data_cap_set.downgrade([&frontier]);
+
+                        let upstream_frontier = MzOffset::from(last_sequence + 1);
+                        for stat in config.statistics.values() {
+                            stat.set_offset_known(upstream_frontier.offset);
+                        }
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: Antichain::from_elem(upstream_frontier),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                    },
+                    Some(upper) = committed_uppers.next() => {
+                        if let Some(upper) = upper.into_option() {
+                            // Acknowledging a message acknowledges every message before it.
+                            let pending = ackers.split_off(&upper.offset);
+                            if let Some((_, acker)) = ackers.pop_last() {
+                                acker
+                                    .ack()
+                                    .await
+                                    .map_err(|e| TransientError::Nats(e.to_string()))?;
+                                metrics.acks.inc();
+                            }
+                            ackers = pending;
+                            for stat in config.statistics.values() {
+                                stat.set_offset_committed(upper.offset);
+                            }
+                        }
+                    },
+                    _ = tokio::time::sleep_until(next_poll) => {
+                        metrics.fetch_requests.inc();
+                        let mut batch = consumer
+                            .fetch()
+                            .max_messages(FETCH_BATCH_SIZE)
+                            .messages()
+                            .await
+                            .map_err(|e| TransientError::Nats(e.to_string()))?;
+                        let mut count = 0;
+                        while let Some(message) = batch
+                            .try_next()
+                            .await
+                            .map_err(|e| TransientError::Nats(e.to_string()))?
+                        {
+                            count += 1;
+                            let info = message
+                                .info()
+                                .map_err(|e| TransientError::Nats(e.to_string()))?;
+                            let sequence = info.stream_sequence;
+                            let published = published_at(info.published);
+                            let (message, acker) = message.split();
+                            ackers.insert(sequence, acker);
+                            // Messages the source has already read can be delivered again when
+                            // their acknowledgement times out.
+                            if sequence < frontier.offset {
+                                continue;
+                            }
+                            let time = MzOffset::from(sequence);
+                            let cap = data_cap_set.delayed(&time);
+                            emit_message(
+                                &message,
+                                sequence,
+                                published,
+                                time,
+                                &outputs,
+                                &data_output,
+                                &cap,
+                            )
+                            .await;
+                            metrics.messages.inc();
+                            frontier = MzOffset::from(sequence + 1);
+                        }
+                        data_cap_set.downgrade([&frontier]);
+
+                        next_poll = if count >= FETCH_BATCH_SIZE {
+                            tokio::time::Instant::now()
+                        } else {
+                            let interval = NATS_SOURCE_POLL_INTERVAL.get(config.config.config_set());
+                            tokio::time::Instant::now() + interval
+                        };
+                    },
+                };
+            }
+        }))
+    });
+
+    let error_stream = definite_errors.concat(transient_errors.map(ReplicationError::Transient));
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        probe_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+type DataOutput = StackedAsyncOutputHandle<MzOffset, (u64, Result<SourceMessage, DataflowError>)>;
+
+/// Gets the stream named `name`.
+async fn get_stream(
+    context: &jetstream::Context,
+    name: &str,
+) -> Result<Result<Stream, DefiniteError>, TransientError> {
+    match context.get_stream(name).await {
+        Ok(stream) => Ok(Ok(stream)),
+        Err(err) => match err.kind() {
+            GetStreamErrorKind::JetStream(e) if e.error_code() == ErrorCode::STREAM_NOT_FOUND => {
+                Ok(Err(DefiniteError::StreamNotFound(name.to_string())))
+            }
+            _ => Err(TransientError::Nats(err.to_string())),
+        },
+    }
+}
+
+/// Gets the durable consumer named `name`, or creates it to deliver the messages of the stream
+/// from `resume_offset` onwards.
+async fn get_consumer(
+    stream: &Stream,
+    name: &str,
+    subject: &str,
+    resume_offset: MzOffset,
+) -> Result<Result<Consumer<PullConfig>, DefiniteError>, TransientError> {
+    let deliver_policy = match resume_offset.offset {
+        0 | 1 => DeliverPolicy::All,
+        start_sequence => DeliverPolicy::ByStartSequence { start_sequence },
+    };
+    let config = PullConfig {
+        durable_name: Some(name.to_string()),
+        deliver_policy,
+        ack_policy: AckPolicy::All,
+        ack_wait: ACK_WAIT,
+        // The source only acknowledges messages once they are committed.
+        max_ack_pending: -1,
+        filter_subject: subject.to_string(),
+        ..Default::default()
+    };
+    let consumer = stream
+        .get_or_create_consumer(name, config)
+        .await
+        .map_err(|e| TransientError::Nats(e.to_string()))?;
+
+    // An existing consumer might have been created for something else.
+    let existing = &consumer.cached_info().config;
+    let incompatible = |reason: String| DefiniteError::IncompatibleConsumer {
+        stream: stream.cached_info().config.name.clone(),
+        consumer: name.to_string(),
+        reason,
+    };
+    if existing.ack_policy != AckPolicy::All {
+        let reason = format!("ack policy is {:?}, not All", existing.ack_policy);
+        return Ok(Err(incompatible(reason)));
+    }
+    if existing.filter_subject != subject {
+        let reason = format!(
+            "filter subject is {:?}, not {:?}",
+            existing.filter_subject, subject
+        );
+        return Ok(Err(incompatible(reason)));
+    }
+    Ok(Ok(consumer))
+}
+
+/// Reads the messages with sequence numbers from `start` up to and including `end` that match
+/// `subject`, through an ordered consumer.
+async fn backfill(
+    stream: &Stream,
+    subject: &str,
+    start: MzOffset,
+    end: u64,
+    outputs: &[SourceOutputInfo],
+    data_output: &DataOutput,
+    data_cap_set: &CapabilitySet<MzOffset>,
+    metrics: &NatsSourceMetrics,
+) -> Result<(), TransientError> {
+    let deliver_policy = match start.offset {
+        0 | 1 => DeliverPolicy::All,
+        start_sequence => DeliverPolicy::ByStartSequence { start_sequence },
+    };
+    let config = OrderedConfig {
+        deliver_policy,
+        filter_subject: subject.to_string(),
+        ..Default::default()
+    };
+    let consumer = stream
+        .create_consumer(config)
+        .await
+        .map_err(|e| TransientError::Nats(e.to_string()))?;
+    // The messages might have been removed from the stream in the meantime.
+    if consumer.cached_info().num_pending == 0 {
+        return Ok(());
+    }
+    let mut messages = consumer
+        .messages()
+        .await
+        .map_err(|e| TransientError::Nats(e.to_string()))?;
+    while let Some(message) = messages
+        .try_next()
+        .await
+        .map_err(|e| TransientError::Nats(e.to_string()))?
+    {
+        let info = message
+            .info()
+            .map_err(|e| TransientError::Nats(e.to_string()))?;
+        let (sequence, pending) = (info.stream_sequence, info.pending);
+        let published = published_at(info.published);
+        if sequence > end {
+            break;
+        }
+        let time = MzOffset::from(sequence);
+        let cap = data_cap_set.delayed(&time);
+        emit_message(
+            &message,
+            sequence,
+            published,
+            time,
+            outputs,
+            data_output,
+            &cap,
+        )
+        .await;
+        metrics.messages.inc();
+        if sequence == end || pending == 0 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the time at which a message was published to the stream.
+fn published_at(published: impl Into<std::time::SystemTime>) -> DateTime<Utc> {
+    DateTime::from(published.into())
+}
+
+/// Emits `message` at `time` to the outputs that haven't ingested it yet.
+async fn emit_message(
+    message: &async_nats::Message,
+    sequence: u64,
+    published: DateTime<Utc>,
+    time: MzOffset,
+    outputs: &[SourceOutputInfo],
+    data_output: &DataOutput,
+    cap: &timely::dataflow::operators::Capability<MzOffset>,
+) {
+    for output in outputs {
+        if !output.resume_upper.less_equal(&time) {
+            continue;
+        }
+        let source_message =
+            construct_source_message(message, sequence, published, &output.metadata_columns);
+        let update = (
+            (output.partition_index, Ok(source_message)),
+            time,
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_output.give_fueled(cap, update, size).await;
+    }
+}
+
+/// Constructs the source message of `message`, with the metadata that `metadata_columns` asks
+/// for.
+fn construct_source_message(
+    message: &async_nats::Message,
+    sequence: u64,
+    published: DateTime<Utc>,
+    metadata_columns: &[NatsMetadataKind],
+) -> SourceMessage {
+    let mut metadata = Row::default();
+    let mut packer = metadata.packer();
+    for kind in metadata_columns {
+        match kind {
+            NatsMetadataKind::Offset => packer.push(Datum::UInt64(sequence)),
+            NatsMetadataKind::Timestamp => {
+                let ts: Option<CheckedTimestamp<NaiveDateTime>> =
+                    published.naive_utc().try_into().ok();
+                let d: Datum = ts.into();
+                packer.push(d)
+            }
+            NatsMetadataKind::Headers => {
+                packer.push_list_with(|r| {
+                    for (name, values) in message.headers.iter().flat_map(|h| h.iter()) {
+                        let name = name.to_string();
+                        for value in values {
+                            r.push_list_with(|record_row| {
+                                record_row.push(Datum::String(&name));
+                                record_row.push(Datum::Bytes(value.as_str().as_bytes()));
+                            });
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    SourceMessage {
+        key: Row::pack([Datum::Bytes(message.subject.as_bytes())]),
+        value: Row::pack([Datum::Bytes(&message.payload)]),
+        metadata,
+    }
+}
+
+/// Helper method to return a "definite" error upstream.
+async fn return_definite_error<'a>(
+    err: DefiniteError,
+    outputs: impl Iterator<Item = &'a SourceOutputInfo>,
+    data_handle: DataOutput,
+    data_capset: &CapabilitySet<MzOffset>,
+    errs_handle: AsyncOutputHandle<MzOffset, CapacityContainerBuilder<Vec<ReplicationError>>>,
+    errs_capset: &CapabilitySet<MzOffset>,
+) {
+    for output in outputs {
+        // The error is a property of the stream itself, so it is emitted at the minimum time of
+        // the outputs that have not ingested anything yet, which is every output that could have
+        // observed the error.
+        if *output.resume_upper != [MzOffset::minimum()] {
+            continue;
+        }
+        let update = (
+            (output.partition_index, Err(err.clone().into())),
+            MzOffset::minimum(),
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_handle.give_fueled(&data_capset[0], update, size).await;
+    }
+    errs_handle.give(
+        &errs_capset[0],
+        ReplicationError::DefiniteError(Rc::new(err)),
+    );
+}
@@ -31,6 +31,15 @@
 //!
 //! See the [replication] module for more information on the replication strategy.
 //!
+//! # Retractions
+//!
+//! Outputs created with `RETRACT ON TRUNCATE` or that include transaction metadata additionally
+//! pass through an operator that keeps their contents. It retracts the contents of the former
+//! whenever the replication reader observes a `TRUNCATE` of their upstream table, and retracts the
+//! rows of the latter with the metadata they were inserted with.
+//!
+//! See the [retractions] module for more information.
+//!
 //! # Error handling
 //!
 //! There are two kinds of errors that can happen during ingestion that are represented as two
@@ -94,9 +103,10 @@ use mz_postgres_util::{Client, PostgresError, Sql, query_opt, simple_query_opt,
 use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
 use mz_storage_types::sources::casts::StorageScalarExpr;
-use mz_storage_types::sources::postgres::CastType;
+use mz_storage_types::sources::postgres::{CastType, PostgresMetadataKind};
 use mz_storage_types::sources::{
-    MzOffset, PostgresSourceConnection, SourceExport, SourceExportDetails, SourceTimestamp,
+    MzOffset, PostgresSourceConnection, SchemaChangePolicy, SourceExport, SourceExportDetails,
+    SourceTimestamp,
 };
 use mz_timely_util::builder_async::PressOnDropButton;
 use serde::{Deserialize, Serialize};
@@ -110,10 +120,11 @@ use tokio_postgres::error::SqlState;
 use tokio_postgres::types::PgLsn;
 
 use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
-use crate::source::types::{Probe, SourceRender, StackedCollection};
+use crate::source::types::{FuelSize, Probe, SourceRender, StackedCollection};
 use crate::source::{RawSourceCreationConfig, SourceMessage};
 
 mod replication;
+mod retractions;
 mod snapshot;
 
 impl SourceRender for PostgresSourceConnection {
@@ -140,20 +151,16 @@ impl SourceRender for PostgresSourceConnection {
     ) {
         // Collect the source outputs that we will be exporting into a per-table map.
         let mut table_info = BTreeMap::new();
+        // The outputs that carry the logical decoding messages of the source.
+        let mut message_outputs = BTreeMap::new();
+        // The outputs whose contents are needed to complete their retractions.
+        let mut retracting_outputs = BTreeMap::new();
         for (idx, (id, export)) in config.source_exports.iter().enumerate() {
             let SourceExport {
                 details,
                 storage_metadata: _,
                 data_config: _,
             } = export;
-            let details = match details {
-                SourceExportDetails::Postgres(details) => details,
-                // This is an export that doesn't need any data output to it.
-                SourceExportDetails::None => continue,
-                _ => panic!("unexpected source export details: {:?}", details),
-            };
-            let desc = details.table.clone();
-            let casts = details.column_casts.clone();
             let resume_upper = Antichain::from_iter(
                 config
                     .source_resume_uppers
@@ -162,10 +169,40 @@ impl SourceRender for PostgresSourceConnection {
                     .iter()
                     .map(MzOffset::decode_row),
             );
+            let details = match details {
+                SourceExportDetails::Postgres(details) => details,
+                SourceExportDetails::PostgresMessages(_) => {
+                    message_outputs.insert(idx, resume_upper);
+                    continue;
+                }
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                _ => panic!("unexpected source export details: {:?}", details),
+            };
+            let desc = details.table.clone();
+            let casts = details.column_casts.clone();
+            let metadata_columns = details
+                .metadata_columns
+                .iter()
+                .map(|(_, kind)| kind.clone())
+                .collect();
+            if details.retract_on_truncate || !details.metadata_columns.is_empty() {
+                let output = retractions::RetractingOutput {
+                    export_id: *id,
+                    resume_upper: resume_upper.clone(),
+                    retract_on_truncate: details.retract_on_truncate,
+                    metadata_len: details.metadata_columns.len(),
+                };
+                retracting_outputs.insert(idx, output);
+            }
             let output = SourceOutputInfo {
                 desc,
                 projection: None,
                 casts,
+                metadata_columns,
+                retract_on_truncate: details.retract_on_truncate,
+                exclude_columns: details.exclude_columns.clone(),
+                schema_change_policy: details.schema_change_policy,
                 resume_upper,
                 export_id: id.clone(),
             };
@@ -186,18 +223,32 @@ impl SourceRender for PostgresSourceConnection {
                 metrics.snapshot_metrics.clone(),
             );
 
-        let (repl_updates, probe_stream, repl_err, repl_token) = replication::render(
+        let (repl_updates, truncates, probe_stream, mut repl_err, repl_token) = replication::render(
             scope.clone(),
             config.clone(),
             self,
             table_info,
+            message_outputs,
             rewinds,
             slot_ready,
             resume_uppers,
             metrics,
         );
+        let mut tokens = vec![snapshot_token, repl_token];
 
-        let updates = snapshot_updates.concat(repl_updates);
+        let mut updates = snapshot_updates.concat(repl_updates);
+        if !retracting_outputs.is_empty() {
+            let (retracted_updates, retractions_err, retractions_token) = retractions::render(
+                scope.clone(),
+                config.clone(),
+                updates,
+                truncates,
+                retracting_outputs,
+            );
+            updates = retracted_updates;
+            repl_err = repl_err.concat(retractions_err);
+            tokens.push(retractions_token);
+        }
         let partition_count = u64::cast_from(config.source_exports.len());
         let data_streams: Vec<_> = updates
             .inner
@@ -259,12 +310,7 @@ impl SourceRender for PostgresSourceConnection {
 
         let health = health_init.concat(errs);
 
-        (
-            data_collections,
-            health,
-            probe_stream,
-            vec![snapshot_token, repl_token],
-        )
+        (data_collections, health, probe_stream, tokens)
     }
 }
 
@@ -277,10 +323,66 @@ struct SourceOutputInfo {
     /// this field is None since we haven't yet observed any schemas.
     projection: Option<Vec<usize>>,
     casts: Vec<(CastType, StorageScalarExpr)>,
+    /// The transaction metadata columns requested by this output, in order.
+    metadata_columns: Vec<PostgresMetadataKind>,
+    /// Whether a `TRUNCATE` of the upstream table retracts the contents of this output instead of
+    /// putting it into an errored state.
+    retract_on_truncate: bool,
+    /// The upstream columns that were deliberately left out of this output.
+    exclude_columns: Vec<String>,
+    schema_change_policy: SchemaChangePolicy,
     resume_upper: Antichain<MzOffset>,
     export_id: GlobalId,
 }
 
+/// Metadata about the upstream transaction that produced a replicated row, as reported by its
+/// `BEGIN` message.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct TransactionInfo {
+    /// The LSN of the transaction's commit record.
+    commit_lsn: u64,
+    /// The commit time of the transaction, in microseconds since the Postgres epoch.
+    commit_time: i64,
+    /// The upstream transaction ID.
+    xid: u32,
+}
+
+impl FuelSize for TransactionInfo {
+    fn fuel_size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
+/// Packs the requested metadata `kinds` of `tx` into `row`. Rows that were not replicated as
+/// part of a transaction, i.e. snapshot rows and their retractions, have all of their metadata
+/// columns set to null.
+fn pack_metadata(kinds: &[PostgresMetadataKind], tx: Option<&TransactionInfo>, row: &mut Row) {
+    let mut packer = row.packer();
+    for kind in kinds {
+        let Some(tx) = tx else {
+            packer.push(Datum::Null);
+            continue;
+        };
+        match kind {
+            PostgresMetadataKind::CommitTimestamp => {
+                // Postgres reports timestamps relative to 2000-01-01T00:00:00Z.
+                const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+                let ts = tx
+                    .commit_time
+                    .checked_add(PG_EPOCH_MICROS)
+                    .and_then(chrono::DateTime::from_timestamp_micros)
+                    .and_then(|dt| dt.naive_utc().try_into().ok());
+                match ts {
+                    Some(ts) => packer.push(Datum::Timestamp(ts)),
+                    None => packer.push(Datum::Null),
+                }
+            }
+            PostgresMetadataKind::Lsn => packer.push(Datum::UInt64(tx.commit_lsn)),
+            PostgresMetadataKind::TransactionId => packer.push(Datum::UInt32(tx.xid)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum ReplicationError {
     #[error(transparent)]
@@ -317,6 +419,8 @@ pub enum TransientError {
     NestedTransaction,
     #[error("recoverable errors should crash the process during snapshots")]
     SyntheticError,
+    #[error("upstream table {0} has new columns that are not yet part of its source tables")]
+    PendingSchemaChange(String),
     #[error("sql client error")]
     SQLClient(#[from] tokio_postgres::Error),
     #[error(transparent)]
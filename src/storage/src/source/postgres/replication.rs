@@ -66,10 +66,30 @@
 //! messages at previous LSNs, because they would have been already generated and received. We
 //! therefore connect the keepalive messages directly to our capability.
 //!
+//! # Logical decoding messages and truncations
+//!
+//! When the ingestion has outputs for logical decoding messages (those emitted upstream with
+//! `pg_logical_emit_message`) the stream is started with the `messages` option. Transactional
+//! messages are emitted at the commit LSN of their transaction, like any other update.
+//! Non-transactional messages are emitted at their own LSN as soon as they are received.
+//!
+//! A `TRUNCATE` of a table normally makes its outputs unusable. Outputs that were created with
+//! `RETRACT ON TRUNCATE` instead have the truncation reported on a separate output, and the
+//! updates the truncating transaction made to them earlier are retracted. Retracting the rest of
+//! their contents is left to the operator in the `retractions` module.
+//!
+//! # Added columns
+//!
+//! Outputs created with `SCHEMA CHANGE POLICY = 'add_columns'` are altered by the adapter to
+//! include columns that were added upstream. Changes made after such a column was added must not
+//! be ingested without it, so when a relation message announces columns that an output doesn't
+//! know about the replication reader errors with a transient error and retries until the output
+//! has been altered.
+//!
 //! [1]: https://www.postgresql.org/docs/15/protocol-replication.html#PROTOCOL-REPLICATION-START-REPLICATION
 //! [2]: https://www.postgresql.org/message-id/CAFPTHDZS9O9WG02EfayBd6oONzK%2BqfUxS6AbVLJ7W%2BKECza2gg%40mail.gmail.com
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::Infallible;
 use std::pin::pin;
 use std::rc::Rc;
@@ -90,13 +110,15 @@ use mz_repr::{Datum, DatumVec, Diff, Row};
 use mz_storage_types::dyncfgs::PG_SCHEMA_VALIDATION_INTERVAL;
 use mz_storage_types::dyncfgs::PG_SOURCE_VALIDATE_TIMELINE;
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sources::{MzOffset, PostgresSourceConnection};
+use mz_storage_types::sources::{MzOffset, PostgresSourceConnection, SchemaChangePolicy};
 use mz_timely_util::builder_async::{
     AsyncOutputHandle, Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder,
     PressOnDropButton,
 };
 use postgres_replication::LogicalReplicationStream;
-use postgres_replication::protocol::{LogicalReplicationMessage, ReplicationMessage, TupleData};
+use postgres_replication::protocol::{
+    LogicalReplicationMessage, MessageBody, ReplicationMessage, TupleData,
+};
 use serde::{Deserialize, Serialize};
 use timely::container::CapacityContainerBuilder;
 use timely::dataflow::channels::pact::{Exchange, Pipeline};
@@ -114,7 +136,9 @@ use tracing::{error, trace};
 use crate::metrics::source::postgres::PgSourceMetrics;
 use crate::source::RawSourceCreationConfig;
 use crate::source::postgres::verify_schema;
-use crate::source::postgres::{DefiniteError, ReplicationError, SourceOutputInfo, TransientError};
+use crate::source::postgres::{
+    DefiniteError, ReplicationError, SourceOutputInfo, TransactionInfo, TransientError,
+};
 use crate::source::probe;
 use crate::source::types::{FuelSize, Probe, SignaledFuture, SourceMessage, StackedCollection};
 
@@ -124,6 +148,19 @@ type LogicalReplMsg = ReplicationMessage<LogicalReplicationMessage>;
 /// A decoded row from a transaction with source information.
 type DecodedRow = (u32, usize, Result<Row, DefiniteError>, Diff);
 
+/// An event extracted from a transaction.
+enum TransactionEvent {
+    /// An update to an output.
+    Row(DecodedRow),
+    /// The upstream table with the given OID was truncated and the given output retracts its
+    /// contents on truncation.
+    Truncate(u32, usize),
+}
+
+/// The OID that rows of the logical decoding message outputs are attributed to. PostgreSQL never
+/// assigns it to a relation.
+const MESSAGES_OID: u32 = 0;
+
 /// Postgres epoch is 2000-01-01T00:00:00Z
 static PG_EPOCH: LazyLock<SystemTime> =
     LazyLock::new(|| UNIX_EPOCH + Duration::from_secs(946_684_800));
@@ -145,12 +182,14 @@ pub(crate) fn render<'scope>(
     config: RawSourceCreationConfig,
     connection: PostgresSourceConnection,
     table_info: BTreeMap<u32, BTreeMap<usize, SourceOutputInfo>>,
+    message_outputs: BTreeMap<usize, Antichain<MzOffset>>,
     rewind_stream: StreamVec<'scope, MzOffset, RewindRequest>,
     slot_ready_stream: StreamVec<'scope, MzOffset, Infallible>,
     committed_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
     metrics: PgSourceMetrics,
 ) -> (
     StackedCollection<'scope, MzOffset, (usize, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, MzOffset, usize>,
     StreamVec<'scope, MzOffset, Probe<MzOffset>>,
     StreamVec<'scope, MzOffset, ReplicationError>,
     PressOnDropButton,
@@ -163,24 +202,41 @@ pub(crate) fn render<'scope>(
     let (definite_error_handle, definite_errors) =
         builder.new_output::<CapacityContainerBuilder<_>>();
     let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+    let (truncate_output, truncate_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
 
     let mut rewind_input =
         builder.new_disconnected_input(rewind_stream, Exchange::new(move |_| slot_reader));
     let mut slot_ready_input = builder.new_disconnected_input(slot_ready_stream, Pipeline);
-    let output_uppers = table_info
+    let mut output_uppers = table_info
         .iter()
         .flat_map(|(_, outputs)| outputs.values().map(|o| o.resume_upper.clone()))
         .collect::<Vec<_>>();
     metrics.tables.set(u64::cast_from(output_uppers.len()));
+    output_uppers.extend(message_outputs.values().cloned());
+    let message_outputs: BTreeSet<usize> = message_outputs.into_keys().collect();
+    // Updates that a transaction made to outputs that retract on truncation are remembered until
+    // its commit, in case it truncates them later on.
+    let retracting_outputs: BTreeSet<usize> = table_info
+        .values()
+        .flat_map(|outputs| outputs.iter())
+        .filter(|(_, info)| info.retract_on_truncate)
+        .map(|(output_index, _)| *output_index)
+        .collect();
 
     let reader_table_info = table_info.clone();
+    let reader_message_outputs = message_outputs.clone();
     let (button, transient_errors) = builder.build_fallible(move |caps| {
         let mut table_info = reader_table_info;
+        let message_outputs = reader_message_outputs;
         let busy_signal = Arc::clone(&config.busy_signal);
         Box::pin(SignaledFuture::new(busy_signal, async move {
             let (id, worker_id) = (config.id, config.worker_id);
-            let [data_cap_set, definite_error_cap_set, probe_cap]: &mut [_; 3] =
-                caps.try_into().unwrap();
+            let [
+                data_cap_set,
+                definite_error_cap_set,
+                probe_cap,
+                truncate_cap_set,
+            ]: &mut [_; 4] = caps.try_into().unwrap();
 
             if !config.responsible_for("slot") {
                 // Emit 0, to mark this worker as having started up correctly.
@@ -345,24 +401,21 @@ pub(crate) fn render<'scope>(
                             );
                             // If the replication stream cannot be obtained from the resume point there is nothing
                             // else to do. These errors are not retractable.
-                            for (oid, outputs) in table_info.iter() {
-                                for output_index in outputs.keys() {
-                                    // We pick `u64::MAX` as the LSN which will (in practice) never conflict
-                                    // any previously revealed portions of the TVC.
-                                    let update = (
-                                        (
-                                            *oid,
-                                            *output_index,
-                                            Err(DataflowError::from(err.clone())),
-                                        ),
-                                        MzOffset::from(u64::MAX),
-                                        Diff::ONE,
-                                    );
-                                    let size = update.fuel_size();
-                                    data_output
-                                        .give_fueled(&data_cap_set[0], update, size)
-                                        .await;
-                                }
+                            for (oid, output_index) in all_outputs(&table_info, &message_outputs) {
+                                // We pick `u64::MAX` as the LSN which will (in practice) never conflict
+                                // any previously revealed portions of the TVC.
+                                let update = (
+                                    (
+                                        (oid, output_index, Err(DataflowError::from(err.clone()))),
+                                        None,
+                                    ),
+                                    MzOffset::from(u64::MAX),
+                                    Diff::ONE,
+                                );
+                                let size = update.fuel_size();
+                                data_output
+                                    .give_fueled(&data_cap_set[0], update, size)
+                                    .await;
                             }
                             definite_error_handle.give(
                                 &definite_error_cap_set[0],
@@ -390,6 +443,7 @@ pub(crate) fn render<'scope>(
                 &probe_output,
                 &probe_cap[0],
                 connection.publication_details.get_is_physical_replica(),
+                !message_outputs.is_empty(),
             )
             .await?;
 
@@ -398,20 +452,21 @@ pub(crate) fn render<'scope>(
                 Err(err) => {
                     // If the replication stream cannot be obtained in a definite way there is
                     // nothing else to do. These errors are not retractable.
-                    for (oid, outputs) in table_info.iter() {
-                        for output_index in outputs.keys() {
-                            // We pick `u64::MAX` as the LSN which will (in practice) never conflict
-                            // any previously revealed portions of the TVC.
-                            let update = (
-                                (*oid, *output_index, Err(DataflowError::from(err.clone()))),
-                                MzOffset::from(u64::MAX),
-                                Diff::ONE,
-                            );
-                            let size = update.fuel_size();
-                            data_output
-                                .give_fueled(&data_cap_set[0], update, size)
-                                .await;
-                        }
+                    for (oid, output_index) in all_outputs(&table_info, &message_outputs) {
+                        // We pick `u64::MAX` as the LSN which will (in practice) never conflict
+                        // any previously revealed portions of the TVC.
+                        let update = (
+                            (
+                                (oid, output_index, Err(DataflowError::from(err.clone()))),
+                                None,
+                            ),
+                            MzOffset::from(u64::MAX),
+                            Diff::ONE,
+                        );
+                        let size = update.fuel_size();
+                        data_output
+                            .give_fueled(&data_cap_set[0], update, size)
+                            .await;
                     }
 
                     definite_error_handle.give(
@@ -449,12 +504,18 @@ pub(crate) fn render<'scope>(
                     Ok(XLogData(data)) => match data.data() {
                         Begin(begin) => {
                             let commit_lsn = MzOffset::from(begin.final_lsn());
+                            let tx_info = TransactionInfo {
+                                commit_lsn: begin.final_lsn(),
+                                commit_time: begin.timestamp(),
+                                xid: begin.xid(),
+                            };
 
                             let mut tx = pin!(extract_transaction(
                                 stream.by_ref(),
                                 &*metadata_client,
                                 commit_lsn,
                                 &mut table_info,
+                                &message_outputs,
                                 &metrics,
                                 &connection.publication,
                             ));
@@ -469,25 +530,99 @@ pub(crate) fn render<'scope>(
                                 "new_upper={data_upper} tx_lsn={commit_lsn}",
                             );
                             data_upper = commit_lsn + 1;
-                            while let Some((oid, output_index, event, diff)) = tx.try_next().await?
-                            {
+                            // The updates of this transaction to outputs that retract their
+                            // contents on truncation, in case the transaction truncates them.
+                            let mut tx_updates: BTreeMap<usize, Vec<(u32, Row, Diff)>> =
+                                BTreeMap::new();
+                            // Outputs truncated by this transaction that can no longer be
+                            // maintained.
+                            let mut truncated_outputs = vec![];
+                            while let Some(event) = tx.try_next().await? {
+                                let (oid, output_index, event, diff) = match event {
+                                    TransactionEvent::Row(row) => row,
+                                    TransactionEvent::Truncate(oid, output_index) => {
+                                        let rewinding = rewinds
+                                            .get(&output_index)
+                                            .is_some_and(|req| commit_lsn <= req.snapshot_lsn);
+                                        if !rewinding {
+                                            let updates = tx_updates.remove(&output_index);
+                                            for (oid, row, diff) in updates.into_iter().flatten() {
+                                                let update = (
+                                                    ((oid, output_index, Ok(row)), Some(tx_info)),
+                                                    commit_lsn,
+                                                    -diff,
+                                                );
+                                                let size = update.fuel_size();
+                                                data_output
+                                                    .give_fueled(&data_cap_set[0], update, size)
+                                                    .await;
+                                            }
+                                            truncate_output
+                                                .give(&truncate_cap_set[0], output_index);
+                                            continue;
+                                        }
+                                        // The snapshot of the output already reflects the
+                                        // truncation but we don't know what it removed, so the
+                                        // output can't be rewound past it.
+                                        truncated_outputs.push((oid, output_index));
+                                        let err = Err(DefiniteError::TableTruncated);
+                                        (oid, output_index, err, Diff::ONE)
+                                    }
+                                };
+                                let rewinding = rewinds
+                                    .get(&output_index)
+                                    .is_some_and(|req| commit_lsn <= req.snapshot_lsn);
+                                if retracting_outputs.contains(&output_index) {
+                                    if let Ok(row) = &event {
+                                        tx_updates.entry(output_index).or_default().push((
+                                            oid,
+                                            row.clone(),
+                                            diff,
+                                        ));
+                                    }
+                                }
                                 let event = event.map_err(Into::into);
                                 let data = (oid, output_index, event);
-                                if let Some(req) = rewinds.get(&output_index) {
-                                    if commit_lsn <= req.snapshot_lsn {
-                                        let update = (data.clone(), MzOffset::from(0), -diff);
-                                        let size = update.fuel_size();
-                                        data_output
-                                            .give_fueled(&data_cap_set[0], update, size)
-                                            .await;
-                                    }
+                                // Updates that are rewound are reflected in the snapshot, whose
+                                // rows carry no transaction metadata, so they carry none either.
+                                let tx_info = if rewinding {
+                                    let update = ((data.clone(), None), MzOffset::from(0), -diff);
+                                    let size = update.fuel_size();
+                                    data_output
+                                        .give_fueled(&data_cap_set[0], update, size)
+                                        .await;
+                                    None
+                                } else {
+                                    Some(tx_info)
+                                };
+                                let update = ((data, tx_info), commit_lsn, diff);
+                                let size = update.fuel_size();
+                                data_output
+                                    .give_fueled(&data_cap_set[0], update, size)
+                                    .await;
+                            }
+                            drop(tx);
+                            for (oid, output_index) in truncated_outputs {
+                                if let Some(outputs) = table_info.get_mut(&oid) {
+                                    outputs.remove(&output_index);
                                 }
-                                let update = (data, commit_lsn, diff);
+                            }
+                        }
+                        // Non-transactional messages are emitted immediately upstream and are
+                        // not part of any transaction.
+                        Message(body) if !message_outputs.is_empty() => {
+                            let lsn = std::cmp::max(data_upper, MzOffset::from(body.lsn()));
+                            let mut row = Row::default();
+                            let row = unpack_message(body, &mut row)?;
+                            for output_index in message_outputs.iter() {
+                                let data = (MESSAGES_OID, *output_index, Ok(row.clone()));
+                                let update = ((data, None), lsn, Diff::ONE);
                                 let size = update.fuel_size();
                                 data_output
                                     .give_fueled(&data_cap_set[0], update, size)
                                     .await;
                             }
+                            data_upper = lsn + 1;
                         }
                         _ => return Err(TransientError::BareTransactionEvent),
                     },
@@ -503,22 +638,24 @@ pub(crate) fn render<'scope>(
                             match error {
                                 Postgres(PostgresError::PublicationMissing(publication)) => {
                                     let err = DefiniteError::PublicationDropped(publication);
-                                    for (oid, outputs) in table_info.iter() {
-                                        for output_index in outputs.keys() {
-                                            let update = (
+                                    let outputs = all_outputs(&table_info, &message_outputs);
+                                    for (oid, output_index) in outputs {
+                                        let update = (
+                                            (
                                                 (
-                                                    *oid,
-                                                    *output_index,
+                                                    oid,
+                                                    output_index,
                                                     Err(DataflowError::from(err.clone())),
                                                 ),
-                                                data_cap_set[0].time().clone(),
-                                                Diff::ONE,
-                                            );
-                                            let size = update.fuel_size();
-                                            data_output
-                                                .give_fueled(&data_cap_set[0], update, size)
-                                                .await;
-                                        }
+                                                None,
+                                            ),
+                                            data_cap_set[0].time().clone(),
+                                            Diff::ONE,
+                                        );
+                                        let size = update.fuel_size();
+                                        data_output
+                                            .give_fueled(&data_cap_set[0], update, size)
+                                            .await;
                                     }
                                     definite_error_handle.give(
                                         &definite_error_cap_set[0],
@@ -535,23 +672,25 @@ pub(crate) fn render<'scope>(
                                     // definite, non-retryable error.
                                     let err =
                                         DefiniteError::InvalidPhysicalReplica { expected, actual };
-                                    for (oid, outputs) in table_info.iter() {
-                                        for output_index in outputs.keys() {
-                                            let update = (
+                                    let outputs = all_outputs(&table_info, &message_outputs);
+                                    for (oid, output_index) in outputs {
+                                        let update = (
+                                            (
                                                 (
-                                                    *oid,
-                                                    *output_index,
+                                                    oid,
+                                                    output_index,
                                                     Err(DataflowError::from(err.clone())),
                                                 ),
-                                                // We don't have a clean way to align on when the replica changed so jump straight to u64::MAX to avoid conflicts.
-                                                MzOffset::from(u64::MAX),
-                                                Diff::ONE,
-                                            );
-                                            let size = update.fuel_size();
-                                            data_output
-                                                .give_fueled(&data_cap_set[0], update, size)
-                                                .await;
-                                        }
+                                                None,
+                                            ),
+                                            // We don't have a clean way to align on when the replica changed so jump straight to u64::MAX to avoid conflicts.
+                                            MzOffset::from(u64::MAX),
+                                            Diff::ONE,
+                                        );
+                                        let size = update.fuel_size();
+                                        data_output
+                                            .give_fueled(&data_cap_set[0], update, size)
+                                            .await;
                                     }
                                     definite_error_handle.give(
                                         &definite_error_cap_set[0],
@@ -570,7 +709,7 @@ pub(crate) fn render<'scope>(
                                     }
 
                                     let update = (
-                                        (oid, output_index, Err(error.into())),
+                                        ((oid, output_index, Err(error.into())), None),
                                         data_cap_set[0].time().clone(),
                                         Diff::ONE,
                                     );
@@ -596,6 +735,7 @@ pub(crate) fn render<'scope>(
                     if rewinds.is_empty() {
                         data_cap_set.downgrade([&data_upper]);
                     }
+                    truncate_cap_set.downgrade([&data_upper]);
                 }
             }
             // We never expect the replication stream to gracefully end
@@ -605,6 +745,7 @@ pub(crate) fn render<'scope>(
 
     // We now process the slot updates and apply the cast expressions
     let mut final_row = Row::default();
+    let mut metadata_row = Row::default();
     let mut datum_vec = DatumVec::new();
     let mut next_worker = (0..u64::cast_from(scope.peers()))
         // Round robin on 1000-records basis to avoid creating tiny containers when there are a
@@ -617,9 +758,19 @@ pub(crate) fn render<'scope>(
             move |input, output| {
                 input.for_each_time(|time, data| {
                     let mut session = output.session(&time);
-                    for ((oid, output_index, event), time, diff) in
+                    for (((oid, output_index, event), tx_info), time, diff) in
                         data.flat_map(|data| data.drain(..))
                     {
+                        if message_outputs.contains(&output_index) {
+                            // Logical decoding messages are decoded into their final shape.
+                            let event = event.map(|row| SourceMessage {
+                                key: Row::default(),
+                                value: row,
+                                metadata: Row::default(),
+                            });
+                            session.give(((output_index, event), time, diff));
+                            continue;
+                        }
                         let output = &table_info
                             .get(&oid)
                             .and_then(|outputs| outputs.get(&output_index))
@@ -627,10 +778,15 @@ pub(crate) fn render<'scope>(
                         let event = event.and_then(|row| {
                             let datums = datum_vec.borrow_with(&row);
                             super::cast_row(&output.casts, &datums, &mut final_row)?;
+                            super::pack_metadata(
+                                &output.metadata_columns,
+                                tx_info.as_ref(),
+                                &mut metadata_row,
+                            );
                             Ok(SourceMessage {
                                 key: Row::default(),
                                 value: final_row.clone(),
-                                metadata: Row::default(),
+                                metadata: metadata_row.clone(),
                             })
                         });
 
@@ -645,12 +801,28 @@ pub(crate) fn render<'scope>(
 
     (
         replication_updates,
+        truncate_stream,
         probe_stream,
         errors,
         button.press_on_drop(),
     )
 }
 
+/// Returns the `(oid, output_index)` pairs of all table outputs and logical decoding message
+/// outputs.
+fn all_outputs<'a>(
+    table_info: &'a BTreeMap<u32, BTreeMap<usize, SourceOutputInfo>>,
+    message_outputs: &'a BTreeSet<usize>,
+) -> impl Iterator<Item = (u32, usize)> + 'a {
+    let tables = table_info
+        .iter()
+        .flat_map(|(oid, outputs)| outputs.keys().map(|output_index| (*oid, *output_index)));
+    let messages = message_outputs
+        .iter()
+        .map(|output_index| (MESSAGES_OID, *output_index));
+    tables.chain(messages)
+}
+
 /// Produces the logical replication stream while taking care of regularly sending standby
 /// keepalive messages with the provided `uppers` stream.
 ///
@@ -667,6 +839,7 @@ async fn raw_stream<'a>(
     probe_output: &'a AsyncOutputHandle<MzOffset, CapacityContainerBuilder<Vec<Probe<MzOffset>>>>,
     probe_cap: &'a Capability<MzOffset>,
     is_physical_replica: bool,
+    messages: bool,
 ) -> Result<
     Result<impl AsyncStream<Item = Result<LogicalReplMsg, TransientError>> + 'a, DefiniteError>,
     TransientError,
@@ -749,12 +922,21 @@ async fn raw_stream<'a>(
     // Postgres will return all transactions that commit *at or after* after the provided LSN,
     // following the timely upper semantics.
     let lsn = PgLsn::from(resume_lsn.offset);
-    let query = sql!(
-        "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" '1', \"publication_names\" {})",
-        Sql::ident(slot),
-        Sql::raw_unchecked(lsn.to_string()),
-        Sql::literal(publication)
-    );
+    let query = if messages {
+        sql!(
+            "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" '1', \"publication_names\" {}, \"messages\" 'true')",
+            Sql::ident(slot),
+            Sql::raw_unchecked(lsn.to_string()),
+            Sql::literal(publication)
+        )
+    } else {
+        sql!(
+            "START_REPLICATION SLOT {} LOGICAL {} (\"proto_version\" '1', \"publication_names\" {})",
+            Sql::ident(slot),
+            Sql::raw_unchecked(lsn.to_string()),
+            Sql::literal(publication)
+        )
+    };
     let copy_stream = match replication_client.copy_both_simple(query.as_str()).await {
         Ok(copy_stream) => copy_stream,
         Err(err) if err.code() == Some(&SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE) => {
@@ -891,9 +1073,10 @@ fn extract_transaction<'a>(
     metadata_client: &'a Client,
     commit_lsn: MzOffset,
     table_info: &'a mut BTreeMap<u32, BTreeMap<usize, SourceOutputInfo>>,
+    message_outputs: &'a BTreeSet<usize>,
     metrics: &'a PgSourceMetrics,
     publication: &'a str,
-) -> impl AsyncStream<Item = Result<DecodedRow, TransientError>> + 'a {
+) -> impl AsyncStream<Item = Result<TransactionEvent, TransientError>> + 'a {
     use LogicalReplicationMessage::*;
     let mut row = Row::default();
     async_stream::try_stream!({
@@ -927,7 +1110,7 @@ fn extract_transaction<'a>(
                         };
                         let datums = projection.iter().map(|idx| &tuple_data[*idx]);
                         let row = unpack_tuple(datums, &mut row);
-                        yield (rel, *output, row, Diff::ONE);
+                        yield TransactionEvent::Row((rel, *output, row, Diff::ONE));
                     }
                 }
                 Update(body) => match body.old_tuple() {
@@ -953,19 +1136,19 @@ fn extract_transaction<'a>(
                             let old_row = unpack_tuple(old_tuple, &mut row);
                             let new_row = unpack_tuple(new_tuple, &mut row);
 
-                            yield (rel, *output, old_row, Diff::MINUS_ONE);
-                            yield (rel, *output, new_row, Diff::ONE);
+                            yield TransactionEvent::Row((rel, *output, old_row, Diff::MINUS_ONE));
+                            yield TransactionEvent::Row((rel, *output, new_row, Diff::ONE));
                         }
                     }
                     None => {
                         let rel = body.rel_id();
                         for (output, _) in table_info.get(&rel).into_iter().flatten() {
-                            yield (
+                            yield TransactionEvent::Row((
                                 rel,
                                 *output,
                                 Err(DefiniteError::DefaultReplicaIdentity),
                                 Diff::ONE,
-                            );
+                            ));
                         }
                     }
                 },
@@ -979,24 +1162,42 @@ fn extract_transaction<'a>(
                             };
                             let datums = projection.iter().map(|idx| &old_tuple.tuple_data()[*idx]);
                             let row = unpack_tuple(datums, &mut row);
-                            yield (rel, *output, row, Diff::MINUS_ONE);
+                            yield TransactionEvent::Row((rel, *output, row, Diff::MINUS_ONE));
                         }
                     }
                     None => {
                         let rel = body.rel_id();
                         for (output, _) in table_info.get(&rel).into_iter().flatten() {
-                            yield (
+                            yield TransactionEvent::Row((
                                 rel,
                                 *output,
                                 Err(DefiniteError::DefaultReplicaIdentity),
                                 Diff::ONE,
-                            );
+                            ));
                         }
                     }
                 },
                 Relation(body) => {
                     let rel_id = body.rel_id();
                     if let Some(outputs) = table_info.get_mut(&body.rel_id()) {
+                        // Outputs that follow upstream column additions must not ingest any
+                        // changes made after a column was added until they have been altered to
+                        // include it, so we stop here and retry until that has happened.
+                        for info in outputs.values() {
+                            if info.schema_change_policy != SchemaChangePolicy::AddColumns {
+                                continue;
+                            }
+                            let has_new_columns = body.columns().iter().any(|col| {
+                                let name = col.name().unwrap();
+                                !info.desc.columns.iter().any(|c| c.name == name)
+                                    && !info.exclude_columns.iter().any(|c| c == name)
+                            });
+                            if has_new_columns {
+                                let name = format!("{}.{}", info.desc.namespace, info.desc.name);
+                                Err(TransientError::PendingSchemaChange(name))?;
+                            }
+                        }
+
                         // Because the replication stream doesn't include columns' attnums, we need
                         // to check the current local schema against the current remote schema to
                         // ensure e.g. we haven't received a schema update with the same terminal
@@ -1043,24 +1244,44 @@ fn extract_transaction<'a>(
                             info.projection = Some(projection);
                         }
                         for schema_error in schema_errors {
-                            yield schema_error;
+                            yield TransactionEvent::Row(schema_error);
                         }
                     }
                 }
                 Truncate(body) => {
                     for &rel_id in body.rel_ids() {
                         if let Some(outputs) = table_info.get_mut(&rel_id) {
-                            for (output, _) in std::mem::take(outputs) {
-                                yield (
+                            // Outputs that retract their contents on truncation stay around, all
+                            // others can no longer be maintained.
+                            let (retracting, truncated) = std::mem::take(outputs)
+                                .into_iter()
+                                .partition(|(_, info)| info.retract_on_truncate);
+                            *outputs = retracting;
+                            for output in outputs.keys() {
+                                yield TransactionEvent::Truncate(rel_id, *output);
+                            }
+                            for (output, _) in truncated {
+                                yield TransactionEvent::Row((
                                     rel_id,
                                     output,
                                     Err(DefiniteError::TableTruncated),
                                     Diff::ONE,
-                                );
+                                ));
                             }
                         }
                     }
                 }
+                Message(body) if !message_outputs.is_empty() => {
+                    let row = unpack_message(&body, &mut row)?;
+                    for output in message_outputs.iter() {
+                        yield TransactionEvent::Row((
+                            MESSAGES_OID,
+                            *output,
+                            Ok(row.clone()),
+                            Diff::ONE,
+                        ));
+                    }
+                }
                 Commit(body) => {
                     if commit_lsn != body.commit_lsn().into() {
                         Err(TransientError::InvalidTransaction)?
@@ -1070,7 +1291,7 @@ fn extract_transaction<'a>(
                 // TODO: We should handle origin messages and emit an error as they indicate that
                 // the upstream performed a point in time restore so all bets are off about the
                 // continuity of the stream.
-                Origin(_) | Type(_) => metrics.ignored.inc(),
+                Origin(_) | Type(_) | Message(_) => metrics.ignored.inc(),
                 Begin(_) => Err(TransientError::NestedTransaction)?,
                 // The enum is marked as non_exhaustive. Better to be conservative
                 _ => Err(TransientError::UnknownLogicalReplicationMessage)?,
@@ -1080,6 +1301,20 @@ fn extract_transaction<'a>(
     })
 }
 
+/// Unpacks a logical decoding message into a row of the shape of
+/// [`PG_LOGICAL_MESSAGES_DESC`](mz_storage_types::sources::postgres::PG_LOGICAL_MESSAGES_DESC).
+fn unpack_message(body: &MessageBody, row: &mut Row) -> Result<Row, TransientError> {
+    let prefix = body
+        .prefix()
+        .map_err(|err| TransientError::Generic(err.into()))?;
+    row.packer().extend([
+        Datum::String(prefix),
+        Datum::Bytes(body.content()),
+        Datum::UInt64(body.lsn()),
+    ]);
+    Ok(row.clone())
+}
+
 /// Unpacks an iterator of TupleData into a list of nullable bytes or an error if this can't be
 /// done.
 #[inline]
@@ -0,0 +1,277 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Renders the operator that completes the retractions of the outputs of a
+//! [`PostgresSourceConnection`] ingestion that the replication stream can't fully describe: those
+//! of outputs that were created with `RETRACT ON TRUNCATE`, and those of outputs that include
+//! transaction metadata.
+//!
+//! ```text
+//!    data      truncation
+//!    updates   events
+//!      │          │
+//!  ╭───┴────╮ ╭───┴────╮
+//!  │exchange│ │exchange│ (one worker per output)
+//!  ╰───┬────╯ ╰───┬────╯
+//!    ┏━v━━━━━━━━━━v━┓
+//!    ┃   output     ┃
+//!    ┃ retractions  ┃
+//!    ┗━━━━━━┯━━━━━━━┛
+//!           │ data
+//!           v updates
+//! ```
+//!
+//! The operator keeps the [`OutputContents`] of every such output and applies the updates of each
+//! LSN to them once the LSN is complete, in order.
+//!
+//! The replication stream only tells us *that* a table was truncated, not what it contained. When
+//! the replication reader reports that a table was truncated at some LSN, the contents accumulated
+//! before that LSN are retracted at it. Updates made by the truncating transaction before the
+//! `TRUNCATE` itself are retracted at the same LSN by the replication reader, so the updates at a
+//! truncation LSN are applied to the contents after retracting them.
+//!
+//! Retractions of replicated rows carry the metadata of the transaction that removed the row, but
+//! must carry the metadata of the row version they retract. The updates of outputs that include
+//! transaction metadata are therefore held back until their LSN is complete, and retractions are
+//! emitted with the metadata of a row of the contents with the same value. Insertions are applied
+//! first, so that a row a transaction inserts and then removes is retracted with the metadata of
+//! that transaction.
+//!
+//! Errors are passed through and never retracted. If the contents of an output can't be read,
+//! e.g. because its persist shard was compacted past the point the output resumes from, the
+//! operator reports a transient error that restarts the source.
+//!
+//! [`PostgresSourceConnection`]: mz_storage_types::sources::PostgresSourceConnection
+
+use std::collections::BTreeMap;
+
+use differential_dataflow::AsCollection;
+use mz_ore::cast::CastFrom;
+use mz_repr::{Diff, GlobalId, Row};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sources::MzOffset;
+use mz_timely_util::builder_async::{
+    Event as AsyncEvent, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::core::Map;
+use timely::dataflow::operators::{Capability, CapabilitySet};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp};
+
+use crate::source::RawSourceCreationConfig;
+use crate::source::contents::OutputContents;
+use crate::source::postgres::{ReplicationError, TransientError};
+use crate::source::types::{SourceMessage, StackedCollection};
+
+/// An output whose contents are needed to complete its retractions.
+#[derive(Clone, Debug)]
+pub(crate) struct RetractingOutput {
+    pub(crate) export_id: GlobalId,
+    /// The point from which the output resumes. Updates before it are already reflected in the
+    /// persist shard of the output.
+    pub(crate) resume_upper: Antichain<MzOffset>,
+    /// Whether the contents of the output are retracted when its upstream table is truncated.
+    pub(crate) retract_on_truncate: bool,
+    /// The number of transaction metadata columns of the output.
+    pub(crate) metadata_len: usize,
+}
+
+/// Renders the output retraction operator. See the module documentation for more information.
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, MzOffset>,
+    config: RawSourceCreationConfig,
+    updates: StackedCollection<'scope, MzOffset, (usize, Result<SourceMessage, DataflowError>)>,
+    truncates: StreamVec<'scope, MzOffset, usize>,
+    outputs: BTreeMap<usize, RetractingOutput>,
+) -> (
+    StackedCollection<'scope, MzOffset, (usize, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, MzOffset, ReplicationError>,
+    PressOnDropButton,
+) {
+    let op_name = format!("OutputRetractions({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope.clone());
+
+    let (output, output_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+    let mut updates_input = builder.new_input_for(
+        updates.inner,
+        Exchange::new(|((output_index, _), _, _)| u64::cast_from(*output_index)),
+        &output,
+    );
+    let mut truncates_input = builder.new_input_for(
+        truncates,
+        Exchange::new(|output_index: &usize| u64::cast_from(*output_index)),
+        &output,
+    );
+
+    let worker_count = u64::cast_from(config.worker_count);
+    let worker_id = u64::cast_from(config.worker_id);
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        Box::pin(async move {
+            // Everything we emit is emitted at the time of an input.
+            let [output_cap_set]: &mut [_; 1] = caps.try_into().unwrap();
+            *output_cap_set = CapabilitySet::new();
+
+            // The contents of the outputs that were routed to this worker. Failing to read them is
+            // the only error, and happens before we hold any capability.
+            let mut contents: BTreeMap<usize, OutputContents> = BTreeMap::new();
+            for (output_index, info) in outputs.iter() {
+                if u64::cast_from(*output_index) % worker_count == worker_id {
+                    let output_contents =
+                        OutputContents::hydrate(&config, info.export_id, info.metadata_len).await?;
+                    contents.insert(*output_index, output_contents);
+                }
+            }
+
+            // The updates that have yet to be applied to the contents, and whether each of them has
+            // yet to be emitted.
+            let mut pending_updates: BTreeMap<
+                MzOffset,
+                (
+                    Capability<MzOffset>,
+                    Vec<(usize, SourceMessage, Diff, bool)>,
+                ),
+            > = BTreeMap::new();
+            let mut pending_truncates: BTreeMap<MzOffset, (Capability<MzOffset>, Vec<usize>)> =
+                BTreeMap::new();
+            let mut updates_upper = Antichain::from_elem(MzOffset::minimum());
+            let mut truncates_upper = Antichain::from_elem(MzOffset::minimum());
+
+            while !(updates_upper.is_empty() && truncates_upper.is_empty()) {
+                tokio::select! {
+                    _ = updates_input.ready() => {
+                        while let Some(event) = updates_input.next_sync() {
+                            match event {
+                                AsyncEvent::Data(cap, data) => {
+                                    for ((output_index, message), time, diff) in data {
+                                        let info = outputs.get(&output_index);
+                                        let (info, message) = match (info, message) {
+                                            (Some(info), Ok(message))
+                                                if info.resume_upper.less_equal(&time) =>
+                                            {
+                                                (info, message)
+                                            }
+                                            (_, message) => {
+                                                output.give(
+                                                    &cap,
+                                                    ((output_index, message), time, diff),
+                                                );
+                                                continue;
+                                            }
+                                        };
+                                        let held_back = info.metadata_len > 0;
+                                        if !held_back {
+                                            let update =
+                                                ((output_index, Ok(message.clone())), time, diff);
+                                            output.give(&cap, update);
+                                        }
+                                        pending_updates
+                                            .entry(time)
+                                            .or_insert_with(|| (cap.delayed(&time), vec![]))
+                                            .1
+                                            .push((output_index, message, diff, held_back));
+                                    }
+                                }
+                                AsyncEvent::Progress(upper) => updates_upper = upper,
+                            }
+                        }
+                    }
+                    _ = truncates_input.ready() => {
+                        while let Some(event) = truncates_input.next_sync() {
+                            match event {
+                                AsyncEvent::Data(cap, data) => {
+                                    let time = *cap.time();
+                                    // Truncations before the resumption point of an output are
+                                    // already reflected in its persist shard.
+                                    let truncated = data.into_iter().filter(|output_index| {
+                                        let info = &outputs[output_index];
+                                        info.retract_on_truncate
+                                            && info.resume_upper.less_equal(&time)
+                                    });
+                                    pending_truncates
+                                        .entry(time)
+                                        .or_insert_with(|| (cap, vec![]))
+                                        .1
+                                        .extend(truncated);
+                                }
+                                AsyncEvent::Progress(upper) => truncates_upper = upper,
+                            }
+                        }
+                    }
+                }
+
+                // Apply all the times that are complete in both inputs, in order.
+                let frontier = Antichain::from_iter(
+                    updates_upper
+                        .iter()
+                        .chain(truncates_upper.iter())
+                        .min()
+                        .copied(),
+                );
+                loop {
+                    let next_update = pending_updates.keys().next();
+                    let next_truncate = pending_truncates.keys().next();
+                    let Some(time) = next_update.into_iter().chain(next_truncate).min().copied()
+                    else {
+                        break;
+                    };
+                    if frontier.less_equal(&time) {
+                        break;
+                    }
+                    if let Some((cap, truncated)) = pending_truncates.remove(&time) {
+                        for output_index in truncated {
+                            let Some(output_contents) = contents.get_mut(&output_index) else {
+                                continue;
+                            };
+                            for (value, metadata, diff) in output_contents.drain() {
+                                let message = SourceMessage {
+                                    key: Row::default(),
+                                    value,
+                                    metadata,
+                                };
+                                output.give(&cap, ((output_index, Ok(message)), time, -diff));
+                            }
+                        }
+                    }
+                    let Some((cap, updates)) = pending_updates.remove(&time) else {
+                        continue;
+                    };
+                    let (insertions, retractions): (Vec<_>, Vec<_>) = updates
+                        .into_iter()
+                        .partition(|(_, _, diff, _)| diff.is_positive());
+                    for (output_index, mut message, diff, held_back) in
+                        insertions.into_iter().chain(retractions)
+                    {
+                        let Some(output_contents) = contents.get_mut(&output_index) else {
+                            continue;
+                        };
+                        if held_back && diff.is_negative() {
+                            output_contents
+                                .retraction_metadata(&message.value, &mut message.metadata);
+                        }
+                        output_contents.update(&message.value, &message.metadata, diff);
+                        if held_back {
+                            output.give(&cap, ((output_index, Ok(message)), time, diff));
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let errors = transient_errors.map(ReplicationError::from);
+    (
+        output_stream.as_collection(),
+        errors,
+        button.press_on_drop(),
+    )
+}
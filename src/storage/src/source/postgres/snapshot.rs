@@ -752,6 +752,7 @@ pub(crate) fn render<'scope>(
     // We now decode the COPY protocol and apply the cast expressions
     let mut text_row = Row::default();
     let mut final_row = Row::default();
+    let mut metadata_row = Row::default();
     let mut datum_vec = DatumVec::new();
     let snapshot_updates = raw_data
         .unary(Pipeline, "PgCastSnapshotRows", |_, _| {
@@ -773,10 +774,15 @@ pub(crate) fn render<'scope>(
                                 decode_copy_row(bytes, output.casts.len(), &mut text_row)?;
                                 let datums = datum_vec.borrow_with(&text_row);
                                 super::cast_row(&output.casts, &datums, &mut final_row)?;
+                                super::pack_metadata(
+                                    &output.metadata_columns,
+                                    None,
+                                    &mut metadata_row,
+                                );
                                 Ok(SourceMessage {
                                     key: Row::default(),
                                     value: final_row.clone(),
-                                    metadata: Row::default(),
+                                    metadata: metadata_row.clone(),
                                 })
                             });
 
@@ -308,6 +308,11 @@ mod tests {
         (operator, initial_batch)
     }
 
+    /// Returns partition `pid` of the only topic of a source.
+    fn pid(pid: i32) -> kafka::KafkaPartition {
+        kafka::KafkaPartition::from(pid)
+    }
+
     /// Generates a [`kafka::NativeFrontier`] antichain where all the provided
     /// partitions are at the specified offset and the gaps in between are filled with range
     /// timestamps at offset zero.
@@ -318,6 +323,7 @@ mod tests {
         let mut frontier = Antichain::new();
         let mut prev = RB::NegInfinity;
         for (pid, offset) in items {
+            let pid = kafka::KafkaPartition::from(pid);
             assert!(prev < RB::before(pid));
             let gap = Partitioned::new_range(prev, RB::before(pid), MzOffset::from(0));
             frontier.extend([gap, Partitioned::new_singleton(RB::exact(pid), offset)]);
@@ -349,12 +355,12 @@ mod tests {
         let mut expected_batch: ReclockBatch<_, Timestamp> = ReclockBatch {
             updates: vec![
                 (
-                    Partitioned::new_range(RB::NegInfinity, RB::before(0), MzOffset::from(0)),
+                    Partitioned::new_range(RB::NegInfinity, RB::before(pid(0)), MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
                 (
-                    Partitioned::new_range(RB::after(0), RB::PosInfinity, MzOffset::from(0)),
+                    Partitioned::new_range(RB::after(pid(0)), RB::PosInfinity, MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
@@ -364,7 +370,7 @@ mod tests {
                     Diff::MINUS_ONE,
                 ),
                 (
-                    Partitioned::new_singleton(RB::exact(0), MzOffset::from(4)),
+                    Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(4)),
                     1000.into(),
                     Diff::ONE,
                 ),
@@ -439,27 +445,27 @@ mod tests {
         let mut expected_batch: ReclockBatch<_, Timestamp> = ReclockBatch {
             updates: vec![
                 (
-                    Partitioned::new_range(RB::NegInfinity, RB::before(0), MzOffset::from(0)),
+                    Partitioned::new_range(RB::NegInfinity, RB::before(pid(0)), MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
                 (
-                    Partitioned::new_range(RB::after(0), RB::PosInfinity, MzOffset::from(0)),
+                    Partitioned::new_range(RB::after(pid(0)), RB::PosInfinity, MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
                 (
-                    Partitioned::new_singleton(RB::exact(0), MzOffset::from(3)),
+                    Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(3)),
                     1000.into(),
                     Diff::ONE,
                 ),
                 (
-                    Partitioned::new_singleton(RB::exact(0), MzOffset::from(3)),
+                    Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(3)),
                     2000.into(),
                     Diff::MINUS_ONE,
                 ),
                 (
-                    Partitioned::new_singleton(RB::exact(0), MzOffset::from(5)),
+                    Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(5)),
                     2000.into(),
                     Diff::ONE,
                 ),
@@ -491,12 +497,12 @@ mod tests {
         let mut expected_batch: ReclockBatch<_, Timestamp> = ReclockBatch {
             updates: vec![
                 (
-                    Partitioned::new_range(RB::NegInfinity, RB::before(0), MzOffset::from(0)),
+                    Partitioned::new_range(RB::NegInfinity, RB::before(pid(0)), MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
                 (
-                    Partitioned::new_range(RB::after(0), RB::PosInfinity, MzOffset::from(0)),
+                    Partitioned::new_range(RB::after(pid(0)), RB::PosInfinity, MzOffset::from(0)),
                     1000.into(),
                     Diff::ONE,
                 ),
@@ -506,7 +512,7 @@ mod tests {
                     Diff::MINUS_ONE,
                 ),
                 (
-                    Partitioned::new_singleton(RB::exact(0), MzOffset::from(3)),
+                    Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(3)),
                     1000.into(),
                     Diff::ONE,
                 ),
@@ -529,12 +535,12 @@ mod tests {
             .await;
         expected_batch.updates.extend([
             (
-                Partitioned::new_singleton(RB::exact(0), MzOffset::from(3)),
+                Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(3)),
                 11000.into(),
                 Diff::MINUS_ONE,
             ),
             (
-                Partitioned::new_singleton(RB::exact(0), MzOffset::from(5)),
+                Partitioned::new_singleton(RB::exact(pid(0)), MzOffset::from(5)),
                 11000.into(),
                 Diff::ONE,
             ),
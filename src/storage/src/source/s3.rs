@@ -0,0 +1,319 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of an [`S3SourceConnection`].
+//!
+//! An S3 source periodically lists the objects under its URI and ingests every
+//! object whose key matches its pattern exactly once, decoding it with the
+//! same CSV and Parquet decoders that `COPY FROM` uses. Every table of the
+//! source decodes the object according to its own columns.
+//!
+//! # Timestamps
+//!
+//! The source is timestamped with [`S3Timestamp`], in which every object is
+//! its own partition identified by a hash of its key. The data of an object is
+//! emitted at offset `0` of its partition, and once the object has been
+//! ingested its partition advances to offset `1`. The frontier of the source
+//! therefore records exactly which objects have been ingested, which is what
+//! allows a restarted source to skip them. Objects that are rewritten under a
+//! key that has already been ingested are not ingested again.
+//!
+//! Since the frontier holds an element for every ingested object, sources are
+//! expected to ingest up to a few hundred thousand objects.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::rc::Rc;
+
+use differential_dataflow::AsCollection;
+use itertools::Itertools;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Diff, GlobalId, RelationDesc};
+use mz_storage_operators::oneshot_source::StorageErrorX;
+use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::s3::S3Timestamp;
+use mz_storage_types::sources::{
+    MzOffset, S3SourceConnection, SourceExport, SourceExportDetails, SourceTimestamp,
+};
+use mz_timely_util::builder_async::PressOnDropButton;
+use mz_timely_util::order::{Partitioned, Step};
+use sha2::{Digest, Sha256};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::Concat;
+use timely::dataflow::operators::core::Partition;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+use uuid::Uuid;
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::source::RawSourceCreationConfig;
+use crate::source::types::{Probe, SourceMessage, SourceRender, StackedCollection};
+
+mod progress;
+mod reader;
+
+#[derive(Debug, Clone)]
+struct SourceOutputInfo {
+    /// The columns that objects are decoded into.
+    desc: RelationDesc,
+    /// Upper to resume ingestion from.
+    resume_upper: Antichain<S3Timestamp>,
+    /// An index to split the timely stream.
+    partition_index: u64,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReplicationError {
+    #[error(transparent)]
+    Transient(#[from] Rc<TransientError>),
+    #[error(transparent)]
+    DefiniteError(#[from] Rc<DefiniteError>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransientError {
+    #[error("{0}")]
+    S3(StorageErrorX),
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+}
+
+impl From<StorageErrorX> for TransientError {
+    fn from(err: StorageErrorX) -> Self {
+        TransientError::S3(err)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DefiniteError {
+    #[error("invalid pattern {0}: {1}")]
+    InvalidPattern(String, String),
+}
+
+impl From<DefiniteError> for DataflowError {
+    fn from(val: DefiniteError) -> Self {
+        let msg = val.to_string().into();
+        DataflowError::SourceError(Box::new(SourceError {
+            error: SourceErrorDetails::Other(msg),
+        }))
+    }
+}
+
+/// Returns the partition of the object with the given key.
+fn object_id(key: &str) -> Uuid {
+    let digest = Sha256::digest(key.as_bytes());
+    let bytes: [u8; 16] = digest[..16].try_into().expect("digest is 32 bytes");
+    Uuid::from_bytes(bytes)
+}
+
+/// Returns the objects that have been ingested at `frontier`.
+fn ingested_objects(frontier: &[S3Timestamp]) -> BTreeSet<Uuid> {
+    frontier
+        .iter()
+        .filter(|ts| *ts.timestamp() > MzOffset::from(0))
+        .filter_map(|ts| ts.interval().singleton().copied())
+        .collect()
+}
+
+/// Returns the frontier at which exactly the objects in `ingested` have been ingested.
+fn ingested_frontier(ingested: &BTreeSet<Uuid>) -> Antichain<S3Timestamp> {
+    let mut frontier = Antichain::new();
+    // The lowest partition that hasn't been added to the frontier yet.
+    let mut lower = Some(Uuid::nil());
+    for id in ingested {
+        let Some(range_lower) = lower else {
+            unreachable!("no partitions after {id}");
+        };
+        if let Some(range_upper) = id.backward_checked(1)
+            && range_lower <= range_upper
+        {
+            frontier.insert(Partitioned::new_range(
+                range_lower,
+                range_upper,
+                MzOffset::from(0),
+            ));
+        }
+        frontier.insert(Partitioned::new_singleton(*id, MzOffset::from(1)));
+        lower = id.forward_checked(1);
+    }
+    if let Some(range_lower) = lower {
+        let range_upper = Uuid::from_u128(u128::MAX);
+        frontier.insert(Partitioned::new_range(
+            range_lower,
+            range_upper,
+            MzOffset::from(0),
+        ));
+    }
+    frontier
+}
+
+impl SourceRender for S3SourceConnection {
+    type Time = S3Timestamp;
+
+    const STATUS_NAMESPACE: StatusNamespace = StatusNamespace::S3;
+
+    fn render<'scope>(
+        self,
+        scope: Scope<'scope, S3Timestamp>,
+        config: &RawSourceCreationConfig,
+        resume_uppers: impl futures::Stream<Item = Antichain<S3Timestamp>> + 'static,
+        _start_signal: impl Future<Output = ()> + 'static,
+    ) -> (
+        // Timely Collection for each Source Export defined in the provided `config`.
+        BTreeMap<
+            GlobalId,
+            StackedCollection<'scope, S3Timestamp, Result<SourceMessage, DataflowError>>,
+        >,
+        StreamVec<'scope, S3Timestamp, HealthStatusMessage>,
+        StreamVec<'scope, S3Timestamp, Probe<S3Timestamp>>,
+        Vec<PressOnDropButton>,
+    ) {
+        // Collect the source outputs that we will be exporting.
+        let mut source_outputs = BTreeMap::new();
+        for (idx, (id, export)) in config.source_exports.iter().enumerate() {
+            let SourceExport {
+                details,
+                storage_metadata,
+                data_config: _,
+            } = export;
+
+            match details {
+                SourceExportDetails::S3(_) => {}
+                // This is an export that doesn't need any data output to it.
+                SourceExportDetails::None => continue,
+                other => unreachable!("unexpected source export details: {other:?}"),
+            }
+
+            let resume_upper = config
+                .source_resume_uppers
+                .get(id)
+                .expect("missing resume upper")
+                .iter()
+                .map(S3Timestamp::decode_row);
+
+            let output_info = SourceOutputInfo {
+                desc: storage_metadata.relation_desc.clone(),
+                resume_upper: Antichain::from_iter(resume_upper),
+                partition_index: u64::cast_from(idx),
+            };
+            source_outputs.insert(*id, output_info);
+        }
+
+        let metrics = config.metrics.get_s3_source_metrics(config.id);
+
+        let (repl_updates, repl_errs, repl_token) = reader::render(
+            scope.clone(),
+            config.clone(),
+            source_outputs,
+            self.clone(),
+            metrics,
+        );
+
+        let (progress_errs, progress_probes, progress_token) =
+            progress::render(scope.clone(), config.clone(), self.clone(), resume_uppers);
+
+        let partition_count = u64::cast_from(config.source_exports.len());
+        let data_streams: Vec<_> = repl_updates
+            .inner
+            .partition::<CapacityContainerBuilder<_>, _, _>(
+                partition_count,
+                move |((partition_idx, data), time, diff): (
+                    (u64, Result<SourceMessage, DataflowError>),
+                    S3Timestamp,
+                    Diff,
+                )| { (partition_idx, (data, time, diff)) },
+            );
+        let mut data_collections = BTreeMap::new();
+        for (id, data_stream) in config.source_exports.keys().zip_eq(data_streams) {
+            data_collections.insert(*id, data_stream.as_collection());
+        }
+
+        let export_ids = config.source_exports.keys().copied();
+        let health_init = export_ids
+            .map(Some)
+            .chain(std::iter::once(None))
+            .map(|id| HealthStatusMessage {
+                id,
+                namespace: Self::STATUS_NAMESPACE,
+                update: HealthStatusUpdate::Running,
+            })
+            .collect::<Vec<_>>()
+            .to_stream(scope);
+
+        let health_errs = repl_errs.concat(progress_errs).map(move |err| {
+            // This update will cause the dataflow to restart
+            let err_string = err.display_with_causes().to_string();
+            let update = HealthStatusUpdate::halting(err_string, None);
+
+            HealthStatusMessage {
+                id: None,
+                namespace: Self::STATUS_NAMESPACE,
+                update,
+            }
+        });
+        let health = health_init.concat(health_errs);
+
+        (
+            data_collections,
+            health,
+            progress_probes,
+            vec![repl_token, progress_token],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use timely::PartialOrder;
+    use timely::progress::Timestamp;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_ingested_frontier_roundtrip() {
+        let ingested: BTreeSet<_> = ["events/a.csv", "events/b.csv", "events/c.csv"]
+            .into_iter()
+            .map(object_id)
+            .collect();
+        let frontier = ingested_frontier(&ingested);
+        // Every ingested object is a singleton, with a range in between each of them.
+        assert_eq!(frontier.len(), 2 * ingested.len() + 1);
+        assert_eq!(ingested_objects(frontier.elements()), ingested);
+
+        assert_eq!(
+            ingested_frontier(&BTreeSet::new()),
+            Antichain::from_elem(S3Timestamp::minimum())
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_ingested_frontier_advances() {
+        let a = object_id("a.parquet");
+        let b = object_id("b.parquet");
+        let before = ingested_frontier(&BTreeSet::from([a]));
+        let after = ingested_frontier(&BTreeSet::from([a, b]));
+        assert!(PartialOrder::less_than(&before, &after));
+
+        // The data of an object is emitted at a time beyond the frontier before it is ingested,
+        // but not beyond the frontier after.
+        let time = Partitioned::new_singleton(b, MzOffset::from(0));
+        assert!(before.less_equal(&time));
+        assert!(!after.less_equal(&time));
+    }
+
+    #[mz_ore::test]
+    fn test_ingested_frontier_extremes() {
+        let ingested = BTreeSet::from([Uuid::nil(), Uuid::from_u128(u128::MAX)]);
+        let frontier = ingested_frontier(&ingested);
+        assert_eq!(frontier.len(), 3);
+        assert_eq!(ingested_objects(frontier.elements()), ingested);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A "non-critical" operator that tracks the progress of an [`S3SourceConnection`].
+//!
+//! The operator does the following:
+//!
+//! * At some cadence `timestamp_interval` will list the objects of the source,
+//!   emit the frontier at which all of them have been ingested as the upstream
+//!   frontier, and update `SourceStatistics` with the number of known objects.
+//! * Listen to a provided [`futures::Stream`] of resume uppers, which represents
+//!   the durably committed upper for _all_ of the exports associated with this
+//!   source, and update `SourceStatistics` with the number of ingested objects.
+//!
+//! The objects observed by earlier listings are remembered, so that the
+//! upstream frontier never regresses when an ingested object is deleted.
+//!
+//! [`S3SourceConnection`]: mz_storage_types::sources::S3SourceConnection
+
+use std::collections::BTreeSet;
+
+use futures::StreamExt;
+use mz_ore::cast::CastFrom;
+use mz_storage_operators::oneshot_source::aws_source::AwsS3Source;
+use mz_storage_operators::oneshot_source::{
+    ObjectFilter, OneshotObject, OneshotSource, aws_s3_use_checksum,
+};
+use mz_storage_types::dyncfgs::ENFORCE_EXTERNAL_ADDRESSES;
+use mz_storage_types::oneshot_sources::ContentFilter;
+use mz_storage_types::sources::s3::S3Timestamp;
+use mz_storage_types::sources::{S3SourceConnection, SourceTimestamp};
+use mz_timely_util::builder_async::{OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton};
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::source::s3::{
+    ReplicationError, TransientError, ingested_frontier, ingested_objects, object_id,
+};
+use crate::source::types::Probe;
+use crate::source::{RawSourceCreationConfig, probe};
+
+/// Used as a partition ID to determine the worker that is responsible for
+/// handling progress.
+static PROGRESS_WORKER: &str = "progress";
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, S3Timestamp>,
+    config: RawSourceCreationConfig,
+    source: S3SourceConnection,
+    committed_uppers: impl futures::Stream<Item = Antichain<S3Timestamp>> + 'static,
+) -> (
+    StreamVec<'scope, S3Timestamp, ReplicationError>,
+    StreamVec<'scope, S3Timestamp, Probe<S3Timestamp>>,
+    PressOnDropButton,
+) {
+    let op_name = format!("S3Progress({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (probe_output, probe_stream) = builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        Box::pin(async move {
+            let [probe_cap]: &mut [_; 1] = caps.try_into().unwrap();
+
+            // Only a single worker is responsible for processing progress.
+            if !config.responsible_for(PROGRESS_WORKER) {
+                // Emit 0 to mark this worker as having started up correctly.
+                for stat in config.statistics.values() {
+                    stat.set_offset_known(0);
+                    stat.set_offset_committed(0);
+                }
+                return Ok(());
+            }
+
+            // An invalid pattern is reported by the reader.
+            let filter = match &source.pattern {
+                Some(pattern) => ContentFilter::Pattern(pattern.clone()),
+                None => ContentFilter::None,
+            };
+            let Ok(filter) = ObjectFilter::try_new(filter) else {
+                return Ok(());
+            };
+
+            // Every object that has been ingested before is known to exist upstream, even if it
+            // has been deleted since.
+            let mut known_objects: BTreeSet<_> = config
+                .source_resume_uppers
+                .values()
+                .flat_map(|upper| {
+                    let upper: Vec<_> = upper.iter().map(S3Timestamp::decode_row).collect();
+                    ingested_objects(&upper)
+                })
+                .collect();
+
+            let connection = source.connection.clone();
+            let use_checksum = aws_s3_use_checksum(&connection, &source.format);
+            let enforce_external_addresses =
+                ENFORCE_EXTERNAL_ADDRESSES.get(config.config.config_set());
+            let s3 = AwsS3Source::new(
+                connection,
+                source.connection_id,
+                config.config.connection_context.clone(),
+                source.uri.clone(),
+                use_checksum,
+                enforce_external_addresses,
+            );
+
+            let timestamp_interval = config.timestamp_interval;
+            let mut probe_ticker = probe::Ticker::new(move || timestamp_interval, config.now_fn);
+
+            let mut committed_uppers = std::pin::pin!(committed_uppers);
+            loop {
+                tokio::select! {
+                    probe_ts = probe_ticker.tick() => {
+                        for (object, _checksum) in s3.list().await? {
+                            if filter.filter::<AwsS3Source>(&object) {
+                                known_objects.insert(object_id(object.path()));
+                            }
+                        }
+                        let known = u64::cast_from(known_objects.len());
+                        for stat in config.statistics.values() {
+                            stat.set_offset_known(known);
+                        }
+                        let probe = Probe {
+                            probe_ts,
+                            upstream_frontier: ingested_frontier(&known_objects),
+                        };
+                        probe_output.give(&probe_cap[0], probe);
+                    },
+                    Some(committed_upper) = committed_uppers.next() => {
+                        if committed_upper.is_empty() {
+                            // It's possible that the source has been dropped, in which case this
+                            // can observe an empty upper. This operator should continue to loop
+                            // until the drop dataflow propagates.
+                            continue;
+                        }
+                        let committed = ingested_objects(committed_upper.elements());
+                        let committed = u64::cast_from(committed.len());
+                        for stat in config.statistics.values() {
+                            stat.set_offset_committed(committed);
+                        }
+                    }
+                };
+            }
+        })
+    });
+
+    let error_stream = transient_errors.map(ReplicationError::Transient);
+
+    (error_stream, probe_stream, button.press_on_drop())
+}
@@ -0,0 +1,345 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the reader of an [`S3SourceConnection`].
+//!
+//! Every worker lists the objects of the source and ingests the ones it is
+//! responsible for. A worker considers an object done as soon as it has
+//! listed it, if the object belongs to another worker, or once it has emitted
+//! all of its data otherwise. The frontier of each worker is the
+//! [`ingested_frontier`] of the objects it considers done, so the frontier of
+//! the operator only moves past an object once the responsible worker has
+//! ingested it.
+//!
+//! Objects are listed again every `s3_source_list_interval`, and every
+//! listing only ingests the objects that the source hasn't ingested before.
+//! An object must not be deleted before it has been ingested.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use mz_ore::cast::CastFrom;
+use mz_repr::{Diff, GlobalId, Row};
+use mz_storage_operators::oneshot_source::aws_source::{AwsS3Source, S3Checksum, S3Object};
+use mz_storage_operators::oneshot_source::csv::CsvDecoder;
+use mz_storage_operators::oneshot_source::parquet::ParquetFormat;
+use mz_storage_operators::oneshot_source::{
+    FormatKind, ObjectFilter, OneshotFormat, OneshotObject, OneshotSource, StorageErrorX,
+    StorageErrorXKind, aws_s3_use_checksum,
+};
+use mz_storage_types::dyncfgs::{ENFORCE_EXTERNAL_ADDRESSES, S3_SOURCE_LIST_INTERVAL};
+use mz_storage_types::errors::{DataflowError, DecodeError, DecodeErrorKind};
+use mz_storage_types::oneshot_sources::{ContentFilter, ContentFormat};
+use mz_storage_types::sources::s3::S3Timestamp;
+use mz_storage_types::sources::{MzOffset, S3SourceConnection};
+use mz_timely_util::builder_async::{
+    AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use mz_timely_util::containers::stack::FueledBuilder;
+use mz_timely_util::order::Partitioned;
+use timely::container::CapacityContainerBuilder;
+use timely::dataflow::operators::vec::Map;
+use timely::dataflow::operators::{Capability, CapabilitySet, Concat};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Timestamp;
+use uuid::Uuid;
+
+use crate::metrics::source::s3::S3SourceMetrics;
+use crate::source::RawSourceCreationConfig;
+use crate::source::s3::{
+    DefiniteError, ReplicationError, SourceOutputInfo, TransientError, ingested_frontier,
+    ingested_objects, object_id,
+};
+use crate::source::types::{FuelSize, SignaledFuture, SourceMessage, StackedCollection};
+
+/// How often the frontier is downgraded while objects are being ingested.
+const FRONTIER_DOWNGRADE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// An output of the source, along with the way it decodes objects.
+struct ReaderOutput {
+    partition_index: u64,
+    format: FormatKind,
+    /// The objects that this output has already ingested.
+    ingested: BTreeSet<Uuid>,
+}
+
+pub(crate) fn render<'scope>(
+    scope: Scope<'scope, S3Timestamp>,
+    config: RawSourceCreationConfig,
+    outputs: BTreeMap<GlobalId, SourceOutputInfo>,
+    source: S3SourceConnection,
+    metrics: S3SourceMetrics,
+) -> (
+    StackedCollection<'scope, S3Timestamp, (u64, Result<SourceMessage, DataflowError>)>,
+    StreamVec<'scope, S3Timestamp, ReplicationError>,
+    PressOnDropButton,
+) {
+    let op_name = format!("S3Reader({})", config.id);
+    let mut builder = AsyncOperatorBuilder::new(op_name, scope);
+
+    let (data_output, data_stream) = builder.new_output::<FueledBuilder<_>>();
+
+    // Captures DefiniteErrors that affect the entire source, including all outputs
+    let (definite_error_handle, definite_errors) =
+        builder.new_output::<CapacityContainerBuilder<_>>();
+
+    let (button, transient_errors) = builder.build_fallible::<TransientError, _>(move |caps| {
+        let busy_signal = Arc::clone(&config.busy_signal);
+        Box::pin(SignaledFuture::new(busy_signal, async move {
+            let [data_cap_set, definite_error_cap_set]: &mut [_; 2] = caps.try_into().unwrap();
+
+            let filter = match &source.pattern {
+                Some(pattern) => ContentFilter::Pattern(pattern.clone()),
+                None => ContentFilter::None,
+            };
+            let filter = match ObjectFilter::try_new(filter) {
+                Ok(filter) => filter,
+                Err(err) => {
+                    let pattern = source.pattern.clone().unwrap_or_default();
+                    let err = DefiniteError::InvalidPattern(pattern, err.to_string());
+                    return_definite_error(
+                        err,
+                        outputs.values(),
+                        data_output,
+                        data_cap_set,
+                        definite_error_handle,
+                        definite_error_cap_set,
+                    )
+                    .await;
+                    return Ok(());
+                }
+            };
+
+            let mut outputs: Vec<_> = outputs
+                .into_values()
+                .map(|output| {
+                    let format = match &source.format {
+                        ContentFormat::Csv(params) => {
+                            FormatKind::Csv(CsvDecoder::new(params.clone(), &output.desc))
+                        }
+                        ContentFormat::Parquet => {
+                            FormatKind::Parquet(ParquetFormat::new(output.desc))
+                        }
+                    };
+                    ReaderOutput {
+                        partition_index: output.partition_index,
+                        format,
+                        ingested: ingested_objects(output.resume_upper.elements()),
+                    }
+                })
+                .collect();
+
+            // The objects that every output has ingested, or that another worker is responsible
+            // for.
+            let mut done = outputs
+                .iter()
+                .map(|output| output.ingested.clone())
+                .reduce(|a, b| &a & &b)
+                .unwrap_or_default();
+            data_cap_set.downgrade(ingested_frontier(&done).iter());
+            // Objects that were ingested by every output no longer need to be tracked per output.
+            for output in outputs.iter_mut() {
+                output.ingested.retain(|id| !done.contains(id));
+            }
+
+            let connection = source.connection.clone();
+            let use_checksum = aws_s3_use_checksum(&connection, &source.format);
+            let enforce_external_addresses =
+                ENFORCE_EXTERNAL_ADDRESSES.get(config.config.config_set());
+            let s3 = AwsS3Source::new(
+                connection,
+                source.connection_id,
+                config.config.connection_context.clone(),
+                source.uri.clone(),
+                use_checksum,
+                enforce_external_addresses,
+            );
+
+            loop {
+                let objects = s3.list().await?;
+                metrics.list_requests.inc();
+
+                let mut last_downgrade = Instant::now();
+                for (object, checksum) in objects {
+                    if !filter.filter::<AwsS3Source>(&object) {
+                        continue;
+                    }
+                    metrics.objects_listed.inc();
+
+                    let id = object_id(object.path());
+                    if done.contains(&id) {
+                        continue;
+                    }
+
+                    if config.responsible_for(id) {
+                        tracing::info!(
+                            %config.id,
+                            key = %object.path(),
+                            "timely-{} ingesting object",
+                            config.worker_id,
+                        );
+                        let time = Partitioned::new_singleton(id, MzOffset::from(0));
+                        let cap = data_cap_set.delayed(&time);
+                        let mut failed = false;
+                        for output in outputs.iter_mut() {
+                            if output.ingested.remove(&id) {
+                                continue;
+                            }
+                            let partition_index = output.partition_index;
+                            let result = ingest(
+                                &s3,
+                                &output.format,
+                                &object,
+                                &checksum,
+                                partition_index,
+                                &data_output,
+                                &cap,
+                                &metrics,
+                            )
+                            .await;
+                            if let Err(err) = result {
+                                if !is_definite(&err) {
+                                    return Err(err.into());
+                                }
+                                // The object can't be decoded, which will never change.
+                                let update = (
+                                    (partition_index, Err(decode_error(&object, &err))),
+                                    time,
+                                    Diff::ONE,
+                                );
+                                let size = update.fuel_size();
+                                data_output.give_fueled(&cap, update, size).await;
+                                failed = true;
+                            }
+                        }
+                        if failed {
+                            metrics.objects_failed.inc();
+                        } else {
+                            metrics.objects_ingested.inc();
+                        }
+                    }
+
+                    done.insert(id);
+                    if last_downgrade.elapsed() > FRONTIER_DOWNGRADE_INTERVAL {
+                        data_cap_set.downgrade(ingested_frontier(&done).iter());
+                        last_downgrade = Instant::now();
+                    }
+                }
+                data_cap_set.downgrade(ingested_frontier(&done).iter());
+
+                let interval = S3_SOURCE_LIST_INTERVAL.get(config.config.config_set());
+                tokio::time::sleep(interval).await;
+            }
+        }))
+    });
+
+    let error_stream = definite_errors.concat(transient_errors.map(ReplicationError::Transient));
+
+    (
+        data_stream.as_collection(),
+        error_stream,
+        button.press_on_drop(),
+    )
+}
+
+type StackedAsyncOutputHandle<T, D> =
+    AsyncOutputHandle<T, FueledBuilder<CapacityContainerBuilder<Vec<(D, T, Diff)>>>>;
+
+/// Decodes `object` with `format` and emits each of its rows to the output with
+/// `partition_index` at the time of `cap`.
+async fn ingest(
+    s3: &AwsS3Source,
+    format: &FormatKind,
+    object: &S3Object,
+    checksum: &S3Checksum,
+    partition_index: u64,
+    data_output: &StackedAsyncOutputHandle<
+        S3Timestamp,
+        (u64, Result<SourceMessage, DataflowError>),
+    >,
+    cap: &Capability<S3Timestamp>,
+    metrics: &S3SourceMetrics,
+) -> Result<(), StorageErrorX> {
+    let requests = format
+        .split_work(s3.clone(), object.clone(), checksum.clone())
+        .await?;
+    let mut rows = Vec::new();
+    for request in requests {
+        let mut chunks = format.fetch_work(s3, request);
+        while let Some(chunk) = chunks.next().await {
+            let count = format.decode_chunk(chunk?, &mut rows)?;
+            metrics.rows.inc_by(u64::cast_from(count));
+            for row in rows.drain(..) {
+                let message = SourceMessage {
+                    key: Row::default(),
+                    value: row,
+                    metadata: Row::default(),
+                };
+                let update = ((partition_index, Ok(message)), *cap.time(), Diff::ONE);
+                let size = update.fuel_size();
+                data_output.give_fueled(cap, update, size).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether `err` is a property of the object itself, as opposed to an error talking to
+/// S3 that might go away when retried.
+fn is_definite(err: &StorageErrorX) -> bool {
+    matches!(
+        err.kind(),
+        StorageErrorXKind::CsvDecoding(_)
+            | StorageErrorXKind::ParquetError(_)
+            | StorageErrorXKind::InvalidRecordBatch(_)
+            | StorageErrorXKind::MissingField(_)
+    )
+}
+
+fn decode_error(object: &S3Object, err: &StorageErrorX) -> DataflowError {
+    let kind =
+        DecodeErrorKind::Text(format!("failed to decode {}: {}", object.path(), err.kind()).into());
+    DataflowError::DecodeError(Box::new(DecodeError {
+        kind,
+        raw: object.path().as_bytes().to_vec(),
+    }))
+}
+
+/// Helper method to return a "definite" error upstream.
+async fn return_definite_error(
+    err: DefiniteError,
+    outputs: impl Iterator<Item = &SourceOutputInfo>,
+    data_handle: StackedAsyncOutputHandle<S3Timestamp, (u64, Result<SourceMessage, DataflowError>)>,
+    data_capset: &CapabilitySet<S3Timestamp>,
+    errs_handle: AsyncOutputHandle<S3Timestamp, CapacityContainerBuilder<Vec<ReplicationError>>>,
+    errs_capset: &CapabilitySet<S3Timestamp>,
+) {
+    for output in outputs {
+        // The error is a property of the source itself, so it is emitted at the minimum time of
+        // the outputs that have not ingested anything yet, which is every output that could have
+        // observed the error.
+        if *output.resume_upper != [S3Timestamp::minimum()] {
+            continue;
+        }
+        let update = (
+            (output.partition_index, Err(err.clone().into())),
+            S3Timestamp::minimum(),
+            Diff::ONE,
+        );
+        let size = update.fuel_size();
+        data_handle.give_fueled(&data_capset[0], update, size).await;
+    }
+    errs_handle.give(
+        &errs_capset[0],
+        ReplicationError::DefiniteError(Rc::new(err)),
+    );
+}
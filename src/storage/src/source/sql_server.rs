@@ -9,7 +9,7 @@
 
 //! Code to render the ingestion dataflow of a [`SqlServerSourceConnection`].
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::future::Future;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -18,11 +18,13 @@ use differential_dataflow::AsCollection;
 use itertools::Itertools;
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
-use mz_repr::{Diff, GlobalId};
-use mz_sql_server_util::SqlServerError;
+use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_sql_server_util::cdc::Lsn;
 use mz_sql_server_util::desc::{SqlServerRowDecoder, SqlServerTableDesc};
+use mz_sql_server_util::inspect::{COMMIT_TIME_COLUMN, TRANSACTION_ID_COLUMN};
+use mz_sql_server_util::{SqlServerDecodeError, SqlServerError};
 use mz_storage_types::errors::{DataflowError, SourceError, SourceErrorDetails};
+use mz_storage_types::sources::sql_server::SqlServerMetadataKind;
 use mz_storage_types::sources::{
     SourceExport, SourceExportDetails, SourceTimestamp, SqlServerSourceConnection,
 };
@@ -50,6 +52,8 @@ struct SourceOutputInfo {
     upstream_desc: Arc<SqlServerTableDesc>,
     /// Type that can decode (and map) SQL Server rows into Materialize rows.
     decoder: Arc<SqlServerRowDecoder>,
+    /// The transaction metadata columns requested by this output, in order.
+    metadata_columns: Arc<[SqlServerMetadataKind]>,
     /// Upper to resume replication from.
     resume_upper: Antichain<Lsn>,
     /// An index to split the timely stream.
@@ -90,6 +94,52 @@ pub enum DefiniteError {
     IncompatibleSchemaChange(String, String),
 }
 
+/// Packs the requested metadata `kinds` of the change `row`, committed at `commit_lsn`, into
+/// `metadata_row`. Rows that were not replicated as part of a transaction, i.e. snapshot rows and
+/// their retractions, have all of their metadata columns set to null.
+fn pack_metadata(
+    kinds: &[SqlServerMetadataKind],
+    change: Option<(Lsn, &tiberius::Row)>,
+    metadata_row: &mut Row,
+) -> Result<(), SqlServerDecodeError> {
+    let mut packer = metadata_row.packer();
+    for kind in kinds {
+        let Some((commit_lsn, row)) = change else {
+            packer.push(Datum::Null);
+            continue;
+        };
+        match kind {
+            SqlServerMetadataKind::CommitTimestamp => {
+                let ts = row
+                    .try_get::<chrono::NaiveDateTime, _>(COMMIT_TIME_COLUMN)
+                    .map_err(|_| SqlServerDecodeError::InvalidColumn {
+                        column_name: COMMIT_TIME_COLUMN.to_string(),
+                        as_type: "datetime",
+                    })?
+                    .and_then(|ts| ts.try_into().ok());
+                match ts {
+                    Some(ts) => packer.push(Datum::Timestamp(ts)),
+                    None => packer.push(Datum::Null),
+                }
+            }
+            SqlServerMetadataKind::Lsn => packer.push(Datum::Bytes(&commit_lsn.as_bytes())),
+            SqlServerMetadataKind::TransactionId => {
+                let tran_id = row
+                    .try_get::<&[u8], _>(TRANSACTION_ID_COLUMN)
+                    .map_err(|_| SqlServerDecodeError::InvalidColumn {
+                        column_name: TRANSACTION_ID_COLUMN.to_string(),
+                        as_type: "bytes",
+                    })?;
+                match tran_id {
+                    Some(tran_id) => packer.push(Datum::Bytes(tran_id)),
+                    None => packer.push(Datum::Null),
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 impl From<DefiniteError> for DataflowError {
     fn from(val: DefiniteError) -> Self {
         let msg = val.to_string().into();
@@ -133,9 +183,13 @@ impl SourceRender for SqlServerSourceConnection {
                 other => unreachable!("unexpected source export details: {other:?}"),
             };
 
+            // Metadata columns trail the upstream columns and aren't decoded from the upstream
+            // row, so they're excluded from the decoder.
+            let relation_desc = &storage_metadata.relation_desc;
+            let upstream_arity = relation_desc.arity() - details.metadata_columns.len();
             let decoder = details
                 .table
-                .decoder(&storage_metadata.relation_desc)
+                .decoder(&relation_desc.apply_demand(&BTreeSet::from_iter(0..upstream_arity)))
                 .expect("TODO handle errors");
             let upstream_desc = Arc::new(details.table.clone());
             let resume_upper = config
@@ -149,6 +203,11 @@ impl SourceRender for SqlServerSourceConnection {
                 capture_instance: Arc::clone(&details.capture_instance),
                 upstream_desc,
                 decoder: Arc::new(decoder),
+                metadata_columns: details
+                    .metadata_columns
+                    .iter()
+                    .map(|(_, kind)| kind.clone())
+                    .collect(),
                 resume_upper: Antichain::from_iter(resume_upper),
                 partition_index: u64::cast_from(idx),
                 initial_lsn: details.initial_lsn,
@@ -30,7 +30,9 @@ use mz_sql_server_util::inspect::{
 use mz_storage_types::dyncfgs::SQL_SERVER_SOURCE_VALIDATE_RESTORE_HISTORY;
 use mz_storage_types::errors::{DataflowError, DecodeError, DecodeErrorKind};
 use mz_storage_types::sources::SqlServerSourceConnection;
-use mz_storage_types::sources::sql_server::{MAX_LSN_WAIT, SNAPSHOT_PROGRESS_REPORT_INTERVAL};
+use mz_storage_types::sources::sql_server::{
+    MAX_LSN_WAIT, SNAPSHOT_PROGRESS_REPORT_INTERVAL, SqlServerMetadataKind,
+};
 use mz_timely_util::builder_async::{
     AsyncOutputHandle, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
 };
@@ -43,8 +45,9 @@ use timely::progress::{Antichain, Timestamp};
 
 use crate::metrics::source::sql_server::SqlServerSourceMetrics;
 use crate::source::RawSourceCreationConfig;
+use crate::source::contents::OutputContents;
 use crate::source::sql_server::{
-    DefiniteError, ReplicationError, SourceOutputInfo, TransientError,
+    DefiniteError, ReplicationError, SourceOutputInfo, TransientError, pack_metadata,
 };
 use crate::source::types::{FuelSize, SignaledFuture, SourceMessage, StackedCollection};
 
@@ -97,6 +100,8 @@ pub(crate) fn render<'scope>(
 
             // The decoder is specific to the export, and each export pulls data from a specific capture instance.
             let mut decoder_map: BTreeMap<_, _> = BTreeMap::new();
+            // The transaction metadata columns requested by each export.
+            let mut metadata_map: BTreeMap<_, _> = BTreeMap::new();
             // Maps the 'capture instance' to the output index for only those outputs that this worker will snapshot
             let mut capture_instance_to_snapshot: BTreeMap<Arc<str>, Vec<_>> = BTreeMap::new();
             // Maps the 'capture instance' to the output index for all outputs of this worker
@@ -112,6 +117,7 @@ pub(crate) fn render<'scope>(
                 if decoder_map.insert(key, Arc::clone(&output.decoder)).is_some() {
                     panic!("Multiple decoders for output index {}", output.partition_index);
                 }
+                metadata_map.insert(key, Arc::clone(&output.metadata_columns));
                 // Collect the included columns from decoder for schema
                 // change validation. The decoder serves as an effective
                 // source of truth for which columns are "included", as we
@@ -159,6 +165,19 @@ pub(crate) fn render<'scope>(
                 return Ok::<_, TransientError>(());
             }
 
+            // The contents of the exports that include transaction metadata, whose retractions
+            // carry the metadata of the rows they retract, along with the upper they resume from.
+            let mut contents = BTreeMap::new();
+            for (export_id, output) in outputs.iter() {
+                if !output.metadata_columns.is_empty() {
+                    let metadata_len = output.metadata_columns.len();
+                    let output_contents =
+                        OutputContents::hydrate(&config, *export_id, metadata_len).await?;
+                    let resume_upper = output.resume_upper.clone();
+                    contents.insert(output.partition_index, (resume_upper, output_contents));
+                }
+            }
+
             let snapshot_instances = capture_instance_to_snapshot
                     .keys()
                     .map(|i| i.as_ref());
@@ -231,7 +250,13 @@ pub(crate) fn render<'scope>(
             let cdc_metrics = PrometheusSqlServerCdcMetrics{inner: &metrics};
             let mut cdc_handle = client
                 .cdc(capture_instances.keys().cloned(), cdc_metrics)
-                .max_lsn_wait(MAX_LSN_WAIT.get(config.config.config_set()));
+                .max_lsn_wait(MAX_LSN_WAIT.get(config.config.config_set()))
+                .transaction_metadata(
+                    metadata_map
+                        .values()
+                        .flat_map(|kinds| kinds.iter())
+                        .any(|kind| kind.requires_lsn_time_mapping()),
+                );
 
             // Snapshot any instance that requires it.
             // Each table snapshot will have its own LSN captured at the moment of snapshotting.
@@ -310,14 +335,19 @@ pub(crate) fn render<'scope>(
                             let decoder = decoder_map
                                 .get(partition_idx)
                                 .expect("decoder for output");
+                            let metadata_columns = metadata_map
+                                .get(partition_idx)
+                                .expect("metadata columns for output");
                             // Try to decode a row, returning a SourceError
                             // if it fails.
                             let message = decode(
                                 decoder,
+                                metadata_columns,
                                 &sql_server_row,
                                 &mut mz_row,
                                 &arena,
                                 None,
+                                None,
                             );
                             let update =
                                 ((*partition_idx, message), Lsn::minimum(), Diff::ONE);
@@ -526,6 +556,8 @@ pub(crate) fn render<'scope>(
                             changes,
                             &valid_partitions,
                             &decoder_map,
+                            &metadata_map,
+                            &mut contents,
                             lsn,
                             &rewinds,
                             &data_output,
@@ -615,6 +647,8 @@ async fn handle_data_event(
     changes: Vec<CdcOperation>,
     partition_indexes: &[u64],
     decoder_map: &BTreeMap<u64, Arc<SqlServerRowDecoder>>,
+    metadata_map: &BTreeMap<u64, Arc<[SqlServerMetadataKind]>>,
+    contents: &mut BTreeMap<u64, (Antichain<Lsn>, OutputContents)>,
     commit_lsn: Lsn,
     rewinds: &BTreeMap<u64, (Lsn, Lsn)>,
     data_output: &StackedAsyncOutputHandle<Lsn, (u64, Result<SourceMessage, DataflowError>)>,
@@ -675,6 +709,7 @@ async fn handle_data_event(
         // Try to decode the input row for each output.
         for partition_idx in partition_indexes {
             let decoder = decoder_map.get(partition_idx).unwrap();
+            let metadata_columns = metadata_map.get(partition_idx).unwrap();
 
             let rewind = rewinds.get(partition_idx);
             // We must continue here to avoid decoding and emitting. We don't have to compare with
@@ -683,15 +718,35 @@ async fn handle_data_event(
                 continue;
             }
 
-            let (message, diff) = if let Some(ref deferred_update) = deferred_update {
+            let rewinding = rewind.is_some_and(|(_, snapshot_lsn)| commit_lsn <= *snapshot_lsn);
+            // Changes that are rewound are reflected in the snapshot, whose rows carry no
+            // transaction metadata, so they carry none either.
+            let change_lsn = (!rewinding).then_some(commit_lsn);
+            // Changes before the resumption point of the output are already reflected in its
+            // contents.
+            let mut contents = contents
+                .get_mut(partition_idx)
+                .filter(|(resume_upper, _)| !rewinding && resume_upper.less_equal(&commit_lsn))
+                .map(|(_, contents)| contents);
+
+            let (mut message, diff) = if let Some(ref deferred_update) = deferred_update {
                 let (old_row, new_row) = match deferred_update {
                     CdcOperation::UpdateOld(_seqval, row) => (row, &sql_server_row),
                     CdcOperation::UpdateNew(_seqval, row) => (&sql_server_row, row),
                     CdcOperation::Insert(_) | CdcOperation::Delete(_) => unreachable!(),
                 };
 
-                let update_old = decode(decoder, old_row, &mut mz_row, &arena, Some(new_row));
-                if rewind.is_some_and(|(_, snapshot_lsn)| commit_lsn <= *snapshot_lsn) {
+                let mut update_old = decode(
+                    decoder,
+                    metadata_columns,
+                    old_row,
+                    &mut mz_row,
+                    &arena,
+                    Some(new_row),
+                    change_lsn,
+                );
+                update_contents(contents.as_deref_mut(), &mut update_old, Diff::MINUS_ONE);
+                if rewinding {
                     let update = (
                         (*partition_idx, update_old.clone()),
                         Lsn::minimum(),
@@ -709,17 +764,34 @@ async fn handle_data_event(
                     .await;
 
                 (
-                    decode(decoder, new_row, &mut mz_row, &arena, None),
+                    decode(
+                        decoder,
+                        metadata_columns,
+                        new_row,
+                        &mut mz_row,
+                        &arena,
+                        None,
+                        change_lsn,
+                    ),
                     Diff::ONE,
                 )
             } else {
                 (
-                    decode(decoder, &sql_server_row, &mut mz_row, &arena, None),
+                    decode(
+                        decoder,
+                        metadata_columns,
+                        &sql_server_row,
+                        &mut mz_row,
+                        &arena,
+                        None,
+                        change_lsn,
+                    ),
                     diff,
                 )
             };
             assert_ne!(Diff::ZERO, diff);
-            if rewind.is_some_and(|(_, snapshot_lsn)| commit_lsn <= *snapshot_lsn) {
+            update_contents(contents, &mut message, diff);
+            if rewinding {
                 let update = ((*partition_idx, message.clone()), Lsn::minimum(), -diff);
                 let size = update.fuel_size();
                 data_output
@@ -741,18 +813,31 @@ type StackedAsyncOutputHandle<T, D> =
 
 /// Helper method to decode a row from a [`tiberius::Row`] (or 2 of them in the case of update)
 /// to a [`Row`]. This centralizes the decode and mapping to result.
+///
+/// `commit_lsn` is the LSN of the transaction that produced the change, or `None` for snapshot
+/// rows, and is used to fill in the requested `metadata_columns`.
 fn decode(
     decoder: &SqlServerRowDecoder,
+    metadata_columns: &[SqlServerMetadataKind],
     row: &tiberius::Row,
     mz_row: &mut Row,
     arena: &RowArena,
     new_row: Option<&tiberius::Row>,
+    commit_lsn: Option<Lsn>,
 ) -> Result<SourceMessage, DataflowError> {
-    match decoder.decode(row, mz_row, arena, new_row) {
+    let mut metadata = Row::default();
+    let result = decoder.decode(row, mz_row, arena, new_row).and_then(|()| {
+        pack_metadata(
+            metadata_columns,
+            commit_lsn.map(|lsn| (lsn, row)),
+            &mut metadata,
+        )
+    });
+    match result {
         Ok(()) => Ok(SourceMessage {
             key: Row::default(),
             value: mz_row.clone(),
-            metadata: Row::default(),
+            metadata,
         }),
         Err(e) => {
             let kind = DecodeErrorKind::Text(e.to_string().into());
@@ -766,6 +851,22 @@ fn decode(
     }
 }
 
+/// Applies an update of `message` to the `contents` of its output, if they are kept. Retractions
+/// are given the metadata of the row version they retract, rather than that of the change that
+/// removed it.
+fn update_contents(
+    contents: Option<&mut OutputContents>,
+    message: &mut Result<SourceMessage, DataflowError>,
+    diff: Diff,
+) {
+    if let (Some(contents), Ok(message)) = (contents, message) {
+        if diff.is_negative() {
+            contents.retraction_metadata(&message.value, &mut message.metadata);
+        }
+        contents.update(&message.value, &message.metadata, diff);
+    }
+}
+
 /// Helper method to return a "definite" error upstream.
 async fn return_definite_error(
     err: DefiniteError,
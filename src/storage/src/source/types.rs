@@ -159,6 +159,15 @@ impl<T: FuelSize, E: FuelSize> FuelSize for Result<T, E> {
     }
 }
 
+impl<T: FuelSize> FuelSize for Option<T> {
+    fn fuel_size(&self) -> usize {
+        match self {
+            Some(t) => t.fuel_size(),
+            None => 0,
+        }
+    }
+}
+
 /// Tuples sum the fuel size of their elements. Trivial coordinate fields
 /// (output indices, timestamps, diffs) implement `FuelSize` to return their
 /// stack size, so an `update.fuel_size()` call charges only the heap-allocated
@@ -29,9 +29,11 @@ use mz_storage_types::StorageDiff;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::sinks::StorageSinkDesc;
 use mz_storage_types::sources::{
-    GenericSourceConnection, IngestionDescription, KafkaSourceConnection,
-    LoadGeneratorSourceConnection, MySqlSourceConnection, PostgresSourceConnection,
-    SourceConnection, SourceData, SourceEnvelope, SourceTimestamp, SqlServerSourceConnection,
+    GenericSourceConnection, IcebergSourceConnection, IngestionDescription, KafkaSourceConnection,
+    KinesisSourceConnection, LoadGeneratorSourceConnection, MongoDbSourceConnection,
+    MqttSourceConnection, MySqlSourceConnection, NatsSourceConnection, PostgresSourceConnection,
+    S3SourceConnection, SourceConnection, SourceData, SourceEnvelope, SourceTimestamp,
+    SqlServerSourceConnection,
 };
 use timely::order::{PartialOrder, TotalOrder};
 use timely::progress::frontier::MutableAntichain;
@@ -347,6 +349,39 @@ impl<T: Timestamp + TimestampManipulation + Lattice + Codec64 + Display + Sync>
                                 .await;
                                 to_vec_row(uppers)
                             }
+                            GenericSourceConnection::Kinesis(_) => {
+                                let uppers = reclock_resume_uppers::<KinesisSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
+                            GenericSourceConnection::Nats(_) => {
+                                let uppers = reclock_resume_uppers::<NatsSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
+                            GenericSourceConnection::Mqtt(_) => {
+                                let uppers = reclock_resume_uppers::<MqttSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
                             GenericSourceConnection::Postgres(_) => {
                                 let uppers = reclock_resume_uppers::<PostgresSourceConnection, _>(
                                     &id,
@@ -380,6 +415,39 @@ impl<T: Timestamp + TimestampManipulation + Lattice + Codec64 + Display + Sync>
                                 .await;
                                 to_vec_row(uppers)
                             }
+                            GenericSourceConnection::MongoDb(_) => {
+                                let uppers = reclock_resume_uppers::<MongoDbSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
+                            GenericSourceConnection::S3(_) => {
+                                let uppers = reclock_resume_uppers::<S3SourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
+                            GenericSourceConnection::Iceberg(_) => {
+                                let uppers = reclock_resume_uppers::<IcebergSourceConnection, _>(
+                                    &id,
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    as_of.clone(),
+                                    &resume_uppers,
+                                )
+                                .await;
+                                to_vec_row(uppers)
+                            }
                             GenericSourceConnection::LoadGenerator(_) => {
                                 let uppers =
                                     reclock_resume_uppers::<LoadGeneratorSourceConnection, _>(
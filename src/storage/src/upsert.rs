@@ -248,17 +248,18 @@ where
 impl UpsertSourceTime for KafkaTimestamp {
     /// Per-record Kafka source times are exact singletons (a single partition
     /// at a single offset; see the source reader), and `KafkaTimestamp`'s
-    /// derived `Ord` is lexicographic on `(partition, offset)`, so this flat
-    /// projection is order-preserving. `RangeBound`'s infinities map to the
-    /// `i64` extrema to remain order-consistent for any non-singleton bound.
-    type Order = (i64, u64);
-    fn upsert_order(&self) -> (i64, u64) {
-        let partition = match self.interval().lower {
-            RangeBound::NegInfinity => i64::MIN,
-            RangeBound::Elem(p, _) => i64::from(p),
-            RangeBound::PosInfinity => i64::MAX,
+    /// derived `Ord` is lexicographic on `((topic, partition), offset)`, so
+    /// this flat projection is order-preserving. `RangeBound`'s infinities map
+    /// to the `i64` extrema to remain order-consistent for any non-singleton
+    /// bound.
+    type Order = (i64, i64, u64);
+    fn upsert_order(&self) -> (i64, i64, u64) {
+        let (topic, partition) = match self.interval().lower {
+            RangeBound::NegInfinity => (i64::MIN, i64::MIN),
+            RangeBound::Elem(p, _) => (i64::from(p.topic), i64::from(p.partition)),
+            RangeBound::PosInfinity => (i64::MAX, i64::MAX),
         };
-        (partition, self.timestamp().offset)
+        (topic, partition, self.timestamp().offset)
     }
 }
 
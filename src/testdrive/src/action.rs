@@ -67,6 +67,7 @@ mod fivetran;
 mod glue;
 mod http;
 mod kafka;
+mod mongodb;
 mod mysql;
 mod nop;
 mod persist;
@@ -263,6 +264,7 @@ pub struct State {
 
     // === Database driver state. ===
     pub duckdb_clients: BTreeMap<String, std::sync::Arc<std::sync::Mutex<::duckdb::Connection>>>,
+    mongodb_clients: BTreeMap<String, ::mongodb::Client>,
     mysql_clients: BTreeMap<String, mysql_async::Conn>,
     postgres_clients: BTreeMap<String, tokio_postgres::Client>,
     sql_server_clients: BTreeMap<String, mz_sql_server_util::Client>,
@@ -890,6 +892,8 @@ impl Run for PosCommand {
                     "kafka-verify-data" => kafka::run_verify_data(builtin, state).await,
                     "kafka-verify-commit" => kafka::run_verify_commit(builtin, state).await,
                     "kafka-verify-topic" => kafka::run_verify_topic(builtin, state).await,
+                    "mongodb-connect" => mongodb::run_connect(builtin, state).await,
+                    "mongodb-execute" => mongodb::run_execute(builtin, state).await,
                     "mysql-connect" => mysql::run_connect(builtin, state).await,
                     "mysql-execute" => mysql::run_execute(builtin, state).await,
                     "nop" => nop::run_nop(),
@@ -1176,6 +1180,7 @@ pub async fn create_state(
 
         // === Database driver state. ===
         duckdb_clients: BTreeMap::new(),
+        mongodb_clients: BTreeMap::new(),
         mysql_clients: BTreeMap::new(),
         postgres_clients: BTreeMap::new(),
         sql_server_clients: BTreeMap::new(),
@@ -173,7 +173,8 @@ enum Format {
         message: String,
         confluent_wire_format: bool,
         schema_id_subject: Option<String>,
-        schema_message_id: u8,
+        /// Overrides the message indexes of the Confluent wire format with a single raw byte.
+        schema_message_id: Option<u8>,
     },
     Bytes {
         terminator: Option<u8>,
@@ -196,7 +197,7 @@ enum Transcoder {
         message: MessageDescriptor,
         confluent_wire_format: bool,
         schema_id: i32,
-        schema_message_id: u8,
+        schema_message_id: Option<u8>,
     },
     Bytes {
         terminator: Option<u8>,
@@ -284,11 +285,21 @@ impl Transcoder {
                         // The first byte is a magic byte (0) that indicates the Confluent
                         // serialization format version, and the next four bytes are a
                         // 32-bit schema ID, which we default to something fun.
-                        // And, as we only support single-message proto files for now,
-                        // we also set the following message id to 0.
+                        // They are followed by the indexes of the message within its
+                        // file.
                         out.write_u8(0).unwrap();
                         out.write_i32::<NetworkEndian>(*schema_id).unwrap();
-                        out.write_u8(*schema_message_id).unwrap();
+                        match schema_message_id {
+                            Some(id) => out.write_u8(*id).unwrap(),
+                            None => {
+                                let descriptor = message.descriptor();
+                                let indexes =
+                                    mz_interchange::protobuf::message_indexes(&descriptor);
+                                mz_interchange::confluent::encode_protobuf_message_indexes(
+                                    &indexes, &mut out,
+                                );
+                            }
+                        }
                     }
                     message.encode(&mut out)?;
                     Ok(Some(out))
@@ -366,7 +377,7 @@ pub async fn run_ingest(
                 // false
                 confluent_wire_format: cmd.args.opt_bool("confluent-wire-format")?.unwrap_or(false),
                 schema_id_subject: cmd.args.opt_string("schema-id-subject"),
-                schema_message_id: cmd.args.opt_parse::<u8>("schema-message-id")?.unwrap_or(0),
+                schema_message_id: cmd.args.opt_parse::<u8>("schema-message-id")?,
             }
         }
         "bytes" => Format::Bytes { terminator: None },
@@ -409,10 +420,7 @@ pub async fn run_ingest(
                 message,
                 confluent_wire_format: cmd.args.opt_bool("confluent-wire-format")?.unwrap_or(false),
                 schema_id_subject: cmd.args.opt_string("key-schema-id-subject"),
-                schema_message_id: cmd
-                    .args
-                    .opt_parse::<u8>("key-schema-message-id")?
-                    .unwrap_or(0),
+                schema_message_id: cmd.args.opt_parse::<u8>("key-schema-message-id")?,
             })
         }
         Some("bytes") => Some(Format::Bytes {
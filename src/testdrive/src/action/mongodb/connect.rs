@@ -0,0 +1,29 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use anyhow::Context;
+
+use crate::action::{ControlFlow, State};
+use crate::parser::BuiltinCommand;
+
+pub async fn run_connect(
+    mut cmd: BuiltinCommand,
+    state: &mut State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let name = cmd.args.string("name")?;
+    let url = cmd.args.string("url")?;
+    cmd.args.done()?;
+
+    let client = mongodb::Client::with_uri_str(&url)
+        .await
+        .with_context(|| format!("connecting to MongoDB deployment at {url}"))?;
+
+    state.mongodb_clients.insert(name, client);
+    Ok(ControlFlow::Continue)
+}
@@ -0,0 +1,46 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use anyhow::{Context, anyhow, bail};
+use mongodb::bson::Bson;
+
+use crate::action::{ControlFlow, State};
+use crate::parser::BuiltinCommand;
+
+/// Runs each line of the input as a database command, written in Extended
+/// JSON, e.g. `{"insert": "orders", "documents": [{"_id": 1}]}`.
+pub async fn run_execute(
+    mut cmd: BuiltinCommand,
+    state: &mut State,
+) -> Result<ControlFlow, anyhow::Error> {
+    let name = cmd.args.string("name")?;
+    let database = cmd.args.string("database")?;
+    cmd.args.done()?;
+
+    let client = state
+        .mongodb_clients
+        .get(&name)
+        .ok_or_else(|| anyhow!("MongoDB connection '{}' not found", &name))?;
+    let db = client.database(&database);
+
+    for command in cmd.input {
+        println!(">> {}", command);
+        let json: serde_json::Value =
+            serde_json::from_str(&command).context("parsing MongoDB command")?;
+        let command = match Bson::try_from(json).context("parsing MongoDB command")? {
+            Bson::Document(command) => command,
+            other => bail!("MongoDB command must be a document, got {other}"),
+        };
+        db.run_command(command)
+            .await
+            .context("executing MongoDB command")?;
+    }
+
+    Ok(ControlFlow::Continue)
+}
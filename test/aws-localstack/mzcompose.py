@@ -9,6 +9,7 @@
 
 """Tests of AWS functionality that run against localstack."""
 
+import time
 import uuid
 from typing import Any, cast
 
@@ -72,7 +73,7 @@ def workflow_default(c: Composition) -> None:
         with c.test_case(name):
             c.workflow(name)
 
-    workflows = ["secrets-manager", "aws-connection", "copy-to-s3"]
+    workflows = ["secrets-manager", "aws-connection", "copy-to-s3", "kinesis-source"]
     c.test_parts(workflows, process)
 
 
@@ -302,3 +303,75 @@ def workflow_copy_to_s3(c: Composition) -> None:
             Bucket=bucket_name, Prefix=f"{path_prefix}/3/"
         )
         validate_upload(third_upload, set(["1000"]))
+
+
+def workflow_kinesis_source(c: Composition) -> None:
+    with c.override(
+        Materialized(
+            depends_on=["localstack"],
+            environment_extra=[
+                f"AWS_ENDPOINT_URL={AWS_ENDPOINT_URL_MZ}",
+                f"AWS_ACCESS_KEY_ID={AWS_ACCESS_KEY_ID}",
+                f"AWS_SECRET_ACCESS_KEY={AWS_SECRET_ACCESS_KEY}",
+            ],
+        )
+    ):
+        c.up("localstack", "materialized")
+        localhost_aws_endpoint_url = f"http://localhost:{c.port('localstack', 4566)}"
+        kinesis_client = boto3.client(
+            "kinesis",
+            endpoint_url=localhost_aws_endpoint_url,
+            region_name=DEFAULT_CLOUD_REGION,
+            aws_access_key_id=AWS_ACCESS_KEY_ID,
+            aws_secret_access_key=AWS_SECRET_ACCESS_KEY,
+        )
+        stream_name = f"kinesis-source-{uuid.uuid4()}"
+
+        def wait_for_active_stream() -> None:
+            for _ in range(60):
+                summary = kinesis_client.describe_stream_summary(StreamName=stream_name)
+                if summary["StreamDescriptionSummary"]["StreamStatus"] == "ACTIVE":
+                    return
+                time.sleep(1)
+            raise RuntimeError(f"stream {stream_name} did not become active")
+
+        def put_records(records: list[tuple[str, str]]) -> None:
+            # Records are put one by one, so that the records of a key are
+            # ordered within their shard.
+            for key, value in records:
+                kinesis_client.put_record(
+                    StreamName=stream_name, PartitionKey=key, Data=value.encode()
+                )
+
+        def run_testdrive(file: str) -> None:
+            c.run_testdrive_files(
+                f"--var=endpoint={AWS_ENDPOINT_URL_MZ}",
+                f"--var=access-key={AWS_ACCESS_KEY_ID}",
+                f"--var=secret-key={AWS_SECRET_ACCESS_KEY}",
+                f"--var=region={DEFAULT_CLOUD_REGION}",
+                f"--var=stream={stream_name}",
+                "--no-reset",
+                "--default-timeout=60s",
+                f"kinesis-source/{file}",
+            )
+
+        kinesis_client.create_stream(StreamName=stream_name, ShardCount=2)
+        wait_for_active_stream()
+        put_records([("a", "a1"), ("b", "b1"), ("c", "c1"), ("a", "a2")])
+        run_testdrive("01-ingest.td")
+
+        # Reshard the stream, which closes its shards and writes later records
+        # to their children.
+        kinesis_client.update_shard_count(
+            StreamName=stream_name, TargetShardCount=4, ScalingType="UNIFORM_SCALING"
+        )
+        wait_for_active_stream()
+        put_records([("a", "a3"), ("b", "b2"), ("d", "d1")])
+        run_testdrive("02-reshard.td")
+
+        # Restart Materialize, after which the source resumes from its
+        # frontier.
+        c.kill("materialized")
+        put_records([("c", "c2"), ("e", "e1")])
+        c.up("materialized")
+        run_testdrive("03-restart.td")
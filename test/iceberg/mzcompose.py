@@ -95,6 +95,18 @@ def workflow_gcp_connection_validation(c: Composition) -> None:
     )
 
 
+def workflow_source(c: Composition) -> None:
+    """Iceberg sources ingest the snapshots that Iceberg sinks commit, including
+    their position and equality deletes."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "source.td",
+    )
+
+
 def workflow_mode_append(c: Composition) -> None:
     key = _setup(c)
 
@@ -139,6 +151,202 @@ def _polaris_get(table_url: str, access_token: str) -> dict:
     return json.loads(resp.read())
 
 
+def _polaris_access_token(base_url: str) -> str:
+    """Get a Polaris access token for the root principal."""
+    token_req = urllib.request.Request(
+        f"{base_url}/api/catalog/v1/oauth/tokens",
+        data=b"grant_type=client_credentials&client_id=root&client_secret=root&scope=PRINCIPAL_ROLE:ALL",
+        headers={"Content-Type": "application/x-www-form-urlencoded"},
+    )
+    token_resp = urllib.request.urlopen(token_req)
+    return json.loads(token_resp.read())["access_token"]
+
+
+def _polaris_table_url(c: Composition, table: str) -> tuple[str, str]:
+    """Return the Polaris base URL and the REST URL of `table`."""
+    base_url = f"http://localhost:{c.port('polaris', 8181)}"
+    table_url = (
+        f"{base_url}/api/catalog/v1/default_catalog"
+        f"/namespaces/default_namespace/tables/{table}"
+    )
+    return base_url, table_url
+
+
+def _polaris_update(table_url: str, access_token: str, payload: dict) -> None:
+    """POST a table update to the Polaris REST API."""
+    req = urllib.request.Request(
+        table_url,
+        data=json.dumps(payload).encode(),
+        headers={
+            "Authorization": f"Bearer {access_token}",
+            "Content-Type": "application/json",
+        },
+        method="POST",
+    )
+    urllib.request.urlopen(req)
+
+
+def workflow_partition_by(c: Composition) -> None:
+    """PARTITION BY writes data and delete files per partition."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "partition-by.td",
+    )
+
+
+def workflow_partition_evolution(c: Composition) -> None:
+    """A sink keeps writing to a table whose partition spec another engine
+    evolved, using the new spec for all files it writes afterwards."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "partition-evolution-setup.td",
+    )
+
+    # Add an identity partition field on `region` to the table's partition
+    # spec, like `ALTER TABLE ... ADD PARTITION FIELD region` in Spark.
+    base_url, table_url = _polaris_table_url(c, "evolution_table")
+    access_token = _polaris_access_token(base_url)
+    metadata = _polaris_get(table_url, access_token)["metadata"]
+    default_spec_id = metadata["default-spec-id"]
+    default_spec = next(
+        spec
+        for spec in metadata["partition-specs"]
+        if spec["spec-id"] == default_spec_id
+    )
+    schema = next(
+        schema
+        for schema in metadata["schemas"]
+        if schema["schema-id"] == metadata["current-schema-id"]
+    )
+    region_id = next(
+        field["id"] for field in schema["fields"] if field["name"] == "region"
+    )
+    fields = default_spec["fields"] + [
+        {
+            "source-id": region_id,
+            "field-id": metadata["last-partition-id"] + 1,
+            "name": "region",
+            "transform": "identity",
+        }
+    ]
+    _polaris_update(
+        table_url,
+        access_token,
+        {
+            "requirements": [
+                {
+                    "type": "assert-default-spec-id",
+                    "default-spec-id": default_spec_id,
+                }
+            ],
+            "updates": [
+                {
+                    "action": "add-spec",
+                    "spec": {
+                        "spec-id": max(
+                            spec["spec-id"] for spec in metadata["partition-specs"]
+                        )
+                        + 1,
+                        "fields": fields,
+                    },
+                },
+                {"action": "set-default-spec", "spec-id": -1},
+            ],
+        },
+    )
+
+    c.run_testdrive_files(
+        "--no-reset",
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "partition-evolution-verify.td",
+    )
+
+
+def workflow_schema_evolution(c: Composition) -> None:
+    """Columns added to the sinked table are added to the Iceberg table once
+    the sink is altered to the table's new version."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "schema-evolution.td",
+    )
+
+
+def workflow_maintenance(c: Composition) -> None:
+    """Compaction commits `replace` snapshots tagged with `mz-maintenance` and
+    snapshot expiration keeps the latest snapshot that records a frontier.
+    A sink restarted while a compaction is its table's current snapshot
+    resumes from the frontier of the snapshot before it."""
+    key = _setup(c)
+
+    c.run_testdrive_files(
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "maintenance-setup.td",
+    )
+
+    base_url, table_url = _polaris_table_url(c, "maintenance_table")
+    access_token = _polaris_access_token(base_url)
+
+    # Compaction runs right after a commit, so with the 30s commit interval
+    # the compaction stays the current snapshot for a while.
+    metadata = None
+    current = None
+    for _ in range(120):
+        metadata = _polaris_get(table_url, access_token)["metadata"]
+        current = next(
+            (
+                snapshot
+                for snapshot in metadata.get("snapshots", [])
+                if snapshot["snapshot-id"] == metadata.get("current-snapshot-id")
+            ),
+            None,
+        )
+        if current is not None and "mz-maintenance" in current["summary"]:
+            break
+        time.sleep(1)
+    else:
+        raise AssertionError("no compaction was committed")
+    assert metadata is not None and current is not None
+
+    summary = current["summary"]
+    assert summary["operation"] == "replace", summary
+    assert "mz-frontier" not in summary, summary
+
+    # Expiration removed every snapshot but the compaction and the latest
+    # snapshot that records a frontier, even though the latter is older than
+    # the retention.
+    frontier_snapshots = [
+        snapshot
+        for snapshot in metadata["snapshots"]
+        if "mz-frontier" in snapshot["summary"]
+    ]
+    assert len(frontier_snapshots) == 1, metadata["snapshots"]
+    assert len(metadata["snapshots"]) == 2, metadata["snapshots"]
+    assert current["parent-snapshot-id"] == frontier_snapshots[0]["snapshot-id"]
+
+    # Restart before the next commit, so that frontier recovery has to skip
+    # the compaction.
+    c.kill("materialized")
+    c.up("materialized")
+
+    c.run_testdrive_files(
+        "--no-reset",
+        f"--var=s3-access-key={key}",
+        "--var=aws-endpoint=minio:9000",
+        "maintenance-verify.td",
+    )
+
+
 def workflow_commit_conflict(c: Composition) -> None:
     """Verify no data loss when catalog commit conflicts occur.
 
@@ -167,21 +375,8 @@ def workflow_commit_conflict(c: Composition) -> None:
     time.sleep(10)
 
     # Phase 3: Set up direct HTTP access to Polaris from host
-    polaris_port = c.port("polaris", 8181)
-    base_url = f"http://localhost:{polaris_port}"
-    table_url = (
-        f"{base_url}/api/catalog/v1/default_catalog"
-        f"/namespaces/default_namespace/tables/conflict_table"
-    )
-
-    # Get access token via direct HTTP
-    token_req = urllib.request.Request(
-        f"{base_url}/api/catalog/v1/oauth/tokens",
-        data=b"grant_type=client_credentials&client_id=root&client_secret=root&scope=PRINCIPAL_ROLE:ALL",
-        headers={"Content-Type": "application/x-www-form-urlencoded"},
-    )
-    token_resp = urllib.request.urlopen(token_req)
-    access_token = json.loads(token_resp.read())["access_token"]
+    base_url, table_url = _polaris_table_url(c, "conflict_table")
+    access_token = _polaris_access_token(base_url)
     print(f"Got Polaris access token (len={len(access_token)})")
 
     # Phase 4: Start background modification loop (~100/sec).
@@ -0,0 +1,73 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Native MongoDB source tests, functional.
+"""
+
+import glob
+import pathlib
+import random
+
+from materialize import MZ_ROOT, buildkite
+from materialize.mzcompose.composition import (
+    Composition,
+    Service,
+    WorkflowArgumentParser,
+)
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.mongodb import MongoDb
+from materialize.mzcompose.services.mz import Mz
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Mz(app_password=""),
+    Materialized(
+        additional_system_parameter_defaults={
+            "log_filter": "mz_storage::source::mongodb=trace,mz_mongodb_util=debug,info",
+            "enable_mongodb_source": "true",
+        },
+    ),
+    Testdrive(),
+    MongoDb(),
+]
+
+
+def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
+    parser.add_argument(
+        "filter",
+        nargs="*",
+        default=["*.td"],
+        help="limit to only the files matching filter",
+    )
+    args = parser.parse_args()
+
+    matching_files: list[str] = []
+    for filter in args.filter:
+        matching_files.extend(
+            glob.glob(filter, root_dir=MZ_ROOT / "test" / "mongodb-cdc")
+        )
+    sharded_files: list[str] = buildkite.shard_list(
+        sorted(matching_files), lambda file: file
+    )
+    print(f"Filter: {args.filter} Files: {sharded_files}")
+
+    c.up("materialized", "mongodb", Service("testdrive", idle=True))
+    seed = random.getrandbits(16)
+
+    def run(file: pathlib.Path | str) -> None:
+        c.run_testdrive_files(
+            "--max-errors=1",
+            f"--seed={seed}",
+            f"--var=replica-set={MongoDb.DEFAULT_REPLICA_SET}",
+            "setup/setup.td",
+            str(file),
+        )
+
+    c.test_parts(sharded_files, run)
@@ -0,0 +1,124 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Functional tests for NATS JetStream and MQTT sources.
+"""
+
+from materialize.mzcompose.composition import (
+    Composition,
+    Service,
+)
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.mz import Mz
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Mz(app_password=""),
+    Materialized(
+        additional_system_parameter_defaults={
+            "log_filter": "mz_storage::source::nats=trace,mz_storage::source::mqtt=trace,info",
+            "enable_nats_source": "true",
+            "enable_mqtt_source": "true",
+        },
+    ),
+    Testdrive(),
+    Service(
+        "nats",
+        {
+            "image": "nats:2.10",
+            "command": ["-js"],
+            "ports": [4222],
+        },
+    ),
+    # Provides the `nats` CLI to set up streams and publish messages.
+    Service(
+        "nats-box",
+        {
+            "image": "natsio/nats-box:0.14.5",
+            "command": ["sleep", "infinity"],
+            "environment": ["NATS_URL=nats://nats:4222"],
+        },
+    ),
+    Service(
+        "mosquitto",
+        {
+            "image": "eclipse-mosquitto:2.0",
+            "command": ["mosquitto", "-c", "/mosquitto-no-auth.conf"],
+            "ports": [1883],
+        },
+    ),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    def process(name: str) -> None:
+        if name == "default":
+            return
+        with c.test_case(name):
+            c.workflow(name)
+
+    c.test_parts(["nats", "mqtt"], process)
+
+
+def workflow_nats(c: Composition) -> None:
+    c.up("nats", "nats-box", "materialized", Service("testdrive", idle=True))
+
+    def nats(*args: str) -> None:
+        c.exec("nats-box", "nats", *args)
+
+    def publish(subject: str, payload: str, *headers: str) -> None:
+        nats("pub", subject, payload, *[f"--header={h}" for h in headers])
+
+    nats("stream", "add", "events", "--subjects=events.>", "--defaults")
+    publish("events.orders.1", "o1", "region:eu")
+    publish("events.orders.2", "o2")
+    publish("events.audit.1", "a1")
+    publish("events.orders.1", "o1-updated")
+    c.run_testdrive_files("--no-reset", "nats/01-ingest.td")
+
+    # Restart Materialize, after which the source resumes from its frontier
+    # without ingesting any message twice.
+    c.kill("materialized")
+    publish("events.orders.3", "o3")
+    publish("events.audit.2", "a2")
+    c.up("materialized")
+    c.run_testdrive_files("--no-reset", "nats/02-restart.td")
+
+
+def workflow_mqtt(c: Composition) -> None:
+    c.up("mosquitto", "materialized", Service("testdrive", idle=True))
+
+    def publish(
+        topic: str, payload: str, *properties: tuple[str, str], retain: bool = False
+    ) -> None:
+        args = ["mosquitto_pub", "-V", "mqttv5", "-q", "1", "-t", topic, "-m", payload]
+        for key, value in properties:
+            args += ["-D", "publish", "user-property", key, value]
+        if retain:
+            args.append("-r")
+        c.exec("mosquitto", *args)
+
+    # Sources only receive the messages that are published after they have
+    # subscribed, apart from retained messages. The testdrive file waits for
+    # the retained message before any other message is published.
+    publish("devices/d0/telemetry", "0", retain=True)
+    c.run_testdrive_files("--no-reset", "mqtt/01-create.td")
+    publish("devices/d1/telemetry", "20", ("unit", "celsius"))
+    publish("devices/d2/telemetry", "30")
+    publish("devices/d1/status", "online")
+    publish("devices/d1/telemetry", "21")
+    c.run_testdrive_files("--no-reset", "mqtt/02-ingest.td")
+
+    # The broker holds the messages of the session of the source while
+    # Materialize is down.
+    c.kill("materialized")
+    publish("devices/d3/telemetry", "40")
+    c.up("materialized")
+    c.run_testdrive_files("--no-reset", "mqtt/03-restart.td")
@@ -248,6 +248,29 @@ def workflow_silent_connection_drop(
         _verify_exactly_n_replication_slots_exist(pg_conn, n=1)
 
 
+def workflow_postgres_sink_restart(
+    c: Composition, parser: WorkflowArgumentParser
+) -> None:
+    """
+    Test that a Postgres sink does not re-apply committed timestamps after a restart.
+    """
+
+    pg_version = get_targeted_pg_version(parser)
+    with c.override(create_postgres(pg_version=pg_version)):
+        c.up("materialized", "postgres")
+
+        c.run_testdrive_files(
+            "--no-reset",
+            f"--var=default-replica-size=scale={Materialized.Size.DEFAULT_SIZE},workers={Materialized.Size.DEFAULT_SIZE}",
+            "override/postgres-sink-restart-part-1.td",
+        )
+
+        c.kill("materialized")
+        c.up("materialized")
+
+        c.run_testdrive_files("--no-reset", "override/postgres-sink-restart-part-2.td")
+
+
 def _await_postgres_replication_slot_state(
     pg_conn: Connection, await_active: bool, error_message: str
 ) -> None: